@@ -30,10 +30,12 @@ use databend_query::api::HttpService;
 use databend_query::api::RpcService;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::metrics::MetricService;
+use databend_query::servers::ClickHouseHandler;
 use databend_query::servers::FlightSQLServer;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::servers::MySQLHandler;
+use databend_query::servers::PostgresHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
 use databend_query::GlobalServices;
@@ -153,6 +155,40 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // PostgreSQL handler.
+    {
+        let hostname = conf.query.postgres_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.postgres_handler_port);
+        let tcp_keepalive_timeout_secs = conf.query.mysql_handler_tcp_keepalive_timeout_secs;
+        let mut handler = PostgresHandler::create(tcp_keepalive_timeout_secs)?;
+        let listening = handler.start(listening.parse()?).await?;
+        shutdown_handle.add_service(handler);
+
+        info!(
+            "Listening for PostgreSQL compatibility protocol: {}, Usage: psql -Uroot -h{} -p{}",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
+    // ClickHouse native TCP handler.
+    if conf.query.clickhouse_handler_enabled {
+        let hostname = conf.query.clickhouse_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.clickhouse_handler_port);
+        let tcp_keepalive_timeout_secs = conf.query.mysql_handler_tcp_keepalive_timeout_secs;
+        let mut handler = ClickHouseHandler::create(tcp_keepalive_timeout_secs)?;
+        let listening = handler.start(listening.parse()?).await?;
+        shutdown_handle.add_service(handler);
+
+        info!(
+            "Listening for ClickHouse native protocol: {}, Usage: clickhouse-client --host {} --port {}",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
     // ClickHouse HTTP handler.
     {
         let hostname = conf.query.clickhouse_http_handler_host.clone();
@@ -244,6 +280,7 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
     println!("    file: {}", conf.log.file);
     println!("    stderr: {}", conf.log.stderr);
     println!("    query: {}", conf.log.query);
+    println!("    audit: {}", conf.log.audit);
     println!("    tracing: {}", conf.log.tracing);
     println!(
         "Meta: {}",
@@ -300,6 +337,26 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         "    connect via: mysql -u${{USER}} -p${{PASSWORD}} -h{} -P{}",
         conf.query.mysql_handler_host, conf.query.mysql_handler_port
     );
+    println!("PostgreSQL");
+    println!(
+        "    listened at {}:{}",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
+    println!(
+        "    connect via: psql -U${{USER}} -h{} -p{}",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
+    if conf.query.clickhouse_handler_enabled {
+        println!("Clickhouse(native)");
+        println!(
+            "    listened at {}:{}",
+            conf.query.clickhouse_handler_host, conf.query.clickhouse_handler_port
+        );
+        println!(
+            "    connect via: clickhouse-client --host {} --port {}",
+            conf.query.clickhouse_handler_host, conf.query.clickhouse_handler_port
+        );
+    }
     println!("Clickhouse(http)");
     println!(
         "    listened at {}:{}",
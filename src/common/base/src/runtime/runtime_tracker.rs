@@ -50,6 +50,7 @@ use std::future::Future;
 use std::mem::take;
 use std::pin::Pin;
 use std::sync::atomic::AtomicI64;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::task::Context;
@@ -341,6 +342,9 @@ pub struct MemStat {
     /// Set to 0 to disable the limit.
     limit: AtomicI64,
 
+    /// The number of allocations rejected because of exceeding the limit of this tracker.
+    exceeded: AtomicU64,
+
     parent_memory_stat: Option<Arc<MemStat>>,
 }
 
@@ -351,6 +355,7 @@ impl MemStat {
             used: AtomicI64::new(0),
             limit: AtomicI64::new(0),
             peak_used: AtomicI64::new(0),
+            exceeded: AtomicU64::new(0),
             parent_memory_stat: None,
         }
     }
@@ -366,6 +371,7 @@ impl MemStat {
             used: AtomicI64::new(0),
             limit: AtomicI64::new(0),
             peak_used: AtomicI64::new(0),
+            exceeded: AtomicU64::new(0),
             parent_memory_stat,
         })
     }
@@ -381,6 +387,18 @@ impl MemStat {
         self.limit.store(size, Ordering::Relaxed);
     }
 
+    /// Get the limit of max used memory, 0 means no limit.
+    #[inline]
+    pub fn get_limit(&self) -> i64 {
+        self.limit.load(Ordering::Relaxed)
+    }
+
+    /// Get the number of allocations rejected by the limit of this tracker, not its ancestors.
+    #[inline]
+    pub fn get_exceeded_count(&self) -> u64 {
+        self.exceeded.load(Ordering::Relaxed)
+    }
+
     /// Feed memory usage stat to MemStat and return if it exceeds the limit.
     ///
     /// It feeds `state` to the this tracker and all of its ancestors, including GLOBAL_TRACKER.
@@ -422,6 +440,7 @@ impl MemStat {
 
         if let Err(cause) = mem_stat.check_limit(used) {
             if NEED_ROLLBACK {
+                mem_stat.exceeded.fetch_add(1, Ordering::Relaxed);
                 let used = mem_stat.used.fetch_sub(memory_usage, Ordering::Relaxed);
                 mem_stat
                     .peak_used
@@ -0,0 +1,179 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A cache whose eviction policy is chosen at runtime.
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+
+use crate::cache::lru::LruCache;
+use crate::cache::s3fifo::S3FifoCache;
+use crate::cache::Cache;
+use crate::meter::count_meter::CountableMeter;
+
+/// The eviction policies that a [`PolicyCache`] can be created with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Least recently used.
+    #[default]
+    Lru,
+    /// S3-FIFO, see [`S3FifoCache`], which is resistant to scans.
+    S3Fifo,
+}
+
+impl fmt::Display for EvictionPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvictionPolicy::Lru => write!(f, "lru"),
+            EvictionPolicy::S3Fifo => write!(f, "s3fifo"),
+        }
+    }
+}
+
+/// A cache that dispatches to the implementation of the chosen [`EvictionPolicy`].
+#[derive(Clone)]
+pub enum PolicyCache<K: Eq + Hash, V, S: BuildHasher + Clone, M: CountableMeter<K, V>> {
+    Lru(LruCache<K, V, S, M>),
+    S3Fifo(S3FifoCache<K, V, S, M>),
+}
+
+macro_rules! dispatch {
+    ($cache: expr, $c: ident => $e: expr) => {
+        match $cache {
+            PolicyCache::Lru($c) => $e,
+            PolicyCache::S3Fifo($c) => $e,
+        }
+    };
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone, M: CountableMeter<K, V>> PolicyCache<K, V, S, M> {
+    /// Creates an empty cache of `policy`, that can hold at most `capacity` as measured by
+    /// `meter` with the given hash builder.
+    pub fn with_policy(policy: EvictionPolicy, capacity: u64, meter: M, hash_builder: S) -> Self {
+        match policy {
+            EvictionPolicy::Lru => PolicyCache::Lru(LruCache::with_meter_and_hasher(
+                capacity,
+                meter,
+                hash_builder,
+            )),
+            EvictionPolicy::S3Fifo => PolicyCache::S3Fifo(S3FifoCache::with_meter_and_hasher(
+                capacity,
+                meter,
+                hash_builder,
+            )),
+        }
+    }
+
+    pub fn policy(&self) -> EvictionPolicy {
+        match self {
+            PolicyCache::Lru(_) => EvictionPolicy::Lru,
+            PolicyCache::S3Fifo(_) => EvictionPolicy::S3Fifo,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone, M: CountableMeter<K, V>> Cache<K, V, S, M>
+    for PolicyCache<K, V, S, M>
+{
+    /// Creates an empty LRU cache, use [`PolicyCache::with_policy`] to choose another policy.
+    fn with_meter_and_hasher(capacity: u64, meter: M, hash_builder: S) -> Self {
+        Self::with_policy(EvictionPolicy::Lru, capacity, meter, hash_builder)
+    }
+
+    fn get<Q>(&mut self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        dispatch!(self, c => c.get(k))
+    }
+
+    fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        dispatch!(self, c => c.get_mut(k))
+    }
+
+    fn peek<'a, Q>(&'a self, k: &Q) -> Option<&'a V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        dispatch!(self, c => c.peek(k))
+    }
+
+    fn peek_mut<'a, Q>(&'a mut self, k: &Q) -> Option<&'a mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        dispatch!(self, c => c.peek_mut(k))
+    }
+
+    fn peek_by_policy(&self) -> Option<(&K, &V)> {
+        dispatch!(self, c => c.peek_by_policy())
+    }
+
+    fn put(&mut self, k: K, v: V) -> Option<V> {
+        dispatch!(self, c => c.put(k, v))
+    }
+
+    fn pop<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        dispatch!(self, c => c.pop(k))
+    }
+
+    fn pop_by_policy(&mut self) -> Option<(K, V)> {
+        dispatch!(self, c => c.pop_by_policy())
+    }
+
+    fn contains<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        dispatch!(self, c => c.contains(k))
+    }
+
+    fn len(&self) -> usize {
+        dispatch!(self, c => c.len())
+    }
+
+    fn is_empty(&self) -> bool {
+        dispatch!(self, c => c.is_empty())
+    }
+
+    fn capacity(&self) -> u64 {
+        dispatch!(self, c => c.capacity())
+    }
+
+    fn set_capacity(&mut self, capacity: u64) {
+        dispatch!(self, c => c.set_capacity(capacity))
+    }
+
+    fn size(&self) -> u64 {
+        dispatch!(self, c => c.size())
+    }
+
+    fn clear(&mut self) {
+        dispatch!(self, c => c.clear())
+    }
+}
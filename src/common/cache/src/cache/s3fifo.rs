@@ -0,0 +1,359 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A cache that holds a limited number of key-value pairs, evicted by the S3-FIFO policy
+//! ("FIFO queues are all you need for cache eviction", SOSP 2023).
+//!
+//! New items are admitted into a small FIFO queue (10% of the capacity). Items which are
+//! accessed again while staying in the small queue are promoted into the main queue when
+//! they reach the head, the others are evicted, and only their keys (hashes) are remembered
+//! in a ghost queue. Items whose keys are found in the ghost queue are put into the main queue
+//! directly. The main queue is a FIFO queue with re-insertion, items accessed since the last
+//! time they reach the head are given another chance.
+//!
+//! Since an item which is only accessed once never leaves the small queue, a large scan can
+//! not flush the frequently accessed items out of the main queue.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use common_cache::{Cache, S3FifoCache};
+//!
+//! let mut cache = S3FifoCache::new(10);
+//!
+//! cache.put(1, 10);
+//! assert_eq!(cache.get(&1), Some(&10));
+//! ```
+
+use std::borrow::Borrow;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+
+use ritelinked::DefaultHashBuilder;
+use ritelinked::LinkedHashMap;
+
+use crate::cache::Cache;
+use crate::meter::count_meter::Count;
+use crate::meter::count_meter::CountableMeter;
+
+/// The access frequency of an item is capped at this value.
+const MAX_FREQUENCY: u8 = 3;
+
+/// Percentage of the capacity taken by the small queue.
+const SMALL_QUEUE_PERCENTAGE: u64 = 10;
+
+#[derive(Clone)]
+struct Entry<V> {
+    value: V,
+    freq: u8,
+}
+
+impl<V> Entry<V> {
+    fn new(value: V) -> Self {
+        Entry { value, freq: 0 }
+    }
+}
+
+/// An S3-FIFO cache.
+#[derive(Clone)]
+pub struct S3FifoCache<
+    K: Eq + Hash,
+    V,
+    S: BuildHasher + Clone = DefaultHashBuilder,
+    M: CountableMeter<K, V> = Count,
+> {
+    small: LinkedHashMap<K, Entry<V>, S>,
+    main: LinkedHashMap<K, Entry<V>, S>,
+    // hashes of the keys recently evicted from the small queue
+    ghost: LinkedHashMap<u64, ()>,
+    hash_builder: S,
+    small_measure: M::Measure,
+    current_measure: M::Measure,
+    max_capacity: u64,
+    meter: M,
+}
+
+impl<K: Eq + Hash, V> S3FifoCache<K, V> {
+    /// Creates an empty cache that can hold at most `capacity` items.
+    pub fn new(capacity: u64) -> Self {
+        Self::with_meter_and_hasher(capacity, Count, DefaultHashBuilder::default())
+    }
+}
+
+impl<K: Eq + Hash, V, M: CountableMeter<K, V>> S3FifoCache<K, V, DefaultHashBuilder, M> {
+    /// Creates an empty cache that can hold at most `capacity` as measured by `meter`.
+    pub fn with_meter(capacity: u64, meter: M) -> Self {
+        Self::with_meter_and_hasher(capacity, meter, DefaultHashBuilder::default())
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone, M: CountableMeter<K, V>> S3FifoCache<K, V, S, M> {
+    fn small_size(&self) -> u64 {
+        self.meter
+            .size(self.small_measure)
+            .unwrap_or(self.small.len() as u64)
+    }
+
+    fn small_capacity(&self) -> u64 {
+        (self.max_capacity * SMALL_QUEUE_PERCENTAGE / 100).max(1)
+    }
+
+    fn key_hash<Q>(&self, k: &Q) -> u64
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.hash_builder.hash_one(k)
+    }
+
+    fn remember_evicted(&mut self, hash: u64) {
+        self.ghost.insert(hash, ());
+        // the ghost queue holds as many keys as the cache does
+        let max_ghost_len = self.len().max(1);
+        while self.ghost.len() > max_ghost_len {
+            self.ghost.pop_front();
+        }
+    }
+
+    fn evict_overflow(&mut self) {
+        while self.size() > self.capacity() {
+            if self.pop_by_policy().is_none() {
+                break;
+            }
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone, M: CountableMeter<K, V>> Cache<K, V, S, M>
+    for S3FifoCache<K, V, S, M>
+{
+    /// Creates an empty cache that can hold at most `capacity` as measured by `meter` with the
+    /// given hash builder.
+    fn with_meter_and_hasher(capacity: u64, meter: M, hash_builder: S) -> Self {
+        S3FifoCache {
+            small: LinkedHashMap::with_hasher(hash_builder.clone()),
+            main: LinkedHashMap::with_hasher(hash_builder.clone()),
+            ghost: LinkedHashMap::new(),
+            hash_builder,
+            small_measure: Default::default(),
+            current_measure: Default::default(),
+            max_capacity: capacity,
+            meter,
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the given key in the cache, if
+    /// any. The access frequency of the item is increased.
+    fn get<Q>(&mut self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_mut(k).map(|v| v as &V)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the given key in the cache, if
+    /// any. The access frequency of the item is increased.
+    fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = if self.small.contains_key(k) {
+            self.small.get_mut(k)
+        } else {
+            self.main.get_mut(k)
+        }?;
+        entry.freq = (entry.freq + 1).min(MAX_FREQUENCY);
+        Some(&mut entry.value)
+    }
+
+    /// Returns a reference to the value corresponding to the key in the cache or `None` if it is
+    /// not present in the cache. Unlike `get`, `peek` does not update the access frequency.
+    fn peek<'a, Q>(&'a self, k: &Q) -> Option<&'a V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.small
+            .get(k)
+            .or_else(|| self.main.get(k))
+            .map(|entry| &entry.value)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key in the cache or `None`
+    /// if it is not present in the cache. Unlike `get_mut`, `peek_mut` does not update the
+    /// access frequency.
+    fn peek_mut<'a, Q>(&'a mut self, k: &Q) -> Option<&'a mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = if self.small.contains_key(k) {
+            self.small.get_mut(k)
+        } else {
+            self.main.get_mut(k)
+        };
+        entry.map(|entry| &mut entry.value)
+    }
+
+    /// Returns the item at the head of the queue that will be evicted from next, or `None` if
+    /// the cache is empty. Note that the item may be given another chance (and not evicted) if
+    /// it has been accessed.
+    fn peek_by_policy(&self) -> Option<(&K, &V)> {
+        let front = if !self.small.is_empty()
+            && (self.small_size() >= self.small_capacity() || self.main.is_empty())
+        {
+            self.small.front()
+        } else {
+            self.main.front()
+        };
+        front.map(|(k, entry)| (k, &entry.value))
+    }
+
+    /// Inserts a key-value pair into the cache. If the key already existed, the old value is
+    /// returned.
+    fn put(&mut self, k: K, v: V) -> Option<V> {
+        let new_size = self.meter.measure(&k, &v);
+        let old_val = if let Some(entry) = self.small.get_mut(&k) {
+            let old_size = self.meter.measure(&k, &entry.value);
+            self.small_measure = self
+                .meter
+                .sub(self.meter.add(self.small_measure, new_size), old_size);
+            self.current_measure = self
+                .meter
+                .sub(self.meter.add(self.current_measure, new_size), old_size);
+            Some(std::mem::replace(&mut entry.value, v))
+        } else if let Some(entry) = self.main.get_mut(&k) {
+            let old_size = self.meter.measure(&k, &entry.value);
+            self.current_measure = self
+                .meter
+                .sub(self.meter.add(self.current_measure, new_size), old_size);
+            Some(std::mem::replace(&mut entry.value, v))
+        } else {
+            self.current_measure = self.meter.add(self.current_measure, new_size);
+            let hash = self.key_hash(&k);
+            if self.ghost.remove(&hash).is_some() {
+                self.main.insert(k, Entry::new(v));
+            } else {
+                self.small_measure = self.meter.add(self.small_measure, new_size);
+                self.small.insert(k, Entry::new(v));
+            }
+            None
+        };
+        self.evict_overflow();
+        old_val
+    }
+
+    /// Removes the given key from the cache and returns its corresponding value.
+    fn pop<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(entry) = self.small.remove(k) {
+            let size = self.meter.measure(k, &entry.value);
+            self.small_measure = self.meter.sub(self.small_measure, size);
+            self.current_measure = self.meter.sub(self.current_measure, size);
+            Some(entry.value)
+        } else {
+            self.main.remove(k).map(|entry| {
+                let size = self.meter.measure(k, &entry.value);
+                self.current_measure = self.meter.sub(self.current_measure, size);
+                entry.value
+            })
+        }
+    }
+
+    /// Evicts and returns a key-value pair by the S3-FIFO policy. The items visited in the way
+    /// may be moved from the small queue to the main queue, or re-inserted into the main queue.
+    fn pop_by_policy(&mut self) -> Option<(K, V)> {
+        loop {
+            if !self.small.is_empty()
+                && (self.small_size() >= self.small_capacity() || self.main.is_empty())
+            {
+                let (k, entry) = self.small.pop_front()?;
+                let size = self.meter.measure(&k, &entry.value);
+                self.small_measure = self.meter.sub(self.small_measure, size);
+                if entry.freq > 0 {
+                    self.main.insert(k, Entry::new(entry.value));
+                    continue;
+                }
+                self.current_measure = self.meter.sub(self.current_measure, size);
+                let hash = self.key_hash(&k);
+                self.remember_evicted(hash);
+                return Some((k, entry.value));
+            }
+
+            let (k, mut entry) = self.main.pop_front()?;
+            if entry.freq > 0 {
+                entry.freq -= 1;
+                self.main.insert(k, entry);
+                continue;
+            }
+            let size = self.meter.measure(&k, &entry.value);
+            self.current_measure = self.meter.sub(self.current_measure, size);
+            return Some((k, entry.value));
+        }
+    }
+
+    /// Checks if the map contains the given key.
+    fn contains<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.small.contains_key(k) || self.main.contains_key(k)
+    }
+
+    /// Returns the number of key-value pairs in the cache.
+    fn len(&self) -> usize {
+        self.small.len() + self.main.len()
+    }
+
+    /// Returns `true` if the cache contains no key-value pairs.
+    fn is_empty(&self) -> bool {
+        self.small.is_empty() && self.main.is_empty()
+    }
+
+    /// Returns the maximum size of the key-value pairs the cache can hold, as measured by the
+    /// `Meter` used by the cache.
+    fn capacity(&self) -> u64 {
+        self.max_capacity
+    }
+
+    /// Sets the size of the key-value pairs the cache can hold, as measured by the `Meter` used by
+    /// the cache. Evicts items by the policy if necessary.
+    fn set_capacity(&mut self, capacity: u64) {
+        self.max_capacity = capacity;
+        self.evict_overflow();
+    }
+
+    /// Returns the size of all the key-value pairs in the cache, as measured by the `Meter` used
+    /// by the cache.
+    fn size(&self) -> u64 {
+        self.meter
+            .size(self.current_measure)
+            .unwrap_or_else(|| self.len() as u64)
+    }
+
+    /// Removes all key-value pairs from the cache.
+    fn clear(&mut self) {
+        self.small.clear();
+        self.main.clear();
+        self.ghost.clear();
+        self.small_measure = Default::default();
+        self.current_measure = Default::default();
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+
+use common_cache::Cache;
+use common_cache::Count;
+use common_cache::DefaultHashBuilder;
+use common_cache::EvictionPolicy;
+use common_cache::Meter;
+use common_cache::PolicyCache;
+use common_cache::S3FifoCache;
+
+#[test]
+fn test_s3fifo_put_and_get() {
+    let mut cache = S3FifoCache::new(2);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    assert_eq!(cache.get_mut(&1), Some(&mut 10));
+    assert_eq!(cache.get(&2), Some(&20));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.size(), 2);
+
+    cache.put(2, 22);
+    assert_eq!(cache.peek(&2), Some(&22));
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn test_s3fifo_pop_and_clear() {
+    let mut cache = S3FifoCache::new(3);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    assert_eq!(cache.pop(&1), Some(10));
+    assert_eq!(cache.pop(&1), None);
+    assert!(!cache.contains(&1));
+    assert_eq!(cache.len(), 1);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.size(), 0);
+}
+
+#[test]
+fn test_s3fifo_scan_resistant() {
+    let mut cache = S3FifoCache::new(10);
+    for i in 0..10 {
+        cache.put(i, i);
+    }
+    // items 0 to 8 are accessed again, and will be promoted to the main queue
+    for i in 0..9 {
+        assert_eq!(cache.get(&i), Some(&i));
+    }
+    cache.put(10, 10);
+    assert_eq!(cache.len(), 10);
+    assert!(!cache.contains(&9));
+
+    // a large scan, the items of which are accessed only once
+    for i in 100..1000 {
+        cache.put(i, i);
+    }
+    assert_eq!(cache.len(), 10);
+    for i in 0..9 {
+        assert!(cache.contains(&i));
+    }
+
+    // an lru cache loses all of them
+    let mut lru = PolicyCache::with_policy(
+        EvictionPolicy::Lru,
+        10,
+        Count,
+        DefaultHashBuilder::default(),
+    );
+    for i in 0..10 {
+        lru.put(i, i);
+        lru.get(&i);
+    }
+    for i in 100..1000 {
+        lru.put(i, i);
+    }
+    for i in 0..10 {
+        assert!(!lru.contains(&i));
+    }
+}
+
+#[test]
+fn test_s3fifo_ghost() {
+    let mut cache = S3FifoCache::new(10);
+    for i in 0..11 {
+        cache.put(i, i);
+    }
+    // item 0 is evicted from the small queue, without being accessed
+    assert!(!cache.contains(&0));
+
+    // it is remembered, and put into the main queue directly
+    cache.put(0, 0);
+    assert_eq!(cache.len(), 10);
+    for i in 100..1000 {
+        cache.put(i, i);
+    }
+    assert!(cache.contains(&0));
+}
+
+#[test]
+fn test_s3fifo_set_capacity() {
+    let mut cache = S3FifoCache::new(4);
+    for i in 0..4 {
+        cache.put(i, i);
+    }
+    cache.set_capacity(2);
+    assert_eq!(cache.capacity(), 2);
+    assert_eq!(cache.len(), 2);
+    assert!(cache.contains(&3));
+}
+
+struct VecLen;
+
+impl<K, T> Meter<K, Vec<T>> for VecLen {
+    type Measure = usize;
+    fn measure<Q: ?Sized>(&self, _: &Q, v: &Vec<T>) -> usize
+    where K: Borrow<Q> {
+        v.len()
+    }
+}
+
+#[test]
+fn test_s3fifo_metered_cache() {
+    let mut cache = S3FifoCache::with_meter(10, VecLen);
+    cache.put("foo1", vec![1, 2, 3, 4]);
+    cache.put("foo2", vec![5, 6, 7, 8]);
+    assert_eq!(cache.size(), 8);
+    assert!(cache.get("foo1").is_some());
+
+    cache.put("foo3", vec![9, 10, 11, 12]);
+    assert_eq!(cache.size(), 8);
+    assert!(cache.contains("foo1"));
+    assert!(!cache.contains("foo2"));
+    assert!(cache.contains("foo3"));
+
+    // reinsert a larger one
+    cache.put("foo1", vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(cache.size(), 10);
+    assert_eq!(cache.pop("foo1"), Some(vec![1, 2, 3, 4, 5, 6]));
+    assert_eq!(cache.size(), 4);
+}
+
+#[test]
+fn test_policy_cache() {
+    for policy in [EvictionPolicy::Lru, EvictionPolicy::S3Fifo] {
+        let mut cache = PolicyCache::with_policy(policy, 2, Count, DefaultHashBuilder::default());
+        assert_eq!(cache.policy(), policy);
+        cache.put(1, 10);
+        cache.put(2, 20);
+        cache.put(3, 30);
+        assert_eq!(cache.len(), 2);
+        assert!(!cache.contains(&1));
+        assert_eq!(cache.get(&3), Some(&30));
+        assert_eq!(cache.pop_by_policy(), Some((2, 20)));
+    }
+
+    let cache: PolicyCache<i32, i32, _, _> =
+        PolicyCache::with_meter_and_hasher(2, Count, DefaultHashBuilder::default());
+    assert_eq!(cache.policy(), EvictionPolicy::Lru);
+    assert_eq!(EvictionPolicy::Lru.to_string(), "lru");
+    assert_eq!(EvictionPolicy::S3Fifo.to_string(), "s3fifo");
+}
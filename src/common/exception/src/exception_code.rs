@@ -146,6 +146,7 @@ build_exceptions! {
     // The table is not a clustered table.
     UnclusteredTable(1118),
     UnknownCatalog(11119),
+    UnknownRowAccessPolicy(1120),
 
     // Data Related Errors
 
@@ -167,6 +168,30 @@ build_exceptions! {
     ///
     /// For example: try to with 3 columns into a table with 4 columns.
     TableSchemaMismatch(1303),
+    /// InvalidPartitionKey is used when the partition key of a table is invalid.
+    ///
+    /// For example: partition by a non-deterministic expression.
+    InvalidPartitionKey(1304),
+    /// UnknownPartition is used when the given partition does not exist in the table.
+    UnknownPartition(1305),
+    /// UnpartitionedTable is used when a partition operation is applied to
+    /// a table without partition key.
+    UnpartitionedTable(1306),
+    /// InvalidTableTtl is used when the TTL expression of a table is invalid.
+    ///
+    /// For example: a TTL expression which is not a timestamp.
+    InvalidTableTtl(1307),
+    /// InvalidTableConstraint is used when a key constraint of a table is invalid.
+    ///
+    /// For example: a primary key on a nullable column.
+    InvalidTableConstraint(1308),
+    /// UniqueConstraintViolation is used when a write would store duplicated
+    /// values of a primary key or unique key.
+    UniqueConstraintViolation(1309),
+    /// InvalidRowAccessPolicy is used when a row access policy can't be applied to a table.
+    ///
+    /// For example: the number of bound columns doesn't match the args of the policy.
+    InvalidRowAccessPolicy(1310),
 
     // License related errors starts here
 
@@ -216,6 +241,15 @@ build_exceptions! {
     NetworkPolicyAlreadyExists(2208),
     IllegalNetworkPolicy(2209),
     NetworkPolicyIsUsedByUser(2210),
+    UnknownWorkloadGroup(2211),
+    WorkloadGroupAlreadyExists(2212),
+    IllegalWorkloadGroup(2213),
+    WorkloadGroupIsUsed(2214),
+    UnknownPasswordPolicy(2215),
+    PasswordPolicyAlreadyExists(2216),
+    IllegalPasswordPolicy(2217),
+    PasswordPolicyIsUsedByUser(2218),
+    InvalidPassword(2219),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -245,6 +279,8 @@ build_exceptions! {
     CatalogNotFound(2320),
     /// data mask error codes
     DatamaskAlreadyExists(2321),
+    /// row access policy error codes
+    RowAccessPolicyAlreadyExists(2322),
 
 
     // Cluster error codes.
@@ -307,6 +343,21 @@ build_exceptions! {
     TenantQuotaUnknown(2902),
     TenantQuotaExceeded(2903),
 
+    // User quota error codes.
+    UserMemoryQuotaExceeded(2911),
+    UserStorageQuotaExceeded(2912),
+
+    // Workload group error codes.
+    WorkloadGroupQueueTimeout(2921),
+
+    // Resource usage error codes.
+    IllegalResourceUsageFormat(2931),
+    ResourceUsageConflict(2932),
+
+    // Audit log error codes.
+    IllegalAuditChainFormat(2941),
+    AuditChainConflict(2942),
+
 }
 
 // Storage errors [3001, 4000].
@@ -317,6 +368,8 @@ build_exceptions! {
     StorageUnsupported(3902),
     StorageInsecure(3903),
     DeprecatedIndexFormat(3904),
+    InvalidEncryptionKey(3905),
+    DecryptionError(3906),
     StorageOther(4000),
     UnresolvableConflict(4001),
 }
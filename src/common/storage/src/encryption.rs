@@ -0,0 +1,746 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client side encryption of the objects written by the fuse tables.
+//!
+//! Every encrypted table owns a random data key, which is wrapped by a master key and stored in
+//! the table options. The master keys are loaded from the config, or from a directory of key
+//! files standing in for a KMS. Rotating the master key only re-wraps the data keys, the objects
+//! already written are left untouched.
+//!
+//! The objects are sealed with AES-256-GCM in chunks of `ENCRYPTION_CHUNK_SIZE` bytes, in the
+//! layout of `MAGIC | NONCE PREFIX | CHUNK_0 | TAG_0 | CHUNK_1 | TAG_1 ...`. The nonce of a chunk
+//! is the random prefix of the object followed by the index of the chunk, and the last chunk is
+//! sealed with a distinct AAD so that a truncated object fails to decrypt. A range read only
+//! loads and decrypts the chunks covering the range.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose;
+use base64::Engine;
+use bytes::Bytes;
+use common_base::base::GlobalInstance;
+use common_exception::ErrorCode;
+use opendal::raw::oio;
+use opendal::raw::oio::ReadExt;
+use opendal::raw::Accessor;
+use opendal::raw::BytesRange;
+use opendal::raw::Layer;
+use opendal::raw::LayeredAccessor;
+use opendal::raw::OpAppend;
+use opendal::raw::OpList;
+use opendal::raw::OpRead;
+use opendal::raw::OpStat;
+use opendal::raw::OpWrite;
+use opendal::raw::RpAppend;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpStat;
+use opendal::raw::RpWrite;
+use opendal::Error;
+use opendal::ErrorKind;
+use opendal::Result;
+use ring::aead::Aad;
+use ring::aead::LessSafeKey;
+use ring::aead::Nonce;
+use ring::aead::UnboundKey;
+use ring::aead::AES_256_GCM;
+use ring::aead::NONCE_LEN;
+use ring::rand::SecureRandom;
+use ring::rand::SystemRandom;
+
+/// The only encryption algorithm supported by the `encryption` table option.
+pub const ENCRYPTION_AES_256_GCM: &str = "aes_256_gcm";
+
+const MAGIC: &[u8; 4] = b"DBE1";
+const KEY_LEN: usize = 32;
+const TAG_LEN: usize = 16;
+
+/// The bytes a wrapped key is larger than its plaintext.
+pub const ENCRYPTION_OVERHEAD: usize = MAGIC.len() + NONCE_LEN + TAG_LEN;
+
+/// The size of the plaintext chunks the objects are sealed in.
+pub const ENCRYPTION_CHUNK_SIZE: usize = 64 * 1024;
+
+const OBJECT_MAGIC: &[u8; 4] = b"DBE2";
+// The last 4 bytes of the nonce of a chunk are the index of the chunk.
+const NONCE_PREFIX_LEN: usize = NONCE_LEN - 4;
+const OBJECT_HEADER_LEN: usize = OBJECT_MAGIC.len() + NONCE_PREFIX_LEN;
+const SEALED_CHUNK_SIZE: usize = ENCRYPTION_CHUNK_SIZE + TAG_LEN;
+
+/// The length of the encrypted object of `plaintext_len` bytes.
+pub fn encrypted_len(plaintext_len: u64) -> u64 {
+    let chunks = plaintext_len.div_ceil(ENCRYPTION_CHUNK_SIZE as u64).max(1);
+    OBJECT_HEADER_LEN as u64 + plaintext_len + chunks * TAG_LEN as u64
+}
+
+fn plaintext_len(encrypted_len: u64) -> u64 {
+    let sealed = encrypted_len.saturating_sub(OBJECT_HEADER_LEN as u64);
+    let chunks = sealed.div_ceil(SEALED_CHUNK_SIZE as u64);
+    sealed.saturating_sub(chunks * TAG_LEN as u64)
+}
+
+// The offset of the sealed chunk in the encrypted object.
+fn sealed_chunk_offset(index: u64) -> u64 {
+    OBJECT_HEADER_LEN as u64 + index * SEALED_CHUNK_SIZE as u64
+}
+
+/// The key the objects of a table are encrypted with.
+pub struct DataKey {
+    key: LessSafeKey,
+}
+
+impl Debug for DataKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DataKey").finish_non_exhaustive()
+    }
+}
+
+impl DataKey {
+    fn try_create(key: &[u8]) -> common_exception::Result<Self> {
+        let key = UnboundKey::new(&AES_256_GCM, key).map_err(|_| {
+            ErrorCode::InvalidEncryptionKey(format!("encryption key must be {KEY_LEN} bytes"))
+        })?;
+        Ok(Self {
+            key: LessSafeKey::new(key),
+        })
+    }
+
+    pub fn encrypt(&self, plaintext: &[u8]) -> common_exception::Result<Vec<u8>> {
+        let mut nonce = [0u8; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| ErrorCode::DecryptionError("failed to generate the nonce"))?;
+
+        let mut in_out = plaintext.to_vec();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut in_out,
+            )
+            .map_err(|_| ErrorCode::DecryptionError("failed to encrypt the data"))?;
+
+        let mut data = Vec::with_capacity(plaintext.len() + ENCRYPTION_OVERHEAD);
+        data.extend_from_slice(MAGIC);
+        data.extend_from_slice(&nonce);
+        data.extend_from_slice(&in_out);
+        Ok(data)
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> common_exception::Result<Vec<u8>> {
+        if data.len() < ENCRYPTION_OVERHEAD || &data[..MAGIC.len()] != MAGIC {
+            return Err(ErrorCode::DecryptionError(
+                "the data is not encrypted by databend",
+            ));
+        }
+        let (nonce, ciphertext) = data[MAGIC.len()..].split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce)
+            .map_err(|_| ErrorCode::DecryptionError("invalid nonce of the encrypted data"))?;
+
+        let mut in_out = ciphertext.to_vec();
+        let plaintext_len = self
+            .key
+            .open_in_place(nonce, Aad::empty(), &mut in_out)
+            .map_err(|_| {
+                ErrorCode::DecryptionError("failed to decrypt the data, the key may be wrong")
+            })?
+            .len();
+        in_out.truncate(plaintext_len);
+        Ok(in_out)
+    }
+
+    fn chunk_nonce(prefix: &[u8; NONCE_PREFIX_LEN], index: u64) -> Result<Nonce> {
+        let index = u32::try_from(index).map_err(|_| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "encrypted object has too many chunks",
+            )
+        })?;
+        let mut nonce = [0u8; NONCE_LEN];
+        nonce[..NONCE_PREFIX_LEN].copy_from_slice(prefix);
+        nonce[NONCE_PREFIX_LEN..].copy_from_slice(&index.to_be_bytes());
+        Ok(Nonce::assume_unique_for_key(nonce))
+    }
+
+    fn chunk_aad(is_last: bool) -> Aad<[u8; 1]> {
+        Aad::from([is_last as u8])
+    }
+
+    /// Encrypt the plaintext into an object sealed in chunks.
+    fn seal_object(&self, plaintext: &[u8]) -> Result<Vec<u8>> {
+        let mut prefix = [0u8; NONCE_PREFIX_LEN];
+        SystemRandom::new()
+            .fill(&mut prefix)
+            .map_err(|_| Error::new(ErrorKind::Unexpected, "failed to generate the nonce"))?;
+
+        let mut data = Vec::with_capacity(encrypted_len(plaintext.len() as u64) as usize);
+        data.extend_from_slice(OBJECT_MAGIC);
+        data.extend_from_slice(&prefix);
+
+        // An empty object still has a chunk, so that its last chunk can be verified.
+        let chunks = plaintext.len().div_ceil(ENCRYPTION_CHUNK_SIZE).max(1);
+        for index in 0..chunks {
+            let start = index * ENCRYPTION_CHUNK_SIZE;
+            let end = (start + ENCRYPTION_CHUNK_SIZE).min(plaintext.len());
+            let chunk_start = data.len();
+            data.extend_from_slice(&plaintext[start..end]);
+            let tag = self
+                .key
+                .seal_in_place_separate_tag(
+                    Self::chunk_nonce(&prefix, index as u64)?,
+                    Self::chunk_aad(index == chunks - 1),
+                    &mut data[chunk_start..],
+                )
+                .map_err(|_| Error::new(ErrorKind::Unexpected, "failed to encrypt the data"))?;
+            data.extend_from_slice(tag.as_ref());
+        }
+        Ok(data)
+    }
+
+    /// Decrypt the sealed chunks starting from the chunk `first`. If `has_last` is set, the last
+    /// of them must be the last chunk of the object.
+    fn open_chunks(
+        &self,
+        prefix: &[u8; NONCE_PREFIX_LEN],
+        first: u64,
+        mut data: Vec<u8>,
+        has_last: bool,
+    ) -> Result<Vec<u8>> {
+        if data.is_empty() && has_last {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "the encrypted object is truncated",
+            ));
+        }
+
+        let chunks = data.len().div_ceil(SEALED_CHUNK_SIZE);
+        let mut plaintext_len = 0;
+        for index in 0..chunks {
+            let start = index * SEALED_CHUNK_SIZE;
+            let end = (start + SEALED_CHUNK_SIZE).min(data.len());
+            let opened = self
+                .key
+                .open_in_place(
+                    Self::chunk_nonce(prefix, first + index as u64)?,
+                    Self::chunk_aad(has_last && index == chunks - 1),
+                    &mut data[start..end],
+                )
+                .map_err(|_| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        "failed to decrypt the data, the key may be wrong or the object is damaged",
+                    )
+                })?
+                .len();
+            // Move the plaintext of the chunk next to the plaintext of the previous chunks.
+            data.copy_within(start..start + opened, plaintext_len);
+            plaintext_len += opened;
+        }
+        data.truncate(plaintext_len);
+        Ok(data)
+    }
+}
+
+fn nonce_prefix(header: &[u8]) -> Result<[u8; NONCE_PREFIX_LEN]> {
+    if header.len() < OBJECT_HEADER_LEN || &header[..OBJECT_MAGIC.len()] != OBJECT_MAGIC {
+        return Err(Error::new(
+            ErrorKind::Unexpected,
+            "the object is not encrypted by databend",
+        ));
+    }
+    let mut prefix = [0u8; NONCE_PREFIX_LEN];
+    prefix.copy_from_slice(&header[OBJECT_MAGIC.len()..OBJECT_HEADER_LEN]);
+    Ok(prefix)
+}
+
+/// The master keys wrapping the data keys of the tables, by the key id.
+///
+/// The data keys are always wrapped by the current master key, the other keys are kept to unwrap
+/// the data keys wrapped before the rotation.
+pub struct MasterKeys {
+    current_key_id: String,
+    keys: HashMap<String, DataKey>,
+}
+
+impl MasterKeys {
+    pub fn init(
+        current_key_id: &str,
+        keys: &str,
+        kms_key_dir: &str,
+    ) -> common_exception::Result<()> {
+        GlobalInstance::set(Arc::new(Self::try_create(
+            current_key_id,
+            keys,
+            kms_key_dir,
+        )?));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<MasterKeys> {
+        GlobalInstance::get()
+    }
+
+    /// Load the master keys from `keys`, a comma separated list of `<key_id>:<base64 key>`, and
+    /// the `<key_id>.key` files in `kms_key_dir` which contain the base64 encoded keys.
+    pub fn try_create(
+        current_key_id: &str,
+        keys: &str,
+        kms_key_dir: &str,
+    ) -> common_exception::Result<Self> {
+        let mut master_keys = HashMap::new();
+        for item in keys.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()) {
+            let (key_id, key) = item.split_once(':').ok_or_else(|| {
+                ErrorCode::InvalidEncryptionKey(
+                    "master key must be in the format of `<key_id>:<base64 key>`",
+                )
+            })?;
+            master_keys.insert(key_id.to_string(), Self::decode_key(key_id, key)?);
+        }
+
+        if !kms_key_dir.is_empty() {
+            for entry in std::fs::read_dir(kms_key_dir)? {
+                let path = entry?.path();
+                if path.extension().and_then(|s| s.to_str()) != Some("key") {
+                    continue;
+                }
+                let key_id = Self::key_id_of_file(&path)?;
+                let key = std::fs::read_to_string(&path)?;
+                master_keys.insert(key_id.clone(), Self::decode_key(&key_id, key.trim())?);
+            }
+        }
+
+        if !current_key_id.is_empty() && !master_keys.contains_key(current_key_id) {
+            return Err(ErrorCode::InvalidEncryptionKey(format!(
+                "master key {current_key_id} not found"
+            )));
+        }
+
+        Ok(Self {
+            current_key_id: current_key_id.to_string(),
+            keys: master_keys,
+        })
+    }
+
+    fn key_id_of_file(path: &Path) -> common_exception::Result<String> {
+        path.file_stem()
+            .and_then(|s| s.to_str())
+            .map(|s| s.to_string())
+            .ok_or_else(|| {
+                ErrorCode::InvalidEncryptionKey(format!("invalid key file {}", path.display()))
+            })
+    }
+
+    fn decode_key(key_id: &str, key: &str) -> common_exception::Result<DataKey> {
+        let key = general_purpose::STANDARD.decode(key).map_err(|e| {
+            ErrorCode::InvalidEncryptionKey(format!("master key {key_id} is not base64: {e}"))
+        })?;
+        DataKey::try_create(&key)
+    }
+
+    fn current_key(&self) -> common_exception::Result<(&str, &DataKey)> {
+        if self.current_key_id.is_empty() {
+            return Err(ErrorCode::InvalidEncryptionKey(
+                "encryption is not enabled, no master key is configured",
+            ));
+        }
+        Ok((&self.current_key_id, &self.keys[&self.current_key_id]))
+    }
+
+    pub fn current_key_id(&self) -> &str {
+        &self.current_key_id
+    }
+
+    /// Generate a new data key, returns it wrapped by the current master key, in the format of
+    /// `<master_key_id>:<base64 wrapped key>`.
+    pub fn generate_data_key(&self) -> common_exception::Result<String> {
+        let mut data_key = [0u8; KEY_LEN];
+        SystemRandom::new()
+            .fill(&mut data_key)
+            .map_err(|_| ErrorCode::InvalidEncryptionKey("failed to generate the data key"))?;
+        self.wrap_data_key(&data_key)
+    }
+
+    fn wrap_data_key(&self, data_key: &[u8]) -> common_exception::Result<String> {
+        let (key_id, master_key) = self.current_key()?;
+        let wrapped = master_key.encrypt(data_key)?;
+        Ok(format!(
+            "{}:{}",
+            key_id,
+            general_purpose::STANDARD.encode(wrapped)
+        ))
+    }
+
+    fn unwrap_data_key_bytes(&self, wrapped: &str) -> common_exception::Result<Vec<u8>> {
+        let (key_id, wrapped) = wrapped
+            .split_once(':')
+            .ok_or_else(|| ErrorCode::InvalidEncryptionKey("invalid wrapped data key"))?;
+        let master_key = self.keys.get(key_id).ok_or_else(|| {
+            ErrorCode::InvalidEncryptionKey(format!("master key {key_id} not found"))
+        })?;
+        let wrapped = general_purpose::STANDARD.decode(wrapped).map_err(|e| {
+            ErrorCode::InvalidEncryptionKey(format!("invalid wrapped data key: {e}"))
+        })?;
+        master_key.decrypt(&wrapped)
+    }
+
+    pub fn unwrap_data_key(&self, wrapped: &str) -> common_exception::Result<DataKey> {
+        DataKey::try_create(&self.unwrap_data_key_bytes(wrapped)?)
+    }
+
+    /// Wrap the data key again by the current master key, the data key itself is not changed.
+    pub fn rewrap_data_key(&self, wrapped: &str) -> common_exception::Result<String> {
+        self.wrap_data_key(&self.unwrap_data_key_bytes(wrapped)?)
+    }
+}
+
+/// Encrypts the objects written through the operator and decrypts the objects read.
+///
+/// A read only loads the sealed chunks covering the range, plus the header of the object, and
+/// `stat` reports the size of the plaintext. A suffix range needs an extra `stat` to locate the
+/// chunks. The writer buffers the plaintext and writes the object on close.
+#[derive(Clone, Debug)]
+pub struct EncryptionLayer {
+    data_key: Arc<DataKey>,
+}
+
+impl EncryptionLayer {
+    pub fn new(data_key: DataKey) -> Self {
+        EncryptionLayer {
+            data_key: Arc::new(data_key),
+        }
+    }
+}
+
+impl<A: Accessor> Layer<A> for EncryptionLayer {
+    type LayeredAccessor = EncryptionAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        EncryptionAccessor {
+            inner,
+            data_key: self.data_key.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct EncryptionAccessor<A: Accessor> {
+    inner: A,
+    data_key: Arc<DataKey>,
+}
+
+/// The sealed chunks to read for a range of the plaintext.
+struct ChunkedRead {
+    offset: u64,
+    size: Option<u64>,
+    first: u64,
+    last: Option<u64>,
+}
+
+impl ChunkedRead {
+    fn new(offset: u64, size: Option<u64>) -> Self {
+        let chunk_size = ENCRYPTION_CHUNK_SIZE as u64;
+        ChunkedRead {
+            offset,
+            size,
+            first: offset / chunk_size,
+            last: size.map(|size| (offset + size).saturating_sub(1) / chunk_size),
+        }
+    }
+
+    // The header is read along with the chunks if they start from the first chunk.
+    fn reads_header(&self) -> bool {
+        self.first == 0
+    }
+
+    // One more byte than the chunks is read, to tell whether the last of them is the last chunk
+    // of the object without a `stat`.
+    fn sealed_range(&self) -> BytesRange {
+        let start = if self.reads_header() {
+            0
+        } else {
+            sealed_chunk_offset(self.first)
+        };
+        let end = self.last.map(|last| sealed_chunk_offset(last + 1) + 1);
+        BytesRange::new(Some(start), end.map(|end| end - start))
+    }
+
+    fn header_range() -> BytesRange {
+        BytesRange::new(Some(0), Some(OBJECT_HEADER_LEN as u64))
+    }
+
+    // The header is `None` if it is read along with the chunks.
+    fn decrypt(
+        &self,
+        data_key: &DataKey,
+        header: Option<&[u8]>,
+        mut data: Vec<u8>,
+    ) -> Result<(RpRead, oio::Cursor)> {
+        let prefix = match header {
+            Some(header) => nonce_prefix(header)?,
+            None => {
+                let prefix = nonce_prefix(&data)?;
+                data.drain(..OBJECT_HEADER_LEN);
+                prefix
+            }
+        };
+
+        let has_last = match self.last {
+            Some(last) => {
+                let len = ((last - self.first + 1) as usize) * SEALED_CHUNK_SIZE;
+                let has_last = data.len() <= len;
+                data.truncate(len);
+                has_last
+            }
+            None => true,
+        };
+        let plaintext = data_key.open_chunks(&prefix, self.first, data, has_last)?;
+
+        let chunks_offset = self.first * ENCRYPTION_CHUNK_SIZE as u64;
+        let len = plaintext.len();
+        let start = ((self.offset - chunks_offset) as usize).min(len);
+        let end = match self.size {
+            Some(size) => ((self.offset + size - chunks_offset) as usize).min(len),
+            None => len,
+        };
+        let bytes = Bytes::from(plaintext).slice(start..end);
+        Ok((RpRead::new(bytes.len() as u64), oio::Cursor::from(bytes)))
+    }
+}
+
+impl<A: Accessor> EncryptionAccessor<A> {
+    fn empty_read() -> (RpRead, oio::Cursor) {
+        (RpRead::new(0), oio::Cursor::from(Bytes::new()))
+    }
+
+    #[async_backtrace::framed]
+    async fn read_all(&self, path: &str, range: BytesRange) -> Result<Vec<u8>> {
+        let (_, mut reader) = self
+            .inner
+            .read(path, OpRead::new().with_range(range))
+            .await?;
+        let mut data = vec![];
+        let mut buf = vec![0; 64 * 1024];
+        loop {
+            let n = reader.read(&mut buf).await?;
+            if n == 0 {
+                break;
+            }
+            data.extend_from_slice(&buf[..n]);
+        }
+        Ok(data)
+    }
+
+    fn blocking_read_all(&self, path: &str, range: BytesRange) -> Result<Vec<u8>> {
+        let (_, mut reader) = self
+            .inner
+            .blocking_read(path, OpRead::new().with_range(range))?;
+        let mut data = vec![];
+        let mut buf = vec![0; 64 * 1024];
+        loop {
+            let n = oio::BlockingRead::read(&mut reader, &mut buf)?;
+            if n == 0 {
+                break;
+            }
+            data.extend_from_slice(&buf[..n]);
+        }
+        Ok(data)
+    }
+
+    // The content length in the write args is the length of the encrypted object.
+    fn encrypted_write_args(args: OpWrite) -> OpWrite {
+        match args.content_length() {
+            Some(len) => args.with_content_length(encrypted_len(len)),
+            None => args,
+        }
+    }
+
+    fn plaintext_stat(rp: RpStat) -> RpStat {
+        let mut meta = rp.into_metadata();
+        if meta.mode().is_file() {
+            let len = meta.content_length();
+            meta.set_content_length(plaintext_len(len));
+        }
+        RpStat::new(meta)
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for EncryptionAccessor<A> {
+    type Inner = A;
+    type Reader = oio::Cursor;
+    type BlockingReader = oio::Cursor;
+    type Writer = EncryptionWriter<A::Writer>;
+    type BlockingWriter = EncryptionWriter<A::BlockingWriter>;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+    type Appender = A::Appender;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    #[async_backtrace::framed]
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let range = args.range();
+        let read = match (range.offset(), range.size()) {
+            (_, Some(0)) => return Ok(Self::empty_read()),
+            (Some(offset), size) => ChunkedRead::new(offset, size),
+            (None, None) => ChunkedRead::new(0, None),
+            (None, Some(size)) => {
+                let total = self.stat(path, OpStat::new()).await?;
+                let total = total.into_metadata().content_length();
+                ChunkedRead::new(total.saturating_sub(size), None)
+            }
+        };
+
+        if read.reads_header() {
+            let data = self.read_all(path, read.sealed_range()).await?;
+            read.decrypt(&self.data_key, None, data)
+        } else {
+            let (header, data) = futures::try_join!(
+                self.read_all(path, ChunkedRead::header_range()),
+                self.read_all(path, read.sealed_range())
+            )?;
+            read.decrypt(&self.data_key, Some(&header), data)
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner
+            .write(path, Self::encrypted_write_args(args))
+            .await
+            .map(|(rp, w)| (rp, EncryptionWriter::new(w, self.data_key.clone())))
+    }
+
+    #[async_backtrace::framed]
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.inner.stat(path, args).await.map(Self::plaintext_stat)
+    }
+
+    #[async_backtrace::framed]
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        self.inner.list(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn append(&self, _path: &str, _args: OpAppend) -> Result<(RpAppend, Self::Appender)> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "append is not supported on encrypted objects",
+        ))
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        let range = args.range();
+        let read = match (range.offset(), range.size()) {
+            (_, Some(0)) => return Ok(Self::empty_read()),
+            (Some(offset), size) => ChunkedRead::new(offset, size),
+            (None, None) => ChunkedRead::new(0, None),
+            (None, Some(size)) => {
+                let total = self.blocking_stat(path, OpStat::new())?;
+                let total = total.into_metadata().content_length();
+                ChunkedRead::new(total.saturating_sub(size), None)
+            }
+        };
+
+        let data = self.blocking_read_all(path, read.sealed_range())?;
+        if read.reads_header() {
+            read.decrypt(&self.data_key, None, data)
+        } else {
+            let header = self.blocking_read_all(path, ChunkedRead::header_range())?;
+            read.decrypt(&self.data_key, Some(&header), data)
+        }
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner
+            .blocking_write(path, Self::encrypted_write_args(args))
+            .map(|(rp, w)| (rp, EncryptionWriter::new(w, self.data_key.clone())))
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        self.inner
+            .blocking_stat(path, args)
+            .map(Self::plaintext_stat)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+/// Buffers the plaintext written, and writes the sealed object on close, so the inner writer
+/// gets a single write of the length given in the write args.
+pub struct EncryptionWriter<W> {
+    inner: W,
+    data_key: Arc<DataKey>,
+    buf: Vec<u8>,
+}
+
+impl<W> EncryptionWriter<W> {
+    fn new(inner: W, data_key: Arc<DataKey>) -> Self {
+        Self {
+            inner,
+            data_key,
+            buf: vec![],
+        }
+    }
+
+    fn encrypted(&mut self) -> Result<Bytes> {
+        let plaintext = std::mem::take(&mut self.buf);
+        self.data_key.seal_object(&plaintext).map(Bytes::from)
+    }
+}
+
+#[async_trait]
+impl<W: oio::Write> oio::Write for EncryptionWriter<W> {
+    #[async_backtrace::framed]
+    async fn write(&mut self, bs: Bytes) -> Result<()> {
+        self.buf.extend_from_slice(&bs);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn abort(&mut self) -> Result<()> {
+        self.buf.clear();
+        self.inner.abort().await
+    }
+
+    #[async_backtrace::framed]
+    async fn close(&mut self) -> Result<()> {
+        let data = self.encrypted()?;
+        self.inner.write(data).await?;
+        self.inner.close().await
+    }
+}
+
+impl<W: oio::BlockingWrite> oio::BlockingWrite for EncryptionWriter<W> {
+    fn write(&mut self, bs: Bytes) -> Result<()> {
+        self.buf.extend_from_slice(&bs);
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<()> {
+        let data = self.encrypted()?;
+        self.inner.write(data)?;
+        self.inner.close()
+    }
+}
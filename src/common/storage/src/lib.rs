@@ -30,6 +30,7 @@
 
 #![allow(clippy::uninlined_format_args)]
 #![feature(io_error_other)]
+#![feature(int_roundings)]
 
 mod config;
 pub use config::ShareTableConfig;
@@ -49,6 +50,15 @@ mod column_node;
 pub use column_node::ColumnNode;
 pub use column_node::ColumnNodes;
 
+mod encryption;
+pub use encryption::encrypted_len;
+pub use encryption::DataKey;
+pub use encryption::EncryptionLayer;
+pub use encryption::MasterKeys;
+pub use encryption::ENCRYPTION_AES_256_GCM;
+pub use encryption::ENCRYPTION_CHUNK_SIZE;
+pub use encryption::ENCRYPTION_OVERHEAD;
+
 mod parquet2;
 pub use parquet2::infer_schema_with_extension;
 pub use parquet2::read_parquet_metas_in_parallel;
@@ -0,0 +1,210 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storage::encrypted_len;
+use common_storage::EncryptionLayer;
+use common_storage::MasterKeys;
+use common_storage::ENCRYPTION_CHUNK_SIZE;
+use common_storage::ENCRYPTION_OVERHEAD;
+use opendal::services::Memory;
+use opendal::Operator;
+
+fn master_key(seed: u8) -> String {
+    general_purpose::STANDARD.encode([seed; 32])
+}
+
+#[test]
+fn test_master_keys() -> Result<()> {
+    let keys = format!("k1:{},k2:{}", master_key(1), master_key(2));
+
+    // No master key configured.
+    let master_keys = MasterKeys::try_create("", &keys, "")?;
+    assert_eq!(
+        master_keys.generate_data_key().unwrap_err().code(),
+        ErrorCode::INVALID_ENCRYPTION_KEY
+    );
+
+    // Unknown current key.
+    assert!(MasterKeys::try_create("k3", &keys, "").is_err());
+    // Bad key length.
+    assert!(MasterKeys::try_create("k1", "k1:AAAA", "").is_err());
+
+    let old_keys = MasterKeys::try_create("k1", &keys, "")?;
+    let wrapped = old_keys.generate_data_key()?;
+    assert!(wrapped.starts_with("k1:"));
+
+    let data_key = old_keys.unwrap_data_key(&wrapped)?;
+    let ciphertext = data_key.encrypt(b"databend")?;
+    assert_eq!(ciphertext.len(), 8 + ENCRYPTION_OVERHEAD);
+    assert_eq!(data_key.decrypt(&ciphertext)?, b"databend");
+
+    // Rotate to k2, the data key is kept.
+    let new_keys = MasterKeys::try_create("k2", &keys, "")?;
+    let rewrapped = new_keys.rewrap_data_key(&wrapped)?;
+    assert!(rewrapped.starts_with("k2:"));
+    let data_key = new_keys.unwrap_data_key(&rewrapped)?;
+    assert_eq!(data_key.decrypt(&ciphertext)?, b"databend");
+
+    // Once k1 is retired, the data keys not rotated can't be unwrapped.
+    let retired = MasterKeys::try_create("k2", &format!("k2:{}", master_key(2)), "")?;
+    assert!(retired.unwrap_data_key(&wrapped).is_err());
+    assert!(retired.unwrap_data_key(&rewrapped).is_ok());
+
+    // Tampered data.
+    let mut tampered = ciphertext.clone();
+    let last = tampered.len() - 1;
+    tampered[last] ^= 1;
+    assert_eq!(
+        data_key.decrypt(&tampered).unwrap_err().code(),
+        ErrorCode::DECRYPTION_ERROR
+    );
+    assert!(data_key.decrypt(b"databend").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_master_keys_from_kms_dir() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("databend_kms_{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("kms1.key"), format!("{}\n", master_key(3)))?;
+
+    let master_keys = MasterKeys::try_create("kms1", "", dir.to_str().unwrap())?;
+    let wrapped = master_keys.generate_data_key()?;
+    assert!(wrapped.starts_with("kms1:"));
+    assert!(master_keys.unwrap_data_key(&wrapped).is_ok());
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn test_encryption_layer() -> Result<()> {
+    let master_keys = MasterKeys::try_create("k1", &format!("k1:{}", master_key(1)), "")?;
+    let wrapped = master_keys.generate_data_key()?;
+
+    let inner = Operator::new(Memory::default())?.finish();
+    let op = inner
+        .clone()
+        .layer(EncryptionLayer::new(master_keys.unwrap_data_key(&wrapped)?));
+
+    let plaintext = b"hello, databend".to_vec();
+    op.blocking().write("data/a", plaintext.clone())?;
+
+    // Stored encrypted.
+    let stored = inner.blocking().read("data/a")?;
+    assert_eq!(stored.len() as u64, encrypted_len(plaintext.len() as u64));
+    assert!(
+        !stored
+            .windows(plaintext.len())
+            .any(|w| w == plaintext.as_slice())
+    );
+
+    // Read transparently.
+    assert_eq!(op.blocking().read("data/a")?, plaintext);
+    assert_eq!(op.blocking().range_read("data/a", 7..15)?, b"databend");
+    assert_eq!(op.blocking().range_read("data/a", 7..)?, b"databend");
+    assert_eq!(
+        op.blocking().stat("data/a")?.content_length(),
+        plaintext.len() as u64
+    );
+
+    // A different data key can't read it.
+    let other = inner.layer(EncryptionLayer::new(
+        master_keys.unwrap_data_key(&master_keys.generate_data_key()?)?,
+    ));
+    assert!(other.blocking().read("data/a").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_encryption_layer_chunks() -> Result<()> {
+    let master_keys = MasterKeys::try_create("k1", &format!("k1:{}", master_key(1)), "")?;
+    let wrapped = master_keys.generate_data_key()?;
+
+    let inner = Operator::new(Memory::default())?.finish();
+    let op = inner
+        .clone()
+        .layer(EncryptionLayer::new(master_keys.unwrap_data_key(&wrapped)?));
+
+    // Objects of an empty, an exact and a partial last chunk.
+    for len in [
+        0,
+        ENCRYPTION_CHUNK_SIZE * 2,
+        ENCRYPTION_CHUNK_SIZE * 2 + 100,
+    ] {
+        let plaintext = (0..len).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        op.blocking().write("data/b", plaintext.clone())?;
+        assert_eq!(
+            inner.blocking().read("data/b")?.len() as u64,
+            encrypted_len(len as u64)
+        );
+        assert_eq!(op.blocking().read("data/b")?, plaintext);
+        assert_eq!(op.blocking().stat("data/b")?.content_length(), len as u64);
+    }
+
+    let len = ENCRYPTION_CHUNK_SIZE * 2 + 100;
+    let plaintext = (0..len).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+    let chunk = ENCRYPTION_CHUNK_SIZE as u64;
+    let ranges = [
+        (0, 10),
+        (10, chunk),
+        (chunk - 1, chunk + 1),
+        (chunk, 2 * chunk),
+        (chunk + 5, len as u64),
+        (2 * chunk + 10, 2 * chunk + 20),
+    ];
+    for (start, end) in ranges {
+        assert_eq!(
+            op.blocking().range_read("data/b", start..end)?,
+            &plaintext[start as usize..end as usize],
+            "range {start}..{end}"
+        );
+        assert_eq!(
+            futures::executor::block_on(op.range_read("data/b", start..end))?,
+            &plaintext[start as usize..end as usize],
+            "range {start}..{end}"
+        );
+    }
+    assert_eq!(
+        op.blocking().range_read("data/b", chunk + 5..)?,
+        &plaintext[chunk as usize + 5..]
+    );
+    assert_eq!(
+        futures::executor::block_on(op.range_read("data/b", chunk + 5..))?,
+        &plaintext[chunk as usize + 5..]
+    );
+
+    // A tampered chunk fails the reads covering it only.
+    let stored = inner.blocking().read("data/b")?;
+    let mut tampered = stored.clone();
+    tampered[100] ^= 1;
+    inner.blocking().write("data/b", tampered)?;
+    assert!(op.blocking().range_read("data/b", 0..10).is_err());
+    assert!(op.blocking().range_read("data/b", 2 * chunk..).is_ok());
+
+    // An object truncated at a chunk boundary fails to read the chunk before the boundary,
+    // which is not sealed as the last chunk.
+    let truncated = stored[..encrypted_len(chunk) as usize].to_vec();
+    inner.blocking().write("data/b", truncated)?;
+    assert!(op.blocking().read("data/b").is_err());
+    assert!(op.blocking().range_read("data/b", 0..10).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,219 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Mutex;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use hmac::Hmac;
+use hmac::Mac;
+use log::info;
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// The log target of the audit events, they are written to the audit log files only.
+pub const AUDIT_LOG_TARGET: &str = "audit";
+
+/// Kinds of the security relevant events written to the audit log.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEventType {
+    Login,
+    NetworkPolicyDenied,
+    ConnectionRejected,
+    AccessDenied,
+    Grant,
+    Revoke,
+    CreateUser,
+    AlterUser,
+    DropUser,
+    CreateRole,
+    DropRole,
+    CreateDataMask,
+    DropDataMask,
+}
+
+impl AuditEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditEventType::Login => "login",
+            AuditEventType::NetworkPolicyDenied => "network_policy_denied",
+            AuditEventType::ConnectionRejected => "connection_rejected",
+            AuditEventType::AccessDenied => "access_denied",
+            AuditEventType::Grant => "grant",
+            AuditEventType::Revoke => "revoke",
+            AuditEventType::CreateUser => "create_user",
+            AuditEventType::AlterUser => "alter_user",
+            AuditEventType::DropUser => "drop_user",
+            AuditEventType::CreateRole => "create_role",
+            AuditEventType::DropRole => "drop_role",
+            AuditEventType::CreateDataMask => "create_data_mask",
+            AuditEventType::DropDataMask => "drop_data_mask",
+        }
+    }
+}
+
+impl fmt::Display for AuditEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// One record of the audit log.
+///
+/// The records written by a node form a hash chain: `hash` is the HMAC-SHA256 of the record
+/// with an empty `hash`, keyed by the audit log key of the config, and `prev_hash` is the `hash`
+/// of the record written before it. The chain continues from the head persisted in the meta
+/// service after a restart, so removing or editing a record, or truncating the log, breaks the
+/// chain, see [`verify_audit_chain`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AuditEvent {
+    pub seq: u64,
+    /// Microseconds since the unix epoch.
+    pub event_time: i64,
+    pub event_type: AuditEventType,
+    pub tenant: String,
+    pub user: String,
+    pub client_address: String,
+    pub query_id: String,
+    /// The user, role, privilege or policy the event is about.
+    pub object: String,
+    pub detail: String,
+    pub success: bool,
+    pub error_code: i32,
+    pub error_message: String,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+impl AuditEvent {
+    pub fn new(event_type: AuditEventType) -> Self {
+        let event_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_micros() as i64);
+        AuditEvent {
+            seq: 0,
+            event_time,
+            event_type,
+            tenant: "".to_string(),
+            user: "".to_string(),
+            client_address: "".to_string(),
+            query_id: "".to_string(),
+            object: "".to_string(),
+            detail: "".to_string(),
+            success: true,
+            error_code: 0,
+            error_message: "".to_string(),
+            prev_hash: "".to_string(),
+            hash: "".to_string(),
+        }
+    }
+
+    /// The HMAC-SHA256 of the record with an empty `hash` keyed by `key`, in hex.
+    pub fn digest(&self, key: &[u8]) -> String {
+        let mut event = self.clone();
+        event.hash = "".to_string();
+        let json = serde_json::to_vec(&event).unwrap_or_default();
+        let mut mac = HmacSha256::new_from_slice(key).expect("HMAC can take a key of any size");
+        mac.update(&json);
+        format!("{:x}", mac.finalize().into_bytes())
+    }
+}
+
+struct AuditChain {
+    key: Vec<u8>,
+    seq: u64,
+    hash: String,
+}
+
+static AUDIT_CHAIN: Mutex<AuditChain> = Mutex::new(AuditChain {
+    key: Vec::new(),
+    seq: 0,
+    hash: String::new(),
+});
+
+/// Set the key of the audit chain, and continue the chain from the head `seq` and `hash`
+/// persisted by the last run of the node.
+pub fn init_audit_chain(key: &[u8], seq: u64, hash: String) {
+    let mut chain = AUDIT_CHAIN.lock().unwrap_or_else(|e| e.into_inner());
+    chain.key = key.to_vec();
+    chain.seq = seq;
+    chain.hash = hash;
+}
+
+/// The `seq` and `hash` of the last record of the audit chain.
+pub fn audit_chain_head() -> (u64, String) {
+    let chain = AUDIT_CHAIN.lock().unwrap_or_else(|e| e.into_inner());
+    (chain.seq, chain.hash.clone())
+}
+
+/// Link the event to the audit chain and write it to the audit log.
+///
+/// Returns the event with `seq`, `prev_hash` and `hash` filled.
+pub fn write_audit_log(mut event: AuditEvent) -> AuditEvent {
+    // Keep the lock while writing, so that the records are written in the order of the chain.
+    let mut chain = AUDIT_CHAIN.lock().unwrap_or_else(|e| e.into_inner());
+    chain.seq += 1;
+    event.seq = chain.seq;
+    event.prev_hash = chain.hash.clone();
+    event.hash = event.digest(&chain.key);
+    chain.hash = event.hash.clone();
+
+    if let Ok(event_str) = serde_json::to_string(&event) {
+        info!(target: AUDIT_LOG_TARGET, "{}", event_str);
+    }
+    event
+}
+
+/// Check the records are consecutive and none of them is modified, `key` is the key the
+/// records are signed with.
+///
+/// `head` is the `seq` and `hash` of the chain head persisted in the meta service, the records
+/// must reach it and end with the same hash, otherwise the log is truncated.
+///
+/// Returns the `seq` of the first broken or missing record if the chain is broken.
+pub fn verify_audit_chain(
+    events: &[AuditEvent],
+    key: &[u8],
+    head: Option<(u64, &str)>,
+) -> std::result::Result<(), u64> {
+    let mut prev: Option<&AuditEvent> = None;
+    for event in events {
+        if event.hash != event.digest(key) {
+            return Err(event.seq);
+        }
+        if let Some(prev) = prev {
+            if event.seq != prev.seq + 1 || event.prev_hash != prev.hash {
+                return Err(event.seq);
+            }
+        }
+        prev = Some(event);
+    }
+
+    if let Some((head_seq, head_hash)) = head {
+        let last_seq = prev.map_or(0, |event| event.seq);
+        if last_seq < head_seq {
+            return Err(last_seq + 1);
+        }
+        // The head is persisted after the records are written, the log may be a bit ahead.
+        if let Some(event) = events.iter().find(|event| event.seq == head_seq) {
+            if event.hash != head_hash {
+                return Err(head_seq);
+            }
+        }
+    }
+    Ok(())
+}
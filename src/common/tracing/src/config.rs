@@ -21,6 +21,7 @@ pub struct Config {
     pub file: FileConfig,
     pub stderr: StderrConfig,
     pub query: QueryLogConfig,
+    pub audit: AuditLogConfig,
     pub tracing: TracingConfig,
 }
 
@@ -43,6 +44,10 @@ impl Config {
                 on: true,
                 dir: "./.databend/logs/query-details".to_string(),
             },
+            audit: AuditLogConfig {
+                on: true,
+                dir: "./.databend/logs/audit".to_string(),
+            },
             tracing: TracingConfig {
                 on: true,
                 capture_log_level: "TRACE".to_string(),
@@ -136,6 +141,28 @@ impl Default for QueryLogConfig {
     }
 }
 
+/// Config for the audit log of security relevant events.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct AuditLogConfig {
+    pub on: bool,
+    pub dir: String,
+}
+
+impl Display for AuditLogConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "enabled={}, dir={}", self.on, self.dir)
+    }
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        Self {
+            on: true,
+            dir: "./.databend/logs/audit".to_string(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
 pub struct TracingConfig {
     pub on: bool,
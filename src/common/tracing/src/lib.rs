@@ -16,10 +16,19 @@
 #![allow(clippy::uninlined_format_args)]
 #![deny(unused_crate_dependencies)]
 
+mod audit;
 mod config;
 mod minitrace;
 mod panic_hook;
 
+pub use crate::audit::audit_chain_head;
+pub use crate::audit::init_audit_chain;
+pub use crate::audit::verify_audit_chain;
+pub use crate::audit::write_audit_log;
+pub use crate::audit::AuditEvent;
+pub use crate::audit::AuditEventType;
+pub use crate::audit::AUDIT_LOG_TARGET;
+pub use crate::config::AuditLogConfig;
 pub use crate::config::Config;
 pub use crate::config::FileConfig;
 pub use crate::config::QueryLogConfig;
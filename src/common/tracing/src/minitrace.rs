@@ -27,6 +27,7 @@ use tracing_appender::rolling::RollingFileAppender;
 use tracing_appender::rolling::Rotation;
 
 use crate::Config;
+use crate::AUDIT_LOG_TARGET;
 
 const HEADER_TRACE_PARENT: &str = "traceparent";
 
@@ -95,6 +96,7 @@ pub fn init_logging(name: &str, cfg: &Config) -> Vec<Box<dyn Drop + Send + Sync
     // Initialize logging
     let mut normal_logger = fern::Dispatch::new();
     let mut query_logger = fern::Dispatch::new();
+    let mut audit_logger = fern::Dispatch::new();
 
     // Console logger
     if cfg.stderr.on {
@@ -149,10 +151,21 @@ pub fn init_logging(name: &str, cfg: &Config) -> Vec<Box<dyn Drop + Send + Sync
         query_logger = query_logger.chain(Box::new(query_log_file) as Box<dyn Write + Send>);
     }
 
+    // Audit logger
+    if cfg.audit.on {
+        let (audit_log_file, flush_guard) = tracing_appender::non_blocking(
+            RollingFileAppender::new(Rotation::HOURLY, &cfg.audit.dir, name),
+        );
+        guards.push(Box::new(flush_guard));
+
+        audit_logger = audit_logger.chain(Box::new(audit_log_file) as Box<dyn Write + Send>);
+    }
+
     let logger = fern::Dispatch::new()
         .chain(
             fern::Dispatch::new()
                 .level_for("query", LevelFilter::Off)
+                .level_for(AUDIT_LOG_TARGET, LevelFilter::Off)
                 .chain(normal_logger),
         )
         .chain(
@@ -160,6 +173,12 @@ pub fn init_logging(name: &str, cfg: &Config) -> Vec<Box<dyn Drop + Send + Sync
                 .level(LevelFilter::Off)
                 .level_for("query", LevelFilter::Info)
                 .chain(query_logger),
+        )
+        .chain(
+            fern::Dispatch::new()
+                .level(LevelFilter::Off)
+                .level_for(AUDIT_LOG_TARGET, LevelFilter::Info)
+                .chain(audit_logger),
         );
 
     // Set global logger
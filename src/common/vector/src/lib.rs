@@ -15,3 +15,5 @@
 mod distance;
 
 pub use distance::cosine_distance;
+pub use distance::inner_product;
+pub use distance::l2_distance;
@@ -26,6 +26,7 @@ pub mod app_error;
 pub mod background;
 pub mod data_mask;
 pub mod principal;
+pub mod row_access_policy;
 pub mod schema;
 pub mod share;
 pub mod storage;
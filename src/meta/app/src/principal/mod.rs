@@ -16,6 +16,8 @@
 
 mod file_format;
 mod network_policy;
+mod ownership_info;
+mod password_policy;
 mod principal_identity;
 mod role_info;
 mod user_auth;
@@ -28,9 +30,13 @@ mod user_privilege;
 mod user_quota;
 mod user_setting;
 mod user_stage;
+mod workload_group;
 
 pub use file_format::*;
 pub use network_policy::NetworkPolicy;
+pub use ownership_info::OwnershipInfo;
+pub use ownership_info::OwnershipObject;
+pub use password_policy::PasswordPolicy;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
@@ -52,3 +58,4 @@ pub use user_quota::UserQuota;
 pub use user_setting::UserSetting;
 pub use user_setting::UserSettingValue;
 pub use user_stage::*;
+pub use workload_group::WorkloadGroup;
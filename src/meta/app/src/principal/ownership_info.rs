@@ -0,0 +1,68 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+/// An object which can be owned by a role.
+///
+/// Databases and tables are identified by id, so that the ownership survives `RENAME`, and a
+/// re-created object with the same name does not inherit the old owner. Dropping an object
+/// removes its owner, an undropped object has no owner.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum OwnershipObject {
+    Database {
+        catalog_name: String,
+        db_id: u64,
+    },
+    Table {
+        catalog_name: String,
+        db_id: u64,
+        table_id: u64,
+    },
+    Stage {
+        name: String,
+    },
+    UDF {
+        name: String,
+    },
+}
+
+impl Display for OwnershipObject {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            OwnershipObject::Database {
+                catalog_name,
+                db_id,
+            } => write!(f, "DATABASE '{}'.{}", catalog_name, db_id),
+            OwnershipObject::Table {
+                catalog_name,
+                db_id,
+                table_id,
+            } => write!(f, "TABLE '{}'.{}.{}", catalog_name, db_id, table_id),
+            OwnershipObject::Stage { name } => write!(f, "STAGE {}", name),
+            OwnershipObject::UDF { name } => write!(f, "UDF {}", name),
+        }
+    }
+}
+
+/// The owner role of an object.
+///
+/// The owner implicitly holds all the privileges on the object, and can transfer the
+/// ownership to another role with `GRANT OWNERSHIP`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipInfo {
+    pub object: OwnershipObject,
+    pub role: String,
+}
@@ -0,0 +1,56 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// Rules on the passwords of the users it is attached to, see `UserOption::password_policy`.
+///
+/// It constrains the complexity of a new password, how long a password can be used,
+/// how many old passwords can't be reused and when an account is locked out after failed logins.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct PasswordPolicy {
+    pub name: String,
+    pub min_length: u64,
+    pub min_upper_case_chars: u64,
+    pub min_lower_case_chars: u64,
+    pub min_numeric_chars: u64,
+    pub min_special_chars: u64,
+    /// The max days a password can be used before it must be changed (0 is no limited).
+    pub max_age_days: u64,
+    /// The max consecutive failed logins before the account is locked out (0 is no limited).
+    pub max_retries: u64,
+    /// The minutes an account stays locked out after `max_retries` failed logins.
+    pub lockout_time_mins: u64,
+    /// The number of the most recent passwords that can't be reused (0 is no limited).
+    pub history: u64,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
+
+impl PasswordPolicy {
+    pub const DEFAULT_MIN_LENGTH: u64 = 8;
+    pub const DEFAULT_MIN_UPPER_CASE_CHARS: u64 = 1;
+    pub const DEFAULT_MIN_LOWER_CASE_CHARS: u64 = 1;
+    pub const DEFAULT_MIN_NUMERIC_CHARS: u64 = 1;
+    pub const DEFAULT_MIN_SPECIAL_CHARS: u64 = 0;
+    pub const DEFAULT_MAX_AGE_DAYS: u64 = 90;
+    pub const DEFAULT_MAX_RETRIES: u64 = 5;
+    pub const DEFAULT_LOCKOUT_TIME_MINS: u64 = 15;
+    pub const DEFAULT_HISTORY: u64 = 0;
+
+    /// Max number of history passwords kept for a user.
+    pub const MAX_HISTORY: u64 = 24;
+}
@@ -23,6 +23,8 @@ const NO_PASSWORD_STR: &str = "no_password";
 const SHA256_PASSWORD_STR: &str = "sha256_password";
 const DOUBLE_SHA1_PASSWORD_STR: &str = "double_sha1_password";
 const JWT_AUTH_STR: &str = "jwt";
+const KEY_PAIR_AUTH_STR: &str = "key_pair";
+const OIDC_AUTH_STR: &str = "oidc";
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub enum AuthType {
@@ -30,6 +32,8 @@ pub enum AuthType {
     Sha256Password,
     DoubleSha1Password,
     JWT,
+    KeyPair,
+    OIDC,
 }
 
 impl std::str::FromStr for AuthType {
@@ -40,6 +44,8 @@ impl std::str::FromStr for AuthType {
             DOUBLE_SHA1_PASSWORD_STR => Ok(AuthType::DoubleSha1Password),
             NO_PASSWORD_STR => Ok(AuthType::NoPassword),
             JWT_AUTH_STR => Ok(AuthType::JWT),
+            KEY_PAIR_AUTH_STR => Ok(AuthType::KeyPair),
+            OIDC_AUTH_STR => Ok(AuthType::OIDC),
             _ => Err(ErrorCode::InvalidAuthInfo(AuthType::bad_auth_types(s))),
         }
     }
@@ -52,6 +58,8 @@ impl AuthType {
             AuthType::Sha256Password => SHA256_PASSWORD_STR,
             AuthType::DoubleSha1Password => DOUBLE_SHA1_PASSWORD_STR,
             AuthType::JWT => JWT_AUTH_STR,
+            AuthType::KeyPair => KEY_PAIR_AUTH_STR,
+            AuthType::OIDC => OIDC_AUTH_STR,
         }
     }
 
@@ -61,6 +69,8 @@ impl AuthType {
             SHA256_PASSWORD_STR,
             DOUBLE_SHA1_PASSWORD_STR,
             JWT_AUTH_STR,
+            KEY_PAIR_AUTH_STR,
+            OIDC_AUTH_STR,
         ];
         let all = all
             .iter()
@@ -90,6 +100,19 @@ pub enum AuthInfo {
         hash_method: PasswordHashMethod,
     },
     JWT,
+    /// Authenticated by a JWT signed with the private key of the user, the token is
+    /// verified against the RSA public keys in PEM format.
+    ///
+    /// There are two key slots for key rotation: setting a new key moves the current
+    /// one to `public_key_2`, so both keys are accepted until the rotation is finished
+    /// by setting the new key again.
+    KeyPair {
+        public_key: String,
+        public_key_2: Option<String>,
+    },
+    /// Authenticated by a token issued by the OIDC provider in the config,
+    /// the user is mapped from the token claims.
+    OIDC,
 }
 
 fn calc_sha1(v: &[u8]) -> [u8; 20] {
@@ -107,6 +130,14 @@ impl AuthInfo {
         match auth_type {
             AuthType::NoPassword => Ok(AuthInfo::None),
             AuthType::JWT => Ok(AuthInfo::JWT),
+            AuthType::OIDC => Ok(AuthInfo::OIDC),
+            AuthType::KeyPair => match auth_string {
+                Some(public_key) => Ok(AuthInfo::KeyPair {
+                    public_key: public_key.clone(),
+                    public_key_2: None,
+                }),
+                None => Err(ErrorCode::InvalidAuthInfo("need public key".to_string())),
+            },
             AuthType::Sha256Password | AuthType::DoubleSha1Password => match auth_string {
                 Some(p) => {
                     let method = auth_type.get_password_type().unwrap();
@@ -158,6 +189,27 @@ impl AuthInfo {
         let old_auth_type = self.get_type();
         let new_auth_type = auth_type.clone().unwrap_or(old_auth_type);
 
+        if let (
+            AuthInfo::KeyPair {
+                public_key: old_public_key,
+                ..
+            },
+            AuthType::KeyPair,
+            Some(public_key),
+        ) = (self, &new_auth_type, auth_string)
+        {
+            // Rotate the key, the same key finishes the rotation.
+            let public_key_2 = if old_public_key == public_key {
+                None
+            } else {
+                Some(old_public_key.clone())
+            };
+            return Ok(AuthInfo::KeyPair {
+                public_key: public_key.clone(),
+                public_key_2,
+            });
+        }
+
         AuthInfo::new(new_auth_type, auth_string)
     }
 
@@ -165,6 +217,8 @@ impl AuthInfo {
         match self {
             AuthInfo::None => AuthType::NoPassword,
             AuthInfo::JWT => AuthType::JWT,
+            AuthInfo::KeyPair { .. } => AuthType::KeyPair,
+            AuthInfo::OIDC => AuthType::OIDC,
             AuthInfo::Password {
                 hash_value: _,
                 hash_method: t,
@@ -181,7 +235,9 @@ impl AuthInfo {
                 hash_value: p,
                 hash_method: t,
             } => t.to_string(p),
-            AuthInfo::None | AuthInfo::JWT => "".to_string(),
+            AuthInfo::None | AuthInfo::JWT | AuthInfo::KeyPair { .. } | AuthInfo::OIDC => {
+                "".to_string()
+            }
         }
     }
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::collections::HashSet;
 use std::fmt;
 use std::ops;
@@ -75,11 +76,24 @@ impl fmt::Display for GrantObject {
 pub struct GrantEntry {
     object: GrantObject,
     privileges: BitFlags<UserPrivilegeType>,
+    /// The columns of a table object the privileges are restricted to,
+    /// empty if the privileges are granted on the whole object.
+    #[serde(default)]
+    columns: BTreeSet<String>,
 }
 
 impl GrantEntry {
     pub fn new(object: GrantObject, privileges: BitFlags<UserPrivilegeType>) -> Self {
-        Self { object, privileges }
+        Self {
+            object,
+            privileges,
+            columns: BTreeSet::new(),
+        }
+    }
+
+    pub fn with_columns(mut self, columns: BTreeSet<String>) -> Self {
+        self.columns = columns;
+        self
     }
 
     pub fn object(&self) -> &GrantObject {
@@ -90,11 +104,25 @@ impl GrantEntry {
         &self.privileges
     }
 
+    pub fn columns(&self) -> &BTreeSet<String> {
+        &self.columns
+    }
+
+    pub fn is_column_level(&self) -> bool {
+        !self.columns.is_empty()
+    }
+
     pub fn verify_privilege(
         &self,
         object: &GrantObject,
         privileges: Vec<UserPrivilegeType>,
     ) -> bool {
+        // the column-level entries only grant a part of the table, they are checked
+        // by `UserGrantSet::granted_columns` instead.
+        if self.is_column_level() {
+            return false;
+        }
+
         // the verified object should be smaller than the object inside my grant entry.
         if !self.object.contains(object) {
             return false;
@@ -108,7 +136,15 @@ impl GrantEntry {
     }
 
     pub fn matches_entry(&self, object: &GrantObject) -> bool {
-        &self.object == object
+        &self.object == object && !self.is_column_level()
+    }
+
+    fn matches_column_entry(
+        &self,
+        object: &GrantObject,
+        privileges: BitFlags<UserPrivilegeType>,
+    ) -> bool {
+        &self.object == object && self.is_column_level() && self.privileges == privileges
     }
 
     fn has_all_available_privileges(&self) -> bool {
@@ -121,6 +157,16 @@ impl GrantEntry {
 impl fmt::Display for GrantEntry {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::result::Result<(), fmt::Error> {
         let privileges: UserPrivilegeSet = self.privileges.into();
+        if self.is_column_level() {
+            let columns = self.columns.iter().cloned().collect::<Vec<_>>();
+            return write!(
+                f,
+                "GRANT {}({}) ON {}",
+                privileges,
+                columns.join(","),
+                self.object
+            );
+        }
         let privileges_str = if self.has_all_available_privileges() {
             "ALL".to_string()
         } else {
@@ -213,12 +259,71 @@ impl UserGrantSet {
             .collect::<Vec<_>>();
         self.entries = new_entries;
     }
+
+    /// Returns the columns of the table object on which the privilege is granted by the
+    /// column-level entries.
+    pub fn granted_columns(
+        &self,
+        object: &GrantObject,
+        privilege: UserPrivilegeType,
+    ) -> BTreeSet<String> {
+        self.entries
+            .iter()
+            .filter(|e| e.is_column_level() && &e.object == object)
+            .filter(|e| e.privileges.contains(privilege))
+            .flat_map(|e| e.columns.iter().cloned())
+            .collect()
+    }
+
+    pub fn grant_column_privileges(
+        &mut self,
+        object: &GrantObject,
+        privileges: UserPrivilegeSet,
+        columns: &BTreeSet<String>,
+    ) {
+        let privileges: BitFlags<UserPrivilegeType> = privileges.into();
+        match self
+            .entries
+            .iter_mut()
+            .find(|e| e.matches_column_entry(object, privileges))
+        {
+            Some(entry) => entry.columns.extend(columns.iter().cloned()),
+            None => self
+                .entries
+                .push(GrantEntry::new(object.clone(), privileges).with_columns(columns.clone())),
+        }
+    }
+
+    pub fn revoke_column_privileges(
+        &mut self,
+        object: &GrantObject,
+        privileges: UserPrivilegeSet,
+        columns: &BTreeSet<String>,
+    ) {
+        let privileges: BitFlags<UserPrivilegeType> = privileges.into();
+        self.entries.retain_mut(|e| {
+            if !e.matches_column_entry(object, privileges) {
+                return true;
+            }
+            e.columns.retain(|c| !columns.contains(c));
+            // an entry without columns left would turn into a grant on the whole table.
+            e.is_column_level()
+        });
+    }
 }
 
 impl ops::BitOrAssign for UserGrantSet {
     fn bitor_assign(&mut self, other: Self) {
         for entry in other.entries() {
-            self.grant_privileges(&entry.object, entry.privileges.into());
+            if entry.is_column_level() {
+                self.grant_column_privileges(
+                    &entry.object,
+                    entry.privileges.into(),
+                    &entry.columns,
+                );
+            } else {
+                self.grant_privileges(&entry.object, entry.privileges.into());
+            }
         }
         for role in other.roles() {
             self.grant_role(role);
@@ -15,6 +15,8 @@
 use core::fmt;
 use std::convert::TryFrom;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use enumflags2::bitflags;
@@ -23,6 +25,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::principal::AuthInfo;
+use crate::principal::PasswordPolicy;
 use crate::principal::UserGrantSet;
 use crate::principal::UserIdentity;
 use crate::principal::UserQuota;
@@ -41,6 +44,17 @@ pub struct UserInfo {
     pub quota: UserQuota,
 
     pub option: UserOption,
+
+    /// The previous auth infos of the user, the most recent one is the last.
+    pub history_auth_infos: Vec<AuthInfo>,
+
+    /// The time of each consecutive failed login, cleared after a successful login.
+    pub password_fails: Vec<DateTime<Utc>>,
+
+    pub password_update_on: Option<DateTime<Utc>>,
+
+    /// The user can't login until this time, after too many failed logins.
+    pub lockout_time: Option<DateTime<Utc>>,
 }
 
 impl UserInfo {
@@ -57,6 +71,10 @@ impl UserInfo {
             grants,
             quota,
             option,
+            history_auth_infos: vec![],
+            password_fails: vec![],
+            password_update_on: None,
+            lockout_time: None,
         }
     }
 
@@ -77,12 +95,32 @@ impl UserInfo {
 
     pub fn update_auth_option(&mut self, auth: Option<AuthInfo>, option: Option<UserOption>) {
         if let Some(auth_info) = auth {
-            self.auth_info = auth_info;
+            self.update_auth_history(auth_info);
         };
         if let Some(user_option) = option {
             self.option = user_option;
         };
     }
+
+    /// Replace the auth info, the previous one is kept in `history_auth_infos` if it is a password,
+    /// at most `PasswordPolicy::MAX_HISTORY` are kept.
+    pub fn update_auth_history(&mut self, auth_info: AuthInfo) {
+        if auth_info == self.auth_info {
+            return;
+        }
+        let prev = std::mem::replace(&mut self.auth_info, auth_info);
+        if matches!(prev, AuthInfo::Password { .. }) {
+            self.history_auth_infos.push(prev);
+            let max_history = PasswordPolicy::MAX_HISTORY as usize;
+            if self.history_auth_infos.len() > max_history {
+                let n = self.history_auth_infos.len() - max_history;
+                self.history_auth_infos.drain(..n);
+            }
+        }
+        self.password_update_on = Some(Utc::now());
+        self.password_fails.clear();
+        self.lockout_time = None;
+    }
 }
 
 impl TryFrom<Vec<u8>> for UserInfo {
@@ -107,6 +145,10 @@ pub struct UserOption {
     default_role: Option<String>,
 
     network_policy: Option<String>,
+
+    workload_group: Option<String>,
+
+    password_policy: Option<String>,
 }
 
 impl UserOption {
@@ -115,6 +157,8 @@ impl UserOption {
             flags,
             default_role: None,
             network_policy: None,
+            workload_group: None,
+            password_policy: None,
         }
     }
 
@@ -137,6 +181,16 @@ impl UserOption {
         self
     }
 
+    pub fn with_workload_group(mut self, workload_group: Option<String>) -> Self {
+        self.workload_group = workload_group;
+        self
+    }
+
+    pub fn with_password_policy(mut self, password_policy: Option<String>) -> Self {
+        self.password_policy = password_policy;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -154,6 +208,14 @@ impl UserOption {
         self.network_policy.as_ref()
     }
 
+    pub fn workload_group(&self) -> Option<&String> {
+        self.workload_group.as_ref()
+    }
+
+    pub fn password_policy(&self) -> Option<&String> {
+        self.password_policy.as_ref()
+    }
+
     pub fn set_default_role(&mut self, default_role: Option<String>) {
         self.default_role = default_role;
     }
@@ -162,6 +224,14 @@ impl UserOption {
         self.network_policy = network_policy;
     }
 
+    pub fn set_workload_group(&mut self, workload_group: Option<String>) {
+        self.workload_group = workload_group;
+    }
+
+    pub fn set_password_policy(&mut self, password_policy: Option<String>) {
+        self.password_policy = password_policy;
+    }
+
     pub fn set_all_flag(&mut self) {
         self.flags = BitFlags::all();
     }
@@ -64,6 +64,9 @@ pub enum UserPrivilegeType {
     DropUser = 1 << 15,
     // Privilege to Create/Drop DataMask.
     CreateDataMask = 1 << 16,
+    // Ownership of a database, table, stage or udf, which implies all the privileges on it.
+    // It is never granted by `GRANT ALL`, see `OwnershipInfo`.
+    Ownership = 1 << 17,
     // TODO: remove this later
     Set = 1 << 4,
 }
@@ -109,6 +112,7 @@ impl std::fmt::Display for UserPrivilegeType {
             UserPrivilegeType::Grant => "GRANT",
             UserPrivilegeType::Set => "SET",
             UserPrivilegeType::CreateDataMask => "CREATE DATAMASK",
+            UserPrivilegeType::Ownership => "OWNERSHIP",
         })
     }
 }
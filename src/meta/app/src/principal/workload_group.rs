@@ -0,0 +1,40 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A named group of queries sharing CPU, memory and concurrency limits.
+///
+/// Users and roles are assigned to a group by name, see `UserOption::workload_group` and
+/// `RoleInfo::workload_group`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct WorkloadGroup {
+    pub name: String,
+    /// The relative weight of the group when the CPU is shared with other busy groups.
+    pub cpu_share: u64,
+    /// The max memory(bytes) can be used by all queries of the group (0 is no limited).
+    pub memory_limit: u64,
+    /// The max number of queries of the group running at the same time (0 is no limited).
+    pub max_concurrency: u64,
+    /// The max seconds a query waits in the queue of the group (0 is no limited).
+    pub queue_timeout: u64,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
+
+impl WorkloadGroup {
+    pub const DEFAULT_CPU_SHARE: u64 = 100;
+}
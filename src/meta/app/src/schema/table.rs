@@ -24,6 +24,7 @@ use std::sync::Arc;
 use chrono::DateTime;
 use chrono::Utc;
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_meta_types::MatchSeq;
@@ -237,6 +238,42 @@ pub struct TableMeta {
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
+    // Secondary indexes defined on this table, keyed by index name.
+    pub indexes: BTreeMap<String, TableIndex>,
+}
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TableIndexType {
+    Inverted = 0,
+}
+
+impl Display for TableIndexType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TableIndexType::Inverted => write!(f, "INVERTED"),
+        }
+    }
+}
+
+/// A secondary index defined on some columns of a table.
+///
+/// The index data is built per block when the block is written,
+/// `options` holds the index type specific settings, e.g. the tokenizer of an inverted index.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableIndex {
+    pub index_type: TableIndexType,
+    pub name: String,
+    pub column_ids: Vec<ColumnId>,
+    pub options: BTreeMap<String, String>,
 }
 
 impl TableMeta {
@@ -334,6 +371,7 @@ impl Default for TableMeta {
             statistics: Default::default(),
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
+            indexes: BTreeMap::new(),
         }
     }
 }
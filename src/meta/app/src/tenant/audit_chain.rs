@@ -0,0 +1,40 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+
+/// The last record of the audit chain of a query node.
+///
+/// It is kept in the meta service, out of reach of the audit log files, the chain continues from
+/// it after the node restarts and a truncated log can be told by it.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct AuditChainHead {
+    pub seq: u64,
+    pub hash: String,
+}
+
+impl TryFrom<Vec<u8>> for AuditChainHead {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> common_exception::Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(head) => Ok(head),
+            Err(err) => Err(ErrorCode::IllegalAuditChainFormat(format!(
+                "Cannot deserialize audit chain head from bytes. cause {}",
+                err
+            ))),
+        }
+    }
+}
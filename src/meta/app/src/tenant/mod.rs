@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod audit_chain;
 mod quota;
+mod resource_usage;
 
+pub use audit_chain::AuditChainHead;
 pub use quota::TenantQuota;
+pub use resource_usage::ResourceUsage;
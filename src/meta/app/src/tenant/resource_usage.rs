@@ -0,0 +1,76 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+
+/// The resources consumed by the queries of a user with a role in a warehouse, in one day.
+///
+/// Usages of the queries finished on the same day are summed up into one record, for chargeback.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct ResourceUsage {
+    // Days since the unix epoch.
+    pub usage_date: i32,
+
+    pub user: String,
+
+    // The current role of the user when the queries ran.
+    pub role: String,
+
+    // The cluster id of the query nodes which ran the queries.
+    pub warehouse: String,
+
+    pub query_count: u64,
+
+    // The time the pipeline executor spent in processors.
+    pub cpu_time_ms: u64,
+
+    // The bytes read from the storage by the fuse tables.
+    pub scan_bytes: u64,
+
+    // The bytes spilled to the storage by aggregations.
+    pub spill_bytes: u64,
+
+    // The bytes of the results sent to the client.
+    pub result_bytes: u64,
+
+    // The bytes written to the storage by the fuse tables.
+    pub written_bytes: u64,
+}
+
+impl ResourceUsage {
+    /// Add up the usages of `other`, which should be of the same day, user, role and warehouse.
+    pub fn merge(&mut self, other: &ResourceUsage) {
+        self.query_count = self.query_count.saturating_add(other.query_count);
+        self.cpu_time_ms = self.cpu_time_ms.saturating_add(other.cpu_time_ms);
+        self.scan_bytes = self.scan_bytes.saturating_add(other.scan_bytes);
+        self.spill_bytes = self.spill_bytes.saturating_add(other.spill_bytes);
+        self.result_bytes = self.result_bytes.saturating_add(other.result_bytes);
+        self.written_bytes = self.written_bytes.saturating_add(other.written_bytes);
+    }
+}
+
+impl TryFrom<Vec<u8>> for ResourceUsage {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> common_exception::Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(usage) => Ok(usage),
+            Err(err) => Err(ErrorCode::IllegalResourceUsageFormat(format!(
+                "Cannot deserialize resource usage from bytes. cause {}",
+                err
+            ))),
+        }
+    }
+}
@@ -13,6 +13,7 @@
 //  limitations under the License.
 
 mod file_format;
+mod user_auth;
 mod user_defined_function;
 mod user_grant;
 mod user_info;
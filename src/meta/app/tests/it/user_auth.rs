@@ -0,0 +1,50 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::exception::Result;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::AuthType;
+
+#[test]
+fn test_key_pair_rotation() -> Result<()> {
+    let key = |k: &str| Some(k.to_string());
+
+    let auth_info = AuthInfo::create2(&Some(AuthType::KeyPair), &key("k1"))?;
+    assert_eq!(auth_info, AuthInfo::KeyPair {
+        public_key: "k1".to_string(),
+        public_key_2: None,
+    });
+
+    // A new key moves the current key to the second slot.
+    let auth_info = auth_info.alter2(&None, &key("k2"))?;
+    assert_eq!(auth_info, AuthInfo::KeyPair {
+        public_key: "k2".to_string(),
+        public_key_2: key("k1"),
+    });
+
+    // The same key finishes the rotation.
+    let auth_info = auth_info.alter2(&Some(AuthType::KeyPair), &key("k2"))?;
+    assert_eq!(auth_info, AuthInfo::KeyPair {
+        public_key: "k2".to_string(),
+        public_key_2: None,
+    });
+
+    // Switching to another auth type drops the keys.
+    let auth_info = auth_info.alter2(&Some(AuthType::OIDC), &None)?;
+    assert_eq!(auth_info, AuthInfo::OIDC);
+
+    assert!(AuthInfo::create2(&Some(AuthType::KeyPair), &None).is_err());
+
+    Ok(())
+}
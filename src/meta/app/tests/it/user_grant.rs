@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
+
 use common_exception::exception::Result;
 use common_meta_app::principal::GrantEntry;
 use common_meta_app::principal::GrantObject;
@@ -200,3 +202,74 @@ fn test_user_grant_set() -> Result<()> {
     ));
     Ok(())
 }
+
+#[test]
+fn test_user_grant_set_columns() -> Result<()> {
+    let table = GrantObject::Table("default".into(), "db1".into(), "table1".into());
+    let columns = |names: &[&str]| -> BTreeSet<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    };
+    let mut grants = UserGrantSet::empty();
+
+    grants.grant_column_privileges(
+        &table,
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+        &columns(&["a", "b"]),
+    );
+    grants.grant_column_privileges(
+        &table,
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+        &columns(&["c"]),
+    );
+    assert_eq!(1, grants.entries().len());
+    assert_eq!(
+        "GRANT SELECT(a,b,c) ON 'default'.'db1'.'table1'",
+        grants.entries()[0].to_string()
+    );
+    assert_eq!(
+        columns(&["a", "b", "c"]),
+        grants.granted_columns(&table, UserPrivilegeType::Select)
+    );
+
+    // column-level grants do not grant the privilege on the whole table.
+    assert!(!grants.verify_privilege(&table, vec![UserPrivilegeType::Select]));
+
+    // table-level grants and revokes leave the column-level entries alone.
+    grants.grant_privileges(&table, make_bitflags!(UserPrivilegeType::{Select}).into());
+    assert_eq!(2, grants.entries().len());
+    assert!(grants.verify_privilege(&table, vec![UserPrivilegeType::Select]));
+    grants.revoke_privileges(&table, make_bitflags!(UserPrivilegeType::{Select}).into());
+    assert_eq!(1, grants.entries().len());
+    assert!(!grants.verify_privilege(&table, vec![UserPrivilegeType::Select]));
+
+    grants.revoke_column_privileges(
+        &table,
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+        &columns(&["a", "c"]),
+    );
+    assert_eq!(
+        columns(&["b"]),
+        grants.granted_columns(&table, UserPrivilegeType::Select)
+    );
+    grants.revoke_column_privileges(
+        &table,
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+        &columns(&["b"]),
+    );
+    assert!(grants.entries().is_empty());
+
+    // column-level entries are kept when merging the grant sets of roles.
+    let mut role_grants = UserGrantSet::empty();
+    role_grants.grant_column_privileges(
+        &table,
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+        &columns(&["d"]),
+    );
+    grants |= role_grants;
+    assert_eq!(
+        columns(&["d"]),
+        grants.granted_columns(&table, UserPrivilegeType::Select)
+    );
+    assert!(!grants.verify_privilege(&table, vec![UserPrivilegeType::Select]));
+    Ok(())
+}
@@ -21,6 +21,7 @@ use common_meta_app::schema as mt;
 use common_meta_app::schema::CatalogOption;
 use common_meta_app::schema::HiveCatalogOption;
 use common_meta_app::schema::IcebergCatalogOption;
+use common_meta_app::schema::RdbmsCatalogOption;
 use common_meta_app::storage::StorageParams;
 use common_protos::pb;
 
@@ -90,6 +91,9 @@ impl FromToProto for mt::CatalogMeta {
                         )?),
                     })
                 }
+                pb::catalog_option::CatalogOption::Rdbms(v) => {
+                    CatalogOption::Rdbms(RdbmsCatalogOption { url: v.url })
+                }
             },
             created_on: DateTime::<Utc>::from_pb(p.created_on)?,
         };
@@ -120,6 +124,15 @@ impl FromToProto for mt::CatalogMeta {
                         },
                     )),
                 }),
+                CatalogOption::Rdbms(v) => Some(pb::CatalogOption {
+                    catalog_option: Some(pb::catalog_option::CatalogOption::Rdbms(
+                        pb::RdbmsCatalogOption {
+                            ver: VER,
+                            min_reader_ver: MIN_READER_VER,
+                            url: v.url,
+                        },
+                    )),
+                }),
             },
             created_on: self.created_on.to_pb()?,
         };
@@ -25,6 +25,7 @@ use common_expression as ex;
 use common_meta_app::schema as mt;
 use common_meta_app::storage::StorageParams;
 use common_protos::pb;
+use num::FromPrimitive;
 
 use crate::reader_check_msg;
 use crate::FromToProto;
@@ -227,6 +228,11 @@ impl FromToProto for mt::TableMeta {
             } else {
                 Some(p.column_mask_policy)
             },
+            indexes: p
+                .indexes
+                .into_iter()
+                .map(|(name, index)| Ok((name, mt::TableIndex::from_pb(index)?)))
+                .collect::<Result<BTreeMap<_, _>, Incompatible>>()?,
         };
         Ok(v)
     }
@@ -266,6 +272,43 @@ impl FromToProto for mt::TableMeta {
                 Some(column_mask_policy) => column_mask_policy.clone(),
                 None => BTreeMap::new(),
             },
+            indexes: self
+                .indexes
+                .iter()
+                .map(|(name, index)| Ok((name.clone(), index.to_pb()?)))
+                .collect::<Result<BTreeMap<_, _>, Incompatible>>()?,
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableIndex {
+    type PB = pb::TableIndex;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableIndex) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            index_type: FromPrimitive::from_i32(p.index_type).ok_or_else(|| Incompatible {
+                reason: format!("invalid TableIndexType: {}", p.index_type),
+            })?,
+            name: p.name,
+            column_ids: p.column_ids,
+            options: p.options,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableIndex, Incompatible> {
+        let p = pb::TableIndex {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            index_type: self.index_type as i32,
+            name: self.name.clone(),
+            column_ids: self.column_ids.clone(),
+            options: self.options.clone(),
         };
         Ok(p)
     }
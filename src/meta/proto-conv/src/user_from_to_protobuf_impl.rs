@@ -47,6 +47,16 @@ impl FromToProto for mt::principal::AuthInfo {
             Some(pb::auth_info::Info::Jwt(pb::auth_info::Jwt {})) => {
                 Ok(mt::principal::AuthInfo::JWT)
             }
+            Some(pb::auth_info::Info::KeyPair(pb::auth_info::KeyPair {
+                public_key,
+                public_key_2,
+            })) => Ok(mt::principal::AuthInfo::KeyPair {
+                public_key,
+                public_key_2,
+            }),
+            Some(pb::auth_info::Info::Oidc(pb::auth_info::Oidc {})) => {
+                Ok(mt::principal::AuthInfo::OIDC)
+            }
             Some(pb::auth_info::Info::Password(pb::auth_info::Password {
                 hash_value,
                 hash_method,
@@ -68,6 +78,16 @@ impl FromToProto for mt::principal::AuthInfo {
                 Some(pb::auth_info::Info::None(pb::auth_info::None {}))
             }
             mt::principal::AuthInfo::JWT => Some(pb::auth_info::Info::Jwt(pb::auth_info::Jwt {})),
+            mt::principal::AuthInfo::KeyPair {
+                public_key,
+                public_key_2,
+            } => Some(pb::auth_info::Info::KeyPair(pb::auth_info::KeyPair {
+                public_key: public_key.clone(),
+                public_key_2: public_key_2.clone(),
+            })),
+            mt::principal::AuthInfo::OIDC => {
+                Some(pb::auth_info::Info::Oidc(pb::auth_info::Oidc {}))
+            }
             mt::principal::AuthInfo::Password {
                 hash_value,
                 hash_method,
@@ -99,7 +119,9 @@ impl FromToProto for mt::principal::UserOption {
         Ok(mt::principal::UserOption::default()
             .with_flags(flags)
             .with_default_role(p.default_role)
-            .with_network_policy(p.network_policy))
+            .with_network_policy(p.network_policy)
+            .with_workload_group(p.workload_group)
+            .with_password_policy(p.password_policy))
     }
 
     fn to_pb(&self) -> Result<pb::UserOption, Incompatible> {
@@ -109,6 +131,8 @@ impl FromToProto for mt::principal::UserOption {
             flags: self.flags().bits(),
             default_role: self.default_role().cloned(),
             network_policy: self.network_policy().cloned(),
+            workload_group: self.workload_group().cloned(),
+            password_policy: self.password_policy().cloned(),
         })
     }
 }
@@ -211,7 +235,8 @@ impl FromToProto for mt::principal::GrantEntry {
                     reason: "GrantEntry.object can not be None".to_string(),
                 })?)?,
                 privileges,
-            )),
+            )
+            .with_columns(p.columns.into_iter().collect())),
             Err(e) => Err(Incompatible {
                 reason: format!("UserPrivilegeType error: {}", e),
             }),
@@ -224,6 +249,7 @@ impl FromToProto for mt::principal::GrantEntry {
             min_reader_ver: MIN_READER_VER,
             object: Some(self.object().to_pb()?),
             privileges: self.privileges().bits(),
+            columns: self.columns().iter().cloned().collect(),
         })
     }
 }
@@ -296,6 +322,24 @@ impl FromToProto for mt::principal::UserInfo {
             option: mt::principal::UserOption::from_pb(p.option.ok_or_else(|| Incompatible {
                 reason: "UserInfo.option cannot be None".to_string(),
             })?)?,
+            history_auth_infos: p
+                .history_auth_infos
+                .into_iter()
+                .map(mt::principal::AuthInfo::from_pb)
+                .collect::<Result<Vec<_>, _>>()?,
+            password_fails: p
+                .password_fails
+                .into_iter()
+                .map(DateTime::<Utc>::from_pb)
+                .collect::<Result<Vec<_>, _>>()?,
+            password_update_on: match p.password_update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+            lockout_time: match p.lockout_time {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
         })
     }
 
@@ -309,6 +353,24 @@ impl FromToProto for mt::principal::UserInfo {
             grants: Some(mt::principal::UserGrantSet::to_pb(&self.grants)?),
             quota: Some(mt::principal::UserQuota::to_pb(&self.quota)?),
             option: Some(mt::principal::UserOption::to_pb(&self.option)?),
+            history_auth_infos: self
+                .history_auth_infos
+                .iter()
+                .map(mt::principal::AuthInfo::to_pb)
+                .collect::<Result<Vec<_>, _>>()?,
+            password_fails: self
+                .password_fails
+                .iter()
+                .map(|t| t.to_pb())
+                .collect::<Result<Vec<_>, _>>()?,
+            password_update_on: match &self.password_update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+            lockout_time: match &self.lockout_time {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
         })
     }
 }
@@ -375,3 +437,97 @@ impl FromToProto for mt::principal::NetworkPolicy {
         })
     }
 }
+
+impl FromToProto for mt::principal::WorkloadGroup {
+    type PB = pb::WorkloadGroup;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::WorkloadGroup) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::WorkloadGroup {
+            name: p.name,
+            cpu_share: p.cpu_share,
+            memory_limit: p.memory_limit,
+            max_concurrency: p.max_concurrency,
+            queue_timeout: p.queue_timeout,
+            comment: p.comment,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+            update_on: match p.update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::WorkloadGroup, Incompatible> {
+        Ok(pb::WorkloadGroup {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            cpu_share: self.cpu_share,
+            memory_limit: self.memory_limit,
+            max_concurrency: self.max_concurrency,
+            queue_timeout: self.queue_timeout,
+            comment: self.comment.clone(),
+            create_on: self.create_on.to_pb()?,
+            update_on: match &self.update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        })
+    }
+}
+
+impl FromToProto for mt::principal::PasswordPolicy {
+    type PB = pb::PasswordPolicy;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::PasswordPolicy) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::PasswordPolicy {
+            name: p.name,
+            min_length: p.min_length,
+            min_upper_case_chars: p.min_upper_case_chars,
+            min_lower_case_chars: p.min_lower_case_chars,
+            min_numeric_chars: p.min_numeric_chars,
+            min_special_chars: p.min_special_chars,
+            max_age_days: p.max_age_days,
+            max_retries: p.max_retries,
+            lockout_time_mins: p.lockout_time_mins,
+            history: p.history,
+            comment: p.comment,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+            update_on: match p.update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::PasswordPolicy, Incompatible> {
+        Ok(pb::PasswordPolicy {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            min_length: self.min_length,
+            min_upper_case_chars: self.min_upper_case_chars,
+            min_lower_case_chars: self.min_lower_case_chars,
+            min_numeric_chars: self.min_numeric_chars,
+            min_special_chars: self.min_special_chars,
+            max_age_days: self.max_age_days,
+            max_retries: self.max_retries,
+            lockout_time_mins: self.lockout_time_mins,
+            history: self.history,
+            comment: self.comment.clone(),
+            create_on: self.create_on.to_pb()?,
+            update_on: match &self.update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        })
+    }
+}
@@ -79,7 +79,14 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (47, "2023-07-03: Add: catalog.proto/CatalogMeta",),
     (48, "2023-07-04: Add: ManualTriggerParams on background_job", ),
     (49, "2023-07-14: Add: user.proto/NetworkPolicy", ),
-    (50, "2023-07-20: Add: user.proto/UserOption::network_policy", )
+    (50, "2023-07-20: Add: user.proto/UserOption::network_policy", ),
+    (51, "2023-07-24: Add: catalog.proto/RdbmsCatalogOption", ),
+    (52, "2023-07-26: Add: table.proto/TableIndex and TableMeta::indexes", ),
+    (53, "2023-07-28: Add: row_access_policy.proto/RowAccessPolicyMeta", ),
+    (54, "2023-07-31: Add: user.proto/WorkloadGroup and UserOption::workload_group", ),
+    (55, "2023-08-02: Add: user.proto/PasswordPolicy, UserOption::password_policy and UserInfo password history, fails, update_on, lockout_time", ),
+    (56, "2023-08-04: Add: user.proto/AuthInfo::KeyPair and AuthInfo::OIDC", ),
+    (57, "2023-08-07: Add: user.proto/GrantEntry::columns", )
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
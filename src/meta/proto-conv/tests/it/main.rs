@@ -55,3 +55,10 @@ mod v047_catalog_meta;
 mod v048_background;
 mod v049_network_policy;
 mod v050_user_info;
+mod v051_catalog_meta;
+mod v052_table_meta;
+mod v053_row_access_policy;
+mod v054_workload_group;
+mod v055_password_policy;
+mod v056_auth_info;
+mod v057_grant_entry_columns;
@@ -203,6 +203,12 @@ fn new_table_meta() -> mt::TableMeta {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        indexes: btreemap! {s("idx1") => mt::TableIndex {
+            index_type: mt::TableIndexType::Inverted,
+            name: s("idx1"),
+            column_ids: vec![20],
+            options: btreemap! {s("tokenizer") => s("standard")},
+        }},
     }
 }
 
@@ -0,0 +1,99 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use chrono::TimeZone;
+use chrono::Utc;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v55_password_policy() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 2, 112, 112, 16, 10, 24, 2, 32, 2, 40, 2, 48, 1, 56, 30, 64, 3, 72, 20, 80, 5, 90, 6,
+        115, 116, 114, 105, 99, 116, 98, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50,
+        58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 106, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56,
+        32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 55, 168, 6, 24,
+    ];
+
+    let want = || common_meta_app::principal::PasswordPolicy {
+        name: "pp".to_string(),
+        min_length: 10,
+        min_upper_case_chars: 2,
+        min_lower_case_chars: 2,
+        min_numeric_chars: 2,
+        min_special_chars: 1,
+        max_age_days: 30,
+        max_retries: 3,
+        lockout_time_mins: 20,
+        history: 5,
+        comment: "strict".to_string(),
+        create_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        update_on: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 55, want())
+}
+
+#[test]
+fn test_decode_v55_user_info() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 1, 117, 18, 1, 37, 26, 15, 18, 7, 10, 3, 110, 101, 119, 16, 2, 160, 6, 55, 168, 6, 24,
+        34, 6, 160, 6, 55, 168, 6, 24, 42, 6, 160, 6, 55, 168, 6, 24, 50, 10, 42, 2, 112, 112, 160,
+        6, 55, 168, 6, 24, 58, 15, 18, 7, 10, 3, 111, 108, 100, 16, 2, 160, 6, 55, 168, 6, 24, 66,
+        23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84,
+        67, 74, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32,
+        85, 84, 67, 82, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 49, 53, 58, 48,
+        57, 32, 85, 84, 67, 160, 6, 55, 168, 6, 24,
+    ];
+
+    let want = || common_meta_app::principal::UserInfo {
+        name: "u".to_string(),
+        hostname: "%".to_string(),
+        auth_info: common_meta_app::principal::AuthInfo::Password {
+            hash_value: b"new".to_vec(),
+            hash_method: common_meta_app::principal::PasswordHashMethod::Sha256,
+        },
+        grants: common_meta_app::principal::UserGrantSet::new(vec![], HashSet::new()),
+        quota: common_meta_app::principal::UserQuota {
+            max_cpu: 0,
+            max_memory_in_bytes: 0,
+            max_storage_in_bytes: 0,
+        },
+        option: common_meta_app::principal::UserOption::default()
+            .with_password_policy(Some("pp".to_string())),
+        history_auth_infos: vec![common_meta_app::principal::AuthInfo::Password {
+            hash_value: b"old".to_vec(),
+            hash_method: common_meta_app::principal::PasswordHashMethod::Sha256,
+        }],
+        password_fails: vec![Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()],
+        password_update_on: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+        lockout_time: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 15, 9).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 55, want())
+}
@@ -0,0 +1,50 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v56_key_pair_auth_info() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        34, 10, 10, 3, 112, 107, 49, 18, 3, 112, 107, 50, 160, 6, 56, 168, 6, 24,
+    ];
+
+    let want = || common_meta_app::principal::AuthInfo::KeyPair {
+        public_key: "pk1".to_string(),
+        public_key_2: Some("pk2".to_string()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 56, want())
+}
+
+#[test]
+fn test_decode_v56_oidc_auth_info() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![42, 0, 160, 6, 56, 168, 6, 24];
+
+    let want = || common_meta_app::principal::AuthInfo::OIDC;
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 56, want())
+}
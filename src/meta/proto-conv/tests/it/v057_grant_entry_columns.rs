@@ -0,0 +1,49 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use common_meta_app as mt;
+use common_meta_app::principal::UserPrivilegeType;
+use enumflags2::make_bitflags;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v57_grant_entry_columns() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 26, 26, 18, 10, 7, 100, 101, 102, 97, 117, 108, 116, 18, 3, 100, 98, 49, 26, 2, 116,
+        49, 160, 6, 57, 168, 6, 24, 16, 4, 26, 1, 97, 26, 1, 98, 160, 6, 57, 168, 6, 24,
+    ];
+
+    let want = || {
+        mt::principal::GrantEntry::new(
+            mt::principal::GrantObject::Table("default".into(), "db1".into(), "t1".into()),
+            make_bitflags!(UserPrivilegeType::{Select}),
+        )
+        .with_columns(BTreeSet::from(["a".to_string(), "b".to_string()]))
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 57, want())
+}
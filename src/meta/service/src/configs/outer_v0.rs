@@ -19,6 +19,7 @@ use clap::Parser;
 use common_meta_raft_store::config::get_default_raft_advertise_host;
 use common_meta_raft_store::config::RaftConfig as InnerRaftConfig;
 use common_meta_types::MetaStartupError;
+use common_tracing::AuditLogConfig;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
 use common_tracing::QueryLogConfig;
@@ -583,6 +584,10 @@ impl Into<InnerLogConfig> for LogConfig {
                 on: false,
                 dir: "".to_string(),
             },
+            audit: AuditLogConfig {
+                on: false,
+                dir: "".to_string(),
+            },
             tracing: TracingConfig::from_env(),
         }
     }
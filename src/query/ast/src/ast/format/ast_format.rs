@@ -936,6 +936,14 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         let mut children = Vec::new();
         self.visit_table_ref(&insert.catalog, &insert.database, &insert.table);
         children.push(self.children.pop().unwrap());
+        if let Some(partition) = &insert.partition {
+            self.visit_expr(partition);
+            let partition_child = self.children.pop().unwrap();
+            let partition_format_ctx = AstFormatContext::with_children("Partition".to_string(), 1);
+            let partition_node =
+                FormatTreeNode::with_children(partition_format_ctx, vec![partition_child]);
+            children.push(partition_node);
+        }
         if !insert.columns.is_empty() {
             let mut columns_children = Vec::with_capacity(insert.columns.len());
             for column in insert.columns.iter() {
@@ -1257,6 +1265,39 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 FormatTreeNode::with_children(cluster_by_format_ctx, cluster_by_children);
             children.push(cluster_by_node);
         }
+        if let Some(partition_by) = &stmt.partition_by {
+            self.visit_expr(partition_by);
+            let partition_by_child = self.children.pop().unwrap();
+            let partition_by_format_ctx =
+                AstFormatContext::with_children("PartitionBy".to_string(), 1);
+            let partition_by_node =
+                FormatTreeNode::with_children(partition_by_format_ctx, vec![partition_by_child]);
+            children.push(partition_by_node);
+        }
+        if let Some(ttl) = &stmt.ttl {
+            self.visit_expr(ttl);
+            let ttl_child = self.children.pop().unwrap();
+            let ttl_format_ctx = AstFormatContext::with_children("Ttl".to_string(), 1);
+            let ttl_node = FormatTreeNode::with_children(ttl_format_ctx, vec![ttl_child]);
+            children.push(ttl_node);
+        }
+        if !stmt.constraints.is_empty() {
+            let constraints_children = stmt
+                .constraints
+                .iter()
+                .map(|constraint| {
+                    let constraint_name = format!("TableConstraint {}", constraint);
+                    FormatTreeNode::new(AstFormatContext::new(constraint_name))
+                })
+                .collect::<Vec<_>>();
+            let constraints_format_ctx = AstFormatContext::with_children(
+                "TableConstraints".to_string(),
+                constraints_children.len(),
+            );
+            let constraints_node =
+                FormatTreeNode::with_children(constraints_format_ctx, constraints_children);
+            children.push(constraints_node);
+        }
         if !stmt.table_options.is_empty() {
             let mut table_options_children = Vec::with_capacity(stmt.table_options.len());
             for (k, v) in stmt.table_options.iter() {
@@ -1417,6 +1458,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::DropPartition { partition, .. } => {
+                self.visit_expr(partition);
+                let partition_child = self.children.pop().unwrap();
+                let action_name = "Action DropPartition".to_string();
+                let action_format_ctx = AstFormatContext::with_children(action_name, 1);
+                FormatTreeNode::with_children(action_format_ctx, vec![partition_child])
+            }
+            AlterTableAction::TruncatePartition { partition } => {
+                self.visit_expr(partition);
+                let partition_child = self.children.pop().unwrap();
+                let action_name = "Action TruncatePartition".to_string();
+                let action_format_ctx = AstFormatContext::with_children(action_name, 1);
+                FormatTreeNode::with_children(action_format_ctx, vec![partition_child])
+            }
             AlterTableAction::ReclusterTable {
                 selection, limit, ..
             } => {
@@ -1450,6 +1505,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let action_name = format!(
+                    "Action AddRowAccessPolicy {} on {}",
+                    policy,
+                    columns
+                        .iter()
+                        .map(|c| c.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                let action_name = format!("Action DropRowAccessPolicy {}", policy);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1622,6 +1695,46 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_inverted_index(&mut self, stmt: &'ast CreateInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let mut columns_children = Vec::with_capacity(stmt.columns.len());
+        for column in stmt.columns.iter() {
+            self.visit_identifier(column);
+            columns_children.push(self.children.pop().unwrap());
+        }
+        let columns_name = "Columns".to_string();
+        let columns_ctx = AstFormatContext::with_children(columns_name, columns_children.len());
+        let columns_child = FormatTreeNode::with_children(columns_ctx, columns_children);
+
+        let mut children = vec![index_child, table_child, columns_child];
+        if let Some(tokenizer) = &stmt.tokenizer {
+            let name = format!("Tokenizer {}", tokenizer);
+            let tokenizer_ctx = AstFormatContext::new(name);
+            children.push(FormatTreeNode::new(tokenizer_ctx));
+        }
+
+        let name = "CreateInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_inverted_index(&mut self, stmt: &'ast DropInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
     fn visit_create_virtual_columns(&mut self, stmt: &'ast CreateVirtualColumnsStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let table_child = self.children.pop().unwrap();
@@ -1831,6 +1944,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::with_children(privileges_name, privileges_children.len());
                 FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
             }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                ..
+            } => {
+                let mut privileges_children = Vec::with_capacity(privileges.len());
+                for privilege in privileges.iter() {
+                    let privilege_name = format!("Privilege {}", privilege);
+                    let privilege_format_ctx = AstFormatContext::new(privilege_name);
+                    privileges_children.push(FormatTreeNode::new(privilege_format_ctx));
+                }
+                for column in columns.iter() {
+                    let column_name = format!("Column {}", column);
+                    let column_format_ctx = AstFormatContext::new(column_name);
+                    privileges_children.push(FormatTreeNode::new(column_format_ctx));
+                }
+                let privileges_name = "ColumnPrivileges".to_string();
+                let privileges_format_ctx =
+                    AstFormatContext::with_children(privileges_name, privileges_children.len());
+                FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
+            }
             AccountMgrSource::ALL { .. } => {
                 let all_name = "All".to_string();
                 let all_format_ctx = AstFormatContext::new(all_name);
@@ -1885,6 +2019,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::with_children(privileges_name, privileges_children.len());
                 FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
             }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                ..
+            } => {
+                let mut privileges_children = Vec::with_capacity(privileges.len());
+                for privilege in privileges.iter() {
+                    let privilege_name = format!("Privilege {}", privilege);
+                    let privilege_format_ctx = AstFormatContext::new(privilege_name);
+                    privileges_children.push(FormatTreeNode::new(privilege_format_ctx));
+                }
+                for column in columns.iter() {
+                    let column_name = format!("Column {}", column);
+                    let column_format_ctx = AstFormatContext::new(column_name);
+                    privileges_children.push(FormatTreeNode::new(column_format_ctx));
+                }
+                let privileges_name = "ColumnPrivileges".to_string();
+                let privileges_format_ctx =
+                    AstFormatContext::with_children(privileges_name, privileges_children.len());
+                FormatTreeNode::with_children(privileges_format_ctx, privileges_children)
+            }
             AccountMgrSource::ALL { .. } => {
                 let all_name = "All".to_string();
                 let all_format_ctx = AstFormatContext::new(all_name);
@@ -2243,6 +2398,18 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_grant_ownership(&mut self, grant: &'ast GrantOwnershipStmt) {
+        let object_format_ctx = AstFormatContext::new(format!("Object {}", grant.object));
+        let object_child = FormatTreeNode::new(object_format_ctx);
+        let role_format_ctx = AstFormatContext::new(format!("Role {}", grant.role));
+        let role_child = FormatTreeNode::new(role_format_ctx);
+
+        let name = "GrantOwnership".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![object_child, role_child]);
+        self.children.push(node);
+    }
+
     fn visit_show_object_grant_privileges(&mut self, stmt: &'ast ShowObjectGrantPrivilegesStmt) {
         let object_format_ctx = AstFormatContext::new(format!("Object {}", stmt.object));
         let child = FormatTreeNode::new(object_format_ctx);
@@ -2293,6 +2460,36 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_row_access_policy(&mut self, stmt: &'ast CreateRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_row_access_policy(&mut self, stmt: &'ast DropRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_row_access_policy(&mut self, stmt: &'ast DescRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_network_policy(&mut self, stmt: &'ast CreateNetworkPolicyStmt) {
         let ctx = AstFormatContext::new(format!("NetworkPolicyName {}", stmt.name));
         let child = FormatTreeNode::new(ctx);
@@ -2339,6 +2536,92 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_password_policy(&mut self, stmt: &'ast CreatePasswordPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("PasswordPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreatePasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_password_policy(&mut self, stmt: &'ast AlterPasswordPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("PasswordPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterPasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_password_policy(&mut self, stmt: &'ast DropPasswordPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("PasswordPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropPasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_password_policy(&mut self, stmt: &'ast DescPasswordPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("PasswordPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescPasswordPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_password_policies(&mut self) {
+        let ctx = AstFormatContext::new("ShowPasswordPolicies".to_string());
+        let node = FormatTreeNode::new(ctx);
+        self.children.push(node);
+    }
+
+    fn visit_create_workload_group(&mut self, stmt: &'ast CreateWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_workload_group(&mut self, stmt: &'ast AlterWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_workload_group(&mut self, stmt: &'ast DropWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_role_workload_group(&mut self, stmt: &'ast AlterRoleWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("RoleName {}", stmt.role_name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterRoleWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_with(&mut self, with: &'ast With) {
         let mut children = Vec::with_capacity(with.ctes.len());
         for cte in with.ctes.iter() {
@@ -26,6 +26,7 @@ use crate::ast::AlterViewStmt;
 use crate::ast::CreateTableSource;
 use crate::ast::CreateTableStmt;
 use crate::ast::CreateViewStmt;
+use crate::ast::TableConstraint;
 use crate::ast::TimeTravelPoint;
 
 pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
@@ -56,7 +57,7 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
                 .append(RcDoc::text(stmt.table.to_string())),
         )
         .append(if let Some(source) = stmt.source {
-            pretty_table_source(source)
+            pretty_table_source(source, stmt.constraints)
         } else {
             RcDoc::nil()
         })
@@ -77,6 +78,20 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
+        .append(if let Some(partition_by) = stmt.partition_by {
+            RcDoc::line()
+                .append(RcDoc::text("PARTITION BY "))
+                .append(parenthesized(pretty_expr(partition_by)))
+        } else {
+            RcDoc::nil()
+        })
+        .append(if let Some(ttl) = stmt.ttl {
+            RcDoc::line()
+                .append(RcDoc::text("TTL = "))
+                .append(pretty_expr(ttl))
+        } else {
+            RcDoc::nil()
+        })
         .append(if !stmt.table_options.is_empty() {
             RcDoc::line()
                 .append(interweave_comma(stmt.table_options.iter().map(|(k, v)| {
@@ -103,13 +118,21 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
         })
 }
 
-fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
+fn pretty_table_source(
+    source: CreateTableSource,
+    constraints: Vec<TableConstraint>,
+) -> RcDoc<'static> {
     match source {
         CreateTableSource::Columns(columns) => RcDoc::space().append(parenthesized(
             interweave_comma(
                 columns
                     .into_iter()
-                    .map(|column| RcDoc::text(column.to_string())),
+                    .map(|column| RcDoc::text(column.to_string()))
+                    .chain(
+                        constraints
+                            .into_iter()
+                            .map(|constraint| RcDoc::text(constraint.to_string())),
+                    ),
             )
             .group(),
         )),
@@ -180,6 +203,20 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::DropTableClusterKey => {
             RcDoc::line().append(RcDoc::text("DROP CLUSTER KEY"))
         }
+        AlterTableAction::DropPartition {
+            if_exists,
+            partition,
+        } => RcDoc::line()
+            .append(RcDoc::text("DROP PARTITION "))
+            .append(if if_exists {
+                RcDoc::text("IF EXISTS ")
+            } else {
+                RcDoc::nil()
+            })
+            .append(parenthesized(pretty_expr(partition))),
+        AlterTableAction::TruncatePartition { partition } => RcDoc::line()
+            .append(RcDoc::text("TRUNCATE PARTITION "))
+            .append(parenthesized(pretty_expr(partition))),
         AlterTableAction::ReclusterTable {
             is_final,
             selection,
@@ -217,6 +254,14 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        AlterTableAction::AddRowAccessPolicy { policy, columns } => RcDoc::line()
+            .append(RcDoc::text(format!("ADD ROW ACCESS POLICY {policy} ON ")))
+            .append(parenthesized(
+                interweave_comma(columns.into_iter().map(|c| RcDoc::text(c.to_string()))).group(),
+            )),
+        AlterTableAction::DropRowAccessPolicy { policy } => {
+            RcDoc::line().append(RcDoc::text(format!("DROP ROW ACCESS POLICY {policy}")))
+        }
     }
 }
 
@@ -52,6 +52,13 @@ pub(crate) fn pretty_insert(insert_stmt: InsertStmt) -> RcDoc<'static> {
                     RcDoc::nil()
                 })
                 .append(RcDoc::text(insert_stmt.table.to_string()))
+                .append(if let Some(partition) = insert_stmt.partition {
+                    RcDoc::space()
+                        .append(RcDoc::text("PARTITION "))
+                        .append(parenthesized(pretty_expr(partition)))
+                } else {
+                    RcDoc::nil()
+                })
                 .append(if !insert_stmt.columns.is_empty() {
                     RcDoc::space()
                         .append(RcDoc::text("("))
@@ -24,8 +24,10 @@ mod index;
 mod insert;
 mod kill;
 mod network_policy;
+mod password_policy;
 mod presign;
 mod replace;
+mod row_access_policy;
 mod share;
 mod show;
 mod stage;
@@ -36,6 +38,7 @@ mod update;
 mod user;
 mod view;
 mod virtual_columns;
+mod workload_group;
 
 pub use call::*;
 pub use catalog::*;
@@ -49,8 +52,10 @@ pub use index::*;
 pub use insert::*;
 pub use kill::*;
 pub use network_policy::*;
+pub use password_policy::*;
 pub use presign::*;
 pub use replace::*;
+pub use row_access_policy::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
@@ -61,3 +66,4 @@ pub use update::*;
 pub use user::*;
 pub use view::*;
 pub use virtual_columns::*;
+pub use workload_group::*;
@@ -0,0 +1,128 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PasswordPolicyOptions {
+    pub min_length: Option<u64>,
+    pub min_upper_case_chars: Option<u64>,
+    pub min_lower_case_chars: Option<u64>,
+    pub min_numeric_chars: Option<u64>,
+    pub min_special_chars: Option<u64>,
+    pub max_age_days: Option<u64>,
+    pub max_retries: Option<u64>,
+    pub lockout_time_mins: Option<u64>,
+    pub history: Option<u64>,
+    pub comment: Option<String>,
+}
+
+impl Display for PasswordPolicyOptions {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(min_length) = self.min_length {
+            write!(f, " PASSWORD_MIN_LENGTH = {min_length}")?;
+        }
+        if let Some(min_upper_case_chars) = self.min_upper_case_chars {
+            write!(f, " PASSWORD_MIN_UPPER_CASE_CHARS = {min_upper_case_chars}")?;
+        }
+        if let Some(min_lower_case_chars) = self.min_lower_case_chars {
+            write!(f, " PASSWORD_MIN_LOWER_CASE_CHARS = {min_lower_case_chars}")?;
+        }
+        if let Some(min_numeric_chars) = self.min_numeric_chars {
+            write!(f, " PASSWORD_MIN_NUMERIC_CHARS = {min_numeric_chars}")?;
+        }
+        if let Some(min_special_chars) = self.min_special_chars {
+            write!(f, " PASSWORD_MIN_SPECIAL_CHARS = {min_special_chars}")?;
+        }
+        if let Some(max_age_days) = self.max_age_days {
+            write!(f, " PASSWORD_MAX_AGE_DAYS = {max_age_days}")?;
+        }
+        if let Some(max_retries) = self.max_retries {
+            write!(f, " PASSWORD_MAX_RETRIES = {max_retries}")?;
+        }
+        if let Some(lockout_time_mins) = self.lockout_time_mins {
+            write!(f, " PASSWORD_LOCKOUT_TIME_MINS = {lockout_time_mins}")?;
+        }
+        if let Some(history) = self.history {
+            write!(f, " PASSWORD_HISTORY = {history}")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatePasswordPolicyStmt {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub options: PasswordPolicyOptions,
+}
+
+impl Display for CreatePasswordPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE PASSWORD POLICY ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}{}", self.name, self.options)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlterPasswordPolicyStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub options: PasswordPolicyOptions,
+}
+
+impl Display for AlterPasswordPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER PASSWORD POLICY ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} SET{}", self.name, self.options)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropPasswordPolicyStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropPasswordPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP PASSWORD POLICY ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescPasswordPolicyStmt {
+    pub name: String,
+}
+
+impl Display for DescPasswordPolicyStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DESCRIBE PASSWORD POLICY {}", self.name)
+    }
+}
@@ -21,6 +21,7 @@ use common_meta_app::share::ShareGrantObjectPrivilege;
 use itertools::Itertools;
 
 use super::UriLocation;
+use crate::ast::GrantObjectName;
 use crate::ast::Identifier;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -211,7 +212,7 @@ impl Display for DropShareEndpointStmt {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ShowObjectGrantPrivilegesStmt {
-    pub object: ShareGrantObjectName,
+    pub object: GrantObjectName,
 }
 
 impl Display for ShowObjectGrantPrivilegesStmt {
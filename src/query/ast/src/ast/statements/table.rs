@@ -28,6 +28,10 @@ use crate::ast::TimeTravelPoint;
 use crate::ast::TypeName;
 use crate::ast::UriLocation;
 
+/// The functions that map multiple columns onto a space-filling curve,
+/// a cluster key built from one of them may reference several columns.
+pub const SPACE_FILLING_CURVES: &[&str] = &["hilbert", "zorder"];
+
 #[derive(Debug, Clone, PartialEq)] // Tables
 pub struct ShowTablesStmt {
     pub catalog: Option<Identifier>,
@@ -128,6 +132,9 @@ pub struct CreateTableStmt {
     pub engine: Option<Engine>,
     pub uri_location: Option<UriLocation>,
     pub cluster_by: Vec<Expr>,
+    pub partition_by: Option<Expr>,
+    pub ttl: Option<Expr>,
+    pub constraints: Vec<TableConstraint>,
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
     pub transient: bool,
@@ -151,8 +158,17 @@ impl Display for CreateTableStmt {
                 .chain(Some(&self.table)),
         )?;
 
-        if let Some(source) = &self.source {
-            write!(f, " {source}")?;
+        match &self.source {
+            // Table constraints are declared inside the column list.
+            Some(CreateTableSource::Columns(columns)) if !self.constraints.is_empty() => {
+                write!(f, " (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ", ")?;
+                write_comma_separated_list(f, &self.constraints)?;
+                write!(f, ")")?;
+            }
+            Some(source) => write!(f, " {source}")?,
+            None => {}
         }
 
         if let Some(engine) = &self.engine {
@@ -165,6 +181,14 @@ impl Display for CreateTableStmt {
             write!(f, ")")?
         }
 
+        if let Some(partition_by) = &self.partition_by {
+            write!(f, " PARTITION BY ({partition_by})")?;
+        }
+
+        if let Some(ttl) = &self.ttl {
+            write!(f, " TTL = {ttl}")?;
+        }
+
         // Format table options
         write_space_separated_map(f, self.table_options.iter())?;
         if let Some(as_query) = &self.as_query {
@@ -340,6 +364,13 @@ pub enum AlterTableAction {
         cluster_by: Vec<Expr>,
     },
     DropTableClusterKey,
+    DropPartition {
+        if_exists: bool,
+        partition: Expr,
+    },
+    TruncatePartition {
+        partition: Expr,
+    },
     ReclusterTable {
         is_final: bool,
         selection: Option<Expr>,
@@ -351,6 +382,13 @@ pub enum AlterTableAction {
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy {
+        policy: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -385,6 +423,19 @@ impl Display for AlterTableAction {
             AlterTableAction::DropTableClusterKey => {
                 write!(f, "DROP CLUSTER KEY")
             }
+            AlterTableAction::DropPartition {
+                if_exists,
+                partition,
+            } => {
+                write!(f, "DROP PARTITION ")?;
+                if *if_exists {
+                    write!(f, "IF EXISTS ")?;
+                }
+                write!(f, "({partition})")
+            }
+            AlterTableAction::TruncatePartition { partition } => {
+                write!(f, "TRUNCATE PARTITION ({partition})")
+            }
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
@@ -406,6 +457,14 @@ impl Display for AlterTableAction {
                 write!(f, "REVERT TO {}", point)?;
                 Ok(())
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                write!(f, "DROP ROW ACCESS POLICY {policy}")
+            }
         }
     }
 }
@@ -718,6 +777,24 @@ impl Display for ColumnDefinition {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableConstraint {
+    PrimaryKey(Vec<Identifier>),
+    Unique(Vec<Identifier>),
+}
+
+impl Display for TableConstraint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let (keyword, columns) = match self {
+            TableConstraint::PrimaryKey(columns) => ("PRIMARY KEY", columns),
+            TableConstraint::Unique(columns) => ("UNIQUE", columns),
+        };
+        write!(f, "{keyword} (")?;
+        write_comma_separated_list(f, columns)?;
+        write!(f, ")")
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ModifyColumnAction {
     // (column name id, masking policy name)
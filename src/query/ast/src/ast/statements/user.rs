@@ -23,6 +23,7 @@ use common_meta_app::principal::UserOptionFlag;
 use common_meta_app::principal::UserPrivilegeType;
 
 use crate::ast::write_comma_separated_list;
+use crate::ast::Identifier;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateUserStmt {
@@ -60,7 +61,10 @@ pub struct AuthOption {
 impl Display for AuthOption {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         if let Some(auth_type) = &self.auth_type {
-            write!(f, "WITH {} ", auth_type.to_str())?;
+            write!(f, "WITH {}", auth_type.to_str())?;
+            if self.password.is_some() {
+                write!(f, " ")?;
+            }
         }
         if let Some(password) = &self.password {
             write!(f, "BY '{password}'")?;
@@ -133,6 +137,48 @@ impl Display for RevokeStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrantOwnershipStmt {
+    pub object: GrantObjectName,
+    pub role: String,
+}
+
+impl Display for GrantOwnershipStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GRANT OWNERSHIP ON {} TO ROLE '{}'",
+            self.object, self.role
+        )
+    }
+}
+
+/// An object which can be owned by a role.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GrantObjectName {
+    Database(Identifier),
+    Table(Option<Identifier>, Identifier),
+    Stage(String),
+    UDF(String),
+}
+
+impl Display for GrantObjectName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GrantObjectName::Database(database) => write!(f, "DATABASE {database}"),
+            GrantObjectName::Table(database, table) => {
+                write!(f, "TABLE ")?;
+                if let Some(database) = database {
+                    write!(f, "{database}.")?;
+                }
+                write!(f, "{table}")
+            }
+            GrantObjectName::Stage(stage) => write!(f, "STAGE {stage}"),
+            GrantObjectName::UDF(udf) => write!(f, "FUNCTION {udf}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AccountMgrSource {
     Role {
@@ -142,6 +188,11 @@ pub enum AccountMgrSource {
         privileges: Vec<UserPrivilegeType>,
         level: AccountMgrLevel,
     },
+    ColumnPrivs {
+        privileges: Vec<UserPrivilegeType>,
+        columns: Vec<Identifier>,
+        level: AccountMgrLevel,
+    },
     ALL {
         level: AccountMgrLevel,
     },
@@ -160,6 +211,10 @@ pub enum UserOptionItem {
     DefaultRole(String),
     SetNetworkPolicy(String),
     UnsetNetworkPolicy,
+    SetWorkloadGroup(String),
+    UnsetWorkloadGroup,
+    SetPasswordPolicy(String),
+    UnsetPasswordPolicy,
 }
 
 impl UserOptionItem {
@@ -171,6 +226,10 @@ impl UserOptionItem {
             Self::DefaultRole(v) => option.set_default_role(Some(v.clone())),
             Self::SetNetworkPolicy(v) => option.set_network_policy(Some(v.clone())),
             Self::UnsetNetworkPolicy => option.set_network_policy(None),
+            Self::SetWorkloadGroup(v) => option.set_workload_group(Some(v.clone())),
+            Self::UnsetWorkloadGroup => option.set_workload_group(None),
+            Self::SetPasswordPolicy(v) => option.set_password_policy(Some(v.clone())),
+            Self::UnsetPasswordPolicy => option.set_password_policy(None),
         }
     }
 }
@@ -182,44 +241,43 @@ impl Display for AccountMgrSource {
             AccountMgrSource::Privs { privileges, level } => {
                 write!(f, " ")?;
                 write_comma_separated_list(f, privileges.iter().map(|p| p.to_string()))?;
-                write!(f, " ON")?;
-                match level {
-                    AccountMgrLevel::Global => write!(f, " *.*")?,
-                    AccountMgrLevel::Database(database_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.*")?;
-                        } else {
-                            write!(f, " *")?;
-                        }
-                    }
-                    AccountMgrLevel::Table(database_name, table_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.{table_name}")?;
-                        } else {
-                            write!(f, " {table_name}")?;
-                        }
-                    }
-                }
+                write!(f, " ON {level}")?;
+            }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                write!(f, " ")?;
+                write_comma_separated_list(f, privileges.iter().map(|p| p.to_string()))?;
+                write!(f, "(")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") ON {level}")?;
             }
             AccountMgrSource::ALL { level, .. } => {
-                write!(f, " ALL PRIVILEGES")?;
-                write!(f, " ON")?;
-                match level {
-                    AccountMgrLevel::Global => write!(f, " *.*")?,
-                    AccountMgrLevel::Database(database_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.*")?;
-                        } else {
-                            write!(f, " *")?;
-                        }
-                    }
-                    AccountMgrLevel::Table(database_name, table_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.{table_name}")?;
-                        } else {
-                            write!(f, " {table_name}")?;
-                        }
-                    }
+                write!(f, " ALL PRIVILEGES ON {level}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for AccountMgrLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccountMgrLevel::Global => write!(f, "*.*")?,
+            AccountMgrLevel::Database(database_name) => {
+                if let Some(database_name) = database_name {
+                    write!(f, "{database_name}.*")?;
+                } else {
+                    write!(f, "*")?;
+                }
+            }
+            AccountMgrLevel::Table(database_name, table_name) => {
+                if let Some(database_name) = database_name {
+                    write!(f, "{database_name}.{table_name}")?;
+                } else {
+                    write!(f, "{table_name}")?;
                 }
             }
         }
@@ -235,6 +293,10 @@ impl Display for UserOptionItem {
             UserOptionItem::DefaultRole(v) => write!(f, "DEFAULT_ROLE = '{}'", v),
             UserOptionItem::SetNetworkPolicy(v) => write!(f, "SET NETWORK POLICY = '{}'", v),
             UserOptionItem::UnsetNetworkPolicy => write!(f, "UNSET NETWORK POLICY"),
+            UserOptionItem::SetWorkloadGroup(v) => write!(f, "SET WORKLOAD GROUP = '{}'", v),
+            UserOptionItem::UnsetWorkloadGroup => write!(f, "UNSET WORKLOAD GROUP"),
+            UserOptionItem::SetPasswordPolicy(v) => write!(f, "SET PASSWORD POLICY = '{}'", v),
+            UserOptionItem::UnsetPasswordPolicy => write!(f, "UNSET PASSWORD POLICY"),
         }
     }
 }
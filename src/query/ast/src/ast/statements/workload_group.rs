@@ -0,0 +1,114 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WorkloadGroupOptions {
+    pub cpu_share: Option<u64>,
+    pub memory_limit: Option<u64>,
+    pub max_concurrency: Option<u64>,
+    pub queue_timeout: Option<u64>,
+    pub comment: Option<String>,
+}
+
+impl Display for WorkloadGroupOptions {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(cpu_share) = self.cpu_share {
+            write!(f, " CPU_SHARE = {cpu_share}")?;
+        }
+        if let Some(memory_limit) = self.memory_limit {
+            write!(f, " MEMORY_LIMIT = {memory_limit}")?;
+        }
+        if let Some(max_concurrency) = self.max_concurrency {
+            write!(f, " MAX_CONCURRENCY = {max_concurrency}")?;
+        }
+        if let Some(queue_timeout) = self.queue_timeout {
+            write!(f, " QUEUE_TIMEOUT = {queue_timeout}")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateWorkloadGroupStmt {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub options: WorkloadGroupOptions,
+}
+
+impl Display for CreateWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE WORKLOAD GROUP ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}{}", self.name, self.options)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlterWorkloadGroupStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub options: WorkloadGroupOptions,
+}
+
+impl Display for AlterWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} SET{}", self.name, self.options)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropWorkloadGroupStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlterRoleWorkloadGroupStmt {
+    pub role_name: String,
+    /// `None` to unassign the role from its workload group.
+    pub workload_group: Option<String>,
+}
+
+impl Display for AlterRoleWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER ROLE '{}' ", self.role_name)?;
+        match &self.workload_group {
+            Some(workload_group) => write!(f, "SET WORKLOAD GROUP = '{workload_group}'"),
+            None => write!(f, "UNSET WORKLOAD GROUP"),
+        }
+    }
+}
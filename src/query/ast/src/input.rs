@@ -96,6 +96,14 @@ impl Dialect {
         }
     }
 
+    /// The quote used to write an identifier in this dialect.
+    pub fn default_ident_quote(&self) -> char {
+        match self {
+            Dialect::MySQL | Dialect::Hive => '`',
+            Dialect::PostgreSQL => '"',
+        }
+    }
+
     pub fn is_string_quote(&self, c: char) -> bool {
         match self {
             Dialect::MySQL => c == '\'' || c == '"',
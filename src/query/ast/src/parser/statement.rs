@@ -28,6 +28,7 @@ use nom::branch::alt;
 use nom::combinator::consumed;
 use nom::combinator::map;
 use nom::combinator::value;
+use nom::combinator::verify;
 use nom::Slice;
 
 use crate::ast::*;
@@ -44,7 +45,7 @@ use crate::util::*;
 use crate::ErrorKind;
 pub enum ShowGrantOption {
     PrincipalIdentity(PrincipalIdentity),
-    ShareGrantObjectName(ShareGrantObjectName),
+    GrantObjectName(GrantObjectName),
     ShareName(String),
 }
 
@@ -98,10 +99,20 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! {
             INSERT ~ #hint? ~ ( INTO | OVERWRITE ) ~ TABLE?
             ~ #period_separated_idents_1_to_3
+            ~ ( PARTITION ~ ^"(" ~ ^#expr ~ ^")" )?
             ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
             ~ #insert_source
         },
-        |(_, opt_hints, overwrite, _, (catalog, database, table), opt_columns, source)| {
+        |(
+            _,
+            opt_hints,
+            overwrite,
+            _,
+            (catalog, database, table),
+            opt_partition,
+            opt_columns,
+            source,
+        )| {
             Statement::Insert(InsertStmt {
                 hints: opt_hints,
                 catalog,
@@ -112,6 +123,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                     .unwrap_or_default(),
                 source,
                 overwrite: overwrite.kind == OVERWRITE,
+                partition: opt_partition.map(|(_, _, partition, _)| partition),
             })
         },
     );
@@ -504,7 +516,9 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             ~ #create_table_source?
             ~ ( #engine )?
             ~ ( #uri_location )?
-            ~ ( CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+            ~ ( CLUSTER ~ ^BY ~ ^#cluster_by )?
+            ~ ( PARTITION ~ ^BY ~ ^"(" ~ ^#expr ~ ^")" )?
+            ~ ( TTL ~ ^"=" ~ ^#expr )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
         },
@@ -514,13 +528,19 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             _,
             opt_if_not_exists,
             (catalog, database, table),
-            source,
+            opt_source,
             engine,
             uri_location,
             opt_cluster_by,
+            opt_partition_by,
+            opt_ttl,
             opt_table_options,
             opt_as_query,
         )| {
+            let (source, constraints) = match opt_source {
+                Some((source, constraints)) => (Some(source), constraints),
+                None => (None, vec![]),
+            };
             Statement::CreateTable(CreateTableStmt {
                 if_not_exists: opt_if_not_exists.is_some(),
                 catalog,
@@ -530,8 +550,11 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 engine,
                 uri_location,
                 cluster_by: opt_cluster_by
-                    .map(|(_, _, _, exprs, _)| exprs)
+                    .map(|(_, _, exprs)| exprs)
                     .unwrap_or_default(),
+                partition_by: opt_partition_by.map(|(_, _, _, expr, _)| expr),
+                ttl: opt_ttl.map(|(_, _, expr)| expr),
+                constraints,
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
                 transient: opt_transient.is_some(),
@@ -772,6 +795,53 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_inverted_index = map(
+        rule! {
+            CREATE ~ INVERTED ~ INDEX ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident ~ ON ~ #period_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+            ~ ( TOKENIZER ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            columns,
+            _,
+            opt_tokenizer,
+        )| {
+            Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                columns,
+                tokenizer: opt_tokenizer.map(|(_, _, tokenizer)| tokenizer),
+            })
+        },
+    );
+
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ EXISTS )? ~ #ident ~ ON ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let create_virtual_columns = map(
         rule! {
             CREATE ~ VIRTUAL ~ COLUMNS ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" ~ FOR ~ #period_separated_idents_1_to_3
@@ -895,6 +965,14 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             role_name,
         },
     );
+    let grant_ownership = map(
+        rule! {
+            GRANT ~ OWNERSHIP ~ ON ~ #grant_object_name ~ TO ~ ROLE ~ #literal_string
+        },
+        |(_, _, _, object, _, _, role)| {
+            Statement::GrantOwnership(GrantOwnershipStmt { object, role })
+        },
+    );
     let grant = map(
         rule! {
             GRANT ~ #grant_source ~ TO ~ #grant_option
@@ -914,7 +992,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             Some(ShowGrantOption::PrincipalIdentity(principal)) => Statement::ShowGrants {
                 principal: Some(principal),
             },
-            Some(ShowGrantOption::ShareGrantObjectName(object)) => {
+            Some(ShowGrantOption::GrantObjectName(object)) => {
                 Statement::ShowObjectGrantPrivileges(ShowObjectGrantPrivilegesStmt { object })
             }
             Some(ShowGrantOption::ShareName(share_name)) => {
@@ -1319,6 +1397,62 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_row_access_policy = map(
+        rule! {
+            CREATE ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
+            ~ AS ~ "(" ~ #comma_separated_list1(row_access_policy_arg) ~ ")"
+            ~ RETURNS ~ #type_name ~ "->" ~ #expr
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            args,
+            _,
+            _,
+            return_type,
+            _,
+            body,
+            opt_comment,
+        )| {
+            Statement::CreateRowAccessPolicy(CreateRowAccessPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                args,
+                return_type,
+                body,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            Statement::DropRowAccessPolicy(DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let describe_row_access_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, name)| {
+            Statement::DescRowAccessPolicy(DescRowAccessPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+
     let create_network_policy = map(
         rule! {
             CREATE ~ NETWORK ~ POLICY ~ ( IF ~ NOT ~ EXISTS )? ~ #ident
@@ -1420,6 +1554,114 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! { SHOW ~ NETWORK ~ POLICIES },
     );
 
+    let create_password_policy = map(
+        rule! {
+            CREATE ~ PASSWORD ~ POLICY ~ ( IF ~ NOT ~ EXISTS )? ~ #ident ~ #password_policy_options
+        },
+        |(_, _, _, opt_if_not_exists, name, options)| {
+            Statement::CreatePasswordPolicy(CreatePasswordPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                options,
+            })
+        },
+    );
+    let alter_password_policy = map(
+        rule! {
+            ALTER ~ PASSWORD ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident ~ SET ~ #password_policy_options
+        },
+        |(_, _, _, opt_if_exists, name, _, options)| {
+            Statement::AlterPasswordPolicy(AlterPasswordPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                options,
+            })
+        },
+    );
+    let drop_password_policy = map(
+        rule! {
+            DROP ~ PASSWORD ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropPasswordPolicy(DropPasswordPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let describe_password_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ PASSWORD ~ POLICY ~ #ident
+        },
+        |(_, _, _, name)| {
+            Statement::DescPasswordPolicy(DescPasswordPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+    let show_password_policies = value(
+        Statement::ShowPasswordPolicies,
+        rule! { SHOW ~ PASSWORD ~ POLICIES },
+    );
+
+    let create_workload_group = map(
+        rule! {
+            CREATE ~ WORKLOAD ~ GROUP ~ ( IF ~ NOT ~ EXISTS )? ~ #ident ~ #workload_group_options
+        },
+        |(_, _, _, opt_if_not_exists, name, options)| {
+            Statement::CreateWorkloadGroup(CreateWorkloadGroupStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                options,
+            })
+        },
+    );
+    let alter_workload_group = map(
+        rule! {
+            ALTER ~ WORKLOAD ~ GROUP ~ ( IF ~ EXISTS )? ~ #ident ~ SET ~ #workload_group_options
+        },
+        |(_, _, _, opt_if_exists, name, _, options)| {
+            Statement::AlterWorkloadGroup(AlterWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                options,
+            })
+        },
+    );
+    let drop_workload_group = map(
+        rule! {
+            DROP ~ WORKLOAD ~ GROUP ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropWorkloadGroup(DropWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+    let set_role_workload_group = map(
+        rule! {
+            ALTER ~ ROLE ~ #literal_string ~ SET ~ WORKLOAD ~ GROUP ~ "=" ~ #literal_string
+        },
+        |(_, _, role_name, _, _, _, _, workload_group)| {
+            Statement::AlterRoleWorkloadGroup(AlterRoleWorkloadGroupStmt {
+                role_name,
+                workload_group: Some(workload_group),
+            })
+        },
+    );
+    let unset_role_workload_group = map(
+        rule! {
+            ALTER ~ ROLE ~ #literal_string ~ UNSET ~ WORKLOAD ~ GROUP
+        },
+        |(_, _, role_name, _, _, _)| {
+            Statement::AlterRoleWorkloadGroup(AlterRoleWorkloadGroupStmt {
+                role_name,
+                workload_group: None,
+            })
+        },
+    );
+
     let statement_body = alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
@@ -1452,6 +1694,28 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #describe_network_policy: "`DESC NETWORK POLICY name`"
             | #show_network_policies: "`SHOW NETWORK POLICIES`"
         ),
+        // password policy
+        rule!(
+            #create_password_policy: "`CREATE PASSWORD POLICY [IF NOT EXISTS] <name> [PASSWORD_MIN_LENGTH = <u64>] [PASSWORD_MIN_UPPER_CASE_CHARS = <u64>] [PASSWORD_MIN_LOWER_CASE_CHARS = <u64>] [PASSWORD_MIN_NUMERIC_CHARS = <u64>] [PASSWORD_MIN_SPECIAL_CHARS = <u64>] [PASSWORD_MAX_AGE_DAYS = <u64>] [PASSWORD_MAX_RETRIES = <u64>] [PASSWORD_LOCKOUT_TIME_MINS = <u64>] [PASSWORD_HISTORY = <u64>] [COMMENT = '<string_literal>']`"
+            | #alter_password_policy: "`ALTER PASSWORD POLICY [IF EXISTS] <name> SET [PASSWORD_MIN_LENGTH = <u64>] ... [COMMENT = '<string_literal>']`"
+            | #drop_password_policy: "`DROP PASSWORD POLICY [IF EXISTS] <name>`"
+            | #describe_password_policy: "`DESC PASSWORD POLICY <name>`"
+            | #show_password_policies: "`SHOW PASSWORD POLICIES`"
+        ),
+        // row access policy
+        rule!(
+            #create_row_access_policy: "`CREATE ROW ACCESS POLICY [IF NOT EXISTS] <policy_name> AS (<arg> <arg_type> [, ...]) RETURNS BOOLEAN -> <expr> [ COMMENT = '<string_literal>' ]`"
+            | #drop_row_access_policy: "`DROP ROW ACCESS POLICY [IF EXISTS] <policy_name>`"
+            | #describe_row_access_policy: "`DESC ROW ACCESS POLICY <policy_name>`"
+        ),
+        // workload group
+        rule!(
+            #create_workload_group: "`CREATE WORKLOAD GROUP [IF NOT EXISTS] <name> [CPU_SHARE = <u64>] [MEMORY_LIMIT = <u64>] [MAX_CONCURRENCY = <u64>] [QUEUE_TIMEOUT = <u64>] [COMMENT = '<string_literal>']`"
+            | #alter_workload_group: "`ALTER WORKLOAD GROUP [IF EXISTS] <name> SET [CPU_SHARE = <u64>] [MEMORY_LIMIT = <u64>] [MAX_CONCURRENCY = <u64>] [QUEUE_TIMEOUT = <u64>] [COMMENT = '<string_literal>']`"
+            | #drop_workload_group: "`DROP WORKLOAD GROUP [IF EXISTS] <name>`"
+            | #set_role_workload_group: "`ALTER ROLE '<role_name>' SET WORKLOAD GROUP = '<name>'`"
+            | #unset_role_workload_group: "`ALTER ROLE '<role_name>' UNSET WORKLOAD GROUP`"
+        ),
         rule!(
             #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
@@ -1491,6 +1755,8 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            | #create_inverted_index: "`CREATE INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> (<column>, ...) [TOKENIZER = '<tokenizer>']`"
+            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
         ),
         rule!(
             #create_virtual_columns: "`CREATE VIRTUAL COLUMNS (expr, ...) FOR [<database>.]<table>`"
@@ -1539,8 +1805,9 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #call: "`CALL <procedure_name>(<parameter>, ...)`"
         ),
         rule!(
-            #grant : "`GRANT { ROLE <role_name> | schemaObjectPrivileges | ALL [ PRIVILEGES ] ON <privileges_level> } TO { [ROLE <role_name>] | [USER] <user> }`"
-            | #show_grants : "`SHOW GRANTS {FOR  { ROLE <role_name> | USER <user> }] | ON {DATABASE <db_name> | TABLE <db_name>.<table_name>} }`"
+            #grant_ownership : "`GRANT OWNERSHIP ON { DATABASE <db_name> | TABLE [<db_name>.]<table_name> | STAGE <stage_name> | FUNCTION <udf_name> } TO ROLE <role_name>`"
+            | #grant : "`GRANT { ROLE <role_name> | schemaObjectPrivileges | ALL [ PRIVILEGES ] ON <privileges_level> } TO { [ROLE <role_name>] | [USER] <user> }`"
+            | #show_grants : "`SHOW GRANTS {FOR  { ROLE <role_name> | USER <user> }] | ON { DATABASE <db_name> | TABLE [<db_name>.]<table_name> | STAGE <stage_name> | FUNCTION <udf_name> } }`"
             | #revoke : "`REVOKE { ROLE <role_name> | schemaObjectPrivileges | ALL [ PRIVILEGES ] ON <privileges_level> } FROM { [ROLE <role_name>] | [USER] <user> }`"
         ),
         rule!(
@@ -1775,11 +2042,20 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
     );
     let privs = map(
         rule! {
-            #comma_separated_list1(priv_type) ~ ON ~ #grant_level
+            #comma_separated_list1(priv_type)
+            ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+            ~ ON ~ #grant_level
         },
-        |(privs, _, level)| AccountMgrSource::Privs {
-            privileges: privs,
-            level,
+        |(privs, opt_columns, _, level)| match opt_columns {
+            Some((_, columns, _)) => AccountMgrSource::ColumnPrivs {
+                privileges: privs,
+                columns,
+                level,
+            },
+            None => AccountMgrSource::Privs {
+                privileges: privs,
+                level,
+            },
         },
     );
     let all = map(
@@ -1789,7 +2065,7 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
 
     rule!(
         #role : "ROLE <role_name>"
-        | #privs : "<privileges> ON <privileges_level>"
+        | #privs : "<privileges> [ ( <column>, ... ) ] ON <privileges_level>"
         | #all : "ALL [ PRIVILEGES ] ON <privileges_level>"
     )(i)
 }
@@ -1854,6 +2130,43 @@ pub fn grant_share_object_name(i: Input) -> IResult<ShareGrantObjectName> {
     )(i)
 }
 
+pub fn grant_object_name(i: Input) -> IResult<GrantObjectName> {
+    let database = map(
+        rule! {
+            DATABASE ~ #ident
+        },
+        |(_, database)| GrantObjectName::Database(database),
+    );
+
+    let table = map(
+        rule! {
+            TABLE ~ #period_separated_idents_1_to_2
+        },
+        |(_, (database, table))| GrantObjectName::Table(database, table),
+    );
+
+    let stage = map(
+        rule! {
+            STAGE ~ #stage_name
+        },
+        |(_, stage)| GrantObjectName::Stage(stage.to_string()),
+    );
+
+    let udf = map(
+        rule! {
+            FUNCTION ~ #ident
+        },
+        |(_, udf)| GrantObjectName::UDF(udf.to_string()),
+    );
+
+    rule!(
+        #database : "DATABASE <database>"
+        | #table : "TABLE [<database>.]<table>"
+        | #stage : "STAGE <stage_name>"
+        | #udf : "FUNCTION <udf_name>"
+    )(i)
+}
+
 pub fn grant_level(i: Input) -> IResult<AccountMgrLevel> {
     // *.*
     let global = map(rule! { "*" ~ "." ~ "*" }, |_| AccountMgrLevel::Global);
@@ -1891,11 +2204,11 @@ pub fn show_grant_option(i: Input) -> IResult<ShowGrantOption> {
         |(_, opt_principal)| ShowGrantOption::PrincipalIdentity(opt_principal),
     );
 
-    let share_object_name = map(
+    let object_name = map(
         rule! {
-            ON ~ #grant_share_object_name
+            ON ~ #grant_object_name
         },
-        |(_, object_name)| ShowGrantOption::ShareGrantObjectName(object_name),
+        |(_, object_name)| ShowGrantOption::GrantObjectName(object_name),
     );
 
     let share_name = map(
@@ -1907,7 +2220,7 @@ pub fn show_grant_option(i: Input) -> IResult<ShowGrantOption> {
 
     rule!(
         #grant_role: "FOR  { ROLE <role_name> | [USER] <user> }"
-        | #share_object_name: "ON {DATABASE <db_name> | TABLE <db_name>.<table_name>}"
+        | #object_name: "ON {DATABASE <db_name> | TABLE <db_name>.<table_name>}"
         | #share_name: "OF SHARE <share_name>"
     )(i)
 }
@@ -1933,21 +2246,69 @@ pub fn grant_option(i: Input) -> IResult<PrincipalIdentity> {
     )(i)
 }
 
-pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
-    let columns = map(
+pub fn table_constraint(i: Input) -> IResult<TableConstraint> {
+    let primary_key = map(
+        rule! {
+            PRIMARY ~ KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, columns, _)| TableConstraint::PrimaryKey(columns),
+    );
+    let unique = map(
         rule! {
-            "(" ~ ^#comma_separated_list1(column_def) ~ ^")"
+            UNIQUE ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, columns, _)| TableConstraint::Unique(columns),
+    );
+
+    rule!(
+        #primary_key
+        | #unique
+    )(i)
+}
+
+pub fn create_table_source(i: Input) -> IResult<(CreateTableSource, Vec<TableConstraint>)> {
+    #[derive(Clone)]
+    enum TableElement {
+        Column(ColumnDefinition),
+        Constraint(TableConstraint),
+    }
+
+    let element = alt((
+        map(table_constraint, TableElement::Constraint),
+        map(column_def, TableElement::Column),
+    ));
+    let columns = map_res(
+        rule! {
+            "(" ~ ^#comma_separated_list1(element) ~ ^")"
+        },
+        |(_, elements, _)| {
+            let mut columns = vec![];
+            let mut constraints = vec![];
+            for element in elements {
+                match element {
+                    TableElement::Column(column) => columns.push(column),
+                    TableElement::Constraint(constraint) => constraints.push(constraint),
+                }
+            }
+            if columns.is_empty() {
+                return Err(ErrorKind::Other("a table must have at least one column"));
+            }
+            Ok((CreateTableSource::Columns(columns), constraints))
         },
-        |(_, columns, _)| CreateTableSource::Columns(columns),
     );
     let like = map(
         rule! {
             LIKE ~ #period_separated_idents_1_to_3
         },
-        |(_, (catalog, database, table))| CreateTableSource::Like {
-            catalog,
-            database,
-            table,
+        |(_, (catalog, database, table))| {
+            (
+                CreateTableSource::Like {
+                    catalog,
+                    database,
+                    table,
+                },
+                vec![],
+            )
         },
     );
 
@@ -2054,9 +2415,9 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
     );
     let alter_table_cluster_key = map(
         rule! {
-            CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+            CLUSTER ~ ^BY ~ ^#cluster_by
         },
-        |(_, _, _, cluster_by, _)| AlterTableAction::AlterTableClusterKey { cluster_by },
+        |(_, _, cluster_by)| AlterTableAction::AlterTableClusterKey { cluster_by },
     );
 
     let drop_table_cluster_key = map(
@@ -2066,6 +2427,23 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _)| AlterTableAction::DropTableClusterKey,
     );
 
+    let drop_partition = map(
+        rule! {
+            DROP ~ PARTITION ~ ( IF ~ ^EXISTS )? ~ ^"(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, opt_if_exists, _, partition, _)| AlterTableAction::DropPartition {
+            if_exists: opt_if_exists.is_some(),
+            partition,
+        },
+    );
+
+    let truncate_partition = map(
+        rule! {
+            TRUNCATE ~ PARTITION ~ ^"(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, _, partition, _)| AlterTableAction::TruncatePartition { partition },
+    );
+
     let recluster_table = map(
         rule! {
             RECLUSTER ~ FINAL? ~ ( WHERE ~ ^#expr )? ~ ( LIMIT ~ #literal_u64 )?
@@ -2091,6 +2469,23 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ POLICY ~ ^#ident ~ ^ON ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ^#ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
     rule!(
         #rename_table
         | #rename_column
@@ -2099,9 +2494,44 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #modify_column
         | #alter_table_cluster_key
         | #drop_table_cluster_key
+        | #drop_partition
+        | #truncate_partition
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #add_row_access_policy
+        | #drop_row_access_policy
+    )(i)
+}
+
+pub fn row_access_policy_arg(i: Input) -> IResult<RowAccessPolicyArg> {
+    map(rule! { #ident ~ #type_name }, |(arg_name, arg_type)| {
+        RowAccessPolicyArg { arg_name, arg_type }
+    })(i)
+}
+
+pub fn cluster_by(i: Input) -> IResult<Vec<Expr>> {
+    let cluster_keys = map(
+        rule! {
+            "(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, cluster_keys, _)| cluster_keys,
+    );
+    // The space-filling curve key can be written without the outer parentheses,
+    // like `CLUSTER BY HILBERT(a, b)`.
+    let curve_key = map(
+        verify(expr, |key: &Expr| match key {
+            Expr::FunctionCall { name, .. } => {
+                SPACE_FILLING_CURVES.contains(&name.name.to_lowercase().as_str())
+            }
+            _ => false,
+        }),
+        |key| vec![key],
+    );
+
+    rule!(
+        #cluster_keys
+        | #curve_key
     )(i)
 }
 
@@ -2322,6 +2752,8 @@ pub fn catalog_type(i: Input) -> IResult<CatalogType> {
         value(CatalogType::Default, rule! {DEFAULT}),
         value(CatalogType::Hive, rule! {HIVE}),
         value(CatalogType::Iceberg, rule! {ICEBERG}),
+        value(CatalogType::Mysql, rule! {MYSQL}),
+        value(CatalogType::Postgres, rule! {POSTGRES}),
     ));
     map(rule! { ^#catalog_type }, |catalog_type| catalog_type)(i)
 }
@@ -2345,6 +2777,30 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, _)| UserOptionItem::UnsetNetworkPolicy,
     );
+    let set_workload_group = map(
+        rule! {
+            SET ~ WORKLOAD ~ GROUP ~ "=" ~ #literal_string
+        },
+        |(_, _, _, _, workload_group)| UserOptionItem::SetWorkloadGroup(workload_group),
+    );
+    let unset_workload_group = map(
+        rule! {
+            UNSET ~ WORKLOAD ~ GROUP
+        },
+        |(_, _, _)| UserOptionItem::UnsetWorkloadGroup,
+    );
+    let set_password_policy = map(
+        rule! {
+            SET ~ PASSWORD ~ POLICY ~ "=" ~ #literal_string
+        },
+        |(_, _, _, _, policy)| UserOptionItem::SetPasswordPolicy(policy),
+    );
+    let unset_password_policy = map(
+        rule! {
+            UNSET ~ PASSWORD ~ POLICY
+        },
+        |(_, _, _)| UserOptionItem::UnsetPasswordPolicy,
+    );
     alt((
         value(UserOptionItem::TenantSetting(true), rule! { TENANTSETTING }),
         value(
@@ -2354,9 +2810,72 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         default_role_option,
         set_network_policy,
         unset_network_policy,
+        set_workload_group,
+        unset_workload_group,
+        set_password_policy,
+        unset_password_policy,
     ))(i)
 }
 
+pub fn workload_group_options(i: Input) -> IResult<WorkloadGroupOptions> {
+    map(
+        rule! {
+            ( CPU_SHARE ~ "=" ~ #literal_u64 )?
+            ~ ( MEMORY_LIMIT ~ "=" ~ #literal_u64 )?
+            ~ ( MAX_CONCURRENCY ~ "=" ~ #literal_u64 )?
+            ~ ( QUEUE_TIMEOUT ~ "=" ~ #literal_u64 )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(cpu_share, memory_limit, max_concurrency, queue_timeout, comment)| WorkloadGroupOptions {
+            cpu_share: cpu_share.map(|(_, _, v)| v),
+            memory_limit: memory_limit.map(|(_, _, v)| v),
+            max_concurrency: max_concurrency.map(|(_, _, v)| v),
+            queue_timeout: queue_timeout.map(|(_, _, v)| v),
+            comment: comment.map(|(_, _, v)| v),
+        },
+    )(i)
+}
+
+pub fn password_policy_options(i: Input) -> IResult<PasswordPolicyOptions> {
+    map(
+        rule! {
+            ( PASSWORD_MIN_LENGTH ~ "=" ~ #literal_u64 )?
+            ~ ( PASSWORD_MIN_UPPER_CASE_CHARS ~ "=" ~ #literal_u64 )?
+            ~ ( PASSWORD_MIN_LOWER_CASE_CHARS ~ "=" ~ #literal_u64 )?
+            ~ ( PASSWORD_MIN_NUMERIC_CHARS ~ "=" ~ #literal_u64 )?
+            ~ ( PASSWORD_MIN_SPECIAL_CHARS ~ "=" ~ #literal_u64 )?
+            ~ ( PASSWORD_MAX_AGE_DAYS ~ "=" ~ #literal_u64 )?
+            ~ ( PASSWORD_MAX_RETRIES ~ "=" ~ #literal_u64 )?
+            ~ ( PASSWORD_LOCKOUT_TIME_MINS ~ "=" ~ #literal_u64 )?
+            ~ ( PASSWORD_HISTORY ~ "=" ~ #literal_u64 )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            min_length,
+            min_upper_case_chars,
+            min_lower_case_chars,
+            min_numeric_chars,
+            min_special_chars,
+            max_age_days,
+            max_retries,
+            lockout_time_mins,
+            history,
+            comment,
+        )| PasswordPolicyOptions {
+            min_length: min_length.map(|(_, _, v)| v),
+            min_upper_case_chars: min_upper_case_chars.map(|(_, _, v)| v),
+            min_lower_case_chars: min_lower_case_chars.map(|(_, _, v)| v),
+            min_numeric_chars: min_numeric_chars.map(|(_, _, v)| v),
+            min_special_chars: min_special_chars.map(|(_, _, v)| v),
+            max_age_days: max_age_days.map(|(_, _, v)| v),
+            max_retries: max_retries.map(|(_, _, v)| v),
+            lockout_time_mins: lockout_time_mins.map(|(_, _, v)| v),
+            history: history.map(|(_, _, v)| v),
+            comment: comment.map(|(_, _, v)| v),
+        },
+    )(i)
+}
+
 pub fn user_identity(i: Input) -> IResult<UserIdentity> {
     map(
         rule! {
@@ -2375,6 +2894,8 @@ pub fn auth_type(i: Input) -> IResult<AuthType> {
         value(AuthType::Sha256Password, rule! { SHA256_PASSWORD }),
         value(AuthType::DoubleSha1Password, rule! { DOUBLE_SHA1_PASSWORD }),
         value(AuthType::JWT, rule! { JWT }),
+        value(AuthType::KeyPair, rule! { KEY_PAIR }),
+        value(AuthType::OIDC, rule! { OIDC }),
     ))(i)
 }
 
@@ -129,8 +129,12 @@ fn test_statement() {
         r#"ALTER USER u1 WITH DEFAULT_ROLE = 'role1', TENANTSETTING;"#,
         r#"ALTER USER u1 WITH SET NETWORK POLICY = 'policy1';"#,
         r#"ALTER USER u1 WITH UNSET NETWORK POLICY;"#,
+        r#"ALTER USER u1 WITH SET WORKLOAD GROUP = 'etl';"#,
+        r#"ALTER USER u1 WITH SET PASSWORD POLICY = 'pp';"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH SET NETWORK POLICY='policy1'"#,
+        r#"CREATE USER u1 IDENTIFIED WITH key_pair BY 'public_key'"#,
+        r#"ALTER USER u1 IDENTIFIED WITH oidc"#,
         r#"DROP database if exists db1;"#,
         r#"select distinct a, count(*) from t where a = 1 and b - 1 < a group by a having a = 1;"#,
         r#"select * from t4;"#,
@@ -182,12 +186,20 @@ fn test_statement() {
         r#"OPTIMIZE TABLE t PURGE BEFORE (SNAPSHOT => '9828b23f74664ff3806f44bbc1925ea5') LIMIT 10;"#,
         r#"OPTIMIZE TABLE t PURGE BEFORE (TIMESTAMP => '2023-06-26 09:49:02.038483'::TIMESTAMP) LIMIT 10;"#,
         r#"ALTER TABLE t CLUSTER BY(c1);"#,
+        r#"ALTER TABLE t CLUSTER BY HILBERT(c1, c2);"#,
         r#"ALTER TABLE t DROP CLUSTER KEY;"#,
+        r#"ALTER TABLE t DROP PARTITION IF EXISTS (1);"#,
+        r#"ALTER TABLE t TRUNCATE PARTITION (1);"#,
+        r#"CREATE TABLE t(c1 int) PARTITION BY (c1);"#,
+        r#"CREATE TABLE t(c1 timestamp) TTL = c1 + INTERVAL 90 DAY;"#,
+        r#"CREATE TABLE t(a int, b string, PRIMARY KEY (a), UNIQUE (b));"#,
+        r#"INSERT OVERWRITE t PARTITION (1) VALUES (1, 2);"#,
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0 LIMIT 10;"#,
         r#"ALTER TABLE t ADD COLUMN a float default 101 COMMENT 'hello';"#,
         r#"ALTER TABLE t RENAME COLUMN a TO b;"#,
         r#"ALTER TABLE t DROP COLUMN b;"#,
         r#"ALTER TABLE t MODIFY COLUMN b SET MASKING POLICY mask;"#,
+        r#"ALTER TABLE t ADD ROW ACCESS POLICY tenant_policy ON (tenant, region);"#,
         r#"ALTER TABLE t MODIFY COLUMN a int, COLUMN b float;"#,
         r#"ALTER TABLE t MODIFY COLUMN a int;"#,
         r#"ALTER TABLE t MODIFY COLUMN a DROP STORED;"#,
@@ -222,6 +234,7 @@ fn test_statement() {
         r#"GRANT SELECT ON db01.tb1 TO ROLE 'role1';"#,
         r#"GRANT SELECT ON tb1 TO ROLE 'role1';"#,
         r#"GRANT ALL ON tb1 TO 'u1';"#,
+        r#"GRANT SELECT(id, name) ON db01.tb1 TO ROLE 'analyst';"#,
         r#"SHOW GRANTS;"#,
         r#"SHOW GRANTS FOR 'test-grant';"#,
         r#"SHOW GRANTS FOR USER 'test-grant';"#,
@@ -229,6 +242,7 @@ fn test_statement() {
         r#"REVOKE SELECT, CREATE ON * FROM 'test-grant';"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE 'role1';"#,
         r#"REVOKE ALL ON tb1 FROM 'u1';"#,
+        r#"REVOKE SELECT(name) ON db01.tb1 FROM ROLE 'analyst';"#,
         r#"COPY INTO mytable
                 FROM @~/mybucket/data.csv
                 FILE_FORMAT = (
@@ -394,6 +408,9 @@ fn test_statement() {
         r#"SHOW SHARES;"#,
         r#"SHOW GRANTS ON TABLE db1.tb1;"#,
         r#"SHOW GRANTS ON DATABASE db;"#,
+        r#"SHOW GRANTS ON STAGE s1;"#,
+        r#"GRANT OWNERSHIP ON TABLE t1 TO ROLE 'r1';"#,
+        r#"GRANT OWNERSHIP ON FUNCTION f1 TO ROLE 'r1';"#,
         r#"SHOW GRANTS OF SHARE t;"#,
         r#"UPDATE db1.tb1 set a = a + 1, b = 2 WHERE c > 3;"#,
         r#"SET max_threads = 10;"#,
@@ -417,12 +434,18 @@ fn test_statement() {
         r#"SELECT * FROM t GROUP BY CUBE (a, b, c)"#,
         r#"SELECT * FROM t GROUP BY ROLLUP (a, b, c)"#,
         r#"CREATE MASKING POLICY email_mask AS (val STRING) RETURN STRING -> CASE WHEN current_role() IN ('ANALYST') THEN VAL ELSE '*********'END comment = 'this is a masking policy'"#,
+        r#"CREATE ROW ACCESS POLICY tenant_policy AS (tenant STRING, region STRING) RETURNS BOOLEAN -> tenant = current_user() COMMENT = 'tenant isolation'"#,
         r#"CREATE VIRTUAL COLUMNS (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"ALTER VIRTUAL COLUMNS (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"DROP VIRTUAL COLUMNS FOR t"#,
         r#"GENERATE VIRTUAL COLUMNS FOR t"#,
         r#"CREATE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"ALTER NETWORK POLICY mypolicy SET ALLOWED_IP_LIST=('192.168.10.0/24','192.168.255.1') BLOCKED_IP_LIST=('192.168.1.99') COMMENT='test'"#,
+        r#"CREATE WORKLOAD GROUP etl CPU_SHARE = 200 MEMORY_LIMIT = 1073741824 MAX_CONCURRENCY = 4 QUEUE_TIMEOUT = 60 COMMENT = 'etl jobs'"#,
+        r#"ALTER WORKLOAD GROUP IF EXISTS etl SET MAX_CONCURRENCY = 8"#,
+        r#"ALTER ROLE 'analyst' SET WORKLOAD GROUP = 'dashboard'"#,
+        r#"CREATE PASSWORD POLICY IF NOT EXISTS pp PASSWORD_MIN_LENGTH = 10 PASSWORD_MAX_RETRIES = 3 COMMENT = 'strict'"#,
+        r#"ALTER PASSWORD POLICY pp SET PASSWORD_HISTORY = 5"#,
         "--各环节转各环节转各环节转各环节转各\n  select 34343",
         "-- xxxxx\n  select 34343;",
     ];
@@ -15,6 +15,7 @@
 use std::fmt::Debug;
 
 use common_expression::types::DataType;
+use common_expression::types::F32;
 use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_expression::TableDataType;
@@ -94,6 +95,22 @@ pub struct PushDownInfo {
     pub lazy_materialization: bool,
     /// Aggregating index information.
     pub agg_index: Option<AggIndexInfo>,
+    /// Optional vector top-k, the blocks can be pruned with the vector index.
+    pub vector_top_k: Option<VectorTopK>,
+}
+
+/// VectorTopK is the push down of `ORDER BY <distance>(column, query) LIMIT k`,
+/// the rows are ordered with the closest ones first.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorTopK {
+    /// Name of the vector column.
+    pub column: String,
+    /// Name of the distance function, `cosine_distance`, `l2_distance` or `inner_product`.
+    pub distance: String,
+    /// The constant query vector.
+    pub query: Vec<F32>,
+    pub limit: usize,
+    pub nulls_first: bool,
 }
 
 /// TopK is a wrapper for topk push down items.
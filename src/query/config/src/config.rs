@@ -41,6 +41,7 @@ use common_meta_app::storage::StorageS3Config as InnerStorageS3Config;
 use common_meta_app::storage::StorageWebhdfsConfig as InnerStorageWebhdfsConfig;
 use common_meta_app::tenant::TenantQuota;
 use common_storage::StorageConfig as InnerStorageConfig;
+use common_tracing::AuditLogConfig;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
 use common_tracing::QueryLogConfig;
@@ -1281,11 +1282,14 @@ pub struct QueryConfig {
     #[clap(long, parse(try_from_str), default_value = "false")]
     pub max_memory_limit_enabled: bool,
 
-    #[deprecated(note = "clickhouse tcp support is deprecated")]
+    /// Start the ClickHouse native TCP handler, its default port 9000 is often taken by the
+    /// object storage, such as MinIO.
+    #[clap(long, parse(try_from_str), default_value = "false")]
+    pub clickhouse_handler_enabled: bool,
+
     #[clap(long, default_value = "127.0.0.1")]
     pub clickhouse_handler_host: String,
 
-    #[deprecated(note = "clickhouse tcp support is deprecated")]
     #[clap(long, default_value = "9000")]
     pub clickhouse_handler_port: u16,
 
@@ -1310,6 +1314,12 @@ pub struct QueryConfig {
     #[clap(long, default_value = "8900")]
     pub flight_sql_handler_port: u16,
 
+    #[clap(long, default_value = "127.0.0.1")]
+    pub postgres_handler_host: String,
+
+    #[clap(long, default_value = "15432")]
+    pub postgres_handler_port: u16,
+
     #[clap(long, default_value = "127.0.0.1:9090")]
     pub flight_api_address: String,
 
@@ -1392,6 +1402,26 @@ pub struct QueryConfig {
     #[clap(skip)]
     pub jwt_key_files: Vec<String>,
 
+    /// The jwks url of the OIDC provider, OIDC auth is disabled if it is empty
+    #[clap(long, default_value_t)]
+    pub oidc_jwks_url: String,
+
+    /// The issuer of the OIDC tokens, the `iss` claim must be equal to it, required if OIDC auth is enabled
+    #[clap(long, default_value_t)]
+    pub oidc_issuer: String,
+
+    /// The audience of the OIDC tokens, the `aud` claim must contain it, required if OIDC auth is enabled
+    #[clap(long, default_value_t)]
+    pub oidc_audience: String,
+
+    /// The claim of the OIDC tokens mapped to the user name
+    #[clap(long, default_value = "sub")]
+    pub oidc_user_claim: String,
+
+    /// The claim of the OIDC tokens mapped to the role of the session, no role is mapped if it is empty
+    #[clap(long, default_value_t)]
+    pub oidc_role_claim: String,
+
     #[clap(long, default_value = "auto")]
     pub default_storage_format: String,
 
@@ -1407,6 +1437,24 @@ pub struct QueryConfig {
     #[clap(long, default_value = "")]
     pub share_endpoint_auth_token_file: String,
 
+    /// The id of the master key wrapping the data keys of the encrypted fuse tables.
+    #[clap(long, default_value = "")]
+    pub fuse_encryption_master_key_id: String,
+
+    /// The master keys, in the format of `<key_id>:<base64 key>,...`.
+    // This will not show in system.configs, put it to mask.rs.
+    #[clap(long, default_value = "")]
+    pub fuse_encryption_master_keys: String,
+
+    /// The directory of `<key_id>.key` files containing the base64 master keys, as a local KMS.
+    #[clap(long, default_value = "")]
+    pub fuse_encryption_kms_key_dir: String,
+
+    /// The key of the HMAC signing the records of the audit log.
+    // This will not show in system.configs, put it to mask.rs.
+    #[clap(long, default_value = "")]
+    pub audit_log_hmac_key: String,
+
     #[clap(skip)]
     quota: Option<TenantQuota>,
 
@@ -1518,6 +1566,9 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             max_active_sessions: self.max_active_sessions,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
+            clickhouse_handler_enabled: self.clickhouse_handler_enabled,
+            clickhouse_handler_host: self.clickhouse_handler_host,
+            clickhouse_handler_port: self.clickhouse_handler_port,
             clickhouse_http_handler_host: self.clickhouse_http_handler_host,
             clickhouse_http_handler_port: self.clickhouse_http_handler_port,
             http_handler_host: self.http_handler_host,
@@ -1526,6 +1577,8 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             flight_api_address: self.flight_api_address,
             flight_sql_handler_host: self.flight_sql_handler_host,
             flight_sql_handler_port: self.flight_sql_handler_port,
+            postgres_handler_host: self.postgres_handler_host,
+            postgres_handler_port: self.postgres_handler_port,
             admin_api_address: self.admin_api_address,
             metric_api_address: self.metric_api_address,
             http_handler_tls_server_cert: self.http_handler_tls_server_cert,
@@ -1549,6 +1602,11 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             max_storage_io_requests: self.max_storage_io_requests,
             jwt_key_file: self.jwt_key_file,
             jwt_key_files: self.jwt_key_files,
+            oidc_jwks_url: self.oidc_jwks_url,
+            oidc_issuer: self.oidc_issuer,
+            oidc_audience: self.oidc_audience,
+            oidc_user_claim: self.oidc_user_claim,
+            oidc_role_claim: self.oidc_role_claim,
             default_storage_format: self.default_storage_format,
             default_compression: self.default_compression,
             idm: InnerIDMConfig {
@@ -1556,6 +1614,10 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             },
             share_endpoint_address: self.share_endpoint_address,
             share_endpoint_auth_token_file: self.share_endpoint_auth_token_file,
+            fuse_encryption_master_key_id: self.fuse_encryption_master_key_id,
+            fuse_encryption_master_keys: self.fuse_encryption_master_keys,
+            fuse_encryption_kms_key_dir: self.fuse_encryption_kms_key_dir,
+            audit_log_hmac_key: self.audit_log_hmac_key,
             tenant_quota: self.quota,
             internal_enable_sandbox_tenant: self.internal_enable_sandbox_tenant,
             internal_merge_on_read_mutation: self.internal_merge_on_read_mutation,
@@ -1570,7 +1632,6 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
     }
 }
 
-#[allow(deprecated)]
 impl From<InnerQueryConfig> for QueryConfig {
     fn from(inner: InnerQueryConfig) -> Self {
         Self {
@@ -1584,11 +1645,9 @@ impl From<InnerQueryConfig> for QueryConfig {
             max_active_sessions: inner.max_active_sessions,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
-
-            // clickhouse tcp is deprecated
-            clickhouse_handler_host: "127.0.0.1".to_string(),
-            clickhouse_handler_port: 9000,
-
+            clickhouse_handler_enabled: inner.clickhouse_handler_enabled,
+            clickhouse_handler_host: inner.clickhouse_handler_host,
+            clickhouse_handler_port: inner.clickhouse_handler_port,
             clickhouse_http_handler_host: inner.clickhouse_http_handler_host,
             clickhouse_http_handler_port: inner.clickhouse_http_handler_port,
             http_handler_host: inner.http_handler_host,
@@ -1597,6 +1656,8 @@ impl From<InnerQueryConfig> for QueryConfig {
             flight_api_address: inner.flight_api_address,
             flight_sql_handler_host: inner.flight_sql_handler_host,
             flight_sql_handler_port: inner.flight_sql_handler_port,
+            postgres_handler_host: inner.postgres_handler_host,
+            postgres_handler_port: inner.postgres_handler_port,
             admin_api_address: inner.admin_api_address,
             metric_api_address: inner.metric_api_address,
             http_handler_tls_server_cert: inner.http_handler_tls_server_cert,
@@ -1620,11 +1681,20 @@ impl From<InnerQueryConfig> for QueryConfig {
             max_storage_io_requests: inner.max_storage_io_requests,
             jwt_key_file: inner.jwt_key_file,
             jwt_key_files: inner.jwt_key_files,
+            oidc_jwks_url: inner.oidc_jwks_url,
+            oidc_issuer: inner.oidc_issuer,
+            oidc_audience: inner.oidc_audience,
+            oidc_user_claim: inner.oidc_user_claim,
+            oidc_role_claim: inner.oidc_role_claim,
             default_storage_format: inner.default_storage_format,
             default_compression: inner.default_compression,
             users: users_from_inner(inner.idm.users),
             share_endpoint_address: inner.share_endpoint_address,
             share_endpoint_auth_token_file: inner.share_endpoint_auth_token_file,
+            fuse_encryption_master_key_id: inner.fuse_encryption_master_key_id,
+            fuse_encryption_master_keys: inner.fuse_encryption_master_keys,
+            fuse_encryption_kms_key_dir: inner.fuse_encryption_kms_key_dir,
+            audit_log_hmac_key: inner.audit_log_hmac_key,
             quota: inner.tenant_quota,
             internal_enable_sandbox_tenant: inner.internal_enable_sandbox_tenant,
             internal_merge_on_read_mutation: false,
@@ -1724,12 +1794,18 @@ impl TryInto<InnerLogConfig> for LogConfig {
             dir: format!("{}/query-details", &file.dir),
         };
 
+        let audit = AuditLogConfig {
+            on: true,
+            dir: format!("{}/audit", &file.dir),
+        };
+
         let tracing = TracingConfig::from_env();
 
         Ok(InnerLogConfig {
             file,
             stderr: self.stderr.try_into()?,
             query,
+            audit,
             tracing,
         })
     }
@@ -2053,6 +2129,14 @@ impl TryInto<AuthInfo> for UserAuthConfig {
         match auth_type {
             AuthType::NoPassword => check_no_auth_string(self.auth_string, AuthInfo::None),
             AuthType::JWT => check_no_auth_string(self.auth_string, AuthInfo::JWT),
+            AuthType::OIDC => check_no_auth_string(self.auth_string, AuthInfo::OIDC),
+            AuthType::KeyPair => match self.auth_string {
+                None => Err(ErrorCode::InvalidConfig("must set auth_string")),
+                Some(public_key) => Ok(AuthInfo::KeyPair {
+                    public_key,
+                    public_key_2: None,
+                }),
+            },
             AuthType::Sha256Password | AuthType::DoubleSha1Password => {
                 let password_type = auth_type.get_password_type().expect("must success");
                 match self.auth_string {
@@ -2138,14 +2222,38 @@ pub struct CacheConfig {
     #[clap(long = "cache-table-meta-snapshot-count", default_value = "256")]
     pub table_meta_snapshot_count: u64,
 
+    /// Eviction policy of table snapshot cache, `lru` or `s3fifo`
+    #[clap(
+        long = "cache-table-meta-snapshot-eviction-policy",
+        value_enum,
+        default_value_t
+    )]
+    pub table_meta_snapshot_eviction_policy: CacheEvictionPolicyConfig,
+
     /// Max bytes of cached table segment
     #[clap(long = "cache-table-meta-segment-bytes", default_value = "1073741824")]
     pub table_meta_segment_bytes: u64,
 
+    /// Eviction policy of table segment cache, `lru` or `s3fifo`
+    #[clap(
+        long = "cache-table-meta-segment-eviction-policy",
+        value_enum,
+        default_value_t
+    )]
+    pub table_meta_segment_eviction_policy: CacheEvictionPolicyConfig,
+
     /// Max number of cached table statistic meta
     #[clap(long = "cache-table-meta-statistic-count", default_value = "256")]
     pub table_meta_statistic_count: u64,
 
+    /// Eviction policy of table statistic meta cache, `lru` or `s3fifo`
+    #[clap(
+        long = "cache-table-meta-statistic-eviction-policy",
+        value_enum,
+        default_value_t
+    )]
+    pub table_meta_statistic_eviction_policy: CacheEvictionPolicyConfig,
+
     /// Enable bloom index cache. Default is enabled. Set it to false to disable all the bloom index caches
     #[clap(long = "cache-enable-table-bloom-index-cache", default_value = "true")]
     #[serde(default = "bool_true")]
@@ -2155,6 +2263,14 @@ pub struct CacheConfig {
     #[clap(long = "cache-table-bloom-index-meta-count", default_value = "3000")]
     pub table_bloom_index_meta_count: u64,
 
+    /// Eviction policy of bloom index meta cache, `lru` or `s3fifo`
+    #[clap(
+        long = "cache-table-bloom-index-meta-eviction-policy",
+        value_enum,
+        default_value_t
+    )]
+    pub table_bloom_index_meta_eviction_policy: CacheEvictionPolicyConfig,
+
     /// Max number of cached bloom index filters. Set it to 0 to disable it.
     // One bloom index filter per column of data block being indexed will be generated if necessary.
     //
@@ -2166,9 +2282,25 @@ pub struct CacheConfig {
     )]
     pub table_bloom_index_filter_count: u64,
 
+    /// Eviction policy of bloom index filter cache, `lru` or `s3fifo`
+    #[clap(
+        long = "cache-table-bloom-index-filter-eviction-policy",
+        value_enum,
+        default_value_t
+    )]
+    pub table_bloom_index_filter_eviction_policy: CacheEvictionPolicyConfig,
+
     #[clap(long = "cache-table-prune-partitions-count", default_value = "256")]
     pub table_prune_partitions_count: u64,
 
+    /// Eviction policy of prune partitions cache, `lru` or `s3fifo`
+    #[clap(
+        long = "cache-table-prune-partitions-eviction-policy",
+        value_enum,
+        default_value_t
+    )]
+    pub table_prune_partitions_eviction_policy: CacheEvictionPolicyConfig,
+
     /// Type of data cache storage
     #[clap(long = "cache-data-cache-storage", value_enum, default_value_t)]
     pub data_cache_storage: CacheStorageTypeConfig,
@@ -2204,6 +2336,14 @@ pub struct CacheConfig {
     #[clap(long = "cache-table-data-deserialized-data-bytes", default_value = "0")]
     pub table_data_deserialized_data_bytes: u64,
 
+    /// Eviction policy of in memory table column object cache, `lru` or `s3fifo`
+    #[clap(
+        long = "cache-table-data-deserialized-data-eviction-policy",
+        value_enum,
+        default_value_t
+    )]
+    pub table_data_deserialized_data_eviction_policy: CacheEvictionPolicyConfig,
+
     // ----- the following options/args are all deprecated               ----
     /// Max number of cached table segment
     #[clap(long = "cache-table-meta-segment-count")]
@@ -2236,6 +2376,21 @@ impl Default for CacheStorageTypeConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheEvictionPolicyConfig {
+    /// Least recently used
+    Lru,
+    /// S3-FIFO, which is resistant to large scans
+    S3fifo,
+}
+
+impl Default for CacheEvictionPolicyConfig {
+    fn default() -> Self {
+        Self::Lru
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args, Default)]
 #[serde(default, deny_unknown_fields)]
 pub struct DiskCacheConfig {
@@ -2243,9 +2398,13 @@ pub struct DiskCacheConfig {
     #[clap(long = "cache-disk-max-bytes", default_value = "21474836480")]
     pub max_bytes: u64,
 
-    /// Table disk cache root path
+    /// Table disk cache root path, the cached data is kept across restarts
     #[clap(long = "cache-disk-path", default_value = "./.databend/_cache")]
     pub path: String,
+
+    /// Eviction policy of table disk cache, `lru` or `s3fifo`
+    #[clap(long = "cache-disk-eviction-policy", value_enum, default_value_t)]
+    pub eviction_policy: CacheEvictionPolicyConfig,
 }
 
 mod cache_config_converters {
@@ -2316,17 +2475,36 @@ mod cache_config_converters {
             Ok(Self {
                 enable_table_meta_cache: value.enable_table_meta_cache,
                 table_meta_snapshot_count: value.table_meta_snapshot_count,
+                table_meta_snapshot_eviction_policy: value
+                    .table_meta_snapshot_eviction_policy
+                    .into(),
                 table_meta_segment_bytes: value.table_meta_segment_bytes,
+                table_meta_segment_eviction_policy: value.table_meta_segment_eviction_policy.into(),
                 table_meta_statistic_count: value.table_meta_statistic_count,
+                table_meta_statistic_eviction_policy: value
+                    .table_meta_statistic_eviction_policy
+                    .into(),
                 enable_table_index_bloom: value.enable_table_bloom_index_cache,
                 table_bloom_index_meta_count: value.table_bloom_index_meta_count,
+                table_bloom_index_meta_eviction_policy: value
+                    .table_bloom_index_meta_eviction_policy
+                    .into(),
                 table_bloom_index_filter_count: value.table_bloom_index_filter_count,
+                table_bloom_index_filter_eviction_policy: value
+                    .table_bloom_index_filter_eviction_policy
+                    .into(),
                 table_prune_partitions_count: value.table_prune_partitions_count,
+                table_prune_partitions_eviction_policy: value
+                    .table_prune_partitions_eviction_policy
+                    .into(),
                 data_cache_storage: value.data_cache_storage.try_into()?,
                 table_data_cache_population_queue_size: value
                     .table_data_cache_population_queue_size,
                 disk_cache_config: value.disk_cache_config.try_into()?,
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
+                table_data_deserialized_data_eviction_policy: value
+                    .table_data_deserialized_data_eviction_policy
+                    .into(),
             })
         }
     }
@@ -2336,17 +2514,36 @@ mod cache_config_converters {
             Self {
                 enable_table_meta_cache: value.enable_table_meta_cache,
                 table_meta_snapshot_count: value.table_meta_snapshot_count,
+                table_meta_snapshot_eviction_policy: value
+                    .table_meta_snapshot_eviction_policy
+                    .into(),
                 table_meta_segment_bytes: value.table_meta_segment_bytes,
+                table_meta_segment_eviction_policy: value.table_meta_segment_eviction_policy.into(),
                 table_meta_statistic_count: value.table_meta_statistic_count,
+                table_meta_statistic_eviction_policy: value
+                    .table_meta_statistic_eviction_policy
+                    .into(),
                 enable_table_bloom_index_cache: value.enable_table_index_bloom,
                 table_bloom_index_meta_count: value.table_bloom_index_meta_count,
+                table_bloom_index_meta_eviction_policy: value
+                    .table_bloom_index_meta_eviction_policy
+                    .into(),
                 table_bloom_index_filter_count: value.table_bloom_index_filter_count,
+                table_bloom_index_filter_eviction_policy: value
+                    .table_bloom_index_filter_eviction_policy
+                    .into(),
                 table_prune_partitions_count: value.table_prune_partitions_count,
+                table_prune_partitions_eviction_policy: value
+                    .table_prune_partitions_eviction_policy
+                    .into(),
                 data_cache_storage: value.data_cache_storage.into(),
                 table_data_cache_population_queue_size: value
                     .table_data_cache_population_queue_size,
                 disk_cache_config: value.disk_cache_config.into(),
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
+                table_data_deserialized_data_eviction_policy: value
+                    .table_data_deserialized_data_eviction_policy
+                    .into(),
                 table_meta_segment_count: None,
             }
         }
@@ -2358,6 +2555,7 @@ mod cache_config_converters {
             Ok(Self {
                 max_bytes: value.max_bytes,
                 path: value.path,
+                eviction_policy: value.eviction_policy.into(),
             })
         }
     }
@@ -2367,6 +2565,7 @@ mod cache_config_converters {
             Self {
                 max_bytes: value.max_bytes,
                 path: value.path,
+                eviction_policy: value.eviction_policy.into(),
             }
         }
     }
@@ -2389,4 +2588,22 @@ mod cache_config_converters {
             }
         }
     }
+
+    impl From<CacheEvictionPolicyConfig> for inner::CacheEvictionPolicyConfig {
+        fn from(value: CacheEvictionPolicyConfig) -> Self {
+            match value {
+                CacheEvictionPolicyConfig::Lru => inner::CacheEvictionPolicyConfig::Lru,
+                CacheEvictionPolicyConfig::S3fifo => inner::CacheEvictionPolicyConfig::S3Fifo,
+            }
+        }
+    }
+
+    impl From<inner::CacheEvictionPolicyConfig> for CacheEvictionPolicyConfig {
+        fn from(value: inner::CacheEvictionPolicyConfig) -> Self {
+            match value {
+                inner::CacheEvictionPolicyConfig::Lru => CacheEvictionPolicyConfig::Lru,
+                inner::CacheEvictionPolicyConfig::S3Fifo => CacheEvictionPolicyConfig::S3fifo,
+            }
+        }
+    }
 }
@@ -152,6 +152,9 @@ pub struct QueryConfig {
     pub max_active_sessions: u64,
     pub max_server_memory_usage: u64,
     pub max_memory_limit_enabled: bool,
+    pub clickhouse_handler_enabled: bool,
+    pub clickhouse_handler_host: String,
+    pub clickhouse_handler_port: u16,
     pub clickhouse_http_handler_host: String,
     pub clickhouse_http_handler_port: u16,
     pub http_handler_host: String,
@@ -160,6 +163,8 @@ pub struct QueryConfig {
     pub flight_api_address: String,
     pub flight_sql_handler_host: String,
     pub flight_sql_handler_port: u16,
+    pub postgres_handler_host: String,
+    pub postgres_handler_port: u16,
     pub admin_api_address: String,
     pub metric_api_address: String,
     pub http_handler_tls_server_cert: String,
@@ -190,11 +195,22 @@ pub struct QueryConfig {
 
     pub jwt_key_file: String,
     pub jwt_key_files: Vec<String>,
+    pub oidc_jwks_url: String,
+    pub oidc_issuer: String,
+    pub oidc_audience: String,
+    pub oidc_user_claim: String,
+    pub oidc_role_claim: String,
     pub default_storage_format: String,
     pub default_compression: String,
     pub idm: IDMConfig,
     pub share_endpoint_address: String,
     pub share_endpoint_auth_token_file: String,
+    /// Master keys for the encrypted fuse tables.
+    pub fuse_encryption_master_key_id: String,
+    pub fuse_encryption_master_keys: String,
+    pub fuse_encryption_kms_key_dir: String,
+    /// The key of the HMAC signing the records of the audit log.
+    pub audit_log_hmac_key: String,
     pub tenant_quota: Option<TenantQuota>,
     pub internal_enable_sandbox_tenant: bool,
     pub internal_merge_on_read_mutation: bool,
@@ -222,6 +238,9 @@ impl Default for QueryConfig {
             max_active_sessions: 256,
             max_server_memory_usage: 0,
             max_memory_limit_enabled: false,
+            clickhouse_handler_enabled: false,
+            clickhouse_handler_host: "127.0.0.1".to_string(),
+            clickhouse_handler_port: 9000,
             clickhouse_http_handler_host: "127.0.0.1".to_string(),
             clickhouse_http_handler_port: 8124,
             http_handler_host: "127.0.0.1".to_string(),
@@ -230,6 +249,8 @@ impl Default for QueryConfig {
             flight_api_address: "127.0.0.1:9090".to_string(),
             flight_sql_handler_host: "127.0.0.1".to_string(),
             flight_sql_handler_port: 8900,
+            postgres_handler_host: "127.0.0.1".to_string(),
+            postgres_handler_port: 15432,
             admin_api_address: "127.0.0.1:8080".to_string(),
             metric_api_address: "127.0.0.1:7070".to_string(),
             api_tls_server_cert: "".to_string(),
@@ -252,11 +273,20 @@ impl Default for QueryConfig {
             max_storage_io_requests: None,
             jwt_key_file: "".to_string(),
             jwt_key_files: Vec::new(),
+            oidc_jwks_url: "".to_string(),
+            oidc_issuer: "".to_string(),
+            oidc_audience: "".to_string(),
+            oidc_user_claim: "sub".to_string(),
+            oidc_role_claim: "".to_string(),
             default_storage_format: "auto".to_string(),
             default_compression: "auto".to_string(),
             idm: IDMConfig::default(),
             share_endpoint_address: "".to_string(),
             share_endpoint_auth_token_file: "".to_string(),
+            fuse_encryption_master_key_id: "".to_string(),
+            fuse_encryption_master_keys: "".to_string(),
+            fuse_encryption_kms_key_dir: "".to_string(),
+            audit_log_hmac_key: "".to_string(),
             tenant_quota: None,
             internal_enable_sandbox_tenant: false,
             internal_merge_on_read_mutation: false,
@@ -287,6 +317,8 @@ impl QueryConfig {
             .clone()
             .map(|s| mask_string(&s, 3));
         sanitized.openai_api_key = mask_string(&self.openai_api_key, 3);
+        sanitized.fuse_encryption_master_keys = mask_string(&self.fuse_encryption_master_keys, 3);
+        sanitized.audit_log_hmac_key = mask_string(&self.audit_log_hmac_key, 3);
         sanitized
     }
 }
@@ -480,21 +512,36 @@ pub struct CacheConfig {
     /// Max number of cached table snapshot
     pub table_meta_snapshot_count: u64,
 
+    /// Eviction policy of table snapshot cache
+    pub table_meta_snapshot_eviction_policy: CacheEvictionPolicyConfig,
+
     /// Max size(in bytes) of cached table segment
     pub table_meta_segment_bytes: u64,
 
+    /// Eviction policy of table segment cache
+    pub table_meta_segment_eviction_policy: CacheEvictionPolicyConfig,
+
     /// Max number of cached table segment
     pub table_meta_statistic_count: u64,
 
+    /// Eviction policy of table statistic meta cache
+    pub table_meta_statistic_eviction_policy: CacheEvictionPolicyConfig,
+
     /// Enable bloom index cache. Default is enabled. Set it to false to disable all the bloom index caches
     pub enable_table_index_bloom: bool,
 
     /// Max number of cached bloom index meta objects. Set it to 0 to disable it.
     pub table_bloom_index_meta_count: u64,
 
+    /// Eviction policy of bloom index meta cache
+    pub table_bloom_index_meta_eviction_policy: CacheEvictionPolicyConfig,
+
     /// Max number of cached prune partitions objects. Set it to 0 to disable it.
     pub table_prune_partitions_count: u64,
 
+    /// Eviction policy of prune partitions cache
+    pub table_prune_partitions_eviction_policy: CacheEvictionPolicyConfig,
+
     /// Max number of cached bloom index filters. Set it to 0 to disable it.
     // One bloom index filter per column of data block being indexed will be generated if necessary.
     //
@@ -502,6 +549,9 @@ pub struct CacheConfig {
     // table filter on 2 columns, might populate 2 * 800 bloom index filter cache items (at most)
     pub table_bloom_index_filter_count: u64,
 
+    /// Eviction policy of bloom index filter cache
+    pub table_bloom_index_filter_eviction_policy: CacheEvictionPolicyConfig,
+
     pub data_cache_storage: CacheStorageTypeConfig,
 
     /// Max size of external cache population queue length
@@ -527,6 +577,9 @@ pub struct CacheConfig {
     /// Only if query nodes have plenty of un-utilized memory, the working set can be fitted into,
     /// and the access pattern will benefit from caching, consider enabled this cache.
     pub table_data_deserialized_data_bytes: u64,
+
+    /// Eviction policy of in memory table column object cache
+    pub table_data_deserialized_data_eviction_policy: CacheEvictionPolicyConfig,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -551,13 +604,37 @@ impl ToString for CacheStorageTypeConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CacheEvictionPolicyConfig {
+    Lru,
+    S3Fifo,
+}
+
+impl Default for CacheEvictionPolicyConfig {
+    fn default() -> Self {
+        Self::Lru
+    }
+}
+
+impl ToString for CacheEvictionPolicyConfig {
+    fn to_string(&self) -> String {
+        match self {
+            CacheEvictionPolicyConfig::Lru => "lru".to_string(),
+            CacheEvictionPolicyConfig::S3Fifo => "s3fifo".to_string(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DiskCacheConfig {
     /// Max bytes of cached raw table data. Default 20GB, set it to 0 to disable it.
     pub max_bytes: u64,
 
-    /// Table disk cache root path
+    /// Table disk cache root path, the cached data is kept across restarts
     pub path: String,
+
+    /// Eviction policy of table disk cache
+    pub eviction_policy: CacheEvictionPolicyConfig,
 }
 
 impl Default for DiskCacheConfig {
@@ -565,6 +642,7 @@ impl Default for DiskCacheConfig {
         Self {
             max_bytes: 21474836480,
             path: "./.databend/_cache".to_owned(),
+            eviction_policy: Default::default(),
         }
     }
 }
@@ -574,16 +652,23 @@ impl Default for CacheConfig {
         Self {
             enable_table_meta_cache: true,
             table_meta_snapshot_count: 256,
+            table_meta_snapshot_eviction_policy: Default::default(),
             table_meta_segment_bytes: 1073741824,
+            table_meta_segment_eviction_policy: Default::default(),
             table_meta_statistic_count: 256,
+            table_meta_statistic_eviction_policy: Default::default(),
             enable_table_index_bloom: true,
             table_bloom_index_meta_count: 3000,
+            table_bloom_index_meta_eviction_policy: Default::default(),
             table_bloom_index_filter_count: 1048576,
+            table_bloom_index_filter_eviction_policy: Default::default(),
             table_prune_partitions_count: 256,
+            table_prune_partitions_eviction_policy: Default::default(),
             data_cache_storage: Default::default(),
             table_data_cache_population_queue_size: 65536,
             disk_cache_config: Default::default(),
             table_data_deserialized_data_bytes: 0,
+            table_data_deserialized_data_eviction_policy: Default::default(),
         }
     }
 }
@@ -35,12 +35,14 @@ mod mask;
 mod obsolete;
 mod version;
 
+pub use config::CacheEvictionPolicyConfig;
 pub use config::CacheStorageTypeConfig;
 pub use config::Config;
 pub use config::QueryConfig;
 pub use config::StorageConfig;
 pub use global::GlobalConfig;
 pub use inner::CacheConfig;
+pub use inner::CacheEvictionPolicyConfig as CacheEvictionPolicyInnerConfig;
 pub use inner::CacheStorageTypeConfig as CacheStorageTypeInnerConfig;
 pub use inner::CatalogConfig;
 pub use inner::CatalogHiveConfig;
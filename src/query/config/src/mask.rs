@@ -16,7 +16,11 @@ use crate::Config;
 
 // Mask the config value to ******
 impl Config {
-    pub const fn mask_option_keys() -> &'static [&'static str; 1] {
-        &["openai_api_key"]
+    pub const fn mask_option_keys() -> &'static [&'static str; 3] {
+        &[
+            "openai_api_key",
+            "fuse_encryption_master_keys",
+            "audit_log_hmac_key",
+        ]
     }
 }
@@ -21,10 +21,14 @@ use arrow_array::LargeBinaryArray;
 use arrow_array::RecordBatch;
 use arrow_array::UInt64Array;
 use chrono::Utc;
+use common_ast::parser::quote::quote_ident;
+use common_ast::Dialect;
 use common_base::base::tokio::sync::mpsc::Sender;
 use common_base::base::tokio::sync::Mutex;
 use common_base::base::tokio::time::Instant;
 use common_base::base::uuid::Uuid;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table_context::TableContext;
 use common_config::InnerConfig;
 use common_exception::Result;
 use common_meta_api::BackgroundApi;
@@ -51,6 +55,7 @@ use log::as_debug;
 use log::debug;
 use log::error;
 use log::info;
+use storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::background_service::job::Job;
 
@@ -219,6 +224,9 @@ impl CompactionJob {
                 }
             }
         }
+        if let Err(e) = self.expire_ttl_tables().await {
+            error!("expire ttl tables failed, err: {}", e);
+        }
         info!(
             job = "compaction",
             background = true;
@@ -453,6 +461,48 @@ impl CompactionJob {
         Ok(true)
     }
 
+    // remove the expired rows of the tables with TTL, blocks that are expired entirely
+    // are dropped without rewrite, and blocks that are partially expired are rewritten.
+    async fn expire_ttl_tables(&self) -> Result<()> {
+        let ctx = self.session.create_query_context().await?;
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+        let dialect = ctx.get_settings().get_sql_dialect()?;
+        for db in catalog.list_databases(&tenant).await? {
+            for table in db.list_tables().await? {
+                let Some(ttl) = table.options().get(OPT_KEY_TTL) else {
+                    continue;
+                };
+                let sql = Self::get_ttl_expiry_sql(dialect, db.name(), table.name(), ttl);
+                debug!(
+                    job = "compaction",
+                    background = true,
+                    sql = sql.as_str();
+                    "ttl_expiry"
+                );
+                let ctx = self.session.create_query_context().await?;
+                match SuggestedBackgroundTasksProcedure::do_execute_sql(ctx, sql).await {
+                    Ok(_) => {
+                        info!(
+                            "ttl expiry success, db: {}, table: {}",
+                            db.name(),
+                            table.name()
+                        );
+                    }
+                    Err(e) => {
+                        error!(
+                            "ttl expiry failed, db: {}, table: {}, err: {}",
+                            db.name(),
+                            table.name(),
+                            e
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub async fn do_get_target_tables_from_config(
         config: &InnerConfig,
         ctx: Arc<QueryContext>,
@@ -713,4 +763,15 @@ impl CompactionJob {
         };
         format!("OPTIMIZE TABLE {}.{} COMPACT{};", database, table, limit)
     }
+
+    /// The identifiers in `ttl` keep the quotes they were created with.
+    pub fn get_ttl_expiry_sql(dialect: Dialect, database: &str, table: &str, ttl: &str) -> String {
+        let quote = dialect.default_ident_quote();
+        format!(
+            "DELETE FROM {}.{} WHERE ({}) <= now();",
+            quote_ident(database, quote, true),
+            quote_ident(table, quote, true),
+            ttl
+        )
+    }
 }
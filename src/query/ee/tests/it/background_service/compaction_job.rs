@@ -14,6 +14,7 @@
 
 use core::default::Default;
 
+use common_ast::Dialect;
 use common_base::base::tokio;
 use common_exception::Result;
 use common_meta_app::schema::TableStatistics;
@@ -66,6 +67,31 @@ async fn test_get_block_compaction_sql() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_get_ttl_expiry_sql() -> Result<()> {
+    let sql = CompactionJob::get_ttl_expiry_sql(
+        Dialect::PostgreSQL,
+        "db1",
+        "tbl1",
+        "(c1 + INTERVAL 90 DAY)",
+    );
+    assert_eq!(
+        sql.trim(),
+        "DELETE FROM \"db1\".\"tbl1\" WHERE ((c1 + INTERVAL 90 DAY)) <= now();"
+    );
+    let sql = CompactionJob::get_ttl_expiry_sql(
+        Dialect::MySQL,
+        "my db",
+        "t`1",
+        "(`C 1` + INTERVAL 90 DAY)",
+    );
+    assert_eq!(
+        sql.trim(),
+        "DELETE FROM `my db`.`t``1` WHERE ((`C 1` + INTERVAL 90 DAY)) <= now();"
+    );
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_parse_target_tables() -> Result<()> {
     let tables = CompactionJob::parse_all_target_tables(Some(&vec![
@@ -83,6 +83,23 @@ impl FieldEncoderValues {
             quote_char: b'\'',
         }
     }
+
+    // The text format of postgres, booleans are 't' and 'f', which is what the
+    // clients (libpq, JDBC, psycopg) expect to decode.
+    pub fn create_for_postgres_handler(timezone: Tz) -> Self {
+        FieldEncoderValues {
+            common_settings: CommonSettings {
+                true_bytes: b"t".to_vec(),
+                false_bytes: b"f".to_vec(),
+                null_bytes: NULL_BYTES_UPPER.as_bytes().to_vec(),
+                nan_bytes: NAN_BYTES_SNAKE.as_bytes().to_vec(),
+                inf_bytes: INF_BYTES_LONG.as_bytes().to_vec(),
+                timezone,
+                disable_variant_check: false,
+            },
+            quote_char: b'\'',
+        }
+    }
 }
 
 impl FieldEncoderRowBased for FieldEncoderValues {
@@ -0,0 +1,212 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::AnyType;
+use common_expression::types::DataType;
+use common_expression::types::NumberScalar;
+use common_expression::Column;
+use common_expression::Function;
+use common_expression::FunctionDomain;
+use common_expression::FunctionEval;
+use common_expression::FunctionRegistry;
+use common_expression::FunctionSignature;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+/// Each argument is mapped to a 64-bit coordinate, so the key of `n` arguments has `8 * n` bytes.
+const COORDINATE_BITS: usize = 64;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // hilbert
+    // This function maps the arguments onto a hilbert curve, and returns the position on the curve
+    // as a big-endian binary string, so rows close to each other in all dimensions get close keys.
+    register_curve(registry, "hilbert", hilbert_key);
+
+    // zorder
+    // This function is like `hilbert`, but interleaves the bits of the arguments (z-order curve).
+    register_curve(registry, "zorder", zorder_key);
+}
+
+fn register_curve(
+    registry: &mut FunctionRegistry,
+    name: &'static str,
+    encode: fn(&mut [u64], &mut Vec<u8>),
+) {
+    registry.register_function_factory(name, move |_, args_type| {
+        if args_type.is_empty() || !args_type.iter().all(is_coordinate_type) {
+            return None;
+        }
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: name.to_string(),
+                args_type: args_type.to_vec(),
+                return_type: DataType::String,
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                eval: Box::new(move |args, _| curve_fn(args, encode)),
+            },
+        }))
+    });
+}
+
+fn is_coordinate_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        DataType::Number(_)
+            | DataType::Decimal(_)
+            | DataType::String
+            | DataType::Timestamp
+            | DataType::Date
+            | DataType::Boolean
+    )
+}
+
+fn curve_fn(args: &[ValueRef<AnyType>], encode: fn(&mut [u64], &mut Vec<u8>)) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+
+    let size = len.unwrap_or(1);
+    let mut builder = StringColumnBuilder::with_capacity(size, size * args.len() * 8);
+    let mut coordinates = vec![0u64; args.len()];
+    for idx in 0..size {
+        for (coordinate, arg) in coordinates.iter_mut().zip(args) {
+            *coordinate = to_coordinate(unsafe { arg.index_unchecked(idx) });
+        }
+        encode(&mut coordinates, &mut builder.data);
+        builder.commit_row();
+    }
+
+    match len {
+        Some(_) => Value::Column(Column::String(builder.build())),
+        _ => Value::Scalar(Scalar::String(builder.build_scalar())),
+    }
+}
+
+/// Maps a value to a coordinate on the curve, the mapping keeps the order of the values.
+///
+/// Numeric values are mapped through their `f64` representation, so the leading bits of the
+/// coordinate (sign and exponent) are significant even if the values only span a small range.
+/// Strings use their first 8 bytes, and NULLs are placed after all the other values.
+fn to_coordinate(value: ScalarRef) -> u64 {
+    match value {
+        ScalarRef::Null => u64::MAX,
+        ScalarRef::Number(v) => float_coordinate(number_to_f64(v)),
+        ScalarRef::Decimal(v) => float_coordinate(v.to_float64()),
+        ScalarRef::Timestamp(v) => float_coordinate(v as f64),
+        ScalarRef::Date(v) => float_coordinate(v as f64),
+        ScalarRef::Boolean(v) => float_coordinate(v as u8 as f64),
+        ScalarRef::String(v) => {
+            let mut buf = [0u8; 8];
+            let len = v.len().min(8);
+            buf[..len].copy_from_slice(&v[..len]);
+            u64::from_be_bytes(buf)
+        }
+        _ => unreachable!("unsupported coordinate value {:?}", value),
+    }
+}
+
+fn number_to_f64(v: NumberScalar) -> f64 {
+    match v {
+        NumberScalar::UInt8(v) => v as f64,
+        NumberScalar::UInt16(v) => v as f64,
+        NumberScalar::UInt32(v) => v as f64,
+        NumberScalar::UInt64(v) => v as f64,
+        NumberScalar::Int8(v) => v as f64,
+        NumberScalar::Int16(v) => v as f64,
+        NumberScalar::Int32(v) => v as f64,
+        NumberScalar::Int64(v) => v as f64,
+        NumberScalar::Float32(v) => v.0 as f64,
+        NumberScalar::Float64(v) => v.0,
+    }
+}
+
+/// Flips the bits of a float so that the unsigned integer order matches the float order.
+fn float_coordinate(v: f64) -> u64 {
+    let bits = v.to_bits();
+    if bits >> 63 == 1 {
+        !bits
+    } else {
+        bits | (1 << 63)
+    }
+}
+
+/// Interleaves the bits of the coordinates, from the most significant bit to the least.
+fn zorder_key(coordinates: &mut [u64], buf: &mut Vec<u8>) {
+    let mut byte = 0u8;
+    let mut filled = 0;
+    for bit in (0..COORDINATE_BITS).rev() {
+        for coordinate in coordinates.iter() {
+            byte = (byte << 1) | ((coordinate >> bit) & 1) as u8;
+            filled += 1;
+            if filled == 8 {
+                buf.push(byte);
+                byte = 0;
+                filled = 0;
+            }
+        }
+    }
+}
+
+/// Converts the coordinates to the "transposed" hilbert index in place, then interleaves
+/// the bits to get the position on the curve.
+///
+/// See John Skilling, "Programming the Hilbert curve", AIP Conference Proceedings 707, 2004.
+fn hilbert_key(coordinates: &mut [u64], buf: &mut Vec<u8>) {
+    let n = coordinates.len();
+    let x = coordinates;
+
+    // Inverse undo.
+    let mut q = 1u64 << (COORDINATE_BITS - 1);
+    while q > 1 {
+        let p = q - 1;
+        for i in 0..n {
+            if x[i] & q != 0 {
+                // invert
+                x[0] ^= p;
+            } else {
+                // exchange
+                let t = (x[0] ^ x[i]) & p;
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+        q >>= 1;
+    }
+
+    // Gray encode.
+    for i in 1..n {
+        x[i] ^= x[i - 1];
+    }
+    let mut t = 0;
+    let mut q = 1u64 << (COORDINATE_BITS - 1);
+    while q > 1 {
+        if x[n - 1] & q != 0 {
+            t ^= q - 1;
+        }
+        q >>= 1;
+    }
+    for v in x.iter_mut() {
+        *v ^= t;
+    }
+
+    zorder_key(x, buf);
+}
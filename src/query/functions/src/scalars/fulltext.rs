@@ -0,0 +1,210 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::F64;
+use common_expression::types::BooleanType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::vectorize_with_builder_3_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+
+/// The BM25 term frequency saturation parameter.
+const BM25_K1: f64 = 1.2;
+
+/// Tokenizer used by full-text search, both when building an inverted index
+/// and when evaluating `match` against the raw text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Tokenizer {
+    /// Splits text on every non alphanumeric character and lowercases the terms.
+    ///
+    /// Characters are lowercased one by one, so that a lowercased fragment of the
+    /// text is always a fragment of the lowercased terms.
+    #[default]
+    Standard,
+    /// Splits text on whitespace, terms are kept as they are.
+    Whitespace,
+}
+
+impl Tokenizer {
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "standard" => Ok(Tokenizer::Standard),
+            "whitespace" => Ok(Tokenizer::Whitespace),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Unknown tokenizer '{}', expecting 'standard' or 'whitespace'",
+                name
+            ))),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tokenizer::Standard => "standard",
+            Tokenizer::Whitespace => "whitespace",
+        }
+    }
+
+    pub fn tokenize(&self, text: &str) -> Vec<String> {
+        match self {
+            Tokenizer::Standard => text
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|term| !term.is_empty())
+                .map(|term| term.chars().flat_map(char::to_lowercase).collect())
+                .collect(),
+            Tokenizer::Whitespace => text
+                .split_whitespace()
+                .map(|term| term.to_string())
+                .collect(),
+        }
+    }
+
+    /// Normalizes a literal fragment of a LIKE pattern to the form the terms are stored in.
+    ///
+    /// Returns `None` if the fragment may span several terms, e.g. contains a separator,
+    /// in which case the index can not tell whether the fragment exists.
+    pub fn normalize_fragment(&self, fragment: &str) -> Option<String> {
+        match self {
+            Tokenizer::Standard if fragment.chars().all(|c| c.is_alphanumeric()) => {
+                Some(fragment.chars().flat_map(char::to_lowercase).collect())
+            }
+            Tokenizer::Whitespace if !fragment.chars().any(|c| c.is_whitespace()) => {
+                Some(fragment.to_string())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Tokenizer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Returns true if the text contains every term of the query.
+pub fn full_text_match(tokenizer: Tokenizer, text: &str, query: &str) -> bool {
+    let query_terms = tokenizer.tokenize(query);
+    if query_terms.is_empty() {
+        return false;
+    }
+    let terms = tokenizer.tokenize(text).into_iter().collect::<HashSet<_>>();
+    query_terms.iter().all(|term| terms.contains(term))
+}
+
+/// Scores the text against the query with the term frequency part of BM25.
+///
+/// The collection statistics are not available to a scalar function, so the IDF
+/// part is left out and every query term weights the same.
+/// Texts that do not contain any of the query terms score 0.
+pub fn full_text_score(tokenizer: Tokenizer, text: &str, query: &str) -> f64 {
+    let mut term_freqs = HashMap::new();
+    for term in tokenizer.tokenize(text) {
+        *term_freqs.entry(term).or_insert(0usize) += 1;
+    }
+    let query_terms = tokenizer
+        .tokenize(query)
+        .into_iter()
+        .collect::<HashSet<_>>();
+    query_terms
+        .iter()
+        .map(|term| {
+            let tf = term_freqs.get(term).copied().unwrap_or(0) as f64;
+            tf * (BM25_K1 + 1.0) / (tf + BM25_K1)
+        })
+        .sum()
+}
+
+fn parse_tokenizer(name: &[u8], ctx: &mut EvalContext, row: usize) -> Tokenizer {
+    match std::str::from_utf8(name)
+        .map_err(|e| ErrorCode::BadArguments(e.to_string()))
+        .and_then(Tokenizer::from_name)
+    {
+        Ok(tokenizer) => tokenizer,
+        Err(err) => {
+            ctx.set_error(row, err.message());
+            Tokenizer::default()
+        }
+    }
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // match(text, query [, tokenizer])
+    // Returns true if the text contains all the terms of the query.
+    // If the column has an inverted index built with the same tokenizer, blocks that
+    // can not match are pruned.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, BooleanType>(
+            |text, query, output, _| {
+                let text = String::from_utf8_lossy(text);
+                let query = String::from_utf8_lossy(query);
+                output.push(full_text_match(Tokenizer::default(), &text, &query));
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, BooleanType>(
+            |text, query, tokenizer, output, ctx| {
+                let tokenizer = parse_tokenizer(tokenizer, ctx, output.len());
+                let text = String::from_utf8_lossy(text);
+                let query = String::from_utf8_lossy(query);
+                output.push(full_text_match(tokenizer, &text, &query));
+            },
+        ),
+    );
+
+    // match_score(text, query [, tokenizer])
+    // Returns the relevance score of the text for the query, see `full_text_score`.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, NumberType<F64>, _, _>(
+        "match_score",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, NumberType<F64>>(
+            |text, query, output, _| {
+                let text = String::from_utf8_lossy(text);
+                let query = String::from_utf8_lossy(query);
+                output.push(F64::from(full_text_score(
+                    Tokenizer::default(),
+                    &text,
+                    &query,
+                )));
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, NumberType<F64>, _, _>(
+        "match_score",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<StringType, StringType, StringType, NumberType<F64>>(
+            |text, query, tokenizer, output, ctx| {
+                let tokenizer = parse_tokenizer(tokenizer, ctx, output.len());
+                let text = String::from_utf8_lossy(text);
+                let query = String::from_utf8_lossy(query);
+                output.push(F64::from(full_text_score(tokenizer, &text, &query)));
+            },
+        ),
+    );
+}
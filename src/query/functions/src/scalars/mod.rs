@@ -21,8 +21,10 @@ mod bitmap;
 mod boolean;
 mod comparison;
 mod control;
+mod curve;
 mod datetime;
 mod decimal;
+mod fulltext;
 mod geo;
 mod geo_h3;
 mod hash;
@@ -39,6 +41,9 @@ pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use fulltext::full_text_match;
+pub use fulltext::full_text_score;
+pub use fulltext::Tokenizer;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -60,4 +65,6 @@ pub fn register(registry: &mut FunctionRegistry) {
     decimal::register(registry);
     vector::register(registry);
     bitmap::register(registry);
+    fulltext::register(registry);
+    curve::register(registry);
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_arrow::arrow::buffer::Buffer;
+use common_exception::Result;
 use common_expression::types::ArrayType;
 use common_expression::types::Float32Type;
 use common_expression::types::StringType;
@@ -23,32 +24,21 @@ use common_expression::FunctionDomain;
 use common_expression::FunctionRegistry;
 use common_openai::OpenAI;
 use common_vector::cosine_distance;
+use common_vector::inner_product;
+use common_vector::l2_distance;
 
 pub fn register(registry: &mut FunctionRegistry) {
     // cosine_distance
     // This function takes two Float32 arrays as input and computes the cosine distance between them.
-    registry.register_passthrough_nullable_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>, Float32Type, _, _>(
-        "cosine_distance",
-        |_, _, _| FunctionDomain::MayThrow,
-        vectorize_with_builder_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>,  Float32Type>(
-            |lhs, rhs, output, ctx| {
-                let l_f32=
-                    unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(lhs) };
-                let r_f32=
-                    unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(rhs) };
+    register_vector_distance(registry, "cosine_distance", cosine_distance);
 
-                match cosine_distance(l_f32.as_slice(), r_f32.as_slice()) {
-                    Ok(dist) => {
-                        output.push(F32::from(dist));
-                    }
-                    Err(err) => {
-                        ctx.set_error(output.len(), err.to_string());
-                        output.push(F32::from(0.0));
-                    }
-                }
-            }
-        ),
-    );
+    // l2_distance
+    // This function takes two Float32 arrays as input and computes the euclidean distance between them.
+    register_vector_distance(registry, "l2_distance", l2_distance);
+
+    // inner_product
+    // This function takes two Float32 arrays as input and computes the inner product of them.
+    register_vector_distance(registry, "inner_product", inner_product);
 
     // embedding_vector
     // This function takes two strings as input, sends an API request to OpenAI, and returns the Float32 array of embeddings.
@@ -144,3 +134,30 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 }
+
+fn register_vector_distance(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    distance: fn(&[f32], &[f32]) -> Result<f32>,
+) {
+    registry.register_passthrough_nullable_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>, Float32Type, _, _>(
+        name,
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>, Float32Type>(
+            move |lhs, rhs, output, ctx| {
+                let l_f32 = unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(lhs) };
+                let r_f32 = unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(rhs) };
+
+                match distance(l_f32.as_slice(), r_f32.as_slice()) {
+                    Ok(dist) => {
+                        output.push(F32::from(dist));
+                    }
+                    Err(err) => {
+                        ctx.set_error(output.len(), err.to_string());
+                        output.push(F32::from(0.0));
+                    }
+                }
+            }
+        ),
+    );
+}
@@ -0,0 +1,25 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::tenant::AuditChainHead;
+
+#[async_trait::async_trait]
+pub trait AuditChainApi: Sync + Send {
+    /// The head of the audit chain, `None` if no record has been written.
+    async fn get_head(&self) -> Result<Option<AuditChainHead>>;
+
+    /// Move the head forward to `head`, a head behind the persisted one is ignored.
+    async fn set_head(&self, head: &AuditChainHead) -> Result<()>;
+}
@@ -0,0 +1,108 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::tenant::AuditChainHead;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::IntoSeqV;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use super::audit_chain_api::AuditChainApi;
+
+static AUDIT_CHAIN_API_KEY_PREFIX: &str = "__fd_audit_chain";
+
+// The head of a chain is only moved by its own node, a conflict is a node started twice.
+const MAX_SET_HEAD_RETRIES: usize = 4;
+
+pub struct AuditChainMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    audit_chain_key: String,
+}
+
+impl AuditChainMgr {
+    /// `chain_id` identifies the node writing the chain, it should not change across restarts.
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+        chain_id: &str,
+    ) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while audit chain mgr create)",
+            ));
+        }
+        Ok(AuditChainMgr {
+            kv_api,
+            audit_chain_key: format!(
+                "{}/{}/{}",
+                AUDIT_CHAIN_API_KEY_PREFIX,
+                escape_for_key(tenant)?,
+                escape_for_key(chain_id)?
+            ),
+        })
+    }
+
+    async fn get_seq_head(&self) -> Result<Option<SeqV<AuditChainHead>>> {
+        match self.kv_api.get_kv(&self.audit_chain_key).await? {
+            Some(seq_value) => Ok(Some(seq_value.into_seqv()?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditChainApi for AuditChainMgr {
+    #[async_backtrace::framed]
+    async fn get_head(&self) -> Result<Option<AuditChainHead>> {
+        Ok(self.get_seq_head().await?.map(|seq_value| seq_value.data))
+    }
+
+    #[async_backtrace::framed]
+    async fn set_head(&self, head: &AuditChainHead) -> Result<()> {
+        for _ in 0..MAX_SET_HEAD_RETRIES {
+            let seq = match self.get_seq_head().await? {
+                Some(seq_value) if seq_value.data.seq >= head.seq => return Ok(()),
+                Some(seq_value) => seq_value.seq,
+                None => 0,
+            };
+
+            let value = serde_json::to_vec(head)?;
+            let res = self
+                .kv_api
+                .upsert_kv(UpsertKVReq::new(
+                    &self.audit_chain_key,
+                    MatchSeq::Exact(seq),
+                    Operation::Update(value),
+                    None,
+                ))
+                .await?;
+            if res.is_changed() {
+                return Ok(());
+            }
+        }
+
+        Err(ErrorCode::AuditChainConflict(format!(
+            "failed to set the audit chain head {}, too many conflicts",
+            self.audit_chain_key
+        )))
+    }
+}
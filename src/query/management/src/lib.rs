@@ -14,25 +14,35 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod audit_chain;
 mod cluster;
 mod file_format;
 mod network_policy;
+mod password_policy;
 mod quota;
+mod resource_usage;
 mod role;
 mod serde;
 mod setting;
 mod stage;
 mod udf;
 mod user;
+mod workload_group;
 
+pub use audit_chain::AuditChainApi;
+pub use audit_chain::AuditChainMgr;
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
 pub use file_format::FileFormatApi;
 pub use file_format::FileFormatMgr;
 pub use network_policy::NetworkPolicyApi;
 pub use network_policy::NetworkPolicyMgr;
+pub use password_policy::PasswordPolicyApi;
+pub use password_policy::PasswordPolicyMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
+pub use resource_usage::ResourceUsageApi;
+pub use resource_usage::ResourceUsageMgr;
 pub use role::RoleApi;
 pub use role::RoleMgr;
 pub use serde::deserialize_struct;
@@ -45,3 +55,5 @@ pub use udf::UdfApi;
 pub use udf::UdfMgr;
 pub use user::UserApi;
 pub use user::UserMgr;
+pub use workload_group::WorkloadGroupApi;
+pub use workload_group::WorkloadGroupMgr;
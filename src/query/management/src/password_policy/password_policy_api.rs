@@ -0,0 +1,35 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::principal::PasswordPolicy;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait PasswordPolicyApi: Sync + Send {
+    async fn add_password_policy(&self, password_policy: PasswordPolicy) -> Result<u64>;
+
+    async fn update_password_policy(
+        &self,
+        password_policy: PasswordPolicy,
+        seq: MatchSeq,
+    ) -> Result<u64>;
+
+    async fn drop_password_policy(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    async fn get_password_policy(&self, name: &str, seq: MatchSeq) -> Result<SeqV<PasswordPolicy>>;
+
+    async fn get_password_policies(&self) -> Result<Vec<PasswordPolicy>>;
+}
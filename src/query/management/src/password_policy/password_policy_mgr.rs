@@ -0,0 +1,169 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PasswordPolicy;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::password_policy::password_policy_api::PasswordPolicyApi;
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+
+static PASSWORD_POLICY_API_KEY_PREFIX: &str = "__fd_password_policies";
+
+pub struct PasswordPolicyMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    password_policy_prefix: String,
+}
+
+impl PasswordPolicyMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create password policy)",
+            ));
+        }
+
+        Ok(PasswordPolicyMgr {
+            kv_api,
+            password_policy_prefix: format!("{}/{}", PASSWORD_POLICY_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_password_policy_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.password_policy_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl PasswordPolicyApi for PasswordPolicyMgr {
+    #[async_backtrace::framed]
+    async fn add_password_policy(&self, password_policy: PasswordPolicy) -> Result<u64> {
+        let match_seq = MatchSeq::Exact(0);
+        let key = self.make_password_policy_key(password_policy.name.as_str())?;
+        let value = Operation::Update(serialize_struct(
+            &password_policy,
+            ErrorCode::IllegalPasswordPolicy,
+            || "",
+        )?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api.upsert_kv(UpsertKVReq::new(&key, match_seq, value, None));
+
+        let res = upsert_kv.await?.added_or_else(|v| {
+            ErrorCode::PasswordPolicyAlreadyExists(format!(
+                "PasswordPolicy already exists, seq [{}]",
+                v.seq
+            ))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    #[async_backtrace::framed]
+    async fn update_password_policy(
+        &self,
+        password_policy: PasswordPolicy,
+        match_seq: MatchSeq,
+    ) -> Result<u64> {
+        let key = self.make_password_policy_key(password_policy.name.as_str())?;
+        let value = Operation::Update(serialize_struct(
+            &password_policy,
+            ErrorCode::IllegalPasswordPolicy,
+            || "",
+        )?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, match_seq, value, None))
+            .await?;
+
+        match upsert_kv.result {
+            Some(SeqV { seq: s, .. }) => Ok(s),
+            None => Err(ErrorCode::UnknownPasswordPolicy(format!(
+                "Unknown PasswordPolicy, or seq not match {}",
+                password_policy.name.clone()
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_password_policy(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_password_policy_key(name)?;
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownPasswordPolicy(format!(
+                "Unknown PasswordPolicy {}",
+                name
+            )))
+        }
+    }
+
+    async fn get_password_policy(&self, name: &str, seq: MatchSeq) -> Result<SeqV<PasswordPolicy>> {
+        let key = self.make_password_policy_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| {
+            ErrorCode::UnknownPasswordPolicy(format!("Unknown PasswordPolicy {}", name))
+        })?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalPasswordPolicy, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownPasswordPolicy(format!(
+                "Unknown PasswordPolicy {}",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_password_policies(&self) -> Result<Vec<PasswordPolicy>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.password_policy_prefix)
+            .await?;
+
+        let mut password_policies = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let password_policy =
+                deserialize_struct(&value.data, ErrorCode::IllegalPasswordPolicy, || "")?;
+            password_policies.push(password_policy);
+        }
+        Ok(password_policies)
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::tenant::ResourceUsage;
+
+#[async_trait::async_trait]
+pub trait ResourceUsageApi: Sync + Send {
+    /// Add `usage` to the record of its day, user, role and warehouse.
+    async fn add_usage(&self, usage: &ResourceUsage) -> Result<()>;
+
+    async fn get_usages(&self) -> Result<Vec<ResourceUsage>>;
+}
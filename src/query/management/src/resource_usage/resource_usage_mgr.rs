@@ -0,0 +1,138 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::tenant::ResourceUsage;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::IntoSeqV;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use super::resource_usage_api::ResourceUsageApi;
+
+static RESOURCE_USAGE_API_KEY_PREFIX: &str = "__fd_resource_usage";
+
+// Queries of the same user finish concurrently on all the query nodes, retry on conflicts.
+const MAX_ADD_USAGE_RETRIES: usize = 16;
+
+// The usages are kept for a bit more than a year, to compare with the same month last year.
+const RESOURCE_USAGE_RETENTION_DAYS: u64 = 400;
+
+pub struct ResourceUsageMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    resource_usage_prefix: String,
+}
+
+impl ResourceUsageMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while resource usage mgr create)",
+            ));
+        }
+        Ok(ResourceUsageMgr {
+            kv_api,
+            resource_usage_prefix: format!(
+                "{}/{}",
+                RESOURCE_USAGE_API_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+        })
+    }
+
+    fn make_resource_usage_key(&self, usage: &ResourceUsage) -> Result<String> {
+        Ok(format!(
+            "{}/{}/{}/{}/{}",
+            self.resource_usage_prefix,
+            usage.usage_date,
+            escape_for_key(&usage.user)?,
+            escape_for_key(&usage.role)?,
+            escape_for_key(&usage.warehouse)?
+        ))
+    }
+
+    // The record of a day expires `RESOURCE_USAGE_RETENTION_DAYS` after the day.
+    fn make_resource_usage_meta(usage: &ResourceUsage) -> KVMeta {
+        let expire_days = (usage.usage_date.max(0) as u64) + RESOURCE_USAGE_RETENTION_DAYS;
+        KVMeta {
+            expire_at: Some(expire_days * 24 * 3600),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ResourceUsageApi for ResourceUsageMgr {
+    #[async_backtrace::framed]
+    async fn add_usage(&self, usage: &ResourceUsage) -> Result<()> {
+        let key = self.make_resource_usage_key(usage)?;
+        for _ in 0..MAX_ADD_USAGE_RETRIES {
+            let (seq, mut total) = match self.kv_api.get_kv(&key).await? {
+                Some(seq_value) => {
+                    let seq_value: SeqV<ResourceUsage> = seq_value.into_seqv()?;
+                    (seq_value.seq, seq_value.data)
+                }
+                None => (0, ResourceUsage {
+                    usage_date: usage.usage_date,
+                    user: usage.user.clone(),
+                    role: usage.role.clone(),
+                    warehouse: usage.warehouse.clone(),
+                    ..Default::default()
+                }),
+            };
+            total.merge(usage);
+
+            let value = serde_json::to_vec(&total)?;
+            let res = self
+                .kv_api
+                .upsert_kv(UpsertKVReq::new(
+                    &key,
+                    MatchSeq::Exact(seq),
+                    Operation::Update(value),
+                    Some(Self::make_resource_usage_meta(usage)),
+                ))
+                .await?;
+            if res.is_changed() {
+                return Ok(());
+            }
+        }
+
+        Err(ErrorCode::ResourceUsageConflict(format!(
+            "failed to add resource usage of user {}, too many conflicts",
+            usage.user
+        )))
+    }
+
+    #[async_backtrace::framed]
+    async fn get_usages(&self) -> Result<Vec<ResourceUsage>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&format!("{}/", self.resource_usage_prefix))
+            .await?;
+
+        let mut usages = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let usage: SeqV<ResourceUsage> = value.into_seqv()?;
+            usages.push(usage.data);
+        }
+        Ok(usages)
+    }
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use common_exception::Result;
+use common_meta_app::principal::OwnershipInfo;
+use common_meta_app::principal::OwnershipObject;
 use common_meta_app::principal::RoleInfo;
 use common_meta_types::MatchSeq;
 use common_meta_types::SeqV;
@@ -36,4 +38,14 @@ pub trait RoleApi: Sync + Send {
     where F: FnOnce(&mut RoleInfo) + Send;
 
     async fn drop_role(&self, role: String, seq: MatchSeq) -> Result<()>;
+
+    /// Set the owner role of the object, the previous owner(if any) is replaced.
+    async fn grant_ownership(&self, object: &OwnershipObject, role: &str) -> Result<()>;
+
+    /// Remove the owner of the object, it's ok if the object has no owner.
+    async fn revoke_ownership(&self, object: &OwnershipObject) -> Result<()>;
+
+    async fn get_ownership(&self, object: &OwnershipObject) -> Result<Option<OwnershipInfo>>;
+
+    async fn get_ownerships(&self) -> Result<Vec<SeqV<OwnershipInfo>>>;
 }
@@ -14,8 +14,11 @@
 
 use std::sync::Arc;
 
+use common_base::base::escape_for_key;
 use common_exception::ErrorCode;
 use common_exception::ToErrorCode;
+use common_meta_app::principal::OwnershipInfo;
+use common_meta_app::principal::OwnershipObject;
 use common_meta_app::principal::RoleInfo;
 use common_meta_kvapi::kvapi;
 use common_meta_kvapi::kvapi::UpsertKVReq;
@@ -29,10 +32,12 @@ use common_meta_types::SeqV;
 use crate::role::role_api::RoleApi;
 
 static ROLE_API_KEY_PREFIX: &str = "__fd_roles";
+static OBJECT_OWNER_API_KEY_PREFIX: &str = "__fd_object_owners";
 
 pub struct RoleMgr {
     kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
     role_prefix: String,
+    owner_prefix: String,
 }
 
 impl RoleMgr {
@@ -49,6 +54,7 @@ impl RoleMgr {
         Ok(RoleMgr {
             kv_api,
             role_prefix: format!("{}/{}", ROLE_API_KEY_PREFIX, tenant),
+            owner_prefix: format!("{}/{}", OBJECT_OWNER_API_KEY_PREFIX, tenant),
         })
     }
 
@@ -77,6 +83,23 @@ impl RoleMgr {
     fn make_role_key(&self, role: &str) -> String {
         format!("{}/{}", self.role_prefix, role)
     }
+
+    fn make_object_owner_key(&self, object: &OwnershipObject) -> Result<String, ErrorCode> {
+        let object_key = match object {
+            OwnershipObject::Database {
+                catalog_name,
+                db_id,
+            } => format!("database-by-id/{}/{}", escape_for_key(catalog_name)?, db_id),
+            OwnershipObject::Table {
+                catalog_name,
+                table_id,
+                ..
+            } => format!("table-by-id/{}/{}", escape_for_key(catalog_name)?, table_id),
+            OwnershipObject::Stage { name } => format!("stage-by-name/{}", escape_for_key(name)?),
+            OwnershipObject::UDF { name } => format!("udf-by-name/{}", escape_for_key(name)?),
+        };
+        Ok(format!("{}/{}", self.owner_prefix, object_key))
+    }
 }
 
 #[async_trait::async_trait]
@@ -174,4 +197,70 @@ impl RoleApi for RoleMgr {
             Err(ErrorCode::UnknownRole(format!("unknown role {}", role)))
         }
     }
+
+    #[async_backtrace::framed]
+    async fn grant_ownership(&self, object: &OwnershipObject, role: &str) -> Result<(), ErrorCode> {
+        let key = self.make_object_owner_key(object)?;
+        let value = serde_json::to_vec(&OwnershipInfo {
+            object: object.clone(),
+            role: role.to_string(),
+        })?;
+
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(0),
+                Operation::Update(value),
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn revoke_ownership(&self, object: &OwnershipObject) -> Result<(), ErrorCode> {
+        let key = self.make_object_owner_key(object)?;
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(0),
+                Operation::Delete,
+                None,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn get_ownership(
+        &self,
+        object: &OwnershipObject,
+    ) -> Result<Option<OwnershipInfo>, ErrorCode> {
+        let key = self.make_object_owner_key(object)?;
+        match self.kv_api.get_kv(&key).await? {
+            None => Ok(None),
+            Some(val) => {
+                let info = serde_json::from_slice::<OwnershipInfo>(&val.data)
+                    .map_err_to_code(ErrorCode::IllegalUserInfoFormat, || "")?;
+                Ok(Some(info))
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_ownerships(&self) -> Result<Vec<SeqV<OwnershipInfo>>, ErrorCode> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&format!("{}/", self.owner_prefix))
+            .await?;
+
+        let mut r = vec![];
+        for (_key, val) in values {
+            let info = serde_json::from_slice::<OwnershipInfo>(&val.data)
+                .map_err_to_code(ErrorCode::IllegalUserInfoFormat, || "")?;
+            r.push(SeqV::new(val.seq, info));
+        }
+
+        Ok(r)
+    }
 }
@@ -0,0 +1,49 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::tenant::AuditChainHead;
+use common_meta_embedded::MetaEmbedded;
+
+fn head(seq: u64, hash: &str) -> AuditChainHead {
+    AuditChainHead {
+        seq,
+        hash: hash.to_string(),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_audit_chain_head() -> Result<()> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = AuditChainMgr::create(test_api.clone(), "databend_query", "127.0.0.1:9090")?;
+    assert_eq!(mgr.get_head().await?, None);
+
+    mgr.set_head(&head(2, "h2")).await?;
+    mgr.set_head(&head(5, "h5")).await?;
+    assert_eq!(mgr.get_head().await?, Some(head(5, "h5")));
+
+    // The head never moves backward.
+    mgr.set_head(&head(3, "h3")).await?;
+    assert_eq!(mgr.get_head().await?, Some(head(5, "h5")));
+
+    // The chains of other nodes are kept apart.
+    let other = AuditChainMgr::create(test_api, "databend_query", "127.0.0.2:9090")?;
+    assert_eq!(other.get_head().await?, None);
+
+    Ok(())
+}
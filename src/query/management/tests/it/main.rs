@@ -14,7 +14,9 @@
 
 #![allow(clippy::uninlined_format_args)]
 
+mod audit_chain;
 mod cluster;
+mod resource_usage;
 mod setting;
 mod stage;
 mod udf;
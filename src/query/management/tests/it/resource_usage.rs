@@ -0,0 +1,110 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::tenant::ResourceUsage;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+
+fn today() -> i32 {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    (now.as_secs() / (24 * 3600)) as i32
+}
+
+fn usage(user: &str, role: &str, cpu_time_ms: u64, scan_bytes: u64) -> ResourceUsage {
+    ResourceUsage {
+        usage_date: today(),
+        user: user.to_string(),
+        role: role.to_string(),
+        warehouse: "wh1".to_string(),
+        query_count: 1,
+        cpu_time_ms,
+        scan_bytes,
+        spill_bytes: 0,
+        result_bytes: 10,
+        written_bytes: 0,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_resource_usage() -> Result<()> {
+    let (kv_api, mgr) = new_resource_usage_api().await?;
+
+    mgr.add_usage(&usage("u1", "r1", 100, 1000)).await?;
+    mgr.add_usage(&usage("u1", "r1", 50, 500)).await?;
+    mgr.add_usage(&usage("u1", "r2", 1, 1)).await?;
+
+    let value = kv_api
+        .get_kv(&format!(
+            "__fd_resource_usage/databend_query/{}/u1/r1/wh1",
+            today()
+        ))
+        .await?
+        .unwrap();
+    assert_eq!(value.seq, 2);
+    // Kept for 400 days.
+    assert_eq!(
+        value.meta.and_then(|m| m.expire_at),
+        Some((today() as u64 + 400) * 24 * 3600)
+    );
+
+    let usages = mgr.get_usages().await?;
+    assert_eq!(usages.len(), 2);
+    assert_eq!(usages[0], ResourceUsage {
+        query_count: 2,
+        cpu_time_ms: 150,
+        scan_bytes: 1500,
+        result_bytes: 20,
+        ..usage("u1", "r1", 0, 0)
+    });
+    assert_eq!(usages[1], usage("u1", "r2", 1, 1));
+
+    // Usages of other tenants are not listed.
+    let other = ResourceUsageMgr::create(kv_api.clone(), "databend_query2")?;
+    other.add_usage(&usage("u1", "r1", 1, 1)).await?;
+    assert_eq!(mgr.get_usages().await?.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_resource_usage_retention() -> Result<()> {
+    let (_, mgr) = new_resource_usage_api().await?;
+
+    // The usage of a day beyond the retention expires.
+    mgr.add_usage(&ResourceUsage {
+        usage_date: today() - 401,
+        ..usage("u1", "r1", 1, 1)
+    })
+    .await?;
+    mgr.add_usage(&usage("u1", "r1", 1, 1)).await?;
+
+    let usages = mgr.get_usages().await?;
+    assert_eq!(usages.len(), 1);
+    assert_eq!(usages[0].usage_date, today());
+
+    Ok(())
+}
+
+async fn new_resource_usage_api() -> Result<(Arc<MetaEmbedded>, ResourceUsageMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = ResourceUsageMgr::create(test_api.clone(), "databend_query")?;
+    Ok((test_api, mgr))
+}
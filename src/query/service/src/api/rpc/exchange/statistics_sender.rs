@@ -22,6 +22,7 @@ use common_base::runtime::TrySpawn;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::tenant::ResourceUsage;
 use futures_util::future::Either;
 use log::warn;
 
@@ -29,6 +30,7 @@ use crate::api::rpc::flight_client::FlightExchange;
 use crate::api::rpc::flight_client::FlightSender;
 use crate::api::rpc::packets::ProgressInfo;
 use crate::api::DataPacket;
+use crate::interpreters::InterpreterResourceUsage;
 use crate::sessions::QueryContext;
 
 pub struct StatisticsSender {
@@ -147,6 +149,12 @@ impl StatisticsSender {
             progress_info.push(ProgressInfo::ResultProgress(result_progress_values));
         }
 
+        // Only the usage not sent yet.
+        let usage = ctx.account_resource_usage(InterpreterResourceUsage::local_usage(ctx));
+        if usage != ResourceUsage::default() {
+            progress_info.push(ProgressInfo::ResourceUsage(usage));
+        }
+
         Ok(progress_info)
     }
 }
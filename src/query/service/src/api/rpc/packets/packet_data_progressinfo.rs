@@ -23,6 +23,7 @@ use byteorder::WriteBytesExt;
 use common_base::base::ProgressValues;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::tenant::ResourceUsage;
 
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -33,6 +34,8 @@ pub enum ProgressInfo {
     ScanProgress(ProgressValues),
     WriteProgress(ProgressValues),
     ResultProgress(ProgressValues),
+    // The resources consumed by the fragments of the query on a node, only the counters are sent.
+    ResourceUsage(ResourceUsage),
 }
 
 impl ProgressInfo {
@@ -41,6 +44,7 @@ impl ProgressInfo {
             ProgressInfo::ScanProgress(values) => ctx.get_scan_progress().incr(values),
             ProgressInfo::WriteProgress(values) => ctx.get_write_progress().incr(values),
             ProgressInfo::ResultProgress(values) => ctx.get_result_progress().incr(values),
+            ProgressInfo::ResourceUsage(usage) => ctx.add_remote_resource_usage(usage),
         };
     }
 
@@ -49,6 +53,14 @@ impl ProgressInfo {
             ProgressInfo::ScanProgress(values) => (1_u8, values),
             ProgressInfo::WriteProgress(values) => (2_u8, values),
             ProgressInfo::ResultProgress(values) => (3_u8, values),
+            ProgressInfo::ResourceUsage(usage) => {
+                bytes.write_u8(4_u8)?;
+                bytes.write_u64::<BigEndian>(usage.cpu_time_ms)?;
+                bytes.write_u64::<BigEndian>(usage.scan_bytes)?;
+                bytes.write_u64::<BigEndian>(usage.spill_bytes)?;
+                bytes.write_u64::<BigEndian>(usage.written_bytes)?;
+                return Ok(());
+            }
         };
 
         bytes.write_u8(info_type)?;
@@ -59,6 +71,16 @@ impl ProgressInfo {
 
     pub fn read<T: Read>(bytes: &mut T) -> Result<ProgressInfo> {
         let info_type = bytes.read_u8()?;
+        if info_type == 4 {
+            return Ok(ProgressInfo::ResourceUsage(ResourceUsage {
+                cpu_time_ms: bytes.read_u64::<BigEndian>()?,
+                scan_bytes: bytes.read_u64::<BigEndian>()?,
+                spill_bytes: bytes.read_u64::<BigEndian>()?,
+                written_bytes: bytes.read_u64::<BigEndian>()?,
+                ..Default::default()
+            }));
+        }
+
         let rows = bytes.read_u64::<BigEndian>()? as usize;
         let bytes = bytes.read_u64::<BigEndian>()? as usize;
 
@@ -21,13 +21,18 @@ use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::UserIdentity;
 use common_meta_app::principal::UserInfo;
+use common_users::verify_key_pair_token;
 use common_users::JwtAuthenticator;
+use common_users::OidcAuthenticator;
+use common_users::UnverifiedClaims;
 use common_users::UserApiProvider;
 
+use crate::interpreters::InterpreterAuditLog;
 use crate::sessions::Session;
 
 pub struct AuthMgr {
     jwt_auth: Option<JwtAuthenticator>,
+    oidc_auth: Option<OidcAuthenticator>,
 }
 
 pub enum Credential {
@@ -44,7 +49,7 @@ pub enum Credential {
 
 impl AuthMgr {
     pub fn init(cfg: &InnerConfig) -> Result<()> {
-        GlobalInstance::set(AuthMgr::create(cfg));
+        GlobalInstance::set(AuthMgr::create(cfg)?);
         Ok(())
     }
 
@@ -52,23 +57,149 @@ impl AuthMgr {
         GlobalInstance::get()
     }
 
-    fn create(cfg: &InnerConfig) -> Arc<AuthMgr> {
-        Arc::new(AuthMgr {
+    fn create(cfg: &InnerConfig) -> Result<Arc<AuthMgr>> {
+        Ok(Arc::new(AuthMgr {
             jwt_auth: JwtAuthenticator::create(
                 cfg.query.jwt_key_file.clone(),
                 cfg.query.jwt_key_files.clone(),
             ),
-        })
+            oidc_auth: OidcAuthenticator::create(
+                cfg.query.oidc_jwks_url.clone(),
+                cfg.query.oidc_issuer.clone(),
+                cfg.query.oidc_audience.clone(),
+                cfg.query.oidc_user_claim.clone(),
+                cfg.query.oidc_role_claim.clone(),
+            )?,
+        }))
     }
 
+    /// Get the user and check the client ip by the network policy of the user,
+    /// the denials are written to the audit log.
+    #[async_backtrace::framed]
+    pub async fn get_user_with_client_ip(
+        tenant: &str,
+        identity: UserIdentity,
+        client_ip: Option<&str>,
+    ) -> Result<UserInfo> {
+        let user_api = UserApiProvider::instance();
+        let user = user_api.get_user(tenant, identity).await?;
+        if let Err(e) = user_api
+            .check_network_policy(tenant, &user, client_ip)
+            .await
+        {
+            InterpreterAuditLog::log_network_policy_denied(
+                tenant,
+                &user.identity().to_string(),
+                user.option
+                    .network_policy()
+                    .map_or("", |name| name.as_str()),
+                client_ip,
+                &e,
+            );
+            return Err(e);
+        }
+        Ok(user)
+    }
+
+    /// Verify the token of a key-pair or OIDC user, which is presented as the password
+    /// by the MySQL and FlightSQL clients, returns the role mapped from the token if any.
+    #[async_backtrace::framed]
+    pub async fn verify_user_token(&self, user: &UserInfo, token: &str) -> Result<Option<String>> {
+        match &user.auth_info {
+            AuthInfo::KeyPair { .. } => {
+                verify_key_pair_token(token, user)?;
+                Ok(None)
+            }
+            AuthInfo::OIDC => {
+                let oidc_auth = self
+                    .oidc_auth
+                    .as_ref()
+                    .ok_or_else(|| ErrorCode::AuthenticateFailure("oidc auth not configured."))?;
+                let identity = oidc_auth.parse_token(token).await?;
+                if identity.user_name != user.name {
+                    return Err(ErrorCode::AuthenticateFailure(format!(
+                        "oidc token is not issued to user {}",
+                        user.identity()
+                    )));
+                }
+                Ok(identity.role)
+            }
+            _ => Err(ErrorCode::AuthenticateFailure("wrong auth type")),
+        }
+    }
+
+    /// Authenticate the session by the credential, the result is written to the audit log.
     #[async_backtrace::framed]
     pub async fn auth(&self, session: Arc<Session>, credential: &Credential) -> Result<()> {
+        let res = self.do_auth(session.clone(), credential).await;
+
+        let (user, client_ip) = match credential {
+            Credential::Jwt { client_ip, .. } => {
+                let user = session
+                    .get_current_user()
+                    .map(|user| user.identity().to_string())
+                    .unwrap_or_default();
+                (user, client_ip)
+            }
+            Credential::Password {
+                name, client_ip, ..
+            } => (UserIdentity::new(name, "%").to_string(), client_ip),
+        };
+        InterpreterAuditLog::log_login(
+            &session.get_current_tenant(),
+            &user,
+            client_ip.as_deref(),
+            res.as_ref().err(),
+        );
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn do_auth(&self, session: Arc<Session>, credential: &Credential) -> Result<()> {
         let user_api = UserApiProvider::instance();
         match credential {
             Credential::Jwt {
                 token: t,
                 client_ip,
             } => {
+                // The tokens of OIDC and key-pair users are verified by their own keys,
+                // others are verified by the jwt key stores in the config.
+                let claims = UnverifiedClaims::decode(t).unwrap_or_default();
+                if let Some(oidc_auth) = &self.oidc_auth {
+                    if oidc_auth.is_issued_by(&claims) {
+                        let identity = oidc_auth.parse_token(t).await?;
+                        let tenant = session.get_current_tenant();
+                        let user = Self::get_user_with_client_ip(
+                            &tenant,
+                            UserIdentity::new(&identity.user_name, "%"),
+                            client_ip.as_deref(),
+                        )
+                        .await?;
+                        if user.auth_info != AuthInfo::OIDC {
+                            return Err(ErrorCode::AuthenticateFailure("wrong auth type"));
+                        }
+                        session.set_authed_user(user, identity.role).await?;
+                        return Ok(());
+                    }
+                }
+                if let Some(user_name) = &claims.subject {
+                    let tenant = session.get_current_tenant();
+                    let identity = UserIdentity::new(user_name, "%");
+                    if let Ok(user) = user_api.get_user(&tenant, identity.clone()).await {
+                        if let AuthInfo::KeyPair { .. } = user.auth_info {
+                            let user = Self::get_user_with_client_ip(
+                                &tenant,
+                                identity,
+                                client_ip.as_deref(),
+                            )
+                            .await?;
+                            verify_key_pair_token(t, &user)?;
+                            session.set_authed_user(user, None).await?;
+                            return Ok(());
+                        }
+                    }
+                }
+
                 let jwt_auth = self
                     .jwt_auth
                     .as_ref()
@@ -89,9 +220,12 @@ impl AuthMgr {
                 let identity = UserIdentity::new(&user_name, "%");
 
                 // create a new user for this identity if not exists
-                let user = match user_api
-                    .get_user_with_client_ip(&tenant, identity.clone(), client_ip.as_deref())
-                    .await
+                let user = match Self::get_user_with_client_ip(
+                    &tenant,
+                    identity.clone(),
+                    client_ip.as_deref(),
+                )
+                .await
                 {
                     Ok(user_info) => match user_info.auth_info {
                         AuthInfo::JWT => user_info,
@@ -130,19 +264,30 @@ impl AuthMgr {
             } => {
                 let tenant = session.get_current_tenant();
                 let identity = UserIdentity::new(n, "%");
-                let user = user_api
-                    .get_user_with_client_ip(&tenant, identity, client_ip.as_deref())
-                    .await?;
-                let user = match &user.auth_info {
-                    AuthInfo::None => user,
+                let user =
+                    Self::get_user_with_client_ip(&tenant, identity, client_ip.as_deref()).await?;
+                let (user, role) = match &user.auth_info {
+                    AuthInfo::None => (user, None),
+                    AuthInfo::KeyPair { .. } | AuthInfo::OIDC => match p {
+                        None => return Err(ErrorCode::AuthenticateFailure("token required")),
+                        Some(p) => {
+                            let token = String::from_utf8_lossy(p);
+                            let role = self.verify_user_token(&user, &token).await?;
+                            (user, role)
+                        }
+                    },
                     AuthInfo::Password {
                         hash_value: h,
                         hash_method: t,
                     } => match p {
                         None => return Err(ErrorCode::AuthenticateFailure("password required")),
                         Some(p) => {
-                            if *h == t.hash(p) {
-                                user
+                            let authed = *h == t.hash(p);
+                            user_api
+                                .check_login_password(&tenant, &user, authed)
+                                .await?;
+                            if authed {
+                                (user, None)
                             } else {
                                 return Err(ErrorCode::AuthenticateFailure("wrong password"));
                             }
@@ -150,7 +295,7 @@ impl AuthMgr {
                     },
                     _ => return Err(ErrorCode::AuthenticateFailure("wrong auth type")),
                 };
-                session.set_authed_user(user, None).await?;
+                session.set_authed_user(user, role).await?;
             }
         };
         Ok(())
@@ -30,6 +30,7 @@ use common_storage::DataOperator;
 #[cfg(feature = "hive")]
 use common_storages_hive::HiveCatalog;
 use common_storages_iceberg::IcebergCatalog;
+use common_storages_rdbms::RdbmsCatalog;
 use dashmap::DashMap;
 
 use crate::catalogs::DatabaseCatalog;
@@ -149,6 +150,14 @@ impl CatalogManagerHelper for CatalogManager {
                 let if_not_exists = req.if_not_exists;
                 self.insert_catalog(ctl_name, catalog, if_not_exists)
             }
+            CatalogOption::Rdbms(opt) => {
+                let ctl_name = &req.name_ident.catalog_name;
+                let catalog: Arc<dyn Catalog> =
+                    Arc::new(RdbmsCatalog::try_create(ctl_name, &opt.url)?);
+
+                let if_not_exists = req.if_not_exists;
+                self.insert_catalog(ctl_name, catalog, if_not_exists)
+            }
         }
     }
 
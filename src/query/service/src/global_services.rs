@@ -22,10 +22,14 @@ use common_exception::Result;
 use common_profile::QueryProfileManager;
 use common_sharing::ShareEndpointManager;
 use common_storage::DataOperator;
+use common_storage::MasterKeys;
 use common_storage::ShareTableConfig;
 use common_tracing::GlobalLogger;
+use common_users::AuditChainManager;
+use common_users::ResourceUsageManager;
 use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
+use common_users::WorkloadGroupManager;
 use storages_common_cache_manager::CacheManager;
 
 use crate::api::DataExchangeManager;
@@ -65,6 +69,11 @@ impl GlobalServices {
             &config.query.share_endpoint_auth_token_file,
             config.query.tenant_id.clone(),
         )?;
+        MasterKeys::init(
+            &config.query.fuse_encryption_master_key_id,
+            &config.query.fuse_encryption_master_keys,
+            &config.query.fuse_encryption_kms_key_dir,
+        )?;
 
         CacheManager::init(&config.cache, &config.query.tenant_id)?;
         CatalogManager::init(&config).await?;
@@ -80,6 +89,14 @@ impl GlobalServices {
         )
         .await?;
         RoleCacheManager::init()?;
+        WorkloadGroupManager::init()?;
+        ResourceUsageManager::init()?;
+        AuditChainManager::init(
+            config.query.tenant_id.as_str(),
+            &config.query.flight_api_address,
+            &config.query.audit_log_hmac_key,
+        )
+        .await?;
         ShareEndpointManager::init()?;
         QueryProfileManager::init();
 
@@ -93,6 +93,7 @@ impl AccessChecker for ManagementModeAccess {
 
                 // Privilege.
                 | Plan::GrantPriv(_)
+                | Plan::GrantOwnership(_)
                 | Plan::RevokePriv(_)
                 | Plan::GrantRole(_)
                 | Plan::RevokeRole(_)
@@ -103,6 +104,15 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreateNetworkPolicy(_)
                 | Plan::AlterNetworkPolicy(_)
                 | Plan::DropNetworkPolicy(_)
+                // Password policy.
+                | Plan::CreatePasswordPolicy(_)
+                | Plan::AlterPasswordPolicy(_)
+                | Plan::DropPasswordPolicy(_)
+                // Workload group.
+                | Plan::CreateWorkloadGroup(_)
+                | Plan::AlterWorkloadGroup(_)
+                | Plan::DropWorkloadGroup(_)
+                | Plan::AlterRoleWorkloadGroup(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
@@ -12,20 +12,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::OwnershipObject;
 use common_meta_app::principal::UserGrantSet;
 use common_meta_app::principal::UserPrivilegeType;
+use common_sql::optimizer::SExpr;
 use common_sql::plans::CopyPlan;
+use common_sql::plans::InsertInputSource;
+use common_sql::plans::RelOperator;
 use common_sql::plans::RewriteKind;
+use common_sql::ColumnEntry;
+use common_sql::ColumnSet;
+use common_sql::IndexType;
+use common_sql::Metadata;
 use common_users::RoleCacheManager;
 
 use crate::interpreters::access::AccessChecker;
 use crate::sessions::QueryContext;
+use crate::sessions::Session;
 use crate::sql::plans::Plan;
 
 pub struct PrivilegeAccess {
@@ -36,6 +47,73 @@ impl PrivilegeAccess {
     pub fn create(ctx: Arc<QueryContext>) -> Box<dyn AccessChecker> {
         Box::new(PrivilegeAccess { ctx })
     }
+
+    // The owner of an object holds all the privileges on it, the ownership is checked if the
+    // privilege is not granted.
+    #[async_backtrace::framed]
+    async fn validate_privilege_or_ownership(
+        &self,
+        object: &GrantObject,
+        privilege: UserPrivilegeType,
+        owned_objects: Vec<OwnershipObject>,
+    ) -> Result<()> {
+        let session = self.ctx.get_current_session();
+        match session.validate_privilege(object, vec![privilege]).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                for owned_object in owned_objects.iter() {
+                    if session.has_ownership(owned_object).await? {
+                        return Ok(());
+                    }
+                }
+                Err(e)
+            }
+        }
+    }
+}
+
+// The object and the database it belongs to.
+fn ownership_objects_of(object: &OwnershipObject) -> Vec<OwnershipObject> {
+    let mut objects = vec![object.clone()];
+    if let OwnershipObject::Table {
+        catalog_name,
+        db_id,
+        ..
+    } = object
+    {
+        objects.push(OwnershipObject::Database {
+            catalog_name: catalog_name.clone(),
+            db_id: *db_id,
+        });
+    }
+    objects
+}
+
+// The columns read by the scans of the query, grouped by the table index.
+fn collect_scan_columns(s_expr: &SExpr, scan_columns: &mut HashMap<IndexType, ColumnSet>) {
+    if let RelOperator::Scan(scan) = s_expr.plan() {
+        scan_columns
+            .entry(scan.table_index)
+            .or_insert_with(ColumnSet::new)
+            .extend(scan.columns.iter().cloned());
+    }
+    for child in s_expr.children() {
+        collect_scan_columns(child, scan_columns);
+    }
+}
+
+// The names of the table columns, the inner fields of a tuple column are resolved to the column.
+fn base_column_names(metadata: &Metadata, columns: &ColumnSet) -> BTreeSet<String> {
+    columns
+        .iter()
+        .filter_map(|index| match metadata.column(*index) {
+            ColumnEntry::BaseTableColumn(column) => match column.path_indices {
+                Some(_) => column.column_name.split(':').next().map(|s| s.to_string()),
+                None => Some(column.column_name.clone()),
+            },
+            _ => None,
+        })
+        .collect()
 }
 
 #[async_trait::async_trait]
@@ -49,6 +127,7 @@ impl AccessChecker for PrivilegeAccess {
 
         match plan {
             Plan::Query {
+                s_expr,
                 metadata,
                 rewrite_kind,
                 ..
@@ -85,18 +164,27 @@ impl AccessChecker for PrivilegeAccess {
                     _ => {}
                 };
                 let metadata = metadata.read().clone();
+                let mut scan_columns = HashMap::new();
+                collect_scan_columns(s_expr, &mut scan_columns);
                 for table in metadata.tables() {
                     if table.is_source_of_view() {
                         continue;
                     }
+                    // The scans removed by rewrites, like folding `COUNT(c)`, are kept in metadata.
+                    let mut columns = scan_columns.remove(&table.index()).unwrap_or_default();
+                    if let Some(pruned_columns) = metadata.scan_columns(table.index()) {
+                        columns.extend(pruned_columns.iter().cloned());
+                    }
+                    let columns = base_column_names(&metadata, &columns);
                     session
-                        .validate_privilege(
+                        .validate_column_privilege(
                             &GrantObject::Table(
                                 table.catalog().to_string(),
                                 table.database().to_string(),
                                 table.name().to_string(),
                             ),
-                            vec![UserPrivilegeType::Select],
+                            &columns,
+                            UserPrivilegeType::Select,
                         )
                         .await?
                 }
@@ -117,10 +205,66 @@ impl AccessChecker for PrivilegeAccess {
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Create])
                     .await?;
             }
-            Plan::DropDatabase(_)
-            | Plan::UndropDatabase(_)
-            | Plan::DropUDF(_)
-            | Plan::DropIndex(_) => {
+            Plan::DropDatabase(plan) => {
+                let object = GrantObject::Database(plan.catalog.clone(), plan.database.clone());
+                let owned_objects = Session::resolve_ownership_objects(&tenant, &object).await;
+                self.validate_privilege_or_ownership(
+                    &GrantObject::Global,
+                    UserPrivilegeType::Drop,
+                    owned_objects,
+                )
+                .await?;
+            }
+            Plan::DropUDF(plan) => {
+                let owned_object = OwnershipObject::UDF {
+                    name: plan.name.clone(),
+                };
+                self.validate_privilege_or_ownership(
+                    &GrantObject::Global,
+                    UserPrivilegeType::Drop,
+                    vec![owned_object],
+                )
+                .await?;
+            }
+            Plan::AlterUDF(plan) => {
+                let owned_object = OwnershipObject::UDF {
+                    name: plan.udf.name.clone(),
+                };
+                self.validate_privilege_or_ownership(
+                    &GrantObject::Global,
+                    UserPrivilegeType::Alter,
+                    vec![owned_object],
+                )
+                .await?;
+            }
+            Plan::DropStage(plan) => {
+                let owned_object = OwnershipObject::Stage {
+                    name: plan.name.clone(),
+                };
+                self.validate_privilege_or_ownership(
+                    &GrantObject::Global,
+                    UserPrivilegeType::Super,
+                    vec![owned_object],
+                )
+                .await?;
+            }
+            Plan::GrantOwnership(plan) => {
+                self.validate_privilege_or_ownership(
+                    &GrantObject::Global,
+                    UserPrivilegeType::Grant,
+                    ownership_objects_of(&plan.object),
+                )
+                .await?;
+            }
+            Plan::ShowObjectGrantPrivileges(plan) => {
+                self.validate_privilege_or_ownership(
+                    &GrantObject::Global,
+                    UserPrivilegeType::Grant,
+                    ownership_objects_of(&plan.object),
+                )
+                .await?;
+            }
+            Plan::UndropDatabase(_) | Plan::DropIndex(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Drop])
                     .await?;
@@ -223,6 +367,10 @@ impl AccessChecker for PrivilegeAccess {
                         vec![UserPrivilegeType::Create],
                     )
                     .await?;
+                // The columns read by `CREATE TABLE ... AS SELECT` are checked like a query.
+                if let Some(select_plan) = &plan.as_select {
+                    self.check(select_plan).await?;
+                }
             }
             Plan::DropTable(plan) => {
                 session
@@ -312,6 +460,30 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::DropInvertedIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             Plan::DropTableColumn(plan) => {
                 session
                     .validate_privilege(
@@ -360,6 +532,18 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::DropTablePartition(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Delete],
+                    )
+                    .await?;
+            }
             Plan::TruncateTable(plan) => {
                 session
                     .validate_privilege(
@@ -428,6 +612,9 @@ impl AccessChecker for PrivilegeAccess {
                         vec![UserPrivilegeType::Insert],
                     )
                     .await?;
+                if let InsertInputSource::SelectPlan(select_plan) = &plan.source {
+                    self.check(select_plan).await?;
+                }
             }
             Plan::Replace(plan) => {
                 session
@@ -440,6 +627,9 @@ impl AccessChecker for PrivilegeAccess {
                         vec![UserPrivilegeType::Insert, UserPrivilegeType::Delete],
                     )
                     .await?;
+                if let InsertInputSource::SelectPlan(select_plan) = &plan.source {
+                    self.check(select_plan).await?;
+                }
             }
             Plan::Delete(plan) => {
                 session
@@ -512,7 +702,6 @@ impl AccessChecker for PrivilegeAccess {
             Plan::GrantShareObject(_)
             | Plan::RevokeShareObject(_)
             | Plan::AlterShareTenants(_)
-            | Plan::ShowObjectGrantPrivileges(_)
             | Plan::ShowGrantTenantsOfShare(_)
             | Plan::SetRole(_)
             | Plan::ShowGrants(_)
@@ -531,7 +720,6 @@ impl AccessChecker for PrivilegeAccess {
                     .await?;
             }
             Plan::AlterUser(_)
-            | Plan::AlterUDF(_)
             | Plan::RenameDatabase(_)
             | Plan::RevertTable(_)
             | Plan::RefreshIndex(_) => {
@@ -571,7 +759,6 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::CreateCatalog(_)
             | Plan::DropCatalog(_)
             | Plan::CreateStage(_)
-            | Plan::DropStage(_)
             | Plan::RemoveStage(_)
             | Plan::CreateFileFormat(_)
             | Plan::DropFileFormat(_)
@@ -580,7 +767,21 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterNetworkPolicy(_)
             | Plan::DropNetworkPolicy(_)
             | Plan::DescNetworkPolicy(_)
-            | Plan::ShowNetworkPolicies(_) => {
+            | Plan::ShowNetworkPolicies(_)
+            | Plan::CreatePasswordPolicy(_)
+            | Plan::AlterPasswordPolicy(_)
+            | Plan::DropPasswordPolicy(_)
+            | Plan::DescPasswordPolicy(_)
+            | Plan::ShowPasswordPolicies(_)
+            | Plan::CreateRowAccessPolicy(_)
+            | Plan::DropRowAccessPolicy(_)
+            | Plan::DescRowAccessPolicy(_)
+            | Plan::AddTableRowAccessPolicy(_)
+            | Plan::DropTableRowAccessPolicy(_)
+            | Plan::CreateWorkloadGroup(_)
+            | Plan::AlterWorkloadGroup(_)
+            | Plan::DropWorkloadGroup(_)
+            | Plan::AlterRoleWorkloadGroup(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
@@ -12,14 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::OwnershipObject;
+use common_users::UserApiProvider;
+use common_users::BUILTIN_ROLE_PUBLIC;
 
 use crate::procedures::ProcedureFactory;
 use crate::sessions::QueryContext;
+use crate::sessions::SessionType;
 
 #[async_backtrace::framed]
 pub async fn validate_grant_object_exists(
@@ -65,3 +70,61 @@ pub async fn validate_grant_object_exists(
 
     Ok(())
 }
+
+/// Check the columns of a column-level GRANT exist in the table.
+#[async_backtrace::framed]
+pub async fn validate_grant_columns_exist(
+    ctx: &Arc<QueryContext>,
+    object: &GrantObject,
+    columns: &BTreeSet<String>,
+) -> Result<()> {
+    if columns.is_empty() {
+        return Ok(());
+    }
+    match object {
+        GrantObject::Table(catalog_name, database_name, table_name) => {
+            let table = ctx
+                .get_table(catalog_name, database_name, table_name)
+                .await?;
+            let schema = table.schema();
+            for column in columns {
+                if schema.field_with_name(column).is_err() {
+                    return Err(common_exception::ErrorCode::UnknownColumn(format!(
+                        "column {} not exists in table {}.{}",
+                        column, database_name, table_name,
+                    )));
+                }
+            }
+            Ok(())
+        }
+        _ => Err(common_exception::ErrorCode::IllegalGrant(
+            "Column privileges can only be granted on a table",
+        )),
+    }
+}
+
+/// Make the current role of the session the owner of a newly created object.
+///
+/// Objects created with the PUBLIC role have no owner, or every role would own them, as PUBLIC
+/// is granted to all the roles.
+#[async_backtrace::framed]
+pub async fn grant_ownership_to_current_role(
+    ctx: &Arc<QueryContext>,
+    object: &OwnershipObject,
+) -> Result<()> {
+    let session = ctx.get_current_session();
+    if matches!(session.get_type(), SessionType::Local) {
+        return Ok(());
+    }
+
+    session.ensure_current_role().await?;
+    if let Some(role) = session
+        .get_current_role()
+        .filter(|r| r.name != BUILTIN_ROLE_PUBLIC)
+    {
+        UserApiProvider::instance()
+            .grant_ownership_to_role(&ctx.get_tenant(), object, &role.name)
+            .await?;
+    }
+    Ok(())
+}
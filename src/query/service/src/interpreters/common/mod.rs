@@ -14,11 +14,19 @@
 
 mod grant;
 mod metrics;
+mod quota;
 mod table;
 mod util;
 
+pub use grant::grant_ownership_to_current_role;
+pub use grant::validate_grant_columns_exist;
 pub use grant::validate_grant_object_exists;
+pub use quota::check_user_storage_quota;
 pub use table::check_referenced_computed_columns;
+pub use table::check_referenced_constraints;
+pub use table::check_referenced_partition_key;
+pub use table::check_referenced_row_access_policy;
+pub use table::check_referenced_ttl;
 pub use util::check_deduplicate_label;
 
 pub use self::metrics::*;
@@ -0,0 +1,90 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use storages_common_table_meta::table::OPT_KEY_CREATED_BY;
+
+use crate::sessions::QueryContext;
+
+// How long the storage usage of a user is cached, it takes all the tables to compute the usage.
+const STORAGE_USAGE_CACHE_TTL: Duration = Duration::from_secs(30);
+
+// Storage usage of the users, key is (tenant, user identity), value is (computed at, used bytes).
+static STORAGE_USAGE_CACHE: Lazy<Mutex<HashMap<(String, String), (Instant, u64)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Check the tables created by the current user don't exceed its storage quota before writing.
+///
+/// The usage is the compressed size of data and index in the latest snapshot of each table,
+/// data only kept for time travel isn't counted. It's cached for `STORAGE_USAGE_CACHE_TTL`,
+/// so the quota may be exceeded by the writes within that period.
+#[async_backtrace::framed]
+pub async fn check_user_storage_quota(ctx: &Arc<QueryContext>) -> Result<()> {
+    let user = ctx.get_current_user()?;
+    let max_storage = user.quota.max_storage_in_bytes;
+    if max_storage == 0 {
+        return Ok(());
+    }
+
+    let identity = user.identity().to_string();
+    let key = (ctx.get_tenant(), identity.clone());
+    let cached = STORAGE_USAGE_CACHE
+        .lock()
+        .get(&key)
+        .filter(|(computed_at, _)| computed_at.elapsed() < STORAGE_USAGE_CACHE_TTL)
+        .map(|(_, used)| *used);
+    let used = match cached {
+        Some(used) => used,
+        None => {
+            let used = get_user_storage_usage(ctx, &identity).await?;
+            STORAGE_USAGE_CACHE
+                .lock()
+                .insert(key, (Instant::now(), used));
+            used
+        }
+    };
+
+    if used >= max_storage {
+        return Err(ErrorCode::UserStorageQuotaExceeded(format!(
+            "Storage quota of user {} exceeded: tables created by the user use {} bytes, quota is {} bytes",
+            identity, used, max_storage
+        )));
+    }
+    Ok(())
+}
+
+#[async_backtrace::framed]
+async fn get_user_storage_usage(ctx: &Arc<QueryContext>, identity: &str) -> Result<u64> {
+    let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+    let mut used = 0;
+    for database in catalog.list_databases(&ctx.get_tenant()).await? {
+        for table in database.list_tables().await? {
+            if table.options().get(OPT_KEY_CREATED_BY).map(|v| v.as_str()) == Some(identity) {
+                let statistics = &table.get_table_info().meta.statistics;
+                used += statistics.compressed_data_bytes + statistics.index_data_bytes;
+            }
+        }
+    }
+    Ok(used)
+}
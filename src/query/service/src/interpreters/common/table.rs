@@ -14,12 +14,18 @@
 
 use std::sync::Arc;
 
+use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::ComputedExpr;
 use common_expression::DataSchemaRef;
 use common_sql::parse_computed_expr;
+use common_sql::parse_exprs;
+use storages_common_table_meta::table::TableConstraints;
+use storages_common_table_meta::table::TableRowAccessPolicy;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
+use storages_common_table_meta::table::OPT_KEY_TTL;
 
 pub fn check_referenced_computed_columns(
     ctx: Arc<dyn TableContext>,
@@ -55,3 +61,76 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+pub fn check_referenced_partition_key(
+    ctx: Arc<dyn TableContext>,
+    table: Arc<dyn Table>,
+    column: &str,
+) -> Result<()> {
+    if let Some(partition_by) = table.options().get(OPT_KEY_PARTITION_BY) {
+        let schema = table.schema();
+        let exprs = parse_exprs(ctx, table.clone(), partition_by)?;
+        let referenced = exprs
+            .iter()
+            .flat_map(|expr| expr.column_refs().into_keys())
+            .any(|index| schema.field(index).name() == column);
+        if referenced {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "column `{}` is referenced by partition key `{}`",
+                column, partition_by
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub fn check_referenced_ttl(
+    ctx: Arc<dyn TableContext>,
+    table: Arc<dyn Table>,
+    column: &str,
+) -> Result<()> {
+    if let Some(ttl) = table.options().get(OPT_KEY_TTL) {
+        let schema = table.schema();
+        let exprs = parse_exprs(ctx, table.clone(), ttl)?;
+        let referenced = exprs
+            .iter()
+            .flat_map(|expr| expr.column_refs().into_keys())
+            .any(|index| schema.field(index).name() == column);
+        if referenced {
+            return Err(ErrorCode::InvalidTableTtl(format!(
+                "column `{}` is referenced by TTL `{}`",
+                column, ttl
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub fn check_referenced_constraints(table: Arc<dyn Table>, column: &str) -> Result<()> {
+    let constraints = TableConstraints::from_options(table.options())?;
+    if constraints.is_empty() {
+        return Ok(());
+    }
+    let column_id = table.schema().column_id_of(column)?;
+    if constraints.references(column_id) {
+        return Err(ErrorCode::InvalidTableConstraint(format!(
+            "column `{}` is referenced by a key constraint",
+            column
+        )));
+    }
+    Ok(())
+}
+
+pub fn check_referenced_row_access_policy(table: Arc<dyn Table>, column: &str) -> Result<()> {
+    let Some(policy) = TableRowAccessPolicy::from_options(table.options())? else {
+        return Ok(());
+    };
+    let column_id = table.schema().column_id_of(column)?;
+    if policy.references(column_id) {
+        return Err(ErrorCode::InvalidRowAccessPolicy(format!(
+            "column `{}` is referenced by row access policy '{}'",
+            column, policy.policy
+        )));
+    }
+    Ok(())
+}
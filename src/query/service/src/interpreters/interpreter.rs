@@ -21,10 +21,14 @@ use common_exception::Result;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_expression::SendableDataBlockStream;
+use common_tracing::AuditEvent;
 use log::error;
+use parking_lot::Mutex;
 
+use crate::interpreters::InterpreterAuditLog;
 use crate::interpreters::InterpreterMetrics;
 use crate::interpreters::InterpreterQueryLog;
+use crate::interpreters::InterpreterResourceUsage;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::executor::PipelinePullingExecutor;
@@ -59,7 +63,30 @@ pub trait Interpreter: Sync + Send {
             log_query_finished(&ctx, Some(err.clone()));
             return Err(err);
         }
-        let mut build_res = match self.execute2().await {
+
+        // Queries of a workload group wait in its queue until the group has room for them,
+        // nested interpreters of an admitted query share the permit of the outer one.
+        let workload_group_permit = if ctx.get_current_session().get_type().is_user_session()
+            && ctx.get_workload_group().is_none()
+        {
+            match ctx.acquire_workload_group().await {
+                Ok(permit) => permit,
+                Err(err) => {
+                    InterpreterMetrics::record_query_error(&ctx);
+                    log_query_finished(&ctx, Some(err.clone()));
+                    return Err(err);
+                }
+            }
+        } else {
+            None
+        };
+
+        let build_res = self.execute2().await;
+        if let Some(event) = self.audit_event() {
+            InterpreterAuditLog::log_query_event(&ctx, event, build_res.as_ref().err());
+        }
+
+        let mut build_res = match build_res {
             Ok(build_res) => build_res,
             Err(build_error) => {
                 InterpreterMetrics::record_query_error(&ctx);
@@ -76,7 +103,9 @@ pub trait Interpreter: Sync + Send {
         }
 
         let query_ctx = ctx.clone();
+        let workload_group_permit = Mutex::new(workload_group_permit);
         build_res.main_pipeline.set_on_finished(move |may_error| {
+            drop(workload_group_permit.lock().take());
             InterpreterMetrics::record_query_finished(&query_ctx, may_error.clone());
             log_query_finished(&query_ctx, may_error.clone());
 
@@ -89,9 +118,8 @@ pub trait Interpreter: Sync + Send {
         ctx.set_status_info("executing pipeline");
 
         let settings = ctx.get_settings();
-        let query_id = ctx.get_id();
         build_res.set_max_threads(settings.get_max_threads()? as usize);
-        let settings = ExecutorSettings::try_create(&settings, query_id)?;
+        let settings = ExecutorSettings::try_create(&ctx)?;
 
         if build_res.main_pipeline.is_complete_pipeline()? {
             let mut pipelines = build_res.sources_pipelines;
@@ -116,6 +144,12 @@ pub trait Interpreter: Sync + Send {
     /// The core of the databend processor which will execute the logical plan and build the pipeline
     async fn execute2(&self) -> Result<PipelineBuildResult>;
 
+    /// The security relevant event of the statement, it is written to the audit log
+    /// with the result of `execute2`.
+    fn audit_event(&self) -> Option<AuditEvent> {
+        None
+    }
+
     fn set_source_pipe_builder(&self, _builder: Option<SourcePipeBuilder>) -> Result<()> {
         Err(ErrorCode::Unimplemented(format!(
             "UnImplement set_source_pipe_builder method for {:?}",
@@ -151,4 +185,6 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>) {
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error) {
         error!("interpreter.finish.error: {:?}", error)
     }
+
+    InterpreterResourceUsage::record_query_finished(ctx, now);
 }
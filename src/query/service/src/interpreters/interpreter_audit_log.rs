@@ -0,0 +1,112 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_storages_system::AuditLogQueue;
+use common_tracing::write_audit_log;
+use common_tracing::AuditEvent;
+use common_tracing::AuditEventType;
+use common_users::AuditChainManager;
+use log::error;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct InterpreterAuditLog;
+
+impl InterpreterAuditLog {
+    /// Write the event to the audit log files and `system.audit_log`.
+    pub fn write_log(event: AuditEvent) {
+        let event = write_audit_log(event);
+        AuditChainManager::instance().notify();
+        if let Err(e) = AuditLogQueue::instance().and_then(|queue| queue.append_data(event)) {
+            error!("fail to write audit_log {:?}", e);
+        }
+    }
+
+    /// Log the event of a statement, with the session and the result of the statement.
+    pub fn log_query_event(ctx: &QueryContext, mut event: AuditEvent, err: Option<&ErrorCode>) {
+        event.tenant = ctx.get_tenant();
+        event.user = ctx
+            .get_current_user()
+            .map(|user| user.identity().to_string())
+            .unwrap_or_default();
+        event.client_address = client_address(ctx);
+        event.query_id = ctx.get_id();
+        Self::write_log(with_error(event, err));
+    }
+
+    pub fn log_login(tenant: &str, user: &str, client_ip: Option<&str>, err: Option<&ErrorCode>) {
+        let event = AuditEvent {
+            tenant: tenant.to_string(),
+            user: user.to_string(),
+            client_address: client_ip.unwrap_or_default().to_string(),
+            ..AuditEvent::new(AuditEventType::Login)
+        };
+        Self::write_log(with_error(event, err));
+    }
+
+    pub fn log_network_policy_denied(
+        tenant: &str,
+        user: &str,
+        network_policy: &str,
+        client_ip: Option<&str>,
+        err: &ErrorCode,
+    ) {
+        let event = AuditEvent {
+            tenant: tenant.to_string(),
+            user: user.to_string(),
+            client_address: client_ip.unwrap_or_default().to_string(),
+            object: format!("NETWORK POLICY {}", network_policy),
+            ..AuditEvent::new(AuditEventType::NetworkPolicyDenied)
+        };
+        Self::write_log(with_error(event, Some(err)));
+    }
+
+    /// Log a statement rejected by the access checker, `kind` is the kind of the statement.
+    pub fn log_access_denied(ctx: &QueryContext, kind: String, err: &ErrorCode) {
+        let event = AuditEvent {
+            detail: kind,
+            ..AuditEvent::new(AuditEventType::AccessDenied)
+        };
+        Self::log_query_event(ctx, event, Some(err));
+    }
+
+    pub fn log_connection_rejected(client_address: String, reason: String, message: String) {
+        let event = AuditEvent {
+            client_address,
+            detail: reason,
+            success: false,
+            error_message: message,
+            ..AuditEvent::new(AuditEventType::ConnectionRejected)
+        };
+        Self::write_log(event);
+    }
+}
+
+fn client_address(ctx: &QueryContext) -> String {
+    match ctx.get_client_address() {
+        Some(addr) => addr.ip().to_string(),
+        None => "".to_string(),
+    }
+}
+
+fn with_error(mut event: AuditEvent, err: Option<&ErrorCode>) -> AuditEvent {
+    if let Some(err) = err {
+        event.success = false;
+        event.error_code = err.code() as i32;
+        event.error_message = err.message();
+    }
+    event
+}
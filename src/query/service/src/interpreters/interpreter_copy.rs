@@ -41,6 +41,7 @@ use log::debug;
 use log::info;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_user_storage_quota;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::pipelines::builders::build_append2table_with_commit_pipeline;
@@ -472,6 +473,7 @@ impl Interpreter for CopyInterpreter {
 
         match &self.plan {
             CopyPlan::IntoTable(plan) => {
+                check_user_storage_quota(&self.ctx).await?;
                 if plan.enable_distributed {
                     let distributed_plan_op = self
                         .try_transform_copy_plan_from_local_to_distributed(plan)
@@ -18,6 +18,8 @@ use common_exception::Result;
 use common_license::license::Feature;
 use common_license::license_manager::get_license_manager;
 use common_sql::plans::CreateDatamaskPolicyPlan;
+use common_tracing::AuditEvent;
+use common_tracing::AuditEventType;
 use common_users::UserApiProvider;
 use data_mask_feature::get_datamask_handler;
 
@@ -43,6 +45,13 @@ impl Interpreter for CreateDataMaskInterpreter {
         "CreateDataMaskInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent {
+            object: format!("MASKING POLICY {}", self.plan.name),
+            ..AuditEvent::new(AuditEventType::CreateDataMask)
+        })
+    }
+
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let license_manager = get_license_manager();
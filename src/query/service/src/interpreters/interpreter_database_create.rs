@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::principal::OwnershipObject;
 use common_meta_app::share::ShareGrantObjectPrivilege;
 use common_meta_app::share::ShareNameIdent;
 use common_meta_types::MatchSeq;
@@ -24,6 +25,7 @@ use common_sql::plans::CreateDatabasePlan;
 use common_users::UserApiProvider;
 use log::debug;
 
+use crate::interpreters::common::grant_ownership_to_current_role;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -115,7 +117,19 @@ impl Interpreter for CreateDatabaseInterpreter {
             self.check_create_database_from_share(&tenant, share_name)
                 .await?;
         }
-        catalog.create_database(self.plan.clone().into()).await?;
+        let exists = databases
+            .iter()
+            .any(|db| db.name() == self.plan.database.as_str());
+        let reply = catalog.create_database(self.plan.clone().into()).await?;
+
+        // The owner of an existing database is kept by `CREATE DATABASE IF NOT EXISTS`.
+        if !exists {
+            let object = OwnershipObject::Database {
+                catalog_name: self.plan.catalog.clone(),
+                db_id: reply.db_id,
+            };
+            grant_ownership_to_current_role(&self.ctx, &object).await?;
+        }
 
         Ok(PipelineBuildResult::create())
     }
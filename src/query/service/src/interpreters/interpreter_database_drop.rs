@@ -15,8 +15,10 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_app::principal::OwnershipObject;
 use common_sql::plans::DropDatabasePlan;
 use common_storages_share::save_share_spec;
+use common_users::UserApiProvider;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -42,8 +44,32 @@ impl Interpreter for DropDatabaseInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
         let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+
+        // The database and its tables may have owners, collect them before they are dropped.
+        let mut owned_objects = vec![];
+        if let Ok(database) = catalog.get_database(&tenant, &self.plan.database).await {
+            let db_id = database.get_db_info().ident.db_id;
+            owned_objects.push(OwnershipObject::Database {
+                catalog_name: self.plan.catalog.clone(),
+                db_id,
+            });
+            for table in database.list_tables().await? {
+                owned_objects.push(OwnershipObject::Table {
+                    catalog_name: self.plan.catalog.clone(),
+                    db_id,
+                    table_id: table.get_id(),
+                });
+            }
+        }
+
         let resp = catalog.drop_database(self.plan.clone().into()).await?;
+
+        let user_mgr = UserApiProvider::instance();
+        for object in &owned_objects {
+            user_mgr.revoke_ownership(&tenant, object).await?;
+        }
         if let Some(spec_vec) = resp.spec_vec {
             let mut share_table_into = Vec::with_capacity(spec_vec.len());
             for share_spec in &spec_vec {
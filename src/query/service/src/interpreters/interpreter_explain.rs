@@ -294,7 +294,7 @@ impl ExplainInterpreter {
         let settings = self.ctx.get_settings();
         let query_id = self.ctx.get_id();
         build_res.set_max_threads(settings.get_max_threads()? as usize);
-        let settings = ExecutorSettings::try_create(&settings, query_id.clone())?;
+        let settings = ExecutorSettings::try_create(&self.ctx)?;
 
         // Drain the data
         if build_res.main_pipeline.is_complete_pipeline()? {
@@ -41,6 +41,7 @@ use crate::interpreters::CreateShareEndpointInterpreter;
 use crate::interpreters::CreateShareInterpreter;
 use crate::interpreters::DropShareInterpreter;
 use crate::interpreters::DropUserInterpreter;
+use crate::interpreters::InterpreterAuditLog;
 use crate::interpreters::SetRoleInterpreter;
 use crate::interpreters::UpdateInterpreter;
 use crate::sessions::QueryContext;
@@ -58,6 +59,7 @@ impl InterpreterFactory {
         let access_checker = Accessor::create(ctx.clone());
         access_checker.check(plan).await.map_err(|e| {
             error!("Access.denied(v2): {:?}", e);
+            InterpreterAuditLog::log_access_denied(&ctx, plan.to_string(), &e);
             e
         })?;
         Self::get_inner(ctx, plan)
@@ -198,6 +200,9 @@ impl InterpreterFactory {
             Plan::ReclusterTable(recluster_table) => Ok(Arc::new(
                 ReclusterTableInterpreter::try_create(ctx, *recluster_table.clone())?,
             )),
+            Plan::DropTablePartition(drop_table_partition) => Ok(Arc::new(
+                DropTablePartitionInterpreter::try_create(ctx, *drop_table_partition.clone())?,
+            )),
             Plan::TruncateTable(truncate_table) => Ok(Arc::new(
                 TruncateTableInterpreter::try_create(ctx, *truncate_table.clone())?,
             )),
@@ -248,6 +253,12 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::CreateInvertedIndex(index) => Ok(Arc::new(
+                CreateInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::DropInvertedIndex(index) => Ok(Arc::new(
+                DropInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
             // Virtual columns
             Plan::CreateVirtualColumns(create_virtual_columns) => Ok(Arc::new(
                 CreateVirtualColumnsInterpreter::try_create(ctx, *create_virtual_columns.clone())?,
@@ -339,6 +350,9 @@ impl InterpreterFactory {
                 ctx,
                 *grant_priv.clone(),
             )?)),
+            Plan::GrantOwnership(grant_ownership) => Ok(Arc::new(
+                GrantOwnershipInterpreter::try_create(ctx, *grant_ownership.clone())?,
+            )),
             Plan::GrantRole(grant_role) => Ok(Arc::new(GrantRoleInterpreter::try_create(
                 ctx,
                 *grant_role.clone(),
@@ -443,6 +457,21 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateRowAccessPolicy(p) => Ok(Arc::new(
+                CreateRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropRowAccessPolicy(p) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DescRowAccessPolicy(p) => Ok(Arc::new(
+                DescRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AddTableRowAccessPolicy(p) => Ok(Arc::new(
+                AddTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropTableRowAccessPolicy(p) => Ok(Arc::new(
+                DropTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
 
             Plan::CreateNetworkPolicy(p) => Ok(Arc::new(
                 CreateNetworkPolicyInterpreter::try_create(ctx, *p.clone())?,
@@ -462,6 +491,39 @@ impl InterpreterFactory {
             Plan::ShowNetworkPolicies(p) => Ok(Arc::new(
                 ShowNetworkPoliciesInterpreter::try_create(ctx, *p.clone())?,
             )),
+
+            Plan::CreatePasswordPolicy(p) => Ok(Arc::new(
+                CreatePasswordPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterPasswordPolicy(p) => Ok(Arc::new(
+                AlterPasswordPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropPasswordPolicy(p) => Ok(Arc::new(DropPasswordPolicyInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DescPasswordPolicy(p) => Ok(Arc::new(DescPasswordPolicyInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::ShowPasswordPolicies(p) => Ok(Arc::new(
+                ShowPasswordPoliciesInterpreter::try_create(ctx, *p.clone())?,
+            )),
+
+            Plan::CreateWorkloadGroup(p) => Ok(Arc::new(
+                CreateWorkloadGroupInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterWorkloadGroup(p) => Ok(Arc::new(AlterWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropWorkloadGroup(p) => Ok(Arc::new(DropWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::AlterRoleWorkloadGroup(p) => Ok(Arc::new(
+                AlterRoleWorkloadGroupInterpreter::try_create(ctx, *p.clone())?,
+            )),
         }
     }
 }
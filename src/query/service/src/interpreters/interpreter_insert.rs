@@ -23,6 +23,7 @@ use aho_corasick::AhoCorasick;
 use common_ast::parser::parse_comma_separated_exprs;
 use common_ast::parser::tokenize_sql;
 use common_catalog::table::AppendMode;
+use common_catalog::table::Table;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::ColumnBuilder;
@@ -33,6 +34,7 @@ use common_formats::FastFieldDecoderValues;
 use common_io::cursor_ext::ReadBytesExt;
 use common_io::cursor_ext::ReadCheckPointExt;
 use common_meta_app::principal::StageFileFormatType;
+use common_pipeline_core::Pipeline;
 use common_pipeline_sources::AsyncSource;
 use common_pipeline_sources::AsyncSourcer;
 use common_sql::executor::DistributedInsertSelect;
@@ -45,14 +47,16 @@ use common_sql::BindContext;
 use common_sql::Metadata;
 use common_sql::MetadataRef;
 use common_sql::NameResolutionContext;
+use common_storages_fuse::FuseTable;
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_user_storage_quota;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
-use crate::pipelines::builders::build_append2table_with_commit_pipeline;
+use crate::pipelines::builders::build_append2table_without_commit_pipeline;
 use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::SourcePipeBuilder;
@@ -97,6 +101,19 @@ impl InsertInterpreter {
         let cast_needed = select_schema != DataSchema::from(output_schema.as_ref()).into();
         Ok(cast_needed)
     }
+
+    fn commit_insertion(&self, table: &dyn Table, pipeline: &mut Pipeline) -> Result<()> {
+        match &self.plan.overwrite_partition {
+            Some(partition) => FuseTable::try_from_table(table)?.commit_partition_overwrite(
+                self.ctx.clone(),
+                pipeline,
+                partition,
+            ),
+            None => {
+                table.commit_insertion(self.ctx.clone(), pipeline, None, self.plan.overwrite, None)
+            }
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -110,6 +127,7 @@ impl Interpreter for InsertInterpreter {
         if check_deduplicate_label(self.ctx.clone()).await? {
             return Ok(PipelineBuildResult::create());
         }
+        check_user_storage_quota(&self.ctx).await?;
         let plan = &self.plan;
         let table = self
             .ctx
@@ -253,13 +271,7 @@ impl Interpreter for InsertInterpreter {
                 )
                 .await?;
 
-                table.commit_insertion(
-                    self.ctx.clone(),
-                    &mut build_res.main_pipeline,
-                    None,
-                    self.plan.overwrite,
-                    None,
-                )?;
+                self.commit_insertion(table.as_ref(), &mut build_res.main_pipeline)?;
 
                 return Ok(build_res);
             }
@@ -271,15 +283,14 @@ impl Interpreter for InsertInterpreter {
             _ => AppendMode::Normal,
         };
 
-        build_append2table_with_commit_pipeline(
+        build_append2table_without_commit_pipeline(
             self.ctx.clone(),
             &mut build_res.main_pipeline,
             table.clone(),
             plan.schema(),
-            None,
-            self.plan.overwrite,
             append_mode,
         )?;
+        self.commit_insertion(table.as_ref(), &mut build_res.main_pipeline)?;
 
         Ok(build_res)
     }
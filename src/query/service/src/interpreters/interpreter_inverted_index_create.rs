@@ -0,0 +1,140 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::TableIndex;
+use common_meta_app::schema::TableIndexType;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateInvertedIndexPlan;
+use common_storages_fuse::TableContext;
+use common_storages_share::save_share_table_info;
+use storages_common_index::INVERTED_INDEX_TOKENIZER;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CreateInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateInvertedIndexPlan,
+}
+
+impl CreateInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateInvertedIndexPlan) -> Result<Self> {
+        Ok(CreateInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateInvertedIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+        let catalog = self.ctx.get_catalog(catalog_name)?;
+        let table = catalog
+            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .await?;
+
+        let table_info = table.get_table_info();
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support inverted index",
+                db_name, tbl_name
+            )));
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        if new_table_meta.indexes.contains_key(&self.plan.index_name) {
+            return if self.plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::IndexAlreadyExists(format!(
+                    "inverted index {} already exists on table {}.{}",
+                    self.plan.index_name, db_name, tbl_name
+                )))
+            };
+        }
+
+        // A column can only be indexed by one inverted index, so that each block
+        // has a single set of postings per column.
+        for index in new_table_meta.indexes.values() {
+            if index.index_type != TableIndexType::Inverted {
+                continue;
+            }
+            if let Some(column_id) = self
+                .plan
+                .column_ids
+                .iter()
+                .find(|id| index.column_ids.contains(id))
+            {
+                let column = new_table_meta
+                    .schema
+                    .fields()
+                    .iter()
+                    .find(|f| f.column_id() == *column_id)
+                    .map_or_else(|| column_id.to_string(), |f| f.name().clone());
+                return Err(ErrorCode::IndexAlreadyExists(format!(
+                    "column {} is already indexed by inverted index {}",
+                    column, index.name
+                )));
+            }
+        }
+
+        let mut options = BTreeMap::new();
+        options.insert(
+            INVERTED_INDEX_TOKENIZER.to_string(),
+            self.plan.tokenizer.clone(),
+        );
+        new_table_meta
+            .indexes
+            .insert(self.plan.index_name.clone(), TableIndex {
+                index_type: TableIndexType::Inverted,
+                name: self.plan.index_name.clone(),
+                column_ids: self.plan.column_ids.clone(),
+                options,
+            });
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+        };
+
+        let res = catalog.update_table_meta(table_info, req).await?;
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.ctx.get_tenant(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
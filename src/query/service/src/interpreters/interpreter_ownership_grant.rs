@@ -0,0 +1,71 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::GrantOwnershipPlan;
+use common_tracing::AuditEvent;
+use common_tracing::AuditEventType;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct GrantOwnershipInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: GrantOwnershipPlan,
+}
+
+impl GrantOwnershipInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: GrantOwnershipPlan) -> Result<Self> {
+        Ok(GrantOwnershipInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for GrantOwnershipInterpreter {
+    fn name(&self) -> &str {
+        "GrantOwnershipInterpreter"
+    }
+
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent {
+            object: format!("ROLE {}", self.plan.role),
+            detail: format!("OWNERSHIP ON {}", self.plan.object_name),
+            ..AuditEvent::new(AuditEventType::Grant)
+        })
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "grant_ownership_execute");
+
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        // The new owner must be an existing role.
+        user_mgr.get_role(&tenant, self.plan.role.clone()).await?;
+        user_mgr
+            .grant_ownership_to_role(&tenant, &self.plan.object, &self.plan.role)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::AlterPasswordPolicyPlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterPasswordPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterPasswordPolicyPlan,
+}
+
+impl AlterPasswordPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterPasswordPolicyPlan) -> Result<Self> {
+        Ok(AlterPasswordPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterPasswordPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AlterPasswordPolicyInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_password_policy_execute");
+
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+
+        let user_mgr = UserApiProvider::instance();
+        user_mgr
+            .update_password_policy(&tenant, &plan.name, plan.if_exists, |password_policy| {
+                if let Some(min_length) = plan.min_length {
+                    password_policy.min_length = min_length;
+                }
+                if let Some(min_upper_case_chars) = plan.min_upper_case_chars {
+                    password_policy.min_upper_case_chars = min_upper_case_chars;
+                }
+                if let Some(min_lower_case_chars) = plan.min_lower_case_chars {
+                    password_policy.min_lower_case_chars = min_lower_case_chars;
+                }
+                if let Some(min_numeric_chars) = plan.min_numeric_chars {
+                    password_policy.min_numeric_chars = min_numeric_chars;
+                }
+                if let Some(min_special_chars) = plan.min_special_chars {
+                    password_policy.min_special_chars = min_special_chars;
+                }
+                if let Some(max_age_days) = plan.max_age_days {
+                    password_policy.max_age_days = max_age_days;
+                }
+                if let Some(max_retries) = plan.max_retries {
+                    password_policy.max_retries = max_retries;
+                }
+                if let Some(lockout_time_mins) = plan.lockout_time_mins {
+                    password_policy.lockout_time_mins = lockout_time_mins;
+                }
+                if let Some(history) = plan.history {
+                    password_policy.history = history;
+                }
+                if let Some(comment) = plan.comment {
+                    password_policy.comment = comment;
+                }
+            })
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_meta_app::principal::PasswordPolicy;
+use common_sql::plans::DescPasswordPolicyPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DescPasswordPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescPasswordPolicyPlan,
+}
+
+impl DescPasswordPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescPasswordPolicyPlan) -> Result<Self> {
+        Ok(DescPasswordPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescPasswordPolicyInterpreter {
+    fn name(&self) -> &str {
+        "DescPasswordPolicyInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        let password_policy = user_mgr
+            .get_password_policy(&tenant, self.plan.name.as_str())
+            .await?;
+
+        let properties = [
+            (
+                "NAME",
+                password_policy.name.clone(),
+                None,
+                "Name of password policy.",
+            ),
+            (
+                "COMMENT",
+                password_policy.comment.clone(),
+                None,
+                "Comment of password policy.",
+            ),
+            (
+                "PASSWORD_MIN_LENGTH",
+                password_policy.min_length.to_string(),
+                Some(PasswordPolicy::DEFAULT_MIN_LENGTH),
+                "Minimum length of new password.",
+            ),
+            (
+                "PASSWORD_MIN_UPPER_CASE_CHARS",
+                password_policy.min_upper_case_chars.to_string(),
+                Some(PasswordPolicy::DEFAULT_MIN_UPPER_CASE_CHARS),
+                "Minimum number of uppercase characters in new password.",
+            ),
+            (
+                "PASSWORD_MIN_LOWER_CASE_CHARS",
+                password_policy.min_lower_case_chars.to_string(),
+                Some(PasswordPolicy::DEFAULT_MIN_LOWER_CASE_CHARS),
+                "Minimum number of lowercase characters in new password.",
+            ),
+            (
+                "PASSWORD_MIN_NUMERIC_CHARS",
+                password_policy.min_numeric_chars.to_string(),
+                Some(PasswordPolicy::DEFAULT_MIN_NUMERIC_CHARS),
+                "Minimum number of numeric characters in new password.",
+            ),
+            (
+                "PASSWORD_MIN_SPECIAL_CHARS",
+                password_policy.min_special_chars.to_string(),
+                Some(PasswordPolicy::DEFAULT_MIN_SPECIAL_CHARS),
+                "Minimum number of special characters in new password.",
+            ),
+            (
+                "PASSWORD_MAX_AGE_DAYS",
+                password_policy.max_age_days.to_string(),
+                Some(PasswordPolicy::DEFAULT_MAX_AGE_DAYS),
+                "Period after which password must be changed, in days (0 is unlimited).",
+            ),
+            (
+                "PASSWORD_MAX_RETRIES",
+                password_policy.max_retries.to_string(),
+                Some(PasswordPolicy::DEFAULT_MAX_RETRIES),
+                "Number of failed login attempts before the account is locked out (0 is unlimited).",
+            ),
+            (
+                "PASSWORD_LOCKOUT_TIME_MINS",
+                password_policy.lockout_time_mins.to_string(),
+                Some(PasswordPolicy::DEFAULT_LOCKOUT_TIME_MINS),
+                "Period of time for which the account is locked out after too many failed logins, in minutes.",
+            ),
+            (
+                "PASSWORD_HISTORY",
+                password_policy.history.to_string(),
+                Some(PasswordPolicy::DEFAULT_HISTORY),
+                "Number of most recent passwords that can't be reused (0 disables the check).",
+            ),
+        ];
+
+        let mut names = Vec::with_capacity(properties.len());
+        let mut values = Vec::with_capacity(properties.len());
+        let mut defaults = Vec::with_capacity(properties.len());
+        let mut descriptions = Vec::with_capacity(properties.len());
+        for (name, value, default, description) in properties {
+            names.push(name.as_bytes().to_vec());
+            values.push(value.as_bytes().to_vec());
+            defaults.push(
+                default
+                    .map(|v| v.to_string())
+                    .unwrap_or_default()
+                    .as_bytes()
+                    .to_vec(),
+            );
+            descriptions.push(description.as_bytes().to_vec());
+        }
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(values),
+            StringType::from_data(defaults),
+            StringType::from_data(descriptions),
+        ])])
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
 use common_exception::Result;
@@ -19,9 +20,12 @@ use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::PrincipalIdentity;
 use common_meta_app::principal::UserPrivilegeSet;
 use common_sql::plans::GrantPrivilegePlan;
+use common_tracing::AuditEvent;
+use common_tracing::AuditEventType;
 use common_users::UserApiProvider;
 use log::debug;
 
+use crate::interpreters::common::validate_grant_columns_exist;
 use crate::interpreters::common::validate_grant_object_exists;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -46,6 +50,18 @@ impl Interpreter for GrantPrivilegeInterpreter {
         "GrantPrivilegeInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent {
+            object: self.plan.principal.to_string().trim_start().to_string(),
+            detail: format_privileges_detail(
+                &self.plan.priv_types,
+                &self.plan.columns,
+                &self.plan.on,
+            ),
+            ..AuditEvent::new(AuditEventType::Grant)
+        })
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
@@ -55,23 +71,46 @@ impl Interpreter for GrantPrivilegeInterpreter {
 
         validate_grant_privileges(&plan.on, plan.priv_types)?;
         validate_grant_object_exists(&self.ctx, &plan.on).await?;
+        validate_grant_columns_exist(&self.ctx, &plan.on, &plan.columns).await?;
 
         // TODO: check user existence
         // TODO: check privilege on granting on the grant object
 
         let tenant = self.ctx.get_tenant();
         let user_mgr = UserApiProvider::instance();
-        match plan.principal {
-            PrincipalIdentity::User(user) => {
+        match (plan.principal, plan.columns.is_empty()) {
+            (PrincipalIdentity::User(user), true) => {
                 user_mgr
                     .grant_privileges_to_user(&tenant, user, plan.on, plan.priv_types)
                     .await?;
             }
-            PrincipalIdentity::Role(role) => {
+            (PrincipalIdentity::Role(role), true) => {
                 user_mgr
                     .grant_privileges_to_role(&tenant, &role, plan.on, plan.priv_types)
                     .await?;
             }
+            (PrincipalIdentity::User(user), false) => {
+                user_mgr
+                    .grant_column_privileges_to_user(
+                        &tenant,
+                        user,
+                        plan.on,
+                        plan.priv_types,
+                        plan.columns,
+                    )
+                    .await?;
+            }
+            (PrincipalIdentity::Role(role), false) => {
+                user_mgr
+                    .grant_column_privileges_to_role(
+                        &tenant,
+                        &role,
+                        plan.on,
+                        plan.priv_types,
+                        plan.columns,
+                    )
+                    .await?;
+            }
         }
 
         Ok(PipelineBuildResult::create())
@@ -93,3 +132,17 @@ pub fn validate_grant_privileges(object: &GrantObject, privileges: UserPrivilege
     }
     Ok(())
 }
+
+/// Format the privileges of GRANT/REVOKE for the audit log, e.g. `SELECT(a,b) ON 'default'.'db'.'t'`.
+pub fn format_privileges_detail(
+    privileges: &UserPrivilegeSet,
+    columns: &BTreeSet<String>,
+    object: &GrantObject,
+) -> String {
+    if columns.is_empty() {
+        format!("{} ON {}", privileges, object)
+    } else {
+        let columns = columns.iter().cloned().collect::<Vec<_>>();
+        format!("{}({}) ON {}", privileges, columns.join(","), object)
+    }
+}
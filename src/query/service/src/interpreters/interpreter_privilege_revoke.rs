@@ -17,10 +17,13 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_meta_app::principal::PrincipalIdentity;
 use common_sql::plans::RevokePrivilegePlan;
+use common_tracing::AuditEvent;
+use common_tracing::AuditEventType;
 use common_users::UserApiProvider;
 use log::debug;
 
 use crate::interpreters::common::validate_grant_object_exists;
+use crate::interpreters::interpreter_privilege_grant::format_privileges_detail;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -44,6 +47,18 @@ impl Interpreter for RevokePrivilegeInterpreter {
         "RevokePrivilegeInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent {
+            object: self.plan.principal.to_string().trim_start().to_string(),
+            detail: format_privileges_detail(
+                &self.plan.priv_types,
+                &self.plan.columns,
+                &self.plan.on,
+            ),
+            ..AuditEvent::new(AuditEventType::Revoke)
+        })
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
@@ -59,17 +74,39 @@ impl Interpreter for RevokePrivilegeInterpreter {
         let tenant = self.ctx.get_tenant();
         let user_mgr = UserApiProvider::instance();
 
-        match plan.principal {
-            PrincipalIdentity::User(user) => {
+        match (plan.principal, plan.columns.is_empty()) {
+            (PrincipalIdentity::User(user), true) => {
                 user_mgr
                     .revoke_privileges_from_user(&tenant, user, plan.on, plan.priv_types)
                     .await?;
             }
-            PrincipalIdentity::Role(role) => {
+            (PrincipalIdentity::Role(role), true) => {
                 user_mgr
                     .revoke_privileges_from_role(&tenant, &role, plan.on, plan.priv_types)
                     .await?;
             }
+            (PrincipalIdentity::User(user), false) => {
+                user_mgr
+                    .revoke_column_privileges_from_user(
+                        &tenant,
+                        user,
+                        plan.on,
+                        plan.priv_types,
+                        plan.columns,
+                    )
+                    .await?;
+            }
+            (PrincipalIdentity::Role(role), false) => {
+                user_mgr
+                    .revoke_column_privileges_from_role(
+                        &tenant,
+                        &role,
+                        plan.on,
+                        plan.priv_types,
+                        plan.columns,
+                    )
+                    .await?;
+            }
         }
 
         Ok(PipelineBuildResult::create())
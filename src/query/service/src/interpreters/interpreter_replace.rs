@@ -31,6 +31,7 @@ use common_sql::NameResolutionContext;
 use log::info;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_user_storage_quota;
 use crate::interpreters::common::metrics_inc_replace_execution_time_ms;
 use crate::interpreters::common::metrics_inc_replace_mutation_time_ms;
 use crate::interpreters::interpreter_copy::CopyInterpreter;
@@ -69,6 +70,7 @@ impl Interpreter for ReplaceInterpreter {
         if check_deduplicate_label(self.ctx.clone()).await? {
             return Ok(PipelineBuildResult::create());
         }
+        check_user_storage_quota(&self.ctx).await?;
 
         self.check_on_conflicts()?;
 
@@ -139,9 +141,8 @@ impl Interpreter for ReplaceInterpreter {
                              }
 
                              let settings = ctx.get_settings();
-                             let query_id = ctx.get_id();
                              build_res.set_max_threads(settings.get_max_threads()? as usize);
-                             let settings = ExecutorSettings::try_create(&settings, query_id)?;
+                             let settings = ExecutorSettings::try_create(&ctx)?;
 
                              if build_res.main_pipeline.is_complete_pipeline()? {
                                  let mut pipelines = build_res.sources_pipelines;
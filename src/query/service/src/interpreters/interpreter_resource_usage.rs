@@ -0,0 +1,72 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::Ordering;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_config::GlobalConfig;
+use common_meta_app::tenant::ResourceUsage;
+use common_users::ResourceUsageManager;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct InterpreterResourceUsage;
+
+impl InterpreterResourceUsage {
+    /// Account the resources consumed by the query to its user, role and warehouse.
+    ///
+    /// In cluster mode, the coordinator records the usage of the whole query, including the
+    /// usage reported by the fragments on the other nodes along with their progress.
+    pub fn record_query_finished(ctx: &QueryContext, now: SystemTime) {
+        if !ctx.get_current_session().get_type().is_user_session() {
+            return;
+        }
+        let user = match ctx.get_current_user() {
+            Ok(user) => user.identity().to_string(),
+            Err(_) => return,
+        };
+
+        let usage_date = now
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| (d.as_secs() / (24 * 3600)) as i32);
+        let mut total = ResourceUsage {
+            usage_date,
+            user,
+            role: ctx.get_current_role().map(|r| r.name).unwrap_or_default(),
+            warehouse: GlobalConfig::instance().query.cluster_id.clone(),
+            query_count: 1,
+            result_bytes: ctx.get_result_progress_value().bytes as u64,
+            ..Self::local_usage(ctx)
+        };
+        total.merge(&ctx.get_remote_resource_usage());
+
+        let usage = ctx.account_resource_usage(total);
+        ResourceUsageManager::instance().record(&ctx.get_tenant(), usage);
+    }
+
+    /// The counters of the resources consumed by the query on this node. The results are
+    /// counted by the result progress, which the nodes already report to the coordinator.
+    pub fn local_usage(ctx: &QueryContext) -> ResourceUsage {
+        let data_metrics = ctx.get_data_metrics();
+        ResourceUsage {
+            cpu_time_ms: ctx.get_cpu_time().load(Ordering::Relaxed) / 1_000_000,
+            scan_bytes: data_metrics.get_read_bytes() as u64,
+            spill_bytes: ctx.get_spill_metrics().get_write_bytes() as u64,
+            written_bytes: data_metrics.get_write_bytes() as u64,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::AlterRoleWorkloadGroupPlan;
+use common_users::RoleCacheManager;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterRoleWorkloadGroupInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterRoleWorkloadGroupPlan,
+}
+
+impl AlterRoleWorkloadGroupInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterRoleWorkloadGroupPlan) -> Result<Self> {
+        Ok(AlterRoleWorkloadGroupInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterRoleWorkloadGroupInterpreter {
+    fn name(&self) -> &str {
+        "AlterRoleWorkloadGroupInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_role_workload_group_execute");
+
+        let plan = self.plan.clone();
+        UserApiProvider::instance()
+            .set_role_workload_group(&plan.tenant, &plan.role_name, plan.workload_group)
+            .await?;
+
+        RoleCacheManager::instance()
+            .force_reload(&plan.tenant)
+            .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
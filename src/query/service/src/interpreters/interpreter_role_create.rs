@@ -17,6 +17,8 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_meta_app::principal::RoleInfo;
 use common_sql::plans::CreateRolePlan;
+use common_tracing::AuditEvent;
+use common_tracing::AuditEventType;
 use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
 use log::debug;
@@ -44,6 +46,13 @@ impl Interpreter for CreateRoleInterpreter {
         "CreateRoleInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent {
+            object: format!("ROLE {}", self.plan.role_name),
+            ..AuditEvent::new(AuditEventType::CreateRole)
+        })
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_sql::plans::DropRolePlan;
+use common_tracing::AuditEvent;
+use common_tracing::AuditEventType;
 use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
 use log::debug;
@@ -43,6 +45,13 @@ impl Interpreter for DropRoleInterpreter {
         "DropRoleInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent {
+            object: format!("ROLE {}", self.plan.role_name),
+            ..AuditEvent::new(AuditEventType::DropRole)
+        })
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
@@ -17,6 +17,8 @@ use std::sync::Arc;
 use common_exception::Result;
 use common_meta_app::principal::PrincipalIdentity;
 use common_sql::plans::RevokeRolePlan;
+use common_tracing::AuditEvent;
+use common_tracing::AuditEventType;
 use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
 use log::debug;
@@ -44,6 +46,14 @@ impl Interpreter for RevokeRoleInterpreter {
         "RevokeRoleInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent {
+            object: self.plan.principal.to_string().trim_start().to_string(),
+            detail: format!("ROLE {}", self.plan.role),
+            ..AuditEvent::new(AuditEventType::Revoke)
+        })
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
@@ -20,7 +20,12 @@ use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
 use common_expression::FromData;
 use common_meta_api::ShareApi;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserGrantSet;
+use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserPrivilegeType;
 use common_meta_app::share::GetObjectGrantPrivilegesReq;
+use common_meta_app::share::ShareGrantObjectName;
 use common_users::UserApiProvider;
 
 use crate::interpreters::Interpreter;
@@ -40,6 +45,25 @@ impl ShowObjectGrantPrivilegesInterpreter {
     }
 }
 
+// The privileges granted exactly on the object, as strings like `SELECT,INSERT`, the column-level
+// privileges are followed by their columns, like `SELECT(a,b)`.
+fn privileges_on(grants: &UserGrantSet, object: &GrantObject) -> Vec<String> {
+    grants
+        .entries()
+        .iter()
+        .filter(|e| e.object() == object)
+        .map(|e| {
+            let privileges = UserPrivilegeSet::from(*e.privileges()).to_string();
+            if e.is_column_level() {
+                let columns = e.columns().iter().cloned().collect::<Vec<_>>();
+                format!("{}({})", privileges, columns.join(","))
+            } else {
+                privileges
+            }
+        })
+        .collect()
+}
+
 #[async_trait::async_trait]
 impl Interpreter for ShowObjectGrantPrivilegesInterpreter {
     fn name(&self) -> &str {
@@ -52,29 +76,81 @@ impl Interpreter for ShowObjectGrantPrivilegesInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        let meta_api = UserApiProvider::instance().get_meta_store_client();
-        let req = GetObjectGrantPrivilegesReq {
-            tenant: self.ctx.get_tenant(),
-            object: self.plan.object.clone(),
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        let mut granted_ons: Vec<Vec<u8>> = vec![];
+        let mut privileges: Vec<Vec<u8>> = vec![];
+        let mut granted_tos: Vec<Vec<u8>> = vec![];
+        let mut grantee_names: Vec<Vec<u8>> = vec![];
+        let mut push_row = |granted_on: &str, privilege: &str, granted_to: &str, name: &str| {
+            granted_ons.push(granted_on.as_bytes().to_vec());
+            privileges.push(privilege.as_bytes().to_vec());
+            granted_tos.push(granted_to.as_bytes().to_vec());
+            grantee_names.push(name.as_bytes().to_vec());
         };
-        let resp = meta_api.get_grant_privileges_of_object(req).await?;
-        if resp.privileges.is_empty() {
-            return Ok(PipelineBuildResult::create());
+
+        let object_name = self.plan.object_name.as_str();
+        if let Some(ownership) = user_mgr.get_ownership(&tenant, &self.plan.object).await? {
+            push_row(
+                object_name,
+                &UserPrivilegeType::Ownership.to_string(),
+                "ROLE",
+                &ownership.role,
+            );
         }
-        let mut share_names: Vec<Vec<u8>> = vec![];
-        let mut privileges: Vec<Vec<u8>> = vec![];
-        let mut created_owns: Vec<Vec<u8>> = vec![];
 
-        for privilege in resp.privileges {
-            share_names.push(privilege.share_name.as_bytes().to_vec());
-            privileges.push(privilege.privileges.to_string().as_bytes().to_vec());
-            created_owns.push(privilege.grant_on.to_string().as_bytes().to_vec());
+        if let Some(grant_object) = &self.plan.grant_object {
+            let mut roles = user_mgr.get_roles(&tenant).await?;
+            roles.sort_by(|a, b| a.name.cmp(&b.name));
+            for role in roles {
+                for privs in privileges_on(&role.grants, grant_object) {
+                    push_row(object_name, &privs, "ROLE", &role.name);
+                }
+            }
+
+            let mut users = user_mgr.get_users(&tenant).await?;
+            users.sort_by(|a, b| a.identity().to_string().cmp(&b.identity().to_string()));
+            for user in users {
+                for privs in privileges_on(&user.grants, grant_object) {
+                    push_row(object_name, &privs, "USER", &user.identity().to_string());
+                }
+            }
+
+            let share_object = match grant_object {
+                GrantObject::Database(_, db) => Some(ShareGrantObjectName::Database(db.clone())),
+                GrantObject::Table(_, db, table) => {
+                    Some(ShareGrantObjectName::Table(db.clone(), table.clone()))
+                }
+                GrantObject::Global => None,
+            };
+            if let Some(share_object) = share_object {
+                let meta_api = user_mgr.get_meta_store_client();
+                let req = GetObjectGrantPrivilegesReq {
+                    tenant: tenant.clone(),
+                    object: share_object,
+                };
+                let resp = meta_api.get_grant_privileges_of_object(req).await?;
+                for privilege in resp.privileges {
+                    push_row(
+                        &privilege.grant_on.to_string(),
+                        &privilege.privileges.to_string(),
+                        "SHARE",
+                        &privilege.share_name,
+                    );
+                }
+            }
+        }
+
+        if granted_ons.is_empty() {
+            return Ok(PipelineBuildResult::create());
         }
 
         PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
-            StringType::from_data(created_owns),
+            StringType::from_data(granted_ons),
             StringType::from_data(privileges),
-            StringType::from_data(share_names),
+            StringType::from_data(granted_tos),
+            StringType::from_data(grantee_names),
         ])])
     }
 }
@@ -0,0 +1,120 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::RowAccessPolicyApi;
+use common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+use common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::AddTableRowAccessPolicyPlan;
+use common_storages_fuse::TableContext;
+use common_users::UserApiProvider;
+use storages_common_table_meta::table::TableRowAccessPolicy;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct AddTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableRowAccessPolicyPlan,
+}
+
+impl AddTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(AddTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AddTableRowAccessPolicyInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        let table = catalog
+            .get_table(
+                self.plan.tenant.as_str(),
+                self.plan.database.as_str(),
+                self.plan.table.as_str(),
+            )
+            .await?;
+
+        if table.engine() != "FUSE" {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "row access policy is not supported by {} engine",
+                table.engine()
+            )));
+        }
+        if let Some(policy) = TableRowAccessPolicy::from_options(table.options())? {
+            return Err(ErrorCode::InvalidRowAccessPolicy(format!(
+                "table `{}` already has row access policy '{}'",
+                self.plan.table, policy.policy
+            )));
+        }
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let policy = meta_api
+            .get_row_access_policy(GetRowAccessPolicyReq {
+                name: RowAccessPolicyNameIdent {
+                    tenant: self.plan.tenant.clone(),
+                    name: self.plan.policy.clone(),
+                },
+            })
+            .await?
+            .policy;
+        if policy.args.len() != self.plan.columns.len() {
+            return Err(ErrorCode::InvalidRowAccessPolicy(format!(
+                "row access policy '{}' takes {} args, but {} columns are given",
+                self.plan.policy,
+                policy.args.len(),
+                self.plan.columns.len()
+            )));
+        }
+
+        let schema = table.schema();
+        let column_ids = self
+            .plan
+            .columns
+            .iter()
+            .map(|column| schema.column_id_of(column))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut options = HashMap::new();
+        TableRowAccessPolicy {
+            policy: self.plan.policy.clone(),
+            column_ids,
+        }
+        .to_options(&mut options);
+
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options,
+        };
+        catalog
+            .upsert_table_option(self.plan.tenant.as_str(), self.plan.database.as_str(), req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -28,6 +28,7 @@ use common_expression::SNAPSHOT_NAME_COL_NAME;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use common_license::license::Feature::ComputedColumn;
 use common_license::license_manager::get_license_manager;
+use common_meta_app::principal::OwnershipObject;
 use common_meta_app::schema::CreateTableReq;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
@@ -38,9 +39,12 @@ use common_sql::plans::CreateTablePlan;
 use common_sql::plans::PREDICATE_COLUMN_NAME;
 use common_sql::BloomIndexColumns;
 use common_storage::DataOperator;
+use common_storage::MasterKeys;
+use common_storage::ENCRYPTION_AES_256_GCM;
 use common_storages_fuse::io::MetaReaders;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use common_storages_fuse::FUSE_OPT_KEY_ENABLE_DELETION_VECTOR;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
@@ -50,17 +54,26 @@ use log::error;
 use once_cell::sync::Lazy;
 use storages_common_cache::LoadParams;
 use storages_common_index::BloomIndex;
+use storages_common_index::VectorIndex;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
+use storages_common_table_meta::table::OPT_KEY_CREATED_BY;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION_DATA_KEY;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_TTL;
+use storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
 
+use crate::interpreters::common::grant_ownership_to_current_role;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -161,6 +174,7 @@ impl CreateTableInterpreter {
         if !reply.new_table {
             return Ok(PipelineBuildResult::create());
         }
+        self.grant_ownership(reply.table_id).await?;
         let table = catalog
             .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
             .await?;
@@ -181,6 +195,7 @@ impl CreateTableInterpreter {
             table_id: table.get_id(),
             schema: self.plan.schema.clone(),
             overwrite: false,
+            overwrite_partition: None,
             source: InsertInputSource::SelectPlan(select_plan),
         };
 
@@ -221,11 +236,30 @@ impl CreateTableInterpreter {
         } else {
             self.build_request(stat)
         }?;
-        catalog.create_table(req).await?;
+        let reply = catalog.create_table(req).await?;
+        if reply.new_table {
+            self.grant_ownership(reply.table_id).await?;
+        }
 
         Ok(PipelineBuildResult::create())
     }
 
+    /// The current role becomes the owner of the new table.
+    #[async_backtrace::framed]
+    async fn grant_ownership(&self, table_id: u64) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        let database = catalog
+            .get_database(tenant.as_str(), &self.plan.database)
+            .await?;
+        let object = OwnershipObject::Table {
+            catalog_name: self.plan.catalog.clone(),
+            db_id: database.get_db_info().ident.db_id,
+            table_id,
+        };
+        grant_ownership_to_current_role(&self.ctx, &object).await
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
@@ -259,8 +293,17 @@ impl CreateTableInterpreter {
 
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
+        is_valid_deletion_vector(
+            &table_meta.options,
+            table_meta.options.get(OPT_KEY_STORAGE_FORMAT),
+        )?;
         // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
+        // check ngram_index_columns.
+        is_valid_ngram_index_columns(&table_meta.options, schema.clone())?;
+        // check vector_index_columns.
+        is_valid_vector_index_columns(&table_meta.options, schema)?;
+        is_valid_encryption(&table_meta.options)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -276,6 +319,32 @@ impl CreateTableInterpreter {
             table_meta = table_meta.push_cluster_key(cluster_key.clone());
         }
 
+        if let Some(partition_by) = &self.plan.partition_by {
+            table_meta
+                .options
+                .insert(OPT_KEY_PARTITION_BY.to_string(), partition_by.clone());
+        }
+
+        if let Some(ttl) = &self.plan.ttl {
+            table_meta
+                .options
+                .insert(OPT_KEY_TTL.to_string(), ttl.clone());
+        }
+
+        self.plan.constraints.to_options(&mut table_meta.options);
+
+        table_meta.options.insert(
+            OPT_KEY_CREATED_BY.to_string(),
+            self.ctx.get_current_user()?.identity().to_string(),
+        );
+
+        if table_meta.options.contains_key(OPT_KEY_ENCRYPTION) {
+            table_meta.options.insert(
+                OPT_KEY_ENCRYPTION_DATA_KEY.to_string(),
+                MasterKeys::instance().generate_data_key()?,
+            );
+        }
+
         let req = CreateTableReq {
             if_not_exists: self.plan.if_not_exists,
             name_ident: TableNameIdent {
@@ -354,12 +423,16 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
+    r.insert(FUSE_OPT_KEY_ENABLE_DELETION_VECTOR);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_NGRAM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_VECTOR_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
+    r.insert(OPT_KEY_ENCRYPTION);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -424,6 +497,39 @@ pub fn is_valid_row_per_block(options: &BTreeMap<String, String>) -> Result<()>
     Ok(())
 }
 
+pub fn is_valid_deletion_vector(
+    options: &BTreeMap<String, String>,
+    storage_format: Option<&String>,
+) -> Result<()> {
+    // check enable_deletion_vector is a bool, and only used by parquet tables.
+    if let Some(value) = options.get(FUSE_OPT_KEY_ENABLE_DELETION_VECTOR) {
+        let enabled = value.parse::<bool>().map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid enable_deletion_vector option {}, expect true or false",
+                value
+            ))
+        })?;
+        if enabled && storage_format.map_or(false, |v| v.eq_ignore_ascii_case("native")) {
+            let error_str = "deletion vector is only supported by the parquet storage format";
+            error!("{}", error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_encryption(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_ENCRYPTION) {
+        if !value.eq_ignore_ascii_case(ENCRYPTION_AES_256_GCM) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid encryption option {}, only {} is supported",
+                value, ENCRYPTION_AES_256_GCM
+            )));
+        }
+    }
+    Ok(())
+}
+
 pub fn is_valid_bloom_index_columns(
     options: &BTreeMap<String, String>,
     schema: TableSchemaRef,
@@ -433,3 +539,23 @@ pub fn is_valid_bloom_index_columns(
     }
     Ok(())
 }
+
+pub fn is_valid_ngram_index_columns(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+        BloomIndexColumns::verify_definition(value, schema, BloomIndex::supported_ngram_type)?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_vector_index_columns(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_VECTOR_INDEX_COLUMNS) {
+        BloomIndexColumns::verify_definition(value, schema, VectorIndex::supported_type)?;
+    }
+    Ok(())
+}
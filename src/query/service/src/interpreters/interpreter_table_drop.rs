@@ -17,10 +17,12 @@ use std::sync::Arc;
 use common_catalog::table::TableExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::principal::OwnershipObject;
 use common_meta_app::schema::DropTableByIdReq;
 use common_sql::plans::DropTablePlan;
 use common_storages_share::save_share_spec;
 use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -68,15 +70,27 @@ impl Interpreter for DropTableInterpreter {
                     &self.plan.database, &self.plan.table, &self.plan.database, &self.plan.table
                 )));
             }
+            let tenant = self.ctx.get_tenant();
             let catalog = self.ctx.get_catalog(catalog_name)?;
+            let database = catalog.get_database(&tenant, db_name).await?;
 
+            let table_id = tbl.get_table_info().ident.table_id;
             let resp = catalog
                 .drop_table_by_id(DropTableByIdReq {
                     if_exists: self.plan.if_exists,
-                    tb_id: tbl.get_table_info().ident.table_id,
+                    tb_id: table_id,
                 })
                 .await?;
 
+            let object = OwnershipObject::Table {
+                catalog_name: catalog_name.to_string(),
+                db_id: database.get_db_info().ident.db_id,
+                table_id,
+            };
+            UserApiProvider::instance()
+                .revoke_ownership(&tenant, &object)
+                .await?;
+
             // if `plan.all`, truncate, then purge the historical data
             if self.plan.all {
                 let purge = true;
@@ -25,8 +25,14 @@ use common_sql::BloomIndexColumns;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_constraints;
+use crate::interpreters::common::check_referenced_partition_key;
+use crate::interpreters::common::check_referenced_row_access_policy;
+use crate::interpreters::common::check_referenced_ttl;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -74,6 +80,11 @@ impl Interpreter for DropTableColumnInterpreter {
             )));
         }
 
+        check_referenced_partition_key(self.ctx.clone(), table.clone(), &self.plan.column)?;
+        check_referenced_ttl(self.ctx.clone(), table.clone(), &self.plan.column)?;
+        check_referenced_constraints(table.clone(), &self.plan.column)?;
+        check_referenced_row_access_policy(table.clone(), &self.plan.column)?;
+
         let mut schema: DataSchema = table_info.schema().into();
         let field = schema.field_with_name(self.plan.column.as_str())?;
         if field.computed_expr().is_none() {
@@ -88,17 +99,35 @@ impl Interpreter for DropTableColumnInterpreter {
 
         let catalog = self.ctx.get_catalog(catalog_name)?;
         let mut new_table_meta = table.get_table_info().meta.clone();
+        let column_id = new_table_meta
+            .schema
+            .field_with_name(&self.plan.column)?
+            .column_id();
         new_table_meta.drop_column(&self.plan.column)?;
 
+        // remove the column from the table indexes, and drop the indexes left empty.
+        for index in new_table_meta.indexes.values_mut() {
+            index.column_ids.retain(|id| *id != column_id);
+        }
+        new_table_meta
+            .indexes
+            .retain(|_, index| !index.column_ids.is_empty());
+
         // update table options
         let opts = &mut new_table_meta.options;
-        if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-            let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
-            if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
-                    // remove from the bloom index columns.
-                    cols.remove(pos);
-                    *value = cols.join(",");
+        for key in [
+            OPT_KEY_BLOOM_INDEX_COLUMNS,
+            OPT_KEY_NGRAM_INDEX_COLUMNS,
+            OPT_KEY_VECTOR_INDEX_COLUMNS,
+        ] {
+            if let Some(value) = opts.get_mut(key) {
+                let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
+                if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
+                    if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
+                        // remove from the bloom index columns.
+                        cols.remove(pos);
+                        *value = cols.join(",");
+                    }
                 }
             }
         }
@@ -112,8 +112,7 @@ impl Interpreter for ReclusterTableInterpreter {
             let max_threads = std::cmp::min(max_threads, reclustered_block_count) as usize;
             pipeline.set_max_threads(max_threads);
 
-            let query_id = ctx.get_id();
-            let executor_settings = ExecutorSettings::try_create(&settings, query_id)?;
+            let executor_settings = ExecutorSettings::try_create(&ctx)?;
             let executor = PipelineCompleteExecutor::try_create(pipeline, executor_settings)?;
 
             ctx.set_executor(executor.get_inner())?;
@@ -23,12 +23,16 @@ use common_sql::plans::SetOptionsPlan;
 use common_storages_fuse::TableContext;
 use log::error;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 
 use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_deletion_vector;
+use super::interpreter_table_create::is_valid_ngram_index_columns;
 use super::interpreter_table_create::is_valid_row_per_block;
+use super::interpreter_table_create::is_valid_vector_index_columns;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -67,6 +71,13 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_STORAGE_FORMAT
             )));
         }
+        if self.plan.set_options.get(OPT_KEY_ENCRYPTION).is_some() {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                OPT_KEY_ENCRYPTION
+            )));
+        }
         if self.plan.set_options.get(OPT_KEY_DATABASE_ID).is_some() {
             error!("{}", &error_str);
             return Err(ErrorCode::TableOptionInvalid(format!(
@@ -100,6 +111,15 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check ngram_index_columns.
+        is_valid_ngram_index_columns(&self.plan.set_options, table.schema())?;
+        // check enable_deletion_vector.
+        is_valid_deletion_vector(
+            &self.plan.set_options,
+            table.options().get(OPT_KEY_STORAGE_FORMAT),
+        )?;
+        // check vector_index_columns.
+        is_valid_vector_index_columns(&self.plan.set_options, table.schema())?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
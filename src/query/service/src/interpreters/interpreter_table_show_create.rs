@@ -18,6 +18,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::BlockEntry;
+use common_expression::ColumnId;
 use common_expression::ComputedExpr;
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
@@ -28,6 +29,9 @@ use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use log::debug;
 use storages_common_table_meta::table::is_internal_opt_key;
+use storages_common_table_meta::table::TableConstraints;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
+use storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -144,6 +148,21 @@ impl Interpreter for ShowCreateTableInterpreter {
 
                 columns.push(column);
             }
+            // Append key constraints, which are declared by column ids.
+            let constraints = TableConstraints::from_options(table.options())?;
+            let key_columns = |key: &[ColumnId]| {
+                key.iter()
+                    .filter_map(|id| schema.fields().iter().find(|f| f.column_id() == *id))
+                    .map(|f| format!("`{}`", f.name()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            if let Some(primary_key) = &constraints.primary_key {
+                columns.push(format!("  PRIMARY KEY ({})", key_columns(primary_key)));
+            }
+            for unique_key in &constraints.unique_keys {
+                columns.push(format!("  UNIQUE ({})", key_columns(unique_key)));
+            }
             // Format is:
             //  (
             //      x,
@@ -160,6 +179,12 @@ impl Interpreter for ShowCreateTableInterpreter {
         if let Some((_, cluster_keys_str)) = table_info.meta.cluster_key() {
             table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
         }
+        if let Some(partition_by) = table_info.options().get(OPT_KEY_PARTITION_BY) {
+            table_create_sql.push_str(format!(" PARTITION BY ({})", partition_by).as_str());
+        }
+        if let Some(ttl) = table_info.options().get(OPT_KEY_TTL) {
+            table_create_sql.push_str(format!(" TTL = {}", ttl).as_str());
+        }
 
         let settings = self.ctx.get_settings();
         let hide_options_in_show_create_table = settings
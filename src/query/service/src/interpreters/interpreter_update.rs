@@ -31,6 +31,7 @@ use log::debug;
 use table_lock::TableLockHandlerWrapper;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_user_storage_quota;
 use crate::interpreters::interpreter_delete::replace_subquery;
 use crate::interpreters::interpreter_delete::subquery_filter;
 use crate::interpreters::Interpreter;
@@ -67,6 +68,7 @@ impl Interpreter for UpdateInterpreter {
         if check_deduplicate_label(self.ctx.clone()).await? {
             return Ok(PipelineBuildResult::create());
         }
+        check_user_storage_quota(&self.ctx).await?;
 
         let catalog_name = self.plan.catalog.as_str();
         let db_name = self.plan.database.as_str();
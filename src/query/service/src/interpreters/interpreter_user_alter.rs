@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_sql::plans::AlterUserPlan;
+use common_tracing::AuditEvent;
+use common_tracing::AuditEventType;
 use common_users::UserApiProvider;
 use log::debug;
 
@@ -42,6 +44,22 @@ impl Interpreter for AlterUserInterpreter {
         "AlterUserInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        // Only the kind of the changes is logged, never the credentials.
+        let mut changes = vec![];
+        if let Some(auth_info) = &self.plan.auth_info {
+            changes.push(format!("auth_type={}", auth_info.get_type().to_str()));
+        }
+        if self.plan.user_option.is_some() {
+            changes.push("options".to_string());
+        }
+        Some(AuditEvent {
+            object: format!("USER {}", self.plan.user),
+            detail: changes.join(", "),
+            ..AuditEvent::new(AuditEventType::AlterUser)
+        })
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::UserGrantSet;
@@ -21,6 +22,8 @@ use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserQuota;
 use common_meta_types::MatchSeq;
 use common_sql::plans::CreateUserPlan;
+use common_tracing::AuditEvent;
+use common_tracing::AuditEventType;
 use common_users::UserApiProvider;
 use log::debug;
 
@@ -47,6 +50,14 @@ impl Interpreter for CreateUserInterpreter {
         "CreateUserInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent {
+            object: format!("USER {}", self.plan.user),
+            detail: format!("auth_type={}", self.plan.auth_info.get_type().to_str()),
+            ..AuditEvent::new(AuditEventType::CreateUser)
+        })
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
@@ -74,6 +85,10 @@ impl Interpreter for CreateUserInterpreter {
             grants: UserGrantSet::empty(),
             quota: UserQuota::no_limit(),
             option: plan.user_option,
+            history_auth_infos: vec![],
+            password_fails: vec![],
+            password_update_on: Some(Utc::now()),
+            lockout_time: None,
         };
         user_mgr
             .add_user(&tenant, user_info, plan.if_not_exists)
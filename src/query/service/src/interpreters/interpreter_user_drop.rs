@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_sql::plans::DropUserPlan;
+use common_tracing::AuditEvent;
+use common_tracing::AuditEventType;
 use common_users::UserApiProvider;
 use log::debug;
 
@@ -42,6 +44,13 @@ impl Interpreter for DropUserInterpreter {
         "DropUserInterpreter"
     }
 
+    fn audit_event(&self) -> Option<AuditEvent> {
+        Some(AuditEvent {
+            object: format!("USER {}", self.plan.user),
+            ..AuditEvent::new(AuditEventType::DropUser)
+        })
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
@@ -16,12 +16,14 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::principal::OwnershipObject;
 use common_meta_app::principal::StageType;
 use common_meta_types::MatchSeq;
 use common_sql::plans::CreateStagePlan;
 use common_users::UserApiProvider;
 use log::debug;
 
+use crate::interpreters::common::grant_ownership_to_current_role;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -76,12 +78,21 @@ impl Interpreter for CreateUserStageInterpreter {
             op.create_dir(&user_stage.stage_prefix()).await?
         }
 
+        let exists = stages.iter().any(|s| s.stage_name == user_stage.stage_name);
+        let object = OwnershipObject::Stage {
+            name: user_stage.stage_name.clone(),
+        };
+
         let mut user_stage = user_stage;
         user_stage.creator = Some(self.ctx.get_current_user()?.identity());
         let _create_stage = user_mgr
             .add_stage(&plan.tenant, user_stage, plan.if_not_exists)
             .await?;
 
+        if !exists {
+            grant_ownership_to_current_role(&self.ctx, &object).await?;
+        }
+
         Ok(PipelineBuildResult::create())
     }
 }
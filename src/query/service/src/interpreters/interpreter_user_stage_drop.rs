@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::principal::OwnershipObject;
 use common_meta_app::principal::StageType;
 use common_sql::plans::DropStagePlan;
 use common_storages_stage::StageTable;
@@ -66,6 +67,10 @@ impl Interpreter for DropUserStageInterpreter {
         user_mgr
             .drop_stage(&tenant, &plan.name, plan.if_exists)
             .await?;
+        let object = OwnershipObject::Stage {
+            name: plan.name.clone(),
+        };
+        user_mgr.revoke_ownership(&tenant, &object).await?;
 
         if let Ok(stage) = stage {
             if !matches!(&stage.stage_type, StageType::External) {
@@ -15,10 +15,12 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_app::principal::OwnershipObject;
 use common_sql::plans::CreateUDFPlan;
 use common_users::UserApiProvider;
 use log::debug;
 
+use crate::interpreters::common::grant_ownership_to_current_role;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -50,10 +52,18 @@ impl Interpreter for CreateUserUDFInterpreter {
         let plan = self.plan.clone();
         let tenant = self.ctx.get_tenant();
         let udf = plan.udf;
-        let _ = UserApiProvider::instance()
+        let object = OwnershipObject::UDF {
+            name: udf.name.clone(),
+        };
+        let seq = UserApiProvider::instance()
             .add_udf(&tenant, udf, plan.if_not_exists)
             .await?;
 
+        // seq is 0 if the udf already exists, its owner is kept.
+        if seq != u64::MIN {
+            grant_ownership_to_current_role(&self.ctx, &object).await?;
+        }
+
         Ok(PipelineBuildResult::create())
     }
 }
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_app::principal::OwnershipObject;
 use common_sql::plans::DropUDFPlan;
 use common_users::UserApiProvider;
 use log::debug;
@@ -49,9 +50,14 @@ impl Interpreter for DropUserUDFInterpreter {
 
         let plan = self.plan.clone();
         let tenant = self.ctx.get_tenant();
-        UserApiProvider::instance()
+        let user_mgr = UserApiProvider::instance();
+        user_mgr
             .drop_udf(&tenant, plan.name.as_str(), plan.if_exists)
             .await?;
+        let object = OwnershipObject::UDF {
+            name: plan.name.clone(),
+        };
+        user_mgr.revoke_ownership(&tenant, &object).await?;
 
         Ok(PipelineBuildResult::create())
     }
@@ -12,24 +12,39 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use std::time::Duration;
 
+use common_catalog::table_context::TableContext;
 use common_exception::Result;
-use common_settings::Settings;
+use common_users::WorkloadGroupResources;
+
+use crate::sessions::QueryContext;
+use crate::sessions::UserResources;
 
 #[derive(Clone)]
 pub struct ExecutorSettings {
     pub query_id: Arc<String>,
     pub max_execute_time_in_seconds: Duration,
+    /// Threads of the executor are acquired from the user's quota, if any.
+    pub user_resources: Option<Arc<UserResources>>,
+    /// Threads and memory of the executor are accounted to the workload group, if any.
+    pub workload_group: Option<Arc<WorkloadGroupResources>>,
+    /// Time the executor spends in processors is accounted to the query, if any.
+    pub cpu_time: Option<Arc<AtomicU64>>,
 }
 
 impl ExecutorSettings {
-    pub fn try_create(settings: &Settings, query_id: String) -> Result<ExecutorSettings> {
+    pub fn try_create(ctx: &QueryContext) -> Result<ExecutorSettings> {
+        let settings = ctx.get_settings();
         let max_execute_time_in_seconds = settings.get_max_execute_time_in_seconds()?;
         Ok(ExecutorSettings {
-            query_id: Arc::new(query_id),
+            query_id: Arc::new(ctx.get_id()),
             max_execute_time_in_seconds: Duration::from_secs(max_execute_time_in_seconds),
+            user_resources: ctx.get_user_resources(),
+            workload_group: ctx.get_workload_group(),
+            cpu_time: Some(ctx.get_cpu_time()),
         })
     }
 }
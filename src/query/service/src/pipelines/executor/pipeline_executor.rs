@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -19,12 +20,15 @@ use common_base::base::tokio;
 use common_base::base::tokio::sync::Notify;
 use common_base::runtime::catch_unwind;
 use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::MemStat;
 use common_base::runtime::Runtime;
 use common_base::runtime::Thread;
 use common_base::runtime::ThreadJoinHandle;
+use common_base::runtime::ThreadTracker;
 use common_base::runtime::TrySpawn;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_users::WorkloadGroupManager;
 use futures::future::select;
 use futures_util::future::Either;
 use log::info;
@@ -40,6 +44,7 @@ use crate::pipelines::executor::executor_tasks::ExecutorTasksQueue;
 use crate::pipelines::executor::executor_worker_context::ExecutorWorkerContext;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::pipeline::Pipeline;
+use crate::sessions::ThreadsPermit;
 
 pub type InitCallback = Box<dyn FnOnce() -> Result<()> + Send + Sync + 'static>;
 
@@ -57,6 +62,7 @@ pub struct PipelineExecutor {
     settings: ExecutorSettings,
     finished_notify: Arc<Notify>,
     finished_error: Mutex<Option<ErrorCode>>,
+    threads_permit: Option<ThreadsPermit>,
 }
 
 impl PipelineExecutor {
@@ -163,6 +169,23 @@ impl PipelineExecutor {
         on_finished_callback: Mutex<Option<FinishedCallback>>,
         settings: ExecutorSettings,
     ) -> Result<Arc<PipelineExecutor>> {
+        // Share the threads with the other busy groups by the CPU share of the workload group.
+        let threads_num = settings
+            .workload_group
+            .as_ref()
+            .map_or(threads_num, |group| {
+                WorkloadGroupManager::instance().max_threads(group, threads_num)
+            });
+
+        // Limit the threads by the CPU quota of the user.
+        let threads_permit = settings
+            .user_resources
+            .as_ref()
+            .map(|resources| resources.acquire_threads(threads_num));
+        let threads_num = threads_permit
+            .as_ref()
+            .map_or(threads_num, |permit| permit.threads());
+
         let workers_condvar = WorkersCondvar::create(threads_num);
         let global_tasks_queue = ExecutorTasksQueue::create(threads_num);
 
@@ -177,6 +200,7 @@ impl PipelineExecutor {
             settings,
             finished_error: Mutex::new(None),
             finished_notify: Arc::new(Notify::new()),
+            threads_permit,
         }))
     }
 
@@ -195,7 +219,10 @@ impl PipelineExecutor {
 
             // We only save the cause of the first error.
             if finished_error.is_none() {
-                *finished_error = Some(cause);
+                *finished_error = match (&self.settings.user_resources, &self.threads_permit) {
+                    (Some(resources), Some(permit)) => Some(resources.map_error(permit, cause)),
+                    _ => Some(cause),
+                };
             }
         }
 
@@ -311,6 +338,17 @@ impl PipelineExecutor {
     fn execute_threads(self: &Arc<Self>, threads: usize) -> Vec<ThreadJoinHandle<Result<()>>> {
         let mut thread_join_handles = Vec::with_capacity(threads);
 
+        // Chain the memory stats as query -> user -> workload group, the user's stat is a child
+        // of the group's if the query runs in a group. See `SessionManager::get_user_resources`.
+        let parent_mem_stat = match (&self.settings.user_resources, &self.settings.workload_group) {
+            (Some(resources), _) => Some(resources.get_mem_stat()),
+            (None, Some(group)) => Some(group.get_mem_stat()),
+            (None, None) => None,
+        };
+        let query_mem_stat = parent_mem_stat.map(|parent| {
+            MemStat::create_child(format!("Query{}", self.settings.query_id), Some(parent))
+        });
+
         for thread_num in 0..threads {
             let this = self.clone();
             #[allow(unused_mut)]
@@ -326,6 +364,11 @@ impl PipelineExecutor {
                 }
             }
 
+            // The memory of the executor threads is tracked by the query, which is a part of
+            // the user and the workload group, if any.
+            let _guard = query_mem_stat
+                .as_ref()
+                .map(|mem_stat| ThreadTracker::enter(Some(mem_stat.clone())));
             thread_join_handles.push(Thread::named_spawn(name, move || unsafe {
                 let this_clone = this.clone();
                 let try_result = catch_unwind(move || -> Result<()> {
@@ -373,7 +416,13 @@ impl PipelineExecutor {
             }
 
             while !self.global_tasks_queue.is_finished() && context.has_task() {
-                if let Some(executed_pid) = context.execute_task()? {
+                let instant = Instant::now();
+                let executed = context.execute_task();
+                if let Some(cpu_time) = &self.settings.cpu_time {
+                    cpu_time.fetch_add(instant.elapsed().as_nanos() as u64, Ordering::Relaxed);
+                }
+
+                if let Some(executed_pid) = executed? {
                     // Not scheduled graph if pipeline is finished.
                     if !self.global_tasks_queue.is_finished() {
                         // We immediately schedule the processor again.
@@ -82,6 +82,7 @@ use common_sql::executor::Window;
 use common_sql::ColumnBinding;
 use common_sql::IndexType;
 use common_storage::DataOperator;
+use common_storage::StorageMetricsLayer;
 use common_storages_factory::Table;
 use common_storages_fuse::operations::build_row_fetcher_pipeline;
 use common_storages_fuse::operations::FillInternalColumnProcessor;
@@ -872,7 +873,9 @@ impl PipelineBuilder {
 
         // If cluster mode, spill write will be completed in exchange serialize, because we need scatter the block data first
         if self.ctx.get_cluster().is_empty() {
-            let operator = DataOperator::instance().operator();
+            let operator = DataOperator::instance()
+                .operator()
+                .layer(StorageMetricsLayer::new(self.ctx.get_spill_metrics()));
             let location_prefix = format!("_aggregate_spill/{}", self.ctx.get_tenant());
             self.main_pipeline.add_transform(|input, output| {
                 let transform = match params.aggregate_functions.is_empty() {
@@ -910,14 +913,23 @@ impl PipelineBuilder {
         }
 
         let tenant = self.ctx.get_tenant();
+        let spill_metrics = self.ctx.get_spill_metrics();
         self.exchange_injector = match params.aggregate_functions.is_empty() {
             true => with_mappedhash_method!(|T| match method.clone() {
-                HashMethodKind::T(method) =>
-                    AggregateInjector::<_, ()>::create(tenant.clone(), method, params.clone()),
+                HashMethodKind::T(method) => AggregateInjector::<_, ()>::create(
+                    tenant.clone(),
+                    spill_metrics.clone(),
+                    method,
+                    params.clone()
+                ),
             }),
             false => with_mappedhash_method!(|T| match method.clone() {
-                HashMethodKind::T(method) =>
-                    AggregateInjector::<_, usize>::create(tenant.clone(), method, params.clone()),
+                HashMethodKind::T(method) => AggregateInjector::<_, usize>::create(
+                    tenant.clone(),
+                    spill_metrics.clone(),
+                    method,
+                    params.clone()
+                ),
             }),
         };
 
@@ -974,6 +986,7 @@ impl PipelineBuilder {
         let method = DataBlock::choose_hash_method(&sample_block, group_cols, efficiently_memory)?;
 
         let tenant = self.ctx.get_tenant();
+        let spill_metrics = self.ctx.get_spill_metrics();
         let old_inject = self.exchange_injector.clone();
 
         match params.aggregate_functions.is_empty() {
@@ -981,8 +994,12 @@ impl PipelineBuilder {
                 HashMethodKind::T(v) => {
                     let input: &PhysicalPlan = &aggregate.input;
                     if matches!(input, PhysicalPlan::ExchangeSource(_)) {
-                        self.exchange_injector =
-                            AggregateInjector::<_, ()>::create(tenant, v.clone(), params.clone());
+                        self.exchange_injector = AggregateInjector::<_, ()>::create(
+                            tenant,
+                            spill_metrics,
+                            v.clone(),
+                            params.clone(),
+                        );
                     }
 
                     self.build_pipeline(&aggregate.input)?;
@@ -1003,6 +1020,7 @@ impl PipelineBuilder {
                     if matches!(input, PhysicalPlan::ExchangeSource(_)) {
                         self.exchange_injector = AggregateInjector::<_, usize>::create(
                             tenant,
+                            spill_metrics,
                             v.clone(),
                             params.clone(),
                         );
@@ -27,6 +27,8 @@ use common_hashtable::HashtableLike;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::Pipeline;
 use common_storage::DataOperator;
+use common_storage::StorageMetrics;
+use common_storage::StorageMetricsLayer;
 use strength_reduce::StrengthReducedU64;
 
 use crate::api::DataExchange;
@@ -186,6 +188,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> FlightScatter
 pub struct AggregateInjector<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> {
     method: Method,
     tenant: String,
+    spill_metrics: Arc<StorageMetrics>,
     aggregator_params: Arc<AggregatorParams>,
     _phantom: PhantomData<V>,
 }
@@ -193,12 +196,14 @@ pub struct AggregateInjector<Method: HashMethodBounds, V: Copy + Send + Sync + '
 impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> AggregateInjector<Method, V> {
     pub fn create(
         tenant: String,
+        spill_metrics: Arc<StorageMetrics>,
         method: Method,
         params: Arc<AggregatorParams>,
     ) -> Arc<dyn ExchangeInjector> {
         Arc::new(AggregateInjector::<Method, V> {
             method,
             tenant,
+            spill_metrics,
             aggregator_params: params,
             _phantom: Default::default(),
         })
@@ -240,7 +245,9 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
         let method = &self.method;
         let params = self.aggregator_params.clone();
 
-        let operator = DataOperator::instance().operator();
+        let operator = DataOperator::instance()
+            .operator()
+            .layer(StorageMetricsLayer::new(self.spill_metrics.clone()));
         let location_prefix = format!("_aggregate_spill/{}", self.tenant);
 
         pipeline.add_transform(|input, output| {
@@ -285,7 +292,9 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
     ) -> Result<()> {
         let method = &self.method;
         let params = self.aggregator_params.clone();
-        let operator = DataOperator::instance().operator();
+        let operator = DataOperator::instance()
+            .operator()
+            .layer(StorageMetricsLayer::new(self.spill_metrics.clone()));
         let location_prefix = format!("_aggregate_spill/{}", self.tenant);
 
         pipeline.add_transform(|input, output| {
@@ -18,6 +18,7 @@ mod fuse_block;
 mod fuse_column;
 mod fuse_segment;
 mod fuse_snapshot;
+mod rotate_encryption_key;
 mod search_tables;
 mod system;
 
@@ -26,5 +27,6 @@ pub use fuse_block::FuseBlockProcedure;
 pub use fuse_column::FuseColumnProcedure;
 pub use fuse_segment::FuseSegmentProcedure;
 pub use fuse_snapshot::FuseSnapshotProcedure;
+pub use rotate_encryption_key::RotateEncryptionKeyProcedure;
 pub use search_tables::SearchTablesProcedure;
 pub use system::SystemProcedure;
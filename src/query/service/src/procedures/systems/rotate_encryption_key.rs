@@ -0,0 +1,117 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRefExt;
+use common_expression::FromData;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_storage::MasterKeys;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION_DATA_KEY;
+
+use crate::procedures::OneBlockProcedure;
+use crate::procedures::Procedure;
+use crate::procedures::ProcedureFeatures;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Re-wrap the data key of an encrypted table by the current master key.
+///
+/// The data key itself is kept, so the objects already written stay readable without being
+/// rewritten, and the old master key can be retired once all the tables are rotated.
+pub struct RotateEncryptionKeyProcedure {}
+
+impl RotateEncryptionKeyProcedure {
+    pub fn try_create() -> Result<Box<dyn Procedure>> {
+        Ok(RotateEncryptionKeyProcedure {}.into_procedure())
+    }
+}
+
+#[async_trait::async_trait]
+impl OneBlockProcedure for RotateEncryptionKeyProcedure {
+    fn name(&self) -> &str {
+        "ROTATE_ENCRYPTION_KEY"
+    }
+
+    fn features(&self) -> ProcedureFeatures {
+        ProcedureFeatures::default().num_arguments(2)
+    }
+
+    #[async_backtrace::framed]
+    async fn all_data(&self, ctx: Arc<QueryContext>, args: Vec<String>) -> Result<DataBlock> {
+        assert_eq!(args.len(), 2);
+        let database_name = args[0].clone();
+        let table_name = args[1].clone();
+        let tenant_id = ctx.get_tenant();
+        let catalog = ctx.get_catalog(&ctx.get_current_catalog())?;
+        let tbl = catalog
+            .get_table(
+                tenant_id.as_str(),
+                database_name.as_str(),
+                table_name.as_str(),
+            )
+            .await?;
+
+        let wrapped_key = tbl
+            .options()
+            .get(OPT_KEY_ENCRYPTION_DATA_KEY)
+            .ok_or_else(|| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "table {}.{} is not encrypted",
+                    database_name, table_name
+                ))
+            })?;
+        let old_key_id = wrapped_key
+            .split_once(':')
+            .map(|(key_id, _)| key_id.to_string())
+            .unwrap_or_default();
+
+        let master_keys = MasterKeys::instance();
+        let rewrapped_key = master_keys.rewrap_data_key(wrapped_key)?;
+
+        let mut options = HashMap::new();
+        options.insert(OPT_KEY_ENCRYPTION_DATA_KEY.to_string(), Some(rewrapped_key));
+        let req = UpsertTableOptionReq {
+            table_id: tbl.get_id(),
+            seq: MatchSeq::Exact(tbl.get_table_info().ident.seq),
+            options,
+        };
+        catalog
+            .upsert_table_option(tenant_id.as_str(), database_name.as_str(), req)
+            .await?;
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![table_name.as_bytes().to_vec()]),
+            StringType::from_data(vec![old_key_id.as_bytes().to_vec()]),
+            StringType::from_data(vec![master_keys.current_key_id().as_bytes().to_vec()]),
+        ]))
+    }
+
+    fn schema(&self) -> Arc<DataSchema> {
+        DataSchemaRefExt::create(vec![
+            DataField::new("table", DataType::String),
+            DataField::new("old_master_key_id", DataType::String),
+            DataField::new("new_master_key_id", DataType::String),
+        ])
+    }
+}
@@ -18,6 +18,7 @@ use crate::procedures::systems::ClusteringInformationProcedure;
 use crate::procedures::systems::FuseBlockProcedure;
 use crate::procedures::systems::FuseSegmentProcedure;
 use crate::procedures::systems::FuseSnapshotProcedure;
+use crate::procedures::systems::RotateEncryptionKeyProcedure;
 use crate::procedures::systems::SearchTablesProcedure;
 use crate::procedures::ProcedureFactory;
 
@@ -49,6 +50,10 @@ impl SystemProcedure {
             "system$search_tables",
             Box::new(SearchTablesProcedure::try_create),
         );
+        factory.register(
+            "system$rotate_encryption_key",
+            Box::new(RotateEncryptionKeyProcedure::try_create),
+        );
         factory.register(
             "system$execute_background_job",
             Box::new(ExecuteJobProcedure::try_create),
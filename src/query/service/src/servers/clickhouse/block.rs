@@ -0,0 +1,419 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::number::F32;
+use common_expression::types::number::F64;
+use common_expression::types::DataType;
+use common_expression::types::DecimalSize;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use ethnum::i256;
+
+use crate::servers::clickhouse::protocol::put_binary;
+use crate::servers::clickhouse::protocol::put_string;
+use crate::servers::clickhouse::protocol::put_varuint;
+use crate::servers::clickhouse::protocol::Cursor;
+use crate::servers::clickhouse::protocol::DecodeResult;
+use crate::servers::clickhouse::types::NativeType;
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+
+/// Write a block in the native format, the columns are named by `schema`.
+pub fn write_block(out: &mut Vec<u8>, schema: &DataSchema, block: &DataBlock) -> Result<()> {
+    write_block_info(out);
+    let block = block.convert_to_full();
+    put_varuint(out, block.num_columns() as u64);
+    put_varuint(out, block.num_rows() as u64);
+
+    for (field, entry) in schema.fields().iter().zip(block.columns()) {
+        let native_type = NativeType::from_data_type(field.data_type());
+        put_string(out, field.name());
+        put_string(out, &native_type.to_string());
+        if block.num_rows() > 0 {
+            let column = entry.value.as_column().ok_or_else(|| {
+                ErrorCode::Internal(format!("column {} of the block is not full", field.name()))
+            })?;
+            let values = column.iter().collect::<Vec<_>>();
+            write_column(out, &native_type, &values);
+        }
+    }
+    Ok(())
+}
+
+/// Read a block in the native format, along with the names and types of its columns.
+pub fn read_block(cursor: &mut Cursor) -> DecodeResult<(DataSchema, DataBlock)> {
+    read_block_info(cursor)?;
+    let num_columns = cursor.varuint()? as usize;
+    let num_rows = cursor.varuint()? as usize;
+
+    let mut fields = Vec::with_capacity(num_columns);
+    let mut entries = Vec::with_capacity(num_columns);
+    for _ in 0..num_columns {
+        let name = cursor.string()?;
+        let native_type = NativeType::parse(&cursor.string()?)?;
+        let data_type = native_type.to_data_type()?;
+        let values = read_column(cursor, &native_type, num_rows)?;
+        let column = build_column(&data_type, &values);
+        fields.push(DataField::new(&name, data_type.clone()));
+        entries.push(BlockEntry::new(data_type, Value::Column(column)));
+    }
+    Ok((DataSchema::new(fields), DataBlock::new(entries, num_rows)))
+}
+
+fn write_block_info(out: &mut Vec<u8>) {
+    // field 1: is_overflows
+    put_varuint(out, 1);
+    out.push(0);
+    // field 2: bucket_num
+    put_varuint(out, 2);
+    out.extend_from_slice(&(-1i32).to_le_bytes());
+    // end of the fields
+    put_varuint(out, 0);
+}
+
+fn read_block_info(cursor: &mut Cursor) -> DecodeResult<()> {
+    loop {
+        match cursor.varuint()? {
+            0 => return Ok(()),
+            1 => {
+                cursor.u8()?;
+            }
+            2 => {
+                cursor.bytes(4)?;
+            }
+            field => {
+                return Err(
+                    ErrorCode::BadBytes(format!("unknown field {} of block info", field)).into(),
+                );
+            }
+        }
+    }
+}
+
+fn build_column(data_type: &DataType, values: &[Scalar]) -> Column {
+    let mut builder = ColumnBuilder::with_capacity(data_type, values.len());
+    for value in values {
+        builder.push(value.as_ref());
+    }
+    builder.build()
+}
+
+fn decimal_width(precision: u8) -> usize {
+    match precision {
+        0..=9 => 4,
+        10..=18 => 8,
+        19..=38 => 16,
+        _ => 32,
+    }
+}
+
+fn fixed_width(native_type: &NativeType) -> usize {
+    match native_type {
+        NativeType::Nothing | NativeType::Bool | NativeType::UInt8 | NativeType::Int8 => 1,
+        NativeType::UInt16 | NativeType::Int16 | NativeType::Date => 2,
+        NativeType::UInt32
+        | NativeType::Int32
+        | NativeType::Float32
+        | NativeType::Date32
+        | NativeType::DateTime => 4,
+        NativeType::UInt64
+        | NativeType::Int64
+        | NativeType::Float64
+        | NativeType::DateTime64(_) => 8,
+        NativeType::Decimal(precision, _) => decimal_width(*precision),
+        NativeType::FixedString(n) => *n,
+        _ => 0,
+    }
+}
+
+/// Write the values of a column, the values of the composite types are written
+/// as the nested columns.
+pub fn write_column(out: &mut Vec<u8>, native_type: &NativeType, values: &[ScalarRef]) {
+    match native_type {
+        NativeType::Nullable(inner) => {
+            for value in values {
+                out.push(matches!(value, ScalarRef::Null) as u8);
+            }
+            write_column(out, inner, values);
+        }
+        NativeType::Array(inner) => {
+            let mut offset = 0u64;
+            let mut items = vec![];
+            for value in values {
+                if let ScalarRef::Array(column) = value {
+                    items.extend(column.iter());
+                    offset += column.len() as u64;
+                }
+                out.extend_from_slice(&offset.to_le_bytes());
+            }
+            write_column(out, inner, &items);
+        }
+        NativeType::Map(key, value_type) => {
+            let mut offset = 0u64;
+            let mut keys = vec![];
+            let mut vals = vec![];
+            for value in values {
+                if let ScalarRef::Map(column) = value {
+                    for entry in column.iter() {
+                        if let ScalarRef::Tuple(mut kv) = entry {
+                            let v = kv.pop().unwrap_or_default();
+                            let k = kv.pop().unwrap_or_default();
+                            keys.push(k);
+                            vals.push(v);
+                            offset += 1;
+                        }
+                    }
+                }
+                out.extend_from_slice(&offset.to_le_bytes());
+            }
+            write_column(out, key, &keys);
+            write_column(out, value_type, &vals);
+        }
+        NativeType::Tuple(fields) => {
+            for (i, field) in fields.iter().enumerate() {
+                let items = values
+                    .iter()
+                    .map(|value| match value {
+                        ScalarRef::Tuple(items) => items.get(i).cloned().unwrap_or_default(),
+                        _ => ScalarRef::Null,
+                    })
+                    .collect::<Vec<_>>();
+                write_column(out, field, &items);
+            }
+        }
+        NativeType::String => {
+            for value in values {
+                match value {
+                    ScalarRef::Null => put_binary(out, b""),
+                    ScalarRef::String(s) => put_binary(out, s),
+                    ScalarRef::Variant(v) => put_string(out, &jsonb::to_string(v)),
+                    other => put_string(out, &other.to_string()),
+                }
+            }
+        }
+        _ => {
+            for value in values {
+                write_value(out, native_type, value);
+            }
+        }
+    }
+}
+
+fn write_value(out: &mut Vec<u8>, native_type: &NativeType, value: &ScalarRef) {
+    match (native_type, value) {
+        (NativeType::Bool, ScalarRef::Boolean(v)) => out.push(*v as u8),
+        (_, ScalarRef::Number(number)) => match number {
+            NumberScalar::UInt8(v) => out.push(*v),
+            NumberScalar::UInt16(v) => out.extend_from_slice(&v.to_le_bytes()),
+            NumberScalar::UInt32(v) => out.extend_from_slice(&v.to_le_bytes()),
+            NumberScalar::UInt64(v) => out.extend_from_slice(&v.to_le_bytes()),
+            NumberScalar::Int8(v) => out.extend_from_slice(&v.to_le_bytes()),
+            NumberScalar::Int16(v) => out.extend_from_slice(&v.to_le_bytes()),
+            NumberScalar::Int32(v) => out.extend_from_slice(&v.to_le_bytes()),
+            NumberScalar::Int64(v) => out.extend_from_slice(&v.to_le_bytes()),
+            NumberScalar::Float32(v) => out.extend_from_slice(&v.0.to_le_bytes()),
+            NumberScalar::Float64(v) => out.extend_from_slice(&v.0.to_le_bytes()),
+        },
+        (NativeType::Decimal(precision, _), ScalarRef::Decimal(decimal)) => {
+            let width = decimal_width(*precision);
+            let bytes = match decimal {
+                DecimalScalar::Decimal128(v, _) => i256::from(*v).to_le_bytes(),
+                DecimalScalar::Decimal256(v, _) => v.to_le_bytes(),
+            };
+            // The values are two's complement, so truncating keeps the sign.
+            out.extend_from_slice(&bytes[..width]);
+        }
+        (NativeType::Date32, ScalarRef::Date(days)) => out.extend_from_slice(&days.to_le_bytes()),
+        (NativeType::DateTime64(precision), ScalarRef::Timestamp(micros)) => {
+            let ticks = rescale(*micros, 6, *precision);
+            out.extend_from_slice(&ticks.to_le_bytes());
+        }
+        (NativeType::FixedString(n), ScalarRef::String(s)) => {
+            let len = s.len().min(*n);
+            out.extend_from_slice(&s[..len]);
+            out.resize(out.len() + n - len, 0);
+        }
+        // Nulls are written as the default value of the type.
+        _ => out.resize(out.len() + fixed_width(native_type), 0),
+    }
+}
+
+fn rescale(value: i64, from: u32, to: u32) -> i64 {
+    if from >= to {
+        value / 10i64.pow(from - to)
+    } else {
+        value.saturating_mul(10i64.pow(to - from))
+    }
+}
+
+/// Read `rows` values of a column of `native_type`.
+pub fn read_column(
+    cursor: &mut Cursor,
+    native_type: &NativeType,
+    rows: usize,
+) -> DecodeResult<Vec<Scalar>> {
+    let mut values = Vec::with_capacity(rows);
+    match native_type {
+        NativeType::Nullable(inner) => {
+            let nulls = cursor.bytes(rows)?;
+            let inner_values = read_column(cursor, inner, rows)?;
+            for (is_null, value) in nulls.iter().zip(inner_values) {
+                values.push(if *is_null != 0 { Scalar::Null } else { value });
+            }
+        }
+        NativeType::Array(inner) => {
+            let offsets = read_offsets(cursor, rows)?;
+            let items = read_column(cursor, inner, *offsets.last().unwrap_or(&0))?;
+            let item_type = inner.to_data_type()?;
+            let mut start = 0;
+            for end in offsets {
+                values.push(Scalar::Array(build_column(&item_type, &items[start..end])));
+                start = end;
+            }
+        }
+        NativeType::Map(key, value_type) => {
+            let offsets = read_offsets(cursor, rows)?;
+            let len = *offsets.last().unwrap_or(&0);
+            let keys = read_column(cursor, key, len)?;
+            let vals = read_column(cursor, value_type, len)?;
+            let entry_type = DataType::Tuple(vec![key.to_data_type()?, value_type.to_data_type()?]);
+            let entries = keys
+                .into_iter()
+                .zip(vals)
+                .map(|(k, v)| Scalar::Tuple(vec![k, v]))
+                .collect::<Vec<_>>();
+            let mut start = 0;
+            for end in offsets {
+                values.push(Scalar::Map(build_column(&entry_type, &entries[start..end])));
+                start = end;
+            }
+        }
+        NativeType::Tuple(fields) => {
+            let mut rows_of_fields = vec![Vec::with_capacity(fields.len()); rows];
+            for field in fields {
+                for (row, value) in read_column(cursor, field, rows)?.into_iter().enumerate() {
+                    rows_of_fields[row].push(value);
+                }
+            }
+            values.extend(rows_of_fields.into_iter().map(Scalar::Tuple));
+        }
+        NativeType::String => {
+            for _ in 0..rows {
+                values.push(Scalar::String(cursor.binary()?.to_vec()));
+            }
+        }
+        _ => {
+            let width = fixed_width(native_type);
+            for _ in 0..rows {
+                values.push(read_value(native_type, cursor.bytes(width)?)?);
+            }
+        }
+    }
+    Ok(values)
+}
+
+fn read_offsets(cursor: &mut Cursor, rows: usize) -> DecodeResult<Vec<usize>> {
+    let mut offsets = Vec::with_capacity(rows);
+    let mut last = 0;
+    for _ in 0..rows {
+        let offset = u64::from_le_bytes(cursor.array::<8>()?) as usize;
+        if offset < last {
+            return Err(ErrorCode::BadBytes("offsets of the column are not monotonic").into());
+        }
+        offsets.push(offset);
+        last = offset;
+    }
+    Ok(offsets)
+}
+
+fn read_value(native_type: &NativeType, bytes: &[u8]) -> DecodeResult<Scalar> {
+    if bytes.len() != fixed_width(native_type) {
+        return Err(ErrorCode::BadBytes(format!(
+            "expect {} bytes for a value of {}, got {}",
+            fixed_width(native_type),
+            native_type,
+            bytes.len()
+        ))
+        .into());
+    }
+
+    macro_rules! le {
+        ($ty:ty) => {
+            <$ty>::from_le_bytes(bytes.try_into().map_err(|_| {
+                ErrorCode::BadBytes(format!("invalid bytes for a value of {}", native_type))
+            })?)
+        };
+    }
+
+    let value = match native_type {
+        NativeType::Nothing => Scalar::Null,
+        NativeType::Bool => Scalar::Boolean(bytes[0] != 0),
+        NativeType::UInt8 => Scalar::Number(NumberScalar::UInt8(bytes[0])),
+        NativeType::UInt16 => Scalar::Number(NumberScalar::UInt16(le!(u16))),
+        NativeType::UInt32 => Scalar::Number(NumberScalar::UInt32(le!(u32))),
+        NativeType::UInt64 => Scalar::Number(NumberScalar::UInt64(le!(u64))),
+        NativeType::Int8 => Scalar::Number(NumberScalar::Int8(bytes[0] as i8)),
+        NativeType::Int16 => Scalar::Number(NumberScalar::Int16(le!(i16))),
+        NativeType::Int32 => Scalar::Number(NumberScalar::Int32(le!(i32))),
+        NativeType::Int64 => Scalar::Number(NumberScalar::Int64(le!(i64))),
+        NativeType::Float32 => Scalar::Number(NumberScalar::Float32(F32::from(le!(f32)))),
+        NativeType::Float64 => Scalar::Number(NumberScalar::Float64(F64::from(le!(f64)))),
+        NativeType::Decimal(precision, scale) => {
+            // Sign extend the value to 256 bits.
+            let fill = if bytes[bytes.len() - 1] & 0x80 != 0 {
+                0xff
+            } else {
+                0
+            };
+            let mut buf = [fill; 32];
+            buf[..bytes.len()].copy_from_slice(bytes);
+            let value = i256::from_le_bytes(buf);
+            let size = DecimalSize {
+                precision: *precision,
+                scale: *scale,
+            };
+            if *precision <= 38 {
+                Scalar::Decimal(DecimalScalar::Decimal128(value.as_i128(), size))
+            } else {
+                Scalar::Decimal(DecimalScalar::Decimal256(value, size))
+            }
+        }
+        NativeType::FixedString(_) => Scalar::String(bytes.to_vec()),
+        NativeType::Date => Scalar::Date(le!(u16) as i32),
+        NativeType::Date32 => Scalar::Date(le!(i32)),
+        NativeType::DateTime => Scalar::Timestamp(le!(u32) as i64 * MICROS_PER_SECOND),
+        NativeType::DateTime64(precision) => Scalar::Timestamp(rescale(le!(i64), *precision, 6)),
+        NativeType::String
+        | NativeType::Nullable(_)
+        | NativeType::Array(_)
+        | NativeType::Map(_, _)
+        | NativeType::Tuple(_) => {
+            return Err(
+                ErrorCode::BadBytes(format!("{} is not a fixed width type", native_type)).into(),
+            );
+        }
+    };
+    Ok(value)
+}
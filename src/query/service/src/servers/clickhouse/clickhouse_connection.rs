@@ -0,0 +1,633 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::net::Shutdown;
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::net::tcp::OwnedReadHalf;
+use common_base::base::tokio::net::tcp::OwnedWriteHalf;
+use common_base::base::tokio::net::TcpStream;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::SendableDataBlockStream;
+use common_formats::ClickhouseFormatType;
+use common_formats::FileFormatOptionsExt;
+use common_pipeline_sources::input_formats::InputContext;
+use common_pipeline_sources::input_formats::StreamingReadBatch;
+use common_sql::plans::InsertInputSource;
+use common_sql::plans::Plan;
+use common_sql::Planner;
+use futures_util::StreamExt;
+use log::error;
+use log::info;
+
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::InterpreterQueryLog;
+use crate::servers::clickhouse::block::read_block;
+use crate::servers::clickhouse::block::write_block;
+use crate::servers::clickhouse::compression::compress_block;
+use crate::servers::clickhouse::compression::decompress_frame;
+use crate::servers::clickhouse::protocol::client_packet;
+use crate::servers::clickhouse::protocol::encode_exception;
+use crate::servers::clickhouse::protocol::put_string;
+use crate::servers::clickhouse::protocol::put_varuint;
+use crate::servers::clickhouse::protocol::server_packet;
+use crate::servers::clickhouse::protocol::ClientHello;
+use crate::servers::clickhouse::protocol::ClientQuery;
+use crate::servers::clickhouse::protocol::Cursor;
+use crate::servers::clickhouse::protocol::DecodeError;
+use crate::servers::clickhouse::protocol::DecodeResult;
+use crate::servers::clickhouse::protocol::Progress;
+use crate::servers::clickhouse::protocol::ServerHello;
+use crate::servers::clickhouse::protocol::DBMS_TCP_PROTOCOL_VERSION;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+// Flush the result blocks to the client once the buffer is larger than 1MB.
+const RESULT_FLUSH_SIZE: usize = 1024 * 1024;
+
+// The codes of ClickHouse exceptions, which are understood by the drivers.
+const UNKNOWN_TABLE: i32 = 60;
+const SYNTAX_ERROR: i32 = 62;
+const UNKNOWN_DATABASE: i32 = 81;
+const QUERY_WAS_CANCELLED: i32 = 394;
+const ACCESS_DENIED: i32 = 497;
+const AUTHENTICATION_FAILED: i32 = 516;
+const UNKNOWN_EXCEPTION: i32 = 1002;
+
+enum ClientPacket {
+    Hello(ClientHello),
+    Query(ClientQuery),
+    Data(String, DataSchema, DataBlock),
+    Cancel,
+    Ping,
+}
+
+fn decode_packet(
+    cursor: &mut Cursor,
+    revision: u64,
+    compression: bool,
+) -> DecodeResult<ClientPacket> {
+    match cursor.varuint()? {
+        client_packet::HELLO => Ok(ClientPacket::Hello(ClientHello::decode(cursor)?)),
+        client_packet::QUERY => Ok(ClientPacket::Query(ClientQuery::decode(cursor, revision)?)),
+        client_packet::DATA => {
+            let table_name = cursor.string()?;
+            let (schema, block) = match compression {
+                false => read_block(cursor)?,
+                // A block may span several compressed frames.
+                true => {
+                    let mut plain = vec![];
+                    loop {
+                        decompress_frame(cursor, &mut plain)?;
+                        match read_block(&mut Cursor::new(&plain)) {
+                            Err(DecodeError::Incomplete) => continue,
+                            result => break result?,
+                        }
+                    }
+                }
+            };
+            Ok(ClientPacket::Data(table_name, schema, block))
+        }
+        client_packet::CANCEL => Ok(ClientPacket::Cancel),
+        client_packet::PING => Ok(ClientPacket::Ping),
+        packet => Err(ErrorCode::BadBytes(format!("unknown packet type {}", packet)).into()),
+    }
+}
+
+fn exception_code(error: &ErrorCode) -> i32 {
+    match error.code() {
+        ErrorCode::UNKNOWN_TABLE => UNKNOWN_TABLE,
+        ErrorCode::SYNTAX_EXCEPTION => SYNTAX_ERROR,
+        ErrorCode::UNKNOWN_DATABASE => UNKNOWN_DATABASE,
+        ErrorCode::ABORTED_QUERY => QUERY_WAS_CANCELLED,
+        ErrorCode::PERMISSION_DENIED => ACCESS_DENIED,
+        ErrorCode::AUTHENTICATE_FAILURE => AUTHENTICATION_FAILED,
+        _ => UNKNOWN_EXCEPTION,
+    }
+}
+
+/// The prefix of `INSERT ... VALUES` or `INSERT ... FORMAT <name>` without inline data,
+/// the data of which is sent by the client as data packets.
+fn insert_prefix(query: &str) -> Option<&str> {
+    let query = query.trim().trim_end_matches(';').trim_end();
+    let first = query.split_whitespace().next()?;
+    if !first.eq_ignore_ascii_case("INSERT") {
+        return None;
+    }
+
+    let (rest, last) = query.rsplit_once(char::is_whitespace)?;
+    if last.eq_ignore_ascii_case("VALUES") {
+        return Some(rest.trim_end());
+    }
+    let (prefix, keyword) = rest.trim_end().rsplit_once(char::is_whitespace)?;
+    if keyword.eq_ignore_ascii_case("FORMAT") {
+        return Some(prefix.trim_end());
+    }
+    None
+}
+
+pub struct ClickHouseConnection {
+    session: Arc<Session>,
+    client_ip: Option<String>,
+    reader: OwnedReadHalf,
+    writer: OwnedWriteHalf,
+    input: Vec<u8>,
+    out: Vec<u8>,
+    revision: u64,
+    compression: bool,
+}
+
+impl ClickHouseConnection {
+    #[async_backtrace::framed]
+    pub async fn run_on_stream(mut stream: TcpStream) -> Result<()> {
+        let session = match SessionManager::instance()
+            .create_session(SessionType::Clickhouse)
+            .await
+        {
+            Ok(session) => session,
+            Err(error) => {
+                let mut out = vec![];
+                encode_exception(
+                    &mut out,
+                    exception_code(&error),
+                    "DB::Exception",
+                    &error.message(),
+                );
+                stream.write_all(&out).await?;
+                return Ok(());
+            }
+        };
+
+        let stream = Self::attach_session(&session, stream)?;
+        let client_ip = stream.peer_addr().ok().map(|addr| addr.ip().to_string());
+        let (reader, writer) = stream.into_split();
+        let mut connection = ClickHouseConnection {
+            session,
+            client_ip,
+            reader,
+            writer,
+            input: vec![],
+            out: vec![],
+            revision: DBMS_TCP_PROTOCOL_VERSION,
+            compression: false,
+        };
+        connection.run().await
+    }
+
+    fn attach_session(session: &Arc<Session>, stream: TcpStream) -> Result<TcpStream> {
+        let stream = stream.into_std()?;
+        let stream_ref = stream.try_clone()?;
+        session.attach(stream.peer_addr().ok(), move || {
+            if let Err(error) = stream_ref.shutdown(Shutdown::Both) {
+                error!("Cannot shutdown ClickHouse session io {}", error);
+            }
+        });
+        Ok(TcpStream::from_std(stream)?)
+    }
+
+    #[async_backtrace::framed]
+    async fn run(&mut self) -> Result<()> {
+        if !self.handshake().await? {
+            return Ok(());
+        }
+
+        loop {
+            let packet = match self.read_packet().await? {
+                None => return Ok(()),
+                Some(packet) => packet,
+            };
+
+            match packet {
+                ClientPacket::Query(query) => {
+                    if let Err(error) = self.query(query).await {
+                        self.write_exception(&error);
+                    }
+                    self.compression = false;
+                }
+                ClientPacket::Ping => put_varuint(&mut self.out, server_packet::PONG),
+                // Nothing is running, or the data packets belong to a failed query.
+                ClientPacket::Cancel | ClientPacket::Data(..) => {}
+                ClientPacket::Hello(_) => {
+                    return Err(ErrorCode::BadBytes("unexpected hello packet"));
+                }
+            }
+            self.flush().await?;
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn handshake(&mut self) -> Result<bool> {
+        let hello = match self.read_packet().await? {
+            None => return Ok(false),
+            Some(ClientPacket::Hello(hello)) => hello,
+            Some(_) => return Err(ErrorCode::BadBytes("the first packet must be hello")),
+        };
+        info!(
+            "ClickHouse client {} {}.{} (revision {}) connected",
+            hello.client_name, hello.version_major, hello.version_minor, hello.revision
+        );
+        self.revision = hello.revision.min(DBMS_TCP_PROTOCOL_VERSION);
+
+        let credential = Credential::Password {
+            name: hello.user.clone(),
+            password: Some(hello.password.into_bytes()),
+            client_ip: self.client_ip.clone(),
+        };
+        if let Err(error) = AuthMgr::instance()
+            .auth(self.session.clone(), &credential)
+            .await
+        {
+            info!(
+                "ClickHouse authenticate failed for user {}: {}",
+                hello.user, error
+            );
+            encode_exception(
+                &mut self.out,
+                AUTHENTICATION_FAILED,
+                "DB::Exception",
+                &format!(
+                    "{}: Authentication failed: password is incorrect or there is no user with such name",
+                    hello.user
+                ),
+            );
+            self.flush().await?;
+            return Ok(false);
+        }
+
+        if !hello.database.is_empty() && hello.database != "default" {
+            let query = format!("USE `{}`", hello.database.replace('`', "``"));
+            if let Err(error) = self.run_to_end(&query).await {
+                self.write_exception(&error);
+                self.flush().await?;
+                return Ok(false);
+            }
+        }
+
+        let timezone = self.session.get_settings().get_timezone()?;
+        ServerHello {
+            name: "Databend",
+            timezone: &timezone,
+            display_name: "Databend",
+        }
+        .encode(&mut self.out, self.revision);
+        self.flush().await?;
+        Ok(true)
+    }
+
+    #[async_backtrace::framed]
+    async fn query(&mut self, query: ClientQuery) -> Result<()> {
+        info!("ClickHouse query: {}", query.query);
+        self.compression = query.compression;
+        let settings = query.settings.into_iter().collect::<HashMap<_, _>>();
+        self.session.get_settings().set_batch_settings(&settings)?;
+
+        // The external tables are sent before the query, which end with an empty block.
+        loop {
+            match self.read_packet().await? {
+                None => return Ok(()),
+                Some(ClientPacket::Data(name, _, block)) => {
+                    if block.num_columns() == 0 {
+                        break;
+                    }
+                    if !name.is_empty() {
+                        return Err(ErrorCode::Unimplemented(
+                            "external tables are not supported",
+                        ));
+                    }
+                }
+                Some(ClientPacket::Ping) => put_varuint(&mut self.out, server_packet::PONG),
+                Some(ClientPacket::Cancel) => {
+                    put_varuint(&mut self.out, server_packet::END_OF_STREAM);
+                    return Ok(());
+                }
+                Some(_) => return Err(ErrorCode::BadBytes("unexpected packet before data")),
+            }
+        }
+
+        if let Some(prefix) = insert_prefix(&query.query) {
+            return self.insert(prefix).await;
+        }
+
+        let (context, plan) = self.plan(&query.query).await?;
+        let interpreter = Self::interpreter(&context, &plan).await?;
+        let mut stream = Self::execute(&context, interpreter.clone()).await?;
+        let schema = interpreter.schema();
+        if plan.has_result_set() {
+            // The header block tells the client the names and types of the columns.
+            self.write_data(&schema, &DataBlock::empty_with_schema(schema.clone()))?;
+            self.flush().await?;
+            while let Some(block) = stream.next().await {
+                let block = block?;
+                if block.num_rows() > 0 {
+                    self.write_data(&schema, &block)?;
+                }
+                if self.out.len() >= RESULT_FLUSH_SIZE {
+                    self.flush().await?;
+                }
+                if self.poll_cancel()? {
+                    self.session.force_kill_query(ErrorCode::AbortedQuery(
+                        "Query was cancelled by the client",
+                    ));
+                    break;
+                }
+            }
+        } else {
+            while let Some(block) = stream.next().await {
+                block?;
+            }
+        }
+
+        self.write_progress(&context);
+        put_varuint(&mut self.out, server_packet::END_OF_STREAM);
+        Ok(())
+    }
+
+    /// Insert the data blocks sent by the client, by converting them to TSV
+    /// and streaming them into the insert as the clickhouse http handler does.
+    #[async_backtrace::framed]
+    async fn insert(&mut self, prefix: &str) -> Result<()> {
+        let query = format!("{} FORMAT TSV", prefix);
+        let (context, mut plan) = self.plan(&query).await?;
+        let schema = plan.schema();
+        let (tx, table_schema) = match &mut plan {
+            Plan::Insert(insert) => match &mut insert.source {
+                InsertInputSource::StreamingWithFormat(format, _, input_context_ref) => {
+                    let (tx, rx) = tokio::sync::mpsc::channel(2);
+                    let to_table = context
+                        .get_table(&insert.catalog, &insert.database, &insert.table)
+                        .await?;
+                    let input_context = InputContext::try_create_from_insert_clickhouse(
+                        format.as_str(),
+                        rx,
+                        context.get_settings(),
+                        insert.schema.clone(),
+                        context.get_scan_progress(),
+                        to_table.get_block_thresholds(),
+                    )
+                    .await?;
+                    *input_context_ref = Some(Arc::new(input_context));
+                    (tx, insert.schema.clone())
+                }
+                _ => return Err(ErrorCode::BadArguments("unexpected source of the insert")),
+            },
+            _ => return Err(ErrorCode::BadArguments("expected an insert statement")),
+        };
+
+        let interpreter = Self::interpreter(&context, &plan).await?;
+        let handle = context.try_spawn({
+            let context = context.clone();
+            async move {
+                let mut stream = interpreter.execute(context).await?;
+                while let Some(block) = stream.next().await {
+                    block?;
+                }
+                Ok::<_, ErrorCode>(())
+            }
+        })?;
+
+        // The header block tells the client the columns to send.
+        self.write_data(&schema, &DataBlock::empty_with_schema(schema.clone()))?;
+        self.flush().await?;
+
+        let mut output_format = FileFormatOptionsExt::get_output_format_from_clickhouse_format(
+            ClickhouseFormatType::parse_clickhouse_format("TSV")?,
+            table_schema,
+            &context.get_settings(),
+        )?;
+        let mut is_start = true;
+        // Keep reading the data until the end even if the insert fails,
+        // so that the connection stays usable.
+        let mut sending = true;
+        loop {
+            match self.read_packet().await? {
+                None => break,
+                Some(ClientPacket::Data(_, _, block)) => {
+                    if block.num_columns() == 0 {
+                        break;
+                    }
+                    if !sending || block.num_rows() == 0 {
+                        continue;
+                    }
+                    if block.num_columns() != schema.num_fields() {
+                        sending = false;
+                        self.session
+                            .force_kill_query(ErrorCode::BadArguments(format!(
+                                "expected {} columns to insert, got {}",
+                                schema.num_fields(),
+                                block.num_columns()
+                            )));
+                        continue;
+                    }
+                    let batch = StreamingReadBatch {
+                        data: output_format.serialize_block(&block)?,
+                        path: "clickhouse_insert".to_string(),
+                        is_start,
+                        compression: None,
+                    };
+                    is_start = false;
+                    // The insert has stopped, the error is returned by the handle.
+                    sending = tx.send(Ok(batch)).await.is_ok();
+                }
+                Some(ClientPacket::Ping) => {
+                    put_varuint(&mut self.out, server_packet::PONG);
+                    self.flush().await?;
+                }
+                Some(ClientPacket::Cancel) => {
+                    self.session.force_kill_query(ErrorCode::AbortedQuery(
+                        "Query was cancelled by the client",
+                    ));
+                    break;
+                }
+                Some(ClientPacket::Hello(_) | ClientPacket::Query(_)) => {
+                    return Err(ErrorCode::BadBytes("unexpected packet during insert"));
+                }
+            }
+        }
+        drop(tx);
+
+        handle.await.map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot join handle from context's runtime",
+        )??;
+
+        self.write_progress(&context);
+        put_varuint(&mut self.out, server_packet::END_OF_STREAM);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn plan(&self, query: &str) -> Result<(Arc<QueryContext>, Plan)> {
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_sql(query).await?;
+        context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+        Ok((context, plan))
+    }
+
+    #[async_backtrace::framed]
+    async fn interpreter(context: &Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
+        match InterpreterFactory::get(context.clone(), plan).await {
+            Ok(interpreter) => Ok(interpreter),
+            Err(error) => {
+                InterpreterQueryLog::fail_to_start(context.clone(), error.clone());
+                Err(error)
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn execute(
+        context: &Arc<QueryContext>,
+        interpreter: InterpreterPtr,
+    ) -> Result<SendableDataBlockStream> {
+        // Execute in the runtime of the query context, the executors may block the
+        // thread which is waiting for them, see `clickhouse_handler::execute`.
+        context
+            .try_spawn({
+                let context = context.clone();
+                async move { interpreter.execute(context).await }
+            })?
+            .await
+            .map_err_to_code(
+                ErrorCode::TokioError,
+                || "Cannot join handle from context's runtime",
+            )?
+    }
+
+    #[async_backtrace::framed]
+    async fn run_to_end(&self, query: &str) -> Result<()> {
+        let (context, plan) = self.plan(query).await?;
+        let interpreter = Self::interpreter(&context, &plan).await?;
+        let mut stream = Self::execute(&context, interpreter).await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+        Ok(())
+    }
+
+    /// Read the next packet, returns None if the client closed the connection.
+    #[async_backtrace::framed]
+    async fn read_packet(&mut self) -> Result<Option<ClientPacket>> {
+        loop {
+            if !self.input.is_empty() {
+                if let Some(packet) = self.decode_buffered()? {
+                    return Ok(Some(packet));
+                }
+            }
+
+            let mut buf = [0u8; 8192];
+            let n = self.reader.read(&mut buf).await?;
+            if n == 0 {
+                return Ok(None);
+            }
+            self.input.extend_from_slice(&buf[..n]);
+        }
+    }
+
+    fn decode_buffered(&mut self) -> Result<Option<ClientPacket>> {
+        let mut cursor = Cursor::new(&self.input);
+        match decode_packet(&mut cursor, self.revision, self.compression) {
+            Ok(packet) => {
+                let consumed = cursor.position();
+                self.input.drain(..consumed);
+                Ok(Some(packet))
+            }
+            Err(DecodeError::Incomplete) => Ok(None),
+            Err(DecodeError::Error(error)) => Err(error),
+        }
+    }
+
+    /// Check whether the client has cancelled the running query without blocking,
+    /// a closed connection counts as a cancel.
+    fn poll_cancel(&mut self) -> Result<bool> {
+        let mut buf = [0u8; 1024];
+        loop {
+            match self.reader.try_read(&mut buf) {
+                Ok(0) => return Ok(true),
+                Ok(n) => self.input.extend_from_slice(&buf[..n]),
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => break,
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        let mut cursor = Cursor::new(&self.input);
+        if let Ok(client_packet::CANCEL) = cursor.varuint() {
+            let consumed = cursor.position();
+            self.input.drain(..consumed);
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn write_data(&mut self, schema: &DataSchemaRef, block: &DataBlock) -> Result<()> {
+        put_varuint(&mut self.out, server_packet::DATA);
+        // The table name is never compressed.
+        put_string(&mut self.out, "");
+        let mut data = vec![];
+        write_block(&mut data, schema, block)?;
+        match self.compression {
+            true => self.out.extend_from_slice(&compress_block(data)?),
+            false => self.out.extend_from_slice(&data),
+        }
+        Ok(())
+    }
+
+    fn write_progress(&mut self, context: &Arc<QueryContext>) {
+        let scan = context.get_scan_progress_value();
+        let write = context.get_write_progress_value();
+        Progress {
+            rows: scan.rows as u64,
+            bytes: scan.bytes as u64,
+            total_rows: 0,
+            written_rows: write.rows as u64,
+            written_bytes: write.bytes as u64,
+        }
+        .encode(&mut self.out, self.revision);
+    }
+
+    fn write_exception(&mut self, error: &ErrorCode) {
+        error!("ClickHouse query error: {}", error);
+        encode_exception(
+            &mut self.out,
+            exception_code(error),
+            "DB::Exception",
+            &error.message(),
+        );
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        if !self.out.is_empty() {
+            self.writer.write_all(&self.out).await?;
+            self.writer.flush().await?;
+            self.out.clear();
+        }
+        Ok(())
+    }
+}
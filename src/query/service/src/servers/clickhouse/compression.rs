@@ -0,0 +1,110 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use naive_cityhash::cityhash128;
+
+use crate::servers::clickhouse::protocol::Cursor;
+use crate::servers::clickhouse::protocol::DecodeResult;
+
+const CHECKSUM_SIZE: usize = 16;
+// 1 byte for method, 4 bytes for compressed size, 4 bytes for uncompressed size.
+const HEADER_SIZE: usize = 9;
+
+const METHOD_NONE: u8 = 0x02;
+const METHOD_LZ4: u8 = 0x82;
+const METHOD_ZSTD: u8 = 0x90;
+
+// Larger frames are rejected rather than allocated.
+const MAX_FRAME_SIZE: usize = 1024 * 1024 * 1024;
+
+/// Compress the bytes into a frame of the ClickHouse compression format,
+/// the default codec is always lz4.
+pub fn compress_block(input: Vec<u8>) -> Result<Vec<u8>> {
+    if input.is_empty() {
+        Ok(vec![])
+    } else {
+        // TODO(youngsofun): optimize buffer usages
+        let uncompressed_size = input.len();
+        let compressed =
+            lz4::block::compress(&input, Some(lz4::block::CompressionMode::FAST(1)), false)
+                .map_err_to_code(ErrorCode::BadBytes, || "lz4 compress error")?;
+
+        let mut compressed_with_header = Vec::with_capacity(compressed.len() + HEADER_SIZE);
+        compressed_with_header.push(METHOD_LZ4);
+        let compressed_size = (compressed.len() + HEADER_SIZE) as u32;
+        let uncompressed_size = uncompressed_size as u32;
+        compressed_with_header.extend_from_slice(&compressed_size.to_le_bytes());
+        compressed_with_header.extend_from_slice(&uncompressed_size.to_le_bytes());
+        compressed_with_header.extend_from_slice(&compressed);
+
+        // 16 bytes checksum
+        let mut output = Vec::with_capacity(compressed_with_header.len() + CHECKSUM_SIZE);
+        let checksum = cityhash128(&compressed_with_header);
+        output.extend_from_slice(&checksum.lo.to_le_bytes());
+        output.extend_from_slice(&checksum.hi.to_le_bytes());
+        output.extend_from_slice(&compressed_with_header);
+        Ok(output)
+    }
+}
+
+/// Read a compressed frame from the cursor, and append the decompressed bytes to `output`.
+pub fn decompress_frame(cursor: &mut Cursor, output: &mut Vec<u8>) -> DecodeResult<()> {
+    let checksum = cursor.array::<CHECKSUM_SIZE>()?;
+    let header = cursor.array::<HEADER_SIZE>()?;
+    let compressed_size = u32::from_le_bytes([header[1], header[2], header[3], header[4]]) as usize;
+    let uncompressed_size =
+        u32::from_le_bytes([header[5], header[6], header[7], header[8]]) as usize;
+    if !(HEADER_SIZE..=MAX_FRAME_SIZE).contains(&compressed_size)
+        || uncompressed_size > MAX_FRAME_SIZE
+    {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid compressed frame, compressed size {}, uncompressed size {}",
+            compressed_size, uncompressed_size
+        ))
+        .into());
+    }
+    let data = cursor.bytes(compressed_size - HEADER_SIZE)?;
+
+    let mut frame = Vec::with_capacity(compressed_size);
+    frame.extend_from_slice(&header);
+    frame.extend_from_slice(data);
+    let hash = cityhash128(&frame);
+    if hash.lo.to_le_bytes() != checksum[..8] || hash.hi.to_le_bytes() != checksum[8..] {
+        return Err(ErrorCode::BadBytes("checksum mismatch of compressed frame").into());
+    }
+
+    match header[0] {
+        METHOD_NONE => output.extend_from_slice(data),
+        METHOD_LZ4 => {
+            let decompressed = lz4::block::decompress(data, Some(uncompressed_size as i32))
+                .map_err_to_code(ErrorCode::BadBytes, || "lz4 decompress error")?;
+            output.extend_from_slice(&decompressed);
+        }
+        METHOD_ZSTD => {
+            return Err(ErrorCode::BadBytes(
+                "zstd network compression is not supported, please use lz4",
+            )
+            .into());
+        }
+        method => {
+            return Err(
+                ErrorCode::BadBytes(format!("unknown compression method: {:#x}", method)).into(),
+            );
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,313 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Packets of the ClickHouse native TCP protocol.
+//!
+//! The fields of a packet depend on the protocol revision negotiated in the handshake,
+//! which is the smaller one of the client revision and [`DBMS_TCP_PROTOCOL_VERSION`].
+
+use common_exception::ErrorCode;
+
+// Revisions which change the layout of the packets.
+pub const DBMS_MIN_REVISION_WITH_CLIENT_INFO: u64 = 54032;
+pub const DBMS_MIN_REVISION_WITH_SERVER_TIMEZONE: u64 = 54058;
+pub const DBMS_MIN_REVISION_WITH_QUOTA_KEY_IN_CLIENT_INFO: u64 = 54060;
+pub const DBMS_MIN_REVISION_WITH_SERVER_DISPLAY_NAME: u64 = 54372;
+pub const DBMS_MIN_REVISION_WITH_VERSION_PATCH: u64 = 54401;
+pub const DBMS_MIN_REVISION_WITH_CLIENT_WRITE_INFO: u64 = 54420;
+pub const DBMS_MIN_REVISION_WITH_SETTINGS_SERIALIZED_AS_STRINGS: u64 = 54429;
+
+/// The revision supported by the server, the newer features (interserver secret,
+/// opentelemetry, custom serialization, ...) are not negotiated.
+pub const DBMS_TCP_PROTOCOL_VERSION: u64 = 54429;
+
+pub const DBMS_VERSION_MAJOR: u64 = 23;
+pub const DBMS_VERSION_MINOR: u64 = 3;
+pub const DBMS_VERSION_PATCH: u64 = 0;
+
+pub mod client_packet {
+    pub const HELLO: u64 = 0;
+    pub const QUERY: u64 = 1;
+    pub const DATA: u64 = 2;
+    pub const CANCEL: u64 = 3;
+    pub const PING: u64 = 4;
+}
+
+pub mod server_packet {
+    pub const HELLO: u64 = 0;
+    pub const DATA: u64 = 1;
+    pub const EXCEPTION: u64 = 2;
+    pub const PROGRESS: u64 = 3;
+    pub const PONG: u64 = 4;
+    pub const END_OF_STREAM: u64 = 5;
+}
+
+/// The stage of the query processing requested by the client, only `Complete` is supported.
+pub const QUERY_STAGE_COMPLETE: u64 = 2;
+
+pub const INTERFACE_TCP: u8 = 1;
+
+pub enum DecodeError {
+    /// More bytes are needed to decode the packet.
+    Incomplete,
+    Error(ErrorCode),
+}
+
+impl From<ErrorCode> for DecodeError {
+    fn from(error: ErrorCode) -> Self {
+        DecodeError::Error(error)
+    }
+}
+
+pub type DecodeResult<T> = std::result::Result<T, DecodeError>;
+
+/// Reads the primitive values of the protocol from a buffer, it fails with
+/// [`DecodeError::Incomplete`] if the buffer ends before the value.
+pub struct Cursor<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Cursor { buf, pos: 0 }
+    }
+
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.buf.len()
+    }
+
+    pub fn bytes(&mut self, len: usize) -> DecodeResult<&'a [u8]> {
+        if self.buf.len() - self.pos < len {
+            return Err(DecodeError::Incomplete);
+        }
+        let bytes = &self.buf[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    pub fn array<const N: usize>(&mut self) -> DecodeResult<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.bytes(N)?);
+        Ok(array)
+    }
+
+    pub fn u8(&mut self) -> DecodeResult<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub fn varuint(&mut self) -> DecodeResult<u64> {
+        let mut value = 0u64;
+        for i in 0..10 {
+            let byte = self.u8()?;
+            value |= ((byte & 0x7f) as u64) << (7 * i);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(ErrorCode::BadBytes("varuint is too long").into())
+    }
+
+    pub fn binary(&mut self) -> DecodeResult<&'a [u8]> {
+        let len = self.varuint()? as usize;
+        self.bytes(len)
+    }
+
+    pub fn string(&mut self) -> DecodeResult<String> {
+        let bytes = self.binary()?;
+        String::from_utf8(bytes.to_vec())
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid utf8 string: {}", e)).into())
+    }
+}
+
+pub fn put_varuint(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+pub fn put_binary(out: &mut Vec<u8>, value: &[u8]) {
+    put_varuint(out, value.len() as u64);
+    out.extend_from_slice(value);
+}
+
+pub fn put_string(out: &mut Vec<u8>, value: &str) {
+    put_binary(out, value.as_bytes());
+}
+
+#[derive(Debug, Default)]
+pub struct ClientHello {
+    pub client_name: String,
+    pub version_major: u64,
+    pub version_minor: u64,
+    pub revision: u64,
+    pub database: String,
+    pub user: String,
+    pub password: String,
+}
+
+impl ClientHello {
+    pub fn decode(cursor: &mut Cursor) -> DecodeResult<Self> {
+        Ok(ClientHello {
+            client_name: cursor.string()?,
+            version_major: cursor.varuint()?,
+            version_minor: cursor.varuint()?,
+            revision: cursor.varuint()?,
+            database: cursor.string()?,
+            user: cursor.string()?,
+            password: cursor.string()?,
+        })
+    }
+}
+
+pub struct ServerHello<'a> {
+    pub name: &'a str,
+    pub timezone: &'a str,
+    pub display_name: &'a str,
+}
+
+impl<'a> ServerHello<'a> {
+    pub fn encode(&self, out: &mut Vec<u8>, revision: u64) {
+        put_varuint(out, server_packet::HELLO);
+        put_string(out, self.name);
+        put_varuint(out, DBMS_VERSION_MAJOR);
+        put_varuint(out, DBMS_VERSION_MINOR);
+        put_varuint(out, DBMS_TCP_PROTOCOL_VERSION);
+        if revision >= DBMS_MIN_REVISION_WITH_SERVER_TIMEZONE {
+            put_string(out, self.timezone);
+        }
+        if revision >= DBMS_MIN_REVISION_WITH_SERVER_DISPLAY_NAME {
+            put_string(out, self.display_name);
+        }
+        if revision >= DBMS_MIN_REVISION_WITH_VERSION_PATCH {
+            put_varuint(out, DBMS_VERSION_PATCH);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ClientQuery {
+    pub query_id: String,
+    pub settings: Vec<(String, String)>,
+    pub stage: u64,
+    pub compression: bool,
+    pub query: String,
+}
+
+impl ClientQuery {
+    pub fn decode(cursor: &mut Cursor, revision: u64) -> DecodeResult<Self> {
+        let query_id = cursor.string()?;
+        if revision >= DBMS_MIN_REVISION_WITH_CLIENT_INFO {
+            Self::skip_client_info(cursor, revision)?;
+        }
+
+        let mut settings = vec![];
+        loop {
+            let name = cursor.string()?;
+            if name.is_empty() {
+                break;
+            }
+            if revision < DBMS_MIN_REVISION_WITH_SETTINGS_SERIALIZED_AS_STRINGS {
+                return Err(ErrorCode::BadBytes(
+                    "settings of protocol revision before 54429 are not supported",
+                )
+                .into());
+            }
+            // The flags tell whether the setting is important, unknown settings are ignored anyway.
+            let _flags = cursor.varuint()?;
+            settings.push((name, cursor.string()?));
+        }
+
+        Ok(ClientQuery {
+            query_id,
+            settings,
+            stage: cursor.varuint()?,
+            compression: cursor.varuint()? != 0,
+            query: cursor.string()?,
+        })
+    }
+
+    fn skip_client_info(cursor: &mut Cursor, revision: u64) -> DecodeResult<()> {
+        let query_kind = cursor.u8()?;
+        if query_kind == 0 {
+            return Ok(());
+        }
+        // initial user, query id and address.
+        for _ in 0..3 {
+            cursor.binary()?;
+        }
+        let interface = cursor.u8()?;
+        if interface == INTERFACE_TCP {
+            // os user, client hostname, client name, version major, minor and revision.
+            for _ in 0..3 {
+                cursor.binary()?;
+            }
+            for _ in 0..3 {
+                cursor.varuint()?;
+            }
+        } else {
+            // http method, user agent
+            cursor.u8()?;
+            cursor.binary()?;
+        }
+        if revision >= DBMS_MIN_REVISION_WITH_QUOTA_KEY_IN_CLIENT_INFO {
+            cursor.binary()?;
+        }
+        if revision >= DBMS_MIN_REVISION_WITH_VERSION_PATCH && interface == INTERFACE_TCP {
+            cursor.varuint()?;
+        }
+        Ok(())
+    }
+}
+
+pub fn encode_exception(out: &mut Vec<u8>, code: i32, name: &str, message: &str) {
+    put_varuint(out, server_packet::EXCEPTION);
+    out.extend_from_slice(&code.to_le_bytes());
+    put_string(out, name);
+    put_string(out, message);
+    // stack trace and has nested.
+    put_string(out, "");
+    out.push(0);
+}
+
+pub struct Progress {
+    pub rows: u64,
+    pub bytes: u64,
+    pub total_rows: u64,
+    pub written_rows: u64,
+    pub written_bytes: u64,
+}
+
+impl Progress {
+    pub fn encode(&self, out: &mut Vec<u8>, revision: u64) {
+        put_varuint(out, server_packet::PROGRESS);
+        put_varuint(out, self.rows);
+        put_varuint(out, self.bytes);
+        put_varuint(out, self.total_rows);
+        if revision >= DBMS_MIN_REVISION_WITH_CLIENT_WRITE_INFO {
+            put_varuint(out, self.written_rows);
+            put_varuint(out, self.written_bytes);
+        }
+    }
+}
@@ -0,0 +1,274 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::DecimalDataType;
+use common_expression::types::DecimalSize;
+use common_expression::types::NumberDataType;
+
+/// The ClickHouse column types which are read and written by the native protocol.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NativeType {
+    Nothing,
+    Bool,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    Float32,
+    Float64,
+    Decimal(u8, u8),
+    String,
+    FixedString(usize),
+    Date,
+    Date32,
+    DateTime,
+    DateTime64(u32),
+    Nullable(Box<NativeType>),
+    Array(Box<NativeType>),
+    Map(Box<NativeType>, Box<NativeType>),
+    Tuple(Vec<NativeType>),
+}
+
+impl NativeType {
+    /// The type to send a column of `data_type`, the types without a ClickHouse
+    /// counterpart (variant, bitmap) are sent as strings.
+    pub fn from_data_type(data_type: &DataType) -> NativeType {
+        match data_type {
+            DataType::Null => NativeType::Nullable(Box::new(NativeType::Nothing)),
+            DataType::EmptyArray => NativeType::Array(Box::new(NativeType::Nothing)),
+            DataType::EmptyMap => {
+                NativeType::Map(Box::new(NativeType::String), Box::new(NativeType::String))
+            }
+            DataType::Boolean => NativeType::Bool,
+            DataType::String => NativeType::String,
+            DataType::Number(number) => match number {
+                NumberDataType::UInt8 => NativeType::UInt8,
+                NumberDataType::UInt16 => NativeType::UInt16,
+                NumberDataType::UInt32 => NativeType::UInt32,
+                NumberDataType::UInt64 => NativeType::UInt64,
+                NumberDataType::Int8 => NativeType::Int8,
+                NumberDataType::Int16 => NativeType::Int16,
+                NumberDataType::Int32 => NativeType::Int32,
+                NumberDataType::Int64 => NativeType::Int64,
+                NumberDataType::Float32 => NativeType::Float32,
+                NumberDataType::Float64 => NativeType::Float64,
+            },
+            DataType::Decimal(decimal) => {
+                let size = decimal.size();
+                NativeType::Decimal(size.precision, size.scale)
+            }
+            DataType::Timestamp => NativeType::DateTime64(6),
+            DataType::Date => NativeType::Date32,
+            DataType::Nullable(inner) => match NativeType::from_data_type(inner) {
+                // Composite types can not be nullable in ClickHouse, the nulls are sent as defaults.
+                inner @ (NativeType::Array(_)
+                | NativeType::Map(_, _)
+                | NativeType::Tuple(_)
+                | NativeType::Nullable(_)) => inner,
+                inner => NativeType::Nullable(Box::new(inner)),
+            },
+            DataType::Array(inner) => {
+                NativeType::Array(Box::new(NativeType::from_data_type(inner)))
+            }
+            DataType::Map(inner) => match inner.as_ref() {
+                DataType::Tuple(kv) if kv.len() == 2 => NativeType::Map(
+                    Box::new(NativeType::from_data_type(&kv[0])),
+                    Box::new(NativeType::from_data_type(&kv[1])),
+                ),
+                _ => NativeType::String,
+            },
+            DataType::Tuple(fields) => {
+                NativeType::Tuple(fields.iter().map(NativeType::from_data_type).collect())
+            }
+            DataType::Bitmap | DataType::Variant | DataType::Generic(_) => NativeType::String,
+        }
+    }
+
+    /// The type of the column which is read from a column of this type.
+    pub fn to_data_type(&self) -> Result<DataType> {
+        Ok(match self {
+            NativeType::Nothing => DataType::Null,
+            NativeType::Bool => DataType::Boolean,
+            NativeType::UInt8 => DataType::Number(NumberDataType::UInt8),
+            NativeType::UInt16 => DataType::Number(NumberDataType::UInt16),
+            NativeType::UInt32 => DataType::Number(NumberDataType::UInt32),
+            NativeType::UInt64 => DataType::Number(NumberDataType::UInt64),
+            NativeType::Int8 => DataType::Number(NumberDataType::Int8),
+            NativeType::Int16 => DataType::Number(NumberDataType::Int16),
+            NativeType::Int32 => DataType::Number(NumberDataType::Int32),
+            NativeType::Int64 => DataType::Number(NumberDataType::Int64),
+            NativeType::Float32 => DataType::Number(NumberDataType::Float32),
+            NativeType::Float64 => DataType::Number(NumberDataType::Float64),
+            NativeType::Decimal(precision, scale) => {
+                DataType::Decimal(DecimalDataType::from_size(DecimalSize {
+                    precision: *precision,
+                    scale: *scale,
+                })?)
+            }
+            NativeType::String | NativeType::FixedString(_) => DataType::String,
+            NativeType::Date | NativeType::Date32 => DataType::Date,
+            NativeType::DateTime | NativeType::DateTime64(_) => DataType::Timestamp,
+            NativeType::Nullable(inner) => match inner.as_ref() {
+                NativeType::Nothing => DataType::Null,
+                inner => DataType::Nullable(Box::new(inner.to_data_type()?)),
+            },
+            NativeType::Array(inner) => DataType::Array(Box::new(inner.to_data_type()?)),
+            NativeType::Map(key, value) => DataType::Map(Box::new(DataType::Tuple(vec![
+                key.to_data_type()?,
+                value.to_data_type()?,
+            ]))),
+            NativeType::Tuple(fields) => DataType::Tuple(
+                fields
+                    .iter()
+                    .map(|field| field.to_data_type())
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+        })
+    }
+
+    /// Parse the type name sent by the client, such as `Nullable(Decimal(10, 2))`.
+    pub fn parse(name: &str) -> Result<NativeType> {
+        let name = name.trim();
+        let (base, args) = match name.find('(') {
+            Some(start) if name.ends_with(')') => (
+                name[..start].trim(),
+                split_args(&name[start + 1..name.len() - 1]),
+            ),
+            _ => (name, vec![]),
+        };
+        let unsupported = || ErrorCode::BadArguments(format!("unsupported column type: {}", name));
+        let int_arg = |i: usize| -> Result<u64> {
+            args.get(i)
+                .and_then(|arg| arg.parse::<u64>().ok())
+                .ok_or_else(unsupported)
+        };
+
+        let native_type = match (base, args.len()) {
+            ("Nothing", 0) => NativeType::Nothing,
+            ("Bool" | "Boolean", 0) => NativeType::Bool,
+            ("UInt8", 0) => NativeType::UInt8,
+            ("UInt16", 0) => NativeType::UInt16,
+            ("UInt32", 0) => NativeType::UInt32,
+            ("UInt64", 0) => NativeType::UInt64,
+            ("Int8", 0) => NativeType::Int8,
+            ("Int16", 0) => NativeType::Int16,
+            ("Int32", 0) => NativeType::Int32,
+            ("Int64", 0) => NativeType::Int64,
+            ("Float32", 0) => NativeType::Float32,
+            ("Float64", 0) => NativeType::Float64,
+            ("Decimal", 2) => NativeType::Decimal(int_arg(0)? as u8, int_arg(1)? as u8),
+            ("Decimal32", 1) => NativeType::Decimal(9, int_arg(0)? as u8),
+            ("Decimal64", 1) => NativeType::Decimal(18, int_arg(0)? as u8),
+            ("Decimal128", 1) => NativeType::Decimal(38, int_arg(0)? as u8),
+            ("Decimal256", 1) => NativeType::Decimal(76, int_arg(0)? as u8),
+            ("String", 0) => NativeType::String,
+            ("FixedString", 1) => NativeType::FixedString(int_arg(0)? as usize),
+            ("Date", 0) => NativeType::Date,
+            ("Date32", 0) => NativeType::Date32,
+            // The timezone only affects how the values are displayed.
+            ("DateTime", 0 | 1) => NativeType::DateTime,
+            ("DateTime64", 1 | 2) => NativeType::DateTime64(int_arg(0)? as u32),
+            ("Nullable", 1) => NativeType::Nullable(Box::new(NativeType::parse(&args[0])?)),
+            ("Array", 1) => NativeType::Array(Box::new(NativeType::parse(&args[0])?)),
+            ("Map", 2) => NativeType::Map(
+                Box::new(NativeType::parse(&args[0])?),
+                Box::new(NativeType::parse(&args[1])?),
+            ),
+            ("Tuple", n) if n > 0 => NativeType::Tuple(
+                args.iter()
+                    .map(|arg| NativeType::parse(arg))
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+            _ => return Err(unsupported()),
+        };
+        if matches!(native_type, NativeType::DateTime64(precision) if precision > 9) {
+            return Err(unsupported());
+        }
+        Ok(native_type)
+    }
+}
+
+// Split the arguments of a type by the top level commas.
+fn split_args(args: &str) -> Vec<String> {
+    let mut result = vec![];
+    let mut depth = 0;
+    let mut in_quote = false;
+    let mut current = String::new();
+    for c in args.chars() {
+        match c {
+            '\'' => in_quote = !in_quote,
+            '(' if !in_quote => depth += 1,
+            ')' if !in_quote => depth -= 1,
+            ',' if !in_quote && depth == 0 => {
+                result.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() {
+        result.push(current.trim().to_string());
+    }
+    result
+}
+
+impl fmt::Display for NativeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NativeType::Nothing => write!(f, "Nothing"),
+            NativeType::Bool => write!(f, "Bool"),
+            NativeType::UInt8 => write!(f, "UInt8"),
+            NativeType::UInt16 => write!(f, "UInt16"),
+            NativeType::UInt32 => write!(f, "UInt32"),
+            NativeType::UInt64 => write!(f, "UInt64"),
+            NativeType::Int8 => write!(f, "Int8"),
+            NativeType::Int16 => write!(f, "Int16"),
+            NativeType::Int32 => write!(f, "Int32"),
+            NativeType::Int64 => write!(f, "Int64"),
+            NativeType::Float32 => write!(f, "Float32"),
+            NativeType::Float64 => write!(f, "Float64"),
+            NativeType::Decimal(precision, scale) => write!(f, "Decimal({}, {})", precision, scale),
+            NativeType::String => write!(f, "String"),
+            NativeType::FixedString(n) => write!(f, "FixedString({})", n),
+            NativeType::Date => write!(f, "Date"),
+            NativeType::Date32 => write!(f, "Date32"),
+            NativeType::DateTime => write!(f, "DateTime"),
+            NativeType::DateTime64(precision) => write!(f, "DateTime64({})", precision),
+            NativeType::Nullable(inner) => write!(f, "Nullable({})", inner),
+            NativeType::Array(inner) => write!(f, "Array({})", inner),
+            NativeType::Map(key, value) => write!(f, "Map({}, {})", key, value),
+            NativeType::Tuple(fields) => {
+                write!(f, "Tuple(")?;
+                for (i, field) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", field)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
@@ -16,7 +16,9 @@ use std::sync::Arc;
 
 use arrow_array::builder::StringBuilder;
 use arrow_array::ArrayRef;
+use arrow_array::Int32Array;
 use arrow_array::RecordBatch;
+use arrow_array::StringArray;
 use arrow_flight::utils::batches_to_flight_data;
 use arrow_schema::DataType;
 use arrow_schema::Field;
@@ -27,6 +29,7 @@ use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use futures_util::stream;
 use log::warn;
+use storages_common_table_meta::table::TableConstraints;
 use tonic::Status;
 
 use crate::servers::flight_sql::flight_sql_service::DoGetStream;
@@ -123,6 +126,75 @@ impl CatalogInfoProvider {
         Self::batch_to_get_stream(batch)
     }
 
+    async fn get_primary_keys_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        database_name: Option<String>,
+        table_name: String,
+    ) -> common_exception::Result<(String, String, Vec<String>)> {
+        let catalog_name = catalog_name.unwrap_or_else(|| ctx.get_current_catalog());
+        let database_name = database_name.unwrap_or_else(|| ctx.get_current_database());
+        let table = ctx
+            .get_catalog(&catalog_name)?
+            .get_table(ctx.get_tenant().as_str(), &database_name, &table_name)
+            .await?;
+
+        let constraints = TableConstraints::from_options(table.options())?;
+        let schema = table.schema();
+        let column_names = constraints
+            .primary_key
+            .unwrap_or_default()
+            .iter()
+            .map(|column_id| {
+                schema
+                    .fields()
+                    .iter()
+                    .find(|f| f.column_id() == *column_id)
+                    .map(|f| f.name().clone())
+                    .ok_or_else(|| {
+                        ErrorCode::InvalidTableConstraint(format!(
+                            "column id {} of primary key does not exist",
+                            column_id
+                        ))
+                    })
+            })
+            .collect::<common_exception::Result<Vec<_>>>()?;
+        Ok((catalog_name, database_name, column_names))
+    }
+
+    pub(crate) async fn get_primary_keys(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        database_name: Option<String>,
+        table_name: String,
+    ) -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_name", DataType::Utf8, true),
+            Field::new("key_sequence", DataType::Int32, false),
+        ]));
+        let (catalog_name, database_name, column_names) =
+            Self::get_primary_keys_internal(ctx, catalog_name, database_name, table_name.clone())
+                .await
+                .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let num_rows = column_names.len();
+        // the primary key is not named, key sequences start from 1
+        let key_names: StringArray = vec![None::<&str>; num_rows].into_iter().collect();
+        let batch = RecordBatch::try_new(schema, vec![
+            Self::string_array(vec![catalog_name; num_rows]),
+            Self::string_array(vec![database_name; num_rows]),
+            Self::string_array(vec![table_name; num_rows]),
+            Self::string_array(column_names),
+            Arc::new(key_names),
+            Arc::new(Int32Array::from_iter_values(1..=num_rows as i32)),
+        ])
+        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
     fn string_array(values: Vec<String>) -> ArrayRef {
         let mut builder = StringBuilder::new();
         for v in &values {
@@ -24,6 +24,7 @@ use tonic::Request;
 use tonic::Status;
 
 use super::status;
+use crate::auth::AuthMgr;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
@@ -96,23 +97,38 @@ impl FlightSqlServiceImpl {
         let tenant = session.get_current_tenant();
 
         let identity = UserIdentity::new(&user, "%");
-        let user = UserApiProvider::instance()
-            .get_user_with_client_ip(&tenant, identity, client_ip)
+        let user = AuthMgr::get_user_with_client_ip(&tenant, identity, client_ip)
             .await
             .map_err(|e| status!("get_user fail {}", e))?;
         let password = password.as_bytes().to_vec();
         let password = (!password.is_empty()).then_some(password);
 
-        let user = match &user.auth_info {
-            AuthInfo::None => user,
+        let (user, role) = match &user.auth_info {
+            AuthInfo::None => (user, None),
+            AuthInfo::KeyPair { .. } | AuthInfo::OIDC => match password {
+                None => return Err(Status::unauthenticated("token required")),
+                Some(p) => {
+                    let token = String::from_utf8_lossy(&p);
+                    let role = AuthMgr::instance()
+                        .verify_user_token(&user, &token)
+                        .await
+                        .map_err(|e| Status::unauthenticated(e.message()))?;
+                    (user, role)
+                }
+            },
             AuthInfo::Password {
                 hash_value: h,
                 hash_method: t,
             } => match password {
                 None => return Err(Status::unauthenticated("password required")),
                 Some(p) => {
-                    if *h == t.hash(&p) {
-                        user
+                    let authed = *h == t.hash(&p);
+                    UserApiProvider::instance()
+                        .check_login_password(&tenant, &user, authed)
+                        .await
+                        .map_err(|e| Status::unauthenticated(e.message()))?;
+                    if authed {
+                        (user, None)
                     } else {
                         return Err(Status::unauthenticated("wrong password"));
                     }
@@ -121,7 +137,7 @@ impl FlightSqlServiceImpl {
             _ => return Err(Status::unauthenticated("wrong auth type")),
         };
         session
-            .set_authed_user(user, None)
+            .set_authed_user(user, role)
             .await
             .map_err(|e| status!("set_authed_user fail {}", e))?;
         Ok(session)
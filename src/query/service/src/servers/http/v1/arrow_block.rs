@@ -0,0 +1,55 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::Schema as ArrowSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+
+pub const ARROW_IPC_CONTENT_TYPE: &str = "application/vnd.apache.arrow.stream";
+
+/// Encode a page of the result as an Arrow IPC stream, the `metadata` is attached to its schema.
+pub fn block_to_arrow_ipc(
+    schema: &DataSchema,
+    block: Option<DataBlock>,
+    metadata: HashMap<String, String>,
+) -> Result<Vec<u8>> {
+    let arrow_schema = Arc::new(ArrowSchema::from(schema).with_metadata(metadata));
+    let mut writer = StreamWriter::try_new(vec![], &arrow_schema)
+        .map_err(|e| ErrorCode::Internal(format!("fail to create arrow ipc writer: {e}")))?;
+    if let Some(block) = block {
+        if block.num_rows() > 0 {
+            let batch = block
+                .to_record_batch(schema)
+                .map_err(|e| ErrorCode::Internal(format!("{e:?}")))?;
+            let batch = RecordBatch::try_new(arrow_schema.clone(), batch.columns().to_vec())
+                .map_err(|e| ErrorCode::Internal(format!("{e:?}")))?;
+            writer
+                .write(&batch)
+                .map_err(|e| ErrorCode::Internal(format!("fail to write arrow ipc: {e}")))?;
+        }
+    }
+    writer
+        .finish()
+        .map_err(|e| ErrorCode::Internal(format!("fail to finish arrow ipc: {e}")))?;
+    writer
+        .into_inner()
+        .map_err(|e| ErrorCode::Internal(format!("fail to finish arrow ipc: {e}")))
+}
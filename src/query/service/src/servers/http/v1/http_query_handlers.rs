@@ -12,18 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use common_exception::ErrorCode;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use http::HeaderMap;
 use log::error;
 use log::info;
 use poem::error::Error as PoemError;
+use poem::error::InternalServerError;
 use poem::error::Result as PoemResult;
 use poem::get;
+use poem::http::header;
 use poem::http::StatusCode;
 use poem::post;
 use poem::web::Json;
 use poem::web::Path;
 use poem::IntoResponse;
+use poem::Response;
 use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
@@ -32,6 +41,8 @@ use serde_json::Value as JsonValue;
 use super::query::ExecuteStateKind;
 use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
+use crate::servers::http::v1::arrow_block::block_to_arrow_ipc;
+use crate::servers::http::v1::arrow_block::ARROW_IPC_CONTENT_TYPE;
 use crate::servers::http::v1::query::Progresses;
 use crate::servers::http::v1::HttpQueryContext;
 use crate::servers::http::v1::HttpQueryManager;
@@ -41,6 +52,37 @@ use crate::sessions::QueryAffect;
 const HEADER_QUERY_ID: &str = "X-DATABEND-QUERY-ID";
 const HEADER_QUERY_STATE: &str = "X-DATABEND-QUERY-STATE";
 const HEADER_QUERY_PAGE_ROWS: &str = "X-DATABEND-QUERY-PAGE-ROWS";
+// The key of the Arrow schema metadata which holds the response without data.
+const ARROW_METADATA_RESPONSE: &str = "response";
+
+/// The encoding of the result pages, negotiated by the `Accept` header of each request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResultFormat {
+    Json,
+    ArrowIpc,
+}
+
+impl ResultFormat {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let accept = headers
+            .get(header::ACCEPT)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default();
+        let is_arrow = accept.split(',').any(|media_type| {
+            media_type
+                .split(';')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .eq_ignore_ascii_case(ARROW_IPC_CONTENT_TYPE)
+        });
+        if is_arrow {
+            ResultFormat::ArrowIpc
+        } else {
+            ResultFormat::Json
+        }
+    }
+}
 
 pub fn make_page_uri(query_id: &str, page_no: usize) -> String {
     format!("/v1/query/{}/page/{}", query_id, page_no)
@@ -123,47 +165,51 @@ impl QueryResponse {
         id: String,
         r: HttpQueryResponseInternal,
         is_final: bool,
-    ) -> impl IntoResponse {
+        format: ResultFormat,
+    ) -> PoemResult<Response> {
         let state = r.state.clone();
         let (data, next_uri) = if is_final {
-            (JsonBlock::empty(), None)
+            (None, None)
         } else {
             match state.state {
                 ExecuteStateKind::Running => match r.data {
-                    None => (JsonBlock::empty(), Some(make_state_uri(&id))),
+                    None => (None, Some(make_state_uri(&id))),
                     Some(d) => {
                         let uri = match d.next_page_no {
                             Some(n) => Some(make_page_uri(&id, n)),
                             None => Some(make_state_uri(&id)),
                         };
-                        (d.page.data, uri)
+                        (Some(d), uri)
                     }
                 },
-                ExecuteStateKind::Failed => (JsonBlock::empty(), Some(make_final_uri(&id))),
+                ExecuteStateKind::Failed => (None, Some(make_final_uri(&id))),
                 ExecuteStateKind::Succeeded => match r.data {
-                    None => (JsonBlock::empty(), Some(make_final_uri(&id))),
+                    None => (None, Some(make_final_uri(&id))),
                     Some(d) => {
                         let uri = match d.next_page_no {
                             Some(n) => Some(make_page_uri(&id, n)),
                             None => Some(make_final_uri(&id)),
                         };
-                        (d.page.data, uri)
+                        (Some(d), uri)
                     }
                 },
             }
         };
 
-        let schema = data.schema().clone();
+        let schema = match &data {
+            Some(d) => d.page.schema.clone(),
+            None => Arc::new(DataSchema::empty()),
+        };
+        let rows = data.as_ref().map_or(0, |d| d.page.data.num_rows());
         let session_id = r.session_id.clone();
         let stats = QueryStats {
             progresses: state.progresses.clone(),
             running_time_ms: state.running_time_ms,
         };
-        let rows = data.data.len();
-        Json(QueryResponse {
-            data: data.into(),
+        let mut response = QueryResponse {
+            data: vec![],
             state: state.state,
-            schema: QueryResponseField::from_schema(schema),
+            schema: QueryResponseField::from_schema(schema.clone()),
             session_id: Some(session_id),
             session: r.session,
             stats,
@@ -174,14 +220,29 @@ impl QueryResponse {
             final_uri: Some(make_final_uri(&id)),
             kill_uri: Some(make_kill_uri(&id)),
             error: r.state.error.as_ref().map(QueryError::from_error_code),
-        })
-        .with_header(HEADER_QUERY_ID, id.clone())
-        .with_header(HEADER_QUERY_STATE, state.state.to_string())
-        .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+        };
+        let response = match format {
+            ResultFormat::Json => {
+                if let Some(d) = data {
+                    response.data = JsonBlock::new(schema, &d.page.data, &d.format_settings)
+                        .map_err(InternalServerError)?
+                        .into();
+                }
+                Json(response).into_response()
+            }
+            ResultFormat::ArrowIpc => {
+                response.into_arrow_response(&schema, data.map(|d| d.page.data))?
+            }
+        };
+        Ok(response
+            .with_header(HEADER_QUERY_ID, id.clone())
+            .with_header(HEADER_QUERY_STATE, state.state.to_string())
+            .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+            .into_response())
     }
 
-    pub(crate) fn fail_to_start_sql(err: &ErrorCode) -> impl IntoResponse {
-        Json(QueryResponse {
+    pub(crate) fn fail_to_start_sql(err: &ErrorCode, format: ResultFormat) -> PoemResult<Response> {
+        let response = QueryResponse {
             id: "".to_string(),
             stats: QueryStats::default(),
             state: ExecuteStateKind::Failed,
@@ -195,7 +256,28 @@ impl QueryResponse {
             final_uri: None,
             kill_uri: None,
             error: Some(QueryError::from_error_code(err)),
-        })
+        };
+        match format {
+            ResultFormat::Json => Ok(Json(response).into_response()),
+            ResultFormat::ArrowIpc => response.into_arrow_response(&DataSchema::empty(), None),
+        }
+    }
+
+    // The rows are sent as an Arrow IPC stream, and the other fields of the response
+    // as JSON in the metadata of its schema.
+    fn into_arrow_response(
+        self,
+        schema: &DataSchema,
+        block: Option<DataBlock>,
+    ) -> PoemResult<Response> {
+        let metadata = HashMap::from([(
+            ARROW_METADATA_RESPONSE.to_string(),
+            serde_json::to_string(&self).map_err(InternalServerError)?,
+        )]);
+        let body = block_to_arrow_ipc(schema, block, metadata).map_err(InternalServerError)?;
+        Ok(Response::builder()
+            .content_type(ARROW_IPC_CONTENT_TYPE)
+            .body(body))
     }
 }
 
@@ -203,6 +285,7 @@ impl QueryResponse {
 async fn query_final_handler(
     _ctx: &HttpQueryContext,
     Path(query_id): Path<String>,
+    headers: &HeaderMap,
 ) -> PoemResult<impl IntoResponse> {
     info!("final http query: {}", query_id);
     let http_query_manager = HttpQueryManager::instance();
@@ -215,7 +298,12 @@ async fn query_final_handler(
                     StatusCode::BAD_REQUEST,
                 ));
             }
-            Ok(QueryResponse::from_internal(query_id, response, true))
+            QueryResponse::from_internal(
+                query_id,
+                response,
+                true,
+                ResultFormat::from_headers(headers),
+            )
         }
         None => Err(query_id_not_found(query_id)),
     }
@@ -243,12 +331,18 @@ async fn query_cancel_handler(
 async fn query_state_handler(
     _ctx: &HttpQueryContext,
     Path(query_id): Path<String>,
+    headers: &HeaderMap,
 ) -> PoemResult<impl IntoResponse> {
     let http_query_manager = HttpQueryManager::instance();
     match http_query_manager.get_query(&query_id).await {
         Some(query) => {
             let response = query.get_response_state_only().await;
-            Ok(QueryResponse::from_internal(query_id, response, false))
+            QueryResponse::from_internal(
+                query_id,
+                response,
+                false,
+                ResultFormat::from_headers(headers),
+            )
         }
         None => Err(query_id_not_found(query_id)),
     }
@@ -258,6 +352,7 @@ async fn query_state_handler(
 async fn query_page_handler(
     _ctx: &HttpQueryContext,
     Path((query_id, page_no)): Path<(String, usize)>,
+    headers: &HeaderMap,
 ) -> PoemResult<impl IntoResponse> {
     let http_query_manager = HttpQueryManager::instance();
     match http_query_manager.get_query(&query_id).await {
@@ -268,7 +363,7 @@ async fn query_page_handler(
                 .await
                 .map_err(|err| poem::Error::from_string(err.message(), StatusCode::NOT_FOUND))?;
             query.update_expire_time(false).await;
-            Ok(QueryResponse::from_internal(query_id, resp, false))
+            QueryResponse::from_internal(query_id, resp, false, ResultFormat::from_headers(headers))
         }
         None => Err(query_id_not_found(query_id)),
     }
@@ -279,8 +374,10 @@ async fn query_page_handler(
 pub(crate) async fn query_handler(
     ctx: &HttpQueryContext,
     Json(req): Json<HttpQueryRequest>,
+    headers: &HeaderMap,
 ) -> PoemResult<impl IntoResponse> {
     info!("new http query request: {:?}", req);
+    let format = ResultFormat::from_headers(headers);
     let http_query_manager = HttpQueryManager::instance();
     let sql = req.sql.clone();
 
@@ -305,11 +402,11 @@ pub(crate) async fn query_handler(
                 &query.id, &resp.state, rows, next_page, sql
             );
             query.update_expire_time(false).await;
-            Ok(QueryResponse::from_internal(query.id.to_string(), resp, false).into_response())
+            QueryResponse::from_internal(query.id.to_string(), resp, false, format)
         }
         Err(e) => {
             error!("Fail to start sql, Error: {:?}", e);
-            Ok(QueryResponse::fail_to_start_sql(&e).into_response())
+            QueryResponse::fail_to_start_sql(&e, format)
         }
     }
 }
@@ -26,6 +26,7 @@ use common_base::runtime::TrySpawn;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_io::prelude::FormatSettings;
 use log::info;
 use log::warn;
 use serde::Deserialize;
@@ -205,6 +206,7 @@ pub struct HttpQuery {
     request: HttpQueryRequest,
     state: Arc<RwLock<Executor>>,
     page_manager: Arc<TokioMutex<PageManager>>,
+    format_settings: FormatSettings,
     config: HttpQueryConfig,
     expire_state: Arc<TokioMutex<ExpireState>>,
 }
@@ -350,7 +352,6 @@ impl HttpQuery {
             request.pagination.max_rows_per_page,
             block_receiver,
             schema,
-            format_settings,
         )));
         let query = HttpQuery {
             id,
@@ -358,6 +359,7 @@ impl HttpQuery {
             request,
             state,
             page_manager: data,
+            format_settings,
             config,
             expire_state: Arc::new(TokioMutex::new(ExpireState::Working)),
         };
@@ -416,6 +418,7 @@ impl HttpQuery {
         let response = ResponseData {
             page,
             next_page_no: page_manager.next_page_no(),
+            format_settings: self.format_settings.clone(),
         };
         Ok(response)
     }
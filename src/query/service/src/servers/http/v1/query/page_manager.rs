@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::VecDeque;
 use std::time::Instant;
 
 use common_base::base::tokio;
@@ -23,11 +22,8 @@ use common_expression::DataSchemaRef;
 use common_io::prelude::FormatSettings;
 use log::debug;
 use log::info;
-use serde_json::Value as JsonValue;
 
-use crate::servers::http::v1::json_block::block_to_json_value;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelReceiver;
-use crate::servers::http::v1::JsonBlock;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum Wait {
@@ -35,15 +31,18 @@ pub enum Wait {
     Deadline(Instant),
 }
 
+/// The rows of a page, which are encoded as JSON or Arrow IPC by the response.
 #[derive(Clone)]
 pub struct Page {
-    pub data: JsonBlock,
+    pub data: DataBlock,
+    pub schema: DataSchemaRef,
     pub total_rows: usize,
 }
 
 pub struct ResponseData {
     pub page: Page,
     pub next_page_no: Option<usize>,
+    pub format_settings: FormatSettings,
 }
 
 pub struct PageManager {
@@ -55,9 +54,9 @@ pub struct PageManager {
     block_end: bool,
     schema: DataSchemaRef,
     last_page: Option<Page>,
-    row_buffer: VecDeque<Vec<JsonValue>>,
+    // The rows of the last received block which do not fit in the previous page.
+    block_buffer: Option<DataBlock>,
     block_receiver: SizedChannelReceiver<DataBlock>,
-    format_settings: FormatSettings,
 }
 
 impl PageManager {
@@ -66,7 +65,6 @@ impl PageManager {
         max_rows_per_page: usize,
         block_receiver: SizedChannelReceiver<DataBlock>,
         schema: DataSchemaRef,
-        format_settings: FormatSettings,
     ) -> PageManager {
         PageManager {
             query_id,
@@ -75,11 +73,10 @@ impl PageManager {
             total_pages: 0,
             end: false,
             block_end: false,
-            row_buffer: Default::default(),
+            block_buffer: None,
             schema,
             block_receiver,
             max_rows_per_page,
-            format_settings,
         }
     }
 
@@ -100,6 +97,7 @@ impl PageManager {
             self.total_rows += num_row;
             let page = Page {
                 data: block,
+                schema: self.schema.clone(),
                 total_rows: self.total_rows,
             };
             if num_row > 0 {
@@ -122,41 +120,43 @@ impl PageManager {
         }
     }
 
+    // Take at most `remain` rows of the block into the page, and buffer the rest,
+    // returns the number of rows taken.
     fn append_block(
         &mut self,
-        rows: &mut Vec<Vec<JsonValue>>,
+        blocks: &mut Vec<DataBlock>,
         block: DataBlock,
         remain: usize,
-    ) -> Result<()> {
-        let format_settings = &self.format_settings;
-        let mut iter = block_to_json_value(&block, format_settings)?
-            .into_iter()
-            .peekable();
-        let chunk: Vec<_> = iter.by_ref().take(remain).collect();
-        rows.extend(chunk);
-        self.row_buffer = iter.by_ref().collect();
-        Ok(())
+    ) -> usize {
+        let num_rows = block.num_rows();
+        if num_rows > remain {
+            blocks.push(block.slice(0..remain));
+            self.block_buffer = Some(block.slice(remain..num_rows));
+            remain
+        } else {
+            if num_rows > 0 {
+                blocks.push(block);
+            }
+            num_rows
+        }
     }
 
     #[async_backtrace::framed]
-    async fn collect_new_page(&mut self, tp: &Wait) -> Result<(JsonBlock, bool)> {
-        let mut res: Vec<Vec<JsonValue>> = Vec::with_capacity(self.max_rows_per_page);
-        while res.len() < self.max_rows_per_page {
-            if let Some(row) = self.row_buffer.pop_front() {
-                res.push(row)
-            } else {
-                break;
-            }
+    async fn collect_new_page(&mut self, tp: &Wait) -> Result<(DataBlock, bool)> {
+        let mut blocks = vec![];
+        let mut rows = 0;
+        if let Some(block) = self.block_buffer.take() {
+            rows += self.append_block(&mut blocks, block, self.max_rows_per_page);
         }
         loop {
-            assert!(self.max_rows_per_page >= res.len());
-            let remain = self.max_rows_per_page - res.len();
+            assert!(self.max_rows_per_page >= rows);
+            let remain = self.max_rows_per_page - rows;
             if remain == 0 {
                 break;
             }
             match tp {
                 Wait::Async => match self.block_receiver.try_recv() {
-                    Some(block) => self.append_block(&mut res, block, remain)?,
+                    Some(block) => rows += self.append_block(&mut blocks, block, remain),
                     None => break,
                 },
                 Wait::Deadline(t) => {
@@ -169,7 +169,7 @@ impl PageManager {
                                 &self.query_id,
                                 block.num_rows()
                             );
-                            self.append_block(&mut res, block, remain)?;
+                            rows += self.append_block(&mut blocks, block, remain);
                         }
                         Ok(None) => {
                             info!("http query {} reach end of blocks", &self.query_id);
@@ -184,16 +184,17 @@ impl PageManager {
             }
         }
 
-        let block = JsonBlock {
-            schema: self.schema.clone(),
-            data: res,
+        let block = match blocks.len() {
+            0 => DataBlock::empty_with_schema(self.schema.clone()),
+            1 => blocks.pop().unwrap(),
+            _ => DataBlock::concat(&blocks)?,
         };
 
         // try to report 'no more data' earlier to client to avoid unnecessary http call
         if !self.block_end {
             self.block_end = self.block_receiver.is_empty();
         }
-        let end = self.block_end && self.row_buffer.is_empty();
+        let end = self.block_end && self.block_buffer.is_none();
         Ok((block, end))
     }
 
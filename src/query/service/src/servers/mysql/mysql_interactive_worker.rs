@@ -28,6 +28,7 @@ use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
 use common_expression::SendableDataBlockStream;
 use common_io::prelude::FormatSettings;
+use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::UserIdentity;
 use common_sql::Planner;
 use common_tracing::func_name;
@@ -46,7 +47,9 @@ use opensrv_mysql::QueryResultWriter;
 use opensrv_mysql::StatementMetaWriter;
 use rand::RngCore;
 
+use crate::auth::AuthMgr;
 use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterAuditLog;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
 use crate::servers::mysql::writers::DFInitResultWriter;
@@ -95,8 +98,24 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
     }
 
     #[async_backtrace::framed]
-    async fn auth_plugin_for_username(&self, _user: &[u8]) -> &str {
-        "mysql_native_password"
+    async fn auth_plugin_for_username(&self, user: &[u8]) -> &str {
+        // The key-pair and OIDC users send their tokens as clear passwords.
+        let tenant = self.base.session.get_current_tenant();
+        let identity = UserIdentity::new(&String::from_utf8_lossy(user), "%");
+        match UserApiProvider::instance()
+            .get_user(&tenant, identity)
+            .await
+        {
+            Ok(user_info)
+                if matches!(
+                    user_info.auth_info,
+                    AuthInfo::KeyPair { .. } | AuthInfo::OIDC
+                ) =>
+            {
+                "mysql_clear_password"
+            }
+            _ => "mysql_native_password",
+        }
     }
 
     fn salt(&self) -> [u8; 20] {
@@ -259,15 +278,44 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
         let ctx = self.session.create_query_context().await?;
         let identity = UserIdentity::new(&info.user_name, "%");
         let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
-        let user_info = UserApiProvider::instance()
-            .get_user_with_client_ip(&ctx.get_tenant(), identity, Some(client_ip))
-            .await?;
+        let tenant = ctx.get_tenant();
+        let user = identity.to_string();
+        let res: Result<bool> = async {
+            let user_info =
+                AuthMgr::get_user_with_client_ip(&tenant, identity, Some(client_ip)).await?;
+
+            if let AuthInfo::KeyPair { .. } | AuthInfo::OIDC = user_info.auth_info {
+                // The clear password is terminated by NUL.
+                let token = info
+                    .user_password
+                    .strip_suffix(&[0])
+                    .unwrap_or(&info.user_password);
+                let token = String::from_utf8_lossy(token);
+                let role = AuthMgr::instance()
+                    .verify_user_token(&user_info, &token)
+                    .await?;
+                self.session.set_authed_user(user_info, role).await?;
+                return Ok(true);
+            }
 
-        let authed = user_info.auth_info.auth_mysql(&info.user_password, salt)?;
-        if authed {
-            self.session.set_authed_user(user_info, None).await?;
+            let authed = user_info.auth_info.auth_mysql(&info.user_password, salt)?;
+            UserApiProvider::instance()
+                .check_login_password(&tenant, &user_info, authed)
+                .await?;
+            if authed {
+                self.session.set_authed_user(user_info, None).await?;
+            }
+            Ok(authed)
         }
-        Ok(authed)
+        .await;
+
+        let err = match &res {
+            Ok(false) => Some(ErrorCode::AuthenticateFailure("wrong password")),
+            Ok(true) => None,
+            Err(e) => Some(e.clone()),
+        };
+        InterpreterAuditLog::log_login(&tenant, &user, Some(client_ip), err.as_ref());
+        res
     }
 
     #[async_backtrace::framed]
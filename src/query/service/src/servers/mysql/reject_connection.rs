@@ -18,6 +18,8 @@ use common_base::base::tokio::net::TcpStream;
 use common_exception::Result;
 use opensrv_mysql::ErrorKind;
 
+use crate::interpreters::InterpreterAuditLog;
+
 pub struct RejectConnection;
 
 impl RejectConnection {
@@ -27,6 +29,17 @@ impl RejectConnection {
         code: ErrorKind,
         error_message: impl Into<String>,
     ) -> Result<()> {
+        let error_message = error_message.into();
+        let client_address = stream
+            .peer_addr()
+            .map(|addr| addr.ip().to_string())
+            .unwrap_or_default();
+        InterpreterAuditLog::log_connection_rejected(
+            client_address,
+            format!("{:?}", code),
+            error_message.clone(),
+        );
+
         RejectConnection::send_handshake(&mut stream).await?;
         RejectConnection::receive_handshake_response(&mut stream).await?;
 
@@ -35,7 +48,7 @@ impl RejectConnection {
         buffer.extend((code as u16).to_le_bytes());
         buffer.extend(&vec![b'#']);
         buffer.extend(code.sqlstate());
-        buffer.extend(error_message.into().as_bytes());
+        buffer.extend(error_message.as_bytes());
 
         let size = buffer.len().to_le_bytes();
         buffer.splice(0..0, [size[0], size[1], size[2], 2].iter().cloned());
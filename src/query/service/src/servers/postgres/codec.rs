@@ -0,0 +1,412 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Messages of the PostgreSQL frontend/backend protocol version 3.0.
+//!
+//! See: https://www.postgresql.org/docs/current/protocol-message-formats.html
+
+use std::collections::HashMap;
+
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+pub const PROTOCOL_VERSION_3: i32 = 196608;
+pub const CANCEL_REQUEST_CODE: i32 = 80877102;
+pub const SSL_REQUEST_CODE: i32 = 80877103;
+pub const GSSENC_REQUEST_CODE: i32 = 80877104;
+
+// The startup packet is small, anything larger is not a postgres client.
+const MAX_STARTUP_MESSAGE_LEN: usize = 10 * 1024;
+const MAX_MESSAGE_LEN: usize = 1024 * 1024 * 1024;
+
+pub const TRANSACTION_IDLE: u8 = b'I';
+pub const TRANSACTION_IN_BLOCK: u8 = b'T';
+pub const TRANSACTION_FAILED: u8 = b'E';
+
+#[derive(Debug, PartialEq)]
+pub enum StartupMessage {
+    Startup { params: HashMap<String, String> },
+    SslRequest,
+    GssEncRequest,
+    Cancel { process_id: i32, secret_key: i32 },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FrontendMessage {
+    Query(String),
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    },
+    /// Describe a prepared statement (`S`) or a portal (`P`).
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    /// Close a prepared statement (`S`) or a portal (`P`).
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Sync,
+    Flush,
+    Terminate,
+    Password(Vec<u8>),
+}
+
+struct MessageBody<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> MessageBody<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        MessageBody { buf, pos: 0 }
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.pos + len > self.buf.len() {
+            return Err(ErrorCode::BadBytes("postgres message is truncated"));
+        }
+        let bytes = &self.buf[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn i16(&mut self) -> Result<i16> {
+        let bytes = self.bytes(2)?;
+        Ok(i16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn i32(&mut self) -> Result<i32> {
+        let bytes = self.bytes(4)?;
+        Ok(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn cstring(&mut self) -> Result<String> {
+        let rest = &self.buf[self.pos..];
+        let end = rest
+            .iter()
+            .position(|b| *b == 0)
+            .ok_or_else(|| ErrorCode::BadBytes("postgres string is not null terminated"))?;
+        let value = String::from_utf8(rest[..end].to_vec())
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid utf8 string: {}", e)))?;
+        self.pos += end + 1;
+        Ok(value)
+    }
+
+    fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+}
+
+async fn read_body<R: AsyncRead + Unpin>(reader: &mut R, len: i32, max: usize) -> Result<Vec<u8>> {
+    if len < 4 || len as usize > max {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid postgres message length: {}",
+            len
+        )));
+    }
+    let mut body = vec![0; len as usize - 4];
+    reader.read_exact(&mut body).await?;
+    Ok(body)
+}
+
+/// Read the first message of a connection, which has no type byte.
+pub async fn read_startup_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<StartupMessage> {
+    let len = reader.read_i32().await?;
+    let body = read_body(reader, len, MAX_STARTUP_MESSAGE_LEN).await?;
+    let mut body = MessageBody::new(&body);
+    match body.i32()? {
+        SSL_REQUEST_CODE => Ok(StartupMessage::SslRequest),
+        GSSENC_REQUEST_CODE => Ok(StartupMessage::GssEncRequest),
+        CANCEL_REQUEST_CODE => Ok(StartupMessage::Cancel {
+            process_id: body.i32()?,
+            secret_key: body.i32()?,
+        }),
+        PROTOCOL_VERSION_3 => {
+            let mut params = HashMap::new();
+            loop {
+                let name = body.cstring()?;
+                if name.is_empty() {
+                    break;
+                }
+                let value = body.cstring()?;
+                params.insert(name, value);
+            }
+            Ok(StartupMessage::Startup { params })
+        }
+        version => Err(ErrorCode::BadBytes(format!(
+            "unsupported postgres protocol version: {}.{}",
+            version >> 16,
+            version & 0xffff
+        ))),
+    }
+}
+
+/// Read a message after the startup phase, returns None if the client closed the connection.
+pub async fn read_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<FrontendMessage>> {
+    let tag = match reader.read_u8().await {
+        Ok(tag) => tag,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let len = reader.read_i32().await?;
+    let body = read_body(reader, len, MAX_MESSAGE_LEN).await?;
+    decode_message(tag, &body).map(Some)
+}
+
+pub fn decode_message(tag: u8, body: &[u8]) -> Result<FrontendMessage> {
+    let mut body = MessageBody::new(body);
+    let message = match tag {
+        b'Q' => FrontendMessage::Query(body.cstring()?),
+        b'P' => {
+            let name = body.cstring()?;
+            let query = body.cstring()?;
+            let num_types = body.i16()?;
+            let mut param_types = Vec::with_capacity(num_types.max(0) as usize);
+            for _ in 0..num_types {
+                param_types.push(body.i32()? as u32);
+            }
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            }
+        }
+        b'B' => {
+            let portal = body.cstring()?;
+            let statement = body.cstring()?;
+            let num_formats = body.i16()?;
+            let mut param_formats = Vec::with_capacity(num_formats.max(0) as usize);
+            for _ in 0..num_formats {
+                param_formats.push(body.i16()?);
+            }
+            let num_params = body.i16()?;
+            let mut params = Vec::with_capacity(num_params.max(0) as usize);
+            for _ in 0..num_params {
+                let len = body.i32()?;
+                if len < 0 {
+                    params.push(None);
+                } else {
+                    params.push(Some(body.bytes(len as usize)?.to_vec()));
+                }
+            }
+            let num_formats = body.i16()?;
+            let mut result_formats = Vec::with_capacity(num_formats.max(0) as usize);
+            for _ in 0..num_formats {
+                result_formats.push(body.i16()?);
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            }
+        }
+        b'D' => FrontendMessage::Describe {
+            kind: body.u8()?,
+            name: body.cstring()?,
+        },
+        b'E' => FrontendMessage::Execute {
+            portal: body.cstring()?,
+            max_rows: body.i32()?,
+        },
+        b'C' => FrontendMessage::Close {
+            kind: body.u8()?,
+            name: body.cstring()?,
+        },
+        b'S' => FrontendMessage::Sync,
+        b'H' => FrontendMessage::Flush,
+        b'X' => FrontendMessage::Terminate,
+        b'p' => {
+            // The password is a null terminated string.
+            let password = body.bytes(body.remaining())?;
+            let password = password.strip_suffix(&[0]).unwrap_or(password);
+            FrontendMessage::Password(password.to_vec())
+        }
+        tag => {
+            return Err(ErrorCode::BadBytes(format!(
+                "unsupported postgres message type: {:?}",
+                tag as char
+            )));
+        }
+    };
+    Ok(message)
+}
+
+/// Description of a column in the RowDescription message.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_size: i16,
+    pub format: i16,
+}
+
+/// Append a backend message of `tag` to `out`, the length is filled after `body` is written.
+pub fn write_message(out: &mut Vec<u8>, tag: u8, body: impl FnOnce(&mut Vec<u8>)) {
+    out.push(tag);
+    let start = out.len();
+    out.extend_from_slice(&[0; 4]);
+    body(out);
+    let len = (out.len() - start) as i32;
+    out[start..start + 4].copy_from_slice(&len.to_be_bytes());
+}
+
+pub fn put_i16(out: &mut Vec<u8>, value: i16) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+pub fn put_i32(out: &mut Vec<u8>, value: i32) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+pub fn put_cstring(out: &mut Vec<u8>, value: &str) {
+    out.extend_from_slice(value.as_bytes());
+    out.push(0);
+}
+
+pub fn authentication_ok(out: &mut Vec<u8>) {
+    write_message(out, b'R', |buf| put_i32(buf, 0));
+}
+
+pub fn authentication_cleartext_password(out: &mut Vec<u8>) {
+    write_message(out, b'R', |buf| put_i32(buf, 3));
+}
+
+pub fn parameter_status(out: &mut Vec<u8>, name: &str, value: &str) {
+    write_message(out, b'S', |buf| {
+        put_cstring(buf, name);
+        put_cstring(buf, value);
+    });
+}
+
+pub fn backend_key_data(out: &mut Vec<u8>, process_id: i32, secret_key: i32) {
+    write_message(out, b'K', |buf| {
+        put_i32(buf, process_id);
+        put_i32(buf, secret_key);
+    });
+}
+
+pub fn ready_for_query(out: &mut Vec<u8>, transaction_status: u8) {
+    write_message(out, b'Z', |buf| buf.push(transaction_status));
+}
+
+pub fn row_description(out: &mut Vec<u8>, fields: &[FieldDescription]) {
+    write_message(out, b'T', |buf| {
+        put_i16(buf, fields.len() as i16);
+        for field in fields {
+            put_cstring(buf, &field.name);
+            // table oid and column attribute number.
+            put_i32(buf, 0);
+            put_i16(buf, 0);
+            put_i32(buf, field.type_oid as i32);
+            put_i16(buf, field.type_size);
+            // type modifier.
+            put_i32(buf, -1);
+            put_i16(buf, field.format);
+        }
+    });
+}
+
+pub fn command_complete(out: &mut Vec<u8>, tag: &str) {
+    write_message(out, b'C', |buf| put_cstring(buf, tag));
+}
+
+pub fn empty_query_response(out: &mut Vec<u8>) {
+    write_message(out, b'I', |_| {});
+}
+
+pub fn error_response(out: &mut Vec<u8>, sql_state: &str, message: &str) {
+    write_message(out, b'E', |buf| {
+        for (field, value) in [(b'S', "ERROR"), (b'V', "ERROR"), (b'C', sql_state)] {
+            buf.push(field);
+            put_cstring(buf, value);
+        }
+        buf.push(b'M');
+        // A null byte would end the field early.
+        put_cstring(buf, &message.replace('\0', ""));
+        buf.push(0);
+    });
+}
+
+pub fn parse_complete(out: &mut Vec<u8>) {
+    write_message(out, b'1', |_| {});
+}
+
+pub fn bind_complete(out: &mut Vec<u8>) {
+    write_message(out, b'2', |_| {});
+}
+
+pub fn close_complete(out: &mut Vec<u8>) {
+    write_message(out, b'3', |_| {});
+}
+
+pub fn no_data(out: &mut Vec<u8>) {
+    write_message(out, b'n', |_| {});
+}
+
+pub fn portal_suspended(out: &mut Vec<u8>) {
+    write_message(out, b's', |_| {});
+}
+
+pub fn parameter_description(out: &mut Vec<u8>, type_oids: &[u32]) {
+    write_message(out, b't', |buf| {
+        put_i16(buf, type_oids.len() as i16);
+        for oid in type_oids {
+            put_i32(buf, *oid as i32);
+        }
+    });
+}
+
+/// Map an error to the SQLSTATE code reported to the client.
+///
+/// See: https://www.postgresql.org/docs/current/errcodes-appendix.html
+pub fn sql_state(error: &ErrorCode) -> &'static str {
+    match error.code() {
+        ErrorCode::SYNTAX_EXCEPTION => "42601",
+        ErrorCode::SEMANTIC_ERROR => "42000",
+        ErrorCode::UNKNOWN_DATABASE => "3D000",
+        ErrorCode::UNKNOWN_TABLE => "42P01",
+        ErrorCode::UNKNOWN_COLUMN => "42703",
+        ErrorCode::UNKNOWN_FUNCTION => "42883",
+        ErrorCode::DATABASE_ALREADY_EXISTS => "42P04",
+        ErrorCode::TABLE_ALREADY_EXISTS => "42P07",
+        ErrorCode::PERMISSION_DENIED => "42501",
+        ErrorCode::AUTHENTICATE_FAILURE => "28P01",
+        ErrorCode::ABORTED_QUERY => "57014",
+        ErrorCode::BAD_ARGUMENTS => "22023",
+        _ => "XX000",
+    }
+}
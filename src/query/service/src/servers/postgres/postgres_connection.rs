@@ -0,0 +1,750 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::net::Shutdown;
+use std::sync::Arc;
+
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::io::BufReader;
+use common_base::base::tokio::io::BufWriter;
+use common_base::base::tokio::net::tcp::OwnedReadHalf;
+use common_base::base::tokio::net::tcp::OwnedWriteHalf;
+use common_base::base::tokio::net::TcpStream;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::ScalarRef;
+use common_expression::SendableDataBlockStream;
+use common_formats::field_encoder::FieldEncoderRowBased;
+use common_formats::field_encoder::FieldEncoderValues;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::UserIdentity;
+use common_sql::plans::Plan;
+use common_sql::Planner;
+use common_users::UserApiProvider;
+use dashmap::DashMap;
+use futures_util::StreamExt;
+use log::error;
+use log::info;
+use rand::Rng;
+
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::servers::postgres::codec;
+use crate::servers::postgres::codec::FrontendMessage;
+use crate::servers::postgres::codec::StartupMessage;
+use crate::servers::postgres::codec::TRANSACTION_FAILED;
+use crate::servers::postgres::codec::TRANSACTION_IDLE;
+use crate::servers::postgres::codec::TRANSACTION_IN_BLOCK;
+use crate::servers::postgres::postgres_federated::FederatedResult;
+use crate::servers::postgres::postgres_federated::PostgresFederated;
+use crate::servers::postgres::statement::bind_placeholders;
+use crate::servers::postgres::statement::command_tag;
+use crate::servers::postgres::statement::count_placeholders;
+use crate::servers::postgres::statement::decode_parameter;
+use crate::servers::postgres::statement::split_statements;
+use crate::servers::postgres::types;
+use crate::servers::postgres::types::BINARY_FORMAT;
+use crate::servers::postgres::types::TEXT_FORMAT;
+use crate::servers::postgres::types::TEXT_OID;
+use crate::servers::postgres::POSTGRES_VERSION;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+// Flush the result set to the client once the buffer is larger than 100KB.
+const RESULT_SET_FLUSH_SIZE: usize = 100 * 1024;
+
+/// The (process id, secret key) of the connections, which identifies the session
+/// to cancel in a CancelRequest.
+pub type BackendKeys = Arc<DashMap<(i32, i32), String>>;
+
+struct PreparedStatement {
+    query: String,
+    param_types: Vec<u32>,
+}
+
+enum PortalState {
+    Bound,
+    Planned(Arc<QueryContext>, Box<Plan>),
+    // Suspended by an Execute with a row limit.
+    Running(Box<RunningQuery>),
+    Finished,
+}
+
+struct Portal {
+    query: String,
+    // The format of each column in the result set, see `types::format_of`.
+    result_formats: Vec<i16>,
+    state: PortalState,
+}
+
+struct RunningQuery {
+    context: Arc<QueryContext>,
+    schema: DataSchemaRef,
+    has_result_set: bool,
+    stream: SendableDataBlockStream,
+    encoder: FieldEncoderValues,
+    result_formats: Vec<i16>,
+    // Columns of the current block and the next row to send.
+    pending: Option<(Vec<Column>, usize)>,
+    rows: usize,
+}
+
+pub struct PostgresConnection {
+    session: Arc<Session>,
+    client_ip: Option<String>,
+    reader: BufReader<OwnedReadHalf>,
+    writer: BufWriter<OwnedWriteHalf>,
+    out: Vec<u8>,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+    transaction_status: u8,
+    // Skip the extended query messages until Sync after an error.
+    skip_until_sync: bool,
+}
+
+impl PostgresConnection {
+    #[async_backtrace::framed]
+    pub async fn run_on_stream(mut stream: TcpStream, backend_keys: BackendKeys) -> Result<()> {
+        let params = match Self::startup(&mut stream, &backend_keys).await? {
+            None => return Ok(()),
+            Some(params) => params,
+        };
+
+        let session = match SessionManager::instance()
+            .create_session(SessionType::Postgres)
+            .await
+        {
+            Ok(session) => session,
+            Err(error) => {
+                let mut out = vec![];
+                codec::error_response(&mut out, "53300", &error.message());
+                stream.write_all(&out).await?;
+                return Ok(());
+            }
+        };
+
+        let stream = Self::attach_session(&session, stream)?;
+        let client_ip = stream.peer_addr().ok().map(|addr| addr.ip().to_string());
+        let (r, w) = stream.into_split();
+        let mut connection = PostgresConnection {
+            session,
+            client_ip,
+            reader: BufReader::new(r),
+            writer: BufWriter::new(w),
+            out: vec![],
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+            transaction_status: TRANSACTION_IDLE,
+            skip_until_sync: false,
+        };
+
+        let key = (
+            rand::thread_rng().gen_range(1..i32::MAX),
+            rand::thread_rng().gen(),
+        );
+        backend_keys.insert(key, connection.session.get_id());
+        let result = connection.run(params, key).await;
+        backend_keys.remove(&key);
+        result
+    }
+
+    /// Handle the messages before the StartupMessage, returns the startup parameters,
+    /// or None if the connection is a CancelRequest.
+    async fn startup(
+        stream: &mut TcpStream,
+        backend_keys: &BackendKeys,
+    ) -> Result<Option<HashMap<String, String>>> {
+        loop {
+            match codec::read_startup_message(stream).await? {
+                // Neither SSL nor GSSAPI encryption is supported, the client may go on
+                // with an unencrypted connection.
+                StartupMessage::SslRequest | StartupMessage::GssEncRequest => {
+                    stream.write_all(b"N").await?;
+                }
+                StartupMessage::Cancel {
+                    process_id,
+                    secret_key,
+                } => {
+                    if let Some(id) = backend_keys.get(&(process_id, secret_key)) {
+                        if let Some(session) =
+                            SessionManager::instance().get_session_by_id(id.value())
+                        {
+                            info!("Postgres cancel request for session {}", id.value());
+                            session.force_kill_query(ErrorCode::AbortedQuery(
+                                "canceling statement due to user request",
+                            ));
+                        }
+                    }
+                    return Ok(None);
+                }
+                StartupMessage::Startup { params } => return Ok(Some(params)),
+            }
+        }
+    }
+
+    fn attach_session(session: &Arc<Session>, stream: TcpStream) -> Result<TcpStream> {
+        let stream = stream.into_std()?;
+        let stream_ref = stream.try_clone()?;
+        session.attach(stream.peer_addr().ok(), move || {
+            if let Err(error) = stream_ref.shutdown(Shutdown::Both) {
+                error!("Cannot shutdown Postgres session io {}", error);
+            }
+        });
+        Ok(TcpStream::from_std(stream)?)
+    }
+
+    #[async_backtrace::framed]
+    async fn run(&mut self, params: HashMap<String, String>, key: (i32, i32)) -> Result<()> {
+        if !self.authenticate(&params).await? {
+            return Ok(());
+        }
+
+        codec::authentication_ok(&mut self.out);
+        let timezone = self.session.get_settings().get_timezone()?;
+        for (name, value) in [
+            ("server_version", POSTGRES_VERSION),
+            ("server_encoding", "UTF8"),
+            ("client_encoding", "UTF8"),
+            ("DateStyle", "ISO, YMD"),
+            ("TimeZone", timezone.as_str()),
+            ("integer_datetimes", "on"),
+            ("standard_conforming_strings", "on"),
+        ] {
+            codec::parameter_status(&mut self.out, name, value);
+        }
+        codec::backend_key_data(&mut self.out, key.0, key.1);
+        codec::ready_for_query(&mut self.out, self.transaction_status);
+        self.flush().await?;
+
+        loop {
+            let message = match codec::read_message(&mut self.reader).await? {
+                None => return Ok(()),
+                Some(message) => message,
+            };
+
+            match message {
+                FrontendMessage::Terminate => return Ok(()),
+                FrontendMessage::Sync => {
+                    self.skip_until_sync = false;
+                    if self.transaction_status != TRANSACTION_IN_BLOCK {
+                        self.portals.clear();
+                    }
+                    codec::ready_for_query(&mut self.out, self.transaction_status);
+                    self.flush().await?;
+                }
+                FrontendMessage::Flush => self.flush().await?,
+                FrontendMessage::Query(query) => {
+                    if let Err(error) = self.simple_query(&query).await {
+                        self.write_error(&error);
+                    }
+                    codec::ready_for_query(&mut self.out, self.transaction_status);
+                    self.flush().await?;
+                }
+                FrontendMessage::Password(_) => {
+                    return Err(ErrorCode::BadBytes(
+                        "unexpected password message after authentication",
+                    ));
+                }
+                message => {
+                    if self.skip_until_sync {
+                        continue;
+                    }
+                    if let Err(error) = self.extended_query(message).await {
+                        self.write_error(&error);
+                        self.skip_until_sync = true;
+                    }
+                }
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn authenticate(&mut self, params: &HashMap<String, String>) -> Result<bool> {
+        let user = params.get("user").cloned().unwrap_or_default();
+        let tenant = self.session.get_current_tenant();
+        let identity = UserIdentity::new(&user, "%");
+
+        // Ask for the password unless the user is known to have none, so that an
+        // unknown user can not be told from a wrong password.
+        let password_required = match UserApiProvider::instance()
+            .get_user_with_client_ip(&tenant, identity, self.client_ip.as_deref())
+            .await
+        {
+            Ok(user_info) => !matches!(user_info.auth_info, AuthInfo::None),
+            Err(_) => true,
+        };
+
+        let password = match password_required {
+            false => None,
+            true => {
+                codec::authentication_cleartext_password(&mut self.out);
+                self.flush().await?;
+                match codec::read_message(&mut self.reader).await? {
+                    Some(FrontendMessage::Password(password)) => Some(password),
+                    _ => return Ok(false),
+                }
+            }
+        };
+
+        let credential = Credential::Password {
+            name: user.clone(),
+            password,
+            client_ip: self.client_ip.clone(),
+        };
+        if let Err(error) = AuthMgr::instance()
+            .auth(self.session.clone(), &credential)
+            .await
+        {
+            info!("Postgres authenticate failed for user {}: {}", user, error);
+            codec::error_response(
+                &mut self.out,
+                "28P01",
+                &format!("password authentication failed for user \"{}\"", user),
+            );
+            self.flush().await?;
+            return Ok(false);
+        }
+
+        // libpq clients connect to the database named after the user by default,
+        // which usually does not exist in databend.
+        if let Some(database) = params.get("database") {
+            if database != &user && database != "postgres" {
+                let query = format!("USE `{}`", database.replace('`', "``"));
+                if let Err(error) = self.run_to_end(&query).await {
+                    self.write_error(&error);
+                    self.flush().await?;
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    #[async_backtrace::framed]
+    async fn simple_query(&mut self, query: &str) -> Result<()> {
+        let statements = split_statements(query);
+        if statements.is_empty() {
+            codec::empty_query_response(&mut self.out);
+            return Ok(());
+        }
+
+        for statement in statements {
+            if let Some(result) = PostgresFederated::check(statement) {
+                self.write_federated(result, true, &[]);
+                continue;
+            }
+            let (context, plan) = self.plan(statement).await?;
+            let mut running = Self::start(context, *plan, vec![]).await?;
+            if running.has_result_set {
+                let fields = types::row_description(&running.schema, &[]);
+                codec::row_description(&mut self.out, &fields);
+            }
+            self.write_rows(&mut running, 0).await?;
+            self.complete(statement, &running);
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn extended_query(&mut self, message: FrontendMessage) -> Result<()> {
+        match message {
+            FrontendMessage::Parse {
+                name,
+                query,
+                mut param_types,
+            } => {
+                if !name.is_empty() && self.statements.contains_key(&name) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "prepared statement \"{}\" already exists",
+                        name
+                    )));
+                }
+                let num_params = count_placeholders(&query).max(param_types.len());
+                param_types.resize(num_params, 0);
+                self.statements
+                    .insert(name, PreparedStatement { query, param_types });
+                codec::parse_complete(&mut self.out);
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            } => {
+                let statement = self.get_statement(&statement)?;
+                if let Some(format) = result_formats
+                    .iter()
+                    .find(|format| !matches!(**format, TEXT_FORMAT | BINARY_FORMAT))
+                {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "unsupported format code: {}",
+                        format
+                    )));
+                }
+                let mut values = Vec::with_capacity(params.len());
+                for (i, param) in params.iter().enumerate() {
+                    let format = types::format_of(&param_formats, i);
+                    let oid = statement.param_types.get(i).copied().unwrap_or(0);
+                    values.push(match param {
+                        None => None,
+                        Some(value) => Some(decode_parameter(value, format, oid)?),
+                    });
+                }
+                let query = bind_placeholders(&statement.query, &values, &statement.param_types)?;
+                self.portals.insert(portal, Portal {
+                    query,
+                    result_formats,
+                    state: PortalState::Bound,
+                });
+                codec::bind_complete(&mut self.out);
+            }
+            FrontendMessage::Describe { kind: b'S', name } => {
+                let statement = self.get_statement(&name)?;
+                let param_types = statement
+                    .param_types
+                    .iter()
+                    .map(|oid| if *oid == 0 { TEXT_OID } else { *oid })
+                    .collect::<Vec<_>>();
+                let query = statement.query.clone();
+                codec::parameter_description(&mut self.out, &param_types);
+
+                if let Some(result) = PostgresFederated::check(&query) {
+                    self.describe_federated(&result, &[]);
+                    return Ok(());
+                }
+                // The parameters are unknown yet, plan with NULLs to know the result set.
+                // The formats are unknown until Bind, they are reported as text.
+                let nulls = vec![None; param_types.len()];
+                let query = bind_placeholders(&query, &nulls, &param_types)?;
+                match self.plan(&query).await {
+                    Ok((_, plan)) if plan.has_result_set() => {
+                        let fields = types::row_description(&plan.schema(), &[]);
+                        codec::row_description(&mut self.out, &fields);
+                    }
+                    _ => codec::no_data(&mut self.out),
+                }
+            }
+            FrontendMessage::Describe { kind: b'P', name } => {
+                let mut portal = self.take_portal(&name)?;
+                let result = self.describe_portal(&mut portal).await;
+                self.portals.insert(name, portal);
+                result?;
+            }
+            FrontendMessage::Execute { portal, max_rows } => {
+                let mut state = self.take_portal(&portal)?;
+                let result = self.execute_portal(&mut state, max_rows).await;
+                self.portals.insert(portal, state);
+                result?;
+            }
+            FrontendMessage::Close { kind, name } => {
+                if kind == b'S' {
+                    self.statements.remove(&name);
+                } else {
+                    self.portals.remove(&name);
+                }
+                codec::close_complete(&mut self.out);
+            }
+            FrontendMessage::Describe { kind, .. } => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid describe message type: {:?}",
+                    kind as char
+                )));
+            }
+            _ => unreachable!("handled out of the extended query protocol"),
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn describe_portal(&mut self, portal: &mut Portal) -> Result<()> {
+        if let Some(result) = PostgresFederated::check(&portal.query) {
+            self.describe_federated(&result, &portal.result_formats);
+            return Ok(());
+        }
+        if matches!(portal.state, PortalState::Bound) && !portal.query.trim().is_empty() {
+            let (context, plan) = self.plan(&portal.query).await?;
+            portal.state = PortalState::Planned(context, plan);
+        }
+        let formats = &portal.result_formats;
+        match &portal.state {
+            PortalState::Planned(_, plan) if plan.has_result_set() => {
+                let fields = types::row_description(&plan.schema(), formats);
+                codec::row_description(&mut self.out, &fields);
+            }
+            PortalState::Running(running) if running.has_result_set => {
+                let fields = types::row_description(&running.schema, formats);
+                codec::row_description(&mut self.out, &fields);
+            }
+            _ => codec::no_data(&mut self.out),
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_portal(&mut self, portal: &mut Portal, max_rows: i32) -> Result<()> {
+        let state = std::mem::replace(&mut portal.state, PortalState::Finished);
+        let mut running = match state {
+            PortalState::Bound => {
+                if portal.query.trim().is_empty() {
+                    codec::empty_query_response(&mut self.out);
+                    return Ok(());
+                }
+                if let Some(result) = PostgresFederated::check(&portal.query) {
+                    self.write_federated(result, false, &portal.result_formats);
+                    return Ok(());
+                }
+                let (context, plan) = self.plan(&portal.query).await?;
+                Self::start(context, *plan, portal.result_formats.clone()).await?
+            }
+            PortalState::Planned(context, plan) => {
+                Self::start(context, *plan, portal.result_formats.clone()).await?
+            }
+            PortalState::Running(running) => *running,
+            PortalState::Finished => {
+                codec::command_complete(&mut self.out, &command_tag(&portal.query, 0));
+                return Ok(());
+            }
+        };
+
+        if self
+            .write_rows(&mut running, max_rows.max(0) as usize)
+            .await?
+        {
+            self.complete(&portal.query, &running);
+        } else {
+            codec::portal_suspended(&mut self.out);
+            portal.state = PortalState::Running(Box::new(running));
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn plan(&self, query: &str) -> Result<(Arc<QueryContext>, Box<Plan>)> {
+        info!("Postgres query: {}", query);
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_sql(query).await?;
+        context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+        Ok((context, Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    async fn start(
+        context: Arc<QueryContext>,
+        plan: Plan,
+        result_formats: Vec<i16>,
+    ) -> Result<RunningQuery> {
+        let interpreter = match InterpreterFactory::get(context.clone(), &plan).await {
+            Ok(interpreter) => interpreter,
+            Err(error) => {
+                InterpreterQueryLog::fail_to_start(context, error.clone());
+                return Err(error);
+            }
+        };
+        // Execute in the runtime of the query context, the executors may block the
+        // thread which is waiting for them, see `clickhouse_handler::execute`.
+        let stream = context
+            .try_spawn({
+                let interpreter = interpreter.clone();
+                let context = context.clone();
+                async move { interpreter.execute(context).await }
+            })?
+            .await
+            .map_err_to_code(
+                ErrorCode::TokioError,
+                || "Cannot join handle from context's runtime",
+            )??;
+        let format = context.get_format_settings()?;
+        Ok(RunningQuery {
+            context,
+            schema: interpreter.schema(),
+            has_result_set: plan.has_result_set(),
+            stream,
+            encoder: FieldEncoderValues::create_for_postgres_handler(format.timezone),
+            result_formats,
+            pending: None,
+            rows: 0,
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn run_to_end(&self, query: &str) -> Result<()> {
+        let (context, plan) = self.plan(query).await?;
+        let mut running = Self::start(context, *plan, vec![]).await?;
+        while let Some(block) = running.stream.next().await {
+            block?;
+        }
+        Ok(())
+    }
+
+    /// Send at most `max_rows` rows of the query (all the rows if 0),
+    /// returns whether all the rows are sent.
+    #[async_backtrace::framed]
+    async fn write_rows(&mut self, running: &mut RunningQuery, max_rows: usize) -> Result<bool> {
+        let mut sent = 0;
+        loop {
+            if let Some((columns, row)) = &mut running.pending {
+                let num_rows = columns.first().map_or(0, |c| c.len());
+                while *row < num_rows {
+                    if max_rows > 0 && sent >= max_rows {
+                        return Ok(false);
+                    }
+                    write_data_row(
+                        &mut self.out,
+                        &running.encoder,
+                        &running.result_formats,
+                        columns,
+                        *row,
+                    );
+                    *row += 1;
+                    sent += 1;
+                    running.rows += 1;
+                    if self.out.len() >= RESULT_SET_FLUSH_SIZE {
+                        self.flush().await?;
+                    }
+                }
+                running.pending = None;
+            }
+
+            match running.stream.next().await {
+                None => return Ok(true),
+                Some(block) => {
+                    let block = block?;
+                    if block.num_rows() > 0 && running.has_result_set {
+                        running.pending = Some((block_columns(&block), 0));
+                    }
+                }
+            }
+        }
+    }
+
+    fn complete(&mut self, query: &str, running: &RunningQuery) {
+        let rows = match running.has_result_set {
+            true => running.rows,
+            false => running.context.get_write_progress_value().rows,
+        };
+        codec::command_complete(&mut self.out, &command_tag(query, rows));
+    }
+
+    fn write_federated(&mut self, result: FederatedResult, describe: bool, formats: &[i16]) {
+        match result {
+            FederatedResult::Command(tag) => {
+                self.transaction_status = match tag.as_str() {
+                    "BEGIN" => TRANSACTION_IN_BLOCK,
+                    "COMMIT" | "ROLLBACK" => TRANSACTION_IDLE,
+                    _ => self.transaction_status,
+                };
+                codec::command_complete(&mut self.out, &tag);
+            }
+            FederatedResult::Rows(schema, block) => {
+                if describe {
+                    let fields = types::row_description(&schema, formats);
+                    codec::row_description(&mut self.out, &fields);
+                }
+                let encoder = FieldEncoderValues::create_for_postgres_handler(
+                    self.session.get_format_settings().timezone,
+                );
+                let columns = block_columns(&block);
+                for row in 0..block.num_rows() {
+                    write_data_row(&mut self.out, &encoder, formats, &columns, row);
+                }
+                codec::command_complete(&mut self.out, &format!("SELECT {}", block.num_rows()));
+            }
+        }
+    }
+
+    fn describe_federated(&mut self, result: &FederatedResult, formats: &[i16]) {
+        match result {
+            FederatedResult::Command(_) => codec::no_data(&mut self.out),
+            FederatedResult::Rows(schema, _) => {
+                let fields = types::row_description(schema, formats);
+                codec::row_description(&mut self.out, &fields);
+            }
+        }
+    }
+
+    fn get_statement(&self, name: &str) -> Result<&PreparedStatement> {
+        self.statements.get(name).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("prepared statement \"{}\" does not exist", name))
+        })
+    }
+
+    fn take_portal(&mut self, name: &str) -> Result<Portal> {
+        self.portals
+            .remove(name)
+            .ok_or_else(|| ErrorCode::BadArguments(format!("portal \"{}\" does not exist", name)))
+    }
+
+    fn write_error(&mut self, error: &ErrorCode) {
+        error!("Postgres query error: {}", error);
+        if self.transaction_status == TRANSACTION_IN_BLOCK {
+            self.transaction_status = TRANSACTION_FAILED;
+        }
+        codec::error_response(&mut self.out, codec::sql_state(error), &error.message());
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        self.writer.write_all(&self.out).await?;
+        self.writer.flush().await?;
+        self.out.clear();
+        Ok(())
+    }
+}
+
+fn block_columns(block: &DataBlock) -> Vec<Column> {
+    block
+        .convert_to_full()
+        .columns()
+        .iter()
+        .map(|column| column.value.clone().into_column().unwrap())
+        .collect()
+}
+
+fn write_data_row(
+    out: &mut Vec<u8>,
+    encoder: &FieldEncoderValues,
+    formats: &[i16],
+    columns: &[Column],
+    row: usize,
+) {
+    codec::write_message(out, b'D', |buf| {
+        codec::put_i16(buf, columns.len() as i16);
+        for (i, column) in columns.iter().enumerate() {
+            if matches!(column.index(row), None | Some(ScalarRef::Null)) {
+                codec::put_i32(buf, -1);
+                continue;
+            }
+            let start = buf.len();
+            codec::put_i32(buf, 0);
+            match types::format_of(formats, i) {
+                BINARY_FORMAT => types::write_binary_field(encoder, column, row, buf),
+                _ => encoder.write_field(column, row, buf, true),
+            }
+            let len = (buf.len() - start - 4) as i32;
+            buf[start..start + 4].copy_from_slice(&len.to_be_bytes());
+        }
+    });
+}
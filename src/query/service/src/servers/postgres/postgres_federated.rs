@@ -0,0 +1,100 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+
+/// Result of a statement answered by the handler itself.
+pub enum FederatedResult {
+    /// A command without result set, with its CommandComplete tag.
+    Command(String),
+    Rows(DataSchemaRef, DataBlock),
+}
+
+/// Statements sent by postgres drivers and tools which have no meaning in databend,
+/// they are answered directly so that the clients can go on.
+pub struct PostgresFederated;
+
+// Session parameters of postgres, which are not databend settings.
+const POSTGRES_ONLY_PARAMETERS: [&str; 14] = [
+    "application_name",
+    "client_encoding",
+    "client_min_messages",
+    "datestyle",
+    "extra_float_digits",
+    "intervalstyle",
+    "lock_timeout",
+    "names",
+    "search_path",
+    "session characteristics",
+    "standard_conforming_strings",
+    "statement_timeout",
+    "transaction",
+    "transaction_isolation",
+];
+
+impl PostgresFederated {
+    pub fn check(query: &str) -> Option<FederatedResult> {
+        let query = query
+            .trim()
+            .trim_end_matches(';')
+            .trim()
+            .to_ascii_lowercase();
+        let words = query.split_whitespace().collect::<Vec<_>>();
+        match words.as_slice() {
+            ["begin", ..] | ["start", "transaction", ..] => {
+                Some(FederatedResult::Command("BEGIN".to_string()))
+            }
+            ["commit", ..] | ["end", ..] => Some(FederatedResult::Command("COMMIT".to_string())),
+            ["rollback", ..] | ["abort", ..] => {
+                Some(FederatedResult::Command("ROLLBACK".to_string()))
+            }
+            ["set", rest @ ..] => {
+                let rest = rest.join(" ");
+                let rest = rest
+                    .strip_prefix("session ")
+                    .or_else(|| rest.strip_prefix("local "))
+                    .unwrap_or(&rest);
+                let name = rest
+                    .split(|c: char| c.is_whitespace() || c == '=')
+                    .next()
+                    .unwrap_or_default();
+                POSTGRES_ONLY_PARAMETERS
+                    .iter()
+                    .any(|p| *p == name || rest.starts_with(p))
+                    .then(|| FederatedResult::Command("SET".to_string()))
+            }
+            ["show", "transaction", "isolation", "level"] | ["show", "transaction_isolation"] => {
+                Some(Self::show_block("transaction_isolation", "read committed"))
+            }
+            ["show", "standard_conforming_strings"] => {
+                Some(Self::show_block("standard_conforming_strings", "on"))
+            }
+            _ => None,
+        }
+    }
+
+    fn show_block(name: &str, value: &str) -> FederatedResult {
+        let schema = DataSchemaRefExt::create(vec![DataField::new(name, DataType::String)]);
+        let block = DataBlock::new_from_columns(vec![StringType::from_data(vec![
+            value.as_bytes().to_vec(),
+        ])]);
+        FederatedResult::Rows(schema, block)
+    }
+}
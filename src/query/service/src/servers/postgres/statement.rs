@@ -0,0 +1,244 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::token::Tokenizer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::servers::postgres::types::is_numeric_oid;
+use crate::servers::postgres::types::BOOL_OID;
+use crate::servers::postgres::types::FLOAT4_OID;
+use crate::servers::postgres::types::FLOAT8_OID;
+use crate::servers::postgres::types::INT2_OID;
+use crate::servers::postgres::types::INT4_OID;
+use crate::servers::postgres::types::INT8_OID;
+
+#[derive(Debug, PartialEq)]
+enum Segment<'a> {
+    Text(&'a str),
+    Placeholder(usize),
+}
+
+/// Split a query into text and `$n` placeholders, the `$` inside string literals,
+/// quoted identifiers and comments is not a placeholder.
+fn split_placeholders(query: &str) -> Vec<Segment<'_>> {
+    let bytes = query.as_bytes();
+    let mut segments = vec![];
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                i += 1;
+                while i < bytes.len() {
+                    if bytes[i] == b'\\' && quote == b'\'' {
+                        i += 2;
+                        continue;
+                    }
+                    if bytes[i] == quote {
+                        // A doubled quote is an escaped quote.
+                        if bytes.get(i + 1) == Some(&quote) {
+                            i += 2;
+                            continue;
+                        }
+                        break;
+                    }
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+                i += 2;
+            }
+            b'$' if bytes.get(i + 1).map_or(false, |b| b.is_ascii_digit()) => {
+                let mut end = i + 1;
+                while end < bytes.len() && bytes[end].is_ascii_digit() {
+                    end += 1;
+                }
+                match query[i + 1..end].parse::<usize>() {
+                    Ok(index) if index > 0 => {
+                        if start < i {
+                            segments.push(Segment::Text(&query[start..i]));
+                        }
+                        segments.push(Segment::Placeholder(index));
+                        start = end;
+                    }
+                    _ => {}
+                }
+                i = end;
+            }
+            _ => i += 1,
+        }
+    }
+    if start < bytes.len() {
+        segments.push(Segment::Text(&query[start..]));
+    }
+    segments
+}
+
+/// The number of parameters of a query, which is the largest `$n` in it.
+pub fn count_placeholders(query: &str) -> usize {
+    split_placeholders(query)
+        .iter()
+        .map(|segment| match segment {
+            Segment::Placeholder(index) => *index,
+            Segment::Text(_) => 0,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Replace the `$n` placeholders with the bound parameters as sql literals,
+/// `None` parameters are NULL.
+pub fn bind_placeholders(
+    query: &str,
+    params: &[Option<String>],
+    param_types: &[u32],
+) -> Result<String> {
+    let mut sql = String::with_capacity(query.len());
+    for segment in split_placeholders(query) {
+        match segment {
+            Segment::Text(text) => sql.push_str(text),
+            Segment::Placeholder(index) => {
+                let param = params.get(index - 1).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "bind message supplies {} parameters, but query requires ${}",
+                        params.len(),
+                        index
+                    ))
+                })?;
+                let oid = param_types.get(index - 1).copied().unwrap_or(0);
+                match param {
+                    None => sql.push_str("NULL"),
+                    Some(value) => sql.push_str(&to_literal(value, oid)),
+                }
+            }
+        }
+    }
+    Ok(sql)
+}
+
+fn to_literal(value: &str, oid: u32) -> String {
+    if is_numeric_oid(oid) && value.parse::<f64>().is_ok() {
+        return value.to_string();
+    }
+    if oid == BOOL_OID {
+        match value.to_ascii_lowercase().as_str() {
+            "t" | "true" | "y" | "yes" | "on" | "1" => return "TRUE".to_string(),
+            "f" | "false" | "n" | "no" | "off" | "0" => return "FALSE".to_string(),
+            _ => {}
+        }
+    }
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+}
+
+/// Decode a bound parameter to its text representation, `format` 0 is text and 1 is binary.
+///
+/// Only the binary format of booleans, integers, floats and strings is supported.
+pub fn decode_parameter(value: &[u8], format: i16, oid: u32) -> Result<String> {
+    let invalid = || {
+        ErrorCode::BadBytes(format!(
+            "invalid binary parameter of type oid {}, length {}",
+            oid,
+            value.len()
+        ))
+    };
+    let text = match format {
+        0 => String::from_utf8(value.to_vec())
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid utf8 parameter: {}", e)))?,
+        1 => match oid {
+            BOOL_OID => match value {
+                [0] => "f".to_string(),
+                [_] => "t".to_string(),
+                _ => return Err(invalid()),
+            },
+            INT2_OID => i16::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string(),
+            INT4_OID => i32::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string(),
+            INT8_OID => i64::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string(),
+            FLOAT4_OID => f32::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string(),
+            FLOAT8_OID => f64::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string(),
+            _ => match String::from_utf8(value.to_vec()) {
+                Ok(text) if !is_numeric_oid(oid) => text,
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "binary format of parameter type oid {} is not supported",
+                        oid
+                    )));
+                }
+            },
+        },
+        format => {
+            return Err(ErrorCode::BadBytes(format!(
+                "unknown parameter format code: {}",
+                format
+            )));
+        }
+    };
+    Ok(text)
+}
+
+/// Split the statements of a simple query by the semicolons, if the query can not be
+/// tokenized, it is left to the planner to report the error.
+pub fn split_statements(query: &str) -> Vec<&str> {
+    let mut statements = vec![];
+    let mut start = 0;
+    for token in Tokenizer::new(query) {
+        match token {
+            Ok(token) if token.kind == TokenKind::SemiColon => {
+                statements.push(&query[start..token.span.start]);
+                start = token.span.end;
+            }
+            Ok(_) => {}
+            Err(_) => return vec![query.trim()],
+        }
+    }
+    statements.push(&query[start..]);
+    statements
+        .into_iter()
+        .map(|statement| statement.trim())
+        .filter(|statement| !statement.is_empty())
+        .collect()
+}
+
+/// The tag of the CommandComplete message, `rows` is the number of rows returned
+/// by a query or affected by a dml.
+pub fn command_tag(query: &str, rows: usize) -> String {
+    let mut words = query
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ';')
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_ascii_uppercase());
+    let first = words.next().unwrap_or_default();
+    match first.as_str() {
+        "SELECT" | "WITH" | "SHOW" | "DESC" | "DESCRIBE" | "EXPLAIN" | "VALUES" => {
+            format!("SELECT {}", rows)
+        }
+        "INSERT" | "REPLACE" => format!("INSERT 0 {}", rows),
+        "UPDATE" | "DELETE" | "COPY" => format!("{} {}", first, rows),
+        "CREATE" | "DROP" | "ALTER" | "TRUNCATE" => match words.next() {
+            Some(second) => format!("{} {}", first, second),
+            None => first,
+        },
+        _ => first,
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::Column;
+use common_expression::DataSchemaRef;
+use common_expression::ScalarRef;
+use common_formats::field_encoder::FieldEncoderRowBased;
+use common_formats::field_encoder::FieldEncoderValues;
+
+use crate::servers::postgres::codec;
+use crate::servers::postgres::codec::FieldDescription;
+
+// Type oids from `pg_type.dat` of postgres.
+pub const BOOL_OID: u32 = 16;
+pub const INT8_OID: u32 = 20;
+pub const INT2_OID: u32 = 21;
+pub const INT4_OID: u32 = 23;
+pub const TEXT_OID: u32 = 25;
+pub const JSON_OID: u32 = 114;
+pub const FLOAT4_OID: u32 = 700;
+pub const FLOAT8_OID: u32 = 701;
+pub const UNKNOWN_OID: u32 = 705;
+pub const VARCHAR_OID: u32 = 1043;
+pub const DATE_OID: u32 = 1082;
+pub const TIMESTAMP_OID: u32 = 1114;
+pub const NUMERIC_OID: u32 = 1700;
+
+pub const TEXT_FORMAT: i16 = 0;
+pub const BINARY_FORMAT: i16 = 1;
+
+// Days and microseconds from the unix epoch to the postgres epoch 2000-01-01.
+const POSTGRES_EPOCH_DAYS: i32 = 10957;
+const POSTGRES_EPOCH_MICROS: i64 = 946_684_800_000_000;
+
+/// Map a column type to the postgres type oid and the type size, unsigned integers
+/// are widened to the next signed type, types without a postgres counterpart are text.
+pub fn type_oid(data_type: &DataType) -> (u32, i16) {
+    match data_type {
+        DataType::Nullable(inner) => type_oid(inner),
+        DataType::Boolean => (BOOL_OID, 1),
+        DataType::Number(number) => match number {
+            NumberDataType::Int8 | NumberDataType::UInt8 | NumberDataType::Int16 => (INT2_OID, 2),
+            NumberDataType::UInt16 | NumberDataType::Int32 => (INT4_OID, 4),
+            NumberDataType::UInt32 | NumberDataType::Int64 => (INT8_OID, 8),
+            NumberDataType::UInt64 => (NUMERIC_OID, -1),
+            NumberDataType::Float32 => (FLOAT4_OID, 4),
+            NumberDataType::Float64 => (FLOAT8_OID, 8),
+        },
+        DataType::Decimal(_) => (NUMERIC_OID, -1),
+        DataType::Date => (DATE_OID, 4),
+        DataType::Timestamp => (TIMESTAMP_OID, 8),
+        DataType::Variant => (JSON_OID, -1),
+        _ => (TEXT_OID, -1),
+    }
+}
+
+/// The format of the i-th column or parameter, a single format applies to all of them.
+pub fn format_of(formats: &[i16], index: usize) -> i16 {
+    match formats.len() {
+        0 => TEXT_FORMAT,
+        1 => formats[0],
+        _ => formats.get(index).copied().unwrap_or(TEXT_FORMAT),
+    }
+}
+
+pub fn row_description(schema: &DataSchemaRef, formats: &[i16]) -> Vec<FieldDescription> {
+    schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let (type_oid, type_size) = type_oid(field.data_type());
+            FieldDescription {
+                name: field.name().clone(),
+                type_oid,
+                type_size,
+                format: format_of(formats, i),
+            }
+        })
+        .collect()
+}
+
+/// Write a non-null field in the binary format of the type oid from `type_oid`,
+/// the binary format of text and json is the same as the text format.
+pub fn write_binary_field(
+    encoder: &FieldEncoderValues,
+    column: &Column,
+    row: usize,
+    buf: &mut Vec<u8>,
+) {
+    match column.index(row) {
+        Some(ScalarRef::Boolean(v)) => buf.push(v as u8),
+        Some(ScalarRef::Number(number)) => match number {
+            NumberScalar::Int8(v) => codec::put_i16(buf, v as i16),
+            NumberScalar::UInt8(v) => codec::put_i16(buf, v as i16),
+            NumberScalar::Int16(v) => codec::put_i16(buf, v),
+            NumberScalar::UInt16(v) => codec::put_i32(buf, v as i32),
+            NumberScalar::Int32(v) => codec::put_i32(buf, v),
+            NumberScalar::UInt32(v) => buf.extend_from_slice(&(v as i64).to_be_bytes()),
+            NumberScalar::Int64(v) => buf.extend_from_slice(&v.to_be_bytes()),
+            NumberScalar::UInt64(v) => put_numeric(buf, &v.to_string()),
+            NumberScalar::Float32(v) => buf.extend_from_slice(&v.0.to_be_bytes()),
+            NumberScalar::Float64(v) => buf.extend_from_slice(&v.0.to_be_bytes()),
+        },
+        Some(ScalarRef::Decimal(v)) => put_numeric(buf, &v.to_string()),
+        Some(ScalarRef::Date(v)) => codec::put_i32(buf, v - POSTGRES_EPOCH_DAYS),
+        Some(ScalarRef::Timestamp(v)) => {
+            buf.extend_from_slice(&(v - POSTGRES_EPOCH_MICROS).to_be_bytes())
+        }
+        _ => encoder.write_field(column, row, buf, true),
+    }
+}
+
+// Write a decimal string in the binary format of numeric: the number of digits, the weight
+// of the first digit, the sign, the display scale and the digits, all in base 10000.
+fn put_numeric(buf: &mut Vec<u8>, text: &str) {
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let (int_part, frac_part) = text.split_once('.').unwrap_or((text, ""));
+    let int_part = int_part.trim_start_matches('0');
+    let int_len = (int_part.len() + 3) / 4 * 4;
+    let frac_len = (frac_part.len() + 3) / 4 * 4;
+    let padded = format!("{:0>int_len$}{:0<frac_len$}", int_part, frac_part);
+    let mut digits = padded
+        .as_bytes()
+        .chunks(4)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .fold(0i16, |acc, c| acc * 10 + (c - b'0') as i16)
+        })
+        .collect::<Vec<_>>();
+
+    let mut weight = (int_len / 4) as i16 - 1;
+    let leading_zeros = digits.iter().take_while(|d| **d == 0).count();
+    digits.drain(..leading_zeros);
+    weight -= leading_zeros as i16;
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        weight = 0;
+    }
+    let sign = if negative && !digits.is_empty() {
+        0x4000
+    } else {
+        0
+    };
+
+    codec::put_i16(buf, digits.len() as i16);
+    codec::put_i16(buf, weight);
+    codec::put_i16(buf, sign);
+    codec::put_i16(buf, frac_part.len() as i16);
+    for digit in digits {
+        codec::put_i16(buf, digit);
+    }
+}
+
+/// Whether a parameter of the type can be inlined into the query without quotes.
+pub fn is_numeric_oid(oid: u32) -> bool {
+    matches!(
+        oid,
+        INT2_OID | INT4_OID | INT8_OID | FLOAT4_OID | FLOAT8_OID | NUMERIC_OID
+    )
+}
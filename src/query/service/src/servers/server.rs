@@ -22,6 +22,8 @@ use common_base::base::DummySignalStream;
 use common_base::base::SignalStream;
 use common_base::base::SignalType;
 use common_exception::Result;
+use common_users::AuditChainManager;
+use common_users::ResourceUsageManager;
 use futures::stream::Abortable;
 use futures::StreamExt;
 use log::error;
@@ -69,6 +71,10 @@ impl ShutdownHandle {
             .unregister_to_metastore(&mut signal)
             .await;
         self.sessions.graceful_shutdown(signal, 5).await;
+        // The usages of the queries finished since the last flush.
+        ResourceUsageManager::instance().flush().await;
+        // The head of the audit chain, the chain continues from it after the restart.
+        AuditChainManager::instance().flush().await;
         self.shutdown_services(false).await;
     }
 
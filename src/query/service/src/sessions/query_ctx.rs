@@ -19,6 +19,7 @@ use std::collections::VecDeque;
 use std::future::Future;
 use std::net::SocketAddr;
 use std::str::FromStr;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -52,6 +53,7 @@ use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::tenant::ResourceUsage;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
@@ -63,7 +65,11 @@ use common_storages_fuse::TableContext;
 use common_storages_parquet::ParquetTable;
 use common_storages_result_cache::ResultScan;
 use common_storages_stage::StageTable;
+use common_users::RoleCacheManager;
 use common_users::UserApiProvider;
+use common_users::WorkloadGroupManager;
+use common_users::WorkloadGroupPermit;
+use common_users::WorkloadGroupResources;
 use dashmap::mapref::multiple::RefMulti;
 use dashmap::DashMap;
 use log::debug;
@@ -80,6 +86,7 @@ use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::sessions::UserResources;
 use crate::storages::Table;
 
 const MYSQL_VERSION: &str = "8.0.26";
@@ -240,6 +247,68 @@ impl QueryContext {
         self.shared.created_time
     }
 
+    pub fn get_user_resources(&self) -> Option<Arc<UserResources>> {
+        self.shared.get_user_resources()
+    }
+
+    pub fn get_workload_group(&self) -> Option<Arc<WorkloadGroupResources>> {
+        self.shared.get_workload_group()
+    }
+
+    pub fn get_cpu_time(&self) -> Arc<AtomicU64> {
+        self.shared.get_cpu_time()
+    }
+
+    pub fn get_spill_metrics(&self) -> Arc<StorageMetrics> {
+        self.shared.get_spill_metrics()
+    }
+
+    pub fn account_resource_usage(&self, total: ResourceUsage) -> ResourceUsage {
+        self.shared.account_resource_usage(total)
+    }
+
+    /// Add the usage reported by the fragments of the query on the other nodes.
+    pub fn add_remote_resource_usage(&self, usage: &ResourceUsage) {
+        self.shared.add_remote_resource_usage(usage)
+    }
+
+    pub fn get_remote_resource_usage(&self) -> ResourceUsage {
+        self.shared.get_remote_resource_usage()
+    }
+
+    /// Wait in the queue of the workload group of the current user, or of the current role
+    /// if the user isn't assigned to any, until the query can run.
+    ///
+    /// The query runs as a part of the group until the returned permit is dropped.
+    #[async_backtrace::framed]
+    pub async fn acquire_workload_group(&self) -> Result<Option<WorkloadGroupPermit>> {
+        let tenant = self.get_tenant();
+        let session = self.get_current_session();
+        let mut name = self.get_current_user()?.option.workload_group().cloned();
+        if name.is_none() {
+            if let Some(role) = session.get_current_role() {
+                let role = RoleCacheManager::instance()
+                    .find_role(&tenant, &role.name)
+                    .await?
+                    .unwrap_or(role);
+                name = role.workload_group;
+            }
+        }
+
+        let Some(name) = name else {
+            return Ok(None);
+        };
+        let group = UserApiProvider::instance()
+            .get_workload_group(&tenant, &name)
+            .await?;
+        let resources = WorkloadGroupManager::instance().get_resources(&tenant, &group);
+
+        self.set_status_info("waiting in the queue of workload group");
+        let permit = resources.acquire().await?;
+        self.shared.set_workload_group(resources);
+        Ok(Some(permit))
+    }
+
     pub fn evict_table_from_cache(&self, catalog: &str, database: &str, table: &str) -> Result<()> {
         self.shared.evict_table_from_cache(catalog, database, table)
     }
@@ -15,6 +15,7 @@
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
@@ -22,6 +23,7 @@ use std::time::SystemTime;
 
 use common_base::base::Progress;
 use common_base::runtime::Runtime;
+use common_base::runtime::ThreadTracker;
 use common_catalog::table_context::MaterializedCtesBlocks;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
@@ -29,11 +31,13 @@ use common_exception::Result;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
+use common_meta_app::tenant::ResourceUsage;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
 use common_storage::DataOperator;
 use common_storage::StorageMetrics;
+use common_users::WorkloadGroupResources;
 use dashmap::DashMap;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
@@ -44,6 +48,8 @@ use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::UserResources;
 use crate::storages::Table;
 
 type DatabaseAndTable = (String, String, String);
@@ -87,6 +93,18 @@ pub struct QueryContextShared {
     pub(in crate::sessions) status: Arc<RwLock<String>>,
     /// Key is (cte index, used_count), value contains cte's materialized blocks
     pub(in crate::sessions) materialized_cte_tables: MaterializedCtesBlocks,
+    /// Resources shared with the other queries of the user, None if the user has no quota.
+    pub(in crate::sessions) user_resources: Arc<RwLock<Option<Arc<UserResources>>>>,
+    /// Resources shared with the other queries of the workload group, set once the query is admitted.
+    pub(in crate::sessions) workload_group: Arc<RwLock<Option<Arc<WorkloadGroupResources>>>>,
+    /// Time the pipeline executors of the query spent in processors, in nanoseconds.
+    pub(in crate::sessions) cpu_time: Arc<AtomicU64>,
+    /// IO of the data spilled to the storage by the query.
+    pub(in crate::sessions) spill_metrics: Arc<StorageMetrics>,
+    /// Resource usage of the query recorded so far, nested interpreters record it too.
+    pub(in crate::sessions) accounted_usage: Arc<Mutex<ResourceUsage>>,
+    /// Resource usage of the fragments of the query run on the other nodes of the cluster.
+    pub(in crate::sessions) remote_usage: Arc<Mutex<ResourceUsage>>,
 }
 
 impl QueryContextShared {
@@ -94,6 +112,14 @@ impl QueryContextShared {
         session: Arc<Session>,
         cluster_cache: Arc<Cluster>,
     ) -> Result<Arc<QueryContextShared>> {
+        let user_resources = match session.get_current_user() {
+            Ok(user) => SessionManager::instance().get_user_resources(
+                &session.get_current_tenant(),
+                &user,
+                None,
+            ),
+            Err(_) => None,
+        };
         Ok(Arc::new(QueryContextShared {
             session,
             cluster_cache,
@@ -121,6 +147,12 @@ impl QueryContextShared {
             can_scan_from_agg_index: Arc::new(AtomicBool::new(true)),
             status: Arc::new(RwLock::new("null".to_string())),
             materialized_cte_tables: Arc::new(Default::default()),
+            user_resources: Arc::new(RwLock::new(user_resources)),
+            workload_group: Arc::new(RwLock::new(None)),
+            cpu_time: Arc::new(AtomicU64::new(0)),
+            spill_metrics: Arc::new(StorageMetrics::default()),
+            accounted_usage: Arc::new(Mutex::new(ResourceUsage::default())),
+            remote_usage: Arc::new(Mutex::new(ResourceUsage::default())),
         }))
     }
 
@@ -292,6 +324,10 @@ impl QueryContextShared {
         match &*query_runtime {
             Some(query_runtime) => Ok(query_runtime.clone()),
             None => {
+                // Track the memory of the query as a part of the memory used by the user.
+                let _guard = self
+                    .get_user_resources()
+                    .map(|resources| ThreadTracker::enter(Some(resources.get_mem_stat())));
                 // To avoid possible deadlock, we should keep at least two threads.
                 let runtime = Arc::new(Runtime::with_worker_threads(
                     2,
@@ -303,6 +339,59 @@ impl QueryContextShared {
         }
     }
 
+    pub fn get_user_resources(&self) -> Option<Arc<UserResources>> {
+        self.user_resources.read().clone()
+    }
+
+    /// Run the query as a part of the workload group, the resources of the user are
+    /// switched to the ones accounted to the group too.
+    pub fn set_workload_group(&self, resources: Arc<WorkloadGroupResources>) {
+        if let Ok(user) = self.session.get_current_user() {
+            *self.user_resources.write() = SessionManager::instance().get_user_resources(
+                &self.session.get_current_tenant(),
+                &user,
+                Some(&resources),
+            );
+        }
+        *self.workload_group.write() = Some(resources);
+    }
+
+    pub fn get_workload_group(&self) -> Option<Arc<WorkloadGroupResources>> {
+        self.workload_group.read().clone()
+    }
+
+    pub fn get_cpu_time(&self) -> Arc<AtomicU64> {
+        self.cpu_time.clone()
+    }
+
+    pub fn get_spill_metrics(&self) -> Arc<StorageMetrics> {
+        self.spill_metrics.clone()
+    }
+
+    /// Returns the part of `total` which is not recorded yet, and mark `total` as recorded.
+    pub fn account_resource_usage(&self, total: ResourceUsage) -> ResourceUsage {
+        let mut accounted = self.accounted_usage.lock();
+        let usage = ResourceUsage {
+            query_count: total.query_count.saturating_sub(accounted.query_count),
+            cpu_time_ms: total.cpu_time_ms.saturating_sub(accounted.cpu_time_ms),
+            scan_bytes: total.scan_bytes.saturating_sub(accounted.scan_bytes),
+            spill_bytes: total.spill_bytes.saturating_sub(accounted.spill_bytes),
+            result_bytes: total.result_bytes.saturating_sub(accounted.result_bytes),
+            written_bytes: total.written_bytes.saturating_sub(accounted.written_bytes),
+            ..total.clone()
+        };
+        *accounted = total;
+        usage
+    }
+
+    pub fn add_remote_resource_usage(&self, usage: &ResourceUsage) {
+        self.remote_usage.lock().merge(usage);
+    }
+
+    pub fn get_remote_resource_usage(&self) -> ResourceUsage {
+        self.remote_usage.lock().clone()
+    }
+
     pub fn get_runtime(&self) -> Option<Arc<Runtime>> {
         let query_runtime = self.runtime.read();
         (*query_runtime).clone()
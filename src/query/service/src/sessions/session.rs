@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
@@ -21,16 +22,19 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::OwnershipObject;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserPrivilegeType;
 use common_settings::ChangeValue;
 use common_settings::Settings;
 use common_users::RoleCacheManager;
+use common_users::UserApiProvider;
 use common_users::BUILTIN_ROLE_PUBLIC;
 use log::debug;
 use parking_lot::RwLock;
 
+use crate::catalogs::CatalogManager;
 use crate::clusters::ClusterDiscovery;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::QueryContext;
@@ -199,7 +203,7 @@ impl Session {
 
     // ensure_current_role() is called after authentication and before any privilege checks
     #[async_backtrace::framed]
-    async fn ensure_current_role(self: &Arc<Self>) -> Result<()> {
+    pub async fn ensure_current_role(self: &Arc<Self>) -> Result<()> {
         let tenant = self.get_current_tenant();
         let public_role = RoleCacheManager::instance()
             .find_role(&tenant, BUILTIN_ROLE_PUBLIC)
@@ -328,6 +332,15 @@ impl Session {
             return Ok(());
         }
 
+        // 3. the owner of the object or its database holds all the privileges on it
+        for owned_object in
+            Self::resolve_ownership_objects(&self.get_current_tenant(), object).await
+        {
+            if self.has_ownership(&owned_object).await? {
+                return Ok(());
+            }
+        }
+
         Err(ErrorCode::PermissionDenied(format!(
             "Permission denied, privilege {:?} is required on {} for user {} with role {}",
             privilege.clone(),
@@ -337,6 +350,130 @@ impl Session {
         )))
     }
 
+    /// Returns the columns of the table on which the privilege is granted to the current user
+    /// or the current role by the column-level grants.
+    #[async_backtrace::framed]
+    pub async fn get_granted_columns(
+        self: &Arc<Self>,
+        object: &GrantObject,
+        privilege: UserPrivilegeType,
+    ) -> Result<BTreeSet<String>> {
+        let mut columns = self
+            .get_current_user()?
+            .grants
+            .granted_columns(object, privilege);
+        self.ensure_current_role().await?;
+        if let Some(role) = self.get_current_role() {
+            columns.extend(role.grants.granted_columns(object, privilege));
+        }
+        Ok(columns)
+    }
+
+    /// Validate the privilege on the columns of a table, which is satisfied either by the
+    /// privilege on the whole table, or by the column-level grants covering all the columns.
+    #[async_backtrace::framed]
+    pub async fn validate_column_privilege(
+        self: &Arc<Self>,
+        object: &GrantObject,
+        columns: &BTreeSet<String>,
+        privilege: UserPrivilegeType,
+    ) -> Result<()> {
+        let err = match self.validate_privilege(object, vec![privilege]).await {
+            Ok(_) => return Ok(()),
+            Err(err) => err,
+        };
+
+        let granted_columns = self.get_granted_columns(object, privilege).await?;
+        if granted_columns.is_empty() {
+            return Err(err);
+        }
+        let denied_columns = columns
+            .difference(&granted_columns)
+            .cloned()
+            .collect::<Vec<_>>();
+        if denied_columns.is_empty() {
+            return Ok(());
+        }
+        Err(ErrorCode::PermissionDenied(format!(
+            "Permission denied, privilege {:?} is required on columns ({}) of {} for user {}",
+            privilege,
+            denied_columns.join(", "),
+            object,
+            &self.get_current_user()?.identity(),
+        )))
+    }
+
+    // Returns true if the owner role of the object is the current role or one of the roles
+    // granted to it.
+    #[async_backtrace::framed]
+    pub async fn has_ownership(self: &Arc<Self>, object: &OwnershipObject) -> Result<bool> {
+        if matches!(self.get_type(), SessionType::Local) {
+            return Ok(true);
+        }
+
+        let tenant = self.get_current_tenant();
+        let owner = match UserApiProvider::instance()
+            .get_ownership(&tenant, object)
+            .await?
+        {
+            Some(ownership) => ownership.role,
+            None => return Ok(false),
+        };
+
+        self.ensure_current_role().await?;
+        let current_role = match self.get_current_role() {
+            Some(role) => role.name,
+            None => return Ok(false),
+        };
+        let related_roles = RoleCacheManager::instance()
+            .find_related_roles(&tenant, &[current_role])
+            .await?;
+        Ok(related_roles.iter().any(|r| r.name == owner))
+    }
+
+    // The objects whose ownership implies the privileges on the grant object, the table itself
+    // and the database it belongs to. Objects which can not be resolved(e.g. not exists yet) are
+    // skipped, the caller will report the missing privilege instead.
+    #[async_backtrace::framed]
+    pub async fn resolve_ownership_objects(
+        tenant: &str,
+        object: &GrantObject,
+    ) -> Vec<OwnershipObject> {
+        let (catalog_name, db_name, table_name) = match object {
+            GrantObject::Global => return vec![],
+            GrantObject::Database(catalog_name, db_name) => (catalog_name, db_name, None),
+            GrantObject::Table(catalog_name, db_name, table_name) => {
+                (catalog_name, db_name, Some(table_name))
+            }
+        };
+
+        let mut objects = vec![];
+        let catalog = match CatalogManager::instance().get_catalog(catalog_name) {
+            Ok(catalog) => catalog,
+            Err(_) => return objects,
+        };
+        let database = match catalog.get_database(tenant, db_name).await {
+            Ok(database) => database,
+            Err(_) => return objects,
+        };
+        let db_id = database.get_db_info().ident.db_id;
+        objects.push(OwnershipObject::Database {
+            catalog_name: catalog_name.clone(),
+            db_id,
+        });
+
+        if let Some(table_name) = table_name {
+            if let Ok(table) = catalog.get_table(tenant, db_name, table_name).await {
+                objects.push(OwnershipObject::Table {
+                    catalog_name: catalog_name.clone(),
+                    db_id,
+                    table_id: table.get_id(),
+                });
+            }
+        }
+        objects
+    }
+
     pub fn get_settings(self: &Arc<Self>) -> Arc<Settings> {
         self.session_ctx.get_settings()
     }
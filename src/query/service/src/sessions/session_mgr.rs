@@ -28,9 +28,12 @@ use common_config::GlobalConfig;
 use common_config::InnerConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserQuota;
 use common_metrics::label_counter;
 use common_metrics::label_gauge;
 use common_settings::Settings;
+use common_users::WorkloadGroupResources;
 use futures::future::Either;
 use futures::StreamExt;
 use log::info;
@@ -41,6 +44,7 @@ use crate::sessions::ProcessInfo;
 use crate::sessions::SessionContext;
 use crate::sessions::SessionManagerStatus;
 use crate::sessions::SessionType;
+use crate::sessions::UserResources;
 
 static METRIC_SESSION_CONNECT_NUMBERS: &str = "session_connect_numbers";
 static METRIC_SESSION_CLOSE_NUMBERS: &str = "session_close_numbers";
@@ -54,6 +58,11 @@ pub struct SessionManager {
     // When typ is MySQL, insert into this map, key is id, val is MySQL connection id.
     pub(crate) mysql_conn_map: Arc<RwLock<HashMap<Option<u32>, String>>>,
     pub(in crate::sessions) mysql_basic_conn_id: AtomicU32,
+
+    // Resources used by the running queries of the users which have a quota,
+    // key is (tenant, user identity, workload group).
+    pub(in crate::sessions) user_resources:
+        Arc<RwLock<HashMap<(String, String, Option<String>), Arc<UserResources>>>>,
 }
 
 impl SessionManager {
@@ -71,6 +80,7 @@ impl SessionManager {
             status: Arc::new(RwLock::new(SessionManagerStatus::default())),
             mysql_conn_map: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
+            user_resources: Arc::new(RwLock::new(HashMap::new())),
         })
     }
 
@@ -285,6 +295,41 @@ impl SessionManager {
         }
     }
 
+    /// Get the resources of the user if it has a quota, with the latest quota of the user applied.
+    ///
+    /// The memory of the user is accounted to the workload group the queries run in, so the
+    /// user has separate resources in each group. A user only runs in several groups at the
+    /// same time if the roles of its sessions are assigned to different groups.
+    pub fn get_user_resources(
+        &self,
+        tenant: &str,
+        user: &UserInfo,
+        group: Option<&Arc<WorkloadGroupResources>>,
+    ) -> Option<Arc<UserResources>> {
+        let identity = user.identity().to_string();
+        if user.quota == UserQuota::no_limit() {
+            // The quota may be removed while queries of the user are still running.
+            for ((key_tenant, key_identity, _), resources) in self.user_resources.read().iter() {
+                if key_tenant == tenant && key_identity == &identity {
+                    resources.apply_quota(&user.quota);
+                }
+            }
+            return None;
+        }
+
+        let key = (
+            tenant.to_string(),
+            identity,
+            group.map(|group| group.name()),
+        );
+        let mut user_resources = self.user_resources.write();
+        let resources = user_resources.entry(key).or_insert_with(|| {
+            UserResources::create(user.identity(), group.map(|group| group.get_mem_stat()))
+        });
+        resources.apply_quota(&user.quota);
+        Some(resources.clone())
+    }
+
     fn validate_max_active_sessions(&self, count: usize, reason: &str) -> Result<()> {
         if count >= self.max_sessions {
             return Err(ErrorCode::TooManyUserConnections(format!(
@@ -0,0 +1,130 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_base::runtime::MemStat;
+use common_exception::ErrorCode;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::principal::UserQuota;
+
+/// The resources used by all running queries of a user, limited by the `UserQuota` of the user.
+///
+/// - Memory: the runtime of each query tracks its memory as a child of `mem_stat`, so
+///   the limit applies to the sum of all queries of the user. `mem_stat` is a child of the
+///   workload group the queries run in, if any.
+/// - CPU: pipeline executors acquire their threads from `max_threads`.
+pub struct UserResources {
+    identity: UserIdentity,
+    mem_stat: Arc<MemStat>,
+    max_threads: AtomicUsize,
+    running_threads: Arc<AtomicUsize>,
+}
+
+impl UserResources {
+    pub fn create(
+        identity: UserIdentity,
+        group_mem_stat: Option<Arc<MemStat>>,
+    ) -> Arc<UserResources> {
+        // Report to the workload group or the global tracker rather than to whichever tracker is current.
+        let mem_stat = MemStat::create_child(format!("User{}", identity), group_mem_stat);
+        Arc::new(UserResources {
+            identity,
+            mem_stat,
+            max_threads: AtomicUsize::new(0),
+            running_threads: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    /// Apply the latest quota of the user, which takes effect for the running queries too.
+    ///
+    /// Note that the memory limit is raised to 256MB at least, see `MemStat::set_limit`.
+    pub fn apply_quota(&self, quota: &UserQuota) {
+        self.mem_stat.set_limit(quota.max_memory_in_bytes as i64);
+        self.max_threads
+            .store(quota.max_cpu as usize, Ordering::Relaxed);
+    }
+
+    pub fn get_mem_stat(&self) -> Arc<MemStat> {
+        self.mem_stat.clone()
+    }
+
+    pub fn get_running_threads(&self) -> usize {
+        self.running_threads.load(Ordering::Relaxed)
+    }
+
+    /// Acquire threads for a pipeline executor which wants `threads`.
+    ///
+    /// At least one thread is granted even if the user has used up the quota, so that every
+    /// query can make progress, but it can't run in parallel until other queries finish.
+    pub fn acquire_threads(&self, threads: usize) -> ThreadsPermit {
+        let max_threads = self.max_threads.load(Ordering::Relaxed);
+        let mut granted = threads;
+        let _ = self
+            .running_threads
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |running| {
+                if max_threads != 0 {
+                    granted = max_threads.saturating_sub(running).clamp(1, threads.max(1));
+                }
+                Some(running + granted)
+            });
+
+        ThreadsPermit {
+            threads: granted,
+            running_threads: self.running_threads.clone(),
+            mem_exceeded_count: self.mem_stat.get_exceeded_count(),
+        }
+    }
+
+    /// Turn the error of exceeding the memory limit of the user into `UserMemoryQuotaExceeded`.
+    ///
+    /// Allocations beyond the limit panic in the allocator, which can only be seen as a `PanicError`.
+    /// It's caused by the quota if the user tracker has rejected allocations since `permit` was acquired.
+    pub fn map_error(&self, permit: &ThreadsPermit, cause: ErrorCode) -> ErrorCode {
+        if cause.code() != ErrorCode::PANIC_ERROR
+            || self.mem_stat.get_exceeded_count() == permit.mem_exceeded_count
+        {
+            return cause;
+        }
+
+        ErrorCode::UserMemoryQuotaExceeded(format!(
+            "Memory quota of user {} exceeded: {}",
+            self.identity,
+            cause.message()
+        ))
+    }
+}
+
+/// Threads acquired by a pipeline executor, which are released when it's dropped.
+pub struct ThreadsPermit {
+    threads: usize,
+    running_threads: Arc<AtomicUsize>,
+    // Allocations rejected by the memory quota of the user before the permit was acquired.
+    mem_exceeded_count: u64,
+}
+
+impl ThreadsPermit {
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+}
+
+impl Drop for ThreadsPermit {
+    fn drop(&mut self) {
+        self.running_threads
+            .fetch_sub(self.threads, Ordering::SeqCst);
+    }
+}
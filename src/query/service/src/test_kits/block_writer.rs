@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use chrono::Utc;
 use common_arrow::parquet::metadata::ThriftFileMetaData;
 use common_exception::Result;
@@ -91,6 +93,10 @@ impl<'a> BlockWriter<'a> {
             bloom_filter_index_size,
             Compression::Lz4Raw,
             Some(Utc::now()),
+            None,
+            0,
+            None,
+            0,
         );
         Ok((block_meta, meta))
     }
@@ -114,6 +120,7 @@ impl<'a> BlockWriter<'a> {
             location.1,
             &[block],
             bloom_columns_map,
+            BTreeMap::new(),
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
@@ -20,6 +20,7 @@ use common_ast::ast::Engine;
 use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::table::AppendMode;
 use common_config::GlobalConfig;
+use common_config::InnerConfig;
 use common_exception::Result;
 use common_expression::block_debug::assert_blocks_sorted_eq_with_name;
 use common_expression::infer_table_schema;
@@ -92,8 +93,11 @@ pub struct TestFixture {
 
 impl TestFixture {
     pub async fn new() -> TestFixture {
+        Self::new_with_config(ConfigBuilder::create().config()).await
+    }
+
+    pub async fn new_with_config(mut conf: InnerConfig) -> TestFixture {
         let tmp_dir = TempDir::new().unwrap();
-        let mut conf = ConfigBuilder::create().config();
 
         // make sure we are using `fs` storage
         conf.storage.params = StorageParams::Fs(StorageFsConfig {
@@ -188,6 +192,9 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: Some("(id)".to_string()),
+            partition_by: None,
+            ttl: None,
+            constraints: Default::default(),
         }
     }
 
@@ -211,6 +218,9 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            partition_by: None,
+            ttl: None,
+            constraints: Default::default(),
         }
     }
 
@@ -245,6 +255,9 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            partition_by: None,
+            ttl: None,
+            constraints: Default::default(),
         }
     }
 
@@ -288,6 +301,9 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            partition_by: None,
+            ttl: None,
+            constraints: Default::default(),
         }
     }
 
@@ -612,8 +628,7 @@ pub async fn execute_query(ctx: Arc<QueryContext>, query: &str) -> Result<Sendab
 }
 
 pub fn execute_pipeline(ctx: Arc<QueryContext>, mut res: PipelineBuildResult) -> Result<()> {
-    let query_id = ctx.get_id();
-    let executor_settings = ExecutorSettings::try_create(&ctx.get_settings(), query_id)?;
+    let executor_settings = ExecutorSettings::try_create(&ctx)?;
     res.set_max_threads(ctx.get_settings().get_max_threads()? as usize);
     let mut pipelines = res.sources_pipelines;
     pipelines.push(res.main_pipeline);
@@ -682,6 +682,7 @@ mysql_handler_host = "127.0.0.1"
 mysql_handler_port = 3307
 max_active_sessions = 256
 max_server_memory_usage = 0
+clickhouse_handler_enabled = false
 clickhouse_handler_host = "127.0.0.1"
 clickhouse_handler_port = 9000
 clickhouse_http_handler_host = "127.0.0.1"
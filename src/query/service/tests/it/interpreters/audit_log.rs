@@ -0,0 +1,106 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_tracing::audit_chain_head;
+use common_tracing::verify_audit_chain;
+use common_tracing::write_audit_log;
+use common_tracing::AuditEvent;
+use common_tracing::AuditEventType;
+
+const KEY: &[u8] = b"audit-key";
+
+fn create_chain(n: u64) -> Vec<AuditEvent> {
+    let mut events = vec![];
+    let mut prev_hash = "".to_string();
+    for seq in 1..=n {
+        let mut event = AuditEvent {
+            seq,
+            user: "'u1'@'%'".to_string(),
+            object: format!("ROLE r{}", seq),
+            prev_hash: prev_hash.clone(),
+            ..AuditEvent::new(AuditEventType::CreateRole)
+        };
+        event.hash = event.digest(KEY);
+        prev_hash = event.hash.clone();
+        events.push(event);
+    }
+    events
+}
+
+#[test]
+fn test_audit_chain_verify() {
+    let events = create_chain(3);
+    assert!(verify_audit_chain(&events, KEY, None).is_ok());
+
+    // Signed with another key.
+    assert_eq!(verify_audit_chain(&events, b"other-key", None), Err(1));
+
+    // A modified record.
+    let mut modified = events.clone();
+    modified[1].object = "ROLE admin".to_string();
+    assert_eq!(verify_audit_chain(&modified, KEY, None), Err(2));
+
+    // A removed record.
+    let mut removed = events.clone();
+    removed.remove(1);
+    assert_eq!(verify_audit_chain(&removed, KEY, None), Err(3));
+
+    // A record replaced with a rehashed one.
+    let mut replaced = events.clone();
+    replaced[1].object = "ROLE admin".to_string();
+    replaced[1].hash = replaced[1].digest(KEY);
+    assert_eq!(verify_audit_chain(&replaced, KEY, None), Err(3));
+
+    // The whole chain rehashed without the key.
+    let mut rehashed = events;
+    for event in rehashed.iter_mut() {
+        event.hash = event.digest(b"");
+    }
+    assert_eq!(verify_audit_chain(&rehashed, KEY, None), Err(1));
+}
+
+#[test]
+fn test_audit_chain_truncated() {
+    let events = create_chain(3);
+    let head = (3, events[2].hash.as_str());
+    assert!(verify_audit_chain(&events, KEY, Some(head)).is_ok());
+
+    // The last records are removed.
+    assert_eq!(verify_audit_chain(&events[..2], KEY, Some(head)), Err(3));
+    assert_eq!(verify_audit_chain(&[], KEY, Some(head)), Err(1));
+
+    // The head is persisted after the records are written.
+    let behind = (2, events[1].hash.as_str());
+    assert!(verify_audit_chain(&events, KEY, Some(behind)).is_ok());
+
+    // The last record is replaced with a rehashed one.
+    let mut replaced = events[..2].to_vec();
+    replaced[1].object = "ROLE admin".to_string();
+    replaced[1].hash = replaced[1].digest(KEY);
+    assert!(verify_audit_chain(&replaced, KEY, None).is_ok());
+    assert_eq!(verify_audit_chain(&replaced, KEY, Some(behind)), Err(2));
+}
+
+#[test]
+fn test_write_audit_log() {
+    // The key of the chain is empty unless the audit chain manager is initialized.
+    let first = write_audit_log(AuditEvent::new(AuditEventType::Login));
+    assert!(first.seq > 0);
+    assert_eq!(first.hash, first.digest(b""));
+
+    let second = write_audit_log(AuditEvent::new(AuditEventType::Login));
+    assert!(second.seq > first.seq);
+    assert_ne!(second.prev_hash, "");
+    assert!(audit_chain_head().0 >= second.seq);
+}
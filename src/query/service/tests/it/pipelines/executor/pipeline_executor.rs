@@ -41,6 +41,9 @@ async fn test_always_call_on_finished() -> Result<()> {
     let settings = ExecutorSettings {
         query_id: Arc::new("".to_string()),
         max_execute_time_in_seconds: Default::default(),
+        user_resources: None,
+        workload_group: None,
+        cpu_time: None,
     };
 
     {
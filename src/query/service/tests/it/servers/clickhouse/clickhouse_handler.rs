@@ -0,0 +1,343 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+
+use common_base::base::tokio;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::net::TcpStream;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use databend_query::servers::clickhouse::block::read_block;
+use databend_query::servers::clickhouse::block::write_block;
+use databend_query::servers::clickhouse::protocol::client_packet;
+use databend_query::servers::clickhouse::protocol::put_string;
+use databend_query::servers::clickhouse::protocol::put_varuint;
+use databend_query::servers::clickhouse::protocol::server_packet;
+use databend_query::servers::clickhouse::protocol::Cursor;
+use databend_query::servers::clickhouse::protocol::DecodeError;
+use databend_query::servers::clickhouse::protocol::DecodeResult;
+use databend_query::servers::clickhouse::protocol::DBMS_TCP_PROTOCOL_VERSION;
+use databend_query::servers::clickhouse::protocol::QUERY_STAGE_COMPLETE;
+use databend_query::servers::clickhouse::types::NativeType;
+use databend_query::servers::ClickHouseHandler;
+use databend_query::servers::Server;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestGlobalServices;
+
+#[derive(Debug)]
+enum ServerPacket {
+    Hello(String),
+    Data(DataBlock),
+    Exception(i32, String),
+    Progress,
+    EndOfStream,
+}
+
+async fn start_handler() -> Result<(Box<dyn Server>, SocketAddr)> {
+    let mut handler = ClickHouseHandler::create(120)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    Ok((handler, listening))
+}
+
+fn hello(user: &str, password: &str) -> Vec<u8> {
+    let mut out = vec![];
+    put_varuint(&mut out, client_packet::HELLO);
+    put_string(&mut out, "test-client");
+    put_varuint(&mut out, 23);
+    put_varuint(&mut out, 3);
+    put_varuint(&mut out, DBMS_TCP_PROTOCOL_VERSION);
+    put_string(&mut out, "default");
+    put_string(&mut out, user);
+    put_string(&mut out, password);
+    out
+}
+
+fn empty_data() -> Vec<u8> {
+    let mut out = vec![];
+    put_varuint(&mut out, client_packet::DATA);
+    put_string(&mut out, "");
+    write_block(&mut out, &DataSchema::empty(), &DataBlock::empty()).unwrap();
+    out
+}
+
+fn query(sql: &str) -> Vec<u8> {
+    let mut out = vec![];
+    put_varuint(&mut out, client_packet::QUERY);
+    put_string(&mut out, "");
+    // query kind: no query
+    out.push(0);
+    // end of settings
+    put_string(&mut out, "");
+    put_varuint(&mut out, QUERY_STAGE_COMPLETE);
+    // no compression
+    put_varuint(&mut out, 0);
+    put_string(&mut out, sql);
+    out.extend_from_slice(&empty_data());
+    out
+}
+
+fn decode_server_packet(cursor: &mut Cursor) -> DecodeResult<ServerPacket> {
+    Ok(match cursor.varuint()? {
+        server_packet::HELLO => {
+            let name = cursor.string()?;
+            for _ in 0..3 {
+                cursor.varuint()?;
+            }
+            // timezone and display name
+            cursor.string()?;
+            cursor.string()?;
+            cursor.varuint()?;
+            ServerPacket::Hello(name)
+        }
+        server_packet::DATA => {
+            cursor.string()?;
+            ServerPacket::Data(read_block(cursor)?.1)
+        }
+        server_packet::EXCEPTION => {
+            let code = i32::from_le_bytes(cursor.array::<4>()?);
+            cursor.string()?;
+            let message = cursor.string()?;
+            cursor.string()?;
+            cursor.u8()?;
+            ServerPacket::Exception(code, message)
+        }
+        server_packet::PROGRESS => {
+            for _ in 0..5 {
+                cursor.varuint()?;
+            }
+            ServerPacket::Progress
+        }
+        server_packet::END_OF_STREAM => ServerPacket::EndOfStream,
+        packet => panic!("unexpected server packet {}", packet),
+    })
+}
+
+/// Read the packets until one of hello, exception or end of stream.
+async fn read_response(stream: &mut TcpStream) -> Result<Vec<ServerPacket>> {
+    let mut input = vec![];
+    let mut packets = vec![];
+    loop {
+        let mut cursor = Cursor::new(&input);
+        match decode_server_packet(&mut cursor) {
+            Ok(packet) => {
+                let consumed = cursor.position();
+                input.drain(..consumed);
+                let last = matches!(
+                    packet,
+                    ServerPacket::Hello(_)
+                        | ServerPacket::Exception(..)
+                        | ServerPacket::EndOfStream
+                );
+                packets.push(packet);
+                if last {
+                    return Ok(packets);
+                }
+            }
+            Err(DecodeError::Incomplete) => {
+                let mut buf = [0u8; 4096];
+                let n = stream.read(&mut buf).await?;
+                assert!(n > 0, "connection closed before the end of response");
+                input.extend_from_slice(&buf[..n]);
+            }
+            Err(DecodeError::Error(error)) => return Err(error),
+        }
+    }
+}
+
+fn rows_of(packets: &[ServerPacket]) -> Vec<Vec<String>> {
+    let mut rows = vec![];
+    for packet in packets {
+        if let ServerPacket::Data(block) = packet {
+            let block = block.convert_to_full();
+            for row in 0..block.num_rows() {
+                rows.push(
+                    block
+                        .columns()
+                        .iter()
+                        .map(|entry| {
+                            let column = entry.value.as_column().unwrap();
+                            column.index(row).unwrap().to_string()
+                        })
+                        .collect(),
+                );
+            }
+        }
+    }
+    rows
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_clickhouse_native_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+    let (_handler, listening) = start_handler().await?;
+    let mut stream = TcpStream::connect(listening).await?;
+
+    stream.write_all(&hello("root", "")).await?;
+    let packets = read_response(&mut stream).await?;
+    assert!(matches!(&packets[..], [ServerPacket::Hello(name)] if name == "Databend"));
+
+    stream
+        .write_all(&query(
+            "SELECT number, to_string(number) FROM numbers(3) ORDER BY number",
+        ))
+        .await?;
+    let packets = read_response(&mut stream).await?;
+    assert!(matches!(packets.last(), Some(ServerPacket::EndOfStream)));
+    assert_eq!(rows_of(&packets), vec![
+        vec!["0".to_string(), "'0'".to_string()],
+        vec!["1".to_string(), "'1'".to_string()],
+        vec!["2".to_string(), "'2'".to_string()],
+    ]);
+
+    stream.write_all(&query("SELECT * FROM t_unknown")).await?;
+    let packets = read_response(&mut stream).await?;
+    assert!(matches!(
+        packets.last(),
+        Some(ServerPacket::Exception(60, _))
+    ));
+
+    // The connection is still usable after an error.
+    let mut ping = vec![];
+    put_varuint(&mut ping, client_packet::PING);
+    stream.write_all(&ping).await?;
+    assert_eq!(stream.read_u8().await? as u64, server_packet::PONG);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_clickhouse_native_authenticate_failed() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+    let (_handler, listening) = start_handler().await?;
+    let mut stream = TcpStream::connect(listening).await?;
+
+    stream.write_all(&hello("unknown_user", "pwd")).await?;
+    let packets = read_response(&mut stream).await?;
+    assert!(matches!(&packets[..], [ServerPacket::Exception(516, _)]));
+
+    Ok(())
+}
+
+#[test]
+fn test_clickhouse_native_type_names() -> Result<()> {
+    for name in [
+        "UInt8",
+        "Nullable(Int64)",
+        "Decimal(10, 2)",
+        "DateTime64(6)",
+        "Array(Nullable(String))",
+        "Map(String, Array(Float64))",
+        "Tuple(Int32, String)",
+    ] {
+        assert_eq!(NativeType::parse(name)?.to_string(), name);
+    }
+    assert_eq!(
+        NativeType::parse("DateTime64(3, 'Asia/Shanghai')")?,
+        NativeType::DateTime64(3)
+    );
+    assert_eq!(
+        NativeType::parse("Decimal64(4)")?,
+        NativeType::Decimal(18, 4)
+    );
+    assert!(NativeType::parse("LowCardinality(String)").is_err());
+
+    assert_eq!(
+        NativeType::from_data_type(&DataType::Timestamp),
+        NativeType::DateTime64(6)
+    );
+    assert_eq!(
+        NativeType::from_data_type(&DataType::Nullable(Box::new(DataType::Array(Box::new(
+            DataType::String
+        ))))),
+        NativeType::Array(Box::new(NativeType::String))
+    );
+    Ok(())
+}
+
+fn column(data_type: &DataType, values: &[Scalar]) -> Column {
+    let mut builder = ColumnBuilder::with_capacity(data_type, values.len());
+    for value in values {
+        builder.push(value.as_ref());
+    }
+    builder.build()
+}
+
+#[test]
+fn test_clickhouse_native_block_round_trip() -> Result<()> {
+    let int_type = DataType::Nullable(Box::new(DataType::Number(NumberDataType::Int32)));
+    let array_type = DataType::Array(Box::new(DataType::Number(NumberDataType::UInt64)));
+    let schema = DataSchema::new(vec![
+        DataField::new("a", int_type.clone()),
+        DataField::new("b", DataType::String),
+        DataField::new("c", array_type.clone()),
+        DataField::new("d", DataType::Timestamp),
+    ]);
+    let array = |values: &[u64]| {
+        Scalar::Array(column(
+            &DataType::Number(NumberDataType::UInt64),
+            &values
+                .iter()
+                .map(|v| Scalar::Number(NumberScalar::UInt64(*v)))
+                .collect::<Vec<_>>(),
+        ))
+    };
+    let block = DataBlock::new_from_columns(vec![
+        column(&int_type, &[
+            Scalar::Number(NumberScalar::Int32(-1)),
+            Scalar::Null,
+        ]),
+        column(&DataType::String, &[
+            Scalar::String(b"x".to_vec()),
+            Scalar::String(vec![]),
+        ]),
+        column(&array_type, &[array(&[1, 2]), array(&[])]),
+        column(&DataType::Timestamp, &[
+            Scalar::Timestamp(1_000_001),
+            Scalar::Timestamp(0),
+        ]),
+    ]);
+
+    let mut out = vec![];
+    write_block(&mut out, &schema, &block)?;
+    let (read_schema, read) = match read_block(&mut Cursor::new(&out)) {
+        Ok(result) => result,
+        Err(_) => panic!("failed to read the block"),
+    };
+    assert_eq!(read_schema, schema);
+    assert_eq!(read.num_rows(), 2);
+    for (expected, actual) in block.columns().iter().zip(read.columns()) {
+        let expected = expected.value.as_column().unwrap();
+        let actual = actual.value.as_column().unwrap();
+        for row in 0..2 {
+            assert_eq!(expected.index(row), actual.index(row));
+        }
+    }
+    assert!(matches!(
+        read.columns()[0].value.as_column().unwrap().index(1),
+        Some(ScalarRef::Null)
+    ));
+    Ok(())
+}
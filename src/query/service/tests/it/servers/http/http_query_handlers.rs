@@ -14,9 +14,13 @@
 
 use std::collections::BTreeMap;
 use std::fs::File;
+use std::io::Cursor;
 use std::io::Read;
 use std::time::Duration;
 
+use arrow_array::cast::AsArray;
+use arrow_array::types::UInt64Type;
+use arrow_ipc::reader::StreamReader;
 use base64::engine::general_purpose;
 use base64::prelude::*;
 use common_base::base::get_free_tcp_port;
@@ -30,6 +34,7 @@ use common_users::EnsureUser;
 use databend_query::auth::AuthMgr;
 use databend_query::servers::http::middleware::HTTPSessionEndpoint;
 use databend_query::servers::http::middleware::HTTPSessionMiddleware;
+use databend_query::servers::http::v1::arrow_block::ARROW_IPC_CONTENT_TYPE;
 use databend_query::servers::http::v1::make_final_uri;
 use databend_query::servers::http::v1::make_page_uri;
 use databend_query::servers::http::v1::make_state_uri;
@@ -396,6 +401,80 @@ async fn test_pagination() -> Result<()> {
     Ok(())
 }
 
+// Read the response of the Arrow IPC format, returns the response in the metadata and the rows.
+async fn check_arrow_response(response: Response) -> Result<(QueryResponse, Vec<u64>)> {
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.content_type(), Some(ARROW_IPC_CONTENT_TYPE));
+    let body = response.into_body().into_vec().await.unwrap();
+    let reader = StreamReader::try_new(Cursor::new(body), None)
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    let result = serde_json::from_str::<QueryResponse>(&reader.schema().metadata()["response"])?;
+    let mut rows = vec![];
+    for batch in reader {
+        let batch = batch.map_err(|e| ErrorCode::Internal(e.to_string()))?;
+        rows.extend(batch.column(0).as_primitive::<UInt64Type>().values().iter());
+    }
+    Ok((result, rows))
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_arrow_ipc_pagination() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let json = serde_json::json!({"sql": "select * from numbers(5)", "pagination": {"wait_time_secs": 1, "max_rows_per_page": 2}});
+    let basic = headers::Authorization::basic("root", "");
+    let response = ep
+        .call(
+            Request::builder()
+                .uri("/v1/query".parse().unwrap())
+                .method(Method::POST)
+                .header(header::CONTENT_TYPE, "application/json")
+                .header(header::ACCEPT, ARROW_IPC_CONTENT_TYPE)
+                .typed_header(basic.clone())
+                .body(serde_json::to_vec(&json)?),
+        )
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+
+    let (result, mut rows) = check_arrow_response(response).await?;
+    assert!(result.error.is_none(), "{:?}", result);
+    assert!(result.data.is_empty(), "{:?}", result);
+    assert_eq!(result.schema.len(), 1, "{:?}", result);
+    assert_eq!(rows, vec![0, 1]);
+
+    let mut next_uri = result.next_uri;
+    while let Some(uri) = next_uri {
+        let response = ep
+            .call(
+                Request::builder()
+                    .uri(uri.parse().unwrap())
+                    .method(Method::GET)
+                    .header(header::ACCEPT, ARROW_IPC_CONTENT_TYPE)
+                    .typed_header(basic.clone())
+                    .finish(),
+            )
+            .await
+            .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+        if uri.ends_with("/final") {
+            let (result, page) = check_arrow_response(response).await?;
+            assert!(page.is_empty());
+            assert_eq!(result.state, ExecuteStateKind::Succeeded);
+            break;
+        }
+        let (result, page) = check_arrow_response(response).await?;
+        rows.extend(page);
+        next_uri = result.next_uri;
+    }
+    assert_eq!(rows, vec![0, 1, 2, 3, 4]);
+
+    // The pages are still JSON without the Accept header.
+    let (status, result) = post_sql_to_endpoint(&ep, "select * from numbers(3)", 1).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.data.len(), 3, "{:?}", result);
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_http_session() -> Result<()> {
     let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod clickhouse;
 mod flight_sql;
 mod http;
 mod mysql;
+mod postgres;
@@ -0,0 +1,211 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::net::TcpStream;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::servers::PostgresHandler;
+use databend_query::servers::Server;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestGlobalServices;
+use tokio_postgres::types::Type;
+use tokio_postgres::Client;
+use tokio_postgres::NoTls;
+use tokio_postgres::SimpleQueryMessage;
+
+async fn start_handler() -> Result<(Box<dyn Server>, SocketAddr)> {
+    let mut handler = PostgresHandler::create(120)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    Ok((handler, listening))
+}
+
+async fn create_connection(port: u16, user: &str) -> Result<Client> {
+    let dsn = format!("host=127.0.0.1 port={} user={} dbname=default", port, user);
+    let (client, connection) = tokio_postgres::connect(&dsn, NoTls)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    tokio::spawn(connection);
+    Ok(client)
+}
+
+fn simple_rows(messages: Vec<SimpleQueryMessage>) -> Vec<Vec<Option<String>>> {
+    messages
+        .into_iter()
+        .filter_map(|message| match message {
+            SimpleQueryMessage::Row(row) => Some(
+                (0..row.len())
+                    .map(|i| row.get(i).map(|v| v.to_string()))
+                    .collect(),
+            ),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_postgres_simple_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+    let (_handler, listening) = start_handler().await?;
+    let client = create_connection(listening.port(), "root").await?;
+
+    let messages = client
+        .simple_query("SELECT 1, 'a', NULL, true; SELECT number FROM numbers(3) ORDER BY number")
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(simple_rows(messages), vec![
+        vec![
+            Some("1".to_string()),
+            Some("a".to_string()),
+            None,
+            Some("t".to_string())
+        ],
+        vec![Some("0".to_string())],
+        vec![Some("1".to_string())],
+        vec![Some("2".to_string())],
+    ]);
+
+    // Statements sent by drivers are accepted.
+    let messages = client
+        .simple_query("SET extra_float_digits = 3; BEGIN; COMMIT")
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(messages.len(), 3);
+
+    let error = client.simple_query("SELECT * FROM not_exists").await;
+    let error = error.err().unwrap();
+    assert_eq!(error.code().unwrap().code(), "42P01");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_postgres_extended_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+    let (_handler, listening) = start_handler().await?;
+    let client = create_connection(listening.port(), "root").await?;
+
+    let statement = client
+        .prepare("SELECT concat($1, '-', 'it''s $2') AS s")
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(statement.params().len(), 1);
+    assert_eq!(statement.columns()[0].name(), "s");
+
+    for value in ["a", "it's"] {
+        let rows = client
+            .query(&statement, &[&value])
+            .await
+            .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+        assert_eq!(rows.len(), 1);
+        let s: String = rows[0].get(0);
+        assert_eq!(s, format!("{}-it's $2", value));
+    }
+
+    // Parameters of the declared types are sent in binary format.
+    let statement = client
+        .prepare_typed("SELECT $1::INT AS n", &[Type::INT4])
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    let rows = client
+        .query(&statement, &[&42i32])
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    let n: i32 = rows[0].get(0);
+    assert_eq!(n, 42);
+
+    // The client asks for the results in binary format.
+    let rows = client
+        .query(
+            "SELECT 1::SMALLINT, number::BIGINT, 1.5::DOUBLE, number = 1, NULL::INT, 'x' \
+             FROM numbers(2) ORDER BY number",
+            &[],
+        )
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(rows.len(), 2);
+    for (i, row) in rows.iter().enumerate() {
+        assert_eq!(row.get::<_, i16>(0), 1);
+        assert_eq!(row.get::<_, i64>(1), i as i64);
+        assert_eq!(row.get::<_, f64>(2), 1.5);
+        assert_eq!(row.get::<_, bool>(3), i == 1);
+        assert_eq!(row.get::<_, Option<i32>>(4), None);
+        assert_eq!(row.get::<_, String>(5), "x");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_postgres_authenticate_failed() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+    let (_handler, listening) = start_handler().await?;
+
+    let result = create_connection(listening.port(), "unknown_user").await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_postgres_ssl_request_refused() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+    let (_handler, listening) = start_handler().await?;
+
+    let mut stream = TcpStream::connect(listening).await?;
+    let mut ssl_request = 8i32.to_be_bytes().to_vec();
+    ssl_request.extend_from_slice(&80877103i32.to_be_bytes());
+    stream.write_all(&ssl_request).await?;
+    assert_eq!(stream.read_u8().await?, b'N');
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_postgres_cancel_request() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+    let (_handler, listening) = start_handler().await?;
+    let client = create_connection(listening.port(), "root").await?;
+    let cancel_token = client.cancel_token();
+
+    let query = tokio::spawn(async move {
+        client
+            .simple_query("SELECT max(number % 7) FROM numbers(1000000000000)")
+            .await
+    });
+
+    // The CancelRequest is sent on a new connection with the key of the BackendKeyData,
+    // repeat it in case the query has not been started.
+    for _ in 0..50 {
+        if query.is_finished() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        cancel_token
+            .cancel_query(NoTls)
+            .await
+            .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    }
+
+    let error = query.await.unwrap().err().unwrap();
+    assert_eq!(error.code().unwrap().code(), "57014");
+
+    Ok(())
+}
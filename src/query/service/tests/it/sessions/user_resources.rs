@@ -0,0 +1,101 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::runtime::MemStat;
+use common_exception::ErrorCode;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::principal::UserQuota;
+use databend_query::sessions::UserResources;
+
+#[test]
+fn test_user_resources_acquire_threads() {
+    let resources = UserResources::create(UserIdentity::new("u1", "%"), None);
+    resources.apply_quota(&UserQuota {
+        max_cpu: 4,
+        max_memory_in_bytes: 0,
+        max_storage_in_bytes: 0,
+    });
+
+    let first = resources.acquire_threads(3);
+    assert_eq!(first.threads(), 3);
+
+    // Only one thread left in the quota.
+    let second = resources.acquire_threads(3);
+    assert_eq!(second.threads(), 1);
+
+    // The quota is used up, but the query can still run with one thread.
+    let third = resources.acquire_threads(3);
+    assert_eq!(third.threads(), 1);
+    assert_eq!(resources.get_running_threads(), 5);
+
+    drop(first);
+    drop(third);
+    assert_eq!(resources.get_running_threads(), 1);
+
+    let fourth = resources.acquire_threads(8);
+    assert_eq!(fourth.threads(), 3);
+}
+
+#[test]
+fn test_user_resources_no_cpu_limit() {
+    let resources = UserResources::create(UserIdentity::new("u2", "%"), None);
+    resources.apply_quota(&UserQuota::no_limit());
+
+    let permit = resources.acquire_threads(16);
+    assert_eq!(permit.threads(), 16);
+    drop(permit);
+    assert_eq!(resources.get_running_threads(), 0);
+}
+
+#[test]
+fn test_user_resources_map_error() {
+    let resources = UserResources::create(UserIdentity::new("u3", "%"), None);
+    resources.apply_quota(&UserQuota {
+        max_cpu: 0,
+        max_memory_in_bytes: 256 * 1024 * 1024,
+        max_storage_in_bytes: 0,
+    });
+
+    let permit = resources.acquire_threads(1);
+    let cause = resources.map_error(&permit, ErrorCode::PanicError("panicked"));
+    assert_eq!(cause.code(), ErrorCode::PANIC_ERROR);
+
+    // Reject an allocation by the memory quota of the user.
+    let mem_stat = Some(resources.get_mem_stat());
+    assert!(MemStat::record_memory::<true>(&mem_stat, 512 * 1024 * 1024).is_err());
+
+    let cause = resources.map_error(&permit, ErrorCode::Internal("other error"));
+    assert_eq!(cause.code(), ErrorCode::INTERNAL);
+    let cause = resources.map_error(&permit, ErrorCode::PanicError("panicked"));
+    assert_eq!(cause.code(), ErrorCode::USER_MEMORY_QUOTA_EXCEEDED);
+
+    // Errors of the later queries are not affected.
+    let permit = resources.acquire_threads(1);
+    let cause = resources.map_error(&permit, ErrorCode::PanicError("panicked"));
+    assert_eq!(cause.code(), ErrorCode::PANIC_ERROR);
+}
+
+#[test]
+fn test_user_resources_in_workload_group() {
+    let group_mem_stat = MemStat::create_child("WorkloadGroupg1".to_string(), None);
+    let resources =
+        UserResources::create(UserIdentity::new("u4", "%"), Some(group_mem_stat.clone()));
+
+    // The memory of the user is accounted to the workload group too.
+    let mem_stat = Some(resources.get_mem_stat());
+    assert!(MemStat::record_memory::<true>(&mem_stat, 1024).is_ok());
+    assert_eq!(resources.get_mem_stat().get_memory_usage(), 1024);
+    assert_eq!(group_mem_stat.get_memory_usage(), 1024);
+    assert!(MemStat::record_memory::<false>(&mem_stat, -1024).is_ok());
+}
@@ -81,6 +81,9 @@ fn create_table_plan(fixture: &TestFixture, format: &str) -> CreateTablePlan {
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        partition_by: None,
+        ttl: None,
+        constraints: Default::default(),
     }
 }
 
@@ -341,6 +341,13 @@ fn build_test_segment_info(num_blocks_per_seg: usize) -> common_exception::Resul
         bloom_filter_index_size: 0,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
+        inverted_index_location: None,
+        inverted_index_size: 0,
+        vector_index_location: None,
+        vector_index_size: 0,
+        deletion_vector_location: None,
+        deletion_vector_size: 0,
+        deleted_row_count: 0,
     };
 
     let block_metas = (0..num_blocks_per_seg)
@@ -356,6 +363,7 @@ fn build_test_segment_info(num_blocks_per_seg: usize) -> common_exception::Resul
         index_size: 0,
         col_stats: col_stats.clone(),
         cluster_stats: None,
+        deleted_row_count: 0,
     };
 
     Ok(SegmentInfo::new(block_metas, statistics))
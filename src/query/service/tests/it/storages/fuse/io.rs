@@ -12,8 +12,23 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use common_arrow::arrow::bitmap::Bitmap;
+use common_base::base::tokio;
 use common_exception::Result;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::DecimalSize;
+use common_expression::types::NumberScalar;
+use common_expression::Scalar;
+use databend_query::storages::fuse::io::decode_deletion_vector;
+use databend_query::storages::fuse::io::encode_deletion_vector;
+use databend_query::storages::fuse::io::live_row_offsets;
+use databend_query::storages::fuse::io::read_segment_key_index_meta;
+use databend_query::storages::fuse::io::write_segment_key_index;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
+use databend_query::storages::fuse::operations::decode_partition_value;
+use databend_query::storages::fuse::operations::encode_partition_value;
+use ethnum::i256;
+use opendal::Operator;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
 use uuid::Uuid;
@@ -31,3 +46,101 @@ fn test_meta_locations() -> Result<()> {
     assert!(snapshot_loc.starts_with(test_prefix));
     Ok(())
 }
+
+#[test]
+fn test_segment_partition_locations() -> Result<()> {
+    let locs = TableMetaLocationGenerator::with_prefix("test_pref".to_owned());
+    let seg_loc = locs.gen_segment_info_location();
+    assert_eq!(
+        TableMetaLocationGenerator::segment_partition(&seg_loc),
+        None
+    );
+
+    for value in [
+        Scalar::Number(NumberScalar::Int32(-3)),
+        Scalar::Number(NumberScalar::UInt64(u64::MAX)),
+        Scalar::Number(NumberScalar::Float64(1.5.into())),
+        Scalar::Decimal(DecimalScalar::Decimal256(i256::MAX, DecimalSize {
+            precision: 76,
+            scale: 2,
+        })),
+        Scalar::Timestamp(1690000000000000),
+        Scalar::Date(19500),
+        Scalar::Boolean(true),
+        Scalar::Null,
+    ] {
+        let encoded = encode_partition_value(&value)?;
+        let seg_loc = locs.gen_segment_info_location_of_partition(Some(&encoded));
+        assert!(seg_loc.starts_with("test_pref"));
+        let partition = TableMetaLocationGenerator::segment_partition(&seg_loc);
+        assert_eq!(partition, Some(encoded.as_str()));
+        assert_eq!(decode_partition_value(partition.unwrap())?, value);
+    }
+
+    // the partition values are encoded into file names, only fixed width values.
+    assert!(encode_partition_value(&Scalar::String(vec![b'a'; 64])).is_err());
+    assert!(decode_partition_value("0801").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_deletion_vector_codec() -> Result<()> {
+    let locs = TableMetaLocationGenerator::with_prefix("test_pref".to_owned());
+    let (path, _ver) = locs.gen_deletion_vector_location();
+    assert!(path.starts_with("test_pref/_dv/"));
+
+    // sparse deletion is encoded as positions, dense deletion as bitmap.
+    let sparse = Bitmap::from_iter((0..10000).map(|i| i % 1000 == 7));
+    let dense = Bitmap::from_iter((0..10000).map(|i| i % 3 != 0));
+    for deleted in [sparse, dense, Bitmap::from_iter([false; 9])] {
+        let encoded = encode_deletion_vector(&deleted);
+        assert!(encoded.len() <= 9 + (deleted.len() + 7) / 8);
+        let decoded = decode_deletion_vector(&encoded)?;
+        assert!(decoded.iter().eq(deleted.iter()));
+    }
+
+    let deleted = Bitmap::from_iter([true, false, false, true, false]);
+    assert_eq!(live_row_offsets(&deleted), vec![1, 2, 4]);
+
+    assert!(decode_deletion_vector(&[0, 1]).is_err());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_segment_key_index() -> Result<()> {
+    let locs = TableMetaLocationGenerator::with_prefix("test_pref".to_owned());
+    let seg_loc = locs.gen_segment_info_location_of_partition(Some("0a"));
+    let location = TableMetaLocationGenerator::segment_key_index_location(&seg_loc);
+    let segment_id = seg_loc
+        .rsplit('/')
+        .next()
+        .unwrap()
+        .split('_')
+        .next()
+        .unwrap();
+    assert_eq!(location.0, format!("test_pref/_i_k/{segment_id}_v0.bin"));
+
+    let op = Operator::new(opendal::services::Memory::default())?.finish();
+    assert!(read_segment_key_index_meta(&op, &location).await?.is_none());
+
+    let digests = (0..1000u128)
+        .map(|i| i * 7919 + (i << 64))
+        .collect::<Vec<_>>();
+    write_segment_key_index(&op, &location, vec![
+        (vec![0], digests.iter().rev().cloned().collect()),
+        (vec![1, 2], vec![]),
+    ])
+    .await?;
+
+    let index = read_segment_key_index_meta(&op, &location).await?.unwrap();
+    assert!(index.key(&[1]).is_none());
+    let key = index.key(&[0]).unwrap();
+    assert!(key.read_filter(&op, &location).await?.is_some());
+    assert_eq!(key.read_digests(&op, &location).await?, digests);
+
+    // none of the rows has a non-NULL key.
+    let key = index.key(&[1, 2]).unwrap();
+    assert!(key.read_filter(&op, &location).await?.is_none());
+    assert!(key.read_digests(&op, &location).await?.is_empty());
+    Ok(())
+}
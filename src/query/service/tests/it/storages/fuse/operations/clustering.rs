@@ -53,6 +53,9 @@ async fn test_fuse_alter_table_cluster_key() -> common_exception::Result<()> {
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        partition_by: None,
+        ttl: None,
+        constraints: Default::default(),
     };
 
     // create test table
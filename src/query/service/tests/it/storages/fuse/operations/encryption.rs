@@ -0,0 +1,138 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_storage::encrypted_len;
+use common_storage::DataOperator;
+use common_storage::MasterKeys;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::TableContext;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::execute_query;
+use databend_query::test_kits::table_test_fixture::expects_ok;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+use databend_query::test_kits::ConfigBuilder;
+
+fn master_key(seed: u8) -> String {
+    general_purpose::STANDARD.encode([seed; 32])
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_encrypted_table() -> Result<()> {
+    let keys = format!("k1:{},k2:{}", master_key(1), master_key(2));
+    let mut conf = ConfigBuilder::create().config();
+    conf.query.fuse_encryption_master_key_id = "k1".to_string();
+    conf.query.fuse_encryption_master_keys = keys.clone();
+
+    let fixture = TestFixture::new_with_config(conf).await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+
+    execute_command(
+        ctx.clone(),
+        &format!("create table {db}.t(id int, s string) encryption = 'aes_256_gcm'"),
+    )
+    .await?;
+
+    // The blocks are larger than a chunk of the encryption.
+    for _ in 0..3 {
+        execute_command(
+            ctx.clone(),
+            &format!(
+                "insert into {db}.t select number, md5(to_string(number)) from numbers(10000)"
+            ),
+        )
+        .await?;
+    }
+
+    let expected = vec![
+        "+----------+-----------+----------+",
+        "| Column 0 | Column 1  | Column 2 |",
+        "+----------+-----------+----------+",
+        "| 30000    | 149985000 | 10000    |",
+        "+----------+-----------+----------+",
+    ];
+    let query = format!("select count(*), sum(id), count(distinct s) from {db}.t");
+    let point_query = format!("select count(*) from {db}.t where s = md5(to_string(4242))");
+    let point_expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 3        |",
+        "+----------+",
+    ];
+    expects_ok(
+        "select after insert",
+        execute_query(ctx.clone(), &query).await,
+        expected.clone(),
+    )
+    .await?;
+    expects_ok(
+        "point query after insert",
+        execute_query(ctx.clone(), &point_query).await,
+        point_expected.clone(),
+    )
+    .await?;
+
+    // Compaction reads and writes the blocks through the encryption layer.
+    ctx.get_settings().set_max_threads(1)?;
+    execute_command(ctx.clone(), &format!("optimize table {db}.t compact")).await?;
+    expects_ok(
+        "select after compact",
+        execute_query(ctx.clone(), &query).await,
+        expected.clone(),
+    )
+    .await?;
+
+    // The objects are stored encrypted.
+    let ctx = ctx.get_current_session().create_query_context().await?;
+    let table = ctx.get_table("default", &db, "t").await?;
+    let table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot_loc = table.snapshot_loc().await?.unwrap();
+    let plaintext = table.get_operator().read(&snapshot_loc).await?;
+    let stored = DataOperator::instance()
+        .operator()
+        .read(&snapshot_loc)
+        .await?;
+    assert_eq!(stored.len() as u64, encrypted_len(plaintext.len() as u64));
+    assert_ne!(stored, plaintext);
+
+    // Rotate to k2 and retire k1, the table is still readable.
+    MasterKeys::init("k2", &keys, "")?;
+    execute_command(
+        ctx.clone(),
+        &format!("call system$rotate_encryption_key('{db}', 't')"),
+    )
+    .await?;
+    MasterKeys::init("k2", &format!("k2:{}", master_key(2)), "")?;
+    // A new query loads the table with the data key wrapped by k2.
+    let ctx = ctx.get_current_session().create_query_context().await?;
+    expects_ok(
+        "select after rotate",
+        execute_query(ctx.clone(), &query).await,
+        expected,
+    )
+    .await?;
+    expects_ok(
+        "point query after rotate",
+        execute_query(ctx.clone(), &point_query).await,
+        point_expected,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -17,6 +17,7 @@ mod alter_table;
 mod analyze;
 mod clustering;
 mod commit;
+mod encryption;
 mod gc;
 mod internal_column;
 mod mutation;
@@ -69,6 +69,10 @@ async fn test_recluster_mutator_block_select() -> Result<()> {
             0,
             meta::Compression::Lz4Raw,
             Some(Utc::now()),
+            None,
+            0,
+            None,
+            0,
         ));
 
         let statistics = reduce_block_metas(
@@ -138,6 +142,7 @@ async fn test_recluster_mutator_block_select() -> Result<()> {
         schema,
         &None,
         BloomIndexColumns::All,
+        BloomIndexColumns::None,
     )?
     .read_pruning(segment_locations)
     .await?;
@@ -98,6 +98,10 @@ fn test_to_partitions() -> Result<()> {
         bloom_filter_size,
         meta::Compression::Lz4Raw,
         Some(Utc::now()),
+        None,
+        0,
+        None,
+        0,
     ));
 
     let blocks_metas = (0..num_of_block)
@@ -610,6 +610,10 @@ fn test_reduce_block_meta() -> common_exception::Result<()> {
             bloom_filter_index_size,
             Compression::Lz4Raw,
             Some(Utc::now()),
+            None,
+            0,
+            None,
+            0,
         );
         blocks.push(block_meta);
     }
@@ -295,6 +295,18 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("unique_key_check_batch_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000000),
+                    desc: "Sets the maximum number of appended rows whose keys are kept in memory when checking the primary key and unique constraints.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("select_star_exclude_denied_columns", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Excludes the columns not covered by column-level SELECT privileges from the expansion of `SELECT *`, instead of rejecting the query.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
 
                 // enterprise license related settings
                 ("enterprise_license", DefaultSettingValue {
@@ -302,6 +302,14 @@ impl Settings {
         Ok(self.try_get_u64("query_result_cache_allow_inconsistent")? != 0)
     }
 
+    pub fn get_unique_key_check_batch_rows(&self) -> Result<u64> {
+        self.try_get_u64("unique_key_check_batch_rows")
+    }
+
+    pub fn get_select_star_exclude_denied_columns(&self) -> Result<bool> {
+        Ok(self.try_get_u64("select_star_exclude_denied_columns")? != 0)
+    }
+
     pub fn get_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
         Ok(self.try_get_u64("spilling_bytes_threshold_per_proc")? as usize)
     }
@@ -23,6 +23,7 @@ use common_catalog::plan::AggIndexInfo;
 use common_catalog::plan::PrewhereInfo;
 use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::VectorTopK;
 use common_catalog::plan::VirtualColumnInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
@@ -1567,6 +1568,31 @@ impl PhysicalPlanBuilder {
             })
             .transpose()?;
 
+        let vector_top_k = scan
+            .vector_top_k
+            .as_ref()
+            .map(|top_k| -> Result<VectorTopK> {
+                let metadata = self.metadata.read();
+                let column = match metadata.column(top_k.column) {
+                    ColumnEntry::BaseTableColumn(BaseTableColumn { column_name, .. }) => {
+                        column_name.clone()
+                    }
+                    _ => {
+                        return Err(ErrorCode::Internal(
+                            "vector top-k should be on a base table column",
+                        ));
+                    }
+                };
+                Ok(VectorTopK {
+                    column,
+                    distance: top_k.distance.clone(),
+                    query: top_k.query.clone(),
+                    limit: top_k.limit,
+                    nulls_first: top_k.nulls_first,
+                })
+            })
+            .transpose()?;
+
         let virtual_columns = self.build_virtual_columns(&scan.columns);
 
         Ok(PushDownInfo {
@@ -1580,6 +1606,7 @@ impl PhysicalPlanBuilder {
             virtual_columns,
             lazy_materialization: !metadata.lazy_columns().is_empty(),
             agg_index: None,
+            vector_top_k,
         })
     }
 
@@ -138,6 +138,10 @@ pub struct BindContext {
     /// It's used to avoid infinite loop.
     pub planning_agg_index: bool,
 
+    /// If true, the query is planning for the target table of a mutation,
+    /// the rows expired by the TTL of the table are kept visible.
+    pub planning_mutation_target: bool,
+
     pub window_definitions: DashMap<String, WindowSpec>,
 }
 
@@ -170,6 +174,7 @@ impl BindContext {
             srfs: DashMap::new(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            planning_mutation_target: false,
             window_definitions: DashMap::new(),
         }
     }
@@ -188,6 +193,7 @@ impl BindContext {
             srfs: DashMap::new(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            planning_mutation_target: false,
             window_definitions: DashMap::new(),
         }
     }
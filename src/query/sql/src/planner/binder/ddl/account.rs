@@ -12,21 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
+
 use common_ast::ast::AccountMgrLevel;
 use common_ast::ast::AccountMgrSource;
 use common_ast::ast::AlterUserStmt;
 use common_ast::ast::CreateUserStmt;
+use common_ast::ast::GrantObjectName;
+use common_ast::ast::GrantOwnershipStmt;
 use common_ast::ast::GrantStmt;
+use common_ast::ast::Identifier;
 use common_ast::ast::RevokeStmt;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::OwnershipObject;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_meta_app::principal::UserPrivilegeType;
+use common_users::parse_rsa_public_key;
 use common_users::UserApiProvider;
 
+use crate::normalize_identifier;
 use crate::plans::AlterUserPlan;
 use crate::plans::CreateUserPlan;
+use crate::plans::GrantOwnershipPlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::Plan;
@@ -59,6 +70,7 @@ impl Binder {
                     principal: principal.clone(),
                     on: grant_object,
                     priv_types,
+                    columns: BTreeSet::new(),
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
             }
@@ -72,9 +84,111 @@ impl Binder {
                     principal: principal.clone(),
                     on: grant_object,
                     priv_types,
+                    columns: BTreeSet::new(),
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
             }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                let (grant_object, priv_types, columns) =
+                    self.bind_column_privileges(privileges, columns, level)?;
+                let plan = GrantPrivilegePlan {
+                    principal: principal.clone(),
+                    on: grant_object,
+                    priv_types,
+                    columns,
+                };
+                Ok(Plan::GrantPriv(Box::new(plan)))
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_grant_ownership(
+        &mut self,
+        stmt: &GrantOwnershipStmt,
+    ) -> Result<Plan> {
+        let GrantOwnershipStmt { object, role } = stmt;
+        let (object, object_name, _) = self.resolve_ownership_object(object).await?;
+        let plan = GrantOwnershipPlan {
+            object,
+            object_name,
+            role: role.clone(),
+        };
+        Ok(Plan::GrantOwnership(Box::new(plan)))
+    }
+
+    /// Resolve the object to the id based `OwnershipObject`, returns it with the normalized
+    /// name of the object and the grant object of databases and tables.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn resolve_ownership_object(
+        &self,
+        object: &GrantObjectName,
+    ) -> Result<(OwnershipObject, String, Option<GrantObject>)> {
+        let tenant = self.ctx.get_tenant();
+        let catalog_name = self.ctx.get_current_catalog();
+        match object {
+            GrantObjectName::Database(database) => {
+                let database = self.normalize_object_identifier(database);
+                let catalog = self.ctx.get_catalog(&catalog_name)?;
+                let db_id = catalog
+                    .get_database(&tenant, &database)
+                    .await?
+                    .get_db_info()
+                    .ident
+                    .db_id;
+                Ok((
+                    OwnershipObject::Database {
+                        catalog_name: catalog_name.clone(),
+                        db_id,
+                    },
+                    format!("DATABASE {}", database),
+                    Some(GrantObject::Database(catalog_name, database)),
+                ))
+            }
+            GrantObjectName::Table(database, table) => {
+                let (catalog_name, database, table) =
+                    self.normalize_object_identifier_triple(&None, database, table);
+                let catalog = self.ctx.get_catalog(&catalog_name)?;
+                let db_id = catalog
+                    .get_database(&tenant, &database)
+                    .await?
+                    .get_db_info()
+                    .ident
+                    .db_id;
+                let table_id = catalog
+                    .get_table(&tenant, &database, &table)
+                    .await?
+                    .get_id();
+                Ok((
+                    OwnershipObject::Table {
+                        catalog_name: catalog_name.clone(),
+                        db_id,
+                        table_id,
+                    },
+                    format!("TABLE {}.{}", database, table),
+                    Some(GrantObject::Table(catalog_name, database, table)),
+                ))
+            }
+            GrantObjectName::Stage(name) => {
+                UserApiProvider::instance().get_stage(&tenant, name).await?;
+                Ok((
+                    OwnershipObject::Stage { name: name.clone() },
+                    format!("STAGE {}", name),
+                    None,
+                ))
+            }
+            GrantObjectName::UDF(name) => {
+                UserApiProvider::instance().get_udf(&tenant, name).await?;
+                Ok((
+                    OwnershipObject::UDF { name: name.clone() },
+                    format!("FUNCTION {}", name),
+                    None,
+                ))
+            }
         }
     }
 
@@ -102,6 +216,7 @@ impl Binder {
                     principal: principal.clone(),
                     on: grant_object,
                     priv_types,
+                    columns: BTreeSet::new(),
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
             }
@@ -115,12 +230,55 @@ impl Binder {
                     principal: principal.clone(),
                     on: grant_object,
                     priv_types,
+                    columns: BTreeSet::new(),
+                };
+                Ok(Plan::RevokePriv(Box::new(plan)))
+            }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                let (grant_object, priv_types, columns) =
+                    self.bind_column_privileges(privileges, columns, level)?;
+                let plan = RevokePrivilegePlan {
+                    principal: principal.clone(),
+                    on: grant_object,
+                    priv_types,
+                    columns,
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
             }
         }
     }
 
+    /// Column lists are only allowed on the SELECT privilege of tables, e.g. `SELECT(a, b) ON db.t`.
+    fn bind_column_privileges(
+        &self,
+        privileges: &[UserPrivilegeType],
+        columns: &[Identifier],
+        level: &AccountMgrLevel,
+    ) -> Result<(GrantObject, UserPrivilegeSet, BTreeSet<String>)> {
+        if privileges.iter().any(|p| *p != UserPrivilegeType::Select) {
+            return Err(ErrorCode::SemanticError(
+                "only the SELECT privilege can be granted on columns",
+            ));
+        }
+        let grant_object = self.convert_to_grant_object(level);
+        if !matches!(grant_object, GrantObject::Table(_, _, _)) {
+            return Err(ErrorCode::SemanticError(
+                "column privileges can only be granted on a table",
+            ));
+        }
+        let mut priv_types = UserPrivilegeSet::empty();
+        priv_types.set_privilege(UserPrivilegeType::Select);
+        let columns = columns
+            .iter()
+            .map(|c| normalize_identifier(c, &self.name_resolution_ctx).name)
+            .collect();
+        Ok((grant_object, priv_types, columns))
+    }
+
     pub(in crate::planner::binder) fn convert_to_grant_object(
         &self,
         source: &AccountMgrLevel,
@@ -159,9 +317,21 @@ impl Binder {
         for option in user_options {
             option.apply(&mut user_option);
         }
+        let auth_info = AuthInfo::create2(&auth_option.auth_type, &auth_option.password)?;
+        if let AuthInfo::KeyPair { public_key, .. } = &auth_info {
+            parse_rsa_public_key(public_key)?;
+        }
+        if let (Some(name), Some(password)) = (user_option.password_policy(), &auth_option.password)
+        {
+            if matches!(auth_info, AuthInfo::Password { .. }) {
+                UserApiProvider::instance()
+                    .verify_password(&self.ctx.get_tenant(), name, password, None)
+                    .await?;
+            }
+        }
         let plan = CreateUserPlan {
             user: user.clone(),
-            auth_info: AuthInfo::create2(&auth_option.auth_type, &auth_option.password)?,
+            auth_info,
             user_option,
             if_not_exists: *if_not_exists,
         };
@@ -187,11 +357,30 @@ impl Binder {
                 .await?
         };
 
+        let mut user_option = user_info.option.clone();
+        for option in user_options {
+            option.apply(&mut user_option);
+        }
+
         // None means no change to make
         let new_auth_info = if let Some(auth_option) = &auth_option {
             let auth_info = user_info
                 .auth_info
                 .alter2(&auth_option.auth_type, &auth_option.password)?;
+            if let AuthInfo::KeyPair { public_key, .. } = &auth_info {
+                parse_rsa_public_key(public_key)?;
+            }
+            // The new password must satisfy the password policy of the user,
+            // so it is checked even if it is the same as the current one.
+            if let (Some(name), Some(password)) =
+                (user_option.password_policy(), &auth_option.password)
+            {
+                if matches!(auth_info, AuthInfo::Password { .. }) {
+                    UserApiProvider::instance()
+                        .verify_password(&self.ctx.get_tenant(), name, password, Some(&user_info))
+                        .await?;
+                }
+            }
             if user_info.auth_info == auth_info {
                 None
             } else {
@@ -201,10 +390,6 @@ impl Binder {
             None
         };
 
-        let mut user_option = user_info.option.clone();
-        for option in user_options {
-            option.apply(&mut user_option);
-        }
         let new_user_option = if user_option == user_info.option {
             None
         } else {
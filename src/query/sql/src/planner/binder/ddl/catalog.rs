@@ -32,6 +32,7 @@ use common_meta_app::schema::CatalogOption;
 use common_meta_app::schema::CatalogType;
 use common_meta_app::schema::HiveCatalogOption;
 use common_meta_app::schema::IcebergCatalogOption;
+use common_meta_app::schema::RdbmsCatalogOption;
 use url::Url;
 
 use crate::binder::parse_uri_location;
@@ -209,6 +210,36 @@ impl Binder {
                 };
                 CatalogOption::Iceberg(opt)
             }
+            CatalogType::Mysql | CatalogType::Postgres => {
+                let url = options
+                    .get("url")
+                    .ok_or_else(|| ErrorCode::InvalidArgument("expected field: URL"))?;
+
+                let parsed = Url::parse(url).map_err(|err| {
+                    ErrorCode::InvalidArgument(format!("expected valid URL: {:?}", err))
+                })?;
+                let expected_schemes: &[&str] = match catalog_type {
+                    CatalogType::Mysql => &["mysql"],
+                    _ => &["postgres", "postgresql"],
+                };
+                if !expected_schemes.contains(&parsed.scheme()) {
+                    return Err(ErrorCode::InvalidArgument(format!(
+                        "expected URL scheme of {} catalog: {}, got: {}",
+                        catalog_type,
+                        expected_schemes.join(" or "),
+                        parsed.scheme()
+                    )));
+                }
+                if parsed.host_str().is_none() {
+                    return Err(ErrorCode::InvalidArgument(
+                        "expected valid URI: no hostname section",
+                    ));
+                }
+
+                CatalogOption::Rdbms(RdbmsCatalogOption {
+                    url: url.to_string(),
+                })
+            }
         };
 
         Ok(CatalogMeta {
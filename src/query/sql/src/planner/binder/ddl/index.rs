@@ -15,7 +15,9 @@
 use std::sync::Arc;
 
 use common_ast::ast::CreateIndexStmt;
+use common_ast::ast::CreateInvertedIndexStmt;
 use common_ast::ast::DropIndexStmt;
+use common_ast::ast::DropInvertedIndexStmt;
 use common_ast::ast::GroupBy;
 use common_ast::ast::Identifier;
 use common_ast::ast::Query;
@@ -29,6 +31,8 @@ use common_ast::walk_statement_mut;
 use common_ast::Dialect;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::TableDataType;
+use common_functions::scalars::Tokenizer;
 use common_meta_app::schema::GetIndexReq;
 use common_meta_app::schema::IndexNameIdent;
 
@@ -36,8 +40,11 @@ use crate::binder::Binder;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
+use crate::planner::semantic::normalize_identifier;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateInvertedIndexPlan;
 use crate::plans::DropIndexPlan;
+use crate::plans::DropInvertedIndexPlan;
 use crate::plans::Plan;
 use crate::plans::RefreshIndexPlan;
 use crate::AggregatingIndexRewriter;
@@ -253,4 +260,101 @@ impl Binder {
             }
         }
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            columns,
+            tokenizer,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if table_info.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(
+                "Inverted index only support FUSE engine",
+            ));
+        }
+        let schema = table_info.schema();
+
+        let mut column_ids = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+            let field = schema.field_with_name(&column)?;
+            if field.computed_expr().is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Inverted index can not be created on computed column {}",
+                    column
+                )));
+            }
+            if !matches!(field.data_type().remove_nullable(), TableDataType::String) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Inverted index only support String type, but column {} is {}",
+                    column,
+                    field.data_type()
+                )));
+            }
+            if column_ids.contains(&field.column_id()) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Duplicated column {} in inverted index",
+                    column
+                )));
+            }
+            column_ids.push(field.column_id());
+        }
+
+        let tokenizer = match tokenizer {
+            Some(tokenizer) => Tokenizer::from_name(tokenizer)?,
+            None => Tokenizer::default(),
+        };
+
+        let plan = CreateInvertedIndexPlan {
+            if_not_exists: *if_not_exists,
+            catalog,
+            database,
+            table,
+            index_name,
+            column_ids,
+            tokenizer: tokenizer.name().to_string(),
+        };
+        Ok(Plan::CreateInvertedIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+        &mut self,
+        stmt: &DropInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let DropInvertedIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = normalize_identifier(index_name, &self.name_resolution_ctx).name;
+
+        let plan = DropInvertedIndexPlan {
+            if_exists: *if_exists,
+            catalog,
+            database,
+            table,
+            index_name,
+        };
+        Ok(Plan::DropInvertedIndex(Box::new(plan)))
+    }
 }
@@ -0,0 +1,182 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PasswordPolicy;
+
+use crate::binder::Binder;
+use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::CreatePasswordPolicyPlan;
+use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DropPasswordPolicyPlan;
+use crate::plans::Plan;
+use crate::plans::ShowPasswordPoliciesPlan;
+
+// The max length of a password, also the max value of the character options.
+const MAX_PASSWORD_LENGTH: u64 = 256;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_password_policy(
+        &mut self,
+        stmt: &CreatePasswordPolicyStmt,
+    ) -> Result<Plan> {
+        let CreatePasswordPolicyStmt {
+            if_not_exists,
+            name,
+            options,
+        } = stmt;
+        check_password_policy_options(options)?;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreatePasswordPolicyPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            name: name.to_string(),
+            min_length: options
+                .min_length
+                .unwrap_or(PasswordPolicy::DEFAULT_MIN_LENGTH),
+            min_upper_case_chars: options
+                .min_upper_case_chars
+                .unwrap_or(PasswordPolicy::DEFAULT_MIN_UPPER_CASE_CHARS),
+            min_lower_case_chars: options
+                .min_lower_case_chars
+                .unwrap_or(PasswordPolicy::DEFAULT_MIN_LOWER_CASE_CHARS),
+            min_numeric_chars: options
+                .min_numeric_chars
+                .unwrap_or(PasswordPolicy::DEFAULT_MIN_NUMERIC_CHARS),
+            min_special_chars: options
+                .min_special_chars
+                .unwrap_or(PasswordPolicy::DEFAULT_MIN_SPECIAL_CHARS),
+            max_age_days: options
+                .max_age_days
+                .unwrap_or(PasswordPolicy::DEFAULT_MAX_AGE_DAYS),
+            max_retries: options
+                .max_retries
+                .unwrap_or(PasswordPolicy::DEFAULT_MAX_RETRIES),
+            lockout_time_mins: options
+                .lockout_time_mins
+                .unwrap_or(PasswordPolicy::DEFAULT_LOCKOUT_TIME_MINS),
+            history: options.history.unwrap_or(PasswordPolicy::DEFAULT_HISTORY),
+            comment: options.comment.clone().unwrap_or_default(),
+        };
+        Ok(Plan::CreatePasswordPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_password_policy(
+        &mut self,
+        stmt: &AlterPasswordPolicyStmt,
+    ) -> Result<Plan> {
+        let AlterPasswordPolicyStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+        check_password_policy_options(options)?;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = AlterPasswordPolicyPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+            min_length: options.min_length,
+            min_upper_case_chars: options.min_upper_case_chars,
+            min_lower_case_chars: options.min_lower_case_chars,
+            min_numeric_chars: options.min_numeric_chars,
+            min_special_chars: options.min_special_chars,
+            max_age_days: options.max_age_days,
+            max_retries: options.max_retries,
+            lockout_time_mins: options.lockout_time_mins,
+            history: options.history,
+            comment: options.comment.clone(),
+        };
+        Ok(Plan::AlterPasswordPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_password_policy(
+        &mut self,
+        stmt: &DropPasswordPolicyStmt,
+    ) -> Result<Plan> {
+        let DropPasswordPolicyStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropPasswordPolicyPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropPasswordPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_password_policy(
+        &mut self,
+        stmt: &DescPasswordPolicyStmt,
+    ) -> Result<Plan> {
+        let DescPasswordPolicyStmt { name } = stmt;
+
+        let plan = DescPasswordPolicyPlan {
+            name: name.to_string(),
+        };
+        Ok(Plan::DescPasswordPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_password_policies(&mut self) -> Result<Plan> {
+        let plan = ShowPasswordPoliciesPlan {};
+        Ok(Plan::ShowPasswordPolicies(Box::new(plan)))
+    }
+}
+
+fn check_password_policy_options(options: &PasswordPolicyOptions) -> Result<()> {
+    if let Some(min_length) = options.min_length {
+        if min_length == 0 || min_length > MAX_PASSWORD_LENGTH {
+            return Err(ErrorCode::SemanticError(format!(
+                "PASSWORD_MIN_LENGTH of password policy must be between 1 and {}",
+                MAX_PASSWORD_LENGTH
+            )));
+        }
+    }
+    let chars_options = [
+        (
+            "PASSWORD_MIN_UPPER_CASE_CHARS",
+            options.min_upper_case_chars,
+        ),
+        (
+            "PASSWORD_MIN_LOWER_CASE_CHARS",
+            options.min_lower_case_chars,
+        ),
+        ("PASSWORD_MIN_NUMERIC_CHARS", options.min_numeric_chars),
+        ("PASSWORD_MIN_SPECIAL_CHARS", options.min_special_chars),
+    ];
+    for (option, value) in chars_options {
+        if value.unwrap_or_default() > MAX_PASSWORD_LENGTH {
+            return Err(ErrorCode::SemanticError(format!(
+                "{} of password policy must not be greater than {}",
+                option, MAX_PASSWORD_LENGTH
+            )));
+        }
+    }
+    if options.history.unwrap_or_default() > PasswordPolicy::MAX_HISTORY {
+        return Err(ErrorCode::SemanticError(format!(
+            "PASSWORD_HISTORY of password policy must not be greater than {}",
+            PasswordPolicy::MAX_HISTORY
+        )));
+    }
+    Ok(())
+}
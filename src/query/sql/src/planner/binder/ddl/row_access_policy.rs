@@ -0,0 +1,142 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_ast::ast::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use parking_lot::RwLock;
+
+use crate::binder::row_access_policy::bind_row_access_policy_body;
+use crate::binder::Binder;
+use crate::binder::ColumnBindingBuilder;
+use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::resolve_type_name;
+use crate::plans::BoundColumnRef;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::Plan;
+use crate::Metadata;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_row_access_policy(
+        &mut self,
+        stmt: &CreateRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateRowAccessPolicyStmt {
+            if_not_exists,
+            name,
+            args,
+            return_type,
+            body,
+            comment,
+        } = stmt;
+
+        if DataType::from(&resolve_type_name(return_type)?).remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "row access policy must return BOOLEAN, but got {}",
+                return_type
+            )));
+        }
+        if args.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "row access policy must have at least one arg",
+            ));
+        }
+
+        let mut arg_names = HashSet::with_capacity(args.len());
+        let mut policy_args = Vec::with_capacity(args.len());
+        let mut aliases = Vec::with_capacity(args.len());
+        for (index, arg) in args.iter().enumerate() {
+            let arg_name = normalize_identifier(&arg.arg_name, &self.name_resolution_ctx).name;
+            if !arg_names.insert(arg_name.clone()) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicate arg name {} in row access policy",
+                    arg_name
+                ))
+                .set_span(arg.arg_name.span));
+            }
+            let data_type = DataType::from(&resolve_type_name(&arg.arg_type)?);
+            let column = ColumnBindingBuilder::new(
+                arg_name.clone(),
+                index,
+                Box::new(data_type),
+                Visibility::Visible,
+            )
+            .build();
+            aliases.push((
+                arg_name.clone(),
+                ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: arg.arg_name.span,
+                    column,
+                }),
+            ));
+            policy_args.push((arg_name, arg.arg_type.to_string()));
+        }
+
+        // Check the body refers only to the args and returns a boolean.
+        let body = body.to_string();
+        let metadata = Arc::new(RwLock::new(Metadata::default()));
+        bind_row_access_policy_body(self, &body, &aliases, metadata).await?;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreateRowAccessPolicyPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            name: name.to_string(),
+            args: policy_args,
+            body,
+            comment: comment.clone(),
+        };
+        Ok(Plan::CreateRowAccessPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_row_access_policy(
+        &mut self,
+        stmt: &DropRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DropRowAccessPolicyStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropRowAccessPolicyPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropRowAccessPolicy(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_row_access_policy(
+        &mut self,
+        stmt: &DescRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DescRowAccessPolicyStmt { name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DescRowAccessPolicyPlan {
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DescRowAccessPolicy(Box::new(plan)))
+    }
+}
@@ -223,9 +223,12 @@ impl Binder {
         stmt: &ShowObjectGrantPrivilegesStmt,
     ) -> Result<Plan> {
         let ShowObjectGrantPrivilegesStmt { object } = stmt;
+        let (object, object_name, grant_object) = self.resolve_ownership_object(object).await?;
 
         let plan = ShowObjectGrantPrivilegesPlan {
-            object: object.clone(),
+            object,
+            object_name,
+            grant_object,
         };
         Ok(Plan::ShowObjectGrantPrivileges(Box::new(plan)))
     }
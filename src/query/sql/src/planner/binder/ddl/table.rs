@@ -41,25 +41,32 @@ use common_ast::ast::ShowLimit;
 use common_ast::ast::ShowTablesStatusStmt;
 use common_ast::ast::ShowTablesStmt;
 use common_ast::ast::Statement;
+use common_ast::ast::TableConstraint;
 use common_ast::ast::TableReference;
 use common_ast::ast::TruncateTableStmt;
 use common_ast::ast::UndropTableStmt;
 use common_ast::ast::UriLocation;
 use common_ast::ast::VacuumDropTableStmt;
 use common_ast::ast::VacuumTableStmt;
+use common_ast::ast::SPACE_FILLING_CURVES;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::walk_expr_mut;
 use common_ast::Dialect;
+use common_catalog::table::Table;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::infer_schema_type;
 use common_expression::infer_table_schema;
+use common_expression::type_check::check_cast;
 use common_expression::types::DataType;
 use common_expression::ComputedExpr;
+use common_expression::ConstantFolder;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
+use common_expression::Scalar;
+use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
@@ -72,7 +79,9 @@ use common_storages_view::view_table::VIEW_ENGINE;
 use log::debug;
 use log::error;
 use storages_common_table_meta::table::is_reserved_opt_key;
+use storages_common_table_meta::table::TableConstraints;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
@@ -87,17 +96,21 @@ use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
 use crate::parse_computed_expr_to_string;
 use crate::parse_default_expr_to_string;
+use crate::parse_exprs;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTableAction;
@@ -108,6 +121,7 @@ use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RewriteKind;
+use crate::plans::ScalarExpr;
 use crate::plans::SetOptionsPlan;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::TruncateTablePlan;
@@ -379,6 +393,9 @@ impl Binder {
             source,
             table_options,
             cluster_by,
+            partition_by,
+            ttl,
+            constraints,
             as_query,
             transient,
             engine,
@@ -560,6 +577,40 @@ impl Binder {
             }
         };
 
+        let partition_by = match partition_by {
+            Some(partition_by) => {
+                if engine != Engine::Fuse {
+                    return Err(ErrorCode::UnsupportedEngineParams(format!(
+                        "Unsupported partition key for engine: {engine}"
+                    )));
+                }
+                Some(
+                    self.analyze_partition_key(partition_by, schema.clone())
+                        .await?,
+                )
+            }
+            None => None,
+        };
+
+        let ttl = match ttl {
+            Some(ttl) => {
+                if engine != Engine::Fuse {
+                    return Err(ErrorCode::UnsupportedEngineParams(format!(
+                        "Unsupported TTL for engine: {engine}"
+                    )));
+                }
+                Some(self.analyze_ttl(ttl, schema.clone()).await?)
+            }
+            None => None,
+        };
+
+        if !constraints.is_empty() && engine != Engine::Fuse {
+            return Err(ErrorCode::UnsupportedEngineParams(format!(
+                "Unsupported key constraints for engine: {engine}"
+            )));
+        }
+        let constraints = self.analyze_constraints(constraints, &schema)?;
+
         let plan = CreateTablePlan {
             if_not_exists: *if_not_exists,
             tenant: self.ctx.get_tenant(),
@@ -573,6 +624,9 @@ impl Binder {
             options,
             field_comments,
             cluster_key,
+            partition_by,
+            ttl,
+            constraints,
             as_select: if let Some(query) = as_query {
                 let mut bind_context = BindContext::new();
                 let stmt = Statement::Query(Box::new(*query.clone()));
@@ -641,6 +695,9 @@ impl Binder {
             options,
             engine: Engine::Fuse,
             cluster_key: None,
+            partition_by: None,
+            ttl: None,
+            constraints: TableConstraints::default(),
             as_select: None,
             schema: Arc::new(TableSchema::default()),
             field_comments: vec![],
@@ -819,6 +876,33 @@ impl Binder {
                     table,
                 },
             ))),
+            AlterTableAction::DropPartition {
+                if_exists,
+                partition,
+            } => {
+                let tbl = self.ctx.get_table(&catalog, &database, &table).await?;
+                let partition = self.bind_partition_value(tbl, partition).await?;
+                Ok(Plan::DropTablePartition(Box::new(DropTablePartitionPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    partition,
+                    if_exists: *if_exists,
+                })))
+            }
+            AlterTableAction::TruncatePartition { partition } => {
+                let tbl = self.ctx.get_table(&catalog, &database, &table).await?;
+                let partition = self.bind_partition_value(tbl, partition).await?;
+                Ok(Plan::DropTablePartition(Box::new(DropTablePartitionPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    partition,
+                    if_exists: true,
+                })))
+            }
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
@@ -872,6 +956,31 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let columns = columns
+                    .iter()
+                    .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+                    .collect();
+                Ok(Plan::AddTableRowAccessPolicy(Box::new(
+                    AddTableRowAccessPolicyPlan {
+                        tenant,
+                        catalog,
+                        database,
+                        table,
+                        policy: policy.to_string(),
+                        columns,
+                    },
+                )))
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(Plan::DropTableRowAccessPolicy(
+                Box::new(DropTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                }),
+            )),
         }
     }
 
@@ -1337,18 +1446,7 @@ impl Binder {
         schema: TableSchemaRef,
     ) -> Result<Vec<String>> {
         // Build a temporary BindContext to resolve the expr
-        let mut bind_context = BindContext::new();
-        for (index, field) in schema.fields().iter().enumerate() {
-            let column = ColumnBindingBuilder::new(
-                field.name().clone(),
-                index,
-                Box::new(DataType::from(field.data_type())),
-                Visibility::Visible,
-            )
-            .build();
-
-            bind_context.columns.push(column);
-        }
+        let mut bind_context = Self::schema_bind_context(&schema);
         let mut scalar_binder = ScalarBinder::new(
             &mut bind_context,
             self.ctx.clone(),
@@ -1359,10 +1457,29 @@ impl Binder {
         // cluster keys cannot be a udf expression.
         scalar_binder.forbid_udf();
 
-        let mut cluster_keys = Vec::with_capacity(cluster_by.len());
+        let cluster_keys_len = cluster_by.len();
+        let mut cluster_keys = Vec::with_capacity(cluster_keys_len);
         for cluster_by in cluster_by.iter() {
             let (cluster_key, _) = scalar_binder.bind(cluster_by).await?;
-            if cluster_key.used_columns().len() != 1 || !cluster_key.valid_for_clustering() {
+            let is_valid = match &cluster_key {
+                // A space-filling curve key combines several columns, so it must be the only
+                // cluster key, and each of its arguments must be a valid cluster key itself.
+                ScalarExpr::FunctionCall(func)
+                    if SPACE_FILLING_CURVES.contains(&func.func_name.as_str()) =>
+                {
+                    if cluster_keys_len > 1 {
+                        return Err(ErrorCode::InvalidClusterKeys(format!(
+                            "Cluster by expression `{:#}` must be the only cluster key",
+                            cluster_by
+                        )));
+                    }
+                    func.arguments
+                        .iter()
+                        .all(|arg| arg.used_columns().len() == 1 && arg.valid_for_clustering())
+                }
+                _ => cluster_key.used_columns().len() == 1 && cluster_key.valid_for_clustering(),
+            };
+            if !is_valid {
                 return Err(ErrorCode::InvalidClusterKeys(format!(
                     "Cluster by expression `{:#}` is invalid",
                     cluster_by
@@ -1398,6 +1515,224 @@ impl Binder {
         Ok(cluster_keys)
     }
 
+    async fn analyze_partition_key(
+        &mut self,
+        partition_by: &Expr,
+        schema: TableSchemaRef,
+    ) -> Result<String> {
+        let mut bind_context = Self::schema_bind_context(&schema);
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        // partition key cannot be a udf expression.
+        scalar_binder.forbid_udf();
+
+        let (partition_key, _) = scalar_binder.bind(partition_by).await?;
+        if partition_key.used_columns().is_empty() || !partition_key.valid_for_clustering() {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Partition by expression `{:#}` is invalid",
+                partition_by
+            )));
+        }
+
+        let expr = partition_key.as_expr()?;
+        if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Partition by expression `{:#}` is not deterministic",
+                partition_by
+            )));
+        }
+
+        let data_type = expr.data_type();
+        if !Self::valid_partition_key_type(data_type) {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Unsupported data type '{}' for partition by expression `{:#}`",
+                data_type, partition_by
+            )));
+        }
+
+        let mut partition_by = partition_by.clone();
+        walk_expr_mut(
+            &mut IdentifierNormalizer {
+                ctx: &self.name_resolution_ctx,
+            },
+            &mut partition_by,
+        );
+        Ok(format!("{:#}", &partition_by))
+    }
+
+    async fn analyze_ttl(&mut self, ttl: &Expr, schema: TableSchemaRef) -> Result<String> {
+        let mut bind_context = Self::schema_bind_context(&schema);
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        // ttl cannot be a udf expression.
+        scalar_binder.forbid_udf();
+
+        let (ttl_expr, _) = scalar_binder.bind(ttl).await?;
+        if ttl_expr.used_columns().is_empty() || !ttl_expr.valid_for_clustering() {
+            return Err(ErrorCode::InvalidTableTtl(format!(
+                "TTL expression `{:#}` is invalid",
+                ttl
+            )));
+        }
+
+        let expr = ttl_expr.as_expr()?;
+        if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            return Err(ErrorCode::InvalidTableTtl(format!(
+                "TTL expression `{:#}` is not deterministic",
+                ttl
+            )));
+        }
+
+        let data_type = expr.data_type().remove_nullable();
+        if !matches!(data_type, DataType::Timestamp | DataType::Date) {
+            return Err(ErrorCode::InvalidTableTtl(format!(
+                "TTL expression `{:#}` must be of type Timestamp or Date, but got '{}'",
+                ttl, data_type
+            )));
+        }
+
+        let mut ttl = ttl.clone();
+        walk_expr_mut(
+            &mut IdentifierNormalizer {
+                ctx: &self.name_resolution_ctx,
+            },
+            &mut ttl,
+        );
+        Ok(format!("{:#}", &ttl))
+    }
+
+    /// Resolves the columns of `PRIMARY KEY` and `UNIQUE` constraints to column ids.
+    ///
+    /// Key columns must be stored top-level columns of a plain type, and the columns
+    /// of the primary key must not be nullable.
+    fn analyze_constraints(
+        &self,
+        constraints: &[TableConstraint],
+        schema: &TableSchemaRef,
+    ) -> Result<TableConstraints> {
+        let mut table_constraints = TableConstraints::default();
+        for constraint in constraints {
+            let (is_primary_key, columns) = match constraint {
+                TableConstraint::PrimaryKey(columns) => (true, columns),
+                TableConstraint::Unique(columns) => (false, columns),
+            };
+            if is_primary_key && table_constraints.primary_key.is_some() {
+                return Err(ErrorCode::InvalidTableConstraint(
+                    "a table can only have one primary key",
+                ));
+            }
+
+            let mut key = Vec::with_capacity(columns.len());
+            for column in columns {
+                let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+                let field = schema.field_with_name(&name).map_err(|_| {
+                    ErrorCode::InvalidTableConstraint(format!(
+                        "column `{name}` of constraint `{constraint}` does not exist"
+                    ))
+                })?;
+                if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
+                    return Err(ErrorCode::InvalidTableConstraint(format!(
+                        "virtual computed column `{name}` can not be used in a key constraint"
+                    )));
+                }
+                if matches!(
+                    field.data_type().remove_nullable(),
+                    TableDataType::Tuple { .. }
+                        | TableDataType::Array(_)
+                        | TableDataType::Map(_)
+                        | TableDataType::Variant
+                ) {
+                    return Err(ErrorCode::InvalidTableConstraint(format!(
+                        "column `{name}` of type '{}' can not be used in a key constraint",
+                        field.data_type()
+                    )));
+                }
+                if is_primary_key && field.is_nullable() {
+                    return Err(ErrorCode::InvalidTableConstraint(format!(
+                        "primary key column `{name}` must be NOT NULL"
+                    )));
+                }
+                if key.contains(&field.column_id()) {
+                    return Err(ErrorCode::InvalidTableConstraint(format!(
+                        "column `{name}` is duplicated in constraint `{constraint}`"
+                    )));
+                }
+                key.push(field.column_id());
+            }
+
+            if is_primary_key {
+                table_constraints.primary_key = Some(key);
+            } else {
+                table_constraints.unique_keys.push(key);
+            }
+        }
+        Ok(table_constraints)
+    }
+
+    /// Binds the value of a partition of the table, the value must be a constant,
+    /// and it is casted to the type of the partition key.
+    pub(in crate::planner::binder) async fn bind_partition_value(
+        &mut self,
+        table: Arc<dyn Table>,
+        partition: &Expr,
+    ) -> Result<Scalar> {
+        let partition_by = table.options().get(OPT_KEY_PARTITION_BY).ok_or_else(|| {
+            ErrorCode::UnpartitionedTable(format!("Table '{}' is not partitioned", table.name()))
+        })?;
+        let partition_key = parse_exprs(self.ctx.clone(), table.clone(), partition_by)?;
+        let data_type = partition_key[0].data_type().clone();
+
+        let mut bind_context = BindContext::new();
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (value, _) = scalar_binder.bind(partition).await?;
+        let value = value.as_expr()?;
+        let value = check_cast(value.span(), false, value, &data_type, &BUILTIN_FUNCTIONS)?;
+        let (value, _) = ConstantFolder::fold(
+            &value,
+            &self.ctx.get_function_context()?,
+            &BUILTIN_FUNCTIONS,
+        );
+        match value {
+            common_expression::Expr::Constant { scalar, .. } => Ok(scalar),
+            _ => Err(ErrorCode::InvalidPartitionKey(format!(
+                "Partition value `{:#}` must be a constant",
+                partition
+            ))),
+        }
+    }
+
+    fn schema_bind_context(schema: &TableSchemaRef) -> BindContext {
+        let mut bind_context = BindContext::new();
+        for (index, field) in schema.fields().iter().enumerate() {
+            let column = ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(DataType::from(field.data_type())),
+                Visibility::Visible,
+            )
+            .build();
+
+            bind_context.columns.push(column);
+        }
+        bind_context
+    }
+
     fn valid_cluster_key_type(data_type: &DataType) -> bool {
         let inner_type = data_type.remove_nullable();
         matches!(
@@ -1410,4 +1745,17 @@ impl Binder {
                 | DataType::Decimal(_)
         )
     }
+
+    // The partition value is encoded into the segment file names, only fixed width types.
+    fn valid_partition_key_type(data_type: &DataType) -> bool {
+        let inner_type = data_type.remove_nullable();
+        matches!(
+            inner_type,
+            DataType::Number(_)
+                | DataType::Timestamp
+                | DataType::Date
+                | DataType::Boolean
+                | DataType::Decimal(_)
+        )
+    }
 }
@@ -0,0 +1,125 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::WorkloadGroup;
+
+use crate::binder::Binder;
+use crate::plans::AlterRoleWorkloadGroupPlan;
+use crate::plans::AlterWorkloadGroupPlan;
+use crate::plans::CreateWorkloadGroupPlan;
+use crate::plans::DropWorkloadGroupPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_workload_group(
+        &mut self,
+        stmt: &CreateWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let CreateWorkloadGroupStmt {
+            if_not_exists,
+            name,
+            options,
+        } = stmt;
+        check_workload_group_options(options)?;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = CreateWorkloadGroupPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            name: name.to_string(),
+            cpu_share: options
+                .cpu_share
+                .unwrap_or(WorkloadGroup::DEFAULT_CPU_SHARE),
+            memory_limit: options.memory_limit.unwrap_or_default(),
+            max_concurrency: options.max_concurrency.unwrap_or_default(),
+            queue_timeout: options.queue_timeout.unwrap_or_default(),
+            comment: options.comment.clone().unwrap_or_default(),
+        };
+        Ok(Plan::CreateWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_workload_group(
+        &mut self,
+        stmt: &AlterWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let AlterWorkloadGroupStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+        check_workload_group_options(options)?;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = AlterWorkloadGroupPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+            cpu_share: options.cpu_share,
+            memory_limit: options.memory_limit,
+            max_concurrency: options.max_concurrency,
+            queue_timeout: options.queue_timeout,
+            comment: options.comment.clone(),
+        };
+        Ok(Plan::AlterWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_workload_group(
+        &mut self,
+        stmt: &DropWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let DropWorkloadGroupStmt { if_exists, name } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = DropWorkloadGroupPlan {
+            if_exists: *if_exists,
+            tenant,
+            name: name.to_string(),
+        };
+        Ok(Plan::DropWorkloadGroup(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_role_workload_group(
+        &mut self,
+        stmt: &AlterRoleWorkloadGroupStmt,
+    ) -> Result<Plan> {
+        let AlterRoleWorkloadGroupStmt {
+            role_name,
+            workload_group,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let plan = AlterRoleWorkloadGroupPlan {
+            tenant,
+            role_name: role_name.clone(),
+            workload_group: workload_group.clone(),
+        };
+        Ok(Plan::AlterRoleWorkloadGroup(Box::new(plan)))
+    }
+}
+
+fn check_workload_group_options(options: &WorkloadGroupOptions) -> Result<()> {
+    if options.cpu_share == Some(0) {
+        return Err(ErrorCode::SemanticError(
+            "CPU_SHARE of workload group must be greater than 0",
+        ));
+    }
+    Ok(())
+}
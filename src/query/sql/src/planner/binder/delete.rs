@@ -28,6 +28,7 @@ use crate::optimizer::SExpr;
 use crate::optimizer::SubqueryRewriter;
 use crate::plans::DeletePlan;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::Operator;
 use crate::plans::Plan;
 use crate::plans::RelOp;
@@ -41,11 +42,25 @@ impl<'a> Binder {
     pub(in crate::planner::binder) async fn process_selection(
         &self,
         filter: &'a Option<Expr>,
+        row_access_policy: Option<ScalarExpr>,
         table_expr: SExpr,
         scalar_binder: &mut ScalarBinder<'_>,
     ) -> Result<(Option<ScalarExpr>, Vec<SubqueryDesc>)> {
-        Ok(if let Some(expr) = filter {
-            let (scalar, _) = scalar_binder.bind(expr).await?;
+        let scalar = match filter {
+            Some(expr) => Some(scalar_binder.bind(expr).await?.0),
+            None => None,
+        };
+        // Only the rows visible under the row access policy can be mutated.
+        let scalar = match (scalar, row_access_policy) {
+            (Some(scalar), Some(policy)) => Some(ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "and".to_string(),
+                params: vec![],
+                arguments: vec![scalar, policy],
+            })),
+            (scalar, policy) => scalar.or(policy),
+        };
+        Ok(if let Some(scalar) = scalar {
             let mut subquery_desc = vec![];
             self.subquery_desc(&scalar, table_expr, &mut subquery_desc)
                 .await?;
@@ -77,9 +92,20 @@ impl<'a> Binder {
             ));
         };
 
+        bind_context.planning_mutation_target = true;
         let (table_expr, mut context) = self
             .bind_table_reference(bind_context, table_reference)
             .await?;
+        bind_context.planning_mutation_target = false;
+
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let row_access_policy = match self.resolve_row_access_policy(table.as_ref()).await? {
+            Some(policy) => Some(self.bind_row_access_policy(&context, &policy).await?),
+            None => None,
+        };
 
         let mut scalar_binder = ScalarBinder::new(
             &mut context,
@@ -90,7 +116,7 @@ impl<'a> Binder {
         );
 
         let (selection, subquery_desc) = self
-            .process_selection(filter, table_expr, &mut scalar_binder)
+            .process_selection(filter, row_access_policy, table_expr, &mut scalar_binder)
             .await?;
 
         let plan = DeletePlan {
@@ -83,6 +83,7 @@ impl Binder {
             columns,
             source,
             overwrite,
+            partition,
             ..
         } = stmt;
         let (catalog_name, database_name, table_name) =
@@ -94,6 +95,18 @@ impl Binder {
         let table_id = table.get_id();
         let schema = self.schema_project(&table.schema(), columns)?;
 
+        let overwrite_partition = match partition {
+            Some(partition) => {
+                if !*overwrite {
+                    return Err(ErrorCode::SemanticError(
+                        "PARTITION clause can only be used with INSERT OVERWRITE",
+                    ));
+                }
+                Some(self.bind_partition_value(table.clone(), partition).await?)
+            }
+            None => None,
+        };
+
         let input_source: Result<InsertInputSource> = match source.clone() {
             InsertSource::Streaming {
                 format,
@@ -125,6 +138,9 @@ impl Binder {
             InsertSource::Values { rest_str } => {
                 let values_str = rest_str.trim_end_matches(';').trim_start().to_owned();
                 match self.ctx.get_stage_attachment() {
+                    Some(_) if overwrite_partition.is_some() => Err(ErrorCode::Unimplemented(
+                        "INSERT OVERWRITE ... PARTITION with stage attachment is not supported",
+                    )),
                     Some(attachment) => {
                         return self
                             .bind_copy_from_attachment(
@@ -162,6 +178,7 @@ impl Binder {
             table_id,
             schema,
             overwrite: *overwrite,
+            overwrite_partition,
             source: input_source?,
         };
 
@@ -34,6 +34,7 @@ mod presign;
 mod project;
 mod project_set;
 mod replace;
+mod row_access_policy;
 mod scalar;
 mod scalar_common;
 mod scalar_visitor;
@@ -56,6 +57,7 @@ pub use column_binding::ColumnBindingBuilder;
 pub use copy::parse_stage_location;
 pub use internal_column_factory::INTERNAL_COLUMN_FACTORY;
 pub use location::parse_uri_location;
+pub use row_access_policy::ResolvedRowAccessPolicy;
 pub use scalar::ScalarBinder;
 pub use scalar_common::*;
 pub use scalar_visitor::*;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -26,6 +27,8 @@ use common_ast::Dialect;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserPrivilegeType;
 
 use super::AggregateInfo;
 use crate::binder::select::SelectItem;
@@ -206,6 +209,7 @@ impl Binder {
                         Some(Indirection::Star(span)) => *span,
                         _ => None,
                     };
+                    let expanded_start = output.items.len();
                     match names.len() {
                         1 | 2 => {
                             self.resolve_qualified_name_without_database_name(
@@ -231,6 +235,13 @@ impl Binder {
                         }
                         _ => return Err(ErrorCode::SemanticError("Unsupported indirection type")),
                     };
+                    if self
+                        .ctx
+                        .get_settings()
+                        .get_select_star_exclude_denied_columns()?
+                    {
+                        self.exclude_denied_columns(&mut output, expanded_start)?;
+                    }
                 }
                 SelectTarget::AliasedExpr { expr, alias } => {
                     let mut scalar_binder = ScalarBinder::new(
@@ -263,6 +274,69 @@ impl Binder {
         Ok(output)
     }
 
+    /// Leave out the expanded columns of the tables on which the current user and role only have
+    /// column-level SELECT privileges not covering them. The query would be rejected by the
+    /// privilege check otherwise.
+    fn exclude_denied_columns(&self, output: &mut SelectList, expanded_start: usize) -> Result<()> {
+        let user = self.ctx.get_current_user()?;
+        let role = self.ctx.get_current_role();
+        let grant_sets = std::iter::once(&user.grants)
+            .chain(role.as_ref().map(|r| &r.grants))
+            .collect::<Vec<_>>();
+
+        // The granted columns of each table, `None` if all the columns are allowed.
+        let mut granted_columns: HashMap<IndexType, Option<BTreeSet<String>>> = HashMap::new();
+        let expanded_items = output.items.split_off(expanded_start);
+        for item in expanded_items {
+            let column = match &item.scalar {
+                ScalarExpr::BoundColumnRef(column_ref) => &column_ref.column,
+                _ => {
+                    output.items.push(item);
+                    continue;
+                }
+            };
+            let table_index = match column.table_index {
+                Some(table_index) => table_index,
+                None => {
+                    output.items.push(item);
+                    continue;
+                }
+            };
+            let granted = granted_columns.entry(table_index).or_insert_with(|| {
+                let metadata = self.metadata.read();
+                let table = metadata.table(table_index);
+                let object = GrantObject::Table(
+                    table.catalog().to_string(),
+                    table.database().to_string(),
+                    table.name().to_string(),
+                );
+                if grant_sets
+                    .iter()
+                    .any(|g| g.verify_privilege(&object, vec![UserPrivilegeType::Select]))
+                {
+                    return None;
+                }
+                let columns = grant_sets
+                    .iter()
+                    .flat_map(|g| g.granted_columns(&object, UserPrivilegeType::Select))
+                    .collect::<BTreeSet<_>>();
+                if columns.is_empty() {
+                    None
+                } else {
+                    Some(columns)
+                }
+            });
+            let allowed = match granted {
+                Some(columns) => columns.contains(&column.column_name),
+                None => true,
+            };
+            if allowed {
+                output.items.push(item);
+            }
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     async fn build_select_item<'a>(
         &self,
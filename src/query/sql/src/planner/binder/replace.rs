@@ -18,6 +18,7 @@ use std::sync::Arc;
 use common_ast::ast::InsertSource;
 use common_ast::ast::ReplaceStmt;
 use common_ast::ast::Statement;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::FileFormatOptionsAst;
 use common_meta_app::principal::OnErrorMode;
@@ -64,6 +65,19 @@ impl Binder {
             .await?;
         let table_id = table.get_id();
 
+        // REPLACE INTO deletes the conflicting rows before the policy could be applied,
+        // so it would let the user overwrite rows the policy hides from them.
+        if self
+            .resolve_row_access_policy(table.as_ref())
+            .await?
+            .is_some()
+        {
+            return Err(ErrorCode::InvalidRowAccessPolicy(format!(
+                "REPLACE INTO is not supported on table '{}' with a row access policy",
+                table_name
+            )));
+        }
+
         let schema = if columns.is_empty() {
             table.schema()
         } else {
@@ -0,0 +1,167 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::parser::parse_expr;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_meta_api::RowAccessPolicyApi;
+use common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+use common_meta_app::row_access_policy::RowAccessPolicyMeta;
+use common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use common_users::UserApiProvider;
+use storages_common_table_meta::table::TableRowAccessPolicy;
+
+use crate::binder::Binder;
+use crate::binder::ScalarBinder;
+use crate::plans::BoundColumnRef;
+use crate::plans::ScalarExpr;
+use crate::BindContext;
+use crate::MetadataRef;
+
+/// The row access policy attached to a table, with the args resolved to column names.
+pub struct ResolvedRowAccessPolicy {
+    pub name: String,
+    pub meta: RowAccessPolicyMeta,
+    /// The name of the column bound to each arg of the policy.
+    pub columns: Vec<String>,
+}
+
+impl Binder {
+    /// Resolve the row access policy attached to the table.
+    ///
+    /// The policy is fetched every time the table is bound, so changes to it take effect
+    /// immediately. If the policy has been dropped, binding fails rather than falling back
+    /// to an unfiltered scan.
+    #[async_backtrace::framed]
+    pub(crate) async fn resolve_row_access_policy(
+        &self,
+        table: &dyn Table,
+    ) -> Result<Option<ResolvedRowAccessPolicy>> {
+        let Some(binding) = TableRowAccessPolicy::from_options(table.options())? else {
+            return Ok(None);
+        };
+
+        let schema = table.schema();
+        let columns = binding
+            .column_ids
+            .iter()
+            .map(|id| {
+                schema
+                    .fields()
+                    .iter()
+                    .find(|field| field.column_id() == *id)
+                    .map(|field| field.name().clone())
+                    .ok_or_else(|| {
+                        ErrorCode::InvalidRowAccessPolicy(format!(
+                            "column bound to row access policy '{}' doesn't exist in table '{}'",
+                            binding.policy,
+                            table.name()
+                        ))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let reply = meta_api
+            .get_row_access_policy(GetRowAccessPolicyReq {
+                name: RowAccessPolicyNameIdent {
+                    tenant: self.ctx.get_tenant(),
+                    name: binding.policy.clone(),
+                },
+            })
+            .await?;
+        if reply.policy.args.len() != columns.len() {
+            return Err(ErrorCode::InvalidRowAccessPolicy(format!(
+                "row access policy '{}' takes {} args, but {} columns of table '{}' are bound to it",
+                binding.policy,
+                reply.policy.args.len(),
+                columns.len(),
+                table.name()
+            )));
+        }
+
+        Ok(Some(ResolvedRowAccessPolicy {
+            name: binding.policy,
+            meta: reply.policy,
+            columns,
+        }))
+    }
+
+    /// Bind the body of the policy to a predicate over the columns in `bind_context`.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_row_access_policy(
+        &self,
+        bind_context: &BindContext,
+        policy: &ResolvedRowAccessPolicy,
+    ) -> Result<ScalarExpr> {
+        let mut aliases = Vec::with_capacity(policy.columns.len());
+        for ((arg_name, _), column_name) in policy.meta.args.iter().zip(policy.columns.iter()) {
+            let column = bind_context
+                .columns
+                .iter()
+                .find(|column| &column.column_name == column_name)
+                .ok_or_else(|| {
+                    ErrorCode::InvalidRowAccessPolicy(format!(
+                        "column {} bound to row access policy '{}' doesn't exist",
+                        column_name, policy.name
+                    ))
+                })?;
+            aliases.push((
+                arg_name.clone(),
+                ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: column.clone(),
+                }),
+            ));
+        }
+
+        // Results computed under the policy depend on the current user and role.
+        self.ctx.set_cacheable(false);
+
+        bind_row_access_policy_body(self, &policy.meta.body, &aliases, self.metadata.clone()).await
+    }
+}
+
+/// Bind the body of a row access policy, in which only the args of the policy are visible.
+#[async_backtrace::framed]
+pub(crate) async fn bind_row_access_policy_body(
+    binder: &Binder,
+    body: &str,
+    args: &[(String, ScalarExpr)],
+    metadata: MetadataRef,
+) -> Result<ScalarExpr> {
+    let tokens = tokenize_sql(body)?;
+    let expr = parse_expr(&tokens, Dialect::PostgreSQL)?;
+    // Bind in a context of its own, so the body can't refer to the columns of the query.
+    let mut bind_context = BindContext::new();
+    let mut scalar_binder = ScalarBinder::new(
+        &mut bind_context,
+        binder.ctx.clone(),
+        &binder.name_resolution_ctx,
+        metadata,
+        args,
+    );
+    let (predicate, data_type) = scalar_binder.bind(&expr).await?;
+    if data_type.remove_nullable() != DataType::Boolean {
+        return Err(ErrorCode::SemanticError(format!(
+            "row access policy must return BOOLEAN, but got {}",
+            data_type
+        )));
+    }
+    Ok(predicate)
+}
@@ -30,6 +30,7 @@ use common_ast::ast::Statement;
 use common_ast::ast::TableAlias;
 use common_ast::ast::TableReference;
 use common_ast::ast::TimeTravelPoint;
+use common_ast::parser::parse_expr;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
@@ -74,6 +75,7 @@ use common_storages_view::view_table::QUERY;
 use common_users::UserApiProvider;
 use dashmap::DashMap;
 use parking_lot::RwLock;
+use storages_common_table_meta::table::OPT_KEY_TTL;
 
 use crate::binder::copy::parse_file_location;
 use crate::binder::scalar::ScalarBinder;
@@ -88,6 +90,7 @@ use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
 use crate::plans::CteScan;
+use crate::plans::Filter;
 use crate::plans::Scan;
 use crate::plans::Statistics;
 use crate::BaseTableColumn;
@@ -361,6 +364,20 @@ impl Binder {
                     _ => {
                         let table_name = table_meta.name();
                         let full_table_name = format!("{catalog}.{database}.{table_name}");
+                        let ttl = if bind_context.planning_agg_index
+                            || bind_context.planning_mutation_target
+                        {
+                            None
+                        } else {
+                            table_meta.options().get(OPT_KEY_TTL).cloned()
+                        };
+                        let row_access_policy = if bind_context.planning_agg_index
+                            || bind_context.planning_mutation_target
+                        {
+                            None
+                        } else {
+                            self.resolve_row_access_policy(table_meta.as_ref()).await?
+                        };
                         let table_index = self.metadata.write().add_table(
                             catalog,
                             database.clone(),
@@ -377,9 +394,23 @@ impl Binder {
                                 .add_agg_indexes(full_table_name, agg_indexes);
                         }
 
-                        let (s_expr, mut bind_context) = self
+                        let (mut s_expr, mut bind_context) = self
                             .bind_base_table(bind_context, database.as_str(), table_index)
                             .await?;
+                        if let Some(ttl) = ttl {
+                            s_expr = self
+                                .bind_ttl_filter(&mut bind_context, &ttl, s_expr)
+                                .await?;
+                        }
+                        if let Some(policy) = row_access_policy {
+                            let predicate =
+                                self.bind_row_access_policy(&bind_context, &policy).await?;
+                            let filter = Filter {
+                                predicates: vec![predicate],
+                                is_having: false,
+                            };
+                            s_expr = SExpr::create_unary(Arc::new(filter.into()), Arc::new(s_expr));
+                        }
                         if let Some(alias) = alias {
                             bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                         }
@@ -779,6 +810,7 @@ impl Binder {
             srfs: Default::default(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            planning_mutation_target: false,
             window_definitions: DashMap::new(),
         };
         let (s_expr, mut new_bind_context) = self
@@ -817,6 +849,35 @@ impl Binder {
         Ok((s_expr, new_bind_context))
     }
 
+    /// Rows whose TTL expression falls in the past are expired, they are hidden from queries
+    /// even if the background service has not removed them yet.
+    #[async_backtrace::framed]
+    async fn bind_ttl_filter(
+        &mut self,
+        bind_context: &mut BindContext,
+        ttl: &str,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        let tokens = tokenize_sql(&format!("({ttl}) > now() OR ({ttl}) IS NULL"))?;
+        let expr = parse_expr(&tokens, Dialect::PostgreSQL)?;
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (predicate, _) = scalar_binder.bind(&expr).await?;
+        let filter = Filter {
+            predicates: vec![predicate],
+            is_having: false,
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(child),
+        ))
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn bind_base_table(
         &mut self,
@@ -64,12 +64,18 @@ impl Binder {
             ));
         };
 
+        bind_context.planning_mutation_target = true;
         let (table_expr, mut context) = self.bind_table_reference(bind_context, table).await?;
+        bind_context.planning_mutation_target = false;
 
         let table = self
             .ctx
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
+        let row_access_policy = match self.resolve_row_access_policy(table.as_ref()).await? {
+            Some(policy) => Some(self.bind_row_access_policy(&context, &policy).await?),
+            None => None,
+        };
 
         let mut scalar_binder = ScalarBinder::new(
             &mut context,
@@ -108,7 +114,7 @@ impl Binder {
         }
 
         let (selection, subquery_desc) = self
-            .process_selection(selection, table_expr, &mut scalar_binder)
+            .process_selection(selection, row_access_policy, table_expr, &mut scalar_binder)
             .await?;
 
         let plan = UpdatePlan {
@@ -88,6 +88,9 @@ impl Plan {
             Plan::DropTableClusterKey(drop_table_cluster_key) => {
                 Ok(format!("{:?}", drop_table_cluster_key))
             }
+            Plan::DropTablePartition(drop_table_partition) => {
+                Ok(format!("{:?}", drop_table_partition))
+            }
             Plan::ReclusterTable(recluster_table) => Ok(format!("{:?}", recluster_table)),
             Plan::TruncateTable(truncate_table) => Ok(format!("{:?}", truncate_table)),
             Plan::OptimizeTable(optimize_table) => Ok(format!("{:?}", optimize_table)),
@@ -105,6 +108,8 @@ impl Plan {
             Plan::CreateIndex(index) => Ok(format!("{:?}", index)),
             Plan::DropIndex(index) => Ok(format!("{:?}", index)),
             Plan::RefreshIndex(index) => Ok(format!("{index:?}")),
+            Plan::CreateInvertedIndex(index) => Ok(format!("{index:?}")),
+            Plan::DropInvertedIndex(index) => Ok(format!("{index:?}")),
 
             // Virtual Columns
             Plan::CreateVirtualColumns(create_virtual_columns) => {
@@ -139,6 +144,7 @@ impl Plan {
             // Account
             Plan::GrantRole(grant_role) => Ok(format!("{:?}", grant_role)),
             Plan::GrantPriv(grant_priv) => Ok(format!("{:?}", grant_priv)),
+            Plan::GrantOwnership(grant_ownership) => Ok(format!("{:?}", grant_ownership)),
             Plan::ShowGrants(show_grants) => Ok(format!("{:?}", show_grants)),
             Plan::RevokePriv(revoke_priv) => Ok(format!("{:?}", revoke_priv)),
             Plan::RevokeRole(revoke_role) => Ok(format!("{:?}", revoke_role)),
@@ -178,12 +184,28 @@ impl Plan {
             Plan::DropDatamaskPolicy(p) => Ok(format!("{:?}", p)),
             Plan::DescDatamaskPolicy(p) => Ok(format!("{:?}", p)),
 
+            // row access policy
+            Plan::CreateRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DescRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::AddTableRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropTableRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+
             // network policy
             Plan::CreateNetworkPolicy(p) => Ok(format!("{:?}", p)),
             Plan::AlterNetworkPolicy(p) => Ok(format!("{:?}", p)),
             Plan::DropNetworkPolicy(p) => Ok(format!("{:?}", p)),
             Plan::DescNetworkPolicy(p) => Ok(format!("{:?}", p)),
             Plan::ShowNetworkPolicies(p) => Ok(format!("{:?}", p)),
+            Plan::CreatePasswordPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::AlterPasswordPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropPasswordPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DescPasswordPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::ShowPasswordPolicies(p) => Ok(format!("{:?}", p)),
+            Plan::CreateWorkloadGroup(p) => Ok(format!("{:?}", p)),
+            Plan::AlterWorkloadGroup(p) => Ok(format!("{:?}", p)),
+            Plan::DropWorkloadGroup(p) => Ok(format!("{:?}", p)),
+            Plan::AlterRoleWorkloadGroup(p) => Ok(format!("{:?}", p)),
         }
     }
 }
@@ -229,6 +251,7 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             order_by: None,
             prewhere: None,
             agg_index: None,
+            vector_top_k: None,
             statistics: Default::default(),
         });
         let scan_expr = SExpr::create_leaf(Arc::new(scan));
@@ -62,6 +62,9 @@ pub struct Metadata {
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
     max_column_position: usize, // for CSV
+    /// Mappings from table index to the columns read by its scans after unused columns are
+    /// pruned, before rewrites like folding `COUNT` may remove the scans from the plan.
+    scan_columns: HashMap<IndexType, ColumnSet>,
 }
 
 impl Metadata {
@@ -134,6 +137,17 @@ impl Metadata {
         self.table_row_id_index.insert(table_index, row_id_index);
     }
 
+    pub fn add_scan_columns(&mut self, table_index: IndexType, columns: &ColumnSet) {
+        self.scan_columns
+            .entry(table_index)
+            .or_default()
+            .extend(columns.iter().cloned());
+    }
+
+    pub fn scan_columns(&self, table_index: IndexType) -> Option<&ColumnSet> {
+        self.scan_columns.get(&table_index)
+    }
+
     pub fn row_id_index_by_table_index(&self, table_index: IndexType) -> Option<IndexType> {
         self.table_row_id_index.get(&table_index).copied()
     }
@@ -25,6 +25,7 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::RuleFactory;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
+use crate::plans::RelOperator;
 use crate::BindContext;
 use crate::MetadataRef;
 
@@ -53,6 +54,7 @@ pub static DEFAULT_REWRITE_RULES: Lazy<Vec<RuleID>> = Lazy::new(|| {
         RuleID::PushDownFilterScan,
         RuleID::PushDownPrewhere, /* PushDownPrwhere should be after all rules except PushDownFilterScan */
         RuleID::PushDownSortScan, // PushDownSortScan should be after PushDownPrewhere
+        RuleID::PushDownVectorTopKScan,
     ]
 });
 
@@ -90,7 +92,22 @@ impl<'a> HeuristicOptimizer<'a> {
         // Don't consider lazy columns pruning in pre optimize, because the order of each operator is not determined.
         let pruner = UnusedColumnPruner::new(self.metadata.clone(), false);
         let require_columns: ColumnSet = self.bind_context.column_set();
-        pruner.remove_unused_columns(&s_expr, require_columns)
+        let s_expr = pruner.remove_unused_columns(&s_expr, require_columns)?;
+        self.record_scan_columns(&s_expr);
+        Ok(s_expr)
+    }
+
+    // Keep the columns read by the scans in metadata for checking the column privileges,
+    // the scans may be removed by the rewrite rules.
+    fn record_scan_columns(&self, s_expr: &SExpr) {
+        if let RelOperator::Scan(scan) = s_expr.plan() {
+            self.metadata
+                .write()
+                .add_scan_columns(scan.table_index, &scan.columns);
+        }
+        for child in s_expr.children() {
+            self.record_scan_columns(child);
+        }
     }
 
     fn post_optimize(&self, s_expr: SExpr) -> Result<SExpr> {
@@ -37,6 +37,7 @@ mod rule_push_down_limit_sort;
 mod rule_push_down_limit_union;
 mod rule_push_down_prewhere;
 mod rule_push_down_sort_scan;
+mod rule_push_down_vector_top_k_scan;
 mod rule_split_aggregate;
 mod rule_try_apply_agg_index;
 
@@ -64,5 +65,6 @@ pub use rule_push_down_limit_sort::RulePushDownLimitSort;
 pub use rule_push_down_limit_union::RulePushDownLimitUnion;
 pub use rule_push_down_prewhere::RulePushDownPrewhere;
 pub use rule_push_down_sort_scan::RulePushDownSortScan;
+pub use rule_push_down_vector_top_k_scan::RulePushDownVectorTopKScan;
 pub use rule_split_aggregate::RuleSplitAggregate;
 pub use rule_try_apply_agg_index::RuleTryApplyAggIndex;
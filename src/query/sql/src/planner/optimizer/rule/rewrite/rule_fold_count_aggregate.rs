@@ -104,7 +104,7 @@ impl Rule for RuleFoldCountAggregate {
                             item.scalar = ScalarExpr::ConstantExpr(ConstantExpr {
                                 span: item.scalar.span(),
                                 value: Scalar::Number(NumberScalar::UInt64(
-                                    table_card - card.null_count,
+                                    table_card.saturating_sub(card.null_count),
                                 )),
                             });
                         } else {
@@ -0,0 +1,211 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::types::DataType;
+use common_expression::types::Float32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::ValueType;
+use common_expression::types::F32;
+use common_expression::ConstantFolder;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::ConstantExpr;
+use crate::plans::EvalScalar;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::Scan;
+use crate::plans::Sort;
+use crate::plans::VectorTopK;
+use crate::ColumnEntry;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+/// Input:  Sort(limit)
+///           \
+///          EvalScalar
+///             \
+///            LogicalGet
+///
+/// Output:
+///         Sort(limit)
+///           \
+///          EvalScalar
+///             \
+///            LogicalGet(padding vector_top_k)
+///
+/// If the first sort item is the distance between a vector column and a constant vector,
+/// ordered with the closest ones first, the blocks can be pruned with the vector index.
+pub struct RulePushDownVectorTopKScan {
+    id: RuleID,
+    patterns: Vec<SExpr>,
+    metadata: MetadataRef,
+    func_ctx: FunctionContext,
+}
+
+impl RulePushDownVectorTopKScan {
+    pub fn new(metadata: MetadataRef, func_ctx: FunctionContext) -> Self {
+        Self {
+            id: RuleID::PushDownVectorTopKScan,
+            patterns: vec![SExpr::create_unary(
+                Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Sort,
+                    }
+                    .into(),
+                ),
+                Arc::new(SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::EvalScalar,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_leaf(Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Scan,
+                        }
+                        .into(),
+                    ))),
+                )),
+            )],
+            metadata,
+            func_ctx,
+        }
+    }
+
+    fn find_vector_top_k(
+        &self,
+        sort: &Sort,
+        eval_scalar: &EvalScalar,
+        scan: &Scan,
+    ) -> Result<Option<VectorTopK>> {
+        let (Some(limit), Some(first)) = (sort.limit, sort.items.first()) else {
+            return Ok(None);
+        };
+        // The filtered rows can not be counted in the top-k.
+        if scan.push_down_predicates.is_some() || scan.prewhere.is_some() {
+            return Ok(None);
+        }
+        let Some(item) = eval_scalar.items.iter().find(|item| item.index == first.index) else {
+            return Ok(None);
+        };
+        let ScalarExpr::FunctionCall(func) = &item.scalar else {
+            return Ok(None);
+        };
+        let closest_first_asc = match func.func_name.as_str() {
+            "cosine_distance" | "l2_distance" => true,
+            "inner_product" => false,
+            _ => return Ok(None),
+        };
+        if first.asc != closest_first_asc {
+            return Ok(None);
+        }
+        let (column, constant) = match func.arguments.as_slice() {
+            [
+                ScalarExpr::BoundColumnRef(column),
+                ScalarExpr::ConstantExpr(constant),
+            ]
+            | [
+                ScalarExpr::ConstantExpr(constant),
+                ScalarExpr::BoundColumnRef(column),
+            ] => (column, constant),
+            _ => return Ok(None),
+        };
+        if column.column.table_index != Some(scan.table_index)
+            || !scan.columns.contains(&column.column.index)
+        {
+            return Ok(None);
+        }
+        // Only the source columns are indexed.
+        match self.metadata.read().column(column.column.index) {
+            ColumnEntry::BaseTableColumn(base) => {
+                if base.path_indices.is_some() || base.virtual_computed_expr.is_some() {
+                    return Ok(None);
+                }
+            }
+            _ => return Ok(None),
+        }
+        let Some(query) = self.constant_vector(constant)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(VectorTopK {
+            column: column.column.index,
+            distance: func.func_name.clone(),
+            query,
+            limit,
+            nulls_first: first.nulls_first,
+        }))
+    }
+
+    // Cast the constant to `Array(Float32)`, None if it is not a vector of numbers.
+    fn constant_vector(&self, constant: &ConstantExpr) -> Result<Option<Vec<F32>>> {
+        let expr = ScalarExpr::ConstantExpr(constant.clone()).as_expr()?;
+        let dest_type = DataType::Array(Box::new(DataType::Number(NumberDataType::Float32)));
+        let Ok(expr) = check_cast(None, false, expr, &dest_type, &BUILTIN_FUNCTIONS) else {
+            return Ok(None);
+        };
+        let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        match expr {
+            Expr::Constant {
+                scalar: Scalar::Array(column),
+                ..
+            } => Ok(Float32Type::try_downcast_column(&column).map(|values| values.to_vec())),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl Rule for RulePushDownVectorTopKScan {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let eval_scalar_expr = s_expr.child(0)?;
+        let eval_scalar: EvalScalar = eval_scalar_expr.plan().clone().try_into()?;
+        let mut get: Scan = eval_scalar_expr.child(0)?.plan().clone().try_into()?;
+        if get.vector_top_k.is_some() {
+            return Ok(());
+        }
+        let Some(vector_top_k) = self.find_vector_top_k(&sort, &eval_scalar, &get)? else {
+            return Ok(());
+        };
+        get.vector_top_k = Some(vector_top_k);
+
+        let get = SExpr::create_leaf(Arc::new(RelOperator::Scan(get)));
+        let eval_scalar_expr = eval_scalar_expr.replace_children(vec![Arc::new(get)]);
+        let mut result = s_expr.replace_children(vec![Arc::new(eval_scalar_expr)]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+}
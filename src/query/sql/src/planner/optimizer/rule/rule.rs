@@ -57,6 +57,7 @@ pub enum RuleID {
     PushDownLimitAggregate,
     PushDownLimitScan,
     PushDownSortScan,
+    PushDownVectorTopKScan,
     EliminateEvalScalar,
     EliminateFilter,
     MergeEvalScalar,
@@ -90,6 +91,7 @@ impl Display for RuleID {
             RuleID::PushDownFilterAggregate => write!(f, "PushDownFilterAggregate"),
             RuleID::PushDownLimitScan => write!(f, "PushDownLimitScan"),
             RuleID::PushDownSortScan => write!(f, "PushDownSortScan"),
+            RuleID::PushDownVectorTopKScan => write!(f, "PushDownVectorTopKScan"),
             RuleID::EliminateEvalScalar => write!(f, "EliminateEvalScalar"),
             RuleID::EliminateFilter => write!(f, "EliminateFilter"),
             RuleID::MergeEvalScalar => write!(f, "MergeEvalScalar"),
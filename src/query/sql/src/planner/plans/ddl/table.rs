@@ -24,12 +24,14 @@ use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::Scalar;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::storage::StorageParams;
+use storages_common_table_meta::table::TableConstraints;
 
 use crate::plans::Plan;
 
@@ -50,6 +52,9 @@ pub struct CreateTablePlan {
     pub options: TableOptions,
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
+    pub partition_by: Option<String>,
+    pub ttl: Option<String>,
+    pub constraints: TableConstraints,
     pub as_select: Option<Box<Plan>>,
 }
 
@@ -387,3 +392,23 @@ impl DropTableClusterKeyPlan {
         Arc::new(DataSchema::empty())
     }
 }
+
+/// Drop partition.
+///
+/// A partition only exists while it holds data, so `TRUNCATE PARTITION` is the
+/// same as `DROP PARTITION IF EXISTS`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTablePartitionPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub partition: Scalar,
+    pub if_exists: bool,
+}
+
+impl DropTablePartitionPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_expression::DataBlock;
 use common_expression::DataSchemaRef;
+use common_expression::Scalar;
 use common_expression::TableSchemaRef;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
@@ -56,6 +57,8 @@ pub struct Insert {
     pub table_id: MetaId,
     pub schema: TableSchemaRef,
     pub overwrite: bool,
+    /// The partition replaced by `INSERT OVERWRITE ... PARTITION`.
+    pub overwrite_partition: Option<Scalar>,
     pub source: InsertInputSource,
 }
 
@@ -87,6 +90,7 @@ impl std::fmt::Debug for Insert {
             .field("table_id", &self.table_id)
             .field("schema", &self.schema)
             .field("overwrite", &self.overwrite)
+            .field("overwrite_partition", &self.overwrite_partition)
             .finish()
     }
 }
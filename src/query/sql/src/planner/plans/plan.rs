@@ -26,10 +26,12 @@ use common_expression::DataSchemaRefExt;
 use super::data_mask::CreateDatamaskPolicyPlan;
 use super::CopyIntoTableMode;
 use super::CreateIndexPlan;
+use super::CreateInvertedIndexPlan;
 use super::CreateShareEndpointPlan;
 use super::DescDatamaskPolicyPlan;
 use super::DropDatamaskPolicyPlan;
 use super::DropIndexPlan;
+use super::DropInvertedIndexPlan;
 use super::DropShareEndpointPlan;
 use super::ModifyTableColumnPlan;
 use super::RenameTableColumnPlan;
@@ -51,43 +53,58 @@ use crate::plans::share::ShowGrantTenantsOfSharePlan;
 use crate::plans::share::ShowObjectGrantPrivilegesPlan;
 use crate::plans::share::ShowSharesPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterNetworkPolicyPlan;
+use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::AlterRoleWorkloadGroupPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnsPlan;
+use crate::plans::AlterWorkloadGroupPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CallPlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateNetworkPolicyPlan;
+use crate::plans::CreatePasswordPolicyPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateRowAccessPolicyPlan;
 use crate::plans::CreateStagePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnsPlan;
+use crate::plans::CreateWorkloadGroupPlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescNetworkPolicyPlan;
+use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropCatalogPlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::DropFileFormatPlan;
 use crate::plans::DropNetworkPolicyPlan;
+use crate::plans::DropPasswordPolicyPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropRowAccessPolicyPlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
+use crate::plans::DropTablePartitionPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::DropVirtualColumnsPlan;
+use crate::plans::DropWorkloadGroupPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::GenerateVirtualColumnsPlan;
+use crate::plans::GrantOwnershipPlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::KillPlan;
@@ -108,6 +125,7 @@ use crate::plans::ShowCreateTablePlan;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantsPlan;
 use crate::plans::ShowNetworkPoliciesPlan;
+use crate::plans::ShowPasswordPoliciesPlan;
 use crate::plans::ShowRolesPlan;
 use crate::plans::ShowShareEndpointPlan;
 use crate::plans::TruncateTablePlan;
@@ -178,6 +196,7 @@ pub enum Plan {
     ModifyTableColumn(Box<ModifyTableColumnPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
+    DropTablePartition(Box<DropTablePartitionPlan>),
     ReclusterTable(Box<ReclusterTablePlan>),
     RevertTable(Box<RevertTablePlan>),
     TruncateTable(Box<TruncateTablePlan>),
@@ -203,6 +222,8 @@ pub enum Plan {
     CreateIndex(Box<CreateIndexPlan>),
     DropIndex(Box<DropIndexPlan>),
     RefreshIndex(Box<RefreshIndexPlan>),
+    CreateInvertedIndex(Box<CreateInvertedIndexPlan>),
+    DropInvertedIndex(Box<DropInvertedIndexPlan>),
 
     // Virtual Columns
     CreateVirtualColumns(Box<CreateVirtualColumnsPlan>),
@@ -226,6 +247,7 @@ pub enum Plan {
     DropRole(Box<DropRolePlan>),
     GrantRole(Box<GrantRolePlan>),
     GrantPriv(Box<GrantPrivilegePlan>),
+    GrantOwnership(Box<GrantOwnershipPlan>),
     ShowGrants(Box<ShowGrantsPlan>),
     RevokePriv(Box<RevokePrivilegePlan>),
     RevokeRole(Box<RevokeRolePlan>),
@@ -268,12 +290,32 @@ pub enum Plan {
     DropDatamaskPolicy(Box<DropDatamaskPolicyPlan>),
     DescDatamaskPolicy(Box<DescDatamaskPolicyPlan>),
 
+    // Row access policy
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+    DescRowAccessPolicy(Box<DescRowAccessPolicyPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
+
     // Network policy
     CreateNetworkPolicy(Box<CreateNetworkPolicyPlan>),
     AlterNetworkPolicy(Box<AlterNetworkPolicyPlan>),
     DropNetworkPolicy(Box<DropNetworkPolicyPlan>),
     DescNetworkPolicy(Box<DescNetworkPolicyPlan>),
     ShowNetworkPolicies(Box<ShowNetworkPoliciesPlan>),
+
+    // Password policy
+    CreatePasswordPolicy(Box<CreatePasswordPolicyPlan>),
+    AlterPasswordPolicy(Box<AlterPasswordPolicyPlan>),
+    DropPasswordPolicy(Box<DropPasswordPolicyPlan>),
+    DescPasswordPolicy(Box<DescPasswordPolicyPlan>),
+    ShowPasswordPolicies(Box<ShowPasswordPoliciesPlan>),
+
+    // Workload Group
+    CreateWorkloadGroup(Box<CreateWorkloadGroupPlan>),
+    AlterWorkloadGroup(Box<AlterWorkloadGroupPlan>),
+    DropWorkloadGroup(Box<DropWorkloadGroupPlan>),
+    AlterRoleWorkloadGroup(Box<AlterRoleWorkloadGroupPlan>),
 }
 
 #[derive(Clone, Debug)]
@@ -334,6 +376,7 @@ impl Display for Plan {
             Plan::DropTableColumn(_) => write!(f, "DropTableColumn"),
             Plan::AlterTableClusterKey(_) => write!(f, "AlterTableClusterKey"),
             Plan::DropTableClusterKey(_) => write!(f, "DropTableClusterKey"),
+            Plan::DropTablePartition(_) => write!(f, "DropTablePartition"),
             Plan::ReclusterTable(_) => write!(f, "ReclusterTable"),
             Plan::TruncateTable(_) => write!(f, "TruncateTable"),
             Plan::OptimizeTable(_) => write!(f, "OptimizeTable"),
@@ -347,6 +390,8 @@ impl Display for Plan {
             Plan::CreateIndex(_) => write!(f, "CreateIndex"),
             Plan::DropIndex(_) => write!(f, "DropIndex"),
             Plan::RefreshIndex(_) => write!(f, "RefreshIndex"),
+            Plan::CreateInvertedIndex(_) => write!(f, "CreateInvertedIndex"),
+            Plan::DropInvertedIndex(_) => write!(f, "DropInvertedIndex"),
             Plan::CreateVirtualColumns(_) => write!(f, "CreateVirtualColumns"),
             Plan::AlterVirtualColumns(_) => write!(f, "AlterVirtualColumns"),
             Plan::DropVirtualColumns(_) => write!(f, "DropVirtualColumns"),
@@ -364,6 +409,7 @@ impl Display for Plan {
             Plan::RemoveStage(_) => write!(f, "RemoveStage"),
             Plan::GrantRole(_) => write!(f, "GrantRole"),
             Plan::GrantPriv(_) => write!(f, "GrantPriv"),
+            Plan::GrantOwnership(_) => write!(f, "GrantOwnership"),
             Plan::ShowGrants(_) => write!(f, "ShowGrants"),
             Plan::ShowRoles(_) => write!(f, "ShowRoles"),
             Plan::RevokePriv(_) => write!(f, "RevokePriv"),
@@ -405,6 +451,11 @@ impl Display for Plan {
             Plan::DescDatamaskPolicy(..) => {
                 write!(f, "Desc Data Mask Policy")
             }
+            Plan::CreateRowAccessPolicy(_) => write!(f, "CreateRowAccessPolicy"),
+            Plan::DropRowAccessPolicy(_) => write!(f, "DropRowAccessPolicy"),
+            Plan::DescRowAccessPolicy(_) => write!(f, "DescRowAccessPolicy"),
+            Plan::AddTableRowAccessPolicy(_) => write!(f, "AddTableRowAccessPolicy"),
+            Plan::DropTableRowAccessPolicy(_) => write!(f, "DropTableRowAccessPolicy"),
             Plan::SetOptions(..) => {
                 write!(f, "SetOptions")
             }
@@ -413,6 +464,15 @@ impl Display for Plan {
             Plan::DropNetworkPolicy(_) => write!(f, "DropNetworkPolicy"),
             Plan::DescNetworkPolicy(_) => write!(f, "DescNetworkPolicy"),
             Plan::ShowNetworkPolicies(_) => write!(f, "ShowNetworkPolicies"),
+            Plan::CreatePasswordPolicy(_) => write!(f, "CreatePasswordPolicy"),
+            Plan::AlterPasswordPolicy(_) => write!(f, "AlterPasswordPolicy"),
+            Plan::DropPasswordPolicy(_) => write!(f, "DropPasswordPolicy"),
+            Plan::DescPasswordPolicy(_) => write!(f, "DescPasswordPolicy"),
+            Plan::ShowPasswordPolicies(_) => write!(f, "ShowPasswordPolicies"),
+            Plan::CreateWorkloadGroup(_) => write!(f, "CreateWorkloadGroup"),
+            Plan::AlterWorkloadGroup(_) => write!(f, "AlterWorkloadGroup"),
+            Plan::DropWorkloadGroup(_) => write!(f, "DropWorkloadGroup"),
+            Plan::AlterRoleWorkloadGroup(_) => write!(f, "AlterRoleWorkloadGroup"),
         }
     }
 }
@@ -453,15 +513,26 @@ impl Plan {
             Plan::ShowShareEndpoint(plan) => plan.schema(),
             Plan::DescShare(plan) => plan.schema(),
             Plan::ShowShares(plan) => plan.schema(),
+            Plan::ShowObjectGrantPrivileges(plan) => plan.schema(),
             Plan::ShowGrantTenantsOfShare(plan) => plan.schema(),
             Plan::CreateDatamaskPolicy(plan) => plan.schema(),
             Plan::DropDatamaskPolicy(plan) => plan.schema(),
             Plan::DescDatamaskPolicy(plan) => plan.schema(),
+            Plan::DescRowAccessPolicy(plan) => plan.schema(),
             Plan::CreateNetworkPolicy(plan) => plan.schema(),
             Plan::AlterNetworkPolicy(plan) => plan.schema(),
             Plan::DropNetworkPolicy(plan) => plan.schema(),
             Plan::DescNetworkPolicy(plan) => plan.schema(),
             Plan::ShowNetworkPolicies(plan) => plan.schema(),
+            Plan::CreatePasswordPolicy(plan) => plan.schema(),
+            Plan::AlterPasswordPolicy(plan) => plan.schema(),
+            Plan::DropPasswordPolicy(plan) => plan.schema(),
+            Plan::DescPasswordPolicy(plan) => plan.schema(),
+            Plan::ShowPasswordPolicies(plan) => plan.schema(),
+            Plan::CreateWorkloadGroup(plan) => plan.schema(),
+            Plan::AlterWorkloadGroup(plan) => plan.schema(),
+            Plan::DropWorkloadGroup(plan) => plan.schema(),
+            Plan::AlterRoleWorkloadGroup(plan) => plan.schema(),
             other => {
                 debug_assert!(!other.has_result_set());
                 Arc::new(DataSchema::empty())
@@ -493,8 +564,11 @@ impl Plan {
                 | Plan::VacuumTable(_)
                 | Plan::VacuumDropTable(_)
                 | Plan::DescDatamaskPolicy(_)
+                | Plan::DescRowAccessPolicy(_)
                 | Plan::DescNetworkPolicy(_)
                 | Plan::ShowNetworkPolicies(_)
+                | Plan::DescPasswordPolicy(_)
+                | Plan::ShowPasswordPolicies(_)
         )
     }
 }
@@ -20,6 +20,7 @@ use common_catalog::table::ColumnStatistics;
 use common_catalog::table::TableStatistics;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::F32;
 use common_expression::TableSchemaRef;
 use itertools::Itertools;
 
@@ -77,6 +78,16 @@ impl AggIndexInfo {
     }
 }
 
+/// The top-k of `ORDER BY <distance>(column, query) LIMIT k`, see [`common_catalog::plan::VectorTopK`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VectorTopK {
+    pub column: IndexType,
+    pub distance: String,
+    pub query: Vec<F32>,
+    pub limit: usize,
+    pub nulls_first: bool,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct Statistics {
     // statistics will be ignored in comparison and hashing
@@ -94,6 +105,7 @@ pub struct Scan {
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
     pub agg_index: Option<AggIndexInfo>,
+    pub vector_top_k: Option<VectorTopK>,
 
     pub statistics: Statistics,
 }
@@ -120,6 +132,7 @@ impl Scan {
             },
             prewhere,
             agg_index: self.agg_index.clone(),
+            vector_top_k: self.vector_top_k.clone(),
         }
     }
 
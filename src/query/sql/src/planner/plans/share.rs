@@ -20,6 +20,8 @@ use common_expression::types::DataType;
 use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::OwnershipObject;
 use common_meta_app::share::CreateShareEndpointReq;
 use common_meta_app::share::CreateShareReq;
 use common_meta_app::share::DropShareEndpointReq;
@@ -205,10 +207,14 @@ impl ShowSharesPlan {
     }
 }
 
-// Show object grant privileges.
+// Show the owner and the privileges granted to roles, users and shares on an object.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ShowObjectGrantPrivilegesPlan {
-    pub object: ShareGrantObjectName,
+    pub object: OwnershipObject,
+    /// The name of the object, e.g. `TABLE db.t`.
+    pub object_name: String,
+    /// The object the privileges are granted on, only databases and tables have privileges.
+    pub grant_object: Option<GrantObject>,
 }
 
 impl ShowObjectGrantPrivilegesPlan {
@@ -216,7 +222,8 @@ impl ShowObjectGrantPrivilegesPlan {
         Arc::new(DataSchema::new(vec![
             DataField::new("Granted_on", DataType::String),
             DataField::new("Privilege", DataType::String),
-            DataField::new("Share_name", DataType::String),
+            DataField::new("Granted_to", DataType::String),
+            DataField::new("Grantee_name", DataType::String),
         ]))
     }
 }
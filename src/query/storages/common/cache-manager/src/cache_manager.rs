@@ -18,7 +18,9 @@ use std::sync::Arc;
 use common_base::base::GlobalInstance;
 use common_cache::CountableMeter;
 use common_cache::DefaultHashBuilder;
+use common_cache::EvictionPolicy;
 use common_config::CacheConfig;
+use common_config::CacheEvictionPolicyInnerConfig;
 use common_config::CacheStorageTypeInnerConfig;
 use common_exception::Result;
 use storages_common_cache::InMemoryCacheBuilder;
@@ -69,6 +71,7 @@ impl CacheManager {
                         &real_disk_cache_root,
                         config.table_data_cache_population_queue_size,
                         config.disk_cache_config.max_bytes,
+                        eviction_policy(&config.disk_cache_config.eviction_policy),
                     )?
                 }
             }
@@ -78,6 +81,7 @@ impl CacheManager {
         let table_column_array_cache = Self::new_in_memory_cache(
             config.table_data_deserialized_data_bytes,
             ColumnArrayMeter,
+            eviction_policy(&config.table_data_deserialized_data_eviction_policy),
             "table_data_column_array",
         );
 
@@ -95,26 +99,43 @@ impl CacheManager {
                 table_column_array_cache,
             }));
         } else {
-            let table_snapshot_cache =
-                Self::new_item_cache(config.table_meta_snapshot_count, "table_snapshot");
-            let table_statistic_cache =
-                Self::new_item_cache(config.table_meta_statistic_count, "table_statistics");
+            let table_snapshot_cache = Self::new_item_cache(
+                config.table_meta_snapshot_count,
+                eviction_policy(&config.table_meta_snapshot_eviction_policy),
+                "table_snapshot",
+            );
+            let table_statistic_cache = Self::new_item_cache(
+                config.table_meta_statistic_count,
+                eviction_policy(&config.table_meta_statistic_eviction_policy),
+                "table_statistics",
+            );
             let segment_info_cache = Self::new_in_memory_cache(
                 config.table_meta_segment_bytes,
                 CompactSegmentInfoMeter {},
+                eviction_policy(&config.table_meta_segment_eviction_policy),
                 "segment_info",
             );
-            let bloom_index_filter_cache =
-                Self::new_item_cache(config.table_bloom_index_filter_count, "bloom_index_filter");
+            let bloom_index_filter_cache = Self::new_item_cache(
+                config.table_bloom_index_filter_count,
+                eviction_policy(&config.table_bloom_index_filter_eviction_policy),
+                "bloom_index_filter",
+            );
             let bloom_index_meta_cache = Self::new_item_cache(
                 config.table_bloom_index_meta_count,
+                eviction_policy(&config.table_bloom_index_meta_eviction_policy),
                 "bloom_index_file_meta_data",
             );
-            let prune_partitions_cache =
-                Self::new_item_cache(config.table_prune_partitions_count, "prune_partitions");
+            let prune_partitions_cache = Self::new_item_cache(
+                config.table_prune_partitions_count,
+                eviction_policy(&config.table_prune_partitions_eviction_policy),
+                "prune_partitions",
+            );
 
-            let file_meta_data_cache =
-                Self::new_item_cache(DEFAULT_FILE_META_DATA_CACHE_ITEMS, "parquet_file_meta");
+            let file_meta_data_cache = Self::new_item_cache(
+                DEFAULT_FILE_META_DATA_CACHE_ITEMS,
+                EvictionPolicy::Lru,
+                "parquet_file_meta",
+            );
             GlobalInstance::set(Arc::new(Self {
                 table_snapshot_cache,
                 segment_info_cache,
@@ -174,10 +195,14 @@ impl CacheManager {
     // create cache that meters size by `Count`
     fn new_item_cache<V>(
         capacity: u64,
+        policy: EvictionPolicy,
         name: impl Into<String>,
     ) -> Option<NamedCache<InMemoryItemCacheHolder<V>>> {
         if capacity > 0 {
-            Some(InMemoryCacheBuilder::new_item_cache(capacity).name_with(name.into()))
+            Some(
+                InMemoryCacheBuilder::new_item_cache_with_policy(capacity, policy)
+                    .name_with(name.into()),
+            )
         } else {
             None
         }
@@ -187,6 +212,7 @@ impl CacheManager {
     fn new_in_memory_cache<V, M>(
         capacity: u64,
         meter: M,
+        policy: EvictionPolicy,
         name: &str,
     ) -> Option<NamedCache<InMemoryItemCacheHolder<V, DefaultHashBuilder, M>>>
     where
@@ -194,7 +220,7 @@ impl CacheManager {
     {
         if capacity > 0 {
             Some(
-                InMemoryCacheBuilder::new_in_memory_cache(capacity, meter)
+                InMemoryCacheBuilder::new_in_memory_cache(capacity, meter, policy)
                     .name_with(name.to_owned()),
             )
         } else {
@@ -206,12 +232,14 @@ impl CacheManager {
         path: &PathBuf,
         population_queue_size: u32,
         disk_cache_bytes_size: u64,
+        policy: EvictionPolicy,
     ) -> Result<Option<TableDataCache>> {
         if disk_cache_bytes_size > 0 {
             let cache_holder = TableDataCacheBuilder::new_table_data_disk_cache(
                 path,
                 population_queue_size,
                 disk_cache_bytes_size,
+                policy,
             )?;
             Ok(Some(cache_holder))
         } else {
@@ -219,3 +247,10 @@ impl CacheManager {
         }
     }
 }
+
+fn eviction_policy(config: &CacheEvictionPolicyInnerConfig) -> EvictionPolicy {
+    match config {
+        CacheEvictionPolicyInnerConfig::Lru => EvictionPolicy::Lru,
+        CacheEvictionPolicyInnerConfig::S3Fifo => EvictionPolicy::S3Fifo,
+    }
+}
@@ -14,15 +14,19 @@
 
 use std::hash::BuildHasher;
 use std::hash::Hash;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use common_cache::Count;
 use common_cache::CountableMeter;
 use common_cache::DefaultHashBuilder;
+use common_cache::EvictionPolicy;
 
 use crate::metrics_inc_cache_access_count;
 use crate::metrics_inc_cache_hit_count;
 use crate::metrics_inc_cache_miss_count;
+use crate::InMemoryItemCacheHolder;
 
 // The cache accessor, crate users usually working on this interface while manipulating caches
 pub trait CacheAccessor<K, V, S = DefaultHashBuilder, M = Count>
@@ -50,17 +54,55 @@ where Self: Sized
         NamedCache {
             name: name.into(),
             cache: self,
+            statistics: Arc::new(CacheHitStatistics::default()),
         }
     }
 }
 
 impl<T> Named for T where T: Sized + Clone {}
 
+/// Hit and miss counters of a cache, shared by the clones of the cache
+#[derive(Default)]
+pub struct CacheHitStatistics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheHitStatistics {
+    pub fn hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Ratio of hits to accesses, 0 if the cache has never been accessed.
+    pub fn hit_rate(&self) -> f64 {
+        let hits = self.hits();
+        let accesses = hits + self.misses();
+        if accesses == 0 {
+            0.0
+        } else {
+            hits as f64 / accesses as f64
+        }
+    }
+}
+
 /// A named cache that with embedded metrics logging
 #[derive(Clone)]
 pub struct NamedCache<C> {
     name: String,
     cache: C,
+    statistics: Arc<CacheHitStatistics>,
 }
 
 impl<C> NamedCache<C> {
@@ -68,6 +110,21 @@ impl<C> NamedCache<C> {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    #[inline]
+    pub fn statistics(&self) -> &CacheHitStatistics {
+        &self.statistics
+    }
+}
+
+impl<V, S, M> NamedCache<InMemoryItemCacheHolder<V, S, M>>
+where
+    S: BuildHasher + Clone,
+    M: CountableMeter<String, Arc<V>>,
+{
+    pub fn policy(&self) -> EvictionPolicy {
+        self.cache.read().policy()
+    }
 }
 
 impl<K, V, S, M, C> CacheAccessor<K, V, S, M> for NamedCache<C>
@@ -82,10 +139,12 @@ where
         match self.cache.get(k) {
             None => {
                 metrics_inc_cache_miss_count(1, &self.name);
+                self.statistics.miss();
                 None
             }
             v @ Some(_) => {
                 metrics_inc_cache_hit_count(1, &self.name);
+                self.statistics.hit();
                 v
             }
         }
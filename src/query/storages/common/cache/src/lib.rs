@@ -20,17 +20,19 @@ mod providers;
 mod read;
 
 pub use cache::CacheAccessor;
+pub use cache::CacheHitStatistics;
 pub use cache::Named;
 pub use cache::NamedCache;
+pub use providers::DiskCacheBuilder;
 pub use providers::DiskCacheError;
+pub use providers::DiskCacheHolder;
 pub use providers::DiskCacheKey;
 pub use providers::DiskCacheResult;
 pub use providers::InMemoryBytesCacheHolder;
 pub use providers::InMemoryCacheBuilder;
 pub use providers::InMemoryItemCacheHolder;
 pub use providers::LruDiskCache;
-pub use providers::LruDiskCacheBuilder;
-pub use providers::LruDiskCacheHolder;
+pub use providers::PolicyDiskCache;
 pub use providers::TableDataCache;
 pub use providers::TableDataCacheBuilder;
 pub use providers::TableDataCacheKey;
@@ -21,15 +21,20 @@ use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use common_cache::Cache;
 use common_cache::Count;
 use common_cache::DefaultHashBuilder;
+use common_cache::EvictionPolicy;
 use common_cache::FileSize;
 use common_cache::LruCache;
+use common_cache::PolicyCache;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use filetime::FileTime;
 use log::error;
+use log::info;
 use log::warn;
 use parking_lot::RwLock;
 use siphasher::sip128;
@@ -75,20 +80,50 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
     ///
     /// Existing files in `path` will be stored with their last-modified time from the filesystem
     /// used as the order for the recency of their use. Any files that are individually larger
-    /// than `size` bytes will be removed.
+    /// than `size` bytes will be removed, so will the files which are not laid out by the cache
+    /// (e.g. partially written files left by a crash).
     ///
     /// The cache is not observant of changes to files under `path` from external sources, it
     /// expects to have sole maintenance of the contents.
     pub fn new<T>(path: T, size: u64) -> self::result::Result<Self>
+    where PathBuf: From<T> {
+        Self::with_cache(
+            path,
+            C::with_meter_and_hasher(size, FileSize, DefaultHashBuilder::default()),
+        )
+    }
+
+    /// Create an `DiskCache` that stores files in `path`, and indexed by `cache`.
+    pub fn with_cache<T>(path: T, cache: C) -> self::result::Result<Self>
     where PathBuf: From<T> {
         DiskCache {
-            cache: C::with_meter_and_hasher(size, FileSize, DefaultHashBuilder::default()),
+            cache,
             root: PathBuf::from(path),
         }
         .init()
     }
 }
 
+impl DiskCache<PolicyCache<String, u64, DefaultHashBuilder, FileSize>> {
+    /// Create an `DiskCache` that stores files in `path`, limited to `size` bytes, and evicts
+    /// files by `policy`.
+    pub fn new_with_policy<T>(
+        path: T,
+        size: u64,
+        policy: EvictionPolicy,
+    ) -> self::result::Result<Self>
+    where
+        PathBuf: From<T>,
+    {
+        let cache = PolicyCache::with_policy(policy, size, FileSize, DefaultHashBuilder::default());
+        Self::with_cache(path, cache)
+    }
+
+    pub fn policy(&self) -> EvictionPolicy {
+        self.cache.policy()
+    }
+}
+
 impl<C> DiskCache<C>
 where C: Cache<String, u64, DefaultHashBuilder, FileSize>
 {
@@ -121,16 +156,74 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
         self.root.join(rel_path)
     }
 
-    fn init(self) -> self::result::Result<Self> {
-        // remove dir when init, ignore remove error
-        if let Err(e) = fs::remove_dir_all(&self.root) {
-            warn!("remove disk cache dir {:?} error {}", self.root, e);
-        }
+    fn init(mut self) -> self::result::Result<Self> {
         fs::create_dir_all(&self.root)?;
 
+        // rebuild the index from the files left by the previous process
+        let mut entries = vec![];
+        for prefix_entry in fs::read_dir(&self.root)? {
+            let prefix_entry = prefix_entry?;
+            let prefix_path = prefix_entry.path();
+            let prefix = prefix_entry.file_name();
+            let prefix = match prefix.to_str() {
+                Some(prefix) if prefix_entry.file_type()?.is_dir() && is_cache_prefix(prefix) => {
+                    prefix.to_owned()
+                }
+                _ => {
+                    remove_stale_path(&prefix_path);
+                    continue;
+                }
+            };
+
+            for entry in fs::read_dir(&prefix_path)? {
+                let entry = entry?;
+                match recover_entry(&prefix, &entry) {
+                    Some(recovered) => entries.push(recovered),
+                    None => remove_stale_path(&entry.path()),
+                }
+            }
+        }
+
+        // files touched earlier are less recently used, put them first
+        entries.sort_by_key(|(_, _, modified)| *modified);
+        let num_files = entries.len();
+        for (cache_key, size, _) in entries {
+            let cache_key = DiskCacheKey(cache_key);
+            if !self.can_store(size) {
+                remove_stale_path(&self.abs_path_of_cache_key(&cache_key));
+                continue;
+            }
+            self.evict_until_fit(size);
+            self.cache.put(cache_key.0, size);
+        }
+
+        info!(
+            "disk cache {:?} restored, {} of {} files kept, {} bytes",
+            self.root,
+            self.cache.len(),
+            num_files,
+            self.cache.size()
+        );
         Ok(self)
     }
 
+    /// Evict files by the cache policy, until there is room for `bytes_len` bytes.
+    fn evict_until_fit(&mut self, bytes_len: u64) {
+        while self.cache.size() + bytes_len > self.cache.capacity() {
+            if let Some((rel_path, _)) = self.cache.pop_by_policy() {
+                let cached_item_path = self.abs_path_of_cache_key(&DiskCacheKey(rel_path));
+                fs::remove_file(&cached_item_path).unwrap_or_else(|e| {
+                    error!(
+                        "Error removing file from cache: `{:?}`: {}",
+                        cached_item_path, e
+                    )
+                });
+            } else {
+                break;
+            }
+        }
+    }
+
     /// Returns `true` if the disk cache can store a file of `size` bytes.
     pub fn can_store(&self, size: u64) -> bool {
         size <= self.cache.capacity()
@@ -153,17 +246,7 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
         }
 
         // check eviction
-        while self.cache.size() + bytes_len > self.cache.capacity() {
-            if let Some((rel_path, _)) = self.cache.pop_by_policy() {
-                let cached_item_path = self.abs_path_of_cache_key(&DiskCacheKey(rel_path));
-                fs::remove_file(&cached_item_path).unwrap_or_else(|e| {
-                    error!(
-                        "Error removing file from cache: `{:?}`: {}",
-                        cached_item_path, e
-                    )
-                });
-            }
-        }
+        self.evict_until_fit(bytes_len);
         debug_assert!(self.cache.size() <= self.cache.capacity());
 
         let cache_key = self.cache_key(key.as_ref());
@@ -171,12 +254,21 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
         if let Some(parent_path) = path.parent() {
             fs::create_dir_all(parent_path)?;
         }
-        let mut f = File::create(&path)?;
+        // write to a temp file first, and then rename it, so that a crash in the middle of
+        // writing never leaves a partially written file under the name of a cache key.
+        let tmp_path = path.with_extension(TMP_FILE_EXTENSION);
+        let mut f = File::create(&tmp_path)?;
         let mut bufs = Vec::with_capacity(bytes.len());
         for slick in bytes {
             bufs.push(IoSlice::new(slick));
         }
-        f.write_all_vectored(&mut bufs)?;
+        if let Err(e) = f
+            .write_all_vectored(&mut bufs)
+            .and_then(|_| fs::rename(&tmp_path, &path))
+        {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(e.into());
+        }
         self.cache.put(cache_key.0, bytes_len);
         Ok(())
     }
@@ -211,6 +303,57 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
     }
 }
 
+const TMP_FILE_EXTENSION: &str = "tmp";
+
+// length of the hex encoded SipHash 2-4 128 bit
+const CACHE_KEY_LEN: usize = 32;
+
+fn is_cache_prefix(name: &str) -> bool {
+    name.len() == 3 && name.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Returns the cache key, size and last modified time of a file left by the previous process,
+/// or None if it is not a valid cache file.
+fn recover_entry(prefix: &str, entry: &fs::DirEntry) -> Option<(String, u64, SystemTime)> {
+    let name = entry.file_name().into_string().ok()?;
+    if name.len() != CACHE_KEY_LEN
+        || !name.bytes().all(|b| b.is_ascii_hexdigit())
+        || !name.starts_with(prefix)
+    {
+        return None;
+    }
+    let meta = entry.metadata().ok()?;
+    // the checksum (if any) is verified when the item is read, and removed if it is corrupted
+    if !meta.is_file() || meta.len() == 0 {
+        return None;
+    }
+    let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+    Some((name, meta.len(), modified))
+}
+
+fn remove_stale_path(path: &Path) {
+    warn!("removing stale disk cache item {:?}", path);
+    let r = if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    };
+    if let Err(e) = r {
+        warn!("failed to remove stale disk cache item {:?}: {}", path, e);
+    }
+}
+
+/// Bump the last modified time of a cache file, so that the recency of the items
+/// can be (approximately) restored after restart.
+fn touch(file: &File) {
+    if let Err(e) = filetime::set_file_handle_times(file, None, Some(FileTime::now())) {
+        warn!(
+            "failed to update the modified time of disk cache item: {}",
+            e
+        );
+    }
+}
+
 pub mod result {
     use std::error::Error as StdError;
     use std::fmt;
@@ -258,9 +401,7 @@ pub mod result {
 
 use result::*;
 
-impl CacheAccessor<String, Vec<u8>, common_cache::DefaultHashBuilder, Count>
-    for LruDiskCacheHolder
-{
+impl CacheAccessor<String, Vec<u8>, common_cache::DefaultHashBuilder, Count> for DiskCacheHolder {
     fn get<Q: AsRef<str>>(&self, k: Q) -> Option<Arc<Vec<u8>>> {
         let k = k.as_ref();
         {
@@ -273,6 +414,7 @@ impl CacheAccessor<String, Vec<u8>, common_cache::DefaultHashBuilder, Count>
                 let mut v = vec![];
                 let mut file = File::open(cache_file_path)?;
                 file.read_to_end(&mut v)?;
+                touch(&file);
                 Ok::<_, Box<dyn std::error::Error>>(v)
             };
 
@@ -369,15 +511,17 @@ fn validate_checksum(bytes: &[u8]) -> Result<()> {
 }
 
 pub type LruDiskCache = DiskCache<LruCache<String, u64, DefaultHashBuilder, FileSize>>;
-pub type LruDiskCacheHolder = Arc<RwLock<LruDiskCache>>;
+pub type PolicyDiskCache = DiskCache<PolicyCache<String, u64, DefaultHashBuilder, FileSize>>;
+pub type DiskCacheHolder = Arc<RwLock<PolicyDiskCache>>;
 
-pub struct LruDiskCacheBuilder;
-impl LruDiskCacheBuilder {
+pub struct DiskCacheBuilder;
+impl DiskCacheBuilder {
     pub fn new_disk_cache(
         path: &PathBuf,
         disk_cache_bytes_size: u64,
-    ) -> Result<LruDiskCacheHolder> {
-        let external_cache = DiskCache::new(path, disk_cache_bytes_size)
+        policy: EvictionPolicy,
+    ) -> Result<DiskCacheHolder> {
+        let external_cache = DiskCache::new_with_policy(path, disk_cache_bytes_size, policy)
             .map_err(|e| ErrorCode::StorageOther(format!("create disk cache failed, {e}")))?;
         Ok(Arc::new(RwLock::new(external_cache)))
     }
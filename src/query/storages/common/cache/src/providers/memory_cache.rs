@@ -20,11 +20,12 @@ use common_cache::Cache;
 use common_cache::Count;
 use common_cache::CountableMeter;
 use common_cache::DefaultHashBuilder;
-use common_cache::LruCache;
+use common_cache::EvictionPolicy;
+use common_cache::PolicyCache;
 use parking_lot::RwLock;
 
-pub type ImMemoryCache<V, S, M> = LruCache<String, Arc<V>, S, M>;
-pub type BytesCache = LruCache<String, Arc<Vec<u8>>, DefaultHashBuilder, BytesMeter>;
+pub type ImMemoryCache<V, S, M> = PolicyCache<String, Arc<V>, S, M>;
+pub type BytesCache = PolicyCache<String, Arc<Vec<u8>>, DefaultHashBuilder, BytesMeter>;
 
 pub type InMemoryItemCacheHolder<T, S = DefaultHashBuilder, M = Count> =
     Arc<RwLock<ImMemoryCache<T, S, M>>>;
@@ -32,28 +33,41 @@ pub type InMemoryBytesCacheHolder = Arc<RwLock<BytesCache>>;
 
 pub struct InMemoryCacheBuilder;
 impl InMemoryCacheBuilder {
-    // new cache that cache `V`, and metered by the given `meter`
+    // new cache that cache `V`, metered by the given `meter`, and evicted by `policy`
     pub fn new_in_memory_cache<V, M>(
         capacity: u64,
         meter: M,
+        policy: EvictionPolicy,
     ) -> InMemoryItemCacheHolder<V, DefaultHashBuilder, M>
     where
         M: CountableMeter<String, Arc<V>>,
     {
-        let cache = LruCache::with_meter_and_hasher(capacity, meter, DefaultHashBuilder::new());
+        let cache = PolicyCache::with_policy(policy, capacity, meter, DefaultHashBuilder::new());
         Arc::new(RwLock::new(cache))
     }
 
-    // new cache that caches `V` and meter by counting
+    // new lru cache that caches `V` and meter by counting
     pub fn new_item_cache<V>(capacity: u64) -> InMemoryItemCacheHolder<V> {
-        let cache = LruCache::new(capacity);
+        Self::new_item_cache_with_policy(capacity, EvictionPolicy::Lru)
+    }
+
+    // new cache that caches `V`, meter by counting, and evicted by `policy`
+    pub fn new_item_cache_with_policy<V>(
+        capacity: u64,
+        policy: EvictionPolicy,
+    ) -> InMemoryItemCacheHolder<V> {
+        let cache = PolicyCache::with_policy(policy, capacity, Count, DefaultHashBuilder::new());
         Arc::new(RwLock::new(cache))
     }
 
-    // new cache that cache `Vec<u8>`, and metered by byte size
+    // new lru cache that cache `Vec<u8>`, and metered by byte size
     pub fn new_bytes_cache(capacity: u64) -> InMemoryBytesCacheHolder {
-        let cache =
-            LruCache::with_meter_and_hasher(capacity, BytesMeter, DefaultHashBuilder::new());
+        let cache = PolicyCache::with_policy(
+            EvictionPolicy::Lru,
+            capacity,
+            BytesMeter,
+            DefaultHashBuilder::new(),
+        );
         Arc::new(RwLock::new(cache))
     }
 }
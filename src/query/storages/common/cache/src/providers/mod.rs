@@ -18,10 +18,11 @@ mod table_data_cache;
 pub use disk_cache::result::Error as DiskCacheError;
 pub use disk_cache::result::Result as DiskCacheResult;
 pub use disk_cache::DiskCache;
+pub use disk_cache::DiskCacheBuilder;
+pub use disk_cache::DiskCacheHolder;
 pub use disk_cache::DiskCacheKey;
 pub use disk_cache::LruDiskCache;
-pub use disk_cache::LruDiskCacheBuilder;
-pub use disk_cache::LruDiskCacheHolder;
+pub use disk_cache::PolicyDiskCache;
 pub use memory_cache::BytesCache;
 pub use memory_cache::ImMemoryCache;
 pub use memory_cache::InMemoryBytesCacheHolder;
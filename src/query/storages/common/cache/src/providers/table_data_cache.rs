@@ -18,6 +18,7 @@ use std::thread::JoinHandle;
 
 use common_cache::Count;
 use common_cache::DefaultHashBuilder;
+use common_cache::EvictionPolicy;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use crossbeam_channel::TrySendError;
@@ -29,9 +30,10 @@ use crate::metrics_inc_cache_hit_count;
 use crate::metrics_inc_cache_miss_count;
 use crate::metrics_inc_cache_population_overflow_count;
 use crate::metrics_inc_cache_population_pending_count;
-use crate::providers::LruDiskCacheHolder;
+use crate::providers::DiskCacheHolder;
 use crate::CacheAccessor;
-use crate::LruDiskCacheBuilder;
+use crate::CacheHitStatistics;
+use crate::DiskCacheBuilder;
 
 struct CacheItem {
     key: String,
@@ -64,9 +66,10 @@ impl AsRef<str> for TableDataCacheKey {
 }
 
 #[derive(Clone)]
-pub struct TableDataCache<T = LruDiskCacheHolder> {
+pub struct TableDataCache<T = DiskCacheHolder> {
     external_cache: T,
     population_queue: crossbeam_channel::Sender<CacheItem>,
+    statistics: Arc<CacheHitStatistics>,
     _cache_populator: DiskCachePopulator,
 }
 
@@ -78,27 +81,41 @@ impl TableDataCacheBuilder {
         path: &PathBuf,
         population_queue_size: u32,
         disk_cache_bytes_size: u64,
-    ) -> Result<TableDataCache<LruDiskCacheHolder>> {
-        let disk_cache = LruDiskCacheBuilder::new_disk_cache(path, disk_cache_bytes_size)?;
+        policy: EvictionPolicy,
+    ) -> Result<TableDataCache<DiskCacheHolder>> {
+        let disk_cache = DiskCacheBuilder::new_disk_cache(path, disk_cache_bytes_size, policy)?;
         let (rx, tx) = crossbeam_channel::bounded(population_queue_size as usize);
         let num_population_thread = 1;
         Ok(TableDataCache {
             external_cache: disk_cache.clone(),
             population_queue: rx,
+            statistics: Arc::new(CacheHitStatistics::default()),
             _cache_populator: DiskCachePopulator::new(tx, disk_cache, num_population_thread)?,
         })
     }
 }
 
+impl TableDataCache {
+    pub fn statistics(&self) -> &CacheHitStatistics {
+        &self.statistics
+    }
+
+    pub fn policy(&self) -> EvictionPolicy {
+        self.external_cache.read().policy()
+    }
+}
+
 impl CacheAccessor<String, Vec<u8>, DefaultHashBuilder, Count> for TableDataCache {
     fn get<Q: AsRef<str>>(&self, k: Q) -> Option<Arc<Vec<u8>>> {
         metrics_inc_cache_access_count(1, TABLE_DATA_CACHE_NAME);
         let k = k.as_ref();
         if let Some(item) = self.external_cache.get(k) {
             metrics_inc_cache_hit_count(1, TABLE_DATA_CACHE_NAME);
+            self.statistics.hit();
             Some(item)
         } else {
             metrics_inc_cache_miss_count(1, TABLE_DATA_CACHE_NAME);
+            self.statistics.miss();
             None
         }
     }
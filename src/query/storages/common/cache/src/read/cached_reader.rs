@@ -38,7 +38,7 @@ pub type CacheHolder<V, S, M> = Arc<RwLock<ImMemoryCache<V, S, M>>>;
 impl<V, L, S, M> CachedReader<L, NamedCache<CacheHolder<V, S, M>>>
 where
     L: Loader<V> + Sync,
-    S: BuildHasher,
+    S: BuildHasher + Clone,
     M: CountableMeter<String, Arc<V>>,
 {
     pub fn new(cache: Option<NamedCache<CacheHolder<V, S, M>>>, loader: L) -> Self {
@@ -17,11 +17,17 @@ use std::io;
 use std::io::Read;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Arc;
 
+use common_cache::EvictionPolicy;
+use filetime::FileTime;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache::DiskCacheBuilder;
 use storages_common_cache::DiskCacheError;
 use storages_common_cache::DiskCacheKey;
 use storages_common_cache::DiskCacheResult;
 use storages_common_cache::LruDiskCache as DiskCache;
+use storages_common_cache::PolicyDiskCache;
 use tempfile::TempDir;
 
 struct TestFixture {
@@ -40,6 +46,12 @@ impl InsertSingleSlice for DiskCache {
     }
 }
 
+impl InsertSingleSlice for PolicyDiskCache {
+    fn insert_single_slice(&mut self, key: &str, bytes: &[u8]) -> DiskCacheResult<()> {
+        self.insert_bytes(key, &[bytes])
+    }
+}
+
 fn read_all<R: Read>(r: &mut R) -> io::Result<Vec<u8>> {
     let mut v = vec![];
     r.read_to_end(&mut v)?;
@@ -152,3 +164,141 @@ fn test_evict_until_enough_space() {
     // file3 MUST be keeped
     assert!(c.contains_key("file3"));
 }
+
+fn set_modified(root: &Path, key: &str, unix_seconds: i64) {
+    let path = root.join(PathBuf::from(&DiskCacheKey::from(key)));
+    filetime::set_file_mtime(path, FileTime::from_unix_time(unix_seconds, 0)).unwrap();
+}
+
+#[test]
+fn test_reopen_keeps_items() {
+    let f = TestFixture::new();
+    {
+        let mut c = DiskCache::new(f.tmp(), 25).unwrap();
+        c.insert_single_slice("file1", &[1; 10]).unwrap();
+        c.insert_single_slice("file2", &[2; 10]).unwrap();
+    }
+
+    let mut c = DiskCache::new(f.tmp(), 25).unwrap();
+    assert_eq!(c.len(), 2);
+    assert_eq!(c.size(), 20);
+    assert_eq!(
+        read_all(&mut File::open(c.get_cache_path("file2").unwrap()).unwrap()).unwrap(),
+        vec![2u8; 10]
+    );
+}
+
+#[test]
+fn test_reopen_restores_recency() {
+    let f = TestFixture::new();
+    {
+        let mut c = DiskCache::new(f.tmp(), 30).unwrap();
+        c.insert_single_slice("file1", &[1; 10]).unwrap();
+        c.insert_single_slice("file2", &[2; 10]).unwrap();
+        c.insert_single_slice("file3", &[3; 10]).unwrap();
+    }
+    // file1 is the most recently used one, and file2 the least.
+    set_modified(f.tmp(), "file1", 3000);
+    set_modified(f.tmp(), "file2", 1000);
+    set_modified(f.tmp(), "file3", 2000);
+
+    // reopen with a smaller capacity, the least recently used one should be evicted.
+    let mut c = DiskCache::new(f.tmp(), 25).unwrap();
+    assert_eq!(c.size(), 20);
+    assert!(c.contains_key("file1"));
+    assert!(!c.contains_key("file2"));
+    assert!(c.contains_key("file3"));
+    let evicted_file_path = PathBuf::from(&DiskCacheKey::from("file2"));
+    assert!(!f.tmp().join(evicted_file_path).exists());
+
+    // then file3 is the next one to be evicted.
+    c.insert_single_slice("file4", &[4; 10]).unwrap();
+    assert!(c.contains_key("file1"));
+    assert!(!c.contains_key("file3"));
+}
+
+#[test]
+fn test_reopen_removes_stale_files() {
+    let f = TestFixture::new();
+    {
+        let mut c = DiskCache::new(f.tmp(), 100).unwrap();
+        c.insert_single_slice("file1", &[1; 10]).unwrap();
+        c.insert_single_slice("file2", &[2; 50]).unwrap();
+    }
+
+    // a partially written file, left by a crash
+    let valid_path = f.tmp().join(PathBuf::from(&DiskCacheKey::from("file1")));
+    let partial_path = valid_path.with_extension("tmp");
+    std::fs::write(&partial_path, [3; 5]).unwrap();
+    // an empty file under a cache key
+    let empty_path = f.tmp().join(PathBuf::from(&DiskCacheKey::from("file3")));
+    std::fs::create_dir_all(empty_path.parent().unwrap()).unwrap();
+    File::create(&empty_path).unwrap();
+    // files not laid out by the cache
+    let unknown_path = f.tmp().join("unknown");
+    std::fs::write(&unknown_path, [4; 5]).unwrap();
+
+    // file2 is larger than the new capacity
+    let c = DiskCache::new(f.tmp(), 20).unwrap();
+    assert_eq!(c.len(), 1);
+    assert_eq!(c.size(), 10);
+    assert!(c.contains_key("file1"));
+    assert!(!c.contains_key("file2"));
+    assert!(!c.contains_key("file3"));
+    assert!(valid_path.exists());
+    assert!(!partial_path.exists());
+    assert!(!empty_path.exists());
+    assert!(!unknown_path.exists());
+    let large_path = f.tmp().join(PathBuf::from(&DiskCacheKey::from("file2")));
+    assert!(!large_path.exists());
+}
+
+#[test]
+fn test_reopen_corrupted_item() {
+    let f = TestFixture::new();
+    let path = f.tmp().to_path_buf();
+    {
+        let c = DiskCacheBuilder::new_disk_cache(&path, 100, EvictionPolicy::Lru).unwrap();
+        c.put("file1".to_string(), Arc::new(vec![1; 10]));
+        c.put("file2".to_string(), Arc::new(vec![2; 10]));
+    }
+
+    // truncate file2, as if the machine crashed before the data was flushed
+    let corrupted_path = f.tmp().join(PathBuf::from(&DiskCacheKey::from("file2")));
+    std::fs::write(&corrupted_path, [2; 8]).unwrap();
+
+    let c = DiskCacheBuilder::new_disk_cache(&path, 100, EvictionPolicy::Lru).unwrap();
+    assert_eq!(c.len(), 2);
+    assert_eq!(c.get("file1").unwrap().as_slice(), &[1; 10]);
+    // checksum mismatch, the item should be removed
+    assert!(c.get("file2").is_none());
+    assert!(!c.contains_key("file2"));
+    assert!(!corrupted_path.exists());
+}
+
+#[test]
+fn test_s3fifo_policy() {
+    let f = TestFixture::new();
+    let mut c = PolicyDiskCache::new_with_policy(f.tmp(), 100, EvictionPolicy::S3Fifo).unwrap();
+    assert_eq!(c.policy(), EvictionPolicy::S3Fifo);
+    for i in 0..10 {
+        c.insert_single_slice(&format!("hot{i}"), &[1; 10]).unwrap();
+    }
+    for i in 0..9 {
+        assert!(c.get_cache_path(&format!("hot{i}")).is_some());
+    }
+
+    // a large scan does not evict the files accessed more than once
+    for i in 0..100 {
+        c.insert_single_slice(&format!("scan{i}"), &[2; 10])
+            .unwrap();
+    }
+    assert_eq!(c.size(), 100);
+    for i in 0..9 {
+        let key = format!("hot{i}");
+        assert!(c.contains_key(&key));
+        let path = PathBuf::from(&DiskCacheKey::from(key.as_str()));
+        assert!(f.tmp().join(path).exists());
+    }
+    assert!(!c.contains_key("hot9"));
+}
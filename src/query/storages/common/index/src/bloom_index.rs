@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -29,6 +30,7 @@ use common_expression::types::MapType;
 use common_expression::types::NullableType;
 use common_expression::types::Number;
 use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
 use common_expression::types::UInt64Type;
 use common_expression::types::ValueType;
 use common_expression::BlockEntry;
@@ -37,6 +39,7 @@ use common_expression::ConstantFolder;
 use common_expression::DataBlock;
 use common_expression::Expr;
 use common_expression::FieldIndex;
+use common_expression::FromData;
 use common_expression::FunctionContext;
 use common_expression::Scalar;
 use common_expression::TableDataType;
@@ -54,8 +57,12 @@ use crate::filters::FilterBuilder;
 use crate::filters::V2BloomBlock;
 use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
+use crate::like_pattern_fragments;
 use crate::Index;
 
+/// Number of characters of the grams indexed by the n-gram bloom filters.
+const NGRAM_SIZE: usize = 3;
+
 #[derive(Clone)]
 pub struct BloomIndexMeta {
     pub columns: Vec<(String, SingleColumnMeta)>,
@@ -176,6 +183,7 @@ impl BloomIndex {
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -289,6 +297,51 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        // create n-gram filter per column, the filter contains the digests of
+        // all the distinct n-grams of the column values.
+        for (index, field) in ngram_columns_map.into_iter() {
+            let field_type = &data_blocks_tobe_indexed[0].get_by_offset(index).data_type;
+            if !matches!(field_type.remove_nullable(), DataType::String) {
+                continue;
+            }
+            let mut grams = HashSet::new();
+            for block in data_blocks_tobe_indexed {
+                let value = &block.get_by_offset(index).value;
+                let column = value
+                    .convert_to_full_column(field_type, block.num_rows())
+                    .remove_nullable();
+                if let Column::String(column) = column {
+                    for v in column.iter() {
+                        Self::collect_ngrams(&String::from_utf8_lossy(v), &mut grams);
+                    }
+                }
+            }
+            if grams.is_empty() {
+                continue;
+            }
+
+            let column = StringType::from_data(
+                grams
+                    .into_iter()
+                    .map(|gram| gram.into_bytes())
+                    .collect::<Vec<_>>(),
+            );
+            let digests = Self::calculate_column_digest(
+                &func_ctx,
+                &column,
+                &DataType::String,
+                &DataType::Number(NumberDataType::UInt64),
+            )?;
+            let digests = UInt64Type::try_downcast_column(&digests).unwrap();
+            let mut filter_builder = Xor8Builder::create();
+            filter_builder.add_digests(digests.deref());
+            let filter = filter_builder.build()?;
+
+            let filter_name = Self::build_ngram_filter_column_name(&field);
+            filter_fields.push(TableField::new(&filter_name, TableDataType::String));
+            filters.push(Arc::new(filter));
+        }
+
         if filter_fields.is_empty() {
             return Ok(None);
         }
@@ -349,6 +402,23 @@ impl BloomIndex {
             },
         )?;
 
+        visit_expr_column_like_constant(&mut expr, &mut |span, col_name, pattern, return_type| {
+            let filter_column =
+                &Self::build_ngram_filter_column_name(data_schema.field_with_name(col_name)?);
+
+            // If the column doesn't contain some n-gram of the pattern, we rewrite the expression to `false`.
+            if self.find_ngrams(filter_column, pattern, scalar_map)? == FilterEvalResult::MustFalse
+            {
+                Ok(Some(Expr::Constant {
+                    span,
+                    scalar: Scalar::Boolean(false),
+                    data_type: return_type.clone(),
+                }))
+            } else {
+                Ok(None)
+            }
+        })?;
+
         let (new_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
 
         match new_expr {
@@ -415,6 +485,25 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all columns that match the pattern of `col LIKE <constant>` in the expression,
+    /// returns the n-grams of the literal fragments of the pattern.
+    pub fn find_like_columns(
+        expr: &Expr<String>,
+        fields: &[TableField],
+    ) -> Result<Vec<(TableField, Vec<String>)>> {
+        let mut cols = Vec::new();
+        visit_expr_column_like_constant(&mut expr.clone(), &mut |_, col_name, pattern, _| {
+            if let Some(v) = fields.iter().find(|f| f.name() == col_name) {
+                let grams = Self::pattern_ngrams(pattern);
+                if !grams.is_empty() {
+                    cols.push((v.clone(), grams));
+                }
+            }
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -430,6 +519,51 @@ impl BloomIndex {
         }
     }
 
+    /// The n-gram filter will be stored with field name 'NGram(column_id)'
+    pub fn build_ngram_filter_column_name(field: &TableField) -> String {
+        format!("NGram({})", field.column_id())
+    }
+
+    /// Collect the n-grams of the text, texts shorter than `NGRAM_SIZE` have no n-gram.
+    fn collect_ngrams(text: &str, grams: &mut HashSet<String>) {
+        let chars = text.chars().collect::<Vec<_>>();
+        for window in chars.windows(NGRAM_SIZE) {
+            grams.insert(window.iter().collect());
+        }
+    }
+
+    /// The n-grams that every value matching the LIKE pattern must contain.
+    pub fn pattern_ngrams(pattern: &str) -> Vec<String> {
+        let mut grams = HashSet::new();
+        for fragment in like_pattern_fragments(pattern) {
+            Self::collect_ngrams(&fragment, &mut grams);
+        }
+        grams.into_iter().collect()
+    }
+
+    fn find_ngrams(
+        &self,
+        filter_column: &str,
+        pattern: &str,
+        scalar_map: &HashMap<Scalar, u64>,
+    ) -> Result<FilterEvalResult> {
+        if !self.filter_schema.has_field(filter_column) {
+            // The column doesn't have a n-gram filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        let filter = &self.filters[idx];
+        for gram in Self::pattern_ngrams(pattern) {
+            if let Some(digest) = scalar_map.get(&Scalar::String(gram.into_bytes())) {
+                if !filter.contains_digest(*digest) {
+                    return Ok(FilterEvalResult::MustFalse);
+                }
+            }
+        }
+        Ok(FilterEvalResult::Uncertain)
+    }
+
     fn find(
         &self,
         filter_column: &str,
@@ -476,6 +610,10 @@ impl BloomIndex {
         Xor8Filter::supported_type(&data_type)
     }
 
+    pub fn supported_ngram_type(data_type: &TableDataType) -> bool {
+        matches!(data_type.remove_nullable(), TableDataType::String)
+    }
+
     /// Checks if the average length of a string column exceeds 256 bytes.
     /// If it does, the bloom index for the column will not be established.
     fn check_large_string(column: &Column) -> bool {
@@ -580,6 +718,54 @@ fn visit_expr_column_eq_constant(
     Ok(())
 }
 
+fn visit_expr_column_like_constant(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &str, &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find patterns like `Column LIKE <constant>`
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        if function.signature.name == "like" {
+            if let [
+                Expr::ColumnRef { id, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(pattern),
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                let pattern = String::from_utf8_lossy(pattern).to_string();
+                // If the visitor returns a new expression, then replace with the current expression.
+                if let Some(new_expr) = visitor(*span, id, &pattern, return_type)? {
+                    *expr = new_expr;
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_like_constant(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_like_constant(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
 fn visit_map_column(
     span: Span,
     args: &[Expr<String>],
@@ -0,0 +1,457 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::types::ValueType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FieldIndex;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_functions::scalars::Tokenizer;
+
+use crate::FilterEvalResult;
+
+/// Name of the table index option that holds the tokenizer of an inverted index.
+pub const INVERTED_INDEX_TOKENIZER: &str = "tokenizer";
+
+const TERM_POSTINGS_FORMAT_V1: u8 = 1;
+
+/// The terms of one column in a block, and the rows each term shows up in.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TermPostings {
+    pub tokenizer: Tokenizer,
+    /// Term -> ascending row numbers of the rows containing the term.
+    pub terms: BTreeMap<String, Vec<u32>>,
+}
+
+impl TermPostings {
+    pub fn new(tokenizer: Tokenizer) -> Self {
+        Self {
+            tokenizer,
+            terms: BTreeMap::new(),
+        }
+    }
+
+    pub fn add_text(&mut self, row: u32, text: &str) {
+        for term in self.tokenizer.tokenize(text) {
+            let rows = self.terms.entry(term).or_default();
+            if rows.last() != Some(&row) {
+                rows.push(row);
+            }
+        }
+    }
+
+    pub fn rows(&self, term: &str) -> Option<&[u32]> {
+        self.terms.get(term).map(|rows| rows.as_slice())
+    }
+
+    pub fn contains_term(&self, term: &str) -> bool {
+        self.terms.contains_key(term)
+    }
+
+    /// Returns true if any term contains the (already normalized) fragment.
+    pub fn contains_fragment(&self, fragment: &str) -> bool {
+        self.terms.keys().any(|term| term.contains(fragment))
+    }
+
+    /// Encodes the postings as:
+    /// `format, tokenizer, term count, [term length, term, row count, row deltas...]...`
+    /// where all the integers are LEB128 varints.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.push(TERM_POSTINGS_FORMAT_V1);
+        buf.push(match self.tokenizer {
+            Tokenizer::Standard => 0,
+            Tokenizer::Whitespace => 1,
+        });
+        put_varint(&mut buf, self.terms.len() as u64);
+        for (term, rows) in &self.terms {
+            put_varint(&mut buf, term.len() as u64);
+            buf.extend_from_slice(term.as_bytes());
+            put_varint(&mut buf, rows.len() as u64);
+            let mut prev = 0;
+            for row in rows {
+                put_varint(&mut buf, (row - prev) as u64);
+                prev = *row;
+            }
+        }
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+        let format = get_u8(&mut reader)?;
+        if format != TERM_POSTINGS_FORMAT_V1 {
+            return Err(ErrorCode::StorageOther(format!(
+                "unknown inverted index format {}",
+                format
+            )));
+        }
+        let tokenizer = match get_u8(&mut reader)? {
+            0 => Tokenizer::Standard,
+            1 => Tokenizer::Whitespace,
+            v => {
+                return Err(ErrorCode::StorageOther(format!(
+                    "unknown inverted index tokenizer {}",
+                    v
+                )));
+            }
+        };
+        let num_terms = get_varint(&mut reader)?;
+        let mut terms = BTreeMap::new();
+        for _ in 0..num_terms {
+            let len = get_varint(&mut reader)? as usize;
+            if reader.len() < len {
+                return Err(ErrorCode::StorageOther("inverted index is truncated"));
+            }
+            let (term, rest) = reader.split_at(len);
+            reader = rest;
+            let term = String::from_utf8(term.to_vec())
+                .map_err(|e| ErrorCode::StorageOther(e.to_string()))?;
+            let num_rows = get_varint(&mut reader)? as usize;
+            let mut rows = Vec::with_capacity(num_rows);
+            let mut prev = 0u32;
+            for _ in 0..num_rows {
+                prev += get_varint(&mut reader)? as u32;
+                rows.push(prev);
+            }
+            terms.insert(term, rows);
+        }
+        Ok(Self { tokenizer, terms })
+    }
+}
+
+/// A full-text predicate that can be checked against an [`InvertedIndex`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FullTextPredicate {
+    /// `match(column, query [, tokenizer])`
+    Match {
+        column: String,
+        query: String,
+        tokenizer: Tokenizer,
+    },
+    /// `column LIKE pattern`, with the literal fragments of the pattern.
+    Like {
+        column: String,
+        fragments: Vec<String>,
+    },
+}
+
+/// InvertedIndex holds the term postings of the indexed string columns of a block.
+///
+/// It is stored as a parquet file with a single row, each column of the file is the
+/// serialized [`TermPostings`] of a source column, named by `Inverted(column_id)`.
+pub struct InvertedIndex {
+    /// The schema of the index block.
+    pub index_schema: TableSchemaRef,
+
+    pub postings: Vec<Arc<TermPostings>>,
+}
+
+impl InvertedIndex {
+    /// Version of the index file layout.
+    pub const VERSION: u64 = 1;
+
+    /// Create the index from source data.
+    ///
+    /// All input blocks should belong to a Parquet file, rows are numbered across the blocks.
+    pub fn try_create(
+        data_blocks_tobe_indexed: &[&DataBlock],
+        index_columns: Vec<(FieldIndex, TableField, Tokenizer)>,
+    ) -> Result<Option<Self>> {
+        if data_blocks_tobe_indexed.is_empty() {
+            return Err(ErrorCode::BadArguments("block is empty"));
+        }
+
+        let mut index_fields = vec![];
+        let mut postings = vec![];
+        for (index, field, tokenizer) in index_columns {
+            if !Self::supported_type(field.data_type()) {
+                continue;
+            }
+            let mut column_postings = TermPostings::new(tokenizer);
+            let mut row = 0u32;
+            for block in data_blocks_tobe_indexed {
+                let entry = block.get_by_offset(index);
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, block.num_rows());
+                let validity = match &column {
+                    Column::Nullable(c) => Some(c.validity.clone()),
+                    _ => None,
+                };
+                let strings = StringType::try_downcast_column(&column.remove_nullable())
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "inverted index column {} is not a string column",
+                            field.name()
+                        ))
+                    })?;
+                for (i, text) in strings.iter().enumerate() {
+                    if validity.as_ref().map_or(true, |v| v.get_bit(i)) {
+                        column_postings.add_text(row, &String::from_utf8_lossy(text));
+                    }
+                    row += 1;
+                }
+            }
+            index_fields.push(TableField::new(
+                &Self::build_index_column_name(&field),
+                TableDataType::String,
+            ));
+            postings.push(Arc::new(column_postings));
+        }
+
+        if index_fields.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            index_schema: Arc::new(TableSchema::new(index_fields)),
+            postings,
+        }))
+    }
+
+    /// Load the index from the block read from an index file.
+    pub fn from_index_block(index_schema: TableSchemaRef, block: &DataBlock) -> Result<Self> {
+        let mut postings = Vec::with_capacity(block.num_columns());
+        for entry in block.columns() {
+            let bytes = match &entry.value {
+                Value::Scalar(Scalar::String(bytes)) => bytes.as_slice(),
+                Value::Column(Column::String(column)) if column.len() > 0 => unsafe {
+                    column.index_unchecked(0)
+                },
+                _ => {
+                    return Err(ErrorCode::StorageOther(
+                        "invalid inverted index block, expecting a string value per column",
+                    ));
+                }
+            };
+            postings.push(Arc::new(TermPostings::from_bytes(bytes)?));
+        }
+        Ok(Self {
+            index_schema,
+            postings,
+        })
+    }
+
+    pub fn serialize_to_data_block(&self) -> Result<DataBlock> {
+        let entries = self
+            .postings
+            .iter()
+            .map(|p| {
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Scalar(Scalar::String(p.to_bytes())),
+                )
+            })
+            .collect();
+        Ok(DataBlock::new(entries, 1))
+    }
+
+    pub fn column_postings(&self, field: &TableField) -> Option<&TermPostings> {
+        let name = Self::build_index_column_name(field);
+        let idx = self.index_schema.index_of(&name).ok()?;
+        self.postings.get(idx).map(|p| p.as_ref())
+    }
+
+    /// Apply the full-text predicates, which are conjuncts of the filter.
+    ///
+    /// Returns `MustFalse` if any of them can not be satisfied by the rows of the block.
+    pub fn apply(
+        &self,
+        predicates: &[FullTextPredicate],
+        data_schema: &TableSchema,
+    ) -> Result<FilterEvalResult> {
+        for predicate in predicates {
+            let column = match predicate {
+                FullTextPredicate::Match { column, .. }
+                | FullTextPredicate::Like { column, .. } => column,
+            };
+            let Ok(field) = data_schema.field_with_name(column) else {
+                continue;
+            };
+            let Some(postings) = self.column_postings(field) else {
+                continue;
+            };
+            if !Self::may_match(postings, predicate) {
+                return Ok(FilterEvalResult::MustFalse);
+            }
+        }
+        Ok(FilterEvalResult::Uncertain)
+    }
+
+    fn may_match(postings: &TermPostings, predicate: &FullTextPredicate) -> bool {
+        match predicate {
+            FullTextPredicate::Match {
+                query, tokenizer, ..
+            } => {
+                if postings.tokenizer != *tokenizer {
+                    return true;
+                }
+                let terms = tokenizer.tokenize(query);
+                !terms.is_empty() && terms.iter().all(|t| postings.contains_term(t))
+            }
+            FullTextPredicate::Like { fragments, .. } => {
+                fragments
+                    .iter()
+                    .all(|f| match postings.tokenizer.normalize_fragment(f) {
+                        Some(f) => postings.contains_fragment(&f),
+                        None => true,
+                    })
+            }
+        }
+    }
+
+    /// Find the full-text predicates in the top level conjuncts of the filter.
+    pub fn find_predicates(expr: &Expr<String>) -> Vec<FullTextPredicate> {
+        let mut predicates = vec![];
+        visit_conjuncts(expr, &mut predicates);
+        predicates
+    }
+
+    pub fn build_index_column_name(field: &TableField) -> String {
+        format!("Inverted({})", field.column_id())
+    }
+
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        matches!(data_type.remove_nullable(), TableDataType::String)
+    }
+}
+
+fn visit_conjuncts(expr: &Expr<String>, predicates: &mut Vec<FullTextPredicate>) {
+    let Expr::FunctionCall { function, args, .. } = expr else {
+        return;
+    };
+    match (function.signature.name.as_str(), args.as_slice()) {
+        ("and" | "and_filters", args) => {
+            for arg in args {
+                visit_conjuncts(arg, predicates);
+            }
+        }
+        ("match", [Expr::ColumnRef { id, .. }, query, rest @ ..]) if rest.len() <= 1 => {
+            let Some(query) = constant_string(query) else {
+                return;
+            };
+            let tokenizer = match rest.first() {
+                None => Tokenizer::default(),
+                Some(tokenizer) => match constant_string(tokenizer)
+                    .and_then(|name| Tokenizer::from_name(&name).ok())
+                {
+                    Some(tokenizer) => tokenizer,
+                    None => return,
+                },
+            };
+            predicates.push(FullTextPredicate::Match {
+                column: id.clone(),
+                query,
+                tokenizer,
+            });
+        }
+        ("like", [Expr::ColumnRef { id, .. }, pattern]) => {
+            if let Some(pattern) = constant_string(pattern) {
+                let fragments = like_pattern_fragments(&pattern);
+                if !fragments.is_empty() {
+                    predicates.push(FullTextPredicate::Like {
+                        column: id.clone(),
+                        fragments,
+                    });
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn constant_string(expr: &Expr<String>) -> Option<String> {
+    match expr {
+        Expr::Constant {
+            scalar: Scalar::String(s),
+            ..
+        } => String::from_utf8(s.clone()).ok(),
+        Expr::Cast { expr, .. } => constant_string(expr),
+        _ => None,
+    }
+}
+
+/// Splits a LIKE pattern into the literal fragments between the wildcards.
+pub fn like_pattern_fragments(pattern: &str) -> Vec<String> {
+    let mut fragments = vec![];
+    let mut current = String::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '%' | '_' => {
+                if !current.is_empty() {
+                    fragments.push(std::mem::take(&mut current));
+                }
+            }
+            '\\' => match chars.next() {
+                Some(escaped) => current.push(escaped),
+                None => current.push(c),
+            },
+            _ => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        fragments.push(current);
+    }
+    fragments
+}
+
+fn put_varint(buf: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        buf.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    buf.push(v as u8);
+}
+
+fn get_u8(reader: &mut &[u8]) -> Result<u8> {
+    match reader.split_first() {
+        Some((v, rest)) => {
+            *reader = rest;
+            Ok(*v)
+        }
+        None => Err(ErrorCode::StorageOther("inverted index is truncated")),
+    }
+}
+
+fn get_varint(reader: &mut &[u8]) -> Result<u64> {
+    let mut v = 0u64;
+    let mut shift = 0;
+    loop {
+        let b = get_u8(reader)?;
+        if shift >= 64 {
+            return Err(ErrorCode::StorageOther("inverted index varint overflow"));
+        }
+        v |= ((b & 0x7f) as u64) << shift;
+        if b & 0x80 == 0 {
+            return Ok(v);
+        }
+        shift += 7;
+    }
+}
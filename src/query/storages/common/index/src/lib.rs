@@ -18,12 +18,24 @@
 mod bloom_index;
 pub mod filters;
 mod index;
+mod inverted_index;
 mod page_index;
 mod range_index;
+mod vector_index;
 
 pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
 pub use bloom_index::FilterEvalResult;
 pub use index::Index;
+pub use inverted_index::like_pattern_fragments;
+pub use inverted_index::FullTextPredicate;
+pub use inverted_index::InvertedIndex;
+pub use inverted_index::TermPostings;
+pub use inverted_index::INVERTED_INDEX_TOKENIZER;
 pub use page_index::PageIndex;
 pub use range_index::RangeIndex;
+pub use vector_index::ScoreBound;
+pub use vector_index::VectorCluster;
+pub use vector_index::VectorClusters;
+pub use vector_index::VectorDistance;
+pub use vector_index::VectorIndex;
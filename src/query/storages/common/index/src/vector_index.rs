@@ -0,0 +1,494 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+use common_arrow::arrow::buffer::Buffer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::ArrayType;
+use common_expression::types::DataType;
+use common_expression::types::Float32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::ValueType;
+use common_expression::types::F32;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::FieldIndex;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+
+const VECTOR_CLUSTERS_FORMAT_V1: u8 = 1;
+
+/// Upper limit of the clusters of a column in a block.
+const MAX_CLUSTERS: usize = 16;
+/// Upper limit of the vectors sampled to train the centroids.
+const MAX_TRAINING_VECTORS: usize = 1024;
+const TRAINING_ITERATIONS: usize = 5;
+
+/// Relative slack added to the score bounds, to absorb the rounding errors of f32.
+const BOUND_SLACK: f64 = 1e-5;
+
+/// The distance functions which can be accelerated by a vector index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorDistance {
+    Cosine,
+    L2,
+    InnerProduct,
+}
+
+impl VectorDistance {
+    pub fn from_func_name(name: &str) -> Option<Self> {
+        match name {
+            "cosine_distance" => Some(VectorDistance::Cosine),
+            "l2_distance" => Some(VectorDistance::L2),
+            "inner_product" => Some(VectorDistance::InnerProduct),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the closest vectors come first in ascending order.
+    ///
+    /// The closest vectors have the largest inner product, and the smallest distances.
+    pub fn closest_first_asc(&self) -> bool {
+        !matches!(self, VectorDistance::InnerProduct)
+    }
+}
+
+/// The bounds of the scores of `rows` vectors, the score is the distance
+/// or the negated inner product, so a lower score is always closer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScoreBound {
+    pub lower: f64,
+    pub upper: f64,
+    pub rows: u64,
+}
+
+/// A cluster of vectors, all of them lie in the ball of `radius` around the centroid.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VectorCluster {
+    pub centroid: Vec<f32>,
+    pub radius: f32,
+    pub rows: u64,
+}
+
+/// The clusters of the vectors of one column in a block, an IVF list without postings.
+///
+/// Rows are not addressed by the index, it only bounds the distances between the query
+/// and the vectors of a block, which is enough to skip the blocks out of a top-k.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VectorClusters {
+    pub dimension: u32,
+    /// Number of NULL rows, they are not part of any cluster.
+    pub nulls: u64,
+    pub clusters: Vec<VectorCluster>,
+}
+
+impl VectorClusters {
+    /// Cluster the vectors with k-means, the centroids are trained on a sample of the vectors.
+    ///
+    /// Returns None if the vectors do not share the same dimension.
+    pub fn build(vectors: &[&[f32]], nulls: u64) -> Option<Self> {
+        let dimension = vectors.first().map_or(0, |v| v.len());
+        if vectors.iter().any(|v| v.len() != dimension) {
+            return None;
+        }
+        if vectors.is_empty() || dimension == 0 {
+            return Some(Self {
+                dimension: dimension as u32,
+                nulls,
+                clusters: vec![],
+            });
+        }
+
+        let num_clusters = ((vectors.len() as f64).sqrt() as usize).clamp(1, MAX_CLUSTERS);
+        let step = (vectors.len() + MAX_TRAINING_VECTORS - 1) / MAX_TRAINING_VECTORS;
+        let samples = vectors.iter().step_by(step).copied().collect::<Vec<_>>();
+
+        let mut centroids = (0..num_clusters)
+            .map(|i| samples[i * samples.len() / num_clusters].to_vec())
+            .collect::<Vec<_>>();
+        for _ in 0..TRAINING_ITERATIONS {
+            let mut sums = vec![vec![0f64; dimension]; num_clusters];
+            let mut counts = vec![0usize; num_clusters];
+            for v in &samples {
+                let c = nearest(&centroids, v);
+                counts[c] += 1;
+                for (s, x) in sums[c].iter_mut().zip(v.iter()) {
+                    *s += *x as f64;
+                }
+            }
+            for ((centroid, sum), count) in centroids.iter_mut().zip(sums).zip(counts) {
+                if count > 0 {
+                    for (x, s) in centroid.iter_mut().zip(sum) {
+                        *x = (s / count as f64) as f32;
+                    }
+                }
+            }
+        }
+
+        let mut radius = vec![0f64; num_clusters];
+        let mut rows = vec![0u64; num_clusters];
+        for v in vectors {
+            let c = nearest(&centroids, v);
+            rows[c] += 1;
+            radius[c] = radius[c].max(l2(&centroids[c], v));
+        }
+
+        let clusters = centroids
+            .into_iter()
+            .zip(radius)
+            .zip(rows)
+            .filter(|(_, rows)| *rows > 0)
+            .map(|((centroid, radius), rows)| VectorCluster {
+                centroid,
+                radius: radius as f32,
+                rows,
+            })
+            .collect();
+        Some(Self {
+            dimension: dimension as u32,
+            nulls,
+            clusters,
+        })
+    }
+
+    /// Bounds of the scores of the vectors in each cluster, against the query.
+    ///
+    /// Returns None if the query does not match the dimension of the vectors.
+    pub fn score_bounds(&self, distance: VectorDistance, query: &[f32]) -> Option<Vec<ScoreBound>> {
+        if query.len() != self.dimension as usize && !self.clusters.is_empty() {
+            return None;
+        }
+        let query_norm = norm(query);
+        let bounds = self
+            .clusters
+            .iter()
+            .map(|cluster| {
+                let radius = cluster.radius as f64;
+                let (lower, upper) = match distance {
+                    VectorDistance::L2 => {
+                        // triangle inequality
+                        let d = l2(&cluster.centroid, query);
+                        ((d - radius).max(0.0), d + radius)
+                    }
+                    VectorDistance::InnerProduct => {
+                        // |q·(v - c)| <= |q| * |v - c|
+                        let p = dot(&cluster.centroid, query);
+                        let r = query_norm * radius;
+                        (-(p + r), -(p - r))
+                    }
+                    VectorDistance::Cosine => {
+                        let centroid_norm = norm(&cluster.centroid);
+                        if centroid_norm <= radius || query_norm == 0.0 {
+                            // the ball holds the origin, vectors of any direction may be in it.
+                            (0.0, 2.0)
+                        } else {
+                            // the angle between the centroid and the vectors in the ball
+                            // is at most asin(radius / |c|).
+                            let cos = dot(&cluster.centroid, query) / (centroid_norm * query_norm);
+                            let theta = cos.clamp(-1.0, 1.0).acos();
+                            let alpha = (radius / centroid_norm).asin();
+                            (
+                                1.0 - (theta - alpha).max(0.0).cos(),
+                                1.0 - (theta + alpha).min(PI).cos(),
+                            )
+                        }
+                    }
+                };
+                ScoreBound {
+                    lower: lower - BOUND_SLACK * (1.0 + lower.abs()),
+                    upper: upper + BOUND_SLACK * (1.0 + upper.abs()),
+                    rows: cluster.rows,
+                }
+            })
+            .collect();
+        Some(bounds)
+    }
+
+    /// Encodes the clusters as:
+    /// `format, dimension, nulls, cluster count, [rows, radius, centroid...]...`
+    /// where all the numbers are little endian.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf =
+            Vec::with_capacity(17 + self.clusters.len() * (12 + self.dimension as usize * 4));
+        buf.push(VECTOR_CLUSTERS_FORMAT_V1);
+        buf.extend_from_slice(&self.dimension.to_le_bytes());
+        buf.extend_from_slice(&self.nulls.to_le_bytes());
+        buf.extend_from_slice(&(self.clusters.len() as u32).to_le_bytes());
+        for cluster in &self.clusters {
+            buf.extend_from_slice(&cluster.rows.to_le_bytes());
+            buf.extend_from_slice(&cluster.radius.to_le_bytes());
+            for x in &cluster.centroid {
+                buf.extend_from_slice(&x.to_le_bytes());
+            }
+        }
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+        let format = take::<1>(&mut reader)?[0];
+        if format != VECTOR_CLUSTERS_FORMAT_V1 {
+            return Err(ErrorCode::StorageOther(format!(
+                "unknown vector index format {}",
+                format
+            )));
+        }
+        let dimension = u32::from_le_bytes(take(&mut reader)?);
+        let nulls = u64::from_le_bytes(take(&mut reader)?);
+        let num_clusters = u32::from_le_bytes(take(&mut reader)?);
+        let mut clusters = Vec::with_capacity(num_clusters as usize);
+        for _ in 0..num_clusters {
+            let rows = u64::from_le_bytes(take(&mut reader)?);
+            let radius = f32::from_le_bytes(take(&mut reader)?);
+            let mut centroid = Vec::with_capacity(dimension as usize);
+            for _ in 0..dimension {
+                centroid.push(f32::from_le_bytes(take(&mut reader)?));
+            }
+            clusters.push(VectorCluster {
+                centroid,
+                radius,
+                rows,
+            });
+        }
+        Ok(Self {
+            dimension,
+            nulls,
+            clusters,
+        })
+    }
+}
+
+/// VectorIndex holds the clusters of the indexed vector columns of a block.
+///
+/// It is stored as a parquet file with a single row, each column of the file is the
+/// serialized [`VectorClusters`] of a source column, named by `Vector(column_id)`.
+pub struct VectorIndex {
+    /// The schema of the index block.
+    pub index_schema: TableSchemaRef,
+
+    pub clusters: Vec<Arc<VectorClusters>>,
+}
+
+impl VectorIndex {
+    /// Version of the index file layout.
+    pub const VERSION: u64 = 1;
+
+    /// Create the index from source data.
+    pub fn try_create(
+        data_blocks_tobe_indexed: &[&DataBlock],
+        index_columns: Vec<(FieldIndex, TableField)>,
+    ) -> Result<Option<Self>> {
+        if data_blocks_tobe_indexed.is_empty() {
+            return Err(ErrorCode::BadArguments("block is empty"));
+        }
+
+        let mut index_fields = vec![];
+        let mut clusters = vec![];
+        for (index, field) in index_columns {
+            if !Self::supported_type(field.data_type()) {
+                continue;
+            }
+            let mut buffers: Vec<Buffer<f32>> = vec![];
+            let mut nulls = 0;
+            for block in data_blocks_tobe_indexed {
+                let entry = block.get_by_offset(index);
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, block.num_rows());
+                let validity = match &column {
+                    Column::Nullable(c) => Some(c.validity.clone()),
+                    _ => None,
+                };
+                let arrays =
+                    ArrayType::<Float32Type>::try_downcast_column(&column.remove_nullable())
+                        .ok_or_else(|| {
+                            ErrorCode::Internal(format!(
+                                "vector index column {} is not an array of float32",
+                                field.name()
+                            ))
+                        })?;
+                for (i, array) in arrays.iter().enumerate() {
+                    if validity.as_ref().map_or(true, |v| v.get_bit(i)) {
+                        buffers.push(unsafe {
+                            std::mem::transmute::<Buffer<F32>, Buffer<f32>>(array)
+                        });
+                    } else {
+                        nulls += 1;
+                    }
+                }
+            }
+            let vectors = buffers.iter().map(|b| b.as_slice()).collect::<Vec<_>>();
+            // Vectors of different dimensions can not be compared, leave the column unindexed.
+            let Some(column_clusters) = VectorClusters::build(&vectors, nulls) else {
+                continue;
+            };
+            index_fields.push(TableField::new(
+                &Self::build_index_column_name(&field),
+                TableDataType::String,
+            ));
+            clusters.push(Arc::new(column_clusters));
+        }
+
+        if index_fields.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            index_schema: Arc::new(TableSchema::new(index_fields)),
+            clusters,
+        }))
+    }
+
+    /// Load the index from the block read from an index file.
+    pub fn from_index_block(index_schema: TableSchemaRef, block: &DataBlock) -> Result<Self> {
+        let mut clusters = Vec::with_capacity(block.num_columns());
+        for entry in block.columns() {
+            let bytes = match &entry.value {
+                Value::Scalar(Scalar::String(bytes)) => bytes.as_slice(),
+                Value::Column(Column::String(column)) if column.len() > 0 => unsafe {
+                    column.index_unchecked(0)
+                },
+                _ => {
+                    return Err(ErrorCode::StorageOther(
+                        "invalid vector index block, expecting a string value per column",
+                    ));
+                }
+            };
+            clusters.push(Arc::new(VectorClusters::from_bytes(bytes)?));
+        }
+        Ok(Self {
+            index_schema,
+            clusters,
+        })
+    }
+
+    pub fn serialize_to_data_block(&self) -> Result<DataBlock> {
+        let entries = self
+            .clusters
+            .iter()
+            .map(|c| {
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Scalar(Scalar::String(c.to_bytes())),
+                )
+            })
+            .collect();
+        Ok(DataBlock::new(entries, 1))
+    }
+
+    pub fn column_clusters(&self, field: &TableField) -> Option<&VectorClusters> {
+        let name = Self::build_index_column_name(field);
+        let idx = self.index_schema.index_of(&name).ok()?;
+        self.clusters.get(idx).map(|c| c.as_ref())
+    }
+
+    pub fn build_index_column_name(field: &TableField) -> String {
+        format!("Vector({})", field.column_id())
+    }
+
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        match data_type.remove_nullable() {
+            TableDataType::Array(inner) => matches!(
+                inner.as_ref(),
+                TableDataType::Number(NumberDataType::Float32)
+            ),
+            _ => false,
+        }
+    }
+
+    /// Decide which blocks may hold the `k` rows closest to the query.
+    ///
+    /// `blocks` holds the score bounds of the clusters of each block, None if the block
+    /// has no index. The threshold is the smallest score that at least `k` indexed rows
+    /// are known to be within, blocks whose clusters are all beyond it are pruned.
+    pub fn top_k_blocks(blocks: &[Option<Vec<ScoreBound>>], k: usize) -> Vec<bool> {
+        let mut uppers = blocks
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|b| (b.upper, b.rows))
+            .collect::<Vec<_>>();
+        uppers.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut rows = 0;
+        let mut threshold = None;
+        for (upper, n) in uppers {
+            rows += n;
+            if rows >= k as u64 {
+                threshold = Some(upper);
+                break;
+            }
+        }
+
+        match threshold {
+            // A NaN threshold can not rule out anything.
+            Some(threshold) if !threshold.is_nan() => blocks
+                .iter()
+                .map(|bounds| match bounds {
+                    Some(bounds) => bounds
+                        .iter()
+                        .any(|b| b.lower <= threshold || b.lower.is_nan()),
+                    None => true,
+                })
+                .collect(),
+            _ => vec![true; blocks.len()],
+        }
+    }
+}
+
+fn nearest(centroids: &[Vec<f32>], v: &[f32]) -> usize {
+    let mut best = 0;
+    let mut best_dist = f64::INFINITY;
+    for (i, c) in centroids.iter().enumerate() {
+        let dist = l2(c, v);
+        if dist < best_dist {
+            best = i;
+            best_dist = dist;
+        }
+    }
+    best
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| *x as f64 * *y as f64).sum()
+}
+
+fn norm(a: &[f32]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+fn l2(a: &[f32], b: &[f32]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (*x as f64 - *y as f64).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+fn take<const N: usize>(reader: &mut &[u8]) -> Result<[u8; N]> {
+    if reader.len() < N {
+        return Err(ErrorCode::StorageOther("vector index is truncated"));
+    }
+    let (bytes, rest) = reader.split_at(N);
+    *reader = rest;
+    Ok(bytes.try_into().unwrap())
+}
@@ -118,6 +118,7 @@ fn test_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -251,6 +252,7 @@ fn test_specify_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -291,6 +293,7 @@ fn test_string_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        BTreeMap::new(),
     )?
     .unwrap();
 
@@ -309,6 +312,97 @@ fn test_string_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ngram_bloom_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("1", TableDataType::String),
+    ]));
+
+    let blocks = vec![DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2]),
+        StringType::from_data(vec!["databend", "fuse engine"]),
+    ])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let ngram_columns = bloom_columns_map(schema.clone(), vec![1]);
+    let fields = ngram_columns.values().cloned().collect::<Vec<_>>();
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &blocks_ref,
+        BTreeMap::new(),
+        ngram_columns,
+    )?
+    .unwrap();
+
+    let cases = [
+        ("%base%", FilterEvalResult::Uncertain),
+        ("data%", FilterEvalResult::Uncertain),
+        ("%ine", FilterEvalResult::Uncertain),
+        ("%fuse%eng%", FilterEvalResult::Uncertain),
+        ("%bend_fuse%", FilterEvalResult::Uncertain),
+        // fragments shorter than the n-gram can not be pruned
+        ("%xy%", FilterEvalResult::Uncertain),
+        ("%query%", FilterEvalResult::MustFalse),
+        ("%fuse%xyz%", FilterEvalResult::MustFalse),
+        ("%dATA%", FilterEvalResult::MustFalse),
+    ];
+    for (pattern, expected) in cases {
+        assert_eq!(
+            expected,
+            eval_like_index(&index, "1", &fields, schema.clone(), pattern),
+            "pattern: {pattern}"
+        );
+    }
+
+    Ok(())
+}
+
+fn eval_like_index(
+    index: &BloomIndex,
+    col_name: &str,
+    fields: &[TableField],
+    schema: Arc<TableSchema>,
+    pattern: &str,
+) -> FilterEvalResult {
+    let expr = check_function(
+        None,
+        "like",
+        &[],
+        &[
+            Expr::ColumnRef {
+                span: None,
+                id: col_name.to_string(),
+                data_type: DataType::String,
+                display_name: col_name.to_string(),
+            },
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::String(pattern.as_bytes().to_vec()),
+                data_type: DataType::String,
+            },
+        ],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+
+    let like_query_cols = BloomIndex::find_like_columns(&expr, fields).unwrap();
+
+    let mut scalar_map = HashMap::<Scalar, u64>::new();
+    let func_ctx = FunctionContext::default();
+    for (_, grams) in like_query_cols.into_iter() {
+        for gram in grams {
+            let scalar = Scalar::String(gram.into_bytes());
+            let digest =
+                BloomIndex::calculate_scalar_digest(&func_ctx, &scalar, &DataType::String).unwrap();
+            scalar_map.insert(scalar, digest);
+        }
+    }
+
+    index.apply(expr, &scalar_map, schema).unwrap()
+}
+
 fn eval_index(
     index: &BloomIndex,
     col_name: &str,
@@ -0,0 +1,165 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::scalars::Tokenizer;
+use storages_common_index::like_pattern_fragments;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::FullTextPredicate;
+use storages_common_index::InvertedIndex;
+use storages_common_index::TermPostings;
+
+#[test]
+fn test_tokenizer() -> Result<()> {
+    assert_eq!(
+        Tokenizer::Standard.tokenize("Connection TIMEOUT: retry-after=30s"),
+        vec!["connection", "timeout", "retry", "after", "30s"]
+    );
+    assert_eq!(
+        Tokenizer::Whitespace.tokenize("Connection TIMEOUT: retry-after=30s"),
+        vec!["Connection", "TIMEOUT:", "retry-after=30s"]
+    );
+    assert_eq!(Tokenizer::from_name("Whitespace")?, Tokenizer::Whitespace);
+    assert!(Tokenizer::from_name("ngram").is_err());
+
+    assert_eq!(
+        Tokenizer::Standard.normalize_fragment("TimeOut"),
+        Some("timeout".to_string())
+    );
+    assert_eq!(Tokenizer::Standard.normalize_fragment("time out"), None);
+    assert_eq!(
+        Tokenizer::Whitespace.normalize_fragment("out:"),
+        Some("out:".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+fn test_term_postings_round_trip() -> Result<()> {
+    let mut postings = TermPostings::new(Tokenizer::Whitespace);
+    postings.add_text(0, "a b a");
+    postings.add_text(3, "b c");
+    postings.add_text(300, "c");
+
+    assert_eq!(postings.rows("a"), Some([0].as_slice()));
+    assert_eq!(postings.rows("b"), Some([0, 3].as_slice()));
+    assert_eq!(postings.rows("c"), Some([3, 300].as_slice()));
+
+    let decoded = TermPostings::from_bytes(&postings.to_bytes())?;
+    assert_eq!(decoded, postings);
+
+    assert!(TermPostings::from_bytes(&postings.to_bytes()[..5]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_inverted_index() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("id", TableDataType::String),
+        TableField::new(
+            "msg",
+            TableDataType::Nullable(Box::new(TableDataType::String)),
+        ),
+    ]));
+
+    let blocks = vec![
+        DataBlock::new_from_columns(vec![
+            StringType::from_data(vec!["1", "2"]),
+            StringType::from_opt_data(vec![Some(b"upstream connect timeout".to_vec()), None]),
+        ]),
+        DataBlock::new_from_columns(vec![
+            StringType::from_data(vec!["3"]),
+            StringType::from_opt_data(vec![Some(b"Request served in 3ms".to_vec())]),
+        ]),
+    ];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let index = InvertedIndex::try_create(&blocks_ref, vec![(
+        1,
+        schema.field(1).clone(),
+        Tokenizer::Standard,
+    )])?
+    .unwrap();
+
+    // Round trip through the index block.
+    let index_block = index.serialize_to_data_block()?;
+    let index = InvertedIndex::from_index_block(index.index_schema.clone(), &index_block)?;
+
+    let postings = index.column_postings(schema.field(1)).unwrap();
+    assert_eq!(postings.rows("timeout"), Some([0].as_slice()));
+    assert_eq!(postings.rows("served"), Some([2].as_slice()));
+    assert!(index.column_postings(schema.field(0)).is_none());
+
+    let matches = |query: &str, tokenizer| FullTextPredicate::Match {
+        column: "msg".to_string(),
+        query: query.to_string(),
+        tokenizer,
+    };
+    let like = |pattern: &str| FullTextPredicate::Like {
+        column: "msg".to_string(),
+        fragments: like_pattern_fragments(pattern),
+    };
+
+    let cases = vec![
+        (
+            matches("Connect timeout", Tokenizer::Standard),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            matches("connect refused", Tokenizer::Standard),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            matches("", Tokenizer::Standard),
+            FilterEvalResult::MustFalse,
+        ),
+        // The index can not answer queries with another tokenizer.
+        (
+            matches("refused", Tokenizer::Whitespace),
+            FilterEvalResult::Uncertain,
+        ),
+        (like("%timeout%"), FilterEvalResult::Uncertain),
+        (like("%TimeOut%"), FilterEvalResult::Uncertain),
+        (like("%serv_d%"), FilterEvalResult::Uncertain),
+        (like("%refused%"), FilterEvalResult::MustFalse),
+        // Fragments with separators may span terms.
+        (like("%connect refused%"), FilterEvalResult::Uncertain),
+    ];
+    for (predicate, expected) in cases {
+        assert_eq!(
+            index.apply(&[predicate.clone()], &schema)?,
+            expected,
+            "{:?}",
+            predicate
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_like_pattern_fragments() {
+    assert_eq!(like_pattern_fragments("%timeout%"), vec!["timeout"]);
+    assert_eq!(like_pattern_fragments("a_b%c"), vec!["a", "b", "c"]);
+    assert_eq!(like_pattern_fragments("100\\%%"), vec!["100%"]);
+    assert!(like_pattern_fragments("%%").is_empty());
+}
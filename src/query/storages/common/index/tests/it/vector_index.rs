@@ -0,0 +1,189 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::AnyType;
+use common_expression::types::ArrayColumn;
+use common_expression::types::Float32Type;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use storages_common_index::ScoreBound;
+use storages_common_index::VectorClusters;
+use storages_common_index::VectorDistance;
+use storages_common_index::VectorIndex;
+
+fn vector_column(vectors: &[Vec<f32>]) -> Column {
+    let values = Float32Type::from_data(vectors.iter().flatten().copied().collect::<Vec<_>>());
+    let mut offsets = vec![0u64];
+    for v in vectors {
+        offsets.push(offsets.last().unwrap() + v.len() as u64);
+    }
+    Column::Array(Box::new(ArrayColumn::<AnyType> {
+        values,
+        offsets: offsets.into(),
+    }))
+}
+
+fn vector_field() -> TableField {
+    TableField::new(
+        "emb",
+        TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::Float32))),
+    )
+}
+
+// Points on a grid around the center.
+fn grid(center: (f32, f32), n: usize) -> Vec<Vec<f32>> {
+    let mut vectors = vec![];
+    for i in 0..n {
+        for j in 0..n {
+            vectors.push(vec![
+                center.0 + i as f32 * 0.1 - 0.05 * n as f32,
+                center.1 + j as f32 * 0.1 - 0.05 * n as f32,
+            ]);
+        }
+    }
+    vectors
+}
+
+fn score(distance: VectorDistance, v: &[f32], q: &[f32]) -> f64 {
+    let dot = v.iter().zip(q).map(|(a, b)| (a * b) as f64).sum::<f64>();
+    let norm = |x: &[f32]| x.iter().map(|a| (a * a) as f64).sum::<f64>().sqrt();
+    match distance {
+        VectorDistance::L2 => v
+            .iter()
+            .zip(q)
+            .map(|(a, b)| ((a - b) as f64).powi(2))
+            .sum::<f64>()
+            .sqrt(),
+        VectorDistance::InnerProduct => -dot,
+        VectorDistance::Cosine => 1.0 - dot / (norm(v) * norm(q)),
+    }
+}
+
+#[test]
+fn test_vector_clusters_round_trip() -> Result<()> {
+    let vectors = grid((3.0, -2.0), 10);
+    let slices = vectors.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
+    let clusters = VectorClusters::build(&slices, 3).unwrap();
+    assert_eq!(clusters.dimension, 2);
+    assert_eq!(clusters.nulls, 3);
+    assert_eq!(
+        clusters.clusters.iter().map(|c| c.rows).sum::<u64>(),
+        vectors.len() as u64
+    );
+    assert!(clusters.clusters.len() > 1);
+
+    let decoded = VectorClusters::from_bytes(&clusters.to_bytes())?;
+    assert_eq!(decoded, clusters);
+
+    assert!(VectorClusters::from_bytes(&clusters.to_bytes()[..20]).is_err());
+
+    // vectors of different dimensions are not indexed.
+    assert!(VectorClusters::build(&[&[1.0, 2.0], &[1.0]], 0).is_none());
+    Ok(())
+}
+
+#[test]
+fn test_vector_score_bounds() -> Result<()> {
+    let vectors = grid((3.0, -2.0), 10);
+    let slices = vectors.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
+    let clusters = VectorClusters::build(&slices, 0).unwrap();
+
+    for distance in [
+        VectorDistance::L2,
+        VectorDistance::InnerProduct,
+        VectorDistance::Cosine,
+    ] {
+        for query in [[0.0, 0.0], [1.0, 1.0], [3.0, -2.0], [-5.0, 7.5]] {
+            let Some(bounds) = clusters.score_bounds(distance, &query) else {
+                unreachable!();
+            };
+            let lower = bounds.iter().map(|b| b.lower).fold(f64::MAX, f64::min);
+            let upper = bounds.iter().map(|b| b.upper).fold(f64::MIN, f64::max);
+            for v in &vectors {
+                let s = score(distance, v, &query);
+                if s.is_nan() {
+                    continue;
+                }
+                assert!(lower <= s && s <= upper, "{distance:?} {query:?} {v:?}");
+            }
+        }
+        assert!(clusters.score_bounds(distance, &[1.0]).is_none());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_vector_index_top_k_blocks() -> Result<()> {
+    let near = DataBlock::new_from_columns(vec![vector_column(&grid((1.0, 0.0), 4))]);
+    let far = DataBlock::new_from_columns(vec![vector_column(&grid((-10.0, 0.0), 4))]);
+    let query = [1.0, 0.0];
+
+    let mut blocks = vec![];
+    for block in [&near, &far] {
+        let index = VectorIndex::try_create(&[block], vec![(0, vector_field())])?.unwrap();
+        let block = index.serialize_to_data_block()?;
+        let index = VectorIndex::from_index_block(index.index_schema.clone(), &block)?;
+        let clusters = index.column_clusters(&vector_field()).unwrap();
+        blocks.push(clusters.score_bounds(VectorDistance::L2, &query));
+    }
+    // a block without index.
+    blocks.push(None);
+
+    assert_eq!(VectorIndex::top_k_blocks(&blocks, 10), vec![
+        true, false, true
+    ]);
+    // more rows than the indexed ones, nothing can be pruned.
+    assert_eq!(VectorIndex::top_k_blocks(&blocks, 100), vec![
+        true, true, true
+    ]);
+
+    let overlapping = vec![
+        Some(vec![ScoreBound {
+            lower: 0.0,
+            upper: 2.0,
+            rows: 5,
+        }]),
+        Some(vec![ScoreBound {
+            lower: 1.5,
+            upper: 3.0,
+            rows: 5,
+        }]),
+        Some(vec![ScoreBound {
+            lower: 2.5,
+            upper: 4.0,
+            rows: 5,
+        }]),
+    ];
+    assert_eq!(VectorIndex::top_k_blocks(&overlapping, 5), vec![
+        true, true, false
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_vector_index_supported_type() {
+    assert!(VectorIndex::supported_type(vector_field().data_type()));
+    assert!(VectorIndex::supported_type(&TableDataType::Nullable(
+        Box::new(vector_field().data_type().clone())
+    )));
+    assert!(!VectorIndex::supported_type(&TableDataType::Array(
+        Box::new(TableDataType::Number(NumberDataType::Float64))
+    )));
+    assert!(!VectorIndex::supported_type(&TableDataType::String));
+}
@@ -58,6 +58,12 @@ impl TopNPrunner {
             return Ok(metas);
         }
 
+        // The min/max of a block with deleted rows may come from the deleted rows,
+        // which makes the block look better than it is.
+        if metas.iter().any(|(_, meta)| meta.deleted_row_count > 0) {
+            return Ok(metas);
+        }
+
         let (sort, asc, nulls_first) = &self.sort[0];
         // Currently, we only support topn on single-column sort.
         // TODO: support monadic + multi expression + order by cluster key sort.
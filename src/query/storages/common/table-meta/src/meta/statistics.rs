@@ -61,6 +61,11 @@ pub struct Statistics {
 
     pub col_stats: HashMap<ColumnId, ColumnStatistics>,
     pub cluster_stats: Option<ClusterStatistics>,
+
+    /// number of rows marked as deleted by the deletion vectors, they are not
+    /// counted in `row_count` but still counted in the column statistics.
+    #[serde(default)]
+    pub deleted_row_count: u64,
 }
 
 // conversions from old meta data
@@ -125,6 +130,7 @@ impl Statistics {
             index_size: v0.index_size,
             col_stats,
             cluster_stats: None,
+            deleted_row_count: 0,
         }
     }
 }
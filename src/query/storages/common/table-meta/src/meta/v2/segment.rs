@@ -81,6 +81,27 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// location of inverted index
+    #[serde(default)]
+    pub inverted_index_location: Option<Location>,
+    #[serde(default)]
+    pub inverted_index_size: u64,
+
+    /// location of vector index
+    #[serde(default)]
+    pub vector_index_location: Option<Location>,
+    #[serde(default)]
+    pub vector_index_size: u64,
+
+    /// location of deletion vector, marks the rows of the block that have been deleted
+    #[serde(default)]
+    pub deletion_vector_location: Option<Location>,
+    #[serde(default)]
+    pub deletion_vector_size: u64,
+    /// number of rows marked as deleted by the deletion vector
+    #[serde(default)]
+    pub deleted_row_count: u64,
 }
 
 impl BlockMeta {
@@ -97,6 +118,10 @@ impl BlockMeta {
         bloom_filter_index_size: u64,
         compression: Compression,
         create_on: Option<DateTime<Utc>>,
+        inverted_index_location: Option<Location>,
+        inverted_index_size: u64,
+        vector_index_location: Option<Location>,
+        vector_index_size: u64,
     ) -> Self {
         Self {
             row_count,
@@ -110,6 +135,13 @@ impl BlockMeta {
             bloom_filter_index_size,
             compression,
             create_on,
+            inverted_index_location,
+            inverted_index_size,
+            vector_index_location,
+            vector_index_size,
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
         }
     }
 
@@ -117,6 +149,11 @@ impl BlockMeta {
         self.compression
     }
 
+    /// Number of rows that are still visible, i.e. not marked by the deletion vector.
+    pub fn live_row_count(&self) -> u64 {
+        self.row_count.saturating_sub(self.deleted_row_count)
+    }
+
     /// Get the page size of the block.
     /// - If the format is parquet, its page size is its row count.
     /// - If the format is native, its page size is the row count of each page.
@@ -262,6 +299,13 @@ impl BlockMeta {
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
             create_on: None,
+            inverted_index_location: None,
+            inverted_index_size: 0,
+            vector_index_location: None,
+            vector_index_size: 0,
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
         }
     }
 
@@ -293,6 +337,13 @@ impl BlockMeta {
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
             create_on: None,
+            inverted_index_location: None,
+            inverted_index_size: 0,
+            vector_index_location: None,
+            vector_index_size: 0,
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
         }
     }
 }
@@ -62,6 +62,13 @@ impl From<BlockMeta> for crate::meta::BlockMeta {
             bloom_filter_index_size: value.bloom_filter_index_size,
             compression: value.compression.into(),
             create_on: None,
+            inverted_index_location: None,
+            inverted_index_size: 0,
+            vector_index_location: None,
+            vector_index_size: 0,
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
         }
     }
 }
@@ -13,9 +13,13 @@
 // limitations under the License.
 
 mod table_compression;
+mod table_constraints;
 mod table_keys;
 mod table_prefix;
+mod table_row_access_policy;
 
 pub use table_compression::TableCompression;
+pub use table_constraints::TableConstraints;
 pub use table_keys::*;
 pub use table_prefix::*;
+pub use table_row_access_policy::TableRowAccessPolicy;
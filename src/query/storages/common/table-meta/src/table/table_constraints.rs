@@ -0,0 +1,98 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+
+use crate::table::OPT_KEY_PRIMARY_KEY;
+use crate::table::OPT_KEY_UNIQUE_KEYS;
+
+/// The key constraints declared on a table.
+///
+/// Keys are recorded by column id rather than by name, so renaming a column
+/// keeps the constraint intact. In the table options, a key is a comma
+/// separated list of column ids, and unique keys are separated by `;`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableConstraints {
+    pub primary_key: Option<Vec<ColumnId>>,
+    pub unique_keys: Vec<Vec<ColumnId>>,
+}
+
+impl TableConstraints {
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        let primary_key = options
+            .get(OPT_KEY_PRIMARY_KEY)
+            .map(|v| parse_key(v))
+            .transpose()?;
+        let unique_keys = match options.get(OPT_KEY_UNIQUE_KEYS) {
+            Some(v) => v.split(';').map(parse_key).collect::<Result<Vec<_>>>()?,
+            None => vec![],
+        };
+        Ok(Self {
+            primary_key,
+            unique_keys,
+        })
+    }
+
+    pub fn to_options(&self, options: &mut BTreeMap<String, String>) {
+        if let Some(primary_key) = &self.primary_key {
+            options.insert(OPT_KEY_PRIMARY_KEY.to_owned(), format_key(primary_key));
+        }
+        if !self.unique_keys.is_empty() {
+            let unique_keys = self
+                .unique_keys
+                .iter()
+                .map(|key| format_key(key))
+                .collect::<Vec<_>>()
+                .join(";");
+            options.insert(OPT_KEY_UNIQUE_KEYS.to_owned(), unique_keys);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.primary_key.is_none() && self.unique_keys.is_empty()
+    }
+
+    /// All the keys that must be unique, the primary key comes first.
+    pub fn keys(&self) -> impl Iterator<Item = &[ColumnId]> {
+        self.primary_key
+            .iter()
+            .chain(self.unique_keys.iter())
+            .map(|key| key.as_slice())
+    }
+
+    pub fn references(&self, column_id: ColumnId) -> bool {
+        self.keys().any(|key| key.contains(&column_id))
+    }
+}
+
+fn parse_key(v: &str) -> Result<Vec<ColumnId>> {
+    v.split(',')
+        .map(|id| {
+            id.trim().parse::<ColumnId>().map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!("invalid key constraint '{}'", v))
+            })
+        })
+        .collect()
+}
+
+fn format_key(key: &[ColumnId]) -> String {
+    key.iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -24,6 +24,27 @@ pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_EXTERNAL_LOCATION: &str = "external_location";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
+pub const OPT_KEY_NGRAM_INDEX_COLUMNS: &str = "ngram_index_columns";
+pub const OPT_KEY_VECTOR_INDEX_COLUMNS: &str = "vector_index_columns";
+/// The partition key of the table, which can only be specified by `PARTITION BY` clause.
+pub const OPT_KEY_PARTITION_BY: &str = "partition_by";
+/// The retention expression of the table, which can only be specified by `TTL` clause.
+pub const OPT_KEY_TTL: &str = "ttl";
+/// The column ids of the primary key, which can only be specified by a `PRIMARY KEY` constraint.
+pub const OPT_KEY_PRIMARY_KEY: &str = "primary_key";
+/// The column ids of the unique keys, which can only be specified by `UNIQUE` constraints.
+pub const OPT_KEY_UNIQUE_KEYS: &str = "unique_keys";
+/// The row access policy attached to the table, which can only be set by
+/// `ALTER TABLE ... ADD ROW ACCESS POLICY`.
+pub const OPT_KEY_ROW_ACCESS_POLICY: &str = "row_access_policy";
+/// The column ids bound to the args of the row access policy.
+pub const OPT_KEY_ROW_ACCESS_POLICY_COLUMNS: &str = "row_access_policy_columns";
+/// The identity of the user who created the table, whose storage quota the table counts against.
+pub const OPT_KEY_CREATED_BY: &str = "created_by";
+/// The algorithm the objects of the table are encrypted with, can only be set on creation.
+pub const OPT_KEY_ENCRYPTION: &str = "encryption";
+/// The data key of the encrypted table, wrapped by a master key.
+pub const OPT_KEY_ENCRYPTION_DATA_KEY: &str = "encryption_data_key";
 
 /// Legacy table snapshot location key
 ///
@@ -42,6 +63,14 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(OPT_KEY_TTL);
+    r.insert(OPT_KEY_PRIMARY_KEY);
+    r.insert(OPT_KEY_UNIQUE_KEYS);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY_COLUMNS);
+    r.insert(OPT_KEY_CREATED_BY);
+    r.insert(OPT_KEY_ENCRYPTION_DATA_KEY);
     r
 });
 
@@ -50,6 +79,14 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(OPT_KEY_TTL);
+    r.insert(OPT_KEY_PRIMARY_KEY);
+    r.insert(OPT_KEY_UNIQUE_KEYS);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY_COLUMNS);
+    r.insert(OPT_KEY_CREATED_BY);
+    r.insert(OPT_KEY_ENCRYPTION_DATA_KEY);
     r
 });
 
@@ -0,0 +1,92 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+
+use crate::table::OPT_KEY_ROW_ACCESS_POLICY;
+use crate::table::OPT_KEY_ROW_ACCESS_POLICY_COLUMNS;
+
+/// The row access policy attached to a table.
+///
+/// The i-th arg of the policy is bound to the column `column_ids[i]`. As with key
+/// constraints, columns are recorded by id, so renaming a column keeps the policy intact.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableRowAccessPolicy {
+    pub policy: String,
+    pub column_ids: Vec<ColumnId>,
+}
+
+impl TableRowAccessPolicy {
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Option<Self>> {
+        let Some(policy) = options.get(OPT_KEY_ROW_ACCESS_POLICY) else {
+            return Ok(None);
+        };
+        let columns = options
+            .get(OPT_KEY_ROW_ACCESS_POLICY_COLUMNS)
+            .ok_or_else(|| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "row access policy '{}' is not bound to any column",
+                    policy
+                ))
+            })?;
+        let column_ids = columns
+            .split(',')
+            .map(|id| {
+                id.trim().parse::<ColumnId>().map_err(|_| {
+                    ErrorCode::TableOptionInvalid(format!(
+                        "invalid row access policy columns '{}'",
+                        columns
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(Self {
+            policy: policy.clone(),
+            column_ids,
+        }))
+    }
+
+    /// Fill the options to upsert for attaching the policy.
+    pub fn to_options(&self, options: &mut HashMap<String, Option<String>>) {
+        let column_ids = self
+            .column_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        options.insert(
+            OPT_KEY_ROW_ACCESS_POLICY.to_owned(),
+            Some(self.policy.clone()),
+        );
+        options.insert(
+            OPT_KEY_ROW_ACCESS_POLICY_COLUMNS.to_owned(),
+            Some(column_ids),
+        );
+    }
+
+    /// Fill the options to upsert for detaching the policy.
+    pub fn remove_options(options: &mut HashMap<String, Option<String>>) {
+        options.insert(OPT_KEY_ROW_ACCESS_POLICY.to_owned(), None);
+        options.insert(OPT_KEY_ROW_ACCESS_POLICY_COLUMNS.to_owned(), None);
+    }
+
+    pub fn references(&self, column_id: ColumnId) -> bool {
+        self.column_ids.contains(&column_id)
+    }
+}
@@ -32,6 +32,7 @@ use common_expression::Scalar;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Compression;
+use storages_common_table_meta::meta::Location;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
 pub struct FusePartInfo {
@@ -45,6 +46,8 @@ pub struct FusePartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    /// Location of the deletion vector of the block, if some rows have been deleted.
+    pub deletion_vector: Option<Location>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -78,6 +81,7 @@ impl FusePartInfo {
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        deletion_vector: Option<Location>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -88,6 +92,7 @@ impl FusePartInfo {
             compression,
             sort_min_max,
             block_meta_index,
+            deletion_vector,
         }))
     }
 
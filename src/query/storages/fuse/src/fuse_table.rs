@@ -37,9 +37,12 @@ use common_expression::ColumnId;
 use common_expression::FieldIndex;
 use common_expression::RemoteExpr;
 use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::scalars::Tokenizer;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::TableIndexType;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_sharing::create_share_table_operator;
@@ -47,6 +50,8 @@ use common_sql::parse_exprs;
 use common_sql::BloomIndexColumns;
 use common_storage::init_operator;
 use common_storage::DataOperator;
+use common_storage::EncryptionLayer;
+use common_storage::MasterKeys;
 use common_storage::ShareTableConfig;
 use common_storage::StorageMetrics;
 use common_storage::StorageMetricsLayer;
@@ -54,6 +59,8 @@ use log::error;
 use log::warn;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
+use storages_common_index::InvertedIndex;
+use storages_common_index::INVERTED_INDEX_TOKENIZER;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
 use storages_common_table_meta::meta::SnapshotId;
@@ -65,11 +72,14 @@ use storages_common_table_meta::table::table_storage_prefix;
 use storages_common_table_meta::table::TableCompression;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION_DATA_KEY;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
 use uuid::Uuid;
 
 use crate::io::MetaReaders;
@@ -85,6 +95,7 @@ use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_ENABLE_DELETION_VECTOR;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
@@ -98,6 +109,8 @@ pub struct FuseTable {
     pub(crate) storage_format: FuseStorageFormat,
     pub(crate) table_compression: TableCompression,
     pub(crate) bloom_index_cols: BloomIndexColumns,
+    pub(crate) ngram_index_cols: BloomIndexColumns,
+    pub(crate) vector_index_cols: BloomIndexColumns,
 
     pub(crate) operator: Operator,
     pub(crate) data_metrics: Arc<StorageMetrics>,
@@ -129,6 +142,13 @@ impl FuseTable {
             }
         }?;
 
+        // Objects of the encrypted table are sealed by the data key of the table, the layer
+        // goes first so the metrics count the bytes actually stored.
+        if let Some(wrapped_key) = table_info.options().get(OPT_KEY_ENCRYPTION_DATA_KEY) {
+            let data_key = MasterKeys::instance().unwrap_data_key(wrapped_key)?;
+            operator = operator.layer(EncryptionLayer::new(data_key));
+        }
+
         let data_metrics = Arc::new(StorageMetrics::default());
         operator = operator.layer(StorageMetricsLayer::new(data_metrics.clone()));
 
@@ -150,6 +170,18 @@ impl FuseTable {
             .and_then(|s| s.parse::<BloomIndexColumns>().ok())
             .unwrap_or(BloomIndexColumns::All);
 
+        let ngram_index_cols = table_info
+            .options()
+            .get(OPT_KEY_NGRAM_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::None);
+
+        let vector_index_cols = table_info
+            .options()
+            .get(OPT_KEY_VECTOR_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::None);
+
         let part_prefix = table_info.meta.part_prefix.clone();
 
         let meta_location_generator =
@@ -160,6 +192,8 @@ impl FuseTable {
             meta_location_generator,
             cluster_key_meta,
             bloom_index_cols,
+            ngram_index_cols,
+            vector_index_cols,
             operator,
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
@@ -346,6 +380,18 @@ impl FuseTable {
         self.table_info.meta.options.contains_key("TRANSIENT")
     }
 
+    /// Whether the objects of the table are encrypted by a data key.
+    pub fn is_encrypted(&self) -> bool {
+        self.table_info
+            .options()
+            .contains_key(OPT_KEY_ENCRYPTION_DATA_KEY)
+    }
+
+    /// Whether DELETE marks the removed rows in deletion vectors instead of rewriting blocks.
+    pub fn deletion_vector_enabled(&self) -> bool {
+        !self.is_native() && self.get_option(FUSE_OPT_KEY_ENABLE_DELETION_VECTOR, false)
+    }
+
     pub fn cluster_key_str(&self) -> Option<&String> {
         self.cluster_key_meta.as_ref().map(|(_, key)| key)
     }
@@ -357,6 +403,45 @@ impl FuseTable {
     pub fn bloom_index_cols(&self) -> BloomIndexColumns {
         self.bloom_index_cols.clone()
     }
+
+    pub fn ngram_index_cols(&self) -> BloomIndexColumns {
+        self.ngram_index_cols.clone()
+    }
+
+    pub fn vector_index_cols(&self) -> BloomIndexColumns {
+        self.vector_index_cols.clone()
+    }
+
+    /// Get the fields and tokenizers of the columns covered by the inverted indexes,
+    /// field index is the position in `source_schema`.
+    ///
+    /// Columns that have been dropped are skipped.
+    pub fn inverted_index_fields(
+        &self,
+        source_schema: &TableSchema,
+    ) -> Result<Vec<(FieldIndex, TableField, Tokenizer)>> {
+        let mut fields = Vec::new();
+        for index in self.table_info.meta.indexes.values() {
+            if index.index_type != TableIndexType::Inverted {
+                continue;
+            }
+            let tokenizer = match index.options.get(INVERTED_INDEX_TOKENIZER) {
+                Some(name) => Tokenizer::from_name(name)?,
+                None => Tokenizer::default(),
+            };
+            for column_id in &index.column_ids {
+                let position = source_schema.fields().iter().position(|f| {
+                    f.column_id() == *column_id && InvertedIndex::supported_type(f.data_type())
+                });
+                if let Some(i) = position {
+                    if fields.iter().all(|(j, _, _)| *j != i) {
+                        fields.push((i, source_schema.field(i).clone(), tokenizer));
+                    }
+                }
+            }
+        }
+        Ok(fields)
+    }
 }
 
 #[async_trait::async_trait]
@@ -623,6 +708,7 @@ impl Table for FuseTable {
                 FuseTableColumnStatisticsProvider {
                     column_stats: stats.clone(),
                     row_count: snapshot.summary.row_count,
+                    deleted_row_count: snapshot.summary.deleted_row_count,
                     // save row count first
                     column_distinct_values: Some(table_statistics.column_distinct_values.clone()),
                 }
@@ -630,6 +716,7 @@ impl Table for FuseTable {
                 FuseTableColumnStatisticsProvider {
                     column_stats: stats.clone(),
                     row_count: snapshot.summary.row_count,
+                    deleted_row_count: snapshot.summary.deleted_row_count,
                     column_distinct_values: None,
                 }
             }
@@ -773,11 +860,17 @@ struct FuseTableColumnStatisticsProvider {
     column_stats: HashMap<ColumnId, FuseColumnStatistics>,
     pub column_distinct_values: Option<HashMap<ColumnId, u64>>,
     pub row_count: u64,
+    pub deleted_row_count: u64,
 }
 
 impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
     fn column_statistics(&self, column_id: ColumnId) -> Option<ColumnStatistics> {
         let col_stats = &self.column_stats.get(&column_id);
+        // The null count also covers the rows marked in the deletion vectors, the number
+        // of visible nulls is unknown, e.g. `count(col)` can not be derived from it.
+        if self.deleted_row_count > 0 && col_stats.map_or(false, |s| s.null_count > 0) {
+            return None;
+        }
         col_stats.map(|s| {
             let mut ndv = self
                 .column_distinct_values
@@ -0,0 +1,138 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use opendal::Operator;
+use storages_common_table_meta::meta::Location;
+
+/// Format version of the deletion vector file.
+pub const DELETION_VECTOR_VERSION: u64 = 0;
+
+const KIND_POSITIONS: u8 = 0;
+const KIND_BITMAP: u8 = 1;
+const HEADER_SIZE: usize = 9;
+
+/// Serializes the deleted rows of a block, a set bit marks a deleted row.
+///
+/// Layout: `kind: u8 | num_rows: u64 (LE) | body`. The body is either the deleted
+/// row positions as `u32 (LE)`, or the packed bitmap, whichever is smaller.
+pub fn encode_deletion_vector(deleted: &Bitmap) -> Vec<u8> {
+    let num_rows = deleted.len();
+    let num_deleted = num_rows - deleted.unset_bits();
+    let positions_size = num_deleted * 4;
+    let bitmap_size = (num_rows + 7) / 8;
+
+    let mut buf = Vec::with_capacity(HEADER_SIZE + positions_size.min(bitmap_size));
+    if positions_size <= bitmap_size {
+        buf.push(KIND_POSITIONS);
+        buf.extend_from_slice(&(num_rows as u64).to_le_bytes());
+        for (pos, is_deleted) in deleted.iter().enumerate() {
+            if is_deleted {
+                buf.extend_from_slice(&(pos as u32).to_le_bytes());
+            }
+        }
+    } else {
+        buf.push(KIND_BITMAP);
+        buf.extend_from_slice(&(num_rows as u64).to_le_bytes());
+        let packed = MutableBitmap::from_iter(deleted.iter());
+        buf.extend_from_slice(packed.as_slice());
+    }
+    buf
+}
+
+pub fn decode_deletion_vector(bytes: &[u8]) -> Result<Bitmap> {
+    if bytes.len() < HEADER_SIZE {
+        return Err(ErrorCode::StorageOther("deletion vector is truncated"));
+    }
+    let num_rows = u64::from_le_bytes(bytes[1..HEADER_SIZE].try_into().unwrap()) as usize;
+    let body = &bytes[HEADER_SIZE..];
+    match bytes[0] {
+        KIND_POSITIONS => {
+            if body.len() % 4 != 0 {
+                return Err(ErrorCode::StorageOther("deletion vector is truncated"));
+            }
+            let mut deleted = MutableBitmap::from_len_zeroed(num_rows);
+            for chunk in body.chunks_exact(4) {
+                let pos = u32::from_le_bytes(chunk.try_into().unwrap()) as usize;
+                if pos >= num_rows {
+                    return Err(ErrorCode::StorageOther(format!(
+                        "deletion vector position {} out of range {}",
+                        pos, num_rows
+                    )));
+                }
+                deleted.set(pos, true);
+            }
+            Ok(deleted.into())
+        }
+        KIND_BITMAP => {
+            if body.len() * 8 < num_rows {
+                return Err(ErrorCode::StorageOther("deletion vector is truncated"));
+            }
+            Bitmap::try_new(body.to_vec(), num_rows).map_err(ErrorCode::from)
+        }
+        kind => Err(ErrorCode::StorageOther(format!(
+            "unknown deletion vector kind {}",
+            kind
+        ))),
+    }
+}
+
+fn check_version(location: &Location) -> Result<()> {
+    if location.1 != DELETION_VECTOR_VERSION {
+        return Err(ErrorCode::StorageOther(format!(
+            "unsupported deletion vector version {}",
+            location.1
+        )));
+    }
+    Ok(())
+}
+
+/// Loads the deleted rows of a block.
+#[async_backtrace::framed]
+pub async fn read_deletion_vector(dal: &Operator, location: &Location) -> Result<Bitmap> {
+    check_version(location)?;
+    let data = dal.read(&location.0).await?;
+    decode_deletion_vector(&data)
+}
+
+pub fn read_deletion_vector_sync(dal: &Operator, location: &Location) -> Result<Bitmap> {
+    check_version(location)?;
+    let data = dal.blocking().read(&location.0)?;
+    decode_deletion_vector(&data)
+}
+
+/// Writes the deleted rows of a block, returns the size of the written file.
+#[async_backtrace::framed]
+pub async fn write_deletion_vector(
+    dal: &Operator,
+    location: &Location,
+    deleted: &Bitmap,
+) -> Result<u64> {
+    let data = encode_deletion_vector(deleted);
+    let size = data.len() as u64;
+    dal.write(&location.0, data).await?;
+    Ok(size)
+}
+
+/// Physical offsets of the live rows.
+pub fn live_row_offsets(deleted: &Bitmap) -> Vec<usize> {
+    deleted
+        .iter()
+        .enumerate()
+        .filter_map(|(pos, is_deleted)| (!is_deleted).then_some(pos))
+        .collect()
+}
@@ -29,8 +29,16 @@ use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
+use crate::index::InvertedIndex;
+use crate::index::VectorIndex;
+use crate::io::DELETION_VECTOR_VERSION;
+use crate::io::SEGMENT_KEY_INDEX_VERSION;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_DELETION_VECTOR_PREFIX;
+use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_SEGMENT_KEY_INDEX_PREFIX;
+use crate::FUSE_TBL_VECTOR_INDEX_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
@@ -96,14 +104,100 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn block_inverted_index_location(&self, block_id: &Uuid) -> Location {
+        (
+            format!(
+                "{}/{}/{}_v{}.parquet",
+                &self.prefix,
+                FUSE_TBL_INVERTED_INDEX_PREFIX,
+                block_id.as_simple(),
+                InvertedIndex::VERSION,
+            ),
+            InvertedIndex::VERSION,
+        )
+    }
+
+    pub fn block_vector_index_location(&self, block_id: &Uuid) -> Location {
+        (
+            format!(
+                "{}/{}/{}_v{}.parquet",
+                &self.prefix,
+                FUSE_TBL_VECTOR_INDEX_PREFIX,
+                block_id.as_simple(),
+                VectorIndex::VERSION,
+            ),
+            VectorIndex::VERSION,
+        )
+    }
+
+    pub fn gen_deletion_vector_location(&self) -> Location {
+        (
+            format!(
+                "{}/{}/{}_v{}.bin",
+                &self.prefix,
+                FUSE_TBL_DELETION_VECTOR_PREFIX,
+                Uuid::new_v4().as_simple(),
+                DELETION_VECTOR_VERSION,
+            ),
+            DELETION_VECTOR_VERSION,
+        )
+    }
+
     pub fn gen_segment_info_location(&self) -> String {
+        self.gen_segment_info_location_of_partition(None)
+    }
+
+    /// Generate the location of a segment that only holds blocks of the given partition.
+    /// The encoded partition value is kept in the file name, so that segments can be
+    /// pruned or dropped by partition without being read.
+    pub fn gen_segment_info_location_of_partition(&self, partition: Option<&str>) -> String {
         let segment_uuid = Uuid::new_v4().simple().to_string();
-        format!(
-            "{}/{}/{}_v{}.mpk",
-            &self.prefix,
-            FUSE_TBL_SEGMENT_PREFIX,
-            segment_uuid,
-            SegmentInfo::VERSION,
+        match partition {
+            Some(partition) => format!(
+                "{}/{}/{}_p{}_v{}.mpk",
+                &self.prefix,
+                FUSE_TBL_SEGMENT_PREFIX,
+                segment_uuid,
+                partition,
+                SegmentInfo::VERSION,
+            ),
+            None => format!(
+                "{}/{}/{}_v{}.mpk",
+                &self.prefix,
+                FUSE_TBL_SEGMENT_PREFIX,
+                segment_uuid,
+                SegmentInfo::VERSION,
+            ),
+        }
+    }
+
+    /// Returns the encoded partition value of the segment, if any.
+    pub fn segment_partition(location: &str) -> Option<&str> {
+        let file_name = location.rsplit('/').next()?;
+        let (_, rest) = file_name.split_once("_p")?;
+        let (partition, _) = rest.rsplit_once("_v")?;
+        Some(partition)
+    }
+
+    /// Reorder the segments so that the segments of the same partition are adjacent,
+    /// the relative order of the segments within a partition is kept.
+    pub fn sort_segments_by_partition(segments: &mut [Location]) {
+        segments.sort_by(|a, b| Self::segment_partition(&a.0).cmp(&Self::segment_partition(&b.0)));
+    }
+
+    /// The key index of a segment is named after the segment, so that it can be found and
+    /// purged without being referenced by the segment.
+    pub fn segment_key_index_location(segment_location: &str) -> Location {
+        let splits = segment_location.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len.saturating_sub(2)].join("/");
+        let segment_name = splits[len - 1];
+        let segment_id = segment_name.split('_').next().unwrap_or(segment_name);
+        (
+            format!(
+                "{prefix}/{FUSE_TBL_SEGMENT_KEY_INDEX_PREFIX}/{segment_id}_v{SEGMENT_KEY_INDEX_VERSION}.bin"
+            ),
+            SEGMENT_KEY_INDEX_VERSION,
         )
     }
 
@@ -12,15 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod deletion_vector;
 mod files;
 mod locations;
 pub mod read;
+mod segment_key_index;
 mod segments;
 mod snapshots;
 mod write;
 
+pub use deletion_vector::decode_deletion_vector;
+pub use deletion_vector::encode_deletion_vector;
+pub use deletion_vector::live_row_offsets;
+pub use deletion_vector::read_deletion_vector;
+pub use deletion_vector::read_deletion_vector_sync;
+pub use deletion_vector::write_deletion_vector;
+pub use deletion_vector::DELETION_VECTOR_VERSION;
 pub use files::Files;
 pub use locations::TableMetaLocationGenerator;
+pub use read::load_inverted_index;
+pub use read::load_vector_index;
 pub use read::AggIndexReader;
 pub use read::BlockReader;
 pub use read::BloomBlockFilterReader;
@@ -33,6 +44,13 @@ pub use read::ReadSettings;
 pub use read::SnapshotHistoryReader;
 pub use read::TableSnapshotReader;
 pub use read::UncompressedBuffer;
+pub use segment_key_index::encode_segment_key_index;
+pub use segment_key_index::key_filter_digest;
+pub use segment_key_index::read_segment_key_index_meta;
+pub use segment_key_index::write_segment_key_index;
+pub use segment_key_index::KeyIndexMeta;
+pub use segment_key_index::SegmentKeyIndexMeta;
+pub use segment_key_index::SEGMENT_KEY_INDEX_VERSION;
 pub use segments::SegmentsIO;
 pub use segments::SerializedSegment;
 pub use snapshots::SnapshotLiteExtended;
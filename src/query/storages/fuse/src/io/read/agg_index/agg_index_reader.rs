@@ -56,6 +56,7 @@ impl AggIndexReader {
         dal: Operator,
         agg: &AggIndexInfo,
         compression: TableCompression,
+        enable_data_cache: bool,
     ) -> Result<Self> {
         let reader = BlockReader::create(
             dal,
@@ -63,6 +64,7 @@ impl AggIndexReader {
             agg.projection.clone(),
             ctx.clone(),
             false,
+            enable_data_cache,
         )?;
 
         let func_ctx = ctx.get_function_context()?;
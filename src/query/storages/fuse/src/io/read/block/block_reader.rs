@@ -46,6 +46,9 @@ pub struct BlockReader {
     pub(crate) parquet_schema_descriptor: SchemaDescriptor,
     pub(crate) default_vals: Vec<Scalar>,
     pub query_internal_columns: bool,
+    // Whether the raw column data may be kept in the table data cache, which lives on the local
+    // disk. It is disabled for encrypted tables so that no plaintext is written there.
+    pub(crate) enable_data_cache: bool,
 }
 
 fn inner_project_field_default_values(default_vals: &[Scalar], paths: &[usize]) -> Result<Scalar> {
@@ -79,6 +82,7 @@ impl BlockReader {
         projection: Projection,
         ctx: Arc<dyn TableContext>,
         query_internal_columns: bool,
+        enable_data_cache: bool,
     ) -> Result<Arc<BlockReader>> {
         // init projected_schema and default_vals of schema.fields
         let (projected_schema, default_vals) = match projection {
@@ -135,6 +139,7 @@ impl BlockReader {
             parquet_schema_descriptor,
             default_vals,
             query_internal_columns,
+            enable_data_cache,
         }))
     }
 
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::ops::Not;
 use std::sync::Arc;
 
 use common_arrow::arrow::array::Array;
@@ -27,6 +28,7 @@ use storages_common_table_meta::meta::Compression;
 
 use super::BlockReader;
 use crate::io::read::block::block_reader_merge_io::DataItem;
+use crate::io::read_deletion_vector;
 use crate::io::ReadSettings;
 use crate::io::UncompressedBuffer;
 use crate::FusePartInfo;
@@ -112,7 +114,7 @@ impl BlockReader {
 
         let num_rows = meta.row_count as usize;
 
-        match storage_format {
+        let block = match storage_format {
             FuseStorageFormat::Parquet => self.deserialize_parquet_chunks_with_buffer(
                 &meta.location.0,
                 num_rows,
@@ -129,6 +131,15 @@ impl BlockReader {
                 column_chunks,
                 None,
             ),
+        }?;
+
+        // Skip the rows marked in the deletion vector.
+        match &meta.deletion_vector_location {
+            Some(location) => {
+                let deleted = read_deletion_vector(&self.operator, location).await?;
+                block.filter_with_bitmap(&deleted.not())
+            }
+            None => Ok(block),
         }
     }
 }
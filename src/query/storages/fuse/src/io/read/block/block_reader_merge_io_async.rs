@@ -47,14 +47,21 @@ impl BlockReader {
         op: Operator,
         location: &str,
         raw_ranges: Vec<(ColumnId, Range<u64>)>,
+        enable_data_cache: bool,
     ) -> Result<MergeIOReadResult> {
+        let table_data_cache = if enable_data_cache {
+            CacheManager::instance().get_table_data_cache()
+        } else {
+            None
+        };
+
         if raw_ranges.is_empty() {
             // shortcut
             let read_res = MergeIOReadResult::create(
                 OwnerMemory::create(vec![]),
                 raw_ranges.len(),
                 location.to_string(),
-                CacheManager::instance().get_table_data_cache(),
+                table_data_cache,
             );
             return Ok(read_res);
         }
@@ -91,7 +98,6 @@ impl BlockReader {
 
         let start = Instant::now();
         let owner_memory = OwnerMemory::create(try_join_all(read_handlers).await?);
-        let table_data_cache = CacheManager::instance().get_table_data_cache();
         let mut read_res = MergeIOReadResult::create(
             owner_memory,
             raw_ranges.len(),
@@ -139,8 +145,12 @@ impl BlockReader {
         }
 
         let mut ranges = vec![];
-        // for async read, try using table data cache (if enabled in settings)
-        let column_data_cache = CacheManager::instance().get_table_data_cache();
+        // for async read, try using table data cache (if enabled in settings and for the table)
+        let column_data_cache = if self.enable_data_cache {
+            CacheManager::instance().get_table_data_cache()
+        } else {
+            None
+        };
         let column_array_cache = CacheManager::instance().get_table_data_array_cache();
         let mut cached_column_data = vec![];
         let mut cached_column_array = vec![];
@@ -172,8 +182,14 @@ impl BlockReader {
             }
         }
 
-        let mut merge_io_read_res =
-            Self::merge_io_read(settings, self.operator.clone(), location, ranges).await?;
+        let mut merge_io_read_res = Self::merge_io_read(
+            settings,
+            self.operator.clone(),
+            location,
+            ranges,
+            self.enable_data_cache,
+        )
+        .await?;
 
         merge_io_read_res.cached_column_data = cached_column_data;
         merge_io_read_res.cached_column_array = cached_column_array;
@@ -15,9 +15,11 @@
 mod agg_index;
 mod block;
 mod bloom;
+mod inverted;
 pub mod meta;
 mod read_settings;
 mod snapshot_history_reader;
+mod vector;
 
 pub use agg_index::AggIndexReader;
 pub use block::BlockReader;
@@ -26,8 +28,10 @@ pub use block::NativeReaderExt;
 pub use block::NativeSourceData;
 pub use block::UncompressedBuffer;
 pub use bloom::BloomBlockFilterReader;
+pub use inverted::load_inverted_index;
 pub use meta::CompactSegmentInfoReader;
 pub use meta::MetaReaders;
 pub use meta::TableSnapshotReader;
 pub use read_settings::ReadSettings;
 pub use snapshot_history_reader::SnapshotHistoryReader;
+pub use vector::load_vector_index;
@@ -0,0 +1,63 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::parquet::read::infer_schema;
+use common_arrow::arrow::io::parquet::read::{self as pread};
+use common_arrow::parquet::read::read_metadata;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::TableSchema;
+use opendal::Operator;
+use storages_common_index::VectorIndex;
+use storages_common_table_meta::meta::Location;
+
+/// Loads the vector index file of a block.
+///
+/// The index file is a single-row parquet file, one column per indexed column.
+#[async_backtrace::framed]
+pub async fn load_vector_index(dal: Operator, location: &Location) -> Result<VectorIndex> {
+    let (path, version) = location;
+    if *version != VectorIndex::VERSION {
+        return Err(ErrorCode::DeprecatedIndexFormat(format!(
+            "unsupported vector index version {}",
+            version
+        )));
+    }
+
+    let data = dal.read(path).await?;
+    let mut reader = Cursor::new(data);
+    let meta = read_metadata(&mut reader)?;
+    let arrow_schema = infer_schema(&meta)?;
+    let table_schema = TableSchema::from(&arrow_schema);
+    let data_schema = DataSchema::from(&table_schema);
+
+    let mut chunks =
+        pread::FileReader::new(reader, meta.row_groups, arrow_schema, None, None, None);
+    let block = match chunks.next() {
+        Some(chunk) => DataBlock::from_arrow_chunk(&chunk?, &data_schema)?,
+        None => {
+            return Err(ErrorCode::StorageOther(format!(
+                "vector index file {} is empty",
+                path
+            )));
+        }
+    };
+
+    VectorIndex::from_index_block(Arc::new(table_schema), &block)
+}
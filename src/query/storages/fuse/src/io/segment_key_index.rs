@@ -0,0 +1,251 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use opendal::ErrorKind;
+use opendal::Operator;
+use storages_common_index::filters::Filter;
+use storages_common_index::filters::FilterBuilder;
+use storages_common_index::filters::Xor8Builder;
+use storages_common_index::filters::Xor8Filter;
+use storages_common_table_meta::meta::Location;
+
+/// Format version of the segment key index file.
+pub const SEGMENT_KEY_INDEX_VERSION: u64 = 0;
+
+const DIRECTORY_LEN_SIZE: u64 = 8;
+const DIGEST_SIZE: u64 = 16;
+
+/// The directory of the key index of a segment.
+///
+/// The key index keeps the digests of the unique keys of the rows of a segment, the rows
+/// having a NULL key are left out. Layout:
+/// `directory_len: u64 | directory | filters | digests`, the directory has an entry for each
+/// key, `num_columns: u32 | column_ids: u32 * num_columns | filter_len: u64 | num_digests: u64`.
+/// The xor filters of the folded digests and the sorted digests (`u128`) of the keys follow in
+/// the order of the entries, all the integers are little endian. So the digests of a key are
+/// only read if its filter may contain the looked up keys.
+pub struct SegmentKeyIndexMeta {
+    pub keys: Vec<KeyIndexMeta>,
+}
+
+pub struct KeyIndexMeta {
+    pub column_ids: Vec<ColumnId>,
+    filter_range: Range<u64>,
+    digests_range: Range<u64>,
+}
+
+impl SegmentKeyIndexMeta {
+    pub fn key(&self, column_ids: &[ColumnId]) -> Option<&KeyIndexMeta> {
+        self.keys.iter().find(|key| key.column_ids == column_ids)
+    }
+
+    fn decode(directory: &[u8]) -> Result<Self> {
+        let mut cursor = directory;
+        let mut entries = vec![];
+        while !cursor.is_empty() {
+            let num_columns = read_u32(&mut cursor)? as usize;
+            let mut column_ids = Vec::with_capacity(num_columns);
+            for _ in 0..num_columns {
+                column_ids.push(read_u32(&mut cursor)?);
+            }
+            let filter_len = read_u64(&mut cursor)?;
+            let num_digests = read_u64(&mut cursor)?;
+            entries.push((column_ids, filter_len, num_digests));
+        }
+
+        let mut filter_offset = DIRECTORY_LEN_SIZE + directory.len() as u64;
+        let mut digests_offset = filter_offset + entries.iter().map(|(_, len, _)| len).sum::<u64>();
+        let mut keys = Vec::with_capacity(entries.len());
+        for (column_ids, filter_len, num_digests) in entries {
+            let digests_len = num_digests * DIGEST_SIZE;
+            keys.push(KeyIndexMeta {
+                column_ids,
+                filter_range: filter_offset..filter_offset + filter_len,
+                digests_range: digests_offset..digests_offset + digests_len,
+            });
+            filter_offset += filter_len;
+            digests_offset += digests_len;
+        }
+        Ok(SegmentKeyIndexMeta { keys })
+    }
+}
+
+impl KeyIndexMeta {
+    /// Loads the filter of the key, `None` if none of the rows has a non-NULL key.
+    #[async_backtrace::framed]
+    pub async fn read_filter(
+        &self,
+        dal: &Operator,
+        location: &Location,
+    ) -> Result<Option<Xor8Filter>> {
+        if self.filter_range.is_empty() {
+            return Ok(None);
+        }
+        let data = dal
+            .range_read(&location.0, self.filter_range.clone())
+            .await?;
+        let (filter, _) = Xor8Filter::from_bytes(&data)?;
+        Ok(Some(filter))
+    }
+
+    /// Loads the sorted digests of the key.
+    #[async_backtrace::framed]
+    pub async fn read_digests(&self, dal: &Operator, location: &Location) -> Result<Vec<u128>> {
+        if self.digests_range.is_empty() {
+            return Ok(vec![]);
+        }
+        let data = dal
+            .range_read(&location.0, self.digests_range.clone())
+            .await?;
+        if data.len() as u64 != self.digests_range.end - self.digests_range.start {
+            return Err(ErrorCode::StorageOther("segment key index is truncated"));
+        }
+        Ok(data
+            .chunks_exact(DIGEST_SIZE as usize)
+            .map(|chunk| u128::from_le_bytes(chunk.try_into().unwrap()))
+            .collect())
+    }
+}
+
+/// The digest of a key in the xor filter.
+pub fn key_filter_digest(digest: u128) -> u64 {
+    (digest as u64) ^ ((digest >> 64) as u64)
+}
+
+/// Serializes the key index of a segment, `keys` are the column ids and the key digests of
+/// each unique key of the table.
+pub fn encode_segment_key_index(keys: Vec<(Vec<ColumnId>, Vec<u128>)>) -> Result<Vec<u8>> {
+    let mut directory = vec![];
+    let mut filters = vec![];
+    let mut digests = vec![];
+    for (column_ids, mut key_digests) in keys {
+        key_digests.sort_unstable();
+        key_digests.dedup();
+        let filter = if key_digests.is_empty() {
+            vec![]
+        } else {
+            let mut filter_digests = key_digests
+                .iter()
+                .map(|digest| key_filter_digest(*digest))
+                .collect::<Vec<_>>();
+            filter_digests.sort_unstable();
+            filter_digests.dedup();
+            let mut builder = Xor8Builder::create();
+            builder.add_digests(filter_digests.iter());
+            builder.build()?.to_bytes()?
+        };
+
+        directory.extend_from_slice(&(column_ids.len() as u32).to_le_bytes());
+        for column_id in column_ids {
+            directory.extend_from_slice(&column_id.to_le_bytes());
+        }
+        directory.extend_from_slice(&(filter.len() as u64).to_le_bytes());
+        directory.extend_from_slice(&(key_digests.len() as u64).to_le_bytes());
+        filters.extend_from_slice(&filter);
+        for digest in key_digests {
+            digests.extend_from_slice(&digest.to_le_bytes());
+        }
+    }
+
+    let mut buf = Vec::with_capacity(
+        DIRECTORY_LEN_SIZE as usize + directory.len() + filters.len() + digests.len(),
+    );
+    buf.extend_from_slice(&(directory.len() as u64).to_le_bytes());
+    buf.extend_from_slice(&directory);
+    buf.extend_from_slice(&filters);
+    buf.extend_from_slice(&digests);
+    Ok(buf)
+}
+
+fn check_version(location: &Location) -> Result<()> {
+    if location.1 != SEGMENT_KEY_INDEX_VERSION {
+        return Err(ErrorCode::StorageOther(format!(
+            "unsupported segment key index version {}",
+            location.1
+        )));
+    }
+    Ok(())
+}
+
+/// Loads the directory of the key index of a segment, `None` if the segment has no key index.
+#[async_backtrace::framed]
+pub async fn read_segment_key_index_meta(
+    dal: &Operator,
+    location: &Location,
+) -> Result<Option<SegmentKeyIndexMeta>> {
+    check_version(location)?;
+    let data = match dal.range_read(&location.0, 0..DIRECTORY_LEN_SIZE).await {
+        Ok(data) => data,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let mut cursor = data.as_slice();
+    let directory_len = read_u64(&mut cursor)?;
+    if directory_len == 0 {
+        return Ok(Some(SegmentKeyIndexMeta { keys: vec![] }));
+    }
+    let directory = dal
+        .range_read(
+            &location.0,
+            DIRECTORY_LEN_SIZE..DIRECTORY_LEN_SIZE + directory_len,
+        )
+        .await?;
+    if directory.len() as u64 != directory_len {
+        return Err(ErrorCode::StorageOther("segment key index is truncated"));
+    }
+    SegmentKeyIndexMeta::decode(&directory).map(Some)
+}
+
+/// Writes the key index of a segment, returns the directory of the written index.
+#[async_backtrace::framed]
+pub async fn write_segment_key_index(
+    dal: &Operator,
+    location: &Location,
+    keys: Vec<(Vec<ColumnId>, Vec<u128>)>,
+) -> Result<SegmentKeyIndexMeta> {
+    check_version(location)?;
+    let data = encode_segment_key_index(keys)?;
+    let mut cursor = data.as_slice();
+    let directory_len = read_u64(&mut cursor)? as usize;
+    let meta = SegmentKeyIndexMeta::decode(&cursor[..directory_len])?;
+    dal.write(&location.0, data).await?;
+    Ok(meta)
+}
+
+fn read_u32(cursor: &mut &[u8]) -> Result<u32> {
+    let (bytes, rest) = split_array::<4>(cursor)?;
+    *cursor = rest;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(cursor: &mut &[u8]) -> Result<u64> {
+    let (bytes, rest) = split_array::<8>(cursor)?;
+    *cursor = rest;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn split_array<const N: usize>(bytes: &[u8]) -> Result<([u8; N], &[u8])> {
+    if bytes.len() < N {
+        return Err(ErrorCode::StorageOther("segment key index is truncated"));
+    }
+    let (head, rest) = bytes.split_at(N);
+    let mut array = [0; N];
+    array.copy_from_slice(head);
+    Ok((array, rest))
+}
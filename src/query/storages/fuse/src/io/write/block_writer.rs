@@ -26,11 +26,14 @@ use common_expression::DataBlock;
 use common_expression::FieldIndex;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
+use common_functions::scalars::Tokenizer;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
 use opendal::Operator;
 use storages_common_blocks::blocks_to_parquet;
 use storages_common_index::BloomIndex;
+use storages_common_index::InvertedIndex;
+use storages_common_index::VectorIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnMeta;
@@ -119,6 +122,7 @@ impl BloomIndexState {
         block: &DataBlock,
         location: Location,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
     ) -> Result<Option<Self>> {
         // write index
         let maybe_bloom_index = BloomIndex::try_create(
@@ -126,6 +130,7 @@ impl BloomIndexState {
             location.1,
             &[block],
             bloom_columns_map,
+            ngram_columns_map,
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
@@ -151,11 +156,85 @@ impl BloomIndexState {
     }
 }
 
+pub struct InvertedIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) size: u64,
+    pub(crate) location: Location,
+}
+
+impl InvertedIndexState {
+    pub fn try_create(
+        block: &DataBlock,
+        location: Location,
+        index_columns: Vec<(FieldIndex, TableField, Tokenizer)>,
+    ) -> Result<Option<Self>> {
+        if index_columns.is_empty() {
+            return Ok(None);
+        }
+        let maybe_inverted_index = InvertedIndex::try_create(&[block], index_columns)?;
+        if let Some(inverted_index) = maybe_inverted_index {
+            let index_block = inverted_index.serialize_to_data_block()?;
+            let mut data = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
+            let (size, _) = blocks_to_parquet(
+                &inverted_index.index_schema,
+                vec![index_block],
+                &mut data,
+                TableCompression::Zstd,
+            )?;
+            Ok(Some(Self {
+                data,
+                size,
+                location,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+pub struct VectorIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) size: u64,
+    pub(crate) location: Location,
+}
+
+impl VectorIndexState {
+    pub fn try_create(
+        block: &DataBlock,
+        location: Location,
+        index_columns: Vec<(FieldIndex, TableField)>,
+    ) -> Result<Option<Self>> {
+        if index_columns.is_empty() {
+            return Ok(None);
+        }
+        let maybe_vector_index = VectorIndex::try_create(&[block], index_columns)?;
+        if let Some(vector_index) = maybe_vector_index {
+            let index_block = vector_index.serialize_to_data_block()?;
+            let mut data = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
+            let (size, _) = blocks_to_parquet(
+                &vector_index.index_schema,
+                vec![index_block],
+                &mut data,
+                TableCompression::Zstd,
+            )?;
+            Ok(Some(Self {
+                data,
+                size,
+                location,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
 pub struct BlockSerialization {
     pub block_raw_data: Vec<u8>,
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub inverted_index_state: Option<InvertedIndexState>,
+    pub vector_index_state: Option<VectorIndexState>,
 }
 
 #[derive(Clone)]
@@ -166,6 +245,9 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub inverted_index_columns: Vec<(FieldIndex, TableField, Tokenizer)>,
+    pub vector_index_columns: Vec<(FieldIndex, TableField)>,
 }
 
 impl BlockBuilder {
@@ -181,11 +263,26 @@ impl BlockBuilder {
             &data_block,
             bloom_index_location,
             self.bloom_columns_map.clone(),
+            self.ngram_columns_map.clone(),
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
 
+        let inverted_index_location = self.meta_locations.block_inverted_index_location(&block_id);
+        let inverted_index_state = InvertedIndexState::try_create(
+            &data_block,
+            inverted_index_location,
+            self.inverted_index_columns.clone(),
+        )?;
+
+        let vector_index_location = self.meta_locations.block_vector_index_location(&block_id);
+        let vector_index_state = VectorIndexState::try_create(
+            &data_block,
+            vector_index_location,
+            self.vector_index_columns.clone(),
+        )?;
+
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
         let col_stats =
@@ -214,6 +311,19 @@ impl BlockBuilder {
                 .unwrap_or_default(),
             compression: self.write_settings.table_compression.try_into()?,
             create_on: Some(Utc::now()),
+            inverted_index_location: inverted_index_state.as_ref().map(|v| v.location.clone()),
+            inverted_index_size: inverted_index_state
+                .as_ref()
+                .map(|v| v.size)
+                .unwrap_or_default(),
+            vector_index_location: vector_index_state.as_ref().map(|v| v.location.clone()),
+            vector_index_size: vector_index_state
+                .as_ref()
+                .map(|v| v.size)
+                .unwrap_or_default(),
+            deletion_vector_location: None,
+            deletion_vector_size: 0,
+            deleted_row_count: 0,
         };
 
         let serialized = BlockSerialization {
@@ -221,6 +331,8 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            inverted_index_state,
+            vector_index_state,
         };
         Ok(serialized)
     }
@@ -41,7 +41,20 @@ impl<'a> SegmentWriter<'a> {
 
     #[async_backtrace::framed]
     pub async fn write_segment(&self, segment: SegmentInfo) -> Result<Location> {
-        let location = self.generate_location();
+        self.write_segment_of_partition(segment, None).await
+    }
+
+    /// Write the segment which only holds the blocks of the given partition.
+    #[async_backtrace::framed]
+    pub async fn write_segment_of_partition(
+        &self,
+        segment: SegmentInfo,
+        partition: Option<&str>,
+    ) -> Result<Location> {
+        let path = self
+            .location_generator
+            .gen_segment_info_location_of_partition(partition);
+        let location = (path, SegmentInfo::VERSION);
         segment
             .write_meta_through_cache(self.data_accessor, &location.0)
             .await?;
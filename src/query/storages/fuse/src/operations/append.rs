@@ -72,6 +72,9 @@ impl FuseTable {
 
         let cluster_stats_gen =
             self.cluster_gen_for_append(ctx.clone(), pipeline, block_thresholds)?;
+        // blocks are split by partition after sorting, the serialized blocks
+        // of different partitions never go into the same segment.
+        self.partition_scatter_for_append(ctx.clone(), pipeline)?;
         pipeline.add_transform(|input, output| {
             let proc = TransformSerializeBlock::try_create(
                 ctx.clone(),
@@ -79,6 +79,18 @@ impl FuseTable {
         copied_files: Option<UpsertTableCopiedFileReq>,
         overwrite: bool,
         prev_snapshot_id: Option<SnapshotId>,
+    ) -> Result<()> {
+        let snapshot_gen = AppendGenerator::new(ctx.clone(), overwrite);
+        self.do_commit_with_generator(ctx, pipeline, copied_files, snapshot_gen, prev_snapshot_id)
+    }
+
+    pub fn do_commit_with_generator(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        copied_files: Option<UpsertTableCopiedFileReq>,
+        snapshot_gen: AppendGenerator,
+        prev_snapshot_id: Option<SnapshotId>,
     ) -> Result<()> {
         pipeline.try_resize(1)?;
 
@@ -95,7 +107,6 @@ impl FuseTable {
             )))
         })?;
 
-        let snapshot_gen = AppendGenerator::new(ctx.clone(), overwrite);
         pipeline.add_sink(|input| {
             CommitSink::try_create(
                 self,
@@ -44,6 +44,16 @@ impl AbortOperation {
         if let Some(index) = block.bloom_filter_index_location.clone() {
             self.bloom_filter_indexes.push(index.0);
         }
+        if let Some(index) = block.inverted_index_location.clone() {
+            self.bloom_filter_indexes.push(index.0);
+        }
+        if let Some(index) = block.vector_index_location.clone() {
+            self.bloom_filter_indexes.push(index.0);
+        }
+    }
+
+    pub fn add_deletion_vector(&mut self, location: String) {
+        self.bloom_filter_indexes.push(location);
     }
 
     pub fn add_segment(&mut self, segment: String) {
@@ -17,6 +17,7 @@ mod mutation_accumulator;
 mod mutation_log;
 mod processors;
 mod snapshot_generator;
+mod unique_key_checker;
 
 pub use abort_operation::AbortOperation;
 pub use mutation_accumulator::MutationAccumulator;
@@ -24,3 +25,4 @@ pub use mutation_accumulator::MutationKind;
 pub use mutation_log::*;
 pub use processors::*;
 pub use snapshot_generator::*;
+pub use unique_key_checker::UniqueKeyChecker;
@@ -39,6 +39,7 @@ use crate::io::SegmentsIO;
 use crate::io::SerializedSegment;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::AbortOperation;
+use crate::operations::common::BlockDeletionVector;
 use crate::operations::common::CommitMeta;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::mutation::BlockIndex;
@@ -53,6 +54,8 @@ use crate::FuseTable;
 struct BlockMutations {
     replaced_blocks: Vec<(BlockIndex, Arc<BlockMeta>)>,
     deleted_blocks: Vec<BlockIndex>,
+    deletion_vectors: Vec<(BlockIndex, BlockDeletionVector)>,
+    appended_blocks: Vec<Arc<BlockMeta>>,
 }
 
 impl BlockMutations {
@@ -60,6 +63,8 @@ impl BlockMutations {
         BlockMutations {
             replaced_blocks: vec![(block_idx, block_meta)],
             deleted_blocks: vec![],
+            deletion_vectors: vec![],
+            appended_blocks: vec![],
         }
     }
 
@@ -67,6 +72,26 @@ impl BlockMutations {
         BlockMutations {
             replaced_blocks: vec![],
             deleted_blocks: vec![block_idx],
+            deletion_vectors: vec![],
+            appended_blocks: vec![],
+        }
+    }
+
+    fn new_deletion_vector(block_idx: BlockIndex, deletion_vector: BlockDeletionVector) -> Self {
+        BlockMutations {
+            replaced_blocks: vec![],
+            deleted_blocks: vec![],
+            deletion_vectors: vec![(block_idx, deletion_vector)],
+            appended_blocks: vec![],
+        }
+    }
+
+    fn new_appended(block_meta: Arc<BlockMeta>) -> Self {
+        BlockMutations {
+            replaced_blocks: vec![],
+            deleted_blocks: vec![],
+            deletion_vectors: vec![],
+            appended_blocks: vec![block_meta],
         }
     }
 
@@ -77,6 +102,18 @@ impl BlockMutations {
     fn push_deleted(&mut self, block_idx: BlockIndex) {
         self.deleted_blocks.push(block_idx)
     }
+
+    fn push_deletion_vector(
+        &mut self,
+        block_idx: BlockIndex,
+        deletion_vector: BlockDeletionVector,
+    ) {
+        self.deletion_vectors.push((block_idx, deletion_vector))
+    }
+
+    fn push_appended(&mut self, block_meta: Arc<BlockMeta>) {
+        self.appended_blocks.push(block_meta)
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -151,6 +188,29 @@ impl MutationAccumulator {
                     .and_modify(|v| v.push_deleted(index.block_idx))
                     .or_insert(BlockMutations::new_deletion(index.block_idx));
             }
+            MutationLogEntry::DeletionVector {
+                index,
+                deletion_vector,
+            } => {
+                self.abort_operation
+                    .add_deletion_vector(deletion_vector.location.0.clone());
+                self.mutations
+                    .entry(index.segment_idx)
+                    .and_modify(|v| {
+                        v.push_deletion_vector(index.block_idx, deletion_vector.clone())
+                    })
+                    .or_insert(BlockMutations::new_deletion_vector(
+                        index.block_idx,
+                        deletion_vector,
+                    ));
+            }
+            MutationLogEntry::AppendedBlock { index, block_meta } => {
+                self.abort_operation.add_block(&block_meta);
+                self.mutations
+                    .entry(index.segment_idx)
+                    .and_modify(|v| v.push_appended(block_meta.clone()))
+                    .or_insert(BlockMutations::new_appended(block_meta));
+            }
             MutationLogEntry::DeletedSegment { deleted_segment } => {
                 self.deleted_segments.push(deleted_segment)
             }
@@ -297,6 +357,9 @@ impl MutationAccumulator {
             let default_cluster_key_id = self.default_cluster_key_id;
 
             tasks.push(async move {
+                // the mutated segment stays in the partition of the old one.
+                let partition =
+                    TableMetaLocationGenerator::segment_partition(&location.0).map(str::to_string);
                 // read the old segment
                 let mut segment_info =
                     SegmentsIO::read_segment(op.clone(), location, schema, false).await?;
@@ -310,13 +373,23 @@ impl MutationAccumulator {
                 for (idx, new_meta) in segment_mutation.replaced_blocks {
                     block_editor.insert(idx, new_meta);
                 }
+                for (idx, deletion_vector) in segment_mutation.deletion_vectors {
+                    if let Some(block_meta) = block_editor.get_mut(&idx) {
+                        let mut new_meta = block_meta.as_ref().clone();
+                        new_meta.deletion_vector_location = Some(deletion_vector.location);
+                        new_meta.deletion_vector_size = deletion_vector.size;
+                        new_meta.deleted_row_count = deletion_vector.deleted_row_count;
+                        *block_meta = Arc::new(new_meta);
+                    }
+                }
                 for idx in segment_mutation.deleted_blocks {
                     block_editor.remove(&idx);
                 }
 
-                if !block_editor.is_empty() {
+                if !block_editor.is_empty() || !segment_mutation.appended_blocks.is_empty() {
                     // assign back the mutated blocks to segment
-                    let new_blocks = block_editor.into_values().collect::<Vec<_>>();
+                    let mut new_blocks = block_editor.into_values().collect::<Vec<_>>();
+                    new_blocks.extend(segment_mutation.appended_blocks);
                     // re-calculate the segment statistics
                     let new_summary =
                         reduce_block_metas(&new_blocks, thresholds, default_cluster_key_id);
@@ -324,7 +397,8 @@ impl MutationAccumulator {
                     let new_segment = SegmentInfo::new(new_blocks, new_summary.clone());
 
                     // write the segment info.
-                    let location = location_gen.gen_segment_info_location();
+                    let location =
+                        location_gen.gen_segment_info_location_of_partition(partition.as_deref());
                     let serialized_segment = SerializedSegment {
                         path: location.clone(),
                         segment: Arc::new(new_segment),
@@ -21,6 +21,7 @@ use common_expression::BlockMetaInfoDowncast;
 use common_expression::DataBlock;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::FormatVersion;
+use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 
 use super::ConflictResolveContext;
@@ -49,9 +50,27 @@ pub enum MutationLogEntry {
         index: BlockMetaIndex,
         block_meta: Arc<BlockMeta>,
     },
+    /// The block is kept as is, the deleted rows are marked in a new deletion vector.
+    DeletionVector {
+        index: BlockMetaIndex,
+        deletion_vector: BlockDeletionVector,
+    },
+    /// A new block appended to the segment of the block at `index`, e.g. the updated rows
+    /// of a block whose old rows are marked in a deletion vector.
+    AppendedBlock {
+        index: BlockMetaIndex,
+        block_meta: Arc<BlockMeta>,
+    },
     DoNothing,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct BlockDeletionVector {
+    pub location: Location,
+    pub size: u64,
+    pub deleted_row_count: u64,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
 pub struct BlockMetaIndex {
     pub segment_idx: usize,
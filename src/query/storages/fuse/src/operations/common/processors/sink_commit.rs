@@ -174,6 +174,17 @@ where F: SnapshotGenerator + Send + 'static
 
         Ok(Event::Async)
     }
+
+    // Removes the files written by the operation.
+    async fn abort(&mut self) -> Result<()> {
+        let duration = self.start_time.elapsed();
+        metrics_inc_commit_aborts();
+        // todo: use histogram when it ready
+        metrics_inc_commit_milliseconds(duration.as_millis());
+        self.heartbeat.shutdown().await?;
+        let op = self.abort_operation.clone();
+        op.abort(self.ctx.clone(), self.dal.clone()).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -279,6 +290,14 @@ where F: SnapshotGenerator + Send + 'static
                     self.snapshot_gen
                         .fill_default_values(schema, &previous)
                         .await?;
+                    if let Err(e) = self
+                        .snapshot_gen
+                        .check_constraints(&fuse_table, &previous)
+                        .await
+                    {
+                        self.abort().await?;
+                        return Err(e);
+                    }
 
                     self.state = State::GenerateSnapshot {
                         previous,
@@ -414,6 +433,14 @@ where F: SnapshotGenerator + Send + 'static
                 self.table = self.table.refresh(self.ctx.as_ref()).await?;
                 let fuse_table = FuseTable::try_from_table(self.table.as_ref())?.to_owned();
                 let previous = fuse_table.read_table_snapshot().await?;
+                if let Err(e) = self
+                    .snapshot_gen
+                    .check_constraints(&fuse_table, &previous)
+                    .await
+                {
+                    self.abort().await?;
+                    return Err(e);
+                }
                 let cluster_key_meta = fuse_table.cluster_key_meta.clone();
                 self.state = State::GenerateSnapshot {
                     previous,
@@ -423,12 +450,7 @@ where F: SnapshotGenerator + Send + 'static
             }
             State::AbortOperation => {
                 let duration = self.start_time.elapsed();
-                metrics_inc_commit_aborts();
-                // todo: use histogram when it ready
-                metrics_inc_commit_milliseconds(duration.as_millis());
-                self.heartbeat.shutdown().await?;
-                let op = self.abort_operation.clone();
-                op.abort(self.ctx.clone(), self.dal.clone()).await?;
+                self.abort().await?;
                 return Err(ErrorCode::StorageOther(format!(
                     "transaction aborted after {} retries, which took {} ms",
                     self.retries,
@@ -27,6 +27,7 @@ use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use opendal::Operator;
 use storages_common_index::BloomIndex;
+use storages_common_index::VectorIndex;
 
 use crate::io::write_data;
 use crate::io::BlockBuilder;
@@ -37,11 +38,14 @@ use crate::metrics::metrics_inc_block_index_write_nums;
 use crate::metrics::metrics_inc_block_write_bytes;
 use crate::metrics::metrics_inc_block_write_milliseconds;
 use crate::metrics::metrics_inc_block_write_nums;
+use crate::operations::common::BlockDeletionVector;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
 use crate::operations::mutation::ClusterStatsGenType;
 use crate::operations::mutation::SerializeDataMeta;
+use crate::operations::PartitionMeta;
+use crate::operations::PartitionedBlockMeta;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::Processor;
@@ -54,10 +58,14 @@ enum State {
         block: DataBlock,
         stats_type: ClusterStatsGenType,
         index: Option<BlockMetaIndex>,
+        partition: Option<String>,
+        deletion_vector: Option<BlockDeletionVector>,
     },
     Serialized {
         serialized: BlockSerialization,
         index: Option<BlockMetaIndex>,
+        partition: Option<String>,
+        deletion_vector: Option<BlockDeletionVector>,
     },
 }
 
@@ -83,6 +91,15 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_columns_map = table
+            .ngram_index_cols
+            .bloom_index_fields(source_schema.clone(), BloomIndex::supported_ngram_type)?;
+        let inverted_index_columns = table.inverted_index_fields(&source_schema)?;
+        let vector_index_columns = table
+            .vector_index_cols
+            .bloom_index_fields(source_schema.clone(), VectorIndex::supported_type)?
+            .into_iter()
+            .collect();
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
@@ -90,6 +107,9 @@ impl TransformSerializeBlock {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            ngram_columns_map,
+            inverted_index_columns,
+            vector_index_columns,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -168,7 +188,21 @@ impl Processor for TransformSerializeBlock {
 
         let mut input_data = self.input.pull_data().unwrap()?;
         let meta = input_data.take_meta();
-        if let Some(meta) = meta {
+        if let Some(meta) = meta.as_ref().and_then(PartitionMeta::downcast_ref_from) {
+            // append block of a partition
+            self.state = State::NeedSerialize {
+                block: input_data,
+                stats_type: ClusterStatsGenType::Generally,
+                index: None,
+                partition: Some(meta.partition.clone()),
+                deletion_vector: None,
+            };
+            Ok(Event::Sync)
+        } else if let Some(logs) = meta.as_ref().and_then(MutationLogs::downcast_ref_from) {
+            // the mutation has already been written by the source, e.g. a deletion vector
+            self.output.push_data(Ok(DataBlock::from(logs.clone())));
+            Ok(Event::NeedConsume)
+        } else if let Some(meta) = meta {
             let meta =
                 SerializeDataMeta::downcast_from(meta).ok_or(ErrorCode::Internal("It's a bug"))?;
             if let Some(deleted_segment) = meta.deleted_segment {
@@ -184,11 +218,14 @@ impl Processor for TransformSerializeBlock {
                 self.output.push_data(Ok(data_block));
                 Ok(Event::NeedConsume)
             } else {
-                // replace the old block
+                // replace the old block, or append a block next to it if the old rows are
+                // marked in a deletion vector
                 self.state = State::NeedSerialize {
                     block: input_data,
                     stats_type: meta.stats_type,
                     index: Some(meta.index),
+                    partition: None,
+                    deletion_vector: meta.deletion_vector,
                 };
                 Ok(Event::Sync)
             }
@@ -203,6 +240,8 @@ impl Processor for TransformSerializeBlock {
                 block: input_data,
                 stats_type: ClusterStatsGenType::Generally,
                 index: None,
+                partition: None,
+                deletion_vector: None,
             };
             Ok(Event::Sync)
         }
@@ -214,6 +253,8 @@ impl Processor for TransformSerializeBlock {
                 block,
                 stats_type,
                 index,
+                partition,
+                deletion_vector,
             } => {
                 let serialized =
                     self.block_builder
@@ -226,7 +267,12 @@ impl Processor for TransformSerializeBlock {
                             }
                         })?;
 
-                self.state = State::Serialized { serialized, index };
+                self.state = State::Serialized {
+                    serialized,
+                    index,
+                    partition,
+                    deletion_vector,
+                };
             }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
@@ -236,7 +282,12 @@ impl Processor for TransformSerializeBlock {
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Consume) {
-            State::Serialized { serialized, index } => {
+            State::Serialized {
+                serialized,
+                index,
+                partition,
+                deletion_vector,
+            } => {
                 let start = Instant::now();
                 // write block data.
                 let raw_block_data = serialized.block_raw_data;
@@ -270,12 +321,39 @@ impl Processor for TransformSerializeBlock {
                         );
                     }
                 }
+                if let Some(inverted_index_state) = serialized.inverted_index_state {
+                    write_data(
+                        inverted_index_state.data,
+                        &self.dal,
+                        &inverted_index_state.location.0,
+                    )
+                    .await?;
+                }
+                if let Some(vector_index_state) = serialized.vector_index_state {
+                    write_data(
+                        vector_index_state.data,
+                        &self.dal,
+                        &vector_index_state.location.0,
+                    )
+                    .await?;
+                }
 
                 let data_block = if let Some(index) = index {
-                    Self::mutation_logs(MutationLogEntry::Replaced {
-                        index,
-                        block_meta: Arc::new(serialized.block_meta),
-                    })
+                    let block_meta = Arc::new(serialized.block_meta);
+                    match deletion_vector {
+                        Some(deletion_vector) => DataBlock::from(MutationLogs {
+                            entries: vec![
+                                MutationLogEntry::DeletionVector {
+                                    index: index.clone(),
+                                    deletion_vector,
+                                },
+                                MutationLogEntry::AppendedBlock { index, block_meta },
+                            ],
+                        }),
+                        None => {
+                            Self::mutation_logs(MutationLogEntry::Replaced { index, block_meta })
+                        }
+                    }
                 } else {
                     let progress_values = ProgressValues {
                         rows: serialized.block_meta.row_count as usize,
@@ -286,7 +364,15 @@ impl Processor for TransformSerializeBlock {
                         .get_write_progress()
                         .incr(&progress_values);
 
-                    DataBlock::empty_with_meta(Box::new(serialized.block_meta))
+                    match partition {
+                        Some(partition) => {
+                            DataBlock::empty_with_meta(Box::new(PartitionedBlockMeta {
+                                partition,
+                                block_meta: serialized.block_meta,
+                            }))
+                        }
+                        None => DataBlock::empty_with_meta(Box::new(serialized.block_meta)),
+                    }
                 };
                 self.output_data = Some(data_block);
             }
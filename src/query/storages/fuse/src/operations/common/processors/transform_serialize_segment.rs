@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -34,6 +35,7 @@ use storages_common_table_meta::meta::Versioned;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
+use crate::operations::PartitionedBlockMeta;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::processor::ProcessorPtr;
@@ -45,7 +47,9 @@ use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 
 enum State {
     None,
-    GenerateSegment,
+    GenerateSegment {
+        partition: Option<String>,
+    },
     SerializedSegment {
         data: Vec<u8>,
         location: String,
@@ -62,6 +66,8 @@ pub struct TransformSerializeSegment {
     data_accessor: Operator,
     meta_locations: TableMetaLocationGenerator,
     accumulator: StatisticsAccumulator,
+    // accumulators of partitioned blocks, keyed by the encoded partition value.
+    partition_accumulators: BTreeMap<String, StatisticsAccumulator>,
     state: State,
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
@@ -88,6 +94,7 @@ impl TransformSerializeSegment {
             meta_locations: table.meta_location_generator().clone(),
             state: State::None,
             accumulator: Default::default(),
+            partition_accumulators: BTreeMap::new(),
             block_per_seg: table
                 .get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT)
                 as u64,
@@ -121,7 +128,7 @@ impl Processor for TransformSerializeSegment {
     fn event(&mut self) -> Result<Event> {
         if matches!(
             &self.state,
-            State::GenerateSegment | State::PreCommitSegment { .. }
+            State::GenerateSegment { .. } | State::PreCommitSegment { .. }
         ) {
             return Ok(Event::Sync);
         }
@@ -145,7 +152,13 @@ impl Processor for TransformSerializeSegment {
 
         if self.input.is_finished() {
             if self.accumulator.summary_row_count != 0 {
-                self.state = State::GenerateSegment;
+                self.state = State::GenerateSegment { partition: None };
+                return Ok(Event::Sync);
+            }
+            if let Some(partition) = self.partition_accumulators.keys().next() {
+                self.state = State::GenerateSegment {
+                    partition: Some(partition.clone()),
+                };
                 return Ok(Event::Sync);
             }
             self.output.finish();
@@ -161,14 +174,28 @@ impl Processor for TransformSerializeSegment {
                 .get_meta()
                 .cloned()
                 .ok_or(ErrorCode::Internal("No block meta. It's a bug"))?;
-            let block_meta = BlockMeta::downcast_ref_from(&input_meta)
-                .ok_or(ErrorCode::Internal("No commit meta. It's a bug"))?
-                .clone();
+            if let Some(meta) = PartitionedBlockMeta::downcast_ref_from(&input_meta) {
+                let accumulator = self
+                    .partition_accumulators
+                    .entry(meta.partition.clone())
+                    .or_default();
+                accumulator.add_with_block_meta(meta.block_meta.clone());
+                if accumulator.summary_block_count >= self.block_per_seg {
+                    self.state = State::GenerateSegment {
+                        partition: Some(meta.partition.clone()),
+                    };
+                    return Ok(Event::Sync);
+                }
+            } else {
+                let block_meta = BlockMeta::downcast_ref_from(&input_meta)
+                    .ok_or(ErrorCode::Internal("No commit meta. It's a bug"))?
+                    .clone();
 
-            self.accumulator.add_with_block_meta(block_meta);
-            if self.accumulator.summary_block_count >= self.block_per_seg {
-                self.state = State::GenerateSegment;
-                return Ok(Event::Sync);
+                self.accumulator.add_with_block_meta(block_meta);
+                if self.accumulator.summary_block_count >= self.block_per_seg {
+                    self.state = State::GenerateSegment { partition: None };
+                    return Ok(Event::Sync);
+                }
             }
         }
 
@@ -178,15 +205,23 @@ impl Processor for TransformSerializeSegment {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::None) {
-            State::GenerateSegment => {
-                let acc = std::mem::take(&mut self.accumulator);
+            State::GenerateSegment { partition } => {
+                let acc = match &partition {
+                    Some(partition) => self
+                        .partition_accumulators
+                        .remove(partition)
+                        .unwrap_or_default(),
+                    None => std::mem::take(&mut self.accumulator),
+                };
                 let summary = acc.summary(self.thresholds, self.default_cluster_key_id);
 
                 let segment_info = SegmentInfo::new(acc.blocks_metas, summary);
 
                 self.state = State::SerializedSegment {
                     data: segment_info.to_bytes()?,
-                    location: self.meta_locations.gen_segment_info_location(),
+                    location: self
+                        .meta_locations
+                        .gen_segment_info_location_of_partition(partition.as_deref()),
                     segment: Arc::new(segment_info),
                 }
             }
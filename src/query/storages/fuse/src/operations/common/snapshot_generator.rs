@@ -25,20 +25,27 @@ use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_sql::field_default_value;
 use log::info;
+use opendal::Operator;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::TableConstraints;
 use uuid::Uuid;
 
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
 use crate::metrics::metrics_inc_commit_mutation_latest_snapshot_append_only;
 use crate::metrics::metrics_inc_commit_mutation_modified_segment_exists_in_latest;
 use crate::metrics::metrics_inc_commit_mutation_unresolvable_conflict;
+use crate::operations::common::UniqueKeyChecker;
 use crate::statistics::merge_statistics;
 use crate::statistics::reducers::deduct_statistics;
 use crate::statistics::reducers::deduct_statistics_mut;
 use crate::statistics::reducers::merge_statistics_mut;
+use crate::FuseTable;
 
 #[async_trait::async_trait]
 pub trait SnapshotGenerator {
@@ -52,6 +59,16 @@ pub trait SnapshotGenerator {
         Ok(())
     }
 
+    /// Checks the key constraints of the table against the data to be committed,
+    /// `previous` is the latest snapshot that the new snapshot will be based on.
+    async fn check_constraints(
+        &mut self,
+        _table: &FuseTable,
+        _previous: &Option<Arc<TableSnapshot>>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
     fn generate_new_snapshot(
         &self,
         schema: TableSchema,
@@ -276,12 +293,30 @@ impl SnapshotGenerator for MutationGenerator {
     }
 }
 
+/// The partition replaced by `INSERT OVERWRITE ... PARTITION`.
+#[derive(Clone)]
+struct PartitionOverwrite {
+    // encoded partition value.
+    partition: String,
+    operator: Operator,
+    // summaries of the replaced segments, keyed by segment location.
+    replaced: HashMap<String, Statistics>,
+}
+
+impl PartitionOverwrite {
+    fn is_replaced(&self, location: &Location) -> bool {
+        TableMetaLocationGenerator::segment_partition(&location.0) == Some(self.partition.as_str())
+    }
+}
+
 #[derive(Clone)]
 pub struct AppendGenerator {
     ctx: Arc<dyn TableContext>,
     leaf_default_values: HashMap<ColumnId, Scalar>,
     overwrite: bool,
+    overwrite_partition: Option<PartitionOverwrite>,
     conflict_resolve_ctx: Option<ConflictResolveContext>,
+    unique_key_checker: Option<UniqueKeyChecker>,
 }
 
 impl AppendGenerator {
@@ -290,10 +325,22 @@ impl AppendGenerator {
             ctx,
             leaf_default_values: HashMap::new(),
             overwrite,
+            overwrite_partition: None,
             conflict_resolve_ctx: None,
+            unique_key_checker: None,
         }
     }
 
+    /// Only replace the segments of the given (encoded) partition, other partitions are kept.
+    pub fn with_overwrite_partition(mut self, partition: String, operator: Operator) -> Self {
+        self.overwrite_partition = Some(PartitionOverwrite {
+            partition,
+            operator,
+            replaced: HashMap::new(),
+        });
+        self
+    }
+
     fn check_fill_default(&self, summary: &Statistics) -> Result<bool> {
         let mut fill_default_values = false;
         // check if need to fill default value in statistics
@@ -339,6 +386,27 @@ impl SnapshotGenerator for AppendGenerator {
         schema: TableSchema,
         previous: &Option<Arc<TableSnapshot>>,
     ) -> Result<()> {
+        if let (Some(snapshot), Some(overwrite)) = (previous, &mut self.overwrite_partition) {
+            let locations = snapshot
+                .segments
+                .iter()
+                .filter(|location| {
+                    overwrite.is_replaced(location) && !overwrite.replaced.contains_key(&location.0)
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            let segments_io = SegmentsIO::create(
+                self.ctx.clone(),
+                overwrite.operator.clone(),
+                Arc::new(schema.clone()),
+            );
+            let segments = segments_io
+                .read_segments::<SegmentInfo>(&locations, false)
+                .await?;
+            for (location, segment) in locations.into_iter().zip(segments) {
+                overwrite.replaced.insert(location.0, segment?.summary);
+            }
+        }
         if let Some(snapshot) = previous {
             if !self.overwrite && self.check_fill_default(&snapshot.summary)? {
                 let mut default_values = Vec::with_capacity(schema.num_fields());
@@ -351,6 +419,42 @@ impl SnapshotGenerator for AppendGenerator {
         Ok(())
     }
 
+    async fn check_constraints(
+        &mut self,
+        table: &FuseTable,
+        previous: &Option<Arc<TableSnapshot>>,
+    ) -> Result<()> {
+        let constraints = TableConstraints::from_options(table.table_info.options())?;
+        if constraints.is_empty() {
+            return Ok(());
+        }
+
+        let appended = self.conflict_resolve_ctx()?.0.merged_segments.clone();
+        // the segments of the previous snapshot that are kept in the new snapshot
+        let existing: Vec<Location> = match previous {
+            Some(snapshot) if !self.overwrite => snapshot
+                .segments
+                .iter()
+                .filter(|location| {
+                    self.overwrite_partition
+                        .as_ref()
+                        .map_or(true, |overwrite| !overwrite.is_replaced(location))
+                })
+                .cloned()
+                .collect(),
+            _ => vec![],
+        };
+        // the checker keeps the segments already checked, in case the commit is retried
+        let checker = match self.unique_key_checker.take() {
+            Some(checker) => checker,
+            None => UniqueKeyChecker::try_create(self.ctx.clone(), table, &constraints)?,
+        };
+        self.unique_key_checker
+            .insert(checker)
+            .check(&appended, &existing)
+            .await
+    }
+
     fn generate_new_snapshot(
         &self,
         schema: TableSchema,
@@ -370,6 +474,17 @@ impl SnapshotGenerator for AppendGenerator {
         let mut new_segments = snapshot_merged.merged_segments.clone();
         let mut new_summary = snapshot_merged.merged_statistics.clone();
 
+        if let Some(overwrite) = &self.overwrite_partition {
+            if new_segments
+                .iter()
+                .any(|location| !overwrite.is_replaced(location))
+            {
+                return Err(ErrorCode::InvalidPartitionKey(
+                    "INSERT OVERWRITE ... PARTITION can not write rows of other partitions",
+                ));
+            }
+        }
+
         if let Some(snapshot) = &previous {
             prev_timestamp = snapshot.timestamp;
             prev_snapshot_id = Some((snapshot.snapshot_id, snapshot.format_version));
@@ -399,10 +514,28 @@ impl SnapshotGenerator for AppendGenerator {
                         });
                 }
 
+                let mut kept_segments = snapshot.segments.clone();
+                if let Some(overwrite) = &self.overwrite_partition {
+                    kept_segments.retain(|location| !overwrite.is_replaced(location));
+                    for location in snapshot.segments.iter() {
+                        if !overwrite.is_replaced(location) {
+                            continue;
+                        }
+                        // a segment of the partition committed by others after `fill_default_values`.
+                        let replaced = overwrite.replaced.get(&location.0).ok_or_else(|| {
+                            ErrorCode::UnresolvableConflict(format!(
+                                "partition was changed during insert overwrite, unexpected segment: {}",
+                                location.0
+                            ))
+                        })?;
+                        deduct_statistics_mut(&mut summary, replaced);
+                    }
+                }
+
                 new_segments = snapshot_merged
                     .merged_segments
                     .iter()
-                    .chain(snapshot.segments.iter())
+                    .chain(kept_segments.iter())
                     .cloned()
                     .collect();
 
@@ -0,0 +1,509 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::AnyType;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use log::info;
+use opendal::Operator;
+use storages_common_index::filters::Filter;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::table::TableConstraints;
+
+use crate::io::key_filter_digest;
+use crate::io::read_segment_key_index_meta;
+use crate::io::write_segment_key_index;
+use crate::io::BlockReader;
+use crate::io::KeyIndexMeta;
+use crate::io::ReadSettings;
+use crate::io::SegmentKeyIndexMeta;
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::replace_into::row_hash_of_columns;
+use crate::operations::replace_into::UniqueKeyDigest;
+use crate::FuseStorageFormat;
+use crate::FuseTable;
+
+/// Checks that the rows appended to a table do not violate its primary key or unique keys.
+///
+/// Every segment of the table has a key index, see [`SegmentKeyIndexMeta`], holding a filter
+/// and the sorted digests of the keys of its rows. The index of an appended segment is written
+/// when the append commits, the segments written by the other operations, like compactions and
+/// mutations, get their indexes when they are checked for the first time. The existing
+/// segments are pruned by the min/max statistics of their summaries and the filters of their
+/// key indexes, then the appended keys are looked up exactly in the digests. Every segment is
+/// checked at most once even if the commit is retried.
+///
+/// The key digests of the appended rows are kept in memory, at most
+/// `unique_key_check_batch_rows` rows at a time. Larger appends are checked batch by batch,
+/// the key indexes of the existing segments are then read once per batch.
+///
+/// Rows having a NULL in any of the key columns are not checked, since NULL is not equal to
+/// any value. Only appends (INSERT, COPY INTO) are checked, mutations like UPDATE and
+/// REPLACE INTO do not check the constraints.
+#[derive(Clone)]
+pub struct UniqueKeyChecker {
+    ctx: Arc<dyn TableContext>,
+    operator: Operator,
+    schema: TableSchemaRef,
+    storage_format: FuseStorageFormat,
+    keys: Vec<UniqueKey>,
+    // the blocks of the appended segments, in batches of bounded number of rows
+    batches: Option<Vec<Vec<AppendedBlock>>>,
+    // the batch whose keys are loaded
+    loaded_batch: Option<usize>,
+    checked_segments: HashSet<Location>,
+}
+
+#[derive(Clone)]
+struct AppendedBlock {
+    segment: Location,
+    // the key index of the segment is written once its last block is loaded
+    last_of_segment: bool,
+    meta: Arc<BlockMeta>,
+}
+
+#[derive(Clone)]
+struct UniqueKey {
+    // e.g. `PRIMARY KEY (a, b)`, used in error messages
+    display_name: String,
+    fields: Vec<TableField>,
+    reader: Arc<BlockReader>,
+    // digests of the keys of the appended rows
+    digests: HashSet<UniqueKeyDigest>,
+    // min/max of each key column of the appended rows
+    min_max: Vec<Option<(Scalar, Scalar)>>,
+    // digests of the keys of the appended segment being loaded, for its key index
+    segment_digests: Vec<UniqueKeyDigest>,
+}
+
+impl UniqueKeyChecker {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        table: &FuseTable,
+        constraints: &TableConstraints,
+    ) -> Result<Self> {
+        let schema = table.schema();
+        let mut keys = Vec::new();
+        for (idx, column_ids) in constraints.keys().enumerate() {
+            let mut fields = Vec::with_capacity(column_ids.len());
+            let mut field_indexes = Vec::with_capacity(column_ids.len());
+            for column_id in column_ids {
+                let (index, field) = schema
+                    .fields()
+                    .iter()
+                    .enumerate()
+                    .find(|(_, f)| f.column_id() == *column_id)
+                    .ok_or_else(|| {
+                        ErrorCode::InvalidTableConstraint(format!(
+                            "column id {} of key constraint does not exist",
+                            column_id
+                        ))
+                    })?;
+                field_indexes.push(index);
+                fields.push(field.clone());
+            }
+
+            let names = fields
+                .iter()
+                .map(|f| f.name().as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let display_name = if idx == 0 && constraints.primary_key.is_some() {
+                format!("PRIMARY KEY ({names})")
+            } else {
+                format!("UNIQUE ({names})")
+            };
+
+            let reader = table.create_block_reader(
+                Projection::Columns(field_indexes),
+                false,
+                ctx.clone(),
+            )?;
+            keys.push(UniqueKey {
+                display_name,
+                min_max: vec![None; fields.len()],
+                fields,
+                reader,
+                digests: HashSet::new(),
+                segment_digests: vec![],
+            });
+        }
+
+        Ok(Self {
+            ctx,
+            operator: table.get_operator(),
+            schema,
+            storage_format: table.storage_format,
+            keys,
+            batches: None,
+            loaded_batch: None,
+            checked_segments: HashSet::new(),
+        })
+    }
+
+    /// Checks the `appended` segments against themselves and the `existing` segments of the table.
+    #[async_backtrace::framed]
+    pub async fn check(&mut self, appended: &[Location], existing: &[Location]) -> Result<()> {
+        let first_check = self.batches.is_none();
+        if first_check {
+            let batch_rows = self
+                .ctx
+                .get_settings()
+                .get_unique_key_check_batch_rows()?
+                .max(1);
+            let mut batches: Vec<Vec<AppendedBlock>> = vec![];
+            let mut rows = 0;
+            let segments = self.read_segments(appended).await?;
+            for (location, segment) in appended.iter().zip(segments) {
+                let num_blocks = segment.blocks.len();
+                for (idx, block) in segment.blocks.into_iter().enumerate() {
+                    if batches.is_empty() || (rows > 0 && rows + block.row_count > batch_rows) {
+                        batches.push(vec![]);
+                        rows = 0;
+                    }
+                    rows += block.row_count;
+                    batches.last_mut().unwrap().push(AppendedBlock {
+                        segment: location.clone(),
+                        last_of_segment: idx + 1 == num_blocks,
+                        meta: block,
+                    });
+                }
+            }
+            self.checked_segments.extend(appended.iter().cloned());
+            self.batches = Some(batches);
+        }
+
+        let segments = existing
+            .iter()
+            .filter(|location| !self.checked_segments.contains(location))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !first_check && segments.is_empty() {
+            return Ok(());
+        }
+
+        let batches = self.batches.clone().unwrap_or_default();
+        let mut num_blocks_read = 0;
+        let mut num_indexes_read = 0;
+        for (idx, batch) in batches.iter().enumerate() {
+            if self.loaded_batch != Some(idx) {
+                // the key indexes of the appended segments are written by the first check.
+                self.load_batch(batch, first_check).await?;
+                self.loaded_batch = Some(idx);
+            }
+            if self.keys.iter().all(|key| key.digests.is_empty()) {
+                continue;
+            }
+
+            // the appended rows of the following batches, the rows of the same batch
+            // have been checked while loading.
+            if first_check {
+                for block in batches[idx + 1..].iter().flatten() {
+                    for key in &self.keys {
+                        num_blocks_read += self.lookup_block(key, &block.meta).await? as usize;
+                    }
+                }
+            }
+
+            if segments.is_empty() {
+                continue;
+            }
+            for (location, segment) in segments.iter().zip(self.read_segments(&segments).await?) {
+                if !self
+                    .keys
+                    .iter()
+                    .any(|key| key.overlapped(&segment.summary.col_stats))
+                {
+                    continue;
+                }
+                let (index_location, index) = self.segment_key_index(location, &segment).await?;
+                for key in &self.keys {
+                    if !key.overlapped(&segment.summary.col_stats) {
+                        continue;
+                    }
+                    let Some(key_index) = index.key(&key.column_ids()) else {
+                        return Err(ErrorCode::StorageOther(format!(
+                            "key index {} misses the key {}",
+                            index_location.0, key.display_name
+                        )));
+                    };
+                    num_indexes_read += key
+                        .lookup_index(&self.operator, &index_location, key_index)
+                        .await? as usize;
+                }
+            }
+        }
+        self.checked_segments.extend(segments.iter().cloned());
+        info!(
+            "unique key check done, {} batches of appended rows, {} segments checked, {} key indexes and {} blocks read",
+            batches.len(),
+            segments.len(),
+            num_indexes_read,
+            num_blocks_read
+        );
+        Ok(())
+    }
+
+    // loads the keys of a batch of the appended blocks, the previous batch is dropped.
+    async fn load_batch(&mut self, batch: &[AppendedBlock], write_index: bool) -> Result<()> {
+        for key in self.keys.iter_mut() {
+            key.reset();
+        }
+        for block in batch {
+            let blocks = self.read_keys(&block.meta).await?;
+            for (key, data) in self.keys.iter_mut().zip(blocks) {
+                key.accumulate(&block.meta, &data, write_index)?;
+            }
+            if write_index && block.last_of_segment {
+                let keys = self
+                    .keys
+                    .iter_mut()
+                    .map(|key| (key.column_ids(), std::mem::take(&mut key.segment_digests)))
+                    .collect();
+                let location =
+                    TableMetaLocationGenerator::segment_key_index_location(&block.segment.0);
+                write_segment_key_index(&self.operator, &location, keys).await?;
+            }
+        }
+        Ok(())
+    }
+
+    // the key index of the segment, the index is built and written if the segment has none.
+    async fn segment_key_index(
+        &self,
+        location: &Location,
+        segment: &SegmentInfo,
+    ) -> Result<(Location, SegmentKeyIndexMeta)> {
+        let index_location = TableMetaLocationGenerator::segment_key_index_location(&location.0);
+        if let Some(index) = read_segment_key_index_meta(&self.operator, &index_location).await? {
+            // the index is rebuilt if the keys of the table are changed since it was written.
+            if self
+                .keys
+                .iter()
+                .all(|key| index.key(&key.column_ids()).is_some())
+            {
+                return Ok((index_location, index));
+            }
+        }
+
+        let mut digests = vec![vec![]; self.keys.len()];
+        for block in &segment.blocks {
+            for (key_digests, data) in digests.iter_mut().zip(self.read_keys(block).await?) {
+                key_digests.extend(block_key_digests(&data)?);
+            }
+        }
+        let keys = self
+            .keys
+            .iter()
+            .zip(digests)
+            .map(|(key, digests)| (key.column_ids(), digests))
+            .collect();
+        let index = write_segment_key_index(&self.operator, &index_location, keys).await?;
+        Ok((index_location, index))
+    }
+
+    // looks up the keys of an appended block if it can not be pruned, returns whether it is read.
+    async fn lookup_block(&self, key: &UniqueKey, block: &BlockMeta) -> Result<bool> {
+        if !key.overlapped(&block.col_stats) {
+            return Ok(false);
+        }
+        let data = key
+            .reader
+            .read_by_meta(
+                &ReadSettings::from_ctx(&self.ctx)?,
+                block,
+                &self.storage_format,
+            )
+            .await?;
+        key.lookup(&data)?;
+        Ok(true)
+    }
+
+    async fn read_segments(&self, locations: &[Location]) -> Result<Vec<SegmentInfo>> {
+        let segments_io =
+            SegmentsIO::create(self.ctx.clone(), self.operator.clone(), self.schema.clone());
+        segments_io
+            .read_segments::<SegmentInfo>(locations, false)
+            .await?
+            .into_iter()
+            .collect()
+    }
+
+    async fn read_keys(&self, block: &BlockMeta) -> Result<Vec<DataBlock>> {
+        let settings = ReadSettings::from_ctx(&self.ctx)?;
+        let mut blocks = Vec::with_capacity(self.keys.len());
+        for key in &self.keys {
+            blocks.push(
+                key.reader
+                    .read_by_meta(&settings, block, &self.storage_format)
+                    .await?,
+            );
+        }
+        Ok(blocks)
+    }
+}
+
+impl UniqueKey {
+    fn reset(&mut self) {
+        self.digests.clear();
+        self.min_max.iter_mut().for_each(|v| *v = None);
+    }
+
+    fn column_ids(&self) -> Vec<ColumnId> {
+        self.fields.iter().map(|f| f.column_id()).collect()
+    }
+
+    /// Collects the keys of an appended block, checking duplicates among the appended rows.
+    fn accumulate(&mut self, block: &BlockMeta, data: &DataBlock, write_index: bool) -> Result<()> {
+        for digest in block_key_digests(data)? {
+            if !self.digests.insert(digest) {
+                return Err(self.violation());
+            }
+            if write_index {
+                self.segment_digests.push(digest);
+            }
+        }
+
+        for (idx, field) in self.fields.iter().enumerate() {
+            if let Some(stats) = block.col_stats.get(&field.column_id()) {
+                merge_min_max(&mut self.min_max[idx], stats);
+            }
+        }
+        Ok(())
+    }
+
+    /// Looks up the keys of a block of the table in the appended keys.
+    fn lookup(&self, data: &DataBlock) -> Result<()> {
+        for digest in block_key_digests(data)? {
+            if self.digests.contains(&digest) {
+                return Err(self.violation());
+            }
+        }
+        Ok(())
+    }
+
+    /// Looks up the appended keys in the key index of a segment, the digests are only read if
+    /// the filter may contain any of the appended keys. Returns whether the digests are read.
+    async fn lookup_index(
+        &self,
+        operator: &Operator,
+        location: &Location,
+        index: &KeyIndexMeta,
+    ) -> Result<bool> {
+        let Some(filter) = index.read_filter(operator, location).await? else {
+            return Ok(false);
+        };
+        if !self
+            .digests
+            .iter()
+            .any(|digest| filter.contains_digest(key_filter_digest(*digest)))
+        {
+            return Ok(false);
+        }
+
+        let digests = index.read_digests(operator, location).await?;
+        if self
+            .digests
+            .iter()
+            .any(|digest| digests.binary_search(digest).is_ok())
+        {
+            return Err(self.violation());
+        }
+        Ok(true)
+    }
+
+    // if the min/max of any key column does NOT overlap with the appended rows, returns false.
+    fn overlapped(&self, col_stats: &HashMap<ColumnId, ColumnStatistics>) -> bool {
+        if self.digests.is_empty() {
+            return false;
+        }
+        self.fields
+            .iter()
+            .zip(self.min_max.iter())
+            .all(
+                |(field, min_max)| match (col_stats.get(&field.column_id()), min_max) {
+                    (Some(stats), Some((min, max))) => {
+                        std::cmp::min(max, &stats.max) >= std::cmp::max(min, &stats.min)
+                    }
+                    _ => true,
+                },
+            )
+    }
+
+    fn violation(&self) -> ErrorCode {
+        ErrorCode::UniqueConstraintViolation(format!(
+            "duplicate key violates constraint {}",
+            self.display_name
+        ))
+    }
+}
+
+// the digests of the keys of the rows of a block, the rows having a NULL key are left out.
+fn block_key_digests(data: &DataBlock) -> Result<Vec<UniqueKeyDigest>> {
+    let columns = data
+        .columns()
+        .iter()
+        .map(|entry| &entry.value)
+        .collect::<Vec<_>>();
+    let mut digests = Vec::with_capacity(data.num_rows());
+    for row in 0..data.num_rows() {
+        if let Some(digest) = key_digest(&columns, row)? {
+            digests.push(digest);
+        }
+    }
+    Ok(digests)
+}
+
+// the digest of the key of the given row, None if any of the key columns is NULL.
+fn key_digest(columns: &[&Value<AnyType>], row: usize) -> Result<Option<UniqueKeyDigest>> {
+    if columns
+        .iter()
+        .any(|column| matches!(column.index(row), Some(ScalarRef::Null)))
+    {
+        return Ok(None);
+    }
+    row_hash_of_columns(columns, row).map(Some)
+}
+
+fn merge_min_max(min_max: &mut Option<(Scalar, Scalar)>, stats: &ColumnStatistics) {
+    match min_max {
+        Some((min, max)) => {
+            if stats.min < *min {
+                *min = stats.min.clone();
+            }
+            if stats.max > *max {
+                *max = stats.max.clone();
+            }
+        }
+        None => *min_max = Some((stats.min.clone(), stats.max.clone())),
+    }
+}
@@ -125,6 +125,9 @@ impl FuseTable {
             self.operator.clone(),
             self.cluster_key_meta.as_ref().map(|k| k.0),
         );
+        mutator.inverted_index_required = !self
+            .inverted_index_fields(&self.table_info.schema().remove_virtual_computed_fields())?
+            .is_empty();
         mutator.target_select().await?;
         if mutator.compact_tasks.is_empty() {
             return Ok(());
@@ -245,6 +245,9 @@ impl FuseTable {
         let max_threads =
             std::cmp::min(ctx.get_settings().get_max_threads()? as usize, total_tasks);
         let max_threads = std::cmp::max(max_threads, 1);
+        let deletion_vector_gen = self
+            .deletion_vector_enabled()
+            .then(|| self.meta_location_generator().clone());
         // Add source pipe.
         pipeline.add_source(
             |output| {
@@ -258,6 +261,7 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     query_row_id_col,
+                    deletion_vector_gen.clone(),
                 )
             },
             max_threads,
@@ -301,10 +305,15 @@ impl FuseTable {
             self.table_info.schema(),
             &push_down,
             self.bloom_index_cols(),
+            self.ngram_index_cols(),
         )?;
 
         let segment_locations = create_segment_location_vector(segment_locations, None);
 
+        if let Some(partition_pruner) = self.partition_pruner(ctx.clone(), filter.as_ref())? {
+            pruner.set_partition_pruner(partition_pruner);
+        }
+
         if let Some(inverse) = inverted_filter {
             // now the `block_metas` refers to the blocks that need to be deleted completely or partially.
             //
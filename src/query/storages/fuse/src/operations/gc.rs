@@ -517,10 +517,17 @@ impl FuseTable {
             .await?;
         }
 
-        // 3. Try to purge segment file chunks.
+        // 3. Try to purge segment file chunks, along with the key indexes of the segments.
         let segments_count = segments_to_be_purged.len();
         if segments_count > 0 {
             counter.segments += segments_count;
+            // The key indexes may be left by constraints dropped since, missing ones are ignored.
+            let key_indexes_to_be_purged = segments_to_be_purged
+                .iter()
+                .map(|location| TableMetaLocationGenerator::segment_key_index_location(location).0)
+                .collect();
+            self.try_purge_location_files(ctx.clone(), key_indexes_to_be_purged)
+                .await?;
             self.try_purge_location_files_and_cache::<CompactSegmentInfo, _, _>(
                 ctx.clone(),
                 segments_to_be_purged,
@@ -669,6 +676,7 @@ struct RootSnapshotInfo {
 #[derive(Default)]
 pub struct LocationTuple {
     pub block_location: HashSet<String>,
+    // locations of the block level index files, bloom filters, inverted and vector indexes.
     pub bloom_location: HashSet<String>,
 }
 
@@ -681,6 +689,15 @@ impl From<SegmentInfo> for LocationTuple {
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            if let Some(inverted_loc) = &block_meta.inverted_index_location {
+                bloom_location.insert(inverted_loc.0.clone());
+            }
+            if let Some(vector_loc) = &block_meta.vector_index_location {
+                bloom_location.insert(vector_loc.0.clone());
+            }
+            if let Some(dv_loc) = &block_meta.deletion_vector_location {
+                bloom_location.insert(dv_loc.0.clone());
+            }
         }
         Self {
             block_location,
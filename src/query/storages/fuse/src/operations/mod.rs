@@ -22,6 +22,7 @@ mod delete;
 mod gc;
 mod mutation;
 mod navigate;
+mod partition;
 mod read;
 mod read_data;
 mod read_partitions;
@@ -45,5 +46,9 @@ pub use mutation::ReclusterMutator;
 pub use mutation::SegmentCompactMutator;
 pub use mutation::SegmentCompactionState;
 pub use mutation::SegmentCompactor;
+pub use partition::decode_partition_value;
+pub use partition::encode_partition_value;
+pub use partition::PartitionMeta;
+pub use partition::PartitionedBlockMeta;
 pub use read::build_row_fetcher_pipeline;
 pub use util::column_parquet_metas;
@@ -33,6 +33,7 @@ use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Statistics;
 
 use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::CompactPartInfo;
 use crate::operations::mutation::MAX_BLOCK_COUNT;
@@ -49,6 +50,8 @@ pub struct BlockCompactMutator {
     pub compact_params: CompactOptions,
     pub column_ids: HashSet<ColumnId>,
     pub cluster_key_id: Option<u32>,
+    // Whether the blocks should carry an inverted index, blocks without one are rewritten.
+    pub inverted_index_required: bool,
 
     // A set of Parts.
     pub compact_tasks: Partitions,
@@ -57,6 +60,8 @@ pub struct BlockCompactMutator {
     pub unchanged_segments_map: BTreeMap<usize, Location>,
     // summarised statistics of all the unchanged segments
     pub unchanged_segment_statistics: Statistics,
+    // encoded partition of the compacted segments, for partitioned table.
+    pub segment_partitions: HashMap<usize, String>,
 }
 
 impl BlockCompactMutator {
@@ -76,10 +81,12 @@ impl BlockCompactMutator {
             compact_params,
             column_ids,
             cluster_key_id,
+            inverted_index_required: false,
             unchanged_blocks_map: HashMap::new(),
             compact_tasks: Partitions::create_nolazy(PartitionsShuffleKind::Mod, vec![]),
             unchanged_segments_map: BTreeMap::new(),
             unchanged_segment_statistics,
+            segment_partitions: HashMap::new(),
         }
     }
 
@@ -87,7 +94,9 @@ impl BlockCompactMutator {
     pub async fn target_select(&mut self) -> Result<()> {
         let start = Instant::now();
         let snapshot = self.compact_params.base_snapshot.clone();
-        let segment_locations = &snapshot.segments;
+        // Segments of different partitions can not be compacted together.
+        let mut segment_locations = snapshot.segments.clone();
+        TableMetaLocationGenerator::sort_segments_by_partition(&mut segment_locations);
         let number_segments = segment_locations.len();
         let limit = self.compact_params.limit.unwrap_or(number_segments);
 
@@ -120,14 +129,19 @@ impl BlockCompactMutator {
                 let segment = segment?;
                 let segments_vec = checker.add(chunk[idx].clone(), segment.clone());
                 for segments in segments_vec {
-                    if SegmentCompactChecker::check_for_compact(&segments) {
+                    if SegmentCompactChecker::check_for_compact(
+                        &segments,
+                        self.inverted_index_required,
+                    ) {
                         compacted_segment_cnt += segments.len();
                         compacted_block_cnt +=
                             segments.iter().fold(0, |acc, x| acc + x.1.blocks.len());
                         // build the compact tasks.
+                        let partition = segment_partition(&segments);
                         self.build_compact_tasks(
                             segments.into_iter().map(|s| s.1).collect(),
                             segment_idx,
+                            partition,
                         );
                     } else {
                         self.unchanged_segments_map
@@ -163,9 +177,14 @@ impl BlockCompactMutator {
         // finalize the compaction.
         if !checker.segments.is_empty() {
             let segments = std::mem::take(&mut checker.segments);
-            if SegmentCompactChecker::check_for_compact(&segments) {
+            if SegmentCompactChecker::check_for_compact(&segments, self.inverted_index_required) {
                 compacted_segment_cnt += segments.len();
-                self.build_compact_tasks(segments.into_iter().map(|s| s.1).collect(), segment_idx);
+                let partition = segment_partition(&segments);
+                self.build_compact_tasks(
+                    segments.into_iter().map(|s| s.1).collect(),
+                    segment_idx,
+                    partition,
+                );
             } else {
                 self.unchanged_segments_map
                     .insert(segment_idx, segment_locations[checked_end_at - 1].clone());
@@ -193,8 +212,17 @@ impl BlockCompactMutator {
     // Select the row_count >= min_rows_per_block or block_size >= max_bytes_per_block
     // as the perfect_block condition(N for short). Gets a set of segments, iterates
     // through the blocks, and finds the blocks >= N and blocks < 2N as a task.
-    fn build_compact_tasks(&mut self, segments: Vec<Arc<SegmentInfo>>, segment_idx: usize) {
-        let mut builder = CompactTaskBuilder::new(self.column_ids.clone(), self.cluster_key_id);
+    fn build_compact_tasks(
+        &mut self,
+        segments: Vec<Arc<SegmentInfo>>,
+        segment_idx: usize,
+        partition: Option<String>,
+    ) {
+        let mut builder = CompactTaskBuilder::new(
+            self.column_ids.clone(),
+            self.cluster_key_id,
+            self.inverted_index_required,
+        );
         let mut tasks = VecDeque::new();
         let mut block_idx = 0;
         // Used to identify whether the latest block is unchanged or needs to be compacted.
@@ -282,11 +310,23 @@ impl BlockCompactMutator {
             self.unchanged_blocks_map
                 .insert(segment_idx, unchanged_blocks);
         }
+        if let Some(partition) = partition {
+            self.segment_partitions.insert(segment_idx, partition);
+        }
     }
 }
 
+fn segment_partition(segments: &[(Location, Arc<SegmentInfo>)]) -> Option<String> {
+    segments
+        .first()
+        .and_then(|(location, _)| TableMetaLocationGenerator::segment_partition(&location.0))
+        .map(|v| v.to_string())
+}
+
 struct SegmentCompactChecker {
     segments: Vec<(Location, Arc<SegmentInfo>)>,
+    // the partition of the pending segments.
+    partition: Option<String>,
     total_block_count: u64,
     threshold: u64,
 }
@@ -297,13 +337,28 @@ impl SegmentCompactChecker {
             threshold,
             total_block_count: 0,
             segments: vec![],
+            partition: None,
         }
     }
 
-    fn check_for_compact(segments: &Vec<(Location, Arc<SegmentInfo>)>) -> bool {
+    fn check_for_compact(
+        segments: &Vec<(Location, Arc<SegmentInfo>)>,
+        inverted_index_required: bool,
+    ) -> bool {
         segments.len() != 1
             || (segments[0].1.summary.block_count > 1
                 && segments[0].1.summary.perfect_block_count != segments[0].1.summary.block_count)
+            || (inverted_index_required
+                && segments[0]
+                    .1
+                    .blocks
+                    .iter()
+                    .any(|b| b.inverted_index_location.is_none()))
+            || segments[0]
+                .1
+                .blocks
+                .iter()
+                .any(|b| b.deletion_vector_location.is_some())
     }
 
     fn add(
@@ -311,31 +366,43 @@ impl SegmentCompactChecker {
         location: Location,
         segment: Arc<SegmentInfo>,
     ) -> Vec<Vec<(Location, Arc<SegmentInfo>)>> {
+        let mut groups = vec![];
+        // The pending segments belong to another partition, they are finished.
+        let partition = TableMetaLocationGenerator::segment_partition(&location.0);
+        if self.partition.as_deref() != partition {
+            if !self.segments.is_empty() {
+                groups.push(std::mem::take(&mut self.segments));
+            }
+            self.total_block_count = 0;
+            self.partition = partition.map(|v| v.to_string());
+        }
+
         self.total_block_count += segment.summary.block_count;
         if self.total_block_count < self.threshold {
             self.segments.push((location, segment));
-            return vec![];
+            return groups;
         }
 
         if self.total_block_count > 2 * self.threshold {
             self.total_block_count = 0;
-            let trivial = vec![(location, segment)];
-            if self.segments.is_empty() {
-                return vec![trivial];
-            } else {
-                return vec![std::mem::take(&mut self.segments), trivial];
+            if !self.segments.is_empty() {
+                groups.push(std::mem::take(&mut self.segments));
             }
+            groups.push(vec![(location, segment)]);
+            return groups;
         }
 
         self.total_block_count = 0;
         self.segments.push((location, segment));
-        vec![std::mem::take(&mut self.segments)]
+        groups.push(std::mem::take(&mut self.segments));
+        groups
     }
 }
 
 struct CompactTaskBuilder {
     column_ids: HashSet<ColumnId>,
     cluster_key_id: Option<u32>,
+    inverted_index_required: bool,
 
     blocks: Vec<Arc<BlockMeta>>,
     total_rows: usize,
@@ -343,10 +410,15 @@ struct CompactTaskBuilder {
 }
 
 impl CompactTaskBuilder {
-    fn new(column_ids: HashSet<ColumnId>, cluster_key_id: Option<u32>) -> Self {
+    fn new(
+        column_ids: HashSet<ColumnId>,
+        cluster_key_id: Option<u32>,
+        inverted_index_required: bool,
+    ) -> Self {
         Self {
             column_ids,
             cluster_key_id,
+            inverted_index_required,
             blocks: vec![],
             total_rows: 0,
             total_size: 0,
@@ -372,7 +444,7 @@ impl CompactTaskBuilder {
             }
         }
 
-        let total_rows = self.total_rows + block.row_count as usize;
+        let total_rows = self.total_rows + block.live_row_count() as usize;
         let total_size = self.total_size + block.block_size as usize;
         if !thresholds.check_large_enough(total_rows, total_size) {
             // blocks < N
@@ -408,6 +480,16 @@ impl CompactTaskBuilder {
     }
 
     fn check_compact(&self, block: &Arc<BlockMeta>) -> bool {
+        if self.inverted_index_required && block.inverted_index_location.is_none() {
+            // Rewrite the block to build the missing inverted index.
+            return true;
+        }
+
+        if block.deletion_vector_location.is_some() {
+            // Rewrite the block to purge the deleted rows.
+            return true;
+        }
+
         let column_ids: HashSet<ColumnId> = block.col_metas.keys().cloned().collect();
         if self.column_ids == column_ids {
             // Check if the block needs to be resort.
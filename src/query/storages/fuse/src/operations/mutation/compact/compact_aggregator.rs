@@ -58,6 +58,8 @@ pub struct CompactAggregator {
     merged_statistics: Statistics,
     // locations all the merged blocks.
     merge_blocks: HashMap<usize, BTreeMap<usize, Arc<BlockMeta>>>,
+    // encoded partition of the merged segments.
+    segment_partitions: HashMap<usize, String>,
     abort_operation: AbortOperation,
 
     start_time: Instant,
@@ -78,6 +80,7 @@ impl CompactAggregator {
             merged_segments: mutator.unchanged_segments_map,
             merged_statistics: mutator.unchanged_segment_statistics,
             merge_blocks: mutator.unchanged_blocks_map,
+            segment_partitions: mutator.segment_partitions,
             thresholds: mutator.thresholds,
             abort_operation: AbortOperation::default(),
             start_time: Instant::now(),
@@ -138,7 +141,10 @@ impl AsyncAccumulatingTransform for CompactAggregator {
                 self.default_cluster_key_id,
             );
             let new_segment = SegmentInfo::new(blocks, new_summary);
-            let location = self.location_gen.gen_segment_info_location();
+            let partition = self.segment_partitions.get(&segment_idx);
+            let location = self
+                .location_gen
+                .gen_segment_info_location_of_partition(partition.map(|v| v.as_str()));
             self.abort_operation.add_segment(location.clone());
             self.merged_segments
                 .insert(segment_idx, (location.clone(), SegmentInfo::VERSION));
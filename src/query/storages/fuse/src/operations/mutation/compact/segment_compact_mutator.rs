@@ -88,6 +88,8 @@ impl SegmentCompactMutator {
         // will be left at the "top", and likely to be merged in the next compaction; instead of leaving
         // an unmergeable fragmented segment in the middle.
         base_segment_locations.reverse();
+        // segments of different partitions can not be merged, make them adjacent.
+        TableMetaLocationGenerator::sort_segments_by_partition(&mut base_segment_locations);
 
         // need at lease 2 segments to make sense
         let num_segments = base_segment_locations.len();
@@ -280,6 +282,15 @@ impl<'a> SegmentCompactor<'a> {
             return Ok(());
         }
 
+        // the fragments collected so far belong to another partition.
+        if let Some((_, last)) = self.fragmented_segments.last() {
+            if TableMetaLocationGenerator::segment_partition(&last.0)
+                != TableMetaLocationGenerator::segment_partition(&location.0)
+            {
+                self.compact_fragments().await?;
+            }
+        }
+
         let s = self.accumulated_num_blocks + num_blocks_current_segment;
 
         if s < self.threshold {
@@ -326,6 +337,8 @@ impl<'a> SegmentCompactor<'a> {
         // 2.1 merge fragmented segments into new segment, and update the statistics
         let mut blocks = Vec::with_capacity(self.threshold as usize);
         let mut new_statistics = Statistics::default();
+        let partition =
+            TableMetaLocationGenerator::segment_partition(&fragments[0].1.0).map(|v| v.to_string());
 
         self.compacted_state.num_fragments_compacted += fragments.len();
         for (segment, _location) in fragments {
@@ -339,7 +352,10 @@ impl<'a> SegmentCompactor<'a> {
 
         // 2.2 write down new segment
         let new_segment = SegmentInfo::new(blocks, new_statistics);
-        let location = self.segment_writer.write_segment(new_segment).await?;
+        let location = self
+            .segment_writer
+            .write_segment_of_partition(new_segment, partition.as_deref())
+            .await?;
         self.compacted_state
             .new_segment_paths
             .push(location.0.clone());
@@ -19,6 +19,7 @@ use common_expression::BlockMetaInfoDowncast;
 use common_expression::BlockMetaInfoPtr;
 use storages_common_table_meta::meta::ClusterStatistics;
 
+use crate::operations::common::BlockDeletionVector;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::MutationDeletedSegment;
 
@@ -33,6 +34,9 @@ pub struct SerializeDataMeta {
     pub index: BlockMetaIndex,
     pub stats_type: ClusterStatsGenType,
     pub deleted_segment: Option<MutationDeletedSegment>,
+    // Some if the old rows are marked in a deletion vector, the block is appended instead of
+    // replacing the old one.
+    pub deletion_vector: Option<BlockDeletionVector>,
 }
 
 #[typetag::serde(name = "serialize_data_meta")]
@@ -59,6 +63,20 @@ impl SerializeDataMeta {
             index,
             stats_type,
             deleted_segment: None,
+            deletion_vector: None,
+        })
+    }
+
+    pub fn create_with_deletion_vector(
+        index: BlockMetaIndex,
+        stats_type: ClusterStatsGenType,
+        deletion_vector: BlockDeletionVector,
+    ) -> BlockMetaInfoPtr {
+        Box::new(SerializeDataMeta {
+            index,
+            stats_type,
+            deleted_segment: None,
+            deletion_vector: Some(deletion_vector),
         })
     }
 
@@ -69,6 +87,7 @@ impl SerializeDataMeta {
             index: BlockMetaIndex::default(),           // default value
             stats_type: ClusterStatsGenType::Generally, // default value
             deleted_segment: Some(deleted_segment),
+            deletion_vector: None,
         })
     }
 }
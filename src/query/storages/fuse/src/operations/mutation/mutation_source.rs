@@ -16,6 +16,8 @@ use std::any::Any;
 use std::ops::Not;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
 use common_base::base::ProgressValues;
 use common_catalog::plan::InternalColumn;
 use common_catalog::plan::InternalColumnMeta;
@@ -30,15 +32,23 @@ use common_expression::BlockEntry;
 use common_expression::DataBlock;
 use common_expression::Evaluator;
 use common_expression::Expr;
+use common_expression::Scalar;
 use common_expression::Value;
 use common_expression::ROW_ID_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::evaluator::BlockOperator;
 
 use crate::fuse_part::FusePartInfo;
+use crate::io::live_row_offsets;
+use crate::io::read_deletion_vector;
+use crate::io::write_deletion_vector;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::common::BlockDeletionVector;
 use crate::operations::common::BlockMetaIndex;
+use crate::operations::common::MutationLogEntry;
+use crate::operations::common::MutationLogs;
 use crate::operations::mutation::mutation_meta::ClusterStatsGenType;
 use crate::operations::mutation::Mutation;
 use crate::operations::mutation::SerializeDataMeta;
@@ -69,6 +79,14 @@ enum State {
         filter: Option<Value<BooleanType>>,
     },
     PerformOperator(DataBlock),
+    WriteDeletionVector(Bitmap),
+    // the old rows are marked in the deletion vector, the updated ones are appended.
+    UpdateDeletionVector {
+        part: PartInfoPtr,
+        deleted: Bitmap,
+        data_block: DataBlock,
+        filter: Value<BooleanType>,
+    },
     Output(Option<PartInfoPtr>, DataBlock),
     Finish,
 }
@@ -85,9 +103,15 @@ pub struct MutationSource {
     storage_format: FuseStorageFormat,
     action: MutationAction,
     query_row_id_col: bool,
+    // Some if the deleted rows are marked in deletion vectors instead of rewriting the block.
+    deletion_vector_gen: Option<TableMetaLocationGenerator>,
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
+    // the rows of the current block that have already been deleted.
+    deleted: Option<Bitmap>,
+    // the deletion vector written for the updated rows of the current block.
+    deletion_vector: Option<BlockDeletionVector>,
 }
 
 impl MutationSource {
@@ -102,6 +126,7 @@ impl MutationSource {
         operators: Vec<BlockOperator>,
         storage_format: FuseStorageFormat,
         query_row_id_col: bool,
+        deletion_vector_gen: Option<TableMetaLocationGenerator>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(MutationSource {
             state: State::ReadData(None),
@@ -114,10 +139,50 @@ impl MutationSource {
             storage_format,
             action,
             query_row_id_col,
+            deletion_vector_gen,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
+            deleted: None,
+            deletion_vector: None,
         })))
     }
+
+    /// Marks the rows hit by the predicates in the deletion vector of the block.
+    ///
+    /// `predicates` is evaluated on the live rows, which are mapped back to the
+    /// physical offsets of the block.
+    fn merge_deletion_vector(&self, predicates: &Bitmap, num_block_rows: usize) -> Bitmap {
+        let (mut deleted, offsets) = match &self.deleted {
+            Some(deleted) => (
+                MutableBitmap::from_iter(deleted.iter()),
+                live_row_offsets(deleted),
+            ),
+            None => (
+                MutableBitmap::from_len_zeroed(num_block_rows),
+                (0..num_block_rows).collect(),
+            ),
+        };
+        for (i, hit) in predicates.iter().enumerate() {
+            if hit {
+                deleted.set(offsets[i], true);
+            }
+        }
+        deleted.into()
+    }
+
+    async fn write_block_deletion_vector(&self, deleted: &Bitmap) -> Result<BlockDeletionVector> {
+        let location_gen = self
+            .deletion_vector_gen
+            .as_ref()
+            .ok_or(ErrorCode::Internal("It's a bug. Need location generator"))?;
+        let location = location_gen.gen_deletion_vector_location();
+        let size = write_deletion_vector(&self.block_reader.operator, &location, deleted).await?;
+        Ok(BlockDeletionVector {
+            location,
+            size,
+            deleted_row_count: (deleted.len() - deleted.unset_bits()) as u64,
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -165,7 +230,13 @@ impl Processor for MutationSource {
             }
         }
 
-        if matches!(self.state, State::ReadData(_) | State::ReadRemain { .. }) {
+        if matches!(
+            self.state,
+            State::ReadData(_)
+                | State::ReadRemain { .. }
+                | State::WriteDeletionVector(_)
+                | State::UpdateDeletionVector { .. }
+        ) {
             Ok(Event::Async)
         } else {
             Ok(Event::Sync)
@@ -181,6 +252,11 @@ impl Processor for MutationSource {
                     chunks,
                     &self.storage_format,
                 )?;
+                let num_block_rows = data_block.num_rows();
+                // Skip the rows that have been deleted before.
+                if let Some(deleted) = &self.deleted {
+                    data_block = data_block.filter_with_bitmap(&deleted.not())?;
+                }
                 let num_rows = data_block.num_rows();
 
                 if let Some(filter) = self.filter.as_ref() {
@@ -194,7 +270,7 @@ impl Processor for MutationSource {
                             block_location: block_meta.block_location.clone(),
                             segment_location: block_meta.segment_location.clone(),
                             snapshot_location: "".to_string(),
-                            offsets: None,
+                            offsets: self.deleted.as_ref().map(live_row_offsets),
                         };
                         let internal_col = InternalColumn {
                             column_name: ROW_ID_COL_NAME.to_string(),
@@ -250,6 +326,12 @@ impl Processor for MutationSource {
                                         self.ctx.get_partition(),
                                         DataBlock::empty_with_meta(meta),
                                     );
+                                } else if self.deletion_vector_gen.is_some() {
+                                    // keep the block, only mark the deleted rows.
+                                    let predicate_col = predicates.into_column().unwrap();
+                                    let deleted =
+                                        self.merge_deletion_vector(&predicate_col, num_block_rows);
+                                    self.state = State::WriteDeletionVector(deleted);
                                 } else {
                                     let predicate_col = predicates.into_column().unwrap();
                                     let filter = predicate_col.not();
@@ -265,6 +347,28 @@ impl Processor for MutationSource {
                                     }
                                 }
                             }
+                            MutationAction::Update
+                                if affect_rows != num_rows
+                                    && self.deletion_vector_gen.is_some() =>
+                            {
+                                // mark the old rows in the deletion vector, only the updated
+                                // rows are written into a new block.
+                                let predicate_col = predicates.into_column().unwrap();
+                                let deleted =
+                                    self.merge_deletion_vector(&predicate_col, num_block_rows);
+                                let mut data_block =
+                                    data_block.filter_with_bitmap(&predicate_col)?;
+                                data_block.add_column(BlockEntry::new(
+                                    DataType::Boolean,
+                                    Value::Scalar(Scalar::Boolean(true)),
+                                ));
+                                self.state = State::UpdateDeletionVector {
+                                    part,
+                                    deleted,
+                                    data_block,
+                                    filter: Value::Column(predicate_col),
+                                };
+                            }
                             MutationAction::Update => {
                                 data_block.add_column(BlockEntry::new(
                                     DataType::Boolean,
@@ -308,6 +412,10 @@ impl Processor for MutationSource {
                         chunks,
                         &self.storage_format,
                     )?;
+                    let remain_block = match &self.deleted {
+                        Some(deleted) => remain_block.filter_with_bitmap(&deleted.not())?,
+                        None => remain_block,
+                    };
 
                     let remain_block = if let Some(filter) = filter {
                         // for deletion.
@@ -331,7 +439,14 @@ impl Processor for MutationSource {
                     .operators
                     .iter()
                     .try_fold(data_block, |input, op| op.execute(&func_ctx, input))?;
-                let meta = SerializeDataMeta::create(self.index.clone(), self.stats_type.clone());
+                let meta = match self.deletion_vector.take() {
+                    Some(deletion_vector) => SerializeDataMeta::create_with_deletion_vector(
+                        self.index.clone(),
+                        self.stats_type.clone(),
+                        deletion_vector,
+                    ),
+                    None => SerializeDataMeta::create(self.index.clone(), self.stats_type.clone()),
+                };
                 self.state = State::Output(self.ctx.get_partition(), block.add_meta(Some(meta))?);
             }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
@@ -372,13 +487,23 @@ impl Processor for MutationSource {
 
                         let inner_part = part.inner_part.clone();
                         let fuse_part = FusePartInfo::from_part(&inner_part)?;
+                        self.deleted = match &fuse_part.deletion_vector {
+                            Some(location) => Some(
+                                read_deletion_vector(&self.block_reader.operator, location).await?,
+                            ),
+                            None => None,
+                        };
 
                         if part.whole_block_mutation
                             && matches!(self.action, MutationAction::Deletion)
                         {
                             // whole block deletion.
+                            let deleted_rows = self
+                                .deleted
+                                .as_ref()
+                                .map_or(0, |deleted| deleted.len() - deleted.unset_bits());
                             let progress_values = ProgressValues {
-                                rows: fuse_part.nums_rows,
+                                rows: fuse_part.nums_rows - deleted_rows,
                                 bytes: 0,
                             };
                             self.ctx.get_write_progress().incr(&progress_values);
@@ -404,6 +529,36 @@ impl Processor for MutationSource {
                     }
                 }
             }
+            State::WriteDeletionVector(deleted) => {
+                let entry = MutationLogEntry::DeletionVector {
+                    index: self.index.clone(),
+                    deletion_vector: self.write_block_deletion_vector(&deleted).await?,
+                };
+                self.state = State::Output(
+                    self.ctx.get_partition(),
+                    MutationLogs {
+                        entries: vec![entry],
+                    }
+                    .into(),
+                );
+            }
+            State::UpdateDeletionVector {
+                part,
+                deleted,
+                data_block,
+                filter,
+            } => {
+                self.deletion_vector = Some(self.write_block_deletion_vector(&deleted).await?);
+                if self.remain_reader.is_none() {
+                    self.state = State::PerformOperator(data_block);
+                } else {
+                    self.state = State::ReadRemain {
+                        part,
+                        data_block,
+                        filter: Some(filter),
+                    };
+                }
+            }
             State::ReadRemain {
                 part,
                 data_block,
@@ -101,7 +101,7 @@ impl ReclusterMutator {
                         .and_modify(|v| v.1.push(i))
                         .or_insert((vec![], vec![i]));
                 }
-                total_rows += meta.row_count;
+                total_rows += meta.live_row_count();
                 total_bytes += meta.block_size;
             }
 
@@ -198,7 +198,7 @@ impl ReclusterMutator {
                 self.mutation_logs
                     .entries
                     .push(MutationLogEntry::DeletedBlock { index: block_idx });
-                self.total_rows += block_meta.row_count as usize;
+                self.total_rows += block_meta.live_row_count() as usize;
                 self.total_bytes = memory_usage;
                 self.selected_blocks.push(block_meta);
             }
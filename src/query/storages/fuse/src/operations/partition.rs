@@ -0,0 +1,459 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::number::NumberScalar;
+use common_expression::BlockMetaInfo;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::pipe::PipeItem;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransform;
+use common_pipeline_transforms::processors::transforms::AccumulatingTransformer;
+use common_sql::parse_exprs;
+use ethnum::i256;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
+use uuid::Uuid;
+
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::common::AppendGenerator;
+use crate::pruning::PartitionPruner;
+use crate::statistics::reducers::deduct_statistics_mut;
+use crate::FuseTable;
+
+// The tags of the encoded partition values, followed by the little-endian bytes of the value.
+const TAG_NULL: u8 = 0;
+const TAG_BOOLEAN: u8 = 1;
+const TAG_UINT8: u8 = 2;
+const TAG_UINT16: u8 = 3;
+const TAG_UINT32: u8 = 4;
+const TAG_UINT64: u8 = 5;
+const TAG_INT8: u8 = 6;
+const TAG_INT16: u8 = 7;
+const TAG_INT32: u8 = 8;
+const TAG_INT64: u8 = 9;
+const TAG_FLOAT32: u8 = 10;
+const TAG_FLOAT64: u8 = 11;
+const TAG_DECIMAL128: u8 = 12;
+const TAG_DECIMAL256: u8 = 13;
+const TAG_TIMESTAMP: u8 = 14;
+const TAG_DATE: u8 = 15;
+
+/// Encode the partition value into a string which can be used in file names.
+///
+/// Only fixed width values are supported, the encoded value is at most 70 characters.
+pub fn encode_partition_value(value: &Scalar) -> Result<String> {
+    let bytes = match value {
+        Scalar::Null => vec![TAG_NULL],
+        Scalar::Boolean(v) => vec![TAG_BOOLEAN, *v as u8],
+        Scalar::Number(v) => match v {
+            NumberScalar::UInt8(v) => tagged(TAG_UINT8, &v.to_le_bytes()),
+            NumberScalar::UInt16(v) => tagged(TAG_UINT16, &v.to_le_bytes()),
+            NumberScalar::UInt32(v) => tagged(TAG_UINT32, &v.to_le_bytes()),
+            NumberScalar::UInt64(v) => tagged(TAG_UINT64, &v.to_le_bytes()),
+            NumberScalar::Int8(v) => tagged(TAG_INT8, &v.to_le_bytes()),
+            NumberScalar::Int16(v) => tagged(TAG_INT16, &v.to_le_bytes()),
+            NumberScalar::Int32(v) => tagged(TAG_INT32, &v.to_le_bytes()),
+            NumberScalar::Int64(v) => tagged(TAG_INT64, &v.to_le_bytes()),
+            NumberScalar::Float32(v) => tagged(TAG_FLOAT32, &v.0.to_le_bytes()),
+            NumberScalar::Float64(v) => tagged(TAG_FLOAT64, &v.0.to_le_bytes()),
+        },
+        Scalar::Decimal(DecimalScalar::Decimal128(v, size)) => {
+            let mut bytes = vec![TAG_DECIMAL128, size.precision, size.scale];
+            bytes.extend_from_slice(&v.to_le_bytes());
+            bytes
+        }
+        Scalar::Decimal(DecimalScalar::Decimal256(v, size)) => {
+            let mut bytes = vec![TAG_DECIMAL256, size.precision, size.scale];
+            bytes.extend_from_slice(&v.to_le_bytes());
+            bytes
+        }
+        Scalar::Timestamp(v) => tagged(TAG_TIMESTAMP, &v.to_le_bytes()),
+        Scalar::Date(v) => tagged(TAG_DATE, &v.to_le_bytes()),
+        _ => {
+            return Err(ErrorCode::InvalidPartitionKey(format!(
+                "Unsupported partition value {}",
+                value
+            )));
+        }
+    };
+    Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+pub fn decode_partition_value(encoded: &str) -> Result<Scalar> {
+    let invalid = || ErrorCode::Internal(format!("Invalid encoded partition value: {}", encoded));
+    if encoded.len() % 2 != 0 || !encoded.is_ascii() {
+        return Err(invalid());
+    }
+    let bytes = (0..encoded.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&encoded[i..i + 2], 16))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|_| invalid())?;
+    let (tag, payload) = bytes.split_first().ok_or_else(invalid)?;
+
+    macro_rules! le {
+        ($ty:ty, $payload:expr) => {
+            <$ty>::from_le_bytes($payload.try_into().map_err(|_| invalid())?)
+        };
+    }
+    let decimal_size = |payload: &[u8]| match payload {
+        [precision, scale, ..] => Ok(DecimalSize {
+            precision: *precision,
+            scale: *scale,
+        }),
+        _ => Err(invalid()),
+    };
+    let value = match *tag {
+        TAG_NULL if payload.is_empty() => Scalar::Null,
+        TAG_BOOLEAN => Scalar::Boolean(le!(u8, payload) != 0),
+        TAG_UINT8 => Scalar::Number(NumberScalar::UInt8(le!(u8, payload))),
+        TAG_UINT16 => Scalar::Number(NumberScalar::UInt16(le!(u16, payload))),
+        TAG_UINT32 => Scalar::Number(NumberScalar::UInt32(le!(u32, payload))),
+        TAG_UINT64 => Scalar::Number(NumberScalar::UInt64(le!(u64, payload))),
+        TAG_INT8 => Scalar::Number(NumberScalar::Int8(le!(i8, payload))),
+        TAG_INT16 => Scalar::Number(NumberScalar::Int16(le!(i16, payload))),
+        TAG_INT32 => Scalar::Number(NumberScalar::Int32(le!(i32, payload))),
+        TAG_INT64 => Scalar::Number(NumberScalar::Int64(le!(i64, payload))),
+        TAG_FLOAT32 => Scalar::Number(NumberScalar::Float32(le!(f32, payload).into())),
+        TAG_FLOAT64 => Scalar::Number(NumberScalar::Float64(le!(f64, payload).into())),
+        TAG_DECIMAL128 => Scalar::Decimal(DecimalScalar::Decimal128(
+            le!(i128, payload.get(2..).ok_or_else(invalid)?),
+            decimal_size(payload)?,
+        )),
+        TAG_DECIMAL256 => Scalar::Decimal(DecimalScalar::Decimal256(
+            le!(i256, payload.get(2..).ok_or_else(invalid)?),
+            decimal_size(payload)?,
+        )),
+        TAG_TIMESTAMP => Scalar::Timestamp(le!(i64, payload)),
+        TAG_DATE => Scalar::Date(le!(i32, payload)),
+        _ => return Err(invalid()),
+    };
+    Ok(value)
+}
+
+fn tagged(tag: u8, payload: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(payload.len() + 1);
+    bytes.push(tag);
+    bytes.extend_from_slice(payload);
+    bytes
+}
+
+/// The partition of the rows of a block, attached before the block is serialized.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct PartitionMeta {
+    pub partition: String,
+}
+
+#[typetag::serde(name = "partition_meta")]
+impl BlockMetaInfo for PartitionMeta {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        match PartitionMeta::downcast_ref_from(info) {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// A serialized block of a partition, segments are generated per partition.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct PartitionedBlockMeta {
+    pub partition: String,
+    pub block_meta: BlockMeta,
+}
+
+#[typetag::serde(name = "partitioned_block_meta")]
+impl BlockMetaInfo for PartitionedBlockMeta {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        match PartitionedBlockMeta::downcast_ref_from(info) {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// Split the input blocks by the value of the partition key.
+#[derive(Clone)]
+pub struct TransformPartitionScatter {
+    func_ctx: FunctionContext,
+    partition_expr: Expr,
+}
+
+impl AccumulatingTransform for TransformPartitionScatter {
+    const NAME: &'static str = "TransformPartitionScatter";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let num_rows = data.num_rows();
+        if num_rows == 0 {
+            return Ok(vec![]);
+        }
+
+        let evaluator = Evaluator::new(&data, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let value = evaluator.run(&self.partition_expr)?;
+
+        let mut partitions: HashMap<Scalar, usize> = HashMap::new();
+        let mut values = vec![];
+        let mut indices = Vec::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let scalar = value
+                .index(row)
+                .map(|v| v.to_owned())
+                .unwrap_or(Scalar::Null);
+            let next = partitions.len();
+            let index = *partitions.entry(scalar.clone()).or_insert_with(|| {
+                values.push(scalar);
+                next
+            });
+            indices.push(index as u32);
+        }
+
+        let blocks = if values.len() == 1 {
+            vec![data]
+        } else {
+            data.scatter(&indices, values.len())?
+        };
+
+        blocks
+            .into_iter()
+            .zip(values.iter())
+            .filter(|(block, _)| !block.is_empty())
+            .map(|(block, value)| {
+                let partition = encode_partition_value(value)?;
+                block.add_meta(Some(Box::new(PartitionMeta { partition })))
+            })
+            .collect()
+    }
+}
+
+impl FuseTable {
+    pub fn is_partitioned(&self) -> bool {
+        self.table_info.options().contains_key(OPT_KEY_PARTITION_BY)
+    }
+
+    /// The partition key of the table, column refs are projected to column names.
+    pub fn partition_expr(&self, ctx: Arc<dyn TableContext>) -> Result<Option<RemoteExpr<String>>> {
+        let Some(partition_by) = self.table_info.options().get(OPT_KEY_PARTITION_BY) else {
+            return Ok(None);
+        };
+        let table_meta = Arc::new(self.clone());
+        let exprs = parse_exprs(ctx, table_meta.clone(), partition_by)?;
+        let expr = exprs.into_iter().next().ok_or_else(|| {
+            ErrorCode::InvalidPartitionKey(format!("Invalid partition key: {}", partition_by))
+        })?;
+        Ok(Some(
+            expr.project_column_ref(|index| table_meta.schema().field(*index).name().to_string())
+                .as_remote_expr(),
+        ))
+    }
+
+    pub fn partition_pruner(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        filter: Option<&RemoteExpr<String>>,
+    ) -> Result<Option<PartitionPruner>> {
+        let (Some(filter), Some(partition_expr)) = (filter, self.partition_expr(ctx.clone())?)
+        else {
+            return Ok(None);
+        };
+        Ok(PartitionPruner::try_create(
+            ctx.get_function_context()?,
+            filter.as_expr(&BUILTIN_FUNCTIONS),
+            partition_expr.as_expr(&BUILTIN_FUNCTIONS),
+        ))
+    }
+
+    fn partition_scatter(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<Option<TransformPartitionScatter>> {
+        let Some(partition_expr) = self.partition_expr(ctx.clone())? else {
+            return Ok(None);
+        };
+        let schema = self.table_info.schema();
+        let partition_expr = partition_expr
+            .as_expr(&BUILTIN_FUNCTIONS)
+            .project_column_ref(|name| schema.index_of(name).unwrap());
+        Ok(Some(TransformPartitionScatter {
+            func_ctx: ctx.get_function_context()?,
+            partition_expr,
+        }))
+    }
+
+    pub fn partition_scatter_for_append(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let Some(scatter) = self.partition_scatter(ctx)? else {
+            return Ok(());
+        };
+        pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                input,
+                output,
+                scatter.clone(),
+            )))
+        })
+    }
+
+    /// The scatter as a single pipe item, for the pipelines which only append part of the ports.
+    pub fn partition_scatter_pipe_item(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<Option<PipeItem>> {
+        let Some(scatter) = self.partition_scatter(ctx)? else {
+            return Ok(None);
+        };
+        let input = InputPort::create();
+        let output = OutputPort::create();
+        Ok(Some(PipeItem::create(
+            ProcessorPtr::create(AccumulatingTransformer::create(
+                input.clone(),
+                output.clone(),
+                scatter,
+            )),
+            vec![input],
+            vec![output],
+        )))
+    }
+
+    /// Drop all the segments of the partition, which is a metadata only operation.
+    #[async_backtrace::framed]
+    pub async fn drop_partition(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        partition: Scalar,
+        if_exists: bool,
+    ) -> Result<()> {
+        if !self.is_partitioned() {
+            return Err(ErrorCode::UnpartitionedTable(format!(
+                "Table '{}' is not partitioned",
+                self.table_info.name
+            )));
+        }
+        let encoded = encode_partition_value(&partition)?;
+        let prev_snapshot = self.read_table_snapshot().await?;
+        let (dropped, kept): (Vec<_>, Vec<_>) = prev_snapshot
+            .as_ref()
+            .map(|snapshot| snapshot.segments.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .partition(|location| {
+                TableMetaLocationGenerator::segment_partition(&location.0) == Some(encoded.as_str())
+            });
+
+        let prev_snapshot = match prev_snapshot {
+            Some(snapshot) if !dropped.is_empty() => snapshot,
+            _ => {
+                return if if_exists {
+                    Ok(())
+                } else {
+                    Err(ErrorCode::UnknownPartition(format!(
+                        "Unknown partition {} of table '{}'",
+                        partition, self.table_info.name
+                    )))
+                };
+            }
+        };
+
+        let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
+        let segments = segments_io
+            .read_segments::<SegmentInfo>(&dropped, false)
+            .await?;
+        let mut summary = prev_snapshot.summary.clone();
+        for segment in segments {
+            deduct_statistics_mut(&mut summary, &segment?.summary);
+        }
+
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &prev_snapshot.timestamp,
+            Some((prev_snapshot.snapshot_id, prev_snapshot.format_version)),
+            prev_snapshot.schema.clone(),
+            summary,
+            kept,
+            self.cluster_key_meta.clone(),
+            prev_snapshot.table_statistics_location.clone(),
+        );
+
+        FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &self.table_info,
+            &self.meta_location_generator,
+            new_snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+    }
+
+    /// Commit the appended data as the new content of the partition,
+    /// the other partitions of the table are kept.
+    pub fn commit_partition_overwrite(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        partition: &Scalar,
+    ) -> Result<()> {
+        if !self.is_partitioned() {
+            return Err(ErrorCode::UnpartitionedTable(format!(
+                "Table '{}' is not partitioned",
+                self.table_info.name
+            )));
+        }
+        let encoded = encode_partition_value(partition)?;
+        let snapshot_gen = AppendGenerator::new(ctx.clone(), false)
+            .with_overwrite_partition(encoded, self.operator.clone());
+        self.do_commit_with_generator(ctx, pipeline, None, snapshot_gen, None)
+    }
+}
@@ -16,6 +16,7 @@ use std::any::Any;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_catalog::plan::PartInfoPtr;
 use common_exception::Result;
 use common_expression::BlockMetaInfo;
@@ -27,7 +28,8 @@ use crate::io::MergeIOReadResult;
 
 pub enum DataSource {
     AggIndex((PartInfoPtr, MergeIOReadResult)),
-    Normal(MergeIOReadResult),
+    /// The block data, with the deleted rows of the block if it has a deletion vector.
+    Normal((MergeIOReadResult, Option<Bitmap>)),
 }
 
 pub struct DataSourceMeta {
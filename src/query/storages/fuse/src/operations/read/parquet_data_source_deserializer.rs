@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::ops::Not;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -32,6 +33,7 @@ use common_pipeline_core::processors::Processor;
 use super::fuse_source::fill_internal_column_meta;
 use super::parquet_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
+use crate::io::live_row_offsets;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
 use crate::io::UncompressedBuffer;
@@ -157,7 +159,7 @@ impl Processor for DeserializeDataTransform {
 
                     self.output_data = Some(block);
                 }
-                DataSource::Normal(data) => {
+                DataSource::Normal((data, deleted)) => {
                     let start = Instant::now();
                     let columns_chunks = data.columns_chunks()?;
                     let part = FusePartInfo::from_part(&part)?;
@@ -178,6 +180,19 @@ impl Processor for DeserializeDataTransform {
                         );
                     }
 
+                    // Drop the rows marked in the deletion vector, internal columns
+                    // keep referring to the physical offsets of the remaining rows.
+                    let (data_block, offsets) = match &deleted {
+                        Some(deleted) => {
+                            let offsets = self
+                                .block_reader
+                                .query_internal_columns()
+                                .then(|| live_row_offsets(deleted));
+                            (data_block.filter_with_bitmap(&deleted.not())?, offsets)
+                        }
+                        None => (data_block, None),
+                    };
+
                     let progress_values = ProgressValues {
                         rows: data_block.num_rows(),
                         bytes: data_block.memory_size(),
@@ -187,7 +202,7 @@ impl Processor for DeserializeDataTransform {
                     // Fill `BlockMetaIndex` as `DataBlock.meta` if query internal columns,
                     // `FillInternalColumnProcessor` will generate internal columns using `BlockMetaIndex` in next pipeline.
                     if self.block_reader.query_internal_columns() {
-                        let data_block = fill_internal_column_meta(data_block, part, None)?;
+                        let data_block = fill_internal_column_meta(data_block, part, offsets)?;
                         self.output_data = Some(data_block);
                     } else {
                         self.output_data = Some(data_block);
@@ -31,6 +31,8 @@ use common_pipeline_sources::SyncSourcer;
 
 use super::parquet_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
+use crate::io::read_deletion_vector;
+use crate::io::read_deletion_vector_sync;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
@@ -96,8 +98,12 @@ impl SyncSource for ReadParquetDataSource<true> {
         match self.partitions.steal_one(self.id) {
             None => Ok(None),
             Some(part) => {
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
+                let fuse_part = FusePartInfo::from_part(&part)?;
+                // The aggregating index is built on the whole block, it can not be used
+                // once some rows of the block have been deleted.
+                if let (Some(index_reader), None) =
+                    (self.index_reader.as_ref(), &fuse_part.deletion_vector)
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -115,14 +121,21 @@ impl SyncSource for ReadParquetDataSource<true> {
                     }
                 }
 
+                let deleted = fuse_part
+                    .deletion_vector
+                    .as_ref()
+                    .map(|loc| read_deletion_vector_sync(&self.block_reader.operator, loc))
+                    .transpose()?;
+
                 Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
                     vec![part.clone()],
-                    vec![DataSource::Normal(
+                    vec![DataSource::Normal((
                         self.block_reader.sync_read_columns_data_by_merge_io(
                             &ReadSettings::from_ctx(&self.partitions.ctx)?,
                             part,
                         )?,
-                    )],
+                        deleted,
+                    ))],
                 ))))
             }
         }
@@ -179,7 +192,9 @@ impl Processor for ReadParquetDataSource<false> {
                     tokio::spawn(async_backtrace::location!().frame(async move {
                         let part = FusePartInfo::from_part(&part)?;
 
-                        if let Some(index_reader) = index_reader.as_ref() {
+                        if let (Some(index_reader), None) =
+                            (index_reader.as_ref(), &part.deletion_vector)
+                        {
                             let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &part.location,
@@ -194,7 +209,14 @@ impl Processor for ReadParquetDataSource<false> {
                             }
                         }
 
-                        Ok(DataSource::Normal(
+                        let deleted = match &part.deletion_vector {
+                            Some(loc) => {
+                                Some(read_deletion_vector(&block_reader.operator, loc).await?)
+                            }
+                            None => None,
+                        };
+
+                        Ok(DataSource::Normal((
                             block_reader
                                 .read_columns_data_by_merge_io(
                                     &settings,
@@ -202,7 +224,8 @@ impl Processor for ReadParquetDataSource<false> {
                                     &part.columns_meta,
                                 )
                                 .await?,
-                        ))
+                            deleted,
+                        )))
                     }))
                     .await
                     .unwrap()
@@ -53,6 +53,7 @@ impl FuseTable {
             projection,
             ctx,
             query_internal_columns,
+            !self.is_encrypted(),
         )
     }
 
@@ -202,6 +203,7 @@ impl FuseTable {
                         self.operator.clone(),
                         agg,
                         self.table_compression,
+                        !self.is_encrypted(),
                     )
                 })
                 .transpose()?,
@@ -172,6 +172,7 @@ impl FuseTable {
                 table_info.schema(),
                 &push_downs,
                 self.bloom_index_cols(),
+                self.ngram_index_cols(),
             )?
         } else {
             let cluster_keys = self.cluster_keys(ctx.clone());
@@ -184,9 +185,15 @@ impl FuseTable {
                 self.cluster_key_meta.clone(),
                 cluster_keys,
                 self.bloom_index_cols(),
+                self.ngram_index_cols(),
             )?
         };
 
+        let filter = push_downs.as_ref().and_then(|p| p.filter.as_ref());
+        if let Some(partition_pruner) = self.partition_pruner(ctx.clone(), filter)? {
+            pruner.set_partition_pruner(partition_pruner);
+        }
+
         let block_metas = pruner.read_pruning(segments_location).await?;
         let pruning_stats = pruner.pruning_stats();
 
@@ -574,6 +581,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector_location.clone(),
         )
     }
 
@@ -618,6 +626,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector_location.clone(),
         )
     }
 }
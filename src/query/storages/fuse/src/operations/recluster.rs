@@ -32,6 +32,7 @@ use common_sql::evaluator::CompoundBlockOperator;
 use log::info;
 use storages_common_table_meta::meta::BlockMeta;
 
+use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::common::CommitSink;
 use crate::operations::common::MutationGenerator;
@@ -108,6 +109,7 @@ impl FuseTable {
             schema,
             &push_downs,
             self.bloom_index_cols(),
+            self.ngram_index_cols(),
         )?;
 
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
@@ -116,23 +118,41 @@ impl FuseTable {
             let mut block_metas = pruner.read_pruning(chunk.to_vec()).await?;
             block_metas.truncate(MAX_BLOCK_COUNT);
 
-            let mut blocks_map: BTreeMap<i32, Vec<(BlockMetaIndex, Arc<BlockMeta>)>> =
-                BTreeMap::new();
+            // Blocks of different partitions can not be reclustered together.
+            let mut partitions_map: BTreeMap<
+                Option<String>,
+                BTreeMap<i32, Vec<(BlockMetaIndex, Arc<BlockMeta>)>>,
+            > = BTreeMap::new();
             block_metas.into_iter().for_each(|(idx, b)| {
                 if let Some(stats) = &b.cluster_stats {
                     if stats.cluster_key_id == default_cluster_key_id && stats.level >= 0 {
-                        blocks_map.entry(stats.level).or_default().push((
-                            BlockMetaIndex {
-                                segment_idx: idx.segment_idx,
-                                block_idx: idx.block_idx,
-                            },
-                            b,
-                        ));
+                        let partition =
+                            TableMetaLocationGenerator::segment_partition(&idx.segment_location)
+                                .map(|v| v.to_string());
+                        partitions_map
+                            .entry(partition)
+                            .or_default()
+                            .entry(stats.level)
+                            .or_default()
+                            .push((
+                                BlockMetaIndex {
+                                    segment_idx: idx.segment_idx,
+                                    block_idx: idx.block_idx,
+                                },
+                                b,
+                            ));
                     }
                 }
             });
 
-            if mutator.target_select(blocks_map).await? {
+            let mut selected = false;
+            for blocks_map in partitions_map.into_values() {
+                if mutator.target_select(blocks_map).await? {
+                    selected = true;
+                    break;
+                }
+            }
+            if selected {
                 break;
             }
         }
@@ -246,6 +266,8 @@ impl FuseTable {
         let output_block_num = mutator.total_rows.div_ceil(final_block_size);
         let max_threads = std::cmp::min(max_threads, output_block_num);
         pipeline.try_resize(max_threads)?;
+        // The selected blocks are of the same partition, attach it to the output blocks.
+        self.partition_scatter_for_append(ctx.clone(), pipeline)?;
         pipeline.add_transform(|transform_input_port, transform_output_port| {
             let proc = TransformSerializeBlock::try_create(
                 ctx.clone(),
@@ -166,6 +166,12 @@ impl FuseTable {
 
         pipeline.add_pipe(replace_into_processor.into_pipe());
 
+        // the appended rows are split by partition before being serialized, the rows
+        // rewritten by the MergeIntoOperationAggregator stay in the partition of the origin block.
+        if let Some(scatter_item) = self.partition_scatter_pipe_item(ctx.clone())? {
+            pipeline.add_pipe(Pipe::create(2, 2, vec![scatter_item, create_dummy_item()]));
+        }
+
         // 3. connect to broadcast processor and append transform
 
         let max_threads = ctx.get_settings().get_max_threads()?;
@@ -307,6 +313,7 @@ impl FuseTable {
                 read_settings.clone(),
                 block_builder.clone(),
                 io_request_semaphore.clone(),
+                !self.is_encrypted(),
             )?;
             items.push(item.into_pipe_item());
         }
@@ -16,6 +16,8 @@ mod meta;
 mod mutator;
 mod processors;
 
+pub(crate) use meta::merge_into_operation_meta::UniqueKeyDigest;
+pub(crate) use mutator::column_hash::row_hash_of_columns;
 pub use processors::BroadcastProcessor;
 pub use processors::MergeIntoOperationAggregator;
 pub use processors::ReplaceIntoProcessor;
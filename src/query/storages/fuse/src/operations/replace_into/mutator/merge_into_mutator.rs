@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::ops::Not;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -42,6 +43,7 @@ use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 
+use crate::io::read_deletion_vector;
 use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
@@ -109,6 +111,7 @@ impl MergeIntoOperationAggregator {
         read_settings: ReadSettings,
         block_builder: BlockBuilder,
         io_request_semaphore: Arc<Semaphore>,
+        enable_data_cache: bool,
     ) -> Result<Self> {
         let deletion_accumulator = DeletionAccumulator::default();
         let segment_reader =
@@ -141,6 +144,7 @@ impl MergeIntoOperationAggregator {
                 projection,
                 ctx.clone(),
                 false,
+                enable_data_cache,
             )
         }?;
 
@@ -155,6 +159,7 @@ impl MergeIntoOperationAggregator {
                     projection,
                     ctx.clone(),
                     false,
+                    enable_data_cache,
                 )?;
                 Some(reader)
             }
@@ -399,11 +404,10 @@ impl AggregationContext {
             .incr(&progress_values);
 
         // shortcut: whole block deletion
-        if delete_nums == block_meta.row_count as usize {
+        if delete_nums == block_meta.live_row_count() as usize {
             info!("whole block deletion");
             metrics_inc_replace_whole_block_deletion(1);
             // whole block deletion
-            // NOTE that if deletion marker is enabled, check the real meaning of `row_count`
             let mutation = MutationLogEntry::DeletedBlock {
                 index: BlockMetaIndex {
                     segment_idx: segment_index,
@@ -477,6 +481,12 @@ impl AggregationContext {
         if let Some(index_state) = serialized.bloom_index_state {
             write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
         }
+        if let Some(index_state) = serialized.inverted_index_state {
+            write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
+        }
+        if let Some(index_state) = serialized.vector_index_state {
+            write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
+        }
 
         // generate log
         let mutation = MutationLogEntry::Replaced {
@@ -551,6 +561,10 @@ impl AggregationContext {
                 &block_meta.col_metas,
             )
             .await?;
+        let deleted = match &block_meta.deletion_vector_location {
+            Some(location) => Some(read_deletion_vector(&reader.operator, location).await?),
+            None => None,
+        };
 
         // deserialize block data
         // cpu intensive task, send them to dedicated thread pool
@@ -560,14 +574,19 @@ impl AggregationContext {
         GlobalIORuntime::instance()
             .spawn_blocking(move || {
                 let column_chunks = merged_io_read_result.columns_chunks()?;
-                reader.deserialize_chunks(
+                let block = reader.deserialize_chunks(
                     block_meta_ptr.location.0.as_str(),
                     block_meta_ptr.row_count as usize,
                     &block_meta_ptr.compression,
                     &block_meta_ptr.col_metas,
                     column_chunks,
                     &storage_format,
-                )
+                )?;
+                // the rows marked in the deletion vector are not visible
+                match deleted {
+                    Some(deleted) => block.filter_with_bitmap(&deleted.not()),
+                    None => Ok(block),
+                }
             })
             .await
     }
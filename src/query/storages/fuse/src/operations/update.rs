@@ -18,6 +18,7 @@ use std::sync::Arc;
 use common_catalog::plan::Projection;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::NumberDataType;
 use common_expression::FieldIndex;
@@ -53,6 +54,20 @@ impl FuseTable {
         query_row_id_col: bool,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
+        if let Some(partition_expr) = self.partition_expr(ctx.clone())? {
+            let schema = self.schema();
+            let partition_columns = partition_expr.as_expr(&BUILTIN_FUNCTIONS).column_refs();
+            if let Some((index, _)) = update_list
+                .iter()
+                .find(|(index, _)| partition_columns.contains_key(schema.field(*index).name()))
+            {
+                return Err(ErrorCode::InvalidPartitionKey(format!(
+                    "Column '{}' of the partition key can not be updated",
+                    schema.field(*index).name()
+                )));
+            }
+        }
+
         let snapshot_opt = self.read_table_snapshot().await?;
 
         // check if table is empty
@@ -267,6 +282,9 @@ impl FuseTable {
         if total_tasks != 0 {
             let max_threads =
                 std::cmp::min(ctx.get_settings().get_max_threads()? as usize, total_tasks);
+            let deletion_vector_gen = self
+                .deletion_vector_enabled()
+                .then(|| self.meta_location_generator().clone());
             // Add source pipe.
             pipeline.add_source(
                 |output| {
@@ -280,6 +298,7 @@ impl FuseTable {
                         ops.clone(),
                         self.storage_format,
                         true,
+                        deletion_vector_gen.clone(),
                     )
                 },
                 max_threads,
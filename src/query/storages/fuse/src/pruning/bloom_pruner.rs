@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::ColumnId;
 use common_expression::Expr;
 use common_expression::FunctionContext;
@@ -50,6 +51,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// n-gram indices that should be loaded from filter block
+    ngram_index_fields: Vec<TableField>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -70,6 +74,7 @@ impl BloomPrunerCreator {
         dal: Operator,
         filter_expr: Option<&Expr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
             let bloom_columns_map =
@@ -77,7 +82,12 @@ impl BloomPrunerCreator {
             let bloom_column_fields = bloom_columns_map.values().cloned().collect::<Vec<_>>();
             let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields)?;
 
-            if !point_query_cols.is_empty() {
+            let ngram_columns_map = ngram_index_cols
+                .bloom_index_fields(schema.clone(), BloomIndex::supported_ngram_type)?;
+            let ngram_column_fields = ngram_columns_map.values().cloned().collect::<Vec<_>>();
+            let like_query_cols = BloomIndex::find_like_columns(expr, &ngram_column_fields)?;
+
+            if !point_query_cols.is_empty() || !like_query_cols.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
@@ -89,9 +99,27 @@ impl BloomPrunerCreator {
                     }
                 }
 
+                // the n-grams are hashed as strings, same as they are indexed
+                let mut ngram_filter_fields = Vec::with_capacity(like_query_cols.len());
+                for (field, grams) in like_query_cols.into_iter() {
+                    ngram_filter_fields.push(field);
+                    for gram in grams {
+                        let scalar = Scalar::String(gram.into_bytes());
+                        if let Entry::Vacant(e) = scalar_map.entry(scalar.clone()) {
+                            let digest = BloomIndex::calculate_scalar_digest(
+                                &func_ctx,
+                                &scalar,
+                                &DataType::String,
+                            )?;
+                            e.insert(digest);
+                        }
+                    }
+                }
+
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    ngram_index_fields: ngram_filter_fields,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -123,6 +151,14 @@ impl BloomPrunerCreator {
                 Ok::<_, ErrorCode>(acc)
             },
         )?;
+        let index_columns = self
+            .ngram_index_fields
+            .iter()
+            .filter(|field| column_ids_of_indexed_block.contains(&field.column_id()))
+            .fold(index_columns, |mut acc, field| {
+                acc.push(BloomIndex::build_ngram_filter_column_name(field));
+                acc
+            });
         // load the relevant index columns
         let maybe_filter = index_location
             .read_block_filter(self.dal.clone(), &index_columns, index_length)
@@ -54,7 +54,11 @@ use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
+use crate::pruning::InvertedIndexPruner;
+use crate::pruning::InvertedIndexPrunerCreator;
+use crate::pruning::PartitionPruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VectorIndexPruner;
 
 pub struct PruningContext {
     pub ctx: Arc<dyn TableContext>,
@@ -65,6 +69,7 @@ pub struct PruningContext {
     pub limit_pruner: Arc<dyn Limiter + Send + Sync>,
     pub range_pruner: Arc<dyn RangePruner + Send + Sync>,
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
+    pub inverted_index_pruner: Option<Arc<dyn InvertedIndexPruner + Send + Sync>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
 
@@ -93,6 +98,7 @@ pub struct FusePruner {
     pub pruning_ctx: Arc<PruningContext>,
     pub push_down: Option<PushDownInfo>,
     pub inverse_range_index: Option<RangeIndex>,
+    pub partition_pruner: Option<Arc<PartitionPruner>>,
     pub deleted_segments: Vec<DeletedSegmentInfo>,
 }
 
@@ -104,6 +110,7 @@ impl FusePruner {
         table_schema: TableSchemaRef,
         push_down: &Option<PushDownInfo>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
     ) -> Result<Self> {
         Self::create_with_pages(
             ctx,
@@ -113,10 +120,12 @@ impl FusePruner {
             None,
             vec![],
             bloom_index_cols,
+            ngram_index_cols,
         )
     }
 
     // Create fuse pruner with pages.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_with_pages(
         ctx: &Arc<dyn TableContext>,
         dal: Operator,
@@ -125,6 +134,7 @@ impl FusePruner {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
     ) -> Result<Self> {
         let func_ctx = ctx.get_function_context()?;
 
@@ -178,8 +188,14 @@ impl FusePruner {
             dal.clone(),
             filter_expr.as_ref(),
             bloom_index_cols,
+            ngram_index_cols,
         )?;
 
+        // Inverted index pruner.
+        // None will be returned, if there is no full-text predicate in the filter expression.
+        let inverted_index_pruner =
+            InvertedIndexPrunerCreator::create(&table_schema, dal.clone(), filter_expr.as_ref())?;
+
         // Page pruner, used in native format
         let page_pruner = PagePrunerCreator::try_create(
             func_ctx.clone(),
@@ -225,6 +241,7 @@ impl FusePruner {
             limit_pruner,
             range_pruner,
             bloom_pruner,
+            inverted_index_pruner,
             page_pruner,
             internal_column_pruner,
             pruning_stats,
@@ -236,6 +253,7 @@ impl FusePruner {
             push_down: push_down.clone(),
             pruning_ctx,
             inverse_range_index: None,
+            partition_pruner: None,
             deleted_segments: vec![],
         })
     }
@@ -256,7 +274,7 @@ impl FusePruner {
         self.pruning(segment_locs, true).await
     }
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> topn pruner -> vector index pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &mut self,
@@ -283,9 +301,13 @@ impl FusePruner {
                 let block_pruner = block_pruner.clone();
                 let segment_pruner = segment_pruner.clone();
                 let pruning_ctx = self.pruning_ctx.clone();
+                let partition_pruner = self.partition_pruner.clone();
 
                 async move {
                     // Build pruning tasks.
+                    if let Some(partition_pruner) = &partition_pruner {
+                        batch.retain(|segment| partition_pruner.should_keep(&segment.location.0));
+                    }
                     if let Some(internal_column_pruner) = &pruning_ctx.internal_column_pruner {
                         batch = batch
                             .into_iter()
@@ -364,7 +386,9 @@ impl FusePruner {
                     // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
                     // will get here, we can prevent other mutations like update and so on.
                     // TopN pruner.
-                    self.topn_pruning(metas)
+                    let metas = self.topn_pruning(metas)?;
+                    // Vector index pruner.
+                    self.vector_index_pruning(metas).await
                 }
             }
         }
@@ -392,6 +416,24 @@ impl FusePruner {
         Ok(metas)
     }
 
+    // vector index pruner:
+    // if there are ordering by a vector distance + limit clause and no filters, use vector index pruner
+    #[async_backtrace::framed]
+    async fn vector_index_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        match VectorIndexPruner::create(
+            &self.table_schema,
+            self.pruning_ctx.dal.clone(),
+            &self.push_down,
+            self.max_concurrency,
+        ) {
+            Some(vector_index_pruner) => vector_index_pruner.prune(metas).await,
+            None => Ok(metas),
+        }
+    }
+
     // Pruning stats.
     pub fn pruning_stats(&self) -> common_catalog::plan::PruningStatistics {
         let stats = self.pruning_ctx.pruning_stats.clone();
@@ -422,4 +464,8 @@ impl FusePruner {
     pub fn get_inverse_range_index(&self) -> Option<RangeIndex> {
         self.inverse_range_index.clone()
     }
+
+    pub fn set_partition_pruner(&mut self, pruner: PartitionPruner) {
+        self.partition_pruner = Some(Arc::new(pruner))
+    }
 }
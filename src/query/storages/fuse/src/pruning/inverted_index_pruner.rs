@@ -0,0 +1,112 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Expr;
+use common_expression::TableSchemaRef;
+use log::warn;
+use opendal::Operator;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::FullTextPredicate;
+use storages_common_index::InvertedIndex;
+use storages_common_table_meta::meta::Location;
+
+use crate::io::load_inverted_index;
+
+#[async_trait::async_trait]
+pub trait InvertedIndexPruner {
+    // returns true, if target should NOT be pruned (false positive allowed)
+    async fn should_keep(&self, index_location: &Option<Location>) -> bool;
+}
+
+pub struct InvertedIndexPrunerCreator {
+    /// the full-text predicates found in the filter expression
+    predicates: Vec<FullTextPredicate>,
+
+    /// the data accessor
+    dal: Operator,
+
+    /// the schema of data being indexed
+    data_schema: TableSchemaRef,
+}
+
+impl InvertedIndexPrunerCreator {
+    pub fn create(
+        schema: &TableSchemaRef,
+        dal: Operator,
+        filter_expr: Option<&Expr<String>>,
+    ) -> Result<Option<Arc<dyn InvertedIndexPruner + Send + Sync>>> {
+        if let Some(expr) = filter_expr {
+            let predicates = InvertedIndex::find_predicates(expr)
+                .into_iter()
+                .filter(|predicate| {
+                    let column = match predicate {
+                        FullTextPredicate::Match { column, .. }
+                        | FullTextPredicate::Like { column, .. } => column,
+                    };
+                    schema
+                        .field_with_name(column)
+                        .map_or(false, |f| InvertedIndex::supported_type(f.data_type()))
+                })
+                .collect::<Vec<_>>();
+
+            if !predicates.is_empty() {
+                let creator = InvertedIndexPrunerCreator {
+                    predicates,
+                    dal,
+                    data_schema: schema.clone(),
+                };
+                return Ok(Some(Arc::new(creator)));
+            }
+        }
+        Ok(None)
+    }
+
+    // Check a location file is hit or not by inverted index.
+    #[async_backtrace::framed]
+    pub async fn apply(&self, index_location: &Location) -> Result<bool> {
+        match load_inverted_index(self.dal.clone(), index_location).await {
+            Ok(index) => Ok(
+                index.apply(&self.predicates, &self.data_schema)? != FilterEvalResult::MustFalse
+            ),
+            Err(e) if e.code() == ErrorCode::DEPRECATED_INDEX_FORMAT => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl InvertedIndexPruner for InvertedIndexPrunerCreator {
+    #[async_backtrace::framed]
+    async fn should_keep(&self, index_location: &Option<Location>) -> bool {
+        if let Some(loc) = index_location {
+            match self.apply(loc).await {
+                Ok(v) => v,
+                Err(e) => {
+                    // swallow exceptions intentionally, corrupted index should not prevent execution
+                    warn!(
+                        "failed to apply inverted index pruner, returning true. {}",
+                        e
+                    );
+                    true
+                }
+            }
+        } else {
+            true
+        }
+    }
+}
@@ -15,9 +15,11 @@
 mod block_pruner;
 mod bloom_pruner;
 mod fuse_pruner;
+mod inverted_index_pruner;
 mod pruner_location;
 mod pruning_statistics;
 mod segment_pruner;
+mod vector_index_pruner;
 
 pub use block_pruner::BlockPruner;
 pub use bloom_pruner::BloomPruner;
@@ -25,6 +27,10 @@ pub use bloom_pruner::BloomPrunerCreator;
 pub use fuse_pruner::DeletedSegmentInfo;
 pub use fuse_pruner::FusePruner;
 pub use fuse_pruner::PruningContext;
+pub use inverted_index_pruner::InvertedIndexPruner;
+pub use inverted_index_pruner::InvertedIndexPrunerCreator;
 pub use pruner_location::create_segment_location_vector;
 pub use pruner_location::SegmentLocation;
 pub use pruning_statistics::FusePruningStatistics;
+pub use segment_pruner::PartitionPruner;
+pub use vector_index_pruner::VectorIndexPruner;
@@ -15,15 +15,22 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_expression::ConstantFolder;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
 use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 
 use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
 use crate::metrics::metrics_inc_bytes_segment_range_pruning_after;
 use crate::metrics::metrics_inc_bytes_segment_range_pruning_before;
 use crate::metrics::metrics_inc_segments_range_pruning_after;
 use crate::metrics::metrics_inc_segments_range_pruning_before;
+use crate::operations::decode_partition_value;
 use crate::pruning::PruningContext;
 use crate::pruning::SegmentLocation;
 
@@ -104,3 +111,168 @@ impl SegmentPruner {
         segment_reader.read(&load_params).await
     }
 }
+
+/// Prunes the segments of a partitioned table by the partition value kept in the
+/// segment location, the segments are pruned without being read.
+pub struct PartitionPruner {
+    func_ctx: FunctionContext,
+    filter: Expr<String>,
+    partition_expr: Expr<String>,
+}
+
+impl PartitionPruner {
+    /// Returns `None` if the filter does not refer to the partition key.
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        filter: Expr<String>,
+        partition_expr: Expr<String>,
+    ) -> Option<Self> {
+        if !contains_expr(&filter, &partition_expr) {
+            return None;
+        }
+        Some(PartitionPruner {
+            func_ctx,
+            filter,
+            partition_expr,
+        })
+    }
+
+    pub fn should_keep(&self, location: &str) -> bool {
+        let Some(encoded) = TableMetaLocationGenerator::segment_partition(location) else {
+            return true;
+        };
+        let Ok(value) = decode_partition_value(encoded) else {
+            return true;
+        };
+        // replace the partition key with the partition value, then fold the filter.
+        let expr = replace_expr(&self.filter, &self.partition_expr, &value);
+        let (folded, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        !matches!(folded, Expr::Constant {
+            scalar: Scalar::Boolean(false) | Scalar::Null,
+            ..
+        })
+    }
+}
+
+// Compare the expressions, ignoring spans and display names.
+fn is_same_expr(left: &Expr<String>, right: &Expr<String>) -> bool {
+    match (left, right) {
+        (
+            Expr::Constant {
+                scalar: l_scalar,
+                data_type: l_type,
+                ..
+            },
+            Expr::Constant {
+                scalar: r_scalar,
+                data_type: r_type,
+                ..
+            },
+        ) => l_scalar == r_scalar && l_type == r_type,
+        (
+            Expr::ColumnRef {
+                id: l_id,
+                data_type: l_type,
+                ..
+            },
+            Expr::ColumnRef {
+                id: r_id,
+                data_type: r_type,
+                ..
+            },
+        ) => l_id == r_id && l_type == r_type,
+        (
+            Expr::Cast {
+                is_try: l_try,
+                expr: l_expr,
+                dest_type: l_type,
+                ..
+            },
+            Expr::Cast {
+                is_try: r_try,
+                expr: r_expr,
+                dest_type: r_type,
+                ..
+            },
+        ) => l_try == r_try && l_type == r_type && is_same_expr(l_expr, r_expr),
+        (
+            Expr::FunctionCall {
+                id: l_id,
+                generics: l_generics,
+                args: l_args,
+                return_type: l_type,
+                ..
+            },
+            Expr::FunctionCall {
+                id: r_id,
+                generics: r_generics,
+                args: r_args,
+                return_type: r_type,
+                ..
+            },
+        ) => {
+            l_id == r_id
+                && l_generics == r_generics
+                && l_type == r_type
+                && l_args.len() == r_args.len()
+                && l_args
+                    .iter()
+                    .zip(r_args.iter())
+                    .all(|(l, r)| is_same_expr(l, r))
+        }
+        _ => false,
+    }
+}
+
+fn contains_expr(expr: &Expr<String>, target: &Expr<String>) -> bool {
+    if is_same_expr(expr, target) {
+        return true;
+    }
+    match expr {
+        Expr::Cast { expr, .. } => contains_expr(expr, target),
+        Expr::FunctionCall { args, .. } => args.iter().any(|arg| contains_expr(arg, target)),
+        _ => false,
+    }
+}
+
+fn replace_expr(expr: &Expr<String>, target: &Expr<String>, value: &Scalar) -> Expr<String> {
+    if is_same_expr(expr, target) {
+        return Expr::Constant {
+            span: None,
+            scalar: value.clone(),
+            data_type: target.data_type().clone(),
+        };
+    }
+    match expr {
+        Expr::Cast {
+            span,
+            is_try,
+            expr,
+            dest_type,
+        } => Expr::Cast {
+            span: *span,
+            is_try: *is_try,
+            expr: Box::new(replace_expr(expr, target, value)),
+            dest_type: dest_type.clone(),
+        },
+        Expr::FunctionCall {
+            span,
+            id,
+            function,
+            generics,
+            args,
+            return_type,
+        } => Expr::FunctionCall {
+            span: *span,
+            id: id.clone(),
+            function: function.clone(),
+            generics: generics.clone(),
+            args: args
+                .iter()
+                .map(|arg| replace_expr(arg, target, value))
+                .collect(),
+            return_type: return_type.clone(),
+        },
+        _ => expr.clone(),
+    }
+}
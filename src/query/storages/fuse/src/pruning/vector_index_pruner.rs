@@ -0,0 +1,117 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use futures::StreamExt;
+use log::warn;
+use opendal::Operator;
+use storages_common_index::ScoreBound;
+use storages_common_index::VectorDistance;
+use storages_common_index::VectorIndex;
+use storages_common_pruner::BlockMetaIndex;
+use storages_common_table_meta::meta::BlockMeta;
+
+use crate::io::load_vector_index;
+
+/// Prunes the blocks which can not hold any of the top-k rows of
+/// `ORDER BY <distance>(column, query) LIMIT k`, by the vector indexes of the blocks.
+pub struct VectorIndexPruner {
+    dal: Operator,
+    field: TableField,
+    distance: VectorDistance,
+    query: Vec<f32>,
+    limit: usize,
+    nulls_first: bool,
+    max_concurrency: usize,
+}
+
+impl VectorIndexPruner {
+    /// Returns None if there is no vector top-k pushed down, or the column can not be indexed.
+    pub fn create(
+        schema: &TableSchemaRef,
+        dal: Operator,
+        push_down: &Option<PushDownInfo>,
+        max_concurrency: usize,
+    ) -> Option<Self> {
+        let push_down = push_down.as_ref()?;
+        // rows filtered out may not be counted in the top-k.
+        if push_down.filter.is_some() || push_down.prewhere.is_some() {
+            return None;
+        }
+        let top_k = push_down.vector_top_k.as_ref()?;
+        let field = schema.field_with_name(&top_k.column).ok()?;
+        if !VectorIndex::supported_type(field.data_type()) {
+            return None;
+        }
+        Some(Self {
+            dal,
+            field: field.clone(),
+            distance: VectorDistance::from_func_name(&top_k.distance)?,
+            query: top_k.query.iter().map(|v| v.0).collect(),
+            limit: top_k.limit,
+            nulls_first: top_k.nulls_first,
+            max_concurrency,
+        })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn prune(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let bounds = futures::stream::iter(metas.iter().map(|(_, meta)| self.score_bounds(meta)))
+            .buffered(self.max_concurrency)
+            .collect::<Vec<_>>()
+            .await;
+        let keep = VectorIndex::top_k_blocks(&bounds, self.limit);
+        Ok(metas
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(meta, keep)| keep.then_some(meta))
+            .collect())
+    }
+
+    // The score bounds of the block, None if the block can not be pruned by the index.
+    #[async_backtrace::framed]
+    async fn score_bounds(&self, meta: &BlockMeta) -> Option<Vec<ScoreBound>> {
+        // The index also covers the deleted rows, the closest rows may be gone.
+        if meta.deletion_vector_location.is_some() {
+            return None;
+        }
+        let location = meta.vector_index_location.as_ref()?;
+        match load_vector_index(self.dal.clone(), location).await {
+            Ok(index) => {
+                let clusters = index.column_clusters(&self.field)?;
+                // NULLs come before the closest rows.
+                if self.nulls_first && clusters.nulls > 0 {
+                    return None;
+                }
+                clusters.score_bounds(self.distance, &self.query)
+            }
+            Err(e) => {
+                // swallow exceptions intentionally, corrupted index should not prevent execution
+                if e.code() != ErrorCode::DEPRECATED_INDEX_FORMAT {
+                    warn!("failed to load vector index, block is kept. {}", e);
+                }
+                None
+            }
+        }
+    }
+}
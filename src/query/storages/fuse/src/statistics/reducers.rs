@@ -156,6 +156,7 @@ pub fn merge_statistics_mut(
     l.uncompressed_byte_size += r.uncompressed_byte_size;
     l.compressed_byte_size += r.compressed_byte_size;
     l.index_size += r.index_size;
+    l.deleted_row_count += r.deleted_row_count;
 }
 
 // Deduct statistics, only be used for calculate snapshot summary.
@@ -173,6 +174,7 @@ pub fn deduct_statistics_mut(l: &mut Statistics, r: &Statistics) {
     l.uncompressed_byte_size -= r.uncompressed_byte_size;
     l.compressed_byte_size -= r.compressed_byte_size;
     l.index_size -= r.index_size;
+    l.deleted_row_count = l.deleted_row_count.saturating_sub(r.deleted_row_count);
     for (id, col_stats) in &mut l.col_stats {
         if let Some(r_col_stats) = r.col_stats.get(id) {
             // The MinMax of a column cannot be recalculated by the right statistics,
@@ -194,6 +196,7 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
     let mut compressed_byte_size: u64 = 0;
     let mut index_size: u64 = 0;
     let mut perfect_block_count: u64 = 0;
+    let mut deleted_row_count: u64 = 0;
 
     let len = block_metas.len();
     let mut col_stats = Vec::with_capacity(len);
@@ -201,12 +204,17 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
 
     block_metas.iter().for_each(|b| {
         let b = b.borrow();
-        row_count += b.row_count;
+        // rows marked in the deletion vector are not counted.
+        row_count += b.live_row_count();
+        deleted_row_count += b.deleted_row_count;
         block_count += 1;
         uncompressed_byte_size += b.block_size;
         compressed_byte_size += b.file_size;
-        index_size += b.bloom_filter_index_size;
-        if thresholds.check_large_enough(b.row_count as usize, b.block_size as usize) {
+        index_size += b.bloom_filter_index_size
+            + b.inverted_index_size
+            + b.vector_index_size
+            + b.deletion_vector_size;
+        if thresholds.check_large_enough(b.live_row_count() as usize, b.block_size as usize) {
             perfect_block_count += 1;
         }
         col_stats.push(&b.col_stats);
@@ -225,5 +233,6 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
         index_size,
         col_stats: merged_col_stats,
         cluster_stats: merged_cluster_stats,
+        deleted_row_count,
     }
 }
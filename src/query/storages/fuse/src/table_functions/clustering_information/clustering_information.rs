@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use std::cmp;
+use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_exception::ErrorCode;
@@ -30,10 +32,12 @@ use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRefExt;
 use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
 use itertools::Itertools;
 use jsonb::Value as JsonbValue;
 use serde_json::json;
 use serde_json::Value as JsonValue;
+use storages_common_table_meta::meta::ColumnId;
 use storages_common_table_meta::meta::SegmentInfo;
 
 use crate::io::SegmentsIO;
@@ -54,8 +58,15 @@ struct ClusteringStatistics {
     average_overlaps: f64,
     average_depth: f64,
     block_depth_histogram: JsonValue,
+    column_overlaps: JsonValue,
 }
 
+/// Gather all statistics points to a hash Map.
+/// Key: The statistics points.
+/// Value: 0: The block indexes with key as min value;
+///        1: The block indexes with key as max value;
+type PointsMap<K> = HashMap<K, (Vec<u64>, Vec<u64>)>;
+
 impl Default for ClusteringStatistics {
     fn default() -> Self {
         ClusteringStatistics {
@@ -65,6 +76,7 @@ impl Default for ClusteringStatistics {
             average_overlaps: 0.0,
             average_depth: 0.0,
             block_depth_histogram: json!({}),
+            column_overlaps: json!({}),
         }
     }
 }
@@ -89,11 +101,11 @@ impl<'a> ClusteringInformation<'a> {
         }
         let snapshot = snapshot.unwrap();
 
-        // Gather all cluster statistics points to a hash Map.
-        // Key: The cluster statistics points.
-        // Value: 0: The block indexes with key as min value;
-        //        1: The block indexes with key as max value;
-        let mut points_map: HashMap<Vec<Scalar>, (Vec<u64>, Vec<u64>)> = HashMap::new();
+        let mut points_map: PointsMap<Vec<Scalar>> = HashMap::new();
+        // The columns used by the cluster keys, the overlaps of each column are
+        // calculated with the column statistics of the clustered blocks.
+        let key_columns = self.cluster_key_columns();
+        let mut column_points_map: Vec<PointsMap<Scalar>> = vec![HashMap::new(); key_columns.len()];
         let mut constant_block_count = 0;
         let mut unclustered_block_count = 0;
         let mut index = 0;
@@ -128,6 +140,23 @@ impl<'a> ClusteringInformation<'a> {
                                 .entry(cluster_stats.max.clone())
                                 .and_modify(|v| v.1.push(index))
                                 .or_insert((vec![], vec![index]));
+                            for ((_, column_id), column_points) in
+                                key_columns.iter().zip(column_points_map.iter_mut())
+                            {
+                                let (min, max) = block
+                                    .col_stats
+                                    .get(column_id)
+                                    .map(|stats| (stats.min.clone(), stats.max.clone()))
+                                    .unwrap_or((Scalar::Null, Scalar::Null));
+                                column_points
+                                    .entry(min)
+                                    .and_modify(|v| v.0.push(index))
+                                    .or_insert((vec![index], vec![]));
+                                column_points
+                                    .entry(max)
+                                    .and_modify(|v| v.1.push(index))
+                                    .or_insert((vec![], vec![index]));
+                            }
                             index += 1;
                         }
                     } else {
@@ -139,49 +168,16 @@ impl<'a> ClusteringInformation<'a> {
         drop(snapshot);
 
         // calculate overlaps and depth.
-        let mut stats = Vec::new();
-        // key: the block index.
-        // value: (overlaps, depth).
-        let mut unfinished_parts: HashMap<u64, (usize, usize)> = HashMap::new();
-        for (_, (start, end)) in points_map
-            .into_iter()
-            .sorted_by(|(a, _), (b, _)| a.iter().cmp_by(b.iter(), cmp_with_null))
-        {
-            let point_depth = unfinished_parts.len() + start.len();
-
-            unfinished_parts.values_mut().for_each(|(overlaps, depth)| {
-                *overlaps += start.len();
-                *depth = cmp::max(*depth, point_depth);
-            });
-
-            start.iter().for_each(|&idx| {
-                unfinished_parts.insert(idx, (point_depth - 1, point_depth));
-            });
-
-            end.iter().for_each(|idx| {
-                if let Some(v) = unfinished_parts.remove(idx) {
-                    stats.push(v);
-                }
-            });
-        }
-        assert!(unfinished_parts.is_empty());
-
-        let mut sum_overlap = 0;
-        let mut sum_depth = 0;
-        let length = stats.len();
+        let stats =
+            calc_overlaps_and_depth(points_map, |a, b| a.iter().cmp_by(b.iter(), cmp_with_null));
+        let (average_overlaps, average_depth) = calc_averages(&stats);
         let mp = stats
             .into_iter()
-            .fold(BTreeMap::new(), |mut acc, (overlap, depth)| {
-                sum_overlap += overlap;
-                sum_depth += depth;
-
+            .fold(BTreeMap::new(), |mut acc, (_, depth)| {
                 let bucket = get_buckets(depth);
                 acc.entry(bucket).and_modify(|v| *v += 1).or_insert(1u32);
                 acc
             });
-        // round the float to 4 decimal places.
-        let average_depth = (10000.0 * sum_depth as f64 / length as f64).round() / 10000.0;
-        let average_overlaps = (10000.0 * sum_overlap as f64 / length as f64).round() / 10000.0;
 
         let map_len = mp.len();
         let objects = mp.into_iter().fold(
@@ -192,6 +188,20 @@ impl<'a> ClusteringInformation<'a> {
             },
         );
         let block_depth_histogram = JsonValue::Object(objects);
+
+        let mut column_overlaps = serde_json::Map::with_capacity(key_columns.len());
+        for ((name, _), column_points) in key_columns.into_iter().zip(column_points_map) {
+            let stats = calc_overlaps_and_depth(column_points, cmp_with_null);
+            let (average_overlaps, average_depth) = calc_averages(&stats);
+            column_overlaps.insert(
+                name,
+                json!({
+                    "average_overlaps": average_overlaps,
+                    "average_depth": average_depth,
+                }),
+            );
+        }
+        let column_overlaps = JsonValue::Object(column_overlaps);
         let info = ClusteringStatistics {
             total_block_count,
             constant_block_count,
@@ -199,11 +209,29 @@ impl<'a> ClusteringInformation<'a> {
             average_overlaps,
             average_depth,
             block_depth_histogram,
+            column_overlaps,
         };
 
         self.build_block(info)
     }
 
+    /// The names and ids of the columns used by the cluster keys, in the order of the table schema.
+    fn cluster_key_columns(&self) -> Vec<(String, ColumnId)> {
+        let names = self
+            .table
+            .cluster_keys(self.ctx.clone())
+            .iter()
+            .flat_map(|key| key.as_expr(&BUILTIN_FUNCTIONS).column_refs().into_keys())
+            .collect::<HashSet<_>>();
+        self.table
+            .schema()
+            .fields()
+            .iter()
+            .filter(|field| names.contains(field.name()))
+            .map(|field| (field.name().clone(), field.column_id()))
+            .collect()
+    }
+
     fn build_block(&self, info: ClusteringStatistics) -> Result<DataBlock> {
         let cluster_by_keys = self
             .table
@@ -249,6 +277,12 @@ impl<'a> ClusteringInformation<'a> {
                         JsonbValue::from(&info.block_depth_histogram).to_vec(),
                     )),
                 ),
+                BlockEntry::new(
+                    DataType::Variant,
+                    Value::Scalar(Scalar::Variant(
+                        JsonbValue::from(&info.column_overlaps).to_vec(),
+                    )),
+                ),
             ],
             1,
         ))
@@ -278,10 +312,53 @@ impl<'a> ClusteringInformation<'a> {
                 TableDataType::Number(NumberDataType::Float64),
             ),
             TableField::new("block_depth_histogram", TableDataType::Variant),
+            TableField::new("column_overlaps", TableDataType::Variant),
         ])
     }
 }
 
+/// Calculate the overlaps and depth of each block, with the min/max points of the blocks.
+fn calc_overlaps_and_depth<K>(
+    points_map: PointsMap<K>,
+    cmp: impl Fn(&K, &K) -> Ordering,
+) -> Vec<(usize, usize)> {
+    let mut stats = Vec::new();
+    // key: the block index.
+    // value: (overlaps, depth).
+    let mut unfinished_parts: HashMap<u64, (usize, usize)> = HashMap::new();
+    for (_, (start, end)) in points_map.into_iter().sorted_by(|(a, _), (b, _)| cmp(a, b)) {
+        let point_depth = unfinished_parts.len() + start.len();
+
+        unfinished_parts.values_mut().for_each(|(overlaps, depth)| {
+            *overlaps += start.len();
+            *depth = cmp::max(*depth, point_depth);
+        });
+
+        start.iter().for_each(|&idx| {
+            unfinished_parts.insert(idx, (point_depth - 1, point_depth));
+        });
+
+        end.iter().for_each(|idx| {
+            if let Some(v) = unfinished_parts.remove(idx) {
+                stats.push(v);
+            }
+        });
+    }
+    assert!(unfinished_parts.is_empty());
+    stats
+}
+
+/// Returns the average overlaps and depth, rounded to 4 decimal places.
+fn calc_averages(stats: &[(usize, usize)]) -> (f64, f64) {
+    let length = stats.len() as f64;
+    let (sum_overlap, sum_depth) = stats
+        .iter()
+        .fold((0, 0), |(o, d), (overlap, depth)| (o + overlap, d + depth));
+    let average_overlaps = (10000.0 * sum_overlap as f64 / length).round() / 10000.0;
+    let average_depth = (10000.0 * sum_depth as f64 / length).round() / 10000.0;
+    (average_overlaps, average_depth)
+}
+
 /// The histogram contains buckets with widths:
 /// 1 to 16 with increments of 1.
 /// For buckets larger than 16, increments of twice the width of the previous bucket (e.g. 32, 64, 128, …).
@@ -81,6 +81,8 @@ use thrift::protocol::*;
 use thrift::transport::*;
 
 use super::hive_database::HiveDatabase;
+use crate::hive_meta_store::HiveMetaStore;
+use crate::hive_meta_store::ThriftHiveMetaStore;
 use crate::hive_table::HiveTable;
 
 pub const HIVE_CATALOG: &str = "hive";
@@ -110,6 +112,10 @@ impl HiveCatalog {
         Ok(ThriftHiveMetastoreSyncClient::new(i_prot, o_prot))
     }
 
+    pub fn get_meta_store(&self) -> Result<Box<dyn HiveMetaStore>> {
+        Ok(Box::new(ThriftHiveMetaStore::create(self.get_client()?)))
+    }
+
     #[async_backtrace::framed]
     pub async fn get_partitions(
         &self,
@@ -0,0 +1,262 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockMetaInfo;
+use common_hive_meta_store as hms;
+use futures::TryStreamExt;
+use log::info;
+use opendal::EntryMode;
+use opendal::Metakey;
+use opendal::Operator;
+
+use crate::hive_meta_store::HiveMetaStore;
+use crate::hive_table::convert_hdfs_path;
+
+/// A parquet file written into a hive table, not visible before commit.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct HiveWrittenFile {
+    // partition name, like 'c_region=ASIA/c_nation=CHINA'
+    pub partition: Option<String>,
+    // unescaped partition values, in the order of partition keys
+    pub partition_values: Vec<String>,
+    // file location, relative to the root of the operator
+    pub location: String,
+    pub num_rows: u64,
+    pub file_size: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct HiveWrittenFiles {
+    pub files: Vec<HiveWrittenFile>,
+}
+
+#[typetag::serde(name = "hive_written_files")]
+impl BlockMetaInfo for HiveWrittenFiles {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        match info.as_any().downcast_ref::<HiveWrittenFiles>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// Makes the files written by an insertion visible in a hive table.
+///
+/// For partitioned tables, partitions which do not exist yet are registered in the
+/// hive metastore. If `overwrite` is set, the existing files of the partitions that
+/// received new data are removed (dynamic partition overwrite); for a table without
+/// partitions, all the existing files of the table are removed.
+pub struct HiveCommitter {
+    dal: Operator,
+    db_name: String,
+    table_name: String,
+    // table location in the hive metastore, like hdfs://namenode:8020/user/hive/warehouse/a.db/b/
+    table_location: String,
+    partitioned: bool,
+    overwrite: bool,
+}
+
+impl HiveCommitter {
+    pub fn create(
+        dal: Operator,
+        db_name: String,
+        table_name: String,
+        table_location: String,
+        partitioned: bool,
+        overwrite: bool,
+    ) -> Self {
+        HiveCommitter {
+            dal,
+            db_name,
+            table_name,
+            table_location,
+            partitioned,
+            overwrite,
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn commit(
+        &self,
+        meta_store: Box<dyn HiveMetaStore>,
+        files: Vec<HiveWrittenFile>,
+    ) -> Result<()> {
+        // opendal normalizes the listed paths without the leading '/'.
+        let written = files
+            .iter()
+            .map(|f| f.location.trim_start_matches('/').to_string())
+            .collect::<HashSet<_>>();
+
+        // partition name -> partition values, of all the partitions that received data
+        let mut partitions = BTreeMap::new();
+        for file in files.iter() {
+            if let Some(partition) = &file.partition {
+                partitions.insert(partition.clone(), file.partition_values.clone());
+            }
+        }
+
+        if self.overwrite {
+            let dirs = if self.partitioned {
+                partitions
+                    .keys()
+                    .map(|name| self.partition_dir(name))
+                    .collect::<Vec<_>>()
+            } else {
+                vec![convert_hdfs_path(&self.table_location, true)]
+            };
+            for dir in dirs {
+                self.remove_stale_files(&dir, &written).await?;
+            }
+        }
+
+        if self.partitioned && !partitions.is_empty() {
+            let db_name = self.db_name.clone();
+            let table_name = self.table_name.clone();
+            let table_location = self.table_location.clone();
+            let added = tokio::task::spawn_blocking(move || {
+                Self::register_partitions(
+                    meta_store,
+                    db_name,
+                    table_name,
+                    table_location,
+                    partitions,
+                )
+            })
+            .await
+            .map_err(|e| {
+                ErrorCode::Internal(format!(
+                    "failed to register the partitions of hive table {}.{}: {}",
+                    self.db_name, self.table_name, e
+                ))
+            })??;
+            info!(
+                "hive table {}.{} committed {} files, registered {} new partitions",
+                self.db_name,
+                self.table_name,
+                files.len(),
+                added
+            );
+        }
+
+        Ok(())
+    }
+
+    // Register the partitions which are not known by the hive metastore,
+    // returns the number of partitions added.
+    fn register_partitions(
+        meta_store: Box<dyn HiveMetaStore>,
+        db_name: String,
+        table_name: String,
+        table_location: String,
+        partitions: BTreeMap<String, Vec<String>>,
+    ) -> Result<usize> {
+        let mut meta_store = meta_store;
+        let existing = meta_store
+            .get_partition_names(&db_name, &table_name)?
+            .into_iter()
+            .collect::<HashSet<_>>();
+
+        let new_partitions = partitions
+            .into_iter()
+            .filter(|(name, _)| !existing.contains(name))
+            .collect::<Vec<_>>();
+        if new_partitions.is_empty() {
+            return Ok(0);
+        }
+
+        // new partitions inherit the storage format of the table.
+        let table = meta_store.get_table(&db_name, &table_name)?;
+        let sd = table.sd.unwrap_or_default();
+        let create_time = Utc::now().timestamp() as i32;
+        let num_partitions = new_partitions.len();
+        let new_partitions = new_partitions
+            .into_iter()
+            .map(|(name, values)| hms::Partition {
+                values: Some(values),
+                db_name: Some(db_name.clone()),
+                table_name: Some(table_name.clone()),
+                create_time: Some(create_time),
+                last_access_time: Some(0),
+                sd: Some(hms::StorageDescriptor {
+                    location: Some(format!("{}/{}", table_location.trim_end_matches('/'), name)),
+                    ..sd.clone()
+                }),
+                parameters: Some(BTreeMap::new()),
+                privileges: None,
+                cat_name: None,
+            })
+            .collect::<Vec<_>>();
+
+        meta_store.add_partitions(new_partitions)?;
+        Ok(num_partitions)
+    }
+
+    fn partition_dir(&self, partition: &str) -> String {
+        format!(
+            "{}{}/",
+            convert_hdfs_path(&self.table_location, true),
+            partition
+        )
+    }
+
+    // Remove the data files in `dir` which are not written by this insertion.
+    #[async_backtrace::framed]
+    async fn remove_stale_files(&self, dir: &str, written: &HashSet<String>) -> Result<()> {
+        let mut lister = match self.dal.list(dir).await {
+            Ok(lister) => lister,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut stale = vec![];
+        while let Some(de) = lister.try_next().await? {
+            let meta = self.dal.metadata(&de, Metakey::Mode).await?;
+            let path = de.path();
+            let file_offset = path.rfind('/').unwrap_or_default() + 1;
+            if path[file_offset..].starts_with('.') || path[file_offset..].starts_with('_') {
+                continue;
+            }
+            let relative_path = path.trim_start_matches('/');
+            if meta.mode() == EntryMode::FILE && !written.contains(relative_path) {
+                stale.push(path.to_string());
+            }
+        }
+
+        info!(
+            "remove {} stale files in {} for overwrite",
+            stale.len(),
+            dir
+        );
+        for path in stale {
+            self.dal.delete(&path).await?;
+        }
+        Ok(())
+    }
+}
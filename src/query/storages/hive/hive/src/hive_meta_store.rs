@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_hive_meta_store as hms;
+use common_hive_meta_store::TThriftHiveMetastoreSyncClient;
+
+/// The hive metastore operations needed to commit a write into a hive table.
+///
+/// It is implemented by the thrift client, and can be mocked in tests.
+pub trait HiveMetaStore: Send {
+    fn get_table(&mut self, db_name: &str, table_name: &str) -> Result<hms::Table>;
+
+    fn get_partition_names(&mut self, db_name: &str, table_name: &str) -> Result<Vec<String>>;
+
+    fn add_partitions(&mut self, partitions: Vec<hms::Partition>) -> Result<()>;
+}
+
+pub struct ThriftHiveMetaStore<C> {
+    client: C,
+}
+
+impl<C> ThriftHiveMetaStore<C>
+where C: TThriftHiveMetastoreSyncClient + Send
+{
+    pub fn create(client: C) -> Self {
+        ThriftHiveMetaStore { client }
+    }
+}
+
+impl<C> HiveMetaStore for ThriftHiveMetaStore<C>
+where C: TThriftHiveMetastoreSyncClient + Send
+{
+    fn get_table(&mut self, db_name: &str, table_name: &str) -> Result<hms::Table> {
+        self.client
+            .get_table(db_name.to_string(), table_name.to_string())
+            .map_err(from_thrift_error)
+    }
+
+    fn get_partition_names(&mut self, db_name: &str, table_name: &str) -> Result<Vec<String>> {
+        self.client
+            .get_partition_names(db_name.to_string(), table_name.to_string(), -1)
+            .map_err(from_thrift_error)
+    }
+
+    fn add_partitions(&mut self, partitions: Vec<hms::Partition>) -> Result<()> {
+        if partitions.is_empty() {
+            return Ok(());
+        }
+        self.client
+            .add_partitions(partitions)
+            .map_err(from_thrift_error)?;
+        Ok(())
+    }
+}
+
+fn from_thrift_error(error: thrift::Error) -> ErrorCode {
+    ErrorCode::from_std_error(error)
+}
@@ -26,6 +26,7 @@ use common_catalog::plan::Partitions;
 use common_catalog::plan::PartitionsShuffleKind;
 use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::table::AppendMode;
 use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
 use common_catalog::table::TableStatistics;
@@ -49,6 +50,7 @@ use common_pipeline_core::Pipeline;
 use common_pipeline_core::SourcePipeBuilder;
 use common_pipeline_sources::SyncSource;
 use common_pipeline_sources::SyncSourcer;
+use common_pipeline_transforms::processors::transforms::AsyncTransformer;
 use common_storage::init_operator;
 use common_storage::DataOperator;
 use futures::TryStreamExt;
@@ -65,7 +67,10 @@ use super::hive_catalog::HiveCatalog;
 use super::hive_partition_pruner::HivePartitionPruner;
 use super::hive_table_options::HiveTableOptions;
 use crate::filter_hive_partition_from_partition_keys;
+use crate::hive_committer::HiveCommitter;
 use crate::hive_parquet_block_reader::HiveBlockReader;
+use crate::hive_table_sink::HiveCommitSink;
+use crate::hive_table_sink::HiveParquetWriter;
 use crate::hive_table_source::HiveTableSource;
 use crate::HiveBlockFilter;
 use crate::HiveFileSplitter;
@@ -440,6 +445,22 @@ impl HiveTable {
         Ok(res)
     }
 
+    fn get_table_location(&self) -> Result<&String> {
+        match &self.table_options.location {
+            Some(path) => Ok(path),
+            None => Err(ErrorCode::TableInfoError(format!(
+                "{}, table location is empty",
+                self.table_info.name
+            ))),
+        }
+    }
+
+    // return (database name, table name) in hive metastore
+    fn get_db_table_name(&self) -> (String, String) {
+        let table_info = self.table_info.desc.split('.').collect::<Vec<&str>>();
+        (table_info[0].to_string(), table_info[1].to_string())
+    }
+
     // return items: (hdfs_location, option<part info>) where part info likes 'c_region=Asia/c_nation=China'
     #[async_backtrace::framed]
     async fn get_query_locations(
@@ -447,15 +468,7 @@ impl HiveTable {
         ctx: Arc<dyn TableContext>,
         push_downs: &Option<PushDownInfo>,
     ) -> Result<Vec<(String, Option<String>)>> {
-        let path = match &self.table_options.location {
-            Some(path) => path,
-            None => {
-                return Err(ErrorCode::TableInfoError(format!(
-                    "{}, table location is empty",
-                    self.table_info.name
-                )));
-            }
-        };
+        let path = self.get_table_location()?;
 
         if let Some(partition_keys) = &self.table_options.partition_keys {
             if !partition_keys.is_empty() {
@@ -586,27 +599,75 @@ impl Table for HiveTable {
         self.do_read2(ctx, plan, pipeline)
     }
 
-    fn commit_insertion(
+    fn append_data(
         &self,
         _ctx: Arc<dyn TableContext>,
-        _pipeline: &mut Pipeline,
+        pipeline: &mut Pipeline,
+        _append_mode: AppendMode,
+    ) -> Result<()> {
+        let location = self.get_table_location()?;
+        let partition_keys = self
+            .table_options
+            .partition_keys
+            .clone()
+            .unwrap_or_default();
+        pipeline.add_transform(|input, output| {
+            // each writer has its own write id, so the file names never conflict.
+            let write_id = uuid::Uuid::new_v4().to_string();
+            let writer = HiveParquetWriter::try_create(
+                self.dal.clone(),
+                location,
+                self.table_info.schema(),
+                &partition_keys,
+                write_id,
+            )?;
+            Ok(ProcessorPtr::create(AsyncTransformer::create(
+                input, output, writer,
+            )))
+        })
+    }
+
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
         _copied_files: Option<UpsertTableCopiedFileReq>,
-        _overwrite: bool,
+        overwrite: bool,
         _prev_snapshot_id: Option<SnapshotId>,
     ) -> Result<()> {
-        Err(ErrorCode::Unimplemented(format!(
-            "commit_insertion operation for table {} is not implemented, table engine is {}",
-            self.name(),
-            self.get_table_info().meta.engine
-        )))
+        let location = self.get_table_location()?;
+        let (db_name, table_name) = self.get_db_table_name();
+        let partitioned = !self.get_partition_key_sets().is_empty();
+
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            let committer = HiveCommitter::create(
+                self.dal.clone(),
+                db_name.clone(),
+                table_name.clone(),
+                location.clone(),
+                partitioned,
+                overwrite,
+            );
+            HiveCommitSink::try_create(input, ctx.clone(), committer)
+        })
     }
 
+    // Like hive, truncate removes all the data files of the table,
+    // but keeps the partitions registered in the hive metastore.
     #[async_backtrace::framed]
-    async fn truncate(&self, _ctx: Arc<dyn TableContext>, _: bool) -> Result<()> {
-        Err(ErrorCode::Unimplemented(format!(
-            "truncate for table {} is not implemented",
-            self.name()
-        )))
+    async fn truncate(&self, ctx: Arc<dyn TableContext>, _: bool) -> Result<()> {
+        let dirs = self.get_query_locations(ctx, &None).await?;
+        let files = self.list_files_from_dirs(dirs).await?;
+        info!(
+            "truncate hive table {}, remove {} files",
+            self.table_info.desc,
+            files.len()
+        );
+        for file in files {
+            self.dal.delete(&file.filename).await?;
+        }
+        Ok(())
     }
 
     #[async_backtrace::framed]
@@ -0,0 +1,248 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use common_catalog::catalog_kind::CATALOG_HIVE;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::DataBlock;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sinks::AsyncSink;
+use common_pipeline_sinks::AsyncSinker;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use opendal::Operator;
+use storages_common_blocks::blocks_to_parquet;
+use storages_common_table_meta::table::TableCompression;
+
+use crate::hive_committer::HiveCommitter;
+use crate::hive_committer::HiveWrittenFile;
+use crate::hive_committer::HiveWrittenFiles;
+use crate::hive_table::convert_hdfs_path;
+use crate::utils::escape_partition_value;
+use crate::utils::scalar_to_partition_value;
+use crate::HiveCatalog;
+
+/// Writes the incoming blocks into parquet files under the partition directories
+/// of a hive table, and outputs the written files as block meta.
+///
+/// Partition columns are not stored in the files, they are encoded in the directory
+/// names, like 'c_region=ASIA/c_nation=CHINA'.
+pub struct HiveParquetWriter {
+    dal: Operator,
+    // table data directory, in opendal path format
+    table_dir: String,
+    // schema of the parquet files, without partition columns
+    file_schema: TableSchemaRef,
+    file_field_offsets: Vec<usize>,
+    // (name, offset) of partition columns, in the order of partition keys
+    partition_fields: Vec<(String, usize)>,
+    write_id: String,
+    file_seq: usize,
+}
+
+impl HiveParquetWriter {
+    pub fn try_create(
+        dal: Operator,
+        table_location: &str,
+        schema: TableSchemaRef,
+        partition_keys: &[String],
+        write_id: String,
+    ) -> Result<Self> {
+        let mut partition_fields = Vec::with_capacity(partition_keys.len());
+        for key in partition_keys {
+            partition_fields.push((key.clone(), schema.index_of(key)?));
+        }
+
+        let mut file_fields = vec![];
+        let mut file_field_offsets = vec![];
+        for (offset, field) in schema.fields().iter().enumerate() {
+            if !partition_keys.contains(field.name()) {
+                file_fields.push(field.clone());
+                file_field_offsets.push(offset);
+            }
+        }
+
+        Ok(HiveParquetWriter {
+            dal,
+            table_dir: convert_hdfs_path(table_location, true),
+            file_schema: Arc::new(TableSchema::new(file_fields)),
+            file_field_offsets,
+            partition_fields,
+            write_id,
+            file_seq: 0,
+        })
+    }
+
+    // Split the rows of the block by partition, the key is (partition name, partition values).
+    fn split_by_partition(
+        &self,
+        block: &DataBlock,
+    ) -> Result<BTreeMap<(String, Vec<String>), Vec<u32>>> {
+        let mut partitions: BTreeMap<(String, Vec<String>), Vec<u32>> = BTreeMap::new();
+        for row in 0..block.num_rows() {
+            let mut name = String::new();
+            let mut values = Vec::with_capacity(self.partition_fields.len());
+            for (i, (key, offset)) in self.partition_fields.iter().enumerate() {
+                let scalar = block.get_by_offset(*offset).value.index(row).unwrap();
+                let value = scalar_to_partition_value(scalar)?;
+                if i > 0 {
+                    name.push('/');
+                }
+                name.push_str(&format!("{}={}", key, escape_partition_value(&value)));
+                values.push(value);
+            }
+            partitions
+                .entry((name, values))
+                .or_default()
+                .push(row as u32);
+        }
+        Ok(partitions)
+    }
+
+    #[async_backtrace::framed]
+    async fn write_file(
+        &mut self,
+        block: &DataBlock,
+        partition: Option<(String, Vec<String>)>,
+    ) -> Result<HiveWrittenFile> {
+        let columns = self
+            .file_field_offsets
+            .iter()
+            .map(|offset| block.get_by_offset(*offset).clone())
+            .collect::<Vec<_>>();
+        let num_rows = block.num_rows();
+        let file_block = DataBlock::new(columns, num_rows);
+
+        let mut data = vec![];
+        let (file_size, _) = blocks_to_parquet(
+            &self.file_schema,
+            vec![file_block],
+            &mut data,
+            TableCompression::Snappy,
+        )?;
+
+        let (partition, partition_values) = match partition {
+            Some((name, values)) => (Some(name), values),
+            None => (None, vec![]),
+        };
+        let dir = match &partition {
+            Some(name) => format!("{}{}/", self.table_dir, name),
+            None => self.table_dir.clone(),
+        };
+        let location = format!("{}part-{}-{:05}.parquet", dir, self.write_id, self.file_seq);
+        self.file_seq += 1;
+
+        self.dal.write(&location, data).await?;
+
+        Ok(HiveWrittenFile {
+            partition,
+            partition_values,
+            location,
+            num_rows: num_rows as u64,
+            file_size,
+        })
+    }
+}
+
+#[async_trait]
+impl AsyncTransform for HiveParquetWriter {
+    const NAME: &'static str = "HiveParquetWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let mut files = vec![];
+        if data.num_rows() > 0 {
+            if self.partition_fields.is_empty() {
+                files.push(self.write_file(&data, None).await?);
+            } else {
+                let partitions = self.split_by_partition(&data)?;
+                let num_partitions = partitions.len();
+                for (partition, rows) in partitions {
+                    let file = if num_partitions == 1 {
+                        self.write_file(&data, Some(partition)).await?
+                    } else {
+                        let block = data.take(&rows)?;
+                        self.write_file(&block, Some(partition)).await?
+                    };
+                    files.push(file);
+                }
+            }
+        }
+        Ok(DataBlock::empty_with_meta(Box::new(HiveWrittenFiles {
+            files,
+        })))
+    }
+}
+
+/// Collects the files written by `HiveParquetWriter`, and commits them once all
+/// the data has been written.
+pub struct HiveCommitSink {
+    ctx: Arc<dyn TableContext>,
+    committer: HiveCommitter,
+    files: Vec<HiveWrittenFile>,
+}
+
+impl HiveCommitSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        committer: HiveCommitter,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(AsyncSinker::create(
+            input,
+            HiveCommitSink {
+                ctx,
+                committer,
+                files: vec![],
+            },
+        )))
+    }
+}
+
+#[async_trait]
+impl AsyncSink for HiveCommitSink {
+    const NAME: &'static str = "HiveCommitSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        let catalog = self.ctx.get_catalog(CATALOG_HIVE)?;
+        let hive_catalog = catalog
+            .as_any()
+            .downcast_ref::<HiveCatalog>()
+            .ok_or_else(|| ErrorCode::Internal("expect hive catalog"))?;
+        let meta_store = hive_catalog.get_meta_store()?;
+        let files = std::mem::take(&mut self.files);
+        self.committer.commit(meta_store, files).await
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block.get_owned_meta() {
+            if let Some(written) = HiveWrittenFiles::downcast_from(meta) {
+                self.files.extend(written.files);
+            }
+        }
+        Ok(false)
+    }
+}
@@ -18,6 +18,7 @@ use common_expression::types::number::NumberScalar;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::Scalar;
+use common_expression::ScalarRef;
 use ordered_float::OrderedFloat;
 
 use crate::hive_table::HIVE_DEFAULT_PARTITION;
@@ -80,3 +81,46 @@ pub(crate) fn str_field_to_scalar(value: &str, data_type: &DataType) -> Result<S
         ))),
     }
 }
+
+/// Format a value of a partition column as a hive partition value,
+/// the inverse of `str_field_to_scalar`.
+pub(crate) fn scalar_to_partition_value(value: ScalarRef) -> Result<String> {
+    match value {
+        ScalarRef::Null => Ok(HIVE_DEFAULT_PARTITION.to_string()),
+        ScalarRef::String(s) => {
+            let s = std::str::from_utf8(s).map_err(|e| {
+                ErrorCode::BadBytes(format!("invalid utf8 hive partition value: {}", e))
+            })?;
+            if s.is_empty() {
+                Ok(HIVE_DEFAULT_PARTITION.to_string())
+            } else {
+                Ok(s.to_string())
+            }
+        }
+        ScalarRef::Number(num) => Ok(num.to_string()),
+        ScalarRef::Boolean(b) => Ok(b.to_string()),
+        _ => Err(ErrorCode::Unimplemented(format!(
+            "hive partition value of {:?} is not supported",
+            value
+        ))),
+    }
+}
+
+// escape the characters which are not allowed in a hive partition path,
+// keep the same with org.apache.hadoop.hive.common.FileUtils#escapePathName
+pub(crate) fn escape_partition_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        let need_escape = matches!(
+            c,
+            '"' | '#' | '%' | '\'' | '*' | '/' | ':' | '=' | '?' | '\\' | '{' | '[' | ']' | '^'
+        ) || (c as u32) < 0x20
+            || c == '\u{7F}';
+        if need_escape {
+            escaped.push_str(&format!("%{:02X}", c as u32));
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
@@ -0,0 +1,248 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_hive_meta_store as hms;
+use common_storages_hive::HiveCommitter;
+use common_storages_hive::HiveMetaStore;
+use common_storages_hive::HiveWrittenFile;
+use opendal::Operator;
+
+const TABLE_LOCATION: &str = "hdfs://namenode:8020/user/hive/warehouse/db.db/t";
+
+#[derive(Clone, Default)]
+struct MockMetaStore {
+    partitions: Arc<Mutex<Vec<hms::Partition>>>,
+}
+
+impl MockMetaStore {
+    fn with_partitions(names: &[&str]) -> Self {
+        let partitions = names
+            .iter()
+            .map(|name| hms::Partition {
+                values: Some(vec![name.split('=').nth(1).unwrap().to_string()]),
+                sd: Some(hms::StorageDescriptor {
+                    location: Some(format!("{}/{}", TABLE_LOCATION, name)),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .collect();
+        MockMetaStore {
+            partitions: Arc::new(Mutex::new(partitions)),
+        }
+    }
+
+    fn partition_locations(&self) -> Vec<String> {
+        let mut locations = self
+            .partitions
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|p| p.sd.as_ref().unwrap().location.clone().unwrap())
+            .collect::<Vec<_>>();
+        locations.sort();
+        locations
+    }
+}
+
+impl HiveMetaStore for MockMetaStore {
+    fn get_table(&mut self, db_name: &str, table_name: &str) -> Result<hms::Table> {
+        Ok(hms::Table {
+            db_name: Some(db_name.to_string()),
+            table_name: Some(table_name.to_string()),
+            sd: Some(hms::StorageDescriptor {
+                location: Some(TABLE_LOCATION.to_string()),
+                input_format: Some(
+                    "org.apache.hadoop.hive.ql.io.parquet.MapredParquetInputFormat".to_string(),
+                ),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+    }
+
+    fn get_partition_names(&mut self, _db_name: &str, _table_name: &str) -> Result<Vec<String>> {
+        Ok(self
+            .partitions
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|p| {
+                let location = p.sd.as_ref().unwrap().location.as_ref().unwrap();
+                location[TABLE_LOCATION.len() + 1..].to_string()
+            })
+            .collect())
+    }
+
+    fn add_partitions(&mut self, partitions: Vec<hms::Partition>) -> Result<()> {
+        self.partitions.lock().unwrap().extend(partitions);
+        Ok(())
+    }
+}
+
+fn written_file(partition: &str, name: &str) -> HiveWrittenFile {
+    HiveWrittenFile {
+        partition: Some(partition.to_string()),
+        partition_values: vec![partition.split('=').nth(1).unwrap().to_string()],
+        location: format!("/user/hive/warehouse/db.db/t/{}/{}", partition, name),
+        num_rows: 1,
+        file_size: 1,
+    }
+}
+
+async fn write_files(op: &Operator, files: &[&str]) -> Result<()> {
+    for file in files {
+        op.write(file, vec![1u8]).await?;
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_commit_registers_new_partitions() -> Result<()> {
+    let op = Operator::new(opendal::services::Memory::default())?.finish();
+    let meta_store = MockMetaStore::with_partitions(&["p=1"]);
+    write_files(&op, &[
+        "/user/hive/warehouse/db.db/t/p=1/old.parquet",
+        "/user/hive/warehouse/db.db/t/p=1/new.parquet",
+        "/user/hive/warehouse/db.db/t/p=2/new.parquet",
+    ])
+    .await?;
+
+    let committer = HiveCommitter::create(
+        op.clone(),
+        "db".to_string(),
+        "t".to_string(),
+        TABLE_LOCATION.to_string(),
+        true,
+        false,
+    );
+    committer
+        .commit(Box::new(meta_store.clone()), vec![
+            written_file("p=1", "new.parquet"),
+            written_file("p=2", "new.parquet"),
+        ])
+        .await?;
+
+    // only p=2 is registered, p=1 already exists.
+    assert_eq!(meta_store.partition_locations(), vec![
+        format!("{}/p=1", TABLE_LOCATION),
+        format!("{}/p=2", TABLE_LOCATION),
+    ]);
+    let added = meta_store.partitions.lock().unwrap()[1].clone();
+    assert_eq!(added.values, Some(vec!["2".to_string()]));
+    assert_eq!(
+        added.sd.unwrap().input_format,
+        Some("org.apache.hadoop.hive.ql.io.parquet.MapredParquetInputFormat".to_string())
+    );
+
+    // files of the existing partition are kept.
+    assert!(
+        op.is_exist("/user/hive/warehouse/db.db/t/p=1/old.parquet")
+            .await?
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_commit_overwrite_partitions() -> Result<()> {
+    let op = Operator::new(opendal::services::Memory::default())?.finish();
+    let meta_store = MockMetaStore::with_partitions(&["p=1", "p=2"]);
+    write_files(&op, &[
+        "/user/hive/warehouse/db.db/t/p=1/old.parquet",
+        "/user/hive/warehouse/db.db/t/p=1/_SUCCESS",
+        "/user/hive/warehouse/db.db/t/p=1/new.parquet",
+        "/user/hive/warehouse/db.db/t/p=2/old.parquet",
+    ])
+    .await?;
+
+    let committer = HiveCommitter::create(
+        op.clone(),
+        "db".to_string(),
+        "t".to_string(),
+        TABLE_LOCATION.to_string(),
+        true,
+        true,
+    );
+    committer
+        .commit(Box::new(meta_store.clone()), vec![written_file(
+            "p=1",
+            "new.parquet",
+        )])
+        .await?;
+
+    // only the partition which received new data is overwritten.
+    assert!(
+        !op.is_exist("/user/hive/warehouse/db.db/t/p=1/old.parquet")
+            .await?
+    );
+    assert!(
+        op.is_exist("/user/hive/warehouse/db.db/t/p=1/new.parquet")
+            .await?
+    );
+    assert!(
+        op.is_exist("/user/hive/warehouse/db.db/t/p=1/_SUCCESS")
+            .await?
+    );
+    assert!(
+        op.is_exist("/user/hive/warehouse/db.db/t/p=2/old.parquet")
+            .await?
+    );
+    assert_eq!(meta_store.partition_locations().len(), 2);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_commit_overwrite_unpartitioned_table() -> Result<()> {
+    let op = Operator::new(opendal::services::Memory::default())?.finish();
+    let meta_store = MockMetaStore::default();
+    write_files(&op, &[
+        "/user/hive/warehouse/db.db/t/old.parquet",
+        "/user/hive/warehouse/db.db/t/new.parquet",
+    ])
+    .await?;
+
+    let committer = HiveCommitter::create(
+        op.clone(),
+        "db".to_string(),
+        "t".to_string(),
+        TABLE_LOCATION.to_string(),
+        false,
+        true,
+    );
+    committer
+        .commit(Box::new(meta_store.clone()), vec![HiveWrittenFile {
+            partition: None,
+            partition_values: vec![],
+            location: "/user/hive/warehouse/db.db/t/new.parquet".to_string(),
+            num_rows: 1,
+            file_size: 1,
+        }])
+        .await?;
+
+    assert!(
+        !op.is_exist("/user/hive/warehouse/db.db/t/old.parquet")
+            .await?
+    );
+    assert!(
+        op.is_exist("/user/hive/warehouse/db.db/t/new.parquet")
+            .await?
+    );
+    assert!(meta_store.partition_locations().is_empty());
+    Ok(())
+}
@@ -0,0 +1,114 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_catalog::database::Database;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DatabaseIdent;
+use common_meta_app::schema::DatabaseInfo;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::DatabaseNameIdent;
+
+use crate::client::RdbmsClientRef;
+use crate::table::RdbmsTable;
+use crate::type_mapping::columns_to_schema;
+
+/// A database (schema) of a remote mysql or postgres database.
+#[derive(Clone)]
+pub struct RdbmsDatabase {
+    /// catalog this database belongs to
+    ctl_name: String,
+    client: RdbmsClientRef,
+    info: DatabaseInfo,
+}
+
+impl RdbmsDatabase {
+    pub fn create(ctl_name: &str, db_name: &str, client: RdbmsClientRef) -> Self {
+        let info = DatabaseInfo {
+            ident: DatabaseIdent { db_id: 0, seq: 0 },
+            name_ident: DatabaseNameIdent {
+                db_name: db_name.to_string(),
+                ..Default::default()
+            },
+            meta: DatabaseMeta {
+                engine: "rdbms".to_string(),
+                created_on: chrono::Utc::now(),
+                updated_on: chrono::Utc::now(),
+                ..Default::default()
+            },
+        };
+        Self {
+            ctl_name: ctl_name.to_string(),
+            client,
+            info,
+        }
+    }
+}
+
+#[async_trait]
+impl Database for RdbmsDatabase {
+    fn name(&self) -> &str {
+        &self.info.name_ident.db_name
+    }
+
+    fn get_db_info(&self) -> &DatabaseInfo {
+        &self.info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table(&self, table_name: &str) -> Result<Arc<dyn Table>> {
+        let dialect = self.client.dialect();
+        let rows = self
+            .client
+            .query(&dialect.list_columns_sql(self.name(), table_name))
+            .await?;
+        if rows.is_empty() {
+            return Err(ErrorCode::UnknownTable(format!(
+                "table {}.{} does not exist",
+                self.name(),
+                table_name
+            )));
+        }
+
+        let schema = columns_to_schema(rows)?;
+        Ok(Arc::new(RdbmsTable::create(
+            &self.ctl_name,
+            self.name(),
+            table_name,
+            schema,
+            self.client.clone(),
+        )))
+    }
+
+    #[async_backtrace::framed]
+    async fn list_tables(&self) -> Result<Vec<Arc<dyn Table>>> {
+        let dialect = self.client.dialect();
+        let rows = self
+            .client
+            .query(&dialect.list_tables_sql(self.name()))
+            .await?;
+        let mut tables = Vec::with_capacity(rows.len());
+        for table_name in rows
+            .into_iter()
+            .filter_map(|row| row.into_iter().next().flatten())
+        {
+            tables.push(self.get_table(&table_name).await?);
+        }
+        Ok(tables)
+    }
+}
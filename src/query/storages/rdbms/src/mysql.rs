@@ -0,0 +1,67 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use mysql_async::prelude::Queryable;
+use mysql_async::Pool;
+use mysql_async::Row;
+use mysql_async::Value;
+
+use crate::client::RdbmsClient;
+use crate::client::RdbmsDialect;
+use crate::client::TextRows;
+
+pub struct MysqlClient {
+    pool: Pool,
+}
+
+impl MysqlClient {
+    pub fn try_create(url: &str) -> Result<Self> {
+        let pool = Pool::from_url(url)
+            .map_err(|e| ErrorCode::BadArguments(format!("invalid mysql url: {}", e)))?;
+        Ok(MysqlClient { pool })
+    }
+}
+
+#[async_trait]
+impl RdbmsClient for MysqlClient {
+    fn dialect(&self) -> RdbmsDialect {
+        RdbmsDialect::Mysql
+    }
+
+    #[async_backtrace::framed]
+    async fn query(&self, sql: &str) -> Result<TextRows> {
+        let mut conn = self.pool.get_conn().await.map_err(from_mysql_error)?;
+        let rows: Vec<Row> = conn.query(sql).await.map_err(from_mysql_error)?;
+        Ok(rows
+            .into_iter()
+            .map(|row| row.unwrap().into_iter().map(value_to_text).collect())
+            .collect())
+    }
+}
+
+// Values of the text protocol are always bytes, except NULL.
+fn value_to_text(value: Value) -> Option<String> {
+    match value {
+        Value::NULL => None,
+        Value::Bytes(bytes) => Some(String::from_utf8_lossy(&bytes).into_owned()),
+        other => Some(other.as_sql(true).trim_matches('\'').to_string()),
+    }
+}
+
+fn from_mysql_error(e: mysql_async::Error) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("mysql query failed: {}", e))
+}
@@ -0,0 +1,81 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use log::warn;
+use tokio_postgres::Config;
+use tokio_postgres::NoTls;
+use tokio_postgres::SimpleQueryMessage;
+
+use crate::client::RdbmsClient;
+use crate::client::RdbmsDialect;
+use crate::client::TextRows;
+
+pub struct PostgresClient {
+    config: Config,
+}
+
+impl PostgresClient {
+    pub fn try_create(url: &str) -> Result<Self> {
+        let config = url
+            .parse::<Config>()
+            .map_err(|e| ErrorCode::BadArguments(format!("invalid postgres url: {}", e)))?;
+        Ok(PostgresClient { config })
+    }
+}
+
+#[async_trait]
+impl RdbmsClient for PostgresClient {
+    fn dialect(&self) -> RdbmsDialect {
+        RdbmsDialect::Postgres
+    }
+
+    #[async_backtrace::framed]
+    async fn query(&self, sql: &str) -> Result<TextRows> {
+        let (client, connection) = self
+            .config
+            .connect(NoTls)
+            .await
+            .map_err(from_postgres_error)?;
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                warn!("postgres connection error: {}", e);
+            }
+        });
+
+        // The simple query protocol returns all the values in text format.
+        let messages = client
+            .simple_query(sql)
+            .await
+            .map_err(from_postgres_error)?;
+        let mut rows = vec![];
+        for message in messages {
+            if let SimpleQueryMessage::Row(row) = message {
+                rows.push(
+                    (0..row.len())
+                        .map(|i| row.get(i).map(|v| v.to_string()))
+                        .collect(),
+                );
+            }
+        }
+        Ok(rows)
+    }
+}
+
+fn from_postgres_error(e: tokio_postgres::Error) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("postgres query failed: {}", e))
+}
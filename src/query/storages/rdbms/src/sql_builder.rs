@@ -0,0 +1,226 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_catalog::plan::PushDownInfo;
+use common_expression::types::date::date_to_string;
+use common_expression::types::timestamp::timestamp_to_string;
+use common_expression::types::DataType;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+
+use crate::client::RdbmsDialect;
+
+/// Build the query sent to the remote database to scan a table.
+///
+/// The filter is pushed down on a best effort basis: the conjuncts which can not be
+/// translated are skipped, and the filter is still evaluated after the scan.
+/// The limit is pushed down only if the whole filter is translated exactly and there is
+/// no order by, otherwise the remote limit could cut rows that pass the local filter.
+pub fn build_select_sql(
+    dialect: RdbmsDialect,
+    db_name: &str,
+    table_name: &str,
+    columns: &[String],
+    push_downs: &Option<PushDownInfo>,
+) -> String {
+    let projection = if columns.is_empty() {
+        "1".to_string()
+    } else {
+        columns
+            .iter()
+            .map(|c| dialect.quote_ident(c))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let mut sql = format!(
+        "SELECT {} FROM {}.{}",
+        projection,
+        dialect.quote_ident(db_name),
+        dialect.quote_ident(table_name)
+    );
+
+    let Some(push_downs) = push_downs else {
+        return sql;
+    };
+
+    let mut filter_exact = true;
+    if let Some(filter) = &push_downs.filter {
+        let mut conjuncts = vec![];
+        split_conjuncts(filter, &mut conjuncts);
+        let translated = conjuncts
+            .iter()
+            .filter_map(|expr| expr_to_sql(dialect, expr, false, &mut filter_exact))
+            .collect::<Vec<_>>();
+        filter_exact &= translated.len() == conjuncts.len();
+        if !translated.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&translated.join(" AND "));
+        }
+    }
+
+    if let Some(limit) = push_downs.limit {
+        if filter_exact && push_downs.order_by.is_empty() {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+    }
+    sql
+}
+
+fn split_conjuncts<'a>(expr: &'a RemoteExpr<String>, conjuncts: &mut Vec<&'a RemoteExpr<String>>) {
+    match expr {
+        RemoteExpr::FunctionCall { id, args, .. }
+            if matches!(id.name().as_ref(), "and" | "and_filters") =>
+        {
+            for arg in args {
+                split_conjuncts(arg, conjuncts);
+            }
+        }
+        _ => conjuncts.push(expr),
+    }
+}
+
+fn data_type_of(expr: &RemoteExpr<String>) -> &DataType {
+    match expr {
+        RemoteExpr::Constant { data_type, .. } => data_type,
+        RemoteExpr::ColumnRef { data_type, .. } => data_type,
+        RemoteExpr::Cast { dest_type, .. } => dest_type,
+        RemoteExpr::FunctionCall { return_type, .. } => return_type,
+    }
+}
+
+// Whether the cast keeps every value unchanged, so that it can be dropped remotely.
+fn is_identity_cast(src_type: &DataType, dest_type: &DataType) -> bool {
+    match (src_type.remove_nullable(), dest_type.remove_nullable()) {
+        (DataType::Number(src), DataType::Number(dest)) => src.can_lossless_cast_to(dest),
+        (src, dest) => src == dest,
+    }
+}
+
+// Translate an expression into the remote dialect, returns None if it can not be translated.
+//
+// String comparisons depend on the collation of the remote database, e.g. the default
+// collation of mysql is case insensitive. They are only translated when not negated,
+// so that the remote database returns a superset of the matched rows, and `exact` is
+// cleared to tell the caller that the rows must be filtered again.
+fn expr_to_sql(
+    dialect: RdbmsDialect,
+    expr: &RemoteExpr<String>,
+    negated: bool,
+    exact: &mut bool,
+) -> Option<String> {
+    match expr {
+        RemoteExpr::Constant { scalar, .. } => scalar_to_sql(dialect, scalar),
+        RemoteExpr::ColumnRef { id, .. } => Some(dialect.quote_ident(id)),
+        RemoteExpr::Cast {
+            is_try: false,
+            expr: inner,
+            dest_type,
+            ..
+        } => {
+            if is_identity_cast(data_type_of(inner), dest_type) {
+                expr_to_sql(dialect, inner, negated, exact)
+            } else {
+                None
+            }
+        }
+        RemoteExpr::Cast { .. } => None,
+        RemoteExpr::FunctionCall { id, args, .. } => {
+            let name = id.name();
+            match (name.as_ref(), args.as_slice()) {
+                ("and" | "and_filters" | "or", args) if !args.is_empty() => {
+                    let op = if name == "or" { " OR " } else { " AND " };
+                    let args = args
+                        .iter()
+                        .map(|arg| expr_to_sql(dialect, arg, negated, exact))
+                        .collect::<Option<Vec<_>>>()?;
+                    Some(format!("({})", args.join(op)))
+                }
+                ("not", [arg]) => Some(format!(
+                    "(NOT {})",
+                    expr_to_sql(dialect, arg, !negated, exact)?
+                )),
+                ("is_null", [arg]) => Some(format!(
+                    "({} IS NULL)",
+                    expr_to_sql(dialect, arg, negated, exact)?
+                )),
+                ("is_not_null", [arg]) => Some(format!(
+                    "({} IS NOT NULL)",
+                    expr_to_sql(dialect, arg, negated, exact)?
+                )),
+                ("eq" | "noteq" | "lt" | "lte" | "gt" | "gte", [lhs, rhs]) => {
+                    let is_string = |e: &RemoteExpr<String>| {
+                        data_type_of(e).remove_nullable() == DataType::String
+                    };
+                    if is_string(lhs) || is_string(rhs) {
+                        if negated || name != "eq" {
+                            return None;
+                        }
+                        *exact = false;
+                    }
+                    let op = match name.as_ref() {
+                        "eq" => "=",
+                        "noteq" => "<>",
+                        "lt" => "<",
+                        "lte" => "<=",
+                        "gt" => ">",
+                        _ => ">=",
+                    };
+                    Some(format!(
+                        "({} {} {})",
+                        expr_to_sql(dialect, lhs, negated, exact)?,
+                        op,
+                        expr_to_sql(dialect, rhs, negated, exact)?
+                    ))
+                }
+                ("like", [lhs, rhs @ RemoteExpr::Constant { .. }]) if !negated => {
+                    *exact = false;
+                    Some(format!(
+                        "({} LIKE {})",
+                        expr_to_sql(dialect, lhs, negated, exact)?,
+                        expr_to_sql(dialect, rhs, negated, exact)?
+                    ))
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
+fn scalar_to_sql(dialect: RdbmsDialect, scalar: &Scalar) -> Option<String> {
+    match scalar {
+        Scalar::Null => Some("NULL".to_string()),
+        Scalar::Number(n) => {
+            let text = n.to_string();
+            // NaN and infinity have no literal in sql.
+            if text
+                .chars()
+                .all(|c| c.is_ascii_digit() || "+-.e".contains(c))
+            {
+                Some(text)
+            } else {
+                None
+            }
+        }
+        Scalar::Decimal(d) => Some(d.to_string()),
+        Scalar::Boolean(true) => Some("TRUE".to_string()),
+        Scalar::Boolean(false) => Some("FALSE".to_string()),
+        Scalar::String(s) => {
+            let s = std::str::from_utf8(s).ok()?;
+            Some(dialect.quote_string(s))
+        }
+        Scalar::Date(d) => Some(format!("'{}'", date_to_string(*d, chrono_tz::UTC))),
+        Scalar::Timestamp(ts) => Some(format!("'{}'", timestamp_to_string(*ts, chrono_tz::UTC))),
+        _ => None,
+    }
+}
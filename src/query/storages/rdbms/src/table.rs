@@ -0,0 +1,173 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_expression::TableSchema;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::Pipeline;
+use common_pipeline_core::SourcePipeBuilder;
+
+use crate::client::RdbmsClientRef;
+use crate::partition::RdbmsPartInfo;
+use crate::sql_builder::build_select_sql;
+use crate::table_source::RdbmsTableSource;
+
+// engine option holding the name of the remote database (schema)
+const OPT_KEY_DATABASE: &str = "database";
+
+/// A table of a remote mysql or postgres database.
+///
+/// The table is read-only, each scan is sent to the remote database as a single query,
+/// with the projection and the filter pushed down.
+pub struct RdbmsTable {
+    info: TableInfo,
+    client: RdbmsClientRef,
+}
+
+impl RdbmsTable {
+    pub fn create(
+        catalog: &str,
+        db_name: &str,
+        table_name: &str,
+        schema: TableSchema,
+        client: RdbmsClientRef,
+    ) -> Self {
+        let engine = match client.dialect() {
+            crate::RdbmsDialect::Mysql => "mysql",
+            crate::RdbmsDialect::Postgres => "postgres",
+        };
+        let info = TableInfo {
+            ident: TableIdent::new(0, 0),
+            desc: format!("'{}'.'{}'", db_name, table_name),
+            name: table_name.to_string(),
+            meta: TableMeta {
+                schema: Arc::new(schema),
+                catalog: catalog.to_string(),
+                engine: engine.to_string(),
+                engine_options: BTreeMap::from([(
+                    OPT_KEY_DATABASE.to_string(),
+                    db_name.to_string(),
+                )]),
+                created_on: Utc::now(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        RdbmsTable { info, client }
+    }
+
+    /// Rebuild the table from the table info, e.g. in the `DataSourcePlan`.
+    pub fn try_create_from_info(info: &TableInfo, client: RdbmsClientRef) -> Result<Self> {
+        if !info.meta.engine_options.contains_key(OPT_KEY_DATABASE) {
+            return Err(ErrorCode::UnknownTable(format!(
+                "Table {} is not a table of remote database",
+                info.desc
+            )));
+        }
+        Ok(RdbmsTable {
+            info: info.clone(),
+            client,
+        })
+    }
+
+    fn db_name(&self) -> &str {
+        &self.info.meta.engine_options[OPT_KEY_DATABASE]
+    }
+}
+
+#[async_trait]
+impl Table for RdbmsTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+
+    fn support_column_projection(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let schema = self.info.schema();
+        let projection = PushDownInfo::projection_of_push_downs(&schema, &push_downs);
+        let columns = projection
+            .project_schema(&schema)
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect::<Vec<_>>();
+
+        let sql = build_select_sql(
+            self.client.dialect(),
+            self.db_name(),
+            &self.info.name,
+            &columns,
+            &push_downs,
+        );
+        let part: Arc<Box<dyn PartInfo>> = Arc::new(Box::new(RdbmsPartInfo { sql }));
+        Ok((
+            PartStatistics::default(),
+            Partitions::create_nolazy(PartitionsShuffleKind::Seq, vec![part]),
+        ))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let output_schema = Arc::new(DataSchema::from(plan.schema()));
+        let output = OutputPort::create();
+        let mut source_builder = SourcePipeBuilder::create();
+        source_builder.add_source(
+            output.clone(),
+            RdbmsTableSource::create(ctx, self.client.clone(), output, output_schema)?,
+        );
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        None
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+
+use crate::client::RdbmsClientRef;
+use crate::partition::RdbmsPartInfo;
+use crate::type_mapping::rows_to_block;
+
+/// Runs the queries of the partitions against the remote database.
+pub struct RdbmsTableSource {
+    ctx: Arc<dyn TableContext>,
+    client: RdbmsClientRef,
+    output_schema: DataSchemaRef,
+}
+
+impl RdbmsTableSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        client: RdbmsClientRef,
+        output: Arc<OutputPort>,
+        output_schema: DataSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, RdbmsTableSource {
+            ctx,
+            client,
+            output_schema,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for RdbmsTableSource {
+    const NAME: &'static str = "RdbmsTableSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        let Some(part) = self.ctx.get_partition() else {
+            return Ok(None);
+        };
+        let part = RdbmsPartInfo::from_part(&part)?;
+        let rows = self.client.query(&part.sql).await?;
+        let block = rows_to_block(&self.output_schema, rows)?;
+        Ok(Some(block))
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::serialize::read_decimal_with_size;
+use common_expression::serialize::uniform_date;
+use common_expression::types::date::string_to_date;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::timestamp::string_to_timestamp;
+use common_expression::types::DataType;
+use common_expression::types::DecimalDataType;
+use common_expression::types::DecimalSize;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+
+use crate::client::TextRows;
+
+const MAX_DECIMAL128_PRECISION: u64 = 38;
+
+/// Convert the rows of `information_schema.columns` into a table schema.
+///
+/// Each row is (column_name, column_type, is_nullable, numeric_precision, numeric_scale).
+pub fn columns_to_schema(rows: TextRows) -> Result<TableSchema> {
+    let mut fields = Vec::with_capacity(rows.len());
+    for row in rows {
+        let mut row = row.into_iter();
+        let mut next = || row.next().flatten();
+        let name = next().ok_or_else(|| ErrorCode::Internal("column name should not be null"))?;
+        let column_type = next().unwrap_or_default();
+        let nullable = next()
+            .map(|v| v.eq_ignore_ascii_case("YES"))
+            .unwrap_or(true);
+        let precision = next().and_then(|v| v.parse::<u64>().ok());
+        let scale = next().and_then(|v| v.parse::<u64>().ok());
+
+        let data_type = remote_type_to_table_type(&column_type, precision, scale);
+        let data_type = if nullable {
+            data_type.wrap_nullable()
+        } else {
+            data_type
+        };
+        fields.push(TableField::new(&name, data_type));
+    }
+    Ok(TableSchema::new(fields))
+}
+
+/// Map a mysql or postgres column type to the table data type.
+///
+/// Types which have no counterpart, like `json`, `uuid` or `enum`, are read as strings.
+pub fn remote_type_to_table_type(
+    column_type: &str,
+    precision: Option<u64>,
+    scale: Option<u64>,
+) -> TableDataType {
+    let column_type = column_type.to_lowercase();
+    let unsigned = column_type.contains("unsigned");
+    // strip the length and modifiers, e.g. 'int(10) unsigned' -> 'int'
+    let base_type = column_type
+        .split(|c| c == '(')
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches(" unsigned")
+        .trim_end_matches(" zerofill")
+        .trim();
+
+    let number = |signed: NumberDataType, unsigned_type: NumberDataType| {
+        if unsigned {
+            TableDataType::Number(unsigned_type)
+        } else {
+            TableDataType::Number(signed)
+        }
+    };
+
+    match base_type {
+        "tinyint" => number(NumberDataType::Int8, NumberDataType::UInt8),
+        "smallint" | "int2" | "smallserial" => {
+            number(NumberDataType::Int16, NumberDataType::UInt16)
+        }
+        "mediumint" | "int" | "integer" | "int4" | "serial" => {
+            number(NumberDataType::Int32, NumberDataType::UInt32)
+        }
+        "bigint" | "int8" | "bigserial" => number(NumberDataType::Int64, NumberDataType::UInt64),
+        "float" | "real" | "float4" => TableDataType::Number(NumberDataType::Float32),
+        "double" | "double precision" | "float8" => TableDataType::Number(NumberDataType::Float64),
+        "decimal" | "numeric" => match (precision, scale) {
+            (Some(precision), scale) if precision > 0 && precision <= MAX_DECIMAL128_PRECISION => {
+                TableDataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+                    precision: precision as u8,
+                    scale: scale.unwrap_or(0) as u8,
+                }))
+            }
+            // numeric without precision in postgres can hold any number of digits.
+            _ => TableDataType::String,
+        },
+        "bool" | "boolean" => TableDataType::Boolean,
+        "date" => TableDataType::Date,
+        "datetime" | "timestamp" | "timestamp without time zone" | "timestamp with time zone" => {
+            TableDataType::Timestamp
+        }
+        _ => TableDataType::String,
+    }
+}
+
+/// Build a data block from the rows returned by the remote database.
+pub fn rows_to_block(schema: &DataSchema, rows: TextRows) -> Result<DataBlock> {
+    let num_rows = rows.len();
+    if schema.fields().is_empty() {
+        return Ok(DataBlock::new(vec![], num_rows));
+    }
+
+    let mut builders = schema
+        .fields()
+        .iter()
+        .map(|f| ColumnBuilder::with_capacity(f.data_type(), num_rows))
+        .collect::<Vec<_>>();
+    for row in rows {
+        if row.len() != builders.len() {
+            return Err(ErrorCode::ReadTableDataError(format!(
+                "expect {} columns from remote database, got {}",
+                builders.len(),
+                row.len()
+            )));
+        }
+        for ((value, builder), field) in row.iter().zip(builders.iter_mut()).zip(schema.fields()) {
+            let scalar = match value {
+                None if field.data_type().is_nullable() => Scalar::Null,
+                None => {
+                    return Err(ErrorCode::ReadTableDataError(format!(
+                        "unexpected null value of non-nullable column {}",
+                        field.name()
+                    )));
+                }
+                Some(text) => text_to_scalar(text, &field.data_type().remove_nullable())?,
+            };
+            builder.push(scalar.as_ref());
+        }
+    }
+
+    let columns = builders
+        .into_iter()
+        .map(|b| b.build())
+        .collect::<Vec<Column>>();
+    Ok(DataBlock::new_from_columns(columns))
+}
+
+/// Parse a value in text format of the remote database.
+pub fn text_to_scalar(text: &str, data_type: &DataType) -> Result<Scalar> {
+    let invalid = || {
+        ErrorCode::ReadTableDataError(format!(
+            "cannot parse '{}' from remote database as {}",
+            text, data_type
+        ))
+    };
+    macro_rules! parse_number {
+        ($ty:ty, $variant:ident) => {
+            text.trim()
+                .parse::<$ty>()
+                .map(|v| Scalar::Number(NumberScalar::$variant(v.into())))
+                .map_err(|_| invalid())
+        };
+    }
+
+    match data_type {
+        DataType::Number(NumberDataType::UInt8) => parse_number!(u8, UInt8),
+        DataType::Number(NumberDataType::UInt16) => parse_number!(u16, UInt16),
+        DataType::Number(NumberDataType::UInt32) => parse_number!(u32, UInt32),
+        DataType::Number(NumberDataType::UInt64) => parse_number!(u64, UInt64),
+        DataType::Number(NumberDataType::Int8) => parse_number!(i8, Int8),
+        DataType::Number(NumberDataType::Int16) => parse_number!(i16, Int16),
+        DataType::Number(NumberDataType::Int32) => parse_number!(i32, Int32),
+        DataType::Number(NumberDataType::Int64) => parse_number!(i64, Int64),
+        DataType::Number(NumberDataType::Float32) => parse_number!(f32, Float32),
+        DataType::Number(NumberDataType::Float64) => parse_number!(f64, Float64),
+        DataType::Decimal(DecimalDataType::Decimal128(size)) => {
+            let (n, _) = read_decimal_with_size::<i128>(text.trim().as_bytes(), *size, true)
+                .map_err(|_| invalid())?;
+            Ok(Scalar::Decimal(DecimalScalar::Decimal128(n, *size)))
+        }
+        DataType::Boolean => match text.trim().to_lowercase().as_str() {
+            "1" | "t" | "true" | "y" | "yes" | "on" => Ok(Scalar::Boolean(true)),
+            "0" | "f" | "false" | "n" | "no" | "off" => Ok(Scalar::Boolean(false)),
+            _ => Err(invalid()),
+        },
+        DataType::Date => string_to_date(text.trim(), chrono_tz::UTC)
+            .map(|d| Scalar::Date(uniform_date(d)))
+            .ok_or_else(invalid),
+        DataType::Timestamp => string_to_timestamp(text.trim(), chrono_tz::UTC)
+            .map(|ts| Scalar::Timestamp(ts.timestamp_micros()))
+            .ok_or_else(invalid),
+        DataType::String => Ok(Scalar::String(text.as_bytes().to_vec())),
+        _ => Err(ErrorCode::Unimplemented(format!(
+            "reading {} from remote database is not supported",
+            data_type
+        ))),
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_base::base::tokio;
+use common_catalog::catalog::Catalog;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_storages_rdbms::RdbmsCatalog;
+use common_storages_rdbms::RdbmsClient;
+use common_storages_rdbms::RdbmsDialect;
+use common_storages_rdbms::TextRows;
+
+/// A client returning canned rows for the queries.
+struct MockClient {
+    dialect: RdbmsDialect,
+    responses: HashMap<String, TextRows>,
+}
+
+impl MockClient {
+    fn create(dialect: RdbmsDialect) -> Self {
+        let mut responses = HashMap::new();
+        let text = |values: &[Option<&str>]| -> Vec<Option<String>> {
+            values.iter().map(|v| v.map(|v| v.to_string())).collect()
+        };
+        responses.insert(dialect.list_databases_sql(), vec![
+            text(&[Some("db1")]),
+            text(&[Some("db2")]),
+        ]);
+        responses.insert(dialect.list_tables_sql("db1"), vec![text(&[Some("t1")])]);
+        responses.insert(dialect.list_tables_sql("db2"), vec![]);
+        responses.insert(dialect.list_columns_sql("db1", "t1"), vec![
+            text(&[
+                Some("id"),
+                Some("bigint"),
+                Some("NO"),
+                Some("19"),
+                Some("0"),
+            ]),
+            text(&[Some("name"), Some("varchar(20)"), Some("YES"), None, None]),
+        ]);
+        MockClient { dialect, responses }
+    }
+}
+
+#[async_trait]
+impl RdbmsClient for MockClient {
+    fn dialect(&self) -> RdbmsDialect {
+        self.dialect
+    }
+
+    async fn query(&self, sql: &str) -> Result<TextRows> {
+        Ok(self.responses.get(sql).cloned().unwrap_or_default())
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_rdbms_catalog() -> Result<()> {
+    for dialect in [RdbmsDialect::Mysql, RdbmsDialect::Postgres] {
+        let client = Arc::new(MockClient::create(dialect));
+        let catalog = RdbmsCatalog::create_with_client("ctl", client);
+
+        let databases = catalog.list_databases("").await?;
+        let names = databases.iter().map(|db| db.name()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["db1", "db2"]);
+
+        let tables = catalog.list_tables("", "db1").await?;
+        assert_eq!(tables.len(), 1);
+        assert!(catalog.list_tables("", "db2").await?.is_empty());
+
+        let table = catalog.get_table("", "db1", "t1").await?;
+        let schema = table.schema();
+        assert_eq!(table.get_table_info().catalog(), "ctl");
+        assert_eq!(schema.fields().len(), 2);
+        assert_eq!(
+            schema.field(0).data_type(),
+            &TableDataType::Number(NumberDataType::Int64)
+        );
+        assert_eq!(
+            schema.field(1).data_type(),
+            &TableDataType::String.wrap_nullable()
+        );
+
+        // the table can be rebuilt from its table info.
+        let rebuilt = catalog.get_table_by_info(table.get_table_info())?;
+        assert_eq!(rebuilt.name(), "t1");
+
+        let err = catalog.get_table("", "db1", "t2").await.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UNKNOWN_TABLE);
+        let err = catalog.get_database("", "db3").await.err().unwrap();
+        assert_eq!(err.code(), ErrorCode::UNKNOWN_DATABASE);
+
+        // the catalog is read only, the other operations fail instead of panicking.
+        let err = catalog.list_tables_history("", "db1").await.err().unwrap();
+        assert_eq!(err.code(), ErrorCode::UNIMPLEMENTED);
+    }
+    Ok(())
+}
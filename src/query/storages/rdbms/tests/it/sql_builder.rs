@@ -0,0 +1,253 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_catalog::plan::PushDownInfo;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::FunctionID;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_storages_rdbms::build_select_sql;
+use common_storages_rdbms::RdbmsDialect;
+
+fn column(name: &str, data_type: DataType) -> RemoteExpr<String> {
+    RemoteExpr::ColumnRef {
+        span: None,
+        id: name.to_string(),
+        data_type,
+        display_name: name.to_string(),
+    }
+}
+
+fn constant(scalar: Scalar, data_type: DataType) -> RemoteExpr<String> {
+    RemoteExpr::Constant {
+        span: None,
+        scalar,
+        data_type,
+    }
+}
+
+fn func(name: &str, args: Vec<RemoteExpr<String>>) -> RemoteExpr<String> {
+    RemoteExpr::FunctionCall {
+        span: None,
+        id: FunctionID::Builtin {
+            name: name.to_string(),
+            id: 0,
+        },
+        generics: vec![],
+        args,
+        return_type: DataType::Boolean,
+    }
+}
+
+fn int_column(name: &str) -> RemoteExpr<String> {
+    column(name, DataType::Number(NumberDataType::Int32))
+}
+
+fn int_value(v: i32) -> RemoteExpr<String> {
+    constant(
+        Scalar::Number(NumberScalar::Int32(v)),
+        DataType::Number(NumberDataType::Int32),
+    )
+}
+
+fn string_value(v: &str) -> RemoteExpr<String> {
+    constant(Scalar::String(v.as_bytes().to_vec()), DataType::String)
+}
+
+fn push_downs(filter: Option<RemoteExpr<String>>, limit: Option<usize>) -> Option<PushDownInfo> {
+    Some(PushDownInfo {
+        filter,
+        limit,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_select_projection() {
+    let columns = vec!["id".to_string(), "na`me".to_string()];
+    assert_eq!(
+        build_select_sql(RdbmsDialect::Mysql, "db", "t", &columns, &None),
+        "SELECT `id`, `na``me` FROM `db`.`t`"
+    );
+    assert_eq!(
+        build_select_sql(RdbmsDialect::Postgres, "public", "t", &columns, &None),
+        "SELECT \"id\", \"na`me\" FROM \"public\".\"t\""
+    );
+    assert_eq!(
+        build_select_sql(RdbmsDialect::Postgres, "public", "t", &[], &None),
+        "SELECT 1 FROM \"public\".\"t\""
+    );
+}
+
+#[test]
+fn test_select_filter_and_limit() {
+    let columns = vec!["id".to_string()];
+
+    // exactly translated filter, limit is pushed down.
+    let filter = func("and_filters", vec![
+        func("gt", vec![int_column("id"), int_value(10)]),
+        func("lt", vec![int_column("id"), int_value(20)]),
+    ]);
+    assert_eq!(
+        build_select_sql(
+            RdbmsDialect::Mysql,
+            "db",
+            "t",
+            &columns,
+            &push_downs(Some(filter), Some(5))
+        ),
+        "SELECT `id` FROM `db`.`t` WHERE (`id` > 10) AND (`id` < 20) LIMIT 5"
+    );
+
+    // string comparisons may match more rows remotely, limit is not pushed down.
+    let filter = func("and_filters", vec![
+        func("gt", vec![int_column("id"), int_value(10)]),
+        func("eq", vec![
+            column("name", DataType::String),
+            string_value("it's"),
+        ]),
+    ]);
+    assert_eq!(
+        build_select_sql(
+            RdbmsDialect::Mysql,
+            "db",
+            "t",
+            &columns,
+            &push_downs(Some(filter), Some(5))
+        ),
+        "SELECT `id` FROM `db`.`t` WHERE (`id` > 10) AND (`name` = 'it''s')"
+    );
+
+    let filter = func("like", vec![
+        column("name", DataType::String),
+        string_value("a%"),
+    ]);
+    assert_eq!(
+        build_select_sql(
+            RdbmsDialect::Mysql,
+            "db",
+            "t",
+            &columns,
+            &push_downs(Some(filter), Some(5))
+        ),
+        "SELECT `id` FROM `db`.`t` WHERE (`name` LIKE 'a%')"
+    );
+
+    // conjuncts can not be translated are skipped, limit is not pushed down.
+    let filter = func("and_filters", vec![
+        func("gt", vec![int_column("id"), int_value(10)]),
+        func("regexp", vec![
+            column("name", DataType::String),
+            string_value("^a"),
+        ]),
+    ]);
+    assert_eq!(
+        build_select_sql(
+            RdbmsDialect::Postgres,
+            "public",
+            "t",
+            &columns,
+            &push_downs(Some(filter), Some(5))
+        ),
+        "SELECT \"id\" FROM \"public\".\"t\" WHERE (\"id\" > 10)"
+    );
+
+    // negated string comparisons depend on the remote collation.
+    let filter = func("not", vec![func("eq", vec![
+        column("name", DataType::String),
+        string_value("a"),
+    ])]);
+    assert_eq!(
+        build_select_sql(
+            RdbmsDialect::Mysql,
+            "db",
+            "t",
+            &columns,
+            &push_downs(Some(filter), None)
+        ),
+        "SELECT `id` FROM `db`.`t`"
+    );
+
+    let filter = func("or", vec![
+        func("is_null", vec![int_column("id")]),
+        func("lte", vec![int_column("id"), int_value(-1)]),
+    ]);
+    assert_eq!(
+        build_select_sql(
+            RdbmsDialect::Mysql,
+            "db",
+            "t",
+            &columns,
+            &push_downs(Some(filter), None)
+        ),
+        "SELECT `id` FROM `db`.`t` WHERE ((`id` IS NULL) OR (`id` <= -1))"
+    );
+}
+
+#[test]
+fn test_select_filter_with_cast() {
+    let columns = vec!["id".to_string()];
+    let cast = |expr: RemoteExpr<String>, dest_type: DataType| RemoteExpr::Cast {
+        span: None,
+        is_try: false,
+        expr: Box::new(expr),
+        dest_type,
+    };
+
+    // lossless casts keep the values, they are dropped remotely.
+    let filter = func("eq", vec![
+        cast(int_column("id"), DataType::Number(NumberDataType::Int64)),
+        constant(
+            Scalar::Number(NumberScalar::Int64(1)),
+            DataType::Number(NumberDataType::Int64),
+        ),
+    ]);
+    assert_eq!(
+        build_select_sql(
+            RdbmsDialect::Mysql,
+            "db",
+            "t",
+            &columns,
+            &push_downs(Some(filter), Some(5))
+        ),
+        "SELECT `id` FROM `db`.`t` WHERE (`id` = 1) LIMIT 5"
+    );
+
+    // `CAST(f AS INT) = 1` also matches 1.2, it can not be sent as `f = 1`.
+    let filter = func("eq", vec![
+        cast(
+            column("f", DataType::Number(NumberDataType::Float64)),
+            DataType::Number(NumberDataType::Int32),
+        ),
+        int_value(1),
+    ]);
+    assert_eq!(
+        build_select_sql(
+            RdbmsDialect::Mysql,
+            "db",
+            "t",
+            &columns,
+            &push_downs(Some(filter), Some(5))
+        ),
+        "SELECT `id` FROM `db`.`t`"
+    );
+}
+
+#[test]
+fn test_quote_string() {
+    assert_eq!(RdbmsDialect::Mysql.quote_string("a\\'b"), "'a\\\\''b'");
+    assert_eq!(RdbmsDialect::Postgres.quote_string("a\\'b"), "'a\\''b'");
+}
@@ -0,0 +1,145 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::DataType;
+use common_expression::types::DecimalDataType;
+use common_expression::types::DecimalSize;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_storages_rdbms::remote_type_to_table_type;
+use common_storages_rdbms::rows_to_block;
+use common_storages_rdbms::text_to_scalar;
+
+#[test]
+fn test_remote_type_to_table_type() {
+    let cases = vec![
+        ("int(11)", TableDataType::Number(NumberDataType::Int32)),
+        (
+            "int(10) unsigned",
+            TableDataType::Number(NumberDataType::UInt32),
+        ),
+        (
+            "bigint unsigned",
+            TableDataType::Number(NumberDataType::UInt64),
+        ),
+        ("smallint", TableDataType::Number(NumberDataType::Int16)),
+        (
+            "double precision",
+            TableDataType::Number(NumberDataType::Float64),
+        ),
+        ("boolean", TableDataType::Boolean),
+        ("datetime", TableDataType::Timestamp),
+        ("timestamp with time zone", TableDataType::Timestamp),
+        ("date", TableDataType::Date),
+        ("varchar(255)", TableDataType::String),
+        ("character varying", TableDataType::String),
+        ("jsonb", TableDataType::String),
+    ];
+    for (remote_type, expected) in cases {
+        assert_eq!(
+            remote_type_to_table_type(remote_type, None, None),
+            expected,
+            "{}",
+            remote_type
+        );
+    }
+
+    assert_eq!(
+        remote_type_to_table_type("decimal(10,2)", Some(10), Some(2)),
+        TableDataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+            precision: 10,
+            scale: 2,
+        }))
+    );
+    // numeric without precision, or exceeds the max precision of decimal128.
+    assert_eq!(
+        remote_type_to_table_type("numeric", None, None),
+        TableDataType::String
+    );
+    assert_eq!(
+        remote_type_to_table_type("numeric", Some(65), Some(0)),
+        TableDataType::String
+    );
+}
+
+#[test]
+fn test_text_to_scalar() -> Result<()> {
+    assert_eq!(
+        text_to_scalar("-12", &DataType::Number(NumberDataType::Int64))?,
+        Scalar::Number(NumberScalar::Int64(-12))
+    );
+    assert_eq!(
+        text_to_scalar("t", &DataType::Boolean)?,
+        Scalar::Boolean(true)
+    );
+    assert_eq!(
+        text_to_scalar("0", &DataType::Boolean)?,
+        Scalar::Boolean(false)
+    );
+    assert_eq!(
+        text_to_scalar("1970-01-02", &DataType::Date)?,
+        Scalar::Date(1)
+    );
+    assert_eq!(
+        text_to_scalar("1970-01-01 00:00:01", &DataType::Timestamp)?,
+        Scalar::Timestamp(1_000_000)
+    );
+    let size = DecimalSize {
+        precision: 10,
+        scale: 2,
+    };
+    assert_eq!(
+        text_to_scalar(
+            "123.45",
+            &DataType::Decimal(DecimalDataType::Decimal128(size))
+        )?,
+        Scalar::Decimal(DecimalScalar::Decimal128(12345, size))
+    );
+    assert!(text_to_scalar("abc", &DataType::Number(NumberDataType::Int32)).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_rows_to_block() -> Result<()> {
+    let schema = DataSchema::new(vec![
+        DataField::new("id", DataType::Number(NumberDataType::Int32)),
+        DataField::new("name", DataType::String.wrap_nullable()),
+    ]);
+    let rows = vec![vec![Some("1".to_string()), Some("a".to_string())], vec![
+        Some("2".to_string()),
+        None,
+    ]];
+    let block = rows_to_block(&schema, rows)?;
+    assert_eq!(block.num_rows(), 2);
+    assert_eq!(block.num_columns(), 2);
+    assert_eq!(
+        block.get_by_offset(1).value.index(1).unwrap().to_owned(),
+        Scalar::Null
+    );
+
+    // null value of non-nullable column.
+    let rows = vec![vec![None, Some("a".to_string())]];
+    assert!(rows_to_block(&schema, rows).is_err());
+
+    // no columns are projected, e.g. `SELECT count(*)`.
+    let block = rows_to_block(&DataSchema::empty(), vec![vec![], vec![]])?;
+    assert_eq!(block.num_rows(), 2);
+    Ok(())
+}
@@ -0,0 +1,98 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use common_tracing::AuditEvent;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+impl SystemLogElement for AuditEvent {
+    const TABLE_NAME: &'static str = "audit_log";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("seq", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("event_type", TableDataType::String),
+            TableField::new("tenant", TableDataType::String),
+            TableField::new("user", TableDataType::String),
+            TableField::new("client_address", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("object", TableDataType::String),
+            TableField::new("detail", TableDataType::String),
+            TableField::new("success", TableDataType::Boolean),
+            TableField::new("error_code", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("error_message", TableDataType::String),
+            TableField::new("prev_hash", TableDataType::String),
+            TableField::new("hash", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.seq)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.event_type.as_str().as_bytes().to_vec()).as_ref());
+        for value in [
+            &self.tenant,
+            &self.user,
+            &self.client_address,
+            &self.query_id,
+            &self.object,
+            &self.detail,
+        ] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.as_bytes().to_vec()).as_ref());
+        }
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Boolean(self.success).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Int32(self.error_code)).as_ref());
+        for value in [&self.error_message, &self.prev_hash, &self.hash] {
+            columns
+                .next()
+                .unwrap()
+                .push(Scalar::String(value.as_bytes().to_vec()).as_ref());
+        }
+        Ok(())
+    }
+}
+
+pub type AuditLogQueue = SystemLogQueue<AuditEvent>;
+pub type AuditLogTable = SystemLogTable<AuditEvent>;
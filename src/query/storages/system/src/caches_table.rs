@@ -14,8 +14,10 @@
 
 use std::sync::Arc;
 
+use common_cache::EvictionPolicy;
 use common_catalog::table::Table;
 use common_exception::Result;
+use common_expression::types::Float64Type;
 use common_expression::types::NumberDataType;
 use common_expression::types::StringType;
 use common_expression::types::UInt64Type;
@@ -29,6 +31,7 @@ use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_storages_fuse::TableContext;
 use storages_common_cache::CacheAccessor;
+use storages_common_cache::CacheHitStatistics;
 use storages_common_cache_manager::CacheManager;
 
 use crate::SyncOneBlockSystemTable;
@@ -50,10 +53,7 @@ impl SyncSystemTable for CachesTable {
 
     fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
         let local_node = ctx.get_cluster().local_id.clone();
-        let mut nodes = Vec::new();
-        let mut names = Vec::new();
-        let mut num_items = Vec::new();
-        let mut size = Vec::new();
+        let mut columns = CachesTableColumns::default();
 
         let cache_manager = CacheManager::instance();
 
@@ -67,78 +67,150 @@ impl SyncSystemTable for CachesTable {
         let table_data_cache = cache_manager.get_table_data_cache();
         let table_column_array_cache = cache_manager.get_table_data_array_cache();
 
-        if let Some(table_snapshot_cache) = table_snapshot_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("table_snapshot_cache");
-            num_items.push(table_snapshot_cache.len() as u64);
-            size.push(table_snapshot_cache.size());
+        if let Some(cache) = table_snapshot_cache {
+            columns.append_row(
+                &local_node,
+                "table_snapshot_cache",
+                cache.len(),
+                cache.size(),
+                cache.policy(),
+                cache.statistics(),
+            );
         }
-        if let Some(table_snapshot_statistic_cache) = table_snapshot_statistic_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("table_snapshot_statistic_cache");
-            num_items.push(table_snapshot_statistic_cache.len() as u64);
-            size.push(table_snapshot_statistic_cache.size());
+        if let Some(cache) = table_snapshot_statistic_cache {
+            columns.append_row(
+                &local_node,
+                "table_snapshot_statistic_cache",
+                cache.len(),
+                cache.size(),
+                cache.policy(),
+                cache.statistics(),
+            );
         }
 
-        if let Some(segment_info_cache) = segment_info_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("segment_info_cache");
-            num_items.push(segment_info_cache.len() as u64);
-            size.push(segment_info_cache.size());
+        if let Some(cache) = segment_info_cache {
+            columns.append_row(
+                &local_node,
+                "segment_info_cache",
+                cache.len(),
+                cache.size(),
+                cache.policy(),
+                cache.statistics(),
+            );
         }
 
-        if let Some(bloom_index_filter_cache) = bloom_index_filter_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("bloom_index_filter_cache");
-            num_items.push(bloom_index_filter_cache.len() as u64);
-            size.push(bloom_index_filter_cache.size());
+        if let Some(cache) = bloom_index_filter_cache {
+            columns.append_row(
+                &local_node,
+                "bloom_index_filter_cache",
+                cache.len(),
+                cache.size(),
+                cache.policy(),
+                cache.statistics(),
+            );
         }
 
-        if let Some(bloom_index_meta_cache) = bloom_index_meta_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("bloom_index_meta_cache");
-            num_items.push(bloom_index_meta_cache.len() as u64);
-            size.push(bloom_index_meta_cache.size());
+        if let Some(cache) = bloom_index_meta_cache {
+            columns.append_row(
+                &local_node,
+                "bloom_index_meta_cache",
+                cache.len(),
+                cache.size(),
+                cache.policy(),
+                cache.statistics(),
+            );
         }
 
-        if let Some(prune_partitions_cache) = prune_partitions_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("prune_partitions_cache");
-            num_items.push(prune_partitions_cache.len() as u64);
-            size.push(prune_partitions_cache.size());
+        if let Some(cache) = prune_partitions_cache {
+            columns.append_row(
+                &local_node,
+                "prune_partitions_cache",
+                cache.len(),
+                cache.size(),
+                cache.policy(),
+                cache.statistics(),
+            );
         }
 
-        if let Some(file_meta_data_cache) = file_meta_data_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("file_meta_data_cache");
-            num_items.push(file_meta_data_cache.len() as u64);
-            size.push(file_meta_data_cache.size());
+        if let Some(cache) = file_meta_data_cache {
+            columns.append_row(
+                &local_node,
+                "file_meta_data_cache",
+                cache.len(),
+                cache.size(),
+                cache.policy(),
+                cache.statistics(),
+            );
         }
 
-        if let Some(table_data_cache) = table_data_cache {
-            nodes.push(local_node.clone().into_bytes());
-            names.push("table_data_cache");
-            num_items.push(table_data_cache.len() as u64);
-            size.push(table_data_cache.size());
+        if let Some(cache) = table_data_cache {
+            columns.append_row(
+                &local_node,
+                "table_data_cache",
+                cache.len(),
+                cache.size(),
+                cache.policy(),
+                cache.statistics(),
+            );
         }
 
-        if let Some(table_column_array_cache) = table_column_array_cache {
-            nodes.push(local_node.into_bytes());
-            names.push("table_column_array_cache");
-            num_items.push(table_column_array_cache.len() as u64);
-            size.push(table_column_array_cache.size());
+        if let Some(cache) = table_column_array_cache {
+            columns.append_row(
+                &local_node,
+                "table_column_array_cache",
+                cache.len(),
+                cache.size(),
+                cache.policy(),
+                cache.statistics(),
+            );
         }
 
-        let names: Vec<_> = names.iter().map(|x| x.as_bytes().to_vec()).collect();
         Ok(DataBlock::new_from_columns(vec![
-            StringType::from_data(nodes),
-            StringType::from_data(names),
-            UInt64Type::from_data(num_items),
-            UInt64Type::from_data(size),
+            StringType::from_data(columns.nodes),
+            StringType::from_data(columns.names),
+            UInt64Type::from_data(columns.num_items),
+            UInt64Type::from_data(columns.size),
+            StringType::from_data(columns.policies),
+            UInt64Type::from_data(columns.hits),
+            UInt64Type::from_data(columns.misses),
+            Float64Type::from_data(columns.hit_rates),
         ]))
     }
 }
 
+#[derive(Default)]
+struct CachesTableColumns {
+    nodes: Vec<Vec<u8>>,
+    names: Vec<Vec<u8>>,
+    num_items: Vec<u64>,
+    size: Vec<u64>,
+    policies: Vec<Vec<u8>>,
+    hits: Vec<u64>,
+    misses: Vec<u64>,
+    hit_rates: Vec<f64>,
+}
+
+impl CachesTableColumns {
+    fn append_row(
+        &mut self,
+        node: &str,
+        name: &str,
+        num_items: usize,
+        size: u64,
+        policy: EvictionPolicy,
+        statistics: &CacheHitStatistics,
+    ) {
+        self.nodes.push(node.as_bytes().to_vec());
+        self.names.push(name.as_bytes().to_vec());
+        self.num_items.push(num_items as u64);
+        self.size.push(size);
+        self.policies.push(policy.to_string().into_bytes());
+        self.hits.push(statistics.hits());
+        self.misses.push(statistics.misses());
+        self.hit_rates.push(statistics.hit_rate());
+    }
+}
+
 impl CachesTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let schema = TableSchemaRefExt::create(vec![
@@ -146,6 +218,10 @@ impl CachesTable {
             TableField::new("name", TableDataType::String),
             TableField::new("num_items", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("size", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("policy", TableDataType::String),
+            TableField::new("hit", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("miss", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("hit_rate", TableDataType::Number(NumberDataType::Float64)),
         ]);
 
         let table_info = TableInfo {
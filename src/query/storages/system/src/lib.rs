@@ -18,6 +18,7 @@
 
 extern crate core;
 
+mod audit_log_table;
 mod background_jobs_table;
 mod background_tasks_table;
 mod backtrace_table;
@@ -43,6 +44,7 @@ mod processes_table;
 mod query_cache_table;
 mod query_log_table;
 mod query_profile_table;
+mod resource_usage_table;
 mod roles_table;
 mod settings_table;
 mod stages_table;
@@ -52,7 +54,10 @@ mod tables_table;
 mod tracing_table;
 mod users_table;
 mod util;
+mod workload_groups_table;
 
+pub use audit_log_table::AuditLogQueue;
+pub use audit_log_table::AuditLogTable;
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
 pub use backtrace_table::BacktraceTable;
@@ -85,6 +90,7 @@ pub use query_log_table::QueryLogElement;
 pub use query_log_table::QueryLogQueue;
 pub use query_log_table::QueryLogTable;
 pub use query_profile_table::QueryProfileTable;
+pub use resource_usage_table::ResourceUsageTable;
 pub use roles_table::RolesTable;
 pub use settings_table::SettingsTable;
 pub use stages_table::StagesTable;
@@ -96,3 +102,4 @@ pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
 pub use tracing_table::TracingTable;
 pub use users_table::UsersTable;
+pub use workload_groups_table::WorkloadGroupsTable;
@@ -0,0 +1,139 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::DateType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::ResourceUsageManager;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct ResourceUsageTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ResourceUsageTable {
+    const NAME: &'static str = "system.resource_usage";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let mut usages = ResourceUsageManager::instance().get_usages(&tenant).await?;
+        usages.sort_by(|a, b| {
+            (a.usage_date, &a.user, &a.role, &a.warehouse).cmp(&(
+                b.usage_date,
+                &b.user,
+                &b.role,
+                &b.warehouse,
+            ))
+        });
+
+        let mut usage_dates = Vec::with_capacity(usages.len());
+        let mut users = Vec::with_capacity(usages.len());
+        let mut roles = Vec::with_capacity(usages.len());
+        let mut warehouses = Vec::with_capacity(usages.len());
+        let mut query_counts = Vec::with_capacity(usages.len());
+        let mut cpu_times = Vec::with_capacity(usages.len());
+        let mut scan_bytes = Vec::with_capacity(usages.len());
+        let mut spill_bytes = Vec::with_capacity(usages.len());
+        let mut result_bytes = Vec::with_capacity(usages.len());
+        let mut written_bytes = Vec::with_capacity(usages.len());
+        for usage in usages {
+            usage_dates.push(usage.usage_date);
+            users.push(usage.user.as_bytes().to_vec());
+            roles.push(usage.role.as_bytes().to_vec());
+            warehouses.push(usage.warehouse.as_bytes().to_vec());
+            query_counts.push(usage.query_count);
+            cpu_times.push(usage.cpu_time_ms);
+            scan_bytes.push(usage.scan_bytes);
+            spill_bytes.push(usage.spill_bytes);
+            result_bytes.push(usage.result_bytes);
+            written_bytes.push(usage.written_bytes);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            DateType::from_data(usage_dates),
+            StringType::from_data(users),
+            StringType::from_data(roles),
+            StringType::from_data(warehouses),
+            UInt64Type::from_data(query_counts),
+            UInt64Type::from_data(cpu_times),
+            UInt64Type::from_data(scan_bytes),
+            UInt64Type::from_data(spill_bytes),
+            UInt64Type::from_data(result_bytes),
+            UInt64Type::from_data(written_bytes),
+        ]))
+    }
+}
+
+impl ResourceUsageTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("usage_date", TableDataType::Date),
+            TableField::new("user", TableDataType::String),
+            TableField::new("role", TableDataType::String),
+            TableField::new("warehouse", TableDataType::String),
+            TableField::new("query_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("cpu_time_ms", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("scan_bytes", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("spill_bytes", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "result_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "written_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'resource_usage'".to_string(),
+            name: "resource_usage".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemResourceUsage".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(ResourceUsageTable { table_info })
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::Int64Type;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+use common_users::WorkloadGroupManager;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct WorkloadGroupsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for WorkloadGroupsTable {
+    const NAME: &'static str = "system.workload_groups";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let groups = UserApiProvider::instance()
+            .get_workload_groups(&tenant)
+            .await?;
+
+        let mut names = Vec::with_capacity(groups.len());
+        let mut cpu_shares = Vec::with_capacity(groups.len());
+        let mut memory_limits = Vec::with_capacity(groups.len());
+        let mut max_concurrencies = Vec::with_capacity(groups.len());
+        let mut queue_timeouts = Vec::with_capacity(groups.len());
+        let mut running_queries = Vec::with_capacity(groups.len());
+        let mut queued_queries = Vec::with_capacity(groups.len());
+        let mut memory_usages = Vec::with_capacity(groups.len());
+        let mut comments = Vec::with_capacity(groups.len());
+        for group in groups {
+            // Only the groups having queries on this node have runtime stats.
+            let resources = WorkloadGroupManager::instance().find_resources(&tenant, &group.name);
+            running_queries.push(resources.as_ref().map_or(0, |r| r.running_queries() as u64));
+            queued_queries.push(resources.as_ref().map_or(0, |r| r.queued_queries() as u64));
+            memory_usages.push(
+                resources
+                    .as_ref()
+                    .map_or(0, |r| r.get_mem_stat().get_memory_usage()),
+            );
+
+            names.push(group.name.as_bytes().to_vec());
+            cpu_shares.push(group.cpu_share);
+            memory_limits.push(group.memory_limit);
+            max_concurrencies.push(group.max_concurrency);
+            queue_timeouts.push(group.queue_timeout);
+            comments.push(group.comment.as_bytes().to_vec());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(cpu_shares),
+            UInt64Type::from_data(memory_limits),
+            UInt64Type::from_data(max_concurrencies),
+            UInt64Type::from_data(queue_timeouts),
+            UInt64Type::from_data(running_queries),
+            UInt64Type::from_data(queued_queries),
+            Int64Type::from_data(memory_usages),
+            StringType::from_data(comments),
+        ]))
+    }
+}
+
+impl WorkloadGroupsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("cpu_share", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "memory_limit",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "max_concurrency",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "queue_timeout",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "running_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "queued_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("memory_usage", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new("comment", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'workload_groups'".to_string(),
+            name: "workload_groups".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemWorkloadGroups".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(WorkloadGroupsTable { table_info })
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::base::GlobalInstance;
+use common_exception::Result;
+use common_management::AuditChainApi;
+use common_meta_app::tenant::AuditChainHead;
+use common_tracing::audit_chain_head;
+use common_tracing::init_audit_chain;
+use log::warn;
+
+use crate::UserApiProvider;
+
+/// Keeps the head of the audit chain of this node in the meta service.
+///
+/// The audit log files can be rewritten by whoever can access the node, the head kept in the meta
+/// service tells a truncated log, and the chain continues from it when the node restarts. The
+/// head is moved forward in the background once the records are written.
+pub struct AuditChainManager {
+    api: Arc<dyn AuditChainApi>,
+    // The seq of the head persisted by this node.
+    flushed_seq: Arc<AtomicU64>,
+    notify: Arc<Notify>,
+    flush_interval: Duration,
+    flush_join_handle: Option<JoinHandle<()>>,
+}
+
+impl AuditChainManager {
+    /// `chain_id` identifies the node across restarts, `key` is the key of the HMAC signing
+    /// the records.
+    #[async_backtrace::framed]
+    pub async fn init(tenant: &str, chain_id: &str, key: &str) -> Result<()> {
+        let api = UserApiProvider::instance().get_audit_chain_api_client(tenant, chain_id)?;
+        GlobalInstance::set(Self::try_create(api, key).await?);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn try_create(
+        api: Arc<dyn AuditChainApi>,
+        key: &str,
+    ) -> Result<Arc<AuditChainManager>> {
+        if key.is_empty() {
+            warn!("audit_log_hmac_key is empty, the audit log is not signed with a secret key");
+        }
+
+        let head = api.get_head().await?.unwrap_or_default();
+        init_audit_chain(key.as_bytes(), head.seq, head.hash);
+
+        let mut audit_chain_manager = Self {
+            api,
+            flushed_seq: Arc::new(AtomicU64::new(head.seq)),
+            notify: Arc::new(Notify::new()),
+            flush_interval: Duration::new(10, 0),
+            flush_join_handle: None,
+        };
+
+        audit_chain_manager.background_flushing();
+        Ok(Arc::new(audit_chain_manager))
+    }
+
+    pub fn instance() -> Arc<AuditChainManager> {
+        GlobalInstance::get()
+    }
+
+    fn background_flushing(&mut self) {
+        let api = self.api.clone();
+        let flushed_seq = self.flushed_seq.clone();
+        let notify = self.notify.clone();
+        let flush_interval = self.flush_interval;
+        self.flush_join_handle = Some(tokio::spawn(async_backtrace::location!().frame(
+            async move {
+                loop {
+                    // Retry the failed flushes periodically.
+                    let _ = tokio::time::timeout(flush_interval, notify.notified()).await;
+                    flush_head(api.as_ref(), &flushed_seq).await;
+                }
+            },
+        )));
+    }
+
+    /// Wake up the background flushing, called after a record is written.
+    pub fn notify(&self) {
+        self.notify.notify_one();
+    }
+
+    /// Persist the head of the audit chain right now.
+    #[async_backtrace::framed]
+    pub async fn flush(&self) {
+        flush_head(self.api.as_ref(), &self.flushed_seq).await
+    }
+}
+
+async fn flush_head(api: &dyn AuditChainApi, flushed_seq: &AtomicU64) {
+    let (seq, hash) = audit_chain_head();
+    if seq <= flushed_seq.load(Ordering::Acquire) {
+        return;
+    }
+
+    match api.set_head(&AuditChainHead { seq, hash }).await {
+        Ok(_) => {
+            flushed_seq.fetch_max(seq, Ordering::AcqRel);
+        }
+        Err(err) => warn!("audit_chain_mgr flush head {} failed: {}", seq, err),
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::UserInfo;
+use jwt_simple::algorithms::RS256PublicKey;
+use jwt_simple::algorithms::RSAPublicKeyLike;
+use jwt_simple::prelude::Clock;
+use jwt_simple::prelude::Duration;
+use jwt_simple::prelude::NoCustomClaims;
+use jwt_simple::prelude::VerificationOptions;
+
+// The max validity of the tokens signed by the users, to limit the damage of a leaked token.
+const KEY_PAIR_TOKEN_MAX_VALIDITY_HOURS: u64 = 1;
+
+/// Parse the RSA public key of a key-pair user, in PEM format or base64 encoded DER format.
+pub fn parse_rsa_public_key(public_key: &str) -> Result<RS256PublicKey> {
+    let public_key = public_key.trim();
+    let res = if public_key.starts_with("-----BEGIN") {
+        RS256PublicKey::from_pem(public_key)
+    } else {
+        let der = general_purpose::STANDARD
+            .decode(public_key.as_bytes())
+            .map_err(|e| ErrorCode::InvalidAuthInfo(format!("invalid public key: {}", e)))?;
+        RS256PublicKey::from_der(&der)
+    };
+    res.map_err(|e| ErrorCode::InvalidAuthInfo(format!("invalid public key: {}", e)))
+}
+
+/// Verify the JWT signed by the private key of a key-pair user.
+///
+/// The token is accepted if it is verified by the key in any of the two slots,
+/// its subject must be the user name and it must expire within an hour.
+pub fn verify_key_pair_token(token: &str, user_info: &UserInfo) -> Result<()> {
+    let public_keys = match &user_info.auth_info {
+        AuthInfo::KeyPair {
+            public_key,
+            public_key_2,
+        } => std::iter::once(public_key).chain(public_key_2.iter()),
+        _ => return Err(ErrorCode::AuthenticateFailure("wrong auth type")),
+    };
+
+    let options = VerificationOptions {
+        required_subject: Some(user_info.name.clone()),
+        ..Default::default()
+    };
+    let mut last_error = None;
+    for public_key in public_keys {
+        let public_key = parse_rsa_public_key(public_key)?;
+        match public_key.verify_token::<NoCustomClaims>(token, Some(options.clone())) {
+            Ok(claims) => {
+                let max_expires_at = Clock::now_since_epoch()
+                    + Duration::from_hours(KEY_PAIR_TOKEN_MAX_VALIDITY_HOURS);
+                return match claims.expires_at {
+                    None => Err(ErrorCode::AuthenticateFailure("missing field `exp` in jwt")),
+                    Some(expires_at) if expires_at > max_expires_at => {
+                        Err(ErrorCode::AuthenticateFailure(format!(
+                            "jwt must expire within {} hour",
+                            KEY_PAIR_TOKEN_MAX_VALIDITY_HOURS
+                        )))
+                    }
+                    Some(_) => Ok(()),
+                };
+            }
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(ErrorCode::AuthenticateFailure(format!(
+        "could not verify jwt with the public keys of user {}: {}",
+        user_info.identity(),
+        last_error.map(|e| e.to_string()).unwrap_or_default()
+    )))
+}
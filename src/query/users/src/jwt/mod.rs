@@ -14,8 +14,15 @@
 
 mod authenticator;
 mod jwk;
+mod key_pair;
+mod oidc;
 
 pub use authenticator::CustomClaims;
 pub use authenticator::EnsureUser;
 pub use authenticator::JwtAuthenticator;
 pub use authenticator::PubKey;
+pub use key_pair::parse_rsa_public_key;
+pub use key_pair::verify_key_pair_token;
+pub use oidc::OidcAuthenticator;
+pub use oidc::OidcIdentity;
+pub use oidc::UnverifiedClaims;
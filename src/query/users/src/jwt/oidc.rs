@@ -0,0 +1,146 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use jwt_simple::algorithms::ECDSAP256PublicKeyLike;
+use jwt_simple::algorithms::RSAPublicKeyLike;
+use jwt_simple::prelude::JWTClaims;
+use jwt_simple::prelude::VerificationOptions;
+use jwt_simple::token::Token;
+use serde::Deserialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use super::jwk;
+use super::PubKey;
+
+/// The claims of a token before it is verified, only used to choose how to verify the token.
+#[derive(Debug, Default, Deserialize)]
+pub struct UnverifiedClaims {
+    #[serde(rename = "iss")]
+    pub issuer: Option<String>,
+    #[serde(rename = "sub")]
+    pub subject: Option<String>,
+}
+
+impl UnverifiedClaims {
+    pub fn decode(token: &str) -> Result<Self> {
+        let payload = token.split('.').nth(1).ok_or_else(|| {
+            ErrorCode::AuthenticateFailure("invalid jwt, missing the payload part")
+        })?;
+        let payload = general_purpose::URL_SAFE_NO_PAD
+            .decode(payload.as_bytes())
+            .map_err(|e| ErrorCode::AuthenticateFailure(format!("invalid jwt: {}", e)))?;
+        serde_json::from_slice(&payload)
+            .map_err(|e| ErrorCode::AuthenticateFailure(format!("invalid jwt: {}", e)))
+    }
+}
+
+/// The user and role mapped from the claims of an OIDC token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OidcIdentity {
+    pub user_name: String,
+    pub role: Option<String>,
+}
+
+pub struct OidcAuthenticator {
+    key_store: jwk::JwkKeyStore,
+    issuer: String,
+    audience: String,
+    user_claim: String,
+    role_claim: String,
+}
+
+impl OidcAuthenticator {
+    pub fn create(
+        jwks_url: String,
+        issuer: String,
+        audience: String,
+        user_claim: String,
+        role_claim: String,
+    ) -> Result<Option<Self>> {
+        if jwks_url.is_empty() {
+            return Ok(None);
+        }
+        // tokens of other issuers or for other clients of the same provider must be refused.
+        if issuer.is_empty() || audience.is_empty() {
+            return Err(ErrorCode::InvalidConfig(
+                "oidc_issuer and oidc_audience must be set if oidc_jwks_url is set",
+            ));
+        }
+        Ok(Some(OidcAuthenticator {
+            key_store: jwk::JwkKeyStore::new(jwks_url),
+            issuer,
+            audience,
+            user_claim,
+            role_claim,
+        }))
+    }
+
+    /// Whether the token is issued by the OIDC provider, the signature is not verified.
+    pub fn is_issued_by(&self, claims: &UnverifiedClaims) -> bool {
+        claims.issuer.as_deref() == Some(self.issuer.as_str())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn parse_token(&self, token: &str) -> Result<OidcIdentity> {
+        let metadata = Token::decode_metadata(token);
+        let key_id = metadata.map_or(None, |e| e.key_id().map(|s| s.to_string()));
+        let pub_key = self.key_store.get_key(key_id).await?;
+
+        let options = VerificationOptions {
+            allowed_issuers: Some(HashSet::from([self.issuer.clone()])),
+            allowed_audiences: Some(HashSet::from([self.audience.clone()])),
+            ..Default::default()
+        };
+        let r = match &pub_key {
+            PubKey::RSA256(pk) => pk.verify_token::<Map<String, Value>>(token, Some(options)),
+            PubKey::ES256(pk) => pk.verify_token::<Map<String, Value>>(token, Some(options)),
+        };
+        let claims = r.map_err(|err| ErrorCode::AuthenticateFailure(err.to_string()))?;
+
+        let user_name = get_claim(&claims, &self.user_claim).ok_or_else(|| {
+            ErrorCode::AuthenticateFailure(format!(
+                "missing claim `{}` of user name in oidc token",
+                self.user_claim
+            ))
+        })?;
+        let role = if self.role_claim.is_empty() {
+            None
+        } else {
+            get_claim(&claims, &self.role_claim)
+        };
+        Ok(OidcIdentity { user_name, role })
+    }
+}
+
+// Get the string value of a claim, the first one is used if the value is an array.
+fn get_claim(claims: &JWTClaims<Map<String, Value>>, name: &str) -> Option<String> {
+    if name == "sub" {
+        return claims.subject.clone();
+    }
+    match claims.custom.get(name)? {
+        Value::String(s) => Some(s.clone()),
+        Value::Array(values) => values
+            .first()
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string()),
+        _ => None,
+    }
+}
@@ -18,8 +18,11 @@
 
 extern crate core;
 
+mod audit_chain_mgr;
 mod jwt;
 mod network_policy;
+mod password_policy;
+mod resource_usage_mgr;
 mod role_mgr;
 mod user;
 mod user_api;
@@ -27,15 +30,22 @@ mod user_mgr;
 mod user_setting;
 mod user_stage;
 mod user_udf;
+mod workload_group;
+mod workload_group_mgr;
 
 pub mod file_format;
 pub mod idm_config;
 pub mod role_cache_mgr;
 pub mod role_util;
 
+pub use audit_chain_mgr::AuditChainManager;
 pub use jwt::*;
+pub use resource_usage_mgr::ResourceUsageManager;
 pub use role_cache_mgr::RoleCacheManager;
 pub use role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
 pub use role_mgr::BUILTIN_ROLE_PUBLIC;
 pub use user::CertifiedInfo;
 pub use user_api::UserApiProvider;
+pub use workload_group_mgr::WorkloadGroupManager;
+pub use workload_group_mgr::WorkloadGroupPermit;
+pub use workload_group_mgr::WorkloadGroupResources;
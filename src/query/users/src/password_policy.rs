@@ -0,0 +1,349 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Duration;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::PasswordPolicyApi;
+use common_management::UserApi;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::PasswordPolicy;
+use common_meta_app::principal::UserInfo;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new password policy.
+    #[async_backtrace::framed]
+    pub async fn add_password_policy(
+        &self,
+        tenant: &str,
+        password_policy: PasswordPolicy,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        if if_not_exists
+            && self
+                .exists_password_policy(tenant, password_policy.name.as_str())
+                .await?
+        {
+            return Ok(0);
+        }
+
+        let client = self.get_password_policy_api_client(tenant)?;
+        let add_password_policy = client.add_password_policy(password_policy);
+        match add_password_policy.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::PASSWORD_POLICY_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back("(while add password policy)"))
+                }
+            }
+        }
+    }
+
+    // Update password policy with the function `f`.
+    #[async_backtrace::framed]
+    pub async fn update_password_policy<F>(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+        f: F,
+    ) -> Result<Option<u64>>
+    where
+        F: FnOnce(&mut PasswordPolicy) + Send,
+    {
+        let client = self.get_password_policy_api_client(tenant)?;
+        let seq_password_policy = match client.get_password_policy(name, MatchSeq::GE(0)).await {
+            Ok(seq_password_policy) => seq_password_policy,
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PASSWORD_POLICY {
+                    return Ok(None);
+                } else {
+                    return Err(e.add_message_back(" (while alter password policy)"));
+                }
+            }
+        };
+
+        let seq = seq_password_policy.seq;
+        let mut password_policy = seq_password_policy.data;
+        f(&mut password_policy);
+        password_policy.update_on = Some(Utc::now());
+
+        match client
+            .update_password_policy(password_policy, MatchSeq::Exact(seq))
+            .await
+        {
+            Ok(res) => Ok(Some(res)),
+            Err(e) => Err(e.add_message_back(" (while alter password policy).")),
+        }
+    }
+
+    // Drop a password policy by name.
+    #[async_backtrace::framed]
+    pub async fn drop_password_policy(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let user_infos = self.get_users(tenant).await?;
+        for user_info in user_infos {
+            if user_info.option.password_policy().map(|p| p.as_str()) == Some(name) {
+                return Err(ErrorCode::PasswordPolicyIsUsedByUser(format!(
+                    "password policy `{}` is used by user {}",
+                    name,
+                    user_info.identity(),
+                )));
+            }
+        }
+
+        let client = self.get_password_policy_api_client(tenant)?;
+        match client.drop_password_policy(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PASSWORD_POLICY {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop password policy)"))
+                }
+            }
+        }
+    }
+
+    // Check whether a password policy is exist.
+    #[async_backtrace::framed]
+    pub async fn exists_password_policy(&self, tenant: &str, name: &str) -> Result<bool> {
+        match self.get_password_policy(tenant, name).await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                if e.code() == ErrorCode::UNKNOWN_PASSWORD_POLICY {
+                    Ok(false)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a password policy by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_password_policy(&self, tenant: &str, name: &str) -> Result<PasswordPolicy> {
+        let client = self.get_password_policy_api_client(tenant)?;
+        let password_policy = client
+            .get_password_policy(name, MatchSeq::GE(0))
+            .await?
+            .data;
+        Ok(password_policy)
+    }
+
+    // Get all password policies by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_password_policies(&self, tenant: &str) -> Result<Vec<PasswordPolicy>> {
+        let client = self.get_password_policy_api_client(tenant)?;
+        let password_policies = client
+            .get_password_policies()
+            .await
+            .map_err(|e| e.add_message_back(" (while get password policies)."))?;
+        Ok(password_policies)
+    }
+
+    // Check the new password of a user by the password policy `name`, the password must be
+    // complex enough and must not be one of the recent passwords of the user, if any.
+    #[async_backtrace::framed]
+    pub async fn verify_password(
+        &self,
+        tenant: &str,
+        name: &str,
+        password: &str,
+        user_info: Option<&UserInfo>,
+    ) -> Result<()> {
+        let password_policy = self.get_password_policy(tenant, name).await?;
+        check_password_complexity(&password_policy, password)?;
+
+        if let Some(user_info) = user_info {
+            let recent_auth_infos = std::iter::once(&user_info.auth_info)
+                .chain(user_info.history_auth_infos.iter().rev())
+                .take(password_policy.history as usize);
+            for auth_info in recent_auth_infos {
+                if let AuthInfo::Password {
+                    hash_value,
+                    hash_method,
+                } = auth_info
+                {
+                    if *hash_value == hash_method.hash(password.as_bytes()) {
+                        return Err(ErrorCode::InvalidPassword(format!(
+                            "the password can't be one of the {} most recent passwords of the user",
+                            password_policy.history
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Check the password login of a user by its password policy, `authed` is the result of
+    // the password verification.
+    //
+    // A locked out user or an expired password is denied. The failed logins are counted,
+    // and the user is locked out after `max_retries` consecutive failures.
+    #[async_backtrace::framed]
+    pub async fn check_login_password(
+        &self,
+        tenant: &str,
+        user_info: &UserInfo,
+        authed: bool,
+    ) -> Result<()> {
+        if !matches!(user_info.auth_info, AuthInfo::Password { .. })
+            || self.get_configured_user(&user_info.name).is_some()
+        {
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        if let Some(lockout_time) = user_info.lockout_time {
+            if lockout_time > now {
+                return Err(ErrorCode::AuthenticateFailure(format!(
+                    "user {} is locked out after too many failed logins, try again after {}",
+                    user_info.identity(),
+                    lockout_time
+                )));
+            }
+        }
+
+        let password_policy = match user_info.option.password_policy() {
+            Some(name) => match self.get_password_policy(tenant, name).await {
+                Ok(password_policy) => password_policy,
+                Err(e) => {
+                    if e.code() == ErrorCode::UNKNOWN_PASSWORD_POLICY {
+                        return Ok(());
+                    }
+                    return Err(e);
+                }
+            },
+            None => return Ok(()),
+        };
+
+        let client = self.get_user_api_client(tenant)?;
+        if !authed {
+            if password_policy.max_retries > 0 {
+                let max_retries = password_policy.max_retries as usize;
+                let lockout_time =
+                    now + Duration::minutes(password_policy.lockout_time_mins as i64);
+                client
+                    .update_user_with(
+                        user_info.identity(),
+                        MatchSeq::GE(1),
+                        |ui: &mut UserInfo| {
+                            ui.password_fails.push(now);
+                            if ui.password_fails.len() >= max_retries {
+                                ui.password_fails.clear();
+                                ui.lockout_time = Some(lockout_time);
+                            }
+                        },
+                    )
+                    .await
+                    .map_err(|e| e.add_message_back("(while record failed login)"))?;
+            }
+            return Ok(());
+        }
+
+        if !user_info.password_fails.is_empty() || user_info.lockout_time.is_some() {
+            client
+                .update_user_with(
+                    user_info.identity(),
+                    MatchSeq::GE(1),
+                    |ui: &mut UserInfo| {
+                        ui.password_fails.clear();
+                        ui.lockout_time = None;
+                    },
+                )
+                .await
+                .map_err(|e| e.add_message_back("(while reset failed logins)"))?;
+        }
+
+        if password_policy.max_age_days > 0 {
+            if let Some(password_update_on) = user_info.password_update_on {
+                let expire_on =
+                    password_update_on + Duration::days(password_policy.max_age_days as i64);
+                if expire_on < now {
+                    return Err(ErrorCode::AuthenticateFailure(format!(
+                        "the password of user {} is expired since {}, it must be reset by an administrator",
+                        user_info.identity(),
+                        expire_on
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_password_complexity(password_policy: &PasswordPolicy, password: &str) -> Result<()> {
+    let mut length = 0;
+    let mut upper_case_chars = 0;
+    let mut lower_case_chars = 0;
+    let mut numeric_chars = 0;
+    let mut special_chars = 0;
+    for c in password.chars() {
+        length += 1;
+        if c.is_uppercase() {
+            upper_case_chars += 1;
+        } else if c.is_lowercase() {
+            lower_case_chars += 1;
+        } else if c.is_numeric() {
+            numeric_chars += 1;
+        } else if !c.is_alphanumeric() {
+            special_chars += 1;
+        }
+    }
+
+    let checks = [
+        (length, password_policy.min_length, "characters"),
+        (
+            upper_case_chars,
+            password_policy.min_upper_case_chars,
+            "upper case characters",
+        ),
+        (
+            lower_case_chars,
+            password_policy.min_lower_case_chars,
+            "lower case characters",
+        ),
+        (
+            numeric_chars,
+            password_policy.min_numeric_chars,
+            "numeric characters",
+        ),
+        (
+            special_chars,
+            password_policy.min_special_chars,
+            "special characters",
+        ),
+    ];
+    for (count, min, kind) in checks {
+        if count < min {
+            return Err(ErrorCode::InvalidPassword(format!(
+                "the password must contain at least {} {}, required by password policy `{}`",
+                min, kind, password_policy.name
+            )));
+        }
+    }
+    Ok(())
+}
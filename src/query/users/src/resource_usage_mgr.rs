@@ -0,0 +1,160 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::task::JoinHandle;
+use common_base::base::GlobalInstance;
+use common_exception::Result;
+use common_meta_app::tenant::ResourceUsage;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::UserApiProvider;
+
+// (tenant, usage_date, user, role, warehouse)
+type ResourceUsageKey = (String, i32, String, String, String);
+
+/// Accounts the resources consumed by the queries on this node.
+///
+/// The usages of finished queries are summed up in memory, and flushed to the meta service
+/// periodically and on shutdown, to avoid a meta write per query.
+pub struct ResourceUsageManager {
+    user_manager: Arc<UserApiProvider>,
+    pending: Arc<Mutex<HashMap<ResourceUsageKey, ResourceUsage>>>,
+    flush_interval: Duration,
+    flush_join_handle: Option<JoinHandle<()>>,
+}
+
+impl ResourceUsageManager {
+    pub fn init() -> Result<()> {
+        // Check that the user API has been initialized.
+        let instance = UserApiProvider::instance();
+
+        GlobalInstance::set(Self::try_create(instance)?);
+        Ok(())
+    }
+
+    pub fn try_create(user_manager: Arc<UserApiProvider>) -> Result<Arc<ResourceUsageManager>> {
+        let mut resource_usage_manager = Self {
+            user_manager,
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            flush_interval: Duration::new(10, 0),
+            flush_join_handle: None,
+        };
+
+        resource_usage_manager.background_flushing();
+        Ok(Arc::new(resource_usage_manager))
+    }
+
+    pub fn instance() -> Arc<ResourceUsageManager> {
+        GlobalInstance::get()
+    }
+
+    fn background_flushing(&mut self) {
+        let pending = self.pending.clone();
+        let flush_interval = self.flush_interval;
+        let user_manager = self.user_manager.clone();
+        self.flush_join_handle = Some(tokio::spawn(async_backtrace::location!().frame(
+            async move {
+                loop {
+                    tokio::time::sleep(flush_interval).await;
+                    flush_pending(&user_manager, &pending).await;
+                }
+            },
+        )));
+    }
+
+    /// Add the usage of a finished query of the tenant.
+    pub fn record(&self, tenant: &str, usage: ResourceUsage) {
+        let key = (
+            tenant.to_string(),
+            usage.usage_date,
+            usage.user.clone(),
+            usage.role.clone(),
+            usage.warehouse.clone(),
+        );
+        let mut pending = self.pending.lock();
+        match pending.get_mut(&key) {
+            Some(total) => total.merge(&usage),
+            None => {
+                pending.insert(key, usage);
+            }
+        }
+    }
+
+    /// Flush the pending usages to the meta service right now.
+    #[async_backtrace::framed]
+    pub async fn flush(&self) {
+        flush_pending(&self.user_manager, &self.pending).await
+    }
+
+    /// The usages of the tenant persisted, together with the usages not flushed yet on this node.
+    #[async_backtrace::framed]
+    pub async fn get_usages(&self, tenant: &str) -> Result<Vec<ResourceUsage>> {
+        let mut usages = self
+            .user_manager
+            .get_resource_usage_api_client(tenant)?
+            .get_usages()
+            .await?;
+
+        let pending = self.pending.lock();
+        for ((pending_tenant, ..), usage) in pending.iter() {
+            if pending_tenant != tenant {
+                continue;
+            }
+            match usages.iter_mut().find(|v| {
+                v.usage_date == usage.usage_date
+                    && v.user == usage.user
+                    && v.role == usage.role
+                    && v.warehouse == usage.warehouse
+            }) {
+                Some(total) => total.merge(usage),
+                None => usages.push(usage.clone()),
+            }
+        }
+        Ok(usages)
+    }
+}
+
+async fn flush_pending(
+    user_manager: &UserApiProvider,
+    pending: &Mutex<HashMap<ResourceUsageKey, ResourceUsage>>,
+) {
+    let usages = std::mem::take(&mut *pending.lock());
+    for (key, usage) in usages {
+        let res = match user_manager.get_resource_usage_api_client(&key.0) {
+            Ok(api) => api.add_usage(&usage).await,
+            Err(err) => Err(err),
+        };
+
+        // Keep the usage to flush it next time.
+        if let Err(err) = res {
+            warn!(
+                "resource_usage_mgr flush usage of tenant {} failed: {}",
+                key.0, err
+            );
+            let mut pending = pending.lock();
+            match pending.get_mut(&key) {
+                Some(total) => total.merge(&usage),
+                None => {
+                    pending.insert(key, usage);
+                }
+            }
+        }
+    }
+}
@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_management::RoleApi;
 use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::OwnershipInfo;
+use common_meta_app::principal::OwnershipObject;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserPrivilegeSet;
 use common_meta_app::principal::UserPrivilegeType;
@@ -171,6 +174,44 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while revoke role privileges)"))
     }
 
+    #[async_backtrace::framed]
+    pub async fn grant_column_privileges_to_role(
+        &self,
+        tenant: &str,
+        role: &String,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+        columns: BTreeSet<String>,
+    ) -> Result<Option<u64>> {
+        let client = self.get_role_api_client(tenant)?;
+        client
+            .update_role_with(role, MatchSeq::GE(1), |ri: &mut RoleInfo| {
+                ri.grants
+                    .grant_column_privileges(&object, privileges, &columns)
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while set role column privileges)"))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn revoke_column_privileges_from_role(
+        &self,
+        tenant: &str,
+        role: &String,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+        columns: BTreeSet<String>,
+    ) -> Result<Option<u64>> {
+        let client = self.get_role_api_client(tenant)?;
+        client
+            .update_role_with(role, MatchSeq::GE(1), |ri: &mut RoleInfo| {
+                ri.grants
+                    .revoke_column_privileges(&object, privileges, &columns)
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while revoke role column privileges)"))
+    }
+
     // the grant_role can not have cycle with target_role.
     #[async_backtrace::framed]
     pub async fn grant_role_to_role(
@@ -215,6 +256,43 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while revoke role from role)"))
     }
 
+    // Set the owner role of an object, the previous owner loses the ownership.
+    #[async_backtrace::framed]
+    pub async fn grant_ownership_to_role(
+        &self,
+        tenant: &str,
+        object: &OwnershipObject,
+        role: &str,
+    ) -> Result<()> {
+        let client = self.get_role_api_client(tenant)?;
+        client
+            .grant_ownership(object, role)
+            .await
+            .map_err(|e| e.add_message_back("(while grant ownership to role)"))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn revoke_ownership(&self, tenant: &str, object: &OwnershipObject) -> Result<()> {
+        let client = self.get_role_api_client(tenant)?;
+        client
+            .revoke_ownership(object)
+            .await
+            .map_err(|e| e.add_message_back("(while revoke ownership)"))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_ownership(
+        &self,
+        tenant: &str,
+        object: &OwnershipObject,
+    ) -> Result<Option<OwnershipInfo>> {
+        let client = self.get_role_api_client(tenant)?;
+        client
+            .get_ownership(object)
+            .await
+            .map_err(|e| e.add_message_back("(while get ownership)"))
+    }
+
     // Drop a role by name
     #[async_backtrace::framed]
     pub async fn drop_role(&self, tenant: &str, role: String, if_exists: bool) -> Result<()> {
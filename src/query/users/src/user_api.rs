@@ -18,12 +18,18 @@ use std::sync::Arc;
 use common_base::base::GlobalInstance;
 use common_exception::Result;
 use common_grpc::RpcClientConf;
+use common_management::AuditChainApi;
+use common_management::AuditChainMgr;
 use common_management::FileFormatApi;
 use common_management::FileFormatMgr;
 use common_management::NetworkPolicyApi;
 use common_management::NetworkPolicyMgr;
+use common_management::PasswordPolicyApi;
+use common_management::PasswordPolicyMgr;
 use common_management::QuotaApi;
 use common_management::QuotaMgr;
+use common_management::ResourceUsageApi;
+use common_management::ResourceUsageMgr;
 use common_management::RoleApi;
 use common_management::RoleMgr;
 use common_management::SettingApi;
@@ -34,6 +40,8 @@ use common_management::UdfApi;
 use common_management::UdfMgr;
 use common_management::UserApi;
 use common_management::UserMgr;
+use common_management::WorkloadGroupApi;
+use common_management::WorkloadGroupMgr;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::tenant::TenantQuota;
 use common_meta_kvapi::kvapi;
@@ -116,6 +124,25 @@ impl UserApiProvider {
         Ok(Arc::new(QuotaMgr::create(self.client.clone(), tenant)?))
     }
 
+    pub fn get_resource_usage_api_client(&self, tenant: &str) -> Result<Arc<dyn ResourceUsageApi>> {
+        Ok(Arc::new(ResourceUsageMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_audit_chain_api_client(
+        &self,
+        tenant: &str,
+        chain_id: &str,
+    ) -> Result<Arc<dyn AuditChainApi>> {
+        Ok(Arc::new(AuditChainMgr::create(
+            self.client.clone(),
+            tenant,
+            chain_id,
+        )?))
+    }
+
     pub fn get_setting_api_client(&self, tenant: &str) -> Result<Arc<dyn SettingApi>> {
         Ok(Arc::new(SettingMgr::create(self.client.clone(), tenant)?))
     }
@@ -130,6 +157,26 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_password_policy_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<impl PasswordPolicyApi>> {
+        Ok(Arc::new(PasswordPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_workload_group_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<impl WorkloadGroupApi>> {
+        Ok(Arc::new(WorkloadGroupMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use core::net::Ipv4Addr;
+use std::collections::BTreeSet;
 
 use cidr::Ipv4Cidr;
 use common_exception::ErrorCode;
@@ -64,7 +65,19 @@ impl UserApiProvider {
         client_ip: Option<&str>,
     ) -> Result<UserInfo> {
         let user_info = self.get_user(tenant, user).await?;
+        self.check_network_policy(tenant, &user_info, client_ip)
+            .await?;
+        Ok(user_info)
+    }
 
+    // Check the client ip is allowed by the network policy of the user, if any.
+    #[async_backtrace::framed]
+    pub async fn check_network_policy(
+        &self,
+        tenant: &str,
+        user_info: &UserInfo,
+        client_ip: Option<&str>,
+    ) -> Result<()> {
         if let Some(name) = user_info.option.network_policy() {
             let ip_addr: Ipv4Addr = match client_ip {
                 Some(client_ip) => client_ip.parse().unwrap(),
@@ -98,7 +111,7 @@ impl UserApiProvider {
                 )));
             }
         }
-        Ok(user_info)
+        Ok(())
     }
 
     // Get the tenant all users list.
@@ -136,6 +149,22 @@ impl UserApiProvider {
                 )));
             }
         }
+        if let Some(name) = user_info.option.workload_group() {
+            if self.get_workload_group(tenant, name).await.is_err() {
+                return Err(ErrorCode::UnknownWorkloadGroup(format!(
+                    "workload group `{}` is not exist",
+                    name
+                )));
+            }
+        }
+        if let Some(name) = user_info.option.password_policy() {
+            if self.get_password_policy(tenant, name).await.is_err() {
+                return Err(ErrorCode::UnknownPasswordPolicy(format!(
+                    "password policy `{}` is not exist",
+                    name
+                )));
+            }
+        }
         if self.get_configured_user(&user_info.name).is_some() {
             return Err(ErrorCode::UserAlreadyExists(format!(
                 "Same name with configured user `{}`",
@@ -202,6 +231,56 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while revoke user privileges)"))
     }
 
+    #[async_backtrace::framed]
+    pub async fn grant_column_privileges_to_user(
+        &self,
+        tenant: &str,
+        user: UserIdentity,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+        columns: BTreeSet<String>,
+    ) -> Result<Option<u64>> {
+        if self.get_configured_user(&user.username).is_some() {
+            return Err(ErrorCode::UserAlreadyExists(format!(
+                "Cannot grant privileges to configured user `{}`",
+                user.username
+            )));
+        }
+        let client = self.get_user_api_client(tenant)?;
+        client
+            .update_user_with(user, MatchSeq::GE(1), |ui: &mut UserInfo| {
+                ui.grants
+                    .grant_column_privileges(&object, privileges, &columns)
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while set user column privileges)"))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn revoke_column_privileges_from_user(
+        &self,
+        tenant: &str,
+        user: UserIdentity,
+        object: GrantObject,
+        privileges: UserPrivilegeSet,
+        columns: BTreeSet<String>,
+    ) -> Result<Option<u64>> {
+        if self.get_configured_user(&user.username).is_some() {
+            return Err(ErrorCode::UserAlreadyExists(format!(
+                "Cannot revoke privileges from configured user `{}`",
+                user.username
+            )));
+        }
+        let client = self.get_user_api_client(tenant)?;
+        client
+            .update_user_with(user, MatchSeq::GE(1), |ui: &mut UserInfo| {
+                ui.grants
+                    .revoke_column_privileges(&object, privileges, &columns)
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while revoke user column privileges)"))
+    }
+
     #[async_backtrace::framed]
     pub async fn grant_role_to_user(
         &self,
@@ -287,6 +366,22 @@ impl UserApiProvider {
                     )));
                 }
             }
+            if let Some(name) = user_option.workload_group() {
+                if self.get_workload_group(tenant, name).await.is_err() {
+                    return Err(ErrorCode::UnknownWorkloadGroup(format!(
+                        "workload group `{}` is not exist",
+                        name
+                    )));
+                }
+            }
+            if let Some(name) = user_option.password_policy() {
+                if self.get_password_policy(tenant, name).await.is_err() {
+                    return Err(ErrorCode::UnknownPasswordPolicy(format!(
+                        "password policy `{}` is not exist",
+                        name
+                    )));
+                }
+            }
         }
         if self.get_configured_user(&user.username).is_some() {
             return Err(ErrorCode::UserAlreadyExists(format!(
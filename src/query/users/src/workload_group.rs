@@ -0,0 +1,190 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::RoleApi;
+use common_management::WorkloadGroupApi;
+use common_meta_app::principal::RoleInfo;
+use common_meta_app::principal::WorkloadGroup;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new workload group.
+    #[async_backtrace::framed]
+    pub async fn add_workload_group(
+        &self,
+        tenant: &str,
+        workload_group: WorkloadGroup,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        if if_not_exists
+            && self
+                .exists_workload_group(tenant, workload_group.name.as_str())
+                .await?
+        {
+            return Ok(0);
+        }
+
+        let client = self.get_workload_group_api_client(tenant)?;
+        let add_workload_group = client.add_workload_group(workload_group);
+        match add_workload_group.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::WORKLOAD_GROUP_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back("(while add workload group)"))
+                }
+            }
+        }
+    }
+
+    // Update workload group with the function `f`.
+    #[async_backtrace::framed]
+    pub async fn update_workload_group<F>(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+        f: F,
+    ) -> Result<Option<u64>>
+    where
+        F: FnOnce(&mut WorkloadGroup) + Send,
+    {
+        let client = self.get_workload_group_api_client(tenant)?;
+        let seq_workload_group = match client.get_workload_group(name, MatchSeq::GE(0)).await {
+            Ok(seq_workload_group) => seq_workload_group,
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_WORKLOAD_GROUP {
+                    return Ok(None);
+                } else {
+                    return Err(e.add_message_back(" (while alter workload group)"));
+                }
+            }
+        };
+
+        let seq = seq_workload_group.seq;
+        let mut workload_group = seq_workload_group.data;
+        f(&mut workload_group);
+        workload_group.update_on = Some(Utc::now());
+
+        match client
+            .update_workload_group(workload_group, MatchSeq::Exact(seq))
+            .await
+        {
+            Ok(res) => Ok(Some(res)),
+            Err(e) => Err(e.add_message_back(" (while alter workload group).")),
+        }
+    }
+
+    // Drop a workload group by name.
+    #[async_backtrace::framed]
+    pub async fn drop_workload_group(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let user_infos = self.get_users(tenant).await?;
+        for user_info in user_infos {
+            if user_info.option.workload_group().map(|g| g.as_str()) == Some(name) {
+                return Err(ErrorCode::WorkloadGroupIsUsed(format!(
+                    "workload group `{}` is used by user {}",
+                    name,
+                    user_info.identity(),
+                )));
+            }
+        }
+        let role_infos = self.get_roles(tenant).await?;
+        for role_info in role_infos {
+            if role_info.workload_group.as_deref() == Some(name) {
+                return Err(ErrorCode::WorkloadGroupIsUsed(format!(
+                    "workload group `{}` is used by role {}",
+                    name, role_info.name,
+                )));
+            }
+        }
+
+        let client = self.get_workload_group_api_client(tenant)?;
+        match client.drop_workload_group(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_WORKLOAD_GROUP {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop workload group)"))
+                }
+            }
+        }
+    }
+
+    // Check whether a workload group is exist.
+    #[async_backtrace::framed]
+    pub async fn exists_workload_group(&self, tenant: &str, name: &str) -> Result<bool> {
+        match self.get_workload_group(tenant, name).await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                if e.code() == ErrorCode::UNKNOWN_WORKLOAD_GROUP {
+                    Ok(false)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a workload group by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_workload_group(&self, tenant: &str, name: &str) -> Result<WorkloadGroup> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        let workload_group = client.get_workload_group(name, MatchSeq::GE(0)).await?.data;
+        Ok(workload_group)
+    }
+
+    // Get all workload groups by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_workload_groups(&self, tenant: &str) -> Result<Vec<WorkloadGroup>> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        let workload_groups = client
+            .get_workload_groups()
+            .await
+            .map_err(|e| e.add_message_back(" (while get workload groups)."))?;
+        Ok(workload_groups)
+    }
+
+    // Assign a role to a workload group, or unassign it if `workload_group` is None.
+    #[async_backtrace::framed]
+    pub async fn set_role_workload_group(
+        &self,
+        tenant: &str,
+        role: &String,
+        workload_group: Option<String>,
+    ) -> Result<Option<u64>> {
+        if let Some(name) = &workload_group {
+            self.get_workload_group(tenant, name).await?;
+        }
+
+        let client = self.get_role_api_client(tenant)?;
+        client
+            .update_role_with(role, MatchSeq::GE(1), |ri: &mut RoleInfo| {
+                ri.workload_group = workload_group
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while set role workload group)"))
+    }
+}
@@ -0,0 +1,291 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::GlobalInstance;
+use common_base::runtime::MemStat;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::WorkloadGroup;
+use common_metrics::label_gauge_with_val_and_labels;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+const METRIC_WORKLOAD_GROUP_RUNNING_QUERIES: &str = "workload_group_running_queries";
+const METRIC_WORKLOAD_GROUP_QUEUED_QUERIES: &str = "workload_group_queued_queries";
+
+/// Queued queries check whether they can run at least this often, so that they notice
+/// a raised `max_concurrency` without waiting for a running query to finish.
+const QUEUE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The runtime state of the workload groups on this node.
+pub struct WorkloadGroupManager {
+    groups: RwLock<HashMap<(String, String), Arc<WorkloadGroupResources>>>,
+}
+
+impl WorkloadGroupManager {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Self::create());
+        Ok(())
+    }
+
+    pub fn create() -> Arc<WorkloadGroupManager> {
+        Arc::new(WorkloadGroupManager {
+            groups: RwLock::new(HashMap::new()),
+        })
+    }
+
+    pub fn instance() -> Arc<WorkloadGroupManager> {
+        GlobalInstance::get()
+    }
+
+    /// Get the resources of the workload group, applying its latest definition.
+    pub fn get_resources(
+        &self,
+        tenant: &str,
+        group: &WorkloadGroup,
+    ) -> Arc<WorkloadGroupResources> {
+        let key = (tenant.to_string(), group.name.clone());
+        let resources = {
+            let groups = self.groups.read();
+            groups.get(&key).cloned()
+        };
+
+        let resources = match resources {
+            Some(resources) => resources,
+            None => {
+                let mut groups = self.groups.write();
+                groups
+                    .entry(key)
+                    .or_insert_with(|| WorkloadGroupResources::create(tenant, group))
+                    .clone()
+            }
+        };
+
+        resources.apply(group);
+        resources
+    }
+
+    /// Get the resources of the workload group, if any query of it has run on this node.
+    pub fn find_resources(&self, tenant: &str, name: &str) -> Option<Arc<WorkloadGroupResources>> {
+        let groups = self.groups.read();
+        groups.get(&(tenant.to_string(), name.to_string())).cloned()
+    }
+
+    pub fn remove_resources(&self, tenant: &str, name: &str) {
+        let mut groups = self.groups.write();
+        groups.remove(&(tenant.to_string(), name.to_string()));
+    }
+
+    /// The threads a query of the workload group can use out of the `threads` it wants.
+    ///
+    /// The CPU is shared by the groups with running queries in proportion to their `cpu_share`,
+    /// and a query always gets one thread at least.
+    pub fn max_threads(&self, resources: &WorkloadGroupResources, threads: usize) -> usize {
+        let cpu_share = resources.cpu_share();
+        let busy_cpu_shares = {
+            let groups = self.groups.read();
+            groups
+                .values()
+                .filter(|group| group.tenant == resources.tenant && group.running_queries() > 0)
+                .map(|group| group.cpu_share())
+                .sum::<u64>()
+                .max(cpu_share)
+        };
+
+        ((threads as u64 * cpu_share / busy_cpu_shares) as usize).clamp(1, threads.max(1))
+    }
+}
+
+#[derive(Default)]
+struct QueueState {
+    running: usize,
+    queued: usize,
+}
+
+/// The resources shared by the running queries of a workload group.
+pub struct WorkloadGroupResources {
+    tenant: String,
+    group: RwLock<WorkloadGroup>,
+    mem_stat: Arc<MemStat>,
+    state: Mutex<QueueState>,
+    notify: Notify,
+}
+
+impl WorkloadGroupResources {
+    pub fn create(tenant: &str, group: &WorkloadGroup) -> Arc<WorkloadGroupResources> {
+        let mem_stat = MemStat::create_child(format!("WorkloadGroup{}", group.name), None);
+        Arc::new(WorkloadGroupResources {
+            tenant: tenant.to_string(),
+            group: RwLock::new(group.clone()),
+            mem_stat,
+            state: Mutex::new(QueueState::default()),
+            notify: Notify::new(),
+        })
+    }
+
+    fn apply(&self, group: &WorkloadGroup) {
+        self.mem_stat.set_limit(group.memory_limit as i64);
+        *self.group.write() = group.clone();
+    }
+
+    pub fn name(&self) -> String {
+        self.group.read().name.clone()
+    }
+
+    pub fn cpu_share(&self) -> u64 {
+        self.group.read().cpu_share.max(1)
+    }
+
+    pub fn get_mem_stat(&self) -> Arc<MemStat> {
+        self.mem_stat.clone()
+    }
+
+    pub fn running_queries(&self) -> usize {
+        self.state.lock().running
+    }
+
+    pub fn queued_queries(&self) -> usize {
+        self.state.lock().queued
+    }
+
+    /// Wait in the queue of the group until the query can run.
+    ///
+    /// The query is admitted once fewer than `max_concurrency` queries of the group are running,
+    /// and fails with `WorkloadGroupQueueTimeout` if it has waited longer than `queue_timeout`.
+    #[async_backtrace::framed]
+    pub async fn acquire(self: &Arc<Self>) -> Result<WorkloadGroupPermit> {
+        let (name, queue_timeout) = {
+            let group = self.group.read();
+            (group.name.clone(), group.queue_timeout)
+        };
+        let deadline =
+            (queue_timeout != 0).then(|| Instant::now() + Duration::from_secs(queue_timeout));
+
+        let mut queued: Option<QueuedGuard> = None;
+        loop {
+            {
+                let max_concurrency = self.group.read().max_concurrency as usize;
+                let mut state = self.state.lock();
+                if max_concurrency == 0 || state.running < max_concurrency {
+                    state.running += 1;
+                    if let Some(mut queued) = queued.take() {
+                        queued.disarm();
+                        state.queued -= 1;
+                    }
+                    self.report_metrics(&state);
+
+                    // Let the next queued query check whether it can run too.
+                    if state.queued > 0 {
+                        self.notify.notify_one();
+                    }
+
+                    return Ok(WorkloadGroupPermit {
+                        resources: self.clone(),
+                    });
+                }
+
+                if queued.is_none() {
+                    state.queued += 1;
+                    self.report_metrics(&state);
+                    queued = Some(QueuedGuard {
+                        resources: Some(self.clone()),
+                    });
+                }
+            }
+
+            let wait = match deadline {
+                None => QUEUE_POLL_INTERVAL,
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(ErrorCode::WorkloadGroupQueueTimeout(format!(
+                            "Query waited in the queue of workload group `{}` for more than {} seconds",
+                            name, queue_timeout
+                        )));
+                    }
+                    (deadline - now).min(QUEUE_POLL_INTERVAL)
+                }
+            };
+            let _ = tokio::time::timeout(wait, self.notify.notified()).await;
+        }
+    }
+
+    fn report_metrics(&self, state: &QueueState) {
+        let labels = vec![
+            ("tenant", self.tenant.clone()),
+            ("workload_group", self.group.read().name.clone()),
+        ];
+        label_gauge_with_val_and_labels(
+            METRIC_WORKLOAD_GROUP_RUNNING_QUERIES,
+            &labels,
+            state.running as f64,
+        );
+        label_gauge_with_val_and_labels(
+            METRIC_WORKLOAD_GROUP_QUEUED_QUERIES,
+            &labels,
+            state.queued as f64,
+        );
+    }
+}
+
+/// Leave the queue if the query is cancelled while waiting.
+struct QueuedGuard {
+    resources: Option<Arc<WorkloadGroupResources>>,
+}
+
+impl QueuedGuard {
+    /// The query has left the queue by being admitted.
+    fn disarm(&mut self) {
+        self.resources = None;
+    }
+}
+
+impl Drop for QueuedGuard {
+    fn drop(&mut self) {
+        if let Some(resources) = self.resources.take() {
+            let mut state = resources.state.lock();
+            state.queued -= 1;
+            resources.report_metrics(&state);
+        }
+    }
+}
+
+/// A query of the workload group is running until it's dropped.
+pub struct WorkloadGroupPermit {
+    resources: Arc<WorkloadGroupResources>,
+}
+
+impl WorkloadGroupPermit {
+    pub fn resources(&self) -> Arc<WorkloadGroupResources> {
+        self.resources.clone()
+    }
+}
+
+impl Drop for WorkloadGroupPermit {
+    fn drop(&mut self) {
+        let mut state = self.resources.state.lock();
+        state.running -= 1;
+        self.resources.report_metrics(&state);
+        drop(state);
+
+        self.resources.notify.notify_one();
+    }
+}
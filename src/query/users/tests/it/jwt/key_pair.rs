@@ -0,0 +1,92 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::UserInfo;
+use common_users::parse_rsa_public_key;
+use common_users::verify_key_pair_token;
+use jwt_simple::prelude::*;
+
+fn key_pair_user(public_key: String, public_key_2: Option<String>) -> UserInfo {
+    UserInfo::new("test-user", "%", AuthInfo::KeyPair {
+        public_key,
+        public_key_2,
+    })
+}
+
+#[test]
+fn test_verify_key_pair_token() -> Result<()> {
+    let key_pair = RS256KeyPair::generate(2048)?;
+    let public_key = key_pair.public_key().to_pem()?;
+    let user_info = key_pair_user(public_key, None);
+
+    // valid token
+    let claims = Claims::create(Duration::from_mins(30)).with_subject("test-user");
+    let token = key_pair.sign(claims)?;
+    verify_key_pair_token(&token, &user_info)?;
+
+    // wrong subject
+    let claims = Claims::create(Duration::from_mins(30)).with_subject("other-user");
+    let token = key_pair.sign(claims)?;
+    assert!(verify_key_pair_token(&token, &user_info).is_err());
+
+    // expires too late
+    let claims = Claims::create(Duration::from_hours(2)).with_subject("test-user");
+    let token = key_pair.sign(claims)?;
+    assert!(verify_key_pair_token(&token, &user_info).is_err());
+
+    // signed by another key
+    let other_key_pair = RS256KeyPair::generate(2048)?;
+    let claims = Claims::create(Duration::from_mins(30)).with_subject("test-user");
+    let token = other_key_pair.sign(claims)?;
+    assert!(verify_key_pair_token(&token, &user_info).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_verify_key_pair_token_with_rotated_key() -> Result<()> {
+    let old_key_pair = RS256KeyPair::generate(2048)?;
+    let new_key_pair = RS256KeyPair::generate(2048)?;
+    let user_info = key_pair_user(
+        new_key_pair.public_key().to_pem()?,
+        Some(old_key_pair.public_key().to_pem()?),
+    );
+
+    for key_pair in [&old_key_pair, &new_key_pair] {
+        let claims = Claims::create(Duration::from_mins(30)).with_subject("test-user");
+        let token = key_pair.sign(claims)?;
+        verify_key_pair_token(&token, &user_info)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_rsa_public_key() -> Result<()> {
+    let key_pair = RS256KeyPair::generate(2048)?;
+    let pem = key_pair.public_key().to_pem()?;
+    parse_rsa_public_key(&pem)?;
+
+    // base64 encoded DER without the PEM header and footer
+    let der = pem
+        .lines()
+        .filter(|line| !line.starts_with("-----"))
+        .collect::<String>();
+    parse_rsa_public_key(&der)?;
+
+    assert!(parse_rsa_public_key("not a key").is_err());
+    Ok(())
+}
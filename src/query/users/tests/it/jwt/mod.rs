@@ -13,3 +13,5 @@
 // limitations under the License.
 
 mod authenticator;
+mod key_pair;
+mod oidc;
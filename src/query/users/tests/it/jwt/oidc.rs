@@ -0,0 +1,123 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_users::OidcAuthenticator;
+use common_users::OidcIdentity;
+use common_users::UnverifiedClaims;
+use jwt_simple::prelude::*;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+
+#[derive(Serialize, Deserialize)]
+struct OidcClaims {
+    email: String,
+    groups: Vec<String>,
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_oidc_parse_token() -> Result<()> {
+    let kid = "test_kid";
+    let key_pair = RS256KeyPair::generate(2048)?.with_key_id(kid);
+    let rsa_components = key_pair.public_key().to_components();
+    let e = general_purpose::URL_SAFE_NO_PAD.encode(rsa_components.e);
+    let n = general_purpose::URL_SAFE_NO_PAD.encode(rsa_components.n);
+    let jwks =
+        serde_json::json!({"keys": [ {"kty": "RSA", "kid": kid, "e": e, "n": n, } ] }).to_string();
+
+    let server = MockServer::start().await;
+    let json_path = "/jwks.json";
+    Mock::given(method("GET"))
+        .and(path(json_path))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(jwks, "application/json"))
+        .expect(1..)
+        .mount(&server)
+        .await;
+
+    let auth = OidcAuthenticator::create(
+        format!("http://{}{}", server.address(), json_path),
+        "https://idp.example.com".to_string(),
+        "databend".to_string(),
+        "email".to_string(),
+        "groups".to_string(),
+    )?
+    .unwrap();
+
+    let custom = || OidcClaims {
+        email: "alice@example.com".to_string(),
+        groups: vec!["analyst".to_string(), "dev".to_string()],
+    };
+
+    // the user and role are mapped from the claims
+    let claims = Claims::with_custom_claims(custom(), Duration::from_hours(1))
+        .with_issuer("https://idp.example.com")
+        .with_audience("databend")
+        .with_subject("alice");
+    let token = key_pair.sign(claims)?;
+    assert!(auth.is_issued_by(&UnverifiedClaims::decode(&token)?));
+    let identity = auth.parse_token(&token).await?;
+    assert_eq!(identity, OidcIdentity {
+        user_name: "alice@example.com".to_string(),
+        role: Some("analyst".to_string()),
+    });
+
+    // wrong audience
+    let claims = Claims::with_custom_claims(custom(), Duration::from_hours(1))
+        .with_issuer("https://idp.example.com")
+        .with_audience("other");
+    let token = key_pair.sign(claims)?;
+    assert!(auth.parse_token(&token).await.is_err());
+
+    // issued by another provider
+    let claims = Claims::with_custom_claims(custom(), Duration::from_hours(1))
+        .with_issuer("https://other.example.com")
+        .with_audience("databend");
+    let token = key_pair.sign(claims)?;
+    assert!(!auth.is_issued_by(&UnverifiedClaims::decode(&token)?));
+    assert!(auth.parse_token(&token).await.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_oidc_invalid_config() -> Result<()> {
+    let create = |jwks_url: &str, issuer: &str, audience: &str| {
+        OidcAuthenticator::create(
+            jwks_url.to_string(),
+            issuer.to_string(),
+            audience.to_string(),
+            "sub".to_string(),
+            "".to_string(),
+        )
+    };
+
+    // oidc auth is disabled
+    assert!(create("", "", "")?.is_none());
+
+    // the issuer and the audience of the tokens must be checked
+    let jwks_url = "http://127.0.0.1/jwks.json";
+    for (issuer, audience) in [("https://idp.example.com", ""), ("", "databend")] {
+        let err = create(jwks_url, issuer, audience).err().unwrap();
+        assert_eq!(err.code(), ErrorCode::INVALID_CONFIG);
+    }
+    assert!(create(jwks_url, "https://idp.example.com", "databend")?.is_some());
+    Ok(())
+}
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 mod jwt;
+mod password_policy;
 mod role_cache_mgr;
 mod role_mgr;
 mod user_mgr;
 mod user_udf;
+mod workload_group_mgr;
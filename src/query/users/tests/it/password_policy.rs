@@ -0,0 +1,157 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_grpc::RpcClientConf;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::PasswordHashMethod;
+use common_meta_app::principal::PasswordPolicy;
+use common_meta_app::principal::UserIdentity;
+use common_meta_app::principal::UserInfo;
+use common_meta_app::principal::UserOption;
+use common_users::UserApiProvider;
+use pretty_assertions::assert_eq;
+
+fn password_auth_info(password: &str) -> AuthInfo {
+    AuthInfo::Password {
+        hash_value: PasswordHashMethod::Sha256.hash(password.as_bytes()),
+        hash_method: PasswordHashMethod::Sha256,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_password_policy() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let user_mgr = UserApiProvider::try_create_simple(conf).await?;
+
+    let tenant = "test";
+    let policy_name = "test_policy";
+    let identity = UserIdentity::new("test-user1", "%");
+
+    let password_policy = PasswordPolicy {
+        name: policy_name.to_string(),
+        min_length: 8,
+        min_upper_case_chars: 1,
+        min_lower_case_chars: 1,
+        min_numeric_chars: 1,
+        min_special_chars: 1,
+        max_age_days: 90,
+        max_retries: 2,
+        lockout_time_mins: 15,
+        history: 2,
+        comment: "".to_string(),
+        create_on: Utc::now(),
+        update_on: None,
+    };
+    user_mgr
+        .add_password_policy(tenant, password_policy, false)
+        .await?;
+
+    // the password must be complex enough.
+    for password in ["Ab1!", "abcdefg1!", "ABCDEFG1!", "Abcdefgh!", "Abcdefgh1"] {
+        let res = user_mgr
+            .verify_password(tenant, policy_name, password, None)
+            .await;
+        assert_eq!(
+            res.err().map(|e| e.code()),
+            Some(ErrorCode::INVALID_PASSWORD),
+            "{password}"
+        );
+    }
+    user_mgr
+        .verify_password(tenant, policy_name, "Abcdefg1!", None)
+        .await?;
+
+    // add user with the password policy.
+    let mut user_info = UserInfo::new(
+        &identity.username,
+        &identity.hostname,
+        password_auth_info("Abcdefg1!"),
+    );
+    user_info.option = UserOption::default().with_password_policy(Some(policy_name.to_string()));
+    user_mgr.add_user(tenant, user_info, false).await?;
+
+    // the recent passwords can't be reused.
+    user_mgr
+        .update_user(
+            tenant,
+            identity.clone(),
+            Some(password_auth_info("Bcdefgh2!")),
+            None,
+        )
+        .await?;
+    let user_info = user_mgr.get_user(tenant, identity.clone()).await?;
+    assert_eq!(user_info.history_auth_infos.len(), 1);
+    assert!(user_info.password_update_on.is_some());
+    for password in ["Abcdefg1!", "Bcdefgh2!"] {
+        let res = user_mgr
+            .verify_password(tenant, policy_name, password, Some(&user_info))
+            .await;
+        assert_eq!(
+            res.err().map(|e| e.code()),
+            Some(ErrorCode::INVALID_PASSWORD),
+            "{password}"
+        );
+    }
+    user_mgr
+        .verify_password(tenant, policy_name, "Cdefghi3!", Some(&user_info))
+        .await?;
+
+    // a successful login resets the failed logins.
+    user_mgr
+        .check_login_password(tenant, &user_info, false)
+        .await?;
+    let user_info = user_mgr.get_user(tenant, identity.clone()).await?;
+    assert_eq!(user_info.password_fails.len(), 1);
+    user_mgr
+        .check_login_password(tenant, &user_info, true)
+        .await?;
+    let user_info = user_mgr.get_user(tenant, identity.clone()).await?;
+    assert!(user_info.password_fails.is_empty());
+
+    // the user is locked out after `max_retries` failed logins.
+    for _ in 0..2 {
+        let user_info = user_mgr.get_user(tenant, identity.clone()).await?;
+        user_mgr
+            .check_login_password(tenant, &user_info, false)
+            .await?;
+    }
+    let user_info = user_mgr.get_user(tenant, identity.clone()).await?;
+    assert!(user_info.lockout_time.is_some());
+    let res = user_mgr
+        .check_login_password(tenant, &user_info, true)
+        .await;
+    assert_eq!(
+        res.err().map(|e| e.code()),
+        Some(ErrorCode::AUTHENTICATE_FAILURE)
+    );
+
+    // the password policy used by a user can't be dropped.
+    let res = user_mgr
+        .drop_password_policy(tenant, policy_name, false)
+        .await;
+    assert_eq!(
+        res.err().map(|e| e.code()),
+        Some(ErrorCode::PASSWORD_POLICY_IS_USED_BY_USER)
+    );
+    user_mgr.drop_user(tenant, identity, false).await?;
+    user_mgr
+        .drop_password_policy(tenant, policy_name, false)
+        .await?;
+
+    Ok(())
+}
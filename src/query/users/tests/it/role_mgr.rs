@@ -17,6 +17,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_grpc::RpcClientConf;
 use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::OwnershipObject;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserPrivilegeSet;
 use common_meta_app::principal::UserPrivilegeType;
@@ -101,5 +102,34 @@ async fn test_role_manager() -> Result<()> {
         assert_eq!(role.grants.entries().len(), 0);
     }
 
+    // grant, transfer and revoke ownership
+    {
+        let object = OwnershipObject::Table {
+            catalog_name: "default".to_string(),
+            db_id: 1,
+            table_id: 2,
+        };
+        assert_eq!(role_mgr.get_ownership(tenant, &object).await?, None);
+
+        role_mgr
+            .grant_ownership_to_role(tenant, &object, "public")
+            .await?;
+        role_mgr
+            .grant_ownership_to_role(tenant, &object, &role_name)
+            .await?;
+        let ownership = role_mgr.get_ownership(tenant, &object).await?.unwrap();
+        assert_eq!(ownership.object, object);
+        assert_eq!(ownership.role, role_name);
+
+        // the ownership is not a role
+        let roles = role_mgr.get_roles(tenant).await?;
+        assert_eq!(roles.len(), 3);
+
+        role_mgr.revoke_ownership(tenant, &object).await?;
+        assert_eq!(role_mgr.get_ownership(tenant, &object).await?, None);
+        // revoke again is ok
+        role_mgr.revoke_ownership(tenant, &object).await?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,84 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::WorkloadGroup;
+use common_users::WorkloadGroupManager;
+
+fn workload_group(name: &str, cpu_share: u64, max_concurrency: u64) -> WorkloadGroup {
+    WorkloadGroup {
+        name: name.to_string(),
+        cpu_share,
+        max_concurrency,
+        queue_timeout: 1,
+        ..Default::default()
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_workload_group_queue() -> Result<()> {
+    let manager = WorkloadGroupManager::create();
+    let resources = manager.get_resources("test", &workload_group("etl", 100, 1));
+
+    let first = resources.acquire().await?;
+    assert_eq!(resources.running_queries(), 1);
+
+    // The second query waits in the queue until the first one finishes.
+    let waiting = {
+        let resources = resources.clone();
+        tokio::spawn(async move { resources.acquire().await })
+    };
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(resources.queued_queries(), 1);
+
+    drop(first);
+    let second = waiting.await.unwrap()?;
+    assert_eq!(resources.running_queries(), 1);
+    assert_eq!(resources.queued_queries(), 0);
+
+    // The third query times out in the queue.
+    let res = resources.acquire().await;
+    assert_eq!(
+        res.err().map(|e| e.code()),
+        Some(ErrorCode::WORKLOAD_GROUP_QUEUE_TIMEOUT)
+    );
+    assert_eq!(resources.queued_queries(), 0);
+
+    drop(second);
+    assert_eq!(resources.running_queries(), 0);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_workload_group_cpu_share() -> Result<()> {
+    let manager = WorkloadGroupManager::create();
+    let etl = manager.get_resources("test", &workload_group("etl", 100, 0));
+    let dashboard = manager.get_resources("test", &workload_group("dashboard", 300, 0));
+
+    // The only busy group uses all the threads.
+    let _etl_permit = etl.acquire().await?;
+    assert_eq!(manager.max_threads(&etl, 8), 8);
+
+    // Busy groups share the threads by their cpu share.
+    let _dashboard_permit = dashboard.acquire().await?;
+    assert_eq!(manager.max_threads(&etl, 8), 2);
+    assert_eq!(manager.max_threads(&dashboard, 8), 6);
+    assert_eq!(manager.max_threads(&etl, 2), 1);
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="mysql --defaults-extra-file=password_20_0014.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=audit-user\npassword=${TEST_USER_PASSWORD}" >> password_20_0014.out
+
+echo "drop user if exists 'audit-user'" | $MYSQL_CLIENT_CONNECT
+echo "create user 'audit-user' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $MYSQL_CLIENT_CONNECT
+echo "create table t20_0014(c int)" | $MYSQL_CLIENT_CONNECT
+
+## rejected by the access checker
+echo "insert into t20_0014 values(1)" | $TEST_USER_CONNECT
+
+## the denied statement is in the audit log
+echo "select event_type, detail, success, error_code from system.audit_log where event_type = 'access_denied' and user = '''audit-user''@''%''' order by seq" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table t20_0014" | $MYSQL_CLIENT_CONNECT
+echo "drop user 'audit-user'" | $MYSQL_CLIENT_CONNECT
+rm -rf password_20_0014.out
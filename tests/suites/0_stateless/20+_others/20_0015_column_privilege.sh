@@ -0,0 +1,55 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="mysql --defaults-extra-file=password_20_0015.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=column-user\npassword=${TEST_USER_PASSWORD}" >> password_20_0015.out
+
+echo "drop database if exists db_20_0015" | $MYSQL_CLIENT_CONNECT
+echo "drop user if exists 'column-user'" | $MYSQL_CLIENT_CONNECT
+echo "drop role if exists 'column_analyst'" | $MYSQL_CLIENT_CONNECT
+
+echo "create database db_20_0015" | $MYSQL_CLIENT_CONNECT
+echo "create table db_20_0015.t1(id int, name string, salary int)" | $MYSQL_CLIENT_CONNECT
+echo "insert into db_20_0015.t1 values(1, 'a', 100), (2, 'b', 200)" | $MYSQL_CLIENT_CONNECT
+echo "create table db_20_0015.mine(v int)" | $MYSQL_CLIENT_CONNECT
+echo "create role 'column_analyst'" | $MYSQL_CLIENT_CONNECT
+echo "grant select(id, name) on db_20_0015.t1 to role 'column_analyst'" | $MYSQL_CLIENT_CONNECT
+echo "grant select, insert on db_20_0015.mine to role 'column_analyst'" | $MYSQL_CLIENT_CONNECT
+echo "grant create on db_20_0015.* to role 'column_analyst'" | $MYSQL_CLIENT_CONNECT
+echo "create user 'column-user' IDENTIFIED BY '$TEST_USER_PASSWORD' with default_role = 'column_analyst'" | $MYSQL_CLIENT_CONNECT
+echo "grant role 'column_analyst' to 'column-user'" | $MYSQL_CLIENT_CONNECT
+
+## allowed columns
+echo "select 'test -- allow'" | $TEST_USER_CONNECT
+echo "select id, name from db_20_0015.t1 order by id" | $TEST_USER_CONNECT
+echo "select count(*) from db_20_0015.t1" | $TEST_USER_CONNECT
+
+## denied columns, including the ones only used by a folded count
+echo "select 'test -- deny'" | $TEST_USER_CONNECT
+echo "select salary from db_20_0015.t1" | $TEST_USER_CONNECT
+echo "select count(salary) from db_20_0015.t1" | $TEST_USER_CONNECT
+
+## select * fails by default
+echo "select 'test -- select star'" | $TEST_USER_CONNECT
+echo "select * from db_20_0015.t1 order by id" | $TEST_USER_CONNECT
+
+## select * leaves out the denied columns
+echo "select 'test -- select star exclude'" | $TEST_USER_CONNECT
+echo "set select_star_exclude_denied_columns = 1; select * from db_20_0015.t1 order by id" | $TEST_USER_CONNECT
+
+## the sources of insert and create table as select are checked too
+echo "select 'test -- insert and create table as select'" | $TEST_USER_CONNECT
+echo "insert into db_20_0015.mine select id from db_20_0015.t1" | $TEST_USER_CONNECT
+echo "select v from db_20_0015.mine order by v" | $TEST_USER_CONNECT
+echo "insert into db_20_0015.mine select salary from db_20_0015.t1" | $TEST_USER_CONNECT
+echo "create table db_20_0015.copied as select salary from db_20_0015.t1" | $TEST_USER_CONNECT
+echo "select count(*) from db_20_0015.mine" | $TEST_USER_CONNECT
+echo "show tables from db_20_0015 like 'copied'" | $MYSQL_CLIENT_CONNECT
+
+echo "drop database db_20_0015" | $MYSQL_CLIENT_CONNECT
+echo "drop user 'column-user'" | $MYSQL_CLIENT_CONNECT
+echo "drop role 'column_analyst'" | $MYSQL_CLIENT_CONNECT
+rm -rf password_20_0015.out